@@ -1,16 +1,28 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::{Parser, Subcommand, ValueEnum};
+use schemars::schema_for;
+use std::collections::{BTreeMap, HashSet};
 use std::env;
+use std::fmt::Write as _;
+use std::io::{IsTerminal, Write as _};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 use std::time::Duration;
 use tracing_subscriber::EnvFilter;
 
 use github_insight::formatter::{
-    TimezoneOffset, issue_body_markdown_with_timezone, issue_body_markdown_with_timezone_light,
+    DEFAULT_LIGHT_BODY_LENGTH, RenderOptions, TimezoneOffset, auth_status_markdown,
+    issue_body_html_with_timezone, issue_body_markdown_light_with_body_truncation,
+    issue_body_markdown_with_comment_range, profile_validation_report_markdown,
     project_body_markdown_with_timezone, project_resource_body_markdown_with_timezone,
-    project_resource_body_markdown_with_timezone_light, pull_request_body_markdown_with_timezone,
-    pull_request_body_markdown_with_timezone_light, repository_body_markdown_with_timezone,
+    project_resource_body_markdown_with_timezone_light, pull_request_body_html_with_timezone,
+    pull_request_body_markdown_light_with_body_truncation,
+    pull_request_body_markdown_with_comment_filter, rate_limit_status_markdown_with_timezone,
+    repository_body_markdown_with_timezone,
     repository_branch_group_list_with_descriptions_markdown,
-    repository_branch_group_markdown_with_timezone,
+    repository_branch_group_markdown_with_timezone, repository_contributors_markdown,
+    repository_stats_markdown_with_timezone, search_results_csv_with_timezone,
+    search_results_table_markdown,
 };
 
 /// Parse timezone if provided, otherwise use local timezone
@@ -20,13 +32,20 @@ fn parse_timezone_or_default(timezone: Option<String>) -> Option<TimezoneOffset>
         .or_else(|| Some(TimezoneOffset::from_local()))
 }
 use github_insight::github::GitHubClient;
-use github_insight::services::{ProfileService, default_profile_config_dir};
+use github_insight::github::error::ApiRetryableError;
+use github_insight::github::graphql::error::{is_not_found_error, is_permission_error};
+use github_insight::services::{ProfileService, ProfileServiceError, default_profile_config_dir};
 use github_insight::tools::functions;
 use github_insight::types::project::{ProjectNumber, ProjectUrl};
-use github_insight::types::repository::{Owner, RepositoryName};
+use github_insight::types::repository::Owner;
 use github_insight::types::{
-    GroupName, IssueUrl, OutputOption, ProfileName, ProjectId, PullRequestUrl,
-    RepositoryBranchPair, RepositoryId, RepositoryUrl, SearchQuery,
+    AuthStatus, ClearScope, Contributor, DEFAULT_SEARCH_QUERY, DeduplicatedProjectResources,
+    GithubRepository, GroupName, InvalidUrlError, Issue, IssueOrPullrequest, IssueUrl,
+    OutputOption, ProfileName, Project, ProjectFetchLimits, ProjectId, ProjectResource,
+    ProjectResourceDeduplication, PullRequest, PullRequestCommits, PullRequestReviews,
+    PullRequestUrl, RateLimitStatus, RepositoryBranchPair, RepositoryId, RepositoryStats,
+    RepositoryUrl, ResourceState, SearchQuery, SearchSort, parse_project_field_filter,
+    parse_search_date,
 };
 
 #[derive(Parser)]
@@ -41,7 +60,7 @@ use github_insight::types::{
 struct Cli {
     #[command(subcommand)]
     command: Commands,
-    /// Output format for results - markdown provides formatted display, json for programmatic use and API integration
+    /// Output format for results - markdown provides formatted display, json for programmatic use and API integration, csv for spreadsheets (search only)
     #[arg(long, global = true, default_value = "markdown")]
     format: OutputFormat,
     /// GitHub personal access token for API access (can also be set via GITHUB_TOKEN or GITHUB_INSIGHT_GITHUB_TOKEN environment variables)
@@ -50,21 +69,120 @@ struct Cli {
     /// Timezone for datetime formatting in output - supports standard timezones (e.g., "JST", "+09:00", "America/New_York", "UTC")
     #[arg(long, global = true)]
     timezone: Option<String>,
+    /// Render issue, pull request, and comment timestamps relative to now, e.g. "3 days ago" (markdown and html output)
+    #[arg(long, global = true)]
+    relative_time: bool,
+    /// Group digits of counts such as stars, forks, additions, and deletions with thousands separators, e.g. "1,234,567" (markdown output)
+    #[arg(long, global = true)]
+    thousands_separator: bool,
     /// Request timeout in seconds for GitHub API calls - useful for slow networks or large data sets (default: 30 seconds)
     #[arg(long, global = true)]
     request_timeout: Option<u64>,
+    /// Maximum number of repositories fetched at the same time by batch commands (get-issues, get-pull-requests, get-pull-request-diffs, get-pull-request-diff-stats, get-repositories, get-repository-stats, get-projects, get-project-resources) - lower it to avoid GitHub secondary rate limits (default: 10)
+    #[arg(long, global = true)]
+    max_concurrency: Option<usize>,
+    /// Only log warnings and errors
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+    /// Increase log verbosity - -v for debug, -vv for trace (an explicit RUST_LOG takes precedence)
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+    /// Write results to this file (UTF-8, overwritten) instead of stdout
+    #[arg(long, global = true)]
+    output_file: Option<PathBuf>,
+    /// Colorize issue and pull request states in markdown output - auto colors only when stdout is a terminal
+    #[arg(long, global = true, default_value = "auto")]
+    color: ColorChoice,
+    /// Disable colored output, same as --color never
+    #[arg(long, global = true, conflicts_with = "color")]
+    no_color: bool,
+    /// Keep only these top-level keys of each object in --format json output, comma separated (search, get-issues, get-pull-requests, get-repositories)
+    #[arg(long, global = true, value_delimiter = ',')]
+    fields: Vec<String>,
+}
+
+/// Environment variable supplying the default of every subcommand's `--profile`
+const PROFILE_ENV_VAR: &str = "GITHUB_INSIGHT_PROFILE";
+
+/// Profile used when neither `--profile` nor `GITHUB_INSIGHT_PROFILE` is given
+const DEFAULT_PROFILE_NAME: &str = "default";
+
+/// Default of every subcommand's `--profile`
+///
+/// An explicit `--profile` always wins over this default.
+fn default_profile() -> String {
+    resolve_default_profile(env::var(PROFILE_ENV_VAR).ok())
+}
+
+/// Pick the profile from the environment variable's value, ignoring an empty value
+fn resolve_default_profile(env_value: Option<String>) -> String {
+    env_value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| DEFAULT_PROFILE_NAME.to_string())
+}
+
+/// Whether the command writes its results itself as they arrive
+///
+/// JSONL search results and `search --watch` are streamed to stdout or --output-file,
+/// leaving only notices in the buffered output. Writing that buffer to the output file
+/// would truncate the streamed results.
+fn streams_output(cli: &Cli) -> bool {
+    matches!(cli.format, OutputFormat::Jsonl)
+        || matches!(cli.command, Commands::Search { watch: Some(_), .. })
+}
+
+/// Resolve the log level for the crate from the --quiet/--verbose flags
+fn log_level(quiet: bool, verbose: u8) -> &'static str {
+    if quiet {
+        return "warn";
+    }
+    match verbose {
+        0 => "info",
+        1 => "debug",
+        _ => "trace",
+    }
 }
 
 #[derive(Clone, ValueEnum)]
 enum OutputFormat {
     Json,
     Markdown,
+    /// Spreadsheet-friendly rows - supported by the search command only
+    Csv,
+    /// Sanitized HTML fragments - supported by the get-issues and get-pull-requests commands only
+    Html,
+    /// One compact JSON object per line, streamed as each repository's results arrive - supported by the search command only
+    Jsonl,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ColorChoice {
+    /// Color when writing markdown to a terminal
+    Auto,
+    /// Always color markdown output, even when piped
+    Always,
+    /// Never emit ANSI color codes
+    Never,
+}
+
+impl ColorChoice {
+    /// Whether to emit ANSI colors; `is_terminal` tells if output goes to a terminal
+    fn enabled(self, is_terminal: bool) -> bool {
+        match self {
+            ColorChoice::Auto => is_terminal,
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
 }
 
 #[derive(Clone, ValueEnum)]
 enum OutputOptionCli {
     Light,
     Rich,
+    /// One markdown table row per result (search only; other commands use light)
+    Table,
 }
 
 impl From<OutputOptionCli> for OutputOption {
@@ -72,6 +190,85 @@ impl From<OutputOptionCli> for OutputOption {
         match cli_option {
             OutputOptionCli::Light => OutputOption::Light,
             OutputOptionCli::Rich => OutputOption::Rich,
+            OutputOptionCli::Table => OutputOption::Table,
+        }
+    }
+}
+
+#[derive(Clone, ValueEnum)]
+enum DeduplicationCli {
+    Raw,
+    KeepFirst,
+    Merge,
+}
+
+impl From<DeduplicationCli> for ProjectResourceDeduplication {
+    fn from(cli_option: DeduplicationCli) -> Self {
+        match cli_option {
+            DeduplicationCli::Raw => ProjectResourceDeduplication::Raw,
+            DeduplicationCli::KeepFirst => ProjectResourceDeduplication::KeepFirst,
+            DeduplicationCli::Merge => ProjectResourceDeduplication::Merge,
+        }
+    }
+}
+
+#[derive(Clone, ValueEnum)]
+enum SearchSortCli {
+    CreatedAsc,
+    CreatedDesc,
+    UpdatedAsc,
+    UpdatedDesc,
+    CommentsAsc,
+    CommentsDesc,
+}
+
+impl From<SearchSortCli> for SearchSort {
+    fn from(cli_option: SearchSortCli) -> Self {
+        match cli_option {
+            SearchSortCli::CreatedAsc => SearchSort::CreatedAsc,
+            SearchSortCli::CreatedDesc => SearchSort::CreatedDesc,
+            SearchSortCli::UpdatedAsc => SearchSort::UpdatedAsc,
+            SearchSortCli::UpdatedDesc => SearchSort::UpdatedDesc,
+            SearchSortCli::CommentsAsc => SearchSort::CommentsAsc,
+            SearchSortCli::CommentsDesc => SearchSort::CommentsDesc,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ResourceStateCli {
+    Open,
+    Closed,
+    Merged,
+    All,
+}
+
+impl From<ResourceStateCli> for ResourceState {
+    fn from(cli_option: ResourceStateCli) -> Self {
+        match cli_option {
+            ResourceStateCli::Open => ResourceState::Open,
+            ResourceStateCli::Closed => ResourceState::Closed,
+            ResourceStateCli::Merged => ResourceState::Merged,
+            ResourceStateCli::All => ResourceState::All,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ClearScopeCli {
+    Repositories,
+    Projects,
+    Groups,
+    All,
+}
+
+impl From<ClearScopeCli> for ClearScope {
+    fn from(cli_option: ClearScopeCli) -> Self {
+        match cli_option {
+            ClearScopeCli::Repositories => ClearScope::Repositories,
+            ClearScopeCli::Projects => ClearScope::Projects,
+            ClearScopeCli::Groups => ClearScope::Groups,
+            ClearScopeCli::All => ClearScope::All,
         }
     }
 }
@@ -82,46 +279,46 @@ enum Commands {
     RegisterRepo {
         /// Repository URL in GitHub format (e.g., <https://github.com/owner/repo>) - supports both .git and non-.git URLs
         repository_url: String,
-        /// Profile name for organizing repositories (default: "default")
-        #[arg(short, long, default_value = "default")]
+        /// Profile name for organizing repositories (default: $GITHUB_INSIGHT_PROFILE, otherwise "default")
+        #[arg(short, long, default_value_t = default_profile())]
         profile: String,
     },
     /// Remove a repository from a profile, excluding it from search and management operations
     UnregisterRepo {
         /// Repository URL to remove from profile
         repository_url: String,
-        /// Profile name containing the repository (default: "default")
-        #[arg(short, long, default_value = "default")]
+        /// Profile name containing the repository (default: $GITHUB_INSIGHT_PROFILE, otherwise "default")
+        #[arg(short, long, default_value_t = default_profile())]
         profile: String,
     },
     /// Register a GitHub project to a profile for comprehensive resource management and tracking with pagination support
     RegisterProject {
         /// GitHub project URL - supports both user and organization projects (e.g., <https://github.com/users/username/projects/1> or <https://github.com/orgs/orgname/projects/1>)
         project_url: String,
-        /// Profile name for organizing projects (default: "default")
-        #[arg(short, long, default_value = "default")]
+        /// Profile name for organizing projects (default: $GITHUB_INSIGHT_PROFILE, otherwise "default")
+        #[arg(short, long, default_value_t = default_profile())]
         profile: String,
     },
     /// Remove a GitHub project from a profile, excluding it from resource management and tracking
     UnregisterProject {
         /// Project URL to remove from profile
         project_url: String,
-        /// Profile name containing the project (default: "default")
-        #[arg(short, long, default_value = "default")]
+        /// Profile name containing the project (default: $GITHUB_INSIGHT_PROFILE, otherwise "default")
+        #[arg(short, long, default_value_t = default_profile())]
         profile: String,
     },
     /// Display all available profiles with their configurations and metadata
     ListProfiles,
     /// Display all repositories registered in a specific profile with their URLs and registration details
     ListRepos {
-        /// Profile name to list repositories from (default: "default")
-        #[arg(short, long, default_value = "default")]
+        /// Profile name to list repositories from (default: $GITHUB_INSIGHT_PROFILE, otherwise "default")
+        #[arg(short, long, default_value_t = default_profile())]
         profile: String,
     },
     /// Display all GitHub projects registered in a specific profile with their URLs and metadata
     ListProjects {
-        /// Profile name to list projects from (default: "default")
-        #[arg(short, long, default_value = "default")]
+        /// Profile name to list projects from (default: $GITHUB_INSIGHT_PROFILE, otherwise "default")
+        #[arg(short, long, default_value_t = default_profile())]
         profile: String,
     },
     /// Create a new profile for organizing repositories and projects with optional description
@@ -132,14 +329,50 @@ enum Commands {
         #[arg(short, long)]
         description: Option<String>,
     },
+    /// Replace the description of an existing profile, keeping its registrations
+    SetProfileDescription {
+        /// Profile name to update
+        name: String,
+        /// New description - omit it to clear the description
+        description: Option<String>,
+    },
     /// Delete a profile and all its associated repository and project registrations (irreversible)
     DeleteProfile {
         /// Profile name to delete permanently
         name: String,
     },
+    /// Unregister all repositories, projects, and/or branch groups of a profile, keeping the profile itself
+    ClearProfile {
+        /// Profile name to clear
+        name: String,
+        /// What to remove from the profile (default: all)
+        #[arg(long, default_value = "all")]
+        scope: ClearScopeCli,
+    },
+    /// Check that the repositories and projects registered in a profile are still reachable
+    ValidateProfile {
+        /// Profile name to validate (default: $GITHUB_INSIGHT_PROFILE, otherwise "default")
+        #[arg(short, long, default_value_t = default_profile())]
+        profile: String,
+        /// Unregister the repositories and projects that were not found
+        #[arg(long)]
+        prune: bool,
+    },
+    /// Set the default search query and limit used by the search command when they are omitted
+    SetProfileDefaults {
+        /// Profile name to update (default: $GITHUB_INSIGHT_PROFILE, otherwise "default")
+        #[arg(short, long, default_value_t = default_profile())]
+        profile: String,
+        /// Default search query - an empty string clears it
+        #[arg(short, long)]
+        query: Option<String>,
+        /// Default maximum number of results - 0 clears it
+        #[arg(short, long)]
+        limit: Option<usize>,
+    },
     /// Register a repository branch group to a profile for managing collections of branches
     RegisterGroup {
-        /// Branch specifiers in format "repo_url@branch" (e.g., "https://github.com/owner/repo@main")
+        /// Branch specifiers in format "repo_url@branch" (e.g., "https://github.com/owner/repo@main"); "repo_url@" or "repo_url" tracks the default branch
         pairs: Vec<String>,
         /// Optional group name - if not provided, auto-generates with yyyymmdd-hash format
         #[arg(short = 'n', long)]
@@ -147,26 +380,26 @@ enum Commands {
         /// Optional description for the group
         #[arg(short = 'd', long)]
         description: Option<String>,
-        /// Profile name for organizing groups (default: "default")
-        #[arg(short, long, default_value = "default")]
+        /// Profile name for organizing groups (default: $GITHUB_INSIGHT_PROFILE, otherwise "default")
+        #[arg(short, long, default_value_t = default_profile())]
         profile: String,
     },
     /// Remove a repository branch group from a profile
     UnregisterGroup {
         /// Group name to remove
         group_name: String,
-        /// Profile name containing the group (default: "default")
-        #[arg(short, long, default_value = "default")]
+        /// Profile name containing the group (default: $GITHUB_INSIGHT_PROFILE, otherwise "default")
+        #[arg(short, long, default_value_t = default_profile())]
         profile: String,
     },
     /// Add branches to an existing group
     AddBranchToBranchGroup {
         /// Group name to add branches to
         group_name: String,
-        /// Branch specifiers in format "repo_url@branch"
+        /// Branch specifiers in format "repo_url@branch"; "repo_url@" or "repo_url" tracks the default branch
         branch_specifiers: Vec<String>,
-        /// Profile name containing the group (default: "default")
-        #[arg(short, long, default_value = "default")]
+        /// Profile name containing the group (default: $GITHUB_INSIGHT_PROFILE, otherwise "default")
+        #[arg(short, long, default_value_t = default_profile())]
         profile: String,
     },
     /// Remove branches from a group
@@ -175,8 +408,8 @@ enum Commands {
         group_name: String,
         /// Branch specifiers in format "repo_url@branch"
         branch_specifiers: Vec<String>,
-        /// Profile name containing the group (default: "default")
-        #[arg(short, long, default_value = "default")]
+        /// Profile name containing the group (default: $GITHUB_INSIGHT_PROFILE, otherwise "default")
+        #[arg(short, long, default_value_t = default_profile())]
         profile: String,
     },
     /// Rename a repository branch group
@@ -185,59 +418,93 @@ enum Commands {
         old_name: String,
         /// New group name
         new_name: String,
-        /// Profile name containing the group (default: "default")
-        #[arg(short, long, default_value = "default")]
+        /// Profile name containing the group (default: $GITHUB_INSIGHT_PROFILE, otherwise "default")
+        #[arg(short, long, default_value_t = default_profile())]
+        profile: String,
+    },
+    /// Copy a repository branch group under a new name
+    CloneGroup {
+        /// Group name to copy
+        source_name: String,
+        /// Name of the new group
+        destination_name: String,
+        /// Profile name containing the group (default: $GITHUB_INSIGHT_PROFILE, otherwise "default")
+        #[arg(short, long, default_value_t = default_profile())]
         profile: String,
     },
     /// List all repository branch groups in a profile
     ListBranchGroups {
-        /// Profile name to list groups from (default: "default")
-        #[arg(short, long, default_value = "default")]
+        /// Profile name to list groups from (default: $GITHUB_INSIGHT_PROFILE, otherwise "default")
+        #[arg(short, long, default_value_t = default_profile())]
         profile: String,
     },
     /// Show details of a specific repository branch group
     ShowGroup {
         /// Group name to show details for
         group_name: String,
-        /// Profile name containing the group (default: "default")
-        #[arg(short, long, default_value = "default")]
+        /// Profile name containing the group (default: $GITHUB_INSIGHT_PROFILE, otherwise "default")
+        #[arg(short, long, default_value_t = default_profile())]
         profile: String,
     },
     /// Remove repository branch groups older than N days
     CleanupGroups {
         /// Number of days - groups older than this will be removed
         days: i64,
-        /// Profile name to clean up (default: "default")
-        #[arg(short, long, default_value = "default")]
+        /// Profile name to clean up (default: $GITHUB_INSIGHT_PROFILE, otherwise "default")
+        #[arg(short, long, default_value_t = default_profile())]
         profile: String,
+        /// List the groups that would be removed without removing them
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Search for issues and pull requests across multiple repositories with advanced GitHub search syntax and pagination support
     Search {
-        /// Search query text - supports full GitHub search syntax (e.g., "is:issue state:open author:username", "is:pr label:bug", "created:>2024-01-01"). Note: Repository specifications (repo:owner/name) are not supported in the query and will be ignored - use the --repository option or register repositories in the profile instead
-        query: String,
-        /// Profile name containing repositories to search (default: "default")
-        #[arg(short, long, default_value = "default")]
+        /// Search query text - supports full GitHub search syntax (e.g., "is:issue state:open author:username", "is:pr label:bug", "created:>2024-01-01"). Defaults to the profile's default query, or "state:open" when none is set. Note: Repository specifications (repo:owner/name) are not supported in the query and will be ignored - use the --repository option or register repositories in the profile instead
+        query: Option<String>,
+        /// Profile name containing repositories to search (default: $GITHUB_INSIGHT_PROFILE, otherwise "default")
+        #[arg(short, long, default_value_t = default_profile())]
         profile: String,
         /// Optional repository to limit search scope - format: GitHub URL (e.g., "https://github.com/microsoft/vscode")
         #[arg(short, long)]
         repository_url: Option<String>,
-        /// Maximum number of results to return - useful for controlling output size (default: 30, max: 100)
-        #[arg(short, long, default_value = "30")]
-        limit: usize,
-        /// Output format for search results - light provides minimal information, rich provides comprehensive details (default: light)
+        /// Maximum number of results to return - useful for controlling output size (default: the profile's default limit, or 30 when none is set; max: 100)
+        #[arg(short, long)]
+        limit: Option<usize>,
+        /// Output format for search results - light provides minimal information, rich provides comprehensive details, table renders one markdown table row per result (default: light)
         #[arg(long, default_value = "light")]
         output: OutputOptionCli,
+        /// Sort order for results in each repository - omit for GitHub's best-match ordering
+        #[arg(long)]
+        sort: Option<SearchSortCli>,
+        /// Only include results created on or after this date - YYYY-MM-DD or days ago like "7d", resolved in --timezone
+        #[arg(long)]
+        since: Option<String>,
+        /// Only include results created on or before this date - YYYY-MM-DD or days ago like "7d", resolved in --timezone
+        #[arg(long)]
+        until: Option<String>,
+        /// Only include results in this state - appends state:open, state:closed, or is:merged to the query unless already present, replacing a conflicting state qualifier such as the default state:open (default: all)
+        #[arg(long, default_value = "all")]
+        state: ResourceStateCli,
+        /// Re-run the search every SECONDS seconds and print only results not seen before, until interrupted with Ctrl-C (minimum: 10). Results are newest first unless --sort is given; use jsonl for one JSON line per result (json, csv, and html are rejected)
+        #[arg(long, value_name = "SECONDS")]
+        watch: Option<u64>,
     },
     /// Fetch detailed project resources including items, metadata, timestamps, and assignees with comprehensive pagination support
     GetProjectResources {
         /// Optional project URL to fetch resources from - if not provided, fetches all projects from profile for batch processing
         project_url: Option<String>,
-        /// Profile name containing projects to fetch resources from (default: "default")
-        #[arg(short, long, default_value = "default")]
+        /// Profile name containing projects to fetch resources from (default: $GITHUB_INSIGHT_PROFILE, otherwise "default")
+        #[arg(short, long, default_value_t = default_profile())]
         profile: String,
         /// Output format for project resources - light provides minimal information, rich provides comprehensive details (default: rich)
         #[arg(long, default_value = "rich")]
         output: OutputOptionCli,
+        /// Collapse items wrapping the same issue or pull request across projects - keep-first keeps the first item, merge also adds custom field values of collapsed items (default: raw)
+        #[arg(long, default_value = "raw")]
+        deduplicate: DeduplicationCli,
+        /// Only include items whose custom field matches, in 'Field=Value' form (e.g. --field-filter "Status=In Progress") - repeatable, all filters must match
+        #[arg(long = "field-filter", value_parser = parse_project_field_filter)]
+        field_filters: Vec<(String, String)>,
     },
     /// Fetch detailed issue information including comments, metadata, labels, and timeline events by URLs
     GetIssues {
@@ -249,6 +516,16 @@ enum Commands {
         /// GitHub pull request URLs to fetch detailed information from - supports multiple URLs for batch processing
         urls: Vec<String>,
     },
+    /// Fetch pull request reviews with reviewer, state, submission time, and body by URLs
+    GetPullRequestReviews {
+        /// GitHub pull request URLs to fetch reviews from - supports multiple URLs for batch processing
+        urls: Vec<String>,
+    },
+    /// Fetch the commits of pull requests by URLs - short SHA, message headline, author, and authored date
+    GetPullRequestCommits {
+        /// GitHub pull request URLs to fetch commits from - supports multiple URLs for batch processing
+        urls: Vec<String>,
+    },
     /// Fetch pull request code diffs in unified diff format by URLs
     GetPullRequestDiffs {
         /// GitHub pull request URLs to fetch diffs from - supports multiple URLs for batch processing
@@ -271,6 +548,12 @@ enum Commands {
         /// Optional maximum number of lines to return
         #[arg(long)]
         limit: Option<u32>,
+        /// Safety cap on returned lines - longer diffs are truncated with a notice (default: 1000)
+        #[arg(long)]
+        max_lines: Option<u32>,
+        /// Return only the complete hunk at this 0-based index, including its @@ header
+        #[arg(long, conflicts_with_all = ["skip", "limit"])]
+        hunk_index: Option<u32>,
     },
     /// Fetch detailed repository information including metadata, statistics, releases (with configurable limit), and configuration by URLs
     GetRepositories {
@@ -282,30 +565,232 @@ enum Commands {
         /// Optional limit for number of milestones to show per repository (default: 10)
         #[arg(long)]
         showing_milestone_limit: Option<usize>,
+        /// Render each label in its GitHub color as an inline HTML span (markdown output only)
+        #[arg(long)]
+        show_label_colors: bool,
+    },
+    /// Show the top contributors of a repository ranked by contribution count
+    GetRepositoryContributors {
+        /// GitHub repository URL to list contributors of
+        url: String,
+        /// Maximum number of contributors to show (default: 10)
+        #[arg(long, default_value = "10")]
+        limit: usize,
+    },
+    /// Show open/closed issue and pull request counts, stars, and forks of repositories
+    GetRepositoryStats {
+        /// GitHub repository URLs to get counts for
+        urls: Vec<String>,
     },
     /// Fetch detailed project information including metadata, description, and timestamps by URLs
     GetProjects {
         /// GitHub project URLs to fetch detailed information from - supports multiple URLs for batch processing
         urls: Vec<String>,
     },
+    /// Show the GitHub GraphQL API rate limit status (remaining points and reset time) of the token
+    GetRateLimitStatus,
+    /// Check the configured GitHub token and show the authenticated user and token scopes
+    AuthCheck,
+    /// Print the JSON Schema of a command's --format json output for validating parsed results
+    Schema {
+        /// Command whose JSON output schema to print
+        command: SchemaTarget,
+    },
+}
+
+/// Commands whose `--format json` output has a published JSON Schema
+#[derive(Clone, ValueEnum)]
+enum SchemaTarget {
+    Search,
+    GetProjectResources,
+    GetIssues,
+    GetPullRequests,
+    GetPullRequestReviews,
+    GetPullRequestCommits,
+    GetRepositories,
+    GetRepositoryContributors,
+    GetRepositoryStats,
+    GetProjects,
+    GetRateLimitStatus,
+    AuthCheck,
+}
+
+impl SchemaTarget {
+    /// JSON Schema of the value the command serializes with `--format json`
+    fn output_schema(&self) -> schemars::schema::RootSchema {
+        match self {
+            SchemaTarget::Search => schema_for!(Vec<IssueOrPullrequest>),
+            SchemaTarget::GetProjectResources => schema_for!(Vec<ProjectResource>),
+            SchemaTarget::GetIssues => schema_for!(BTreeMap<String, Vec<Issue>>),
+            SchemaTarget::GetPullRequests => schema_for!(BTreeMap<String, Vec<PullRequest>>),
+            SchemaTarget::GetPullRequestReviews => schema_for!(Vec<PullRequestReviews>),
+            SchemaTarget::GetPullRequestCommits => schema_for!(Vec<PullRequestCommits>),
+            SchemaTarget::GetRepositories => schema_for!(Vec<GithubRepository>),
+            SchemaTarget::GetRepositoryContributors => schema_for!(Vec<Contributor>),
+            SchemaTarget::GetRepositoryStats => schema_for!(Vec<RepositoryStats>),
+            SchemaTarget::GetProjects => schema_for!(Vec<Project>),
+            SchemaTarget::GetRateLimitStatus => schema_for!(RateLimitStatus),
+            SchemaTarget::AuthCheck => schema_for!(AuthStatus),
+        }
+    }
 }
 
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() -> ExitCode {
     // Initialize rustls crypto provider early to prevent "no process-level CryptoProvider available" panics
     rustls::crypto::ring::default_provider()
         .install_default()
         .expect("Failed to install rustls crypto provider");
 
-    // Initialize logging
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(e) => {
+            // --help and --version are reported as errors too, but on stdout
+            let _ = e.print();
+            return if e.use_stderr() {
+                ExitCode::from(USAGE_EXIT_CODE)
+            } else {
+                ExitCode::SUCCESS
+            };
+        }
+    };
+
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            let error = CliError::from_error(&error);
+            eprintln!("Error: {}", error);
+            ExitCode::from(error.exit_code())
+        }
+    }
+}
+
+/// Exit code of command line usage errors, such as unknown arguments
+const USAGE_EXIT_CODE: u8 = 64;
+
+/// Error categories of the CLI, each exiting with its own process exit code
+///
+/// Scripts can branch on the exit code instead of parsing the message, which is
+/// printed to stderr.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CliError {
+    /// A repository, project, issue, or pull request URL could not be parsed
+    InvalidUrl(String),
+    /// GitHub rejected the request for missing or insufficient credentials
+    AuthRequired(String),
+    /// A profile, a registered resource, or a GitHub resource does not exist
+    NotFound(String),
+    /// The GitHub API rate limit is exhausted
+    RateLimited(String),
+    /// GitHub could not be reached
+    Network(String),
+    /// Any other failure
+    Other(String),
+}
+
+impl CliError {
+    /// Process exit code of this error category
+    fn exit_code(&self) -> u8 {
+        match self {
+            Self::Other(_) => 1,
+            Self::NotFound(_) => 2,
+            Self::AuthRequired(_) => 3,
+            Self::RateLimited(_) => 4,
+            Self::Network(_) => 5,
+            Self::InvalidUrl(_) => 6,
+        }
+    }
+
+    /// Classify a profile service error, keeping `message` for display
+    fn from_profile_service_error(error: &ProfileServiceError, message: String) -> Self {
+        match error {
+            ProfileServiceError::ProfileNotFound(_)
+            | ProfileServiceError::RepositoryNotFound(_)
+            | ProfileServiceError::ProjectNotFound(_)
+            | ProfileServiceError::GroupNotFound(_)
+            | ProfileServiceError::PairNotFound(_) => Self::NotFound(message),
+            _ => Self::Other(message),
+        }
+    }
+
+    /// Classify an error returned by a command
+    ///
+    /// Errors are recognized by the types in their context chain: `CliError`,
+    /// `ProfileServiceError`, `InvalidUrlError`, `ApiRetryableError` and `reqwest::Error`.
+    /// Only GraphQL error bodies, which GitHub reports as text, are classified by the
+    /// phrases GitHub uses for each category.
+    fn from_error(error: &anyhow::Error) -> Self {
+        if let Some(cli_error) = error.downcast_ref::<CliError>() {
+            return cli_error.clone();
+        }
+
+        let message = format!("{:#}", error);
+        for cause in error.chain() {
+            if let Some(profile_error) = cause.downcast_ref::<ProfileServiceError>() {
+                return Self::from_profile_service_error(profile_error, message);
+            }
+            if cause.is::<InvalidUrlError>() {
+                return Self::InvalidUrl(message);
+            }
+            if let Some(api_error) = cause.downcast_ref::<ApiRetryableError>() {
+                match api_error {
+                    ApiRetryableError::RateLimit => return Self::RateLimited(message),
+                    ApiRetryableError::Network(_) => return Self::Network(message),
+                    ApiRetryableError::Unauthorized(_) => return Self::AuthRequired(message),
+                    ApiRetryableError::Retryable(_) | ApiRetryableError::NonRetryable(_) => {}
+                }
+            }
+            if let Some(request_error) = cause.downcast_ref::<reqwest::Error>() {
+                if request_error.is_timeout() || request_error.is_connect() {
+                    return Self::Network(message);
+                }
+                match request_error.status().map(|status| status.as_u16()) {
+                    Some(401) => return Self::AuthRequired(message),
+                    Some(404) => return Self::NotFound(message),
+                    _ => {}
+                }
+            }
+        }
+
+        if message.to_lowercase().contains("rate limit") {
+            Self::RateLimited(message)
+        } else if is_permission_error(&message) {
+            Self::AuthRequired(message)
+        } else if is_not_found_error(&message) {
+            Self::NotFound(message)
+        } else {
+            Self::Other(message)
+        }
+    }
+}
+
+impl std::fmt::Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidUrl(message)
+            | Self::AuthRequired(message)
+            | Self::NotFound(message)
+            | Self::RateLimited(message)
+            | Self::Network(message)
+            | Self::Other(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+async fn run(cli: Cli) -> Result<()> {
+    // Initialize logging, honoring an explicit RUST_LOG over the --quiet/--verbose flags
+    let level = log_level(cli.quiet, cli.verbose);
     tracing_subscriber::fmt()
         .with_env_filter(
-            EnvFilter::from_default_env().add_directive("github-insight=info".parse()?),
+            EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| format!("error,github_insight={}", level).into()),
         )
         .with_writer(std::io::stderr)
         .init();
 
-    let cli = Cli::parse();
+    let streams_output = streams_output(&cli);
 
     // Get GitHub token from CLI or environment
     let github_token = cli
@@ -315,39 +800,90 @@ async fn main() -> Result<()> {
     // Parse timezone if provided, otherwise use local timezone
     let timezone = parse_timezone_or_default(cli.timezone);
 
+    let color = if cli.no_color {
+        ColorChoice::Never
+    } else {
+        cli.color
+    };
+    let is_terminal = cli.output_file.is_none() && std::io::stdout().is_terminal();
+    let render_options = RenderOptions {
+        // JSON, CSV, and HTML output is never colored
+        color: color.enabled(is_terminal) && matches!(cli.format, OutputFormat::Markdown),
+        thousands_separator: cli.thousands_separator,
+        // CSV rows stay absolute so they can be sorted and parsed
+        relative_time: cli.relative_time && !matches!(cli.format, OutputFormat::Csv),
+    };
+
     // Initialize profile service
-    let config_dir = default_profile_config_dir()
-        .map_err(|e| anyhow::anyhow!("Failed to get config directory: {}", e))?;
+    let config_dir = default_profile_config_dir().context("Failed to get config directory")?;
+
+    let mut profile_service =
+        ProfileService::new(config_dir).context("Failed to initialize profile service")?;
+
+    // CSV rows are only defined for search results; other commands print markdown or JSON.
+    // With CSV, JSONL, and HTML rejected here, command handlers match JSON and render
+    // every other format they receive as markdown
+    if matches!(cli.format, OutputFormat::Csv) && !matches!(cli.command, Commands::Search { .. }) {
+        return Err(anyhow::anyhow!(
+            "--format csv is only supported by the search command"
+        ));
+    }
+
+    // JSONL records are streamed by the search command only
+    if matches!(cli.format, OutputFormat::Jsonl) && !matches!(cli.command, Commands::Search { .. })
+    {
+        return Err(anyhow::anyhow!(
+            "--format jsonl is only supported by the search command"
+        ));
+    }
+
+    // HTML rendering is defined for issue and pull request details only
+    if matches!(cli.format, OutputFormat::Html)
+        && !matches!(
+            cli.command,
+            Commands::GetIssues { .. } | Commands::GetPullRequests { .. }
+        )
+    {
+        return Err(anyhow::anyhow!(
+            "--format html is only supported by the get-issues and get-pull-requests commands"
+        ));
+    }
 
-    let mut profile_service = ProfileService::new(config_dir)
-        .map_err(|e| anyhow::anyhow!("Failed to initialize profile service: {}", e))?;
+    // Results of every command are collected here and written once by write_output
+    let mut out = String::new();
 
     match cli.command {
         Commands::RegisterRepo {
             repository_url,
             profile,
         } => {
-            let repo_id = parse_repository_url(&repository_url)?;
+            let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url.clone()))
+                .map_err(CliError::InvalidUrl)?;
             profile_service
                 .register_repository(&ProfileName::from(profile.as_str()), repo_id)
-                .map_err(|e| anyhow::anyhow!("Failed to register repository: {}", e))?;
-            println!(
+                .map_err(|e| profile_error(&profile_service, "Failed to register repository", e))?;
+            writeln!(
+                out,
                 "Successfully registered repository '{}' to profile '{}'",
                 repository_url, profile
-            );
+            )?;
         }
         Commands::UnregisterRepo {
             repository_url,
             profile,
         } => {
-            let repo_id = parse_repository_url(&repository_url)?;
+            let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url.clone()))
+                .map_err(CliError::InvalidUrl)?;
             profile_service
                 .unregister_repository(&ProfileName::from(profile.as_str()), &repo_id)
-                .map_err(|e| anyhow::anyhow!("Failed to unregister repository: {}", e))?;
-            println!(
+                .map_err(|e| {
+                    profile_error(&profile_service, "Failed to unregister repository", e)
+                })?;
+            writeln!(
+                out,
                 "Successfully unregistered repository '{}' from profile '{}'",
                 repository_url, profile
-            );
+            )?;
         }
         Commands::RegisterProject {
             project_url,
@@ -356,11 +892,12 @@ async fn main() -> Result<()> {
             let project_id = parse_project_url(&project_url)?;
             profile_service
                 .register_project(&ProfileName::from(profile.as_str()), project_id)
-                .map_err(|e| anyhow::anyhow!("Failed to register project: {}", e))?;
-            println!(
+                .map_err(|e| profile_error(&profile_service, "Failed to register project", e))?;
+            writeln!(
+                out,
                 "Successfully registered project '{}' to profile '{}'",
                 project_url, profile
-            );
+            )?;
         }
         Commands::UnregisterProject {
             project_url,
@@ -369,60 +906,147 @@ async fn main() -> Result<()> {
             let project_id = parse_project_url(&project_url)?;
             profile_service
                 .unregister_project(&ProfileName::from(profile.as_str()), &project_id)
-                .map_err(|e| anyhow::anyhow!("Failed to unregister project: {}", e))?;
-            println!(
+                .map_err(|e| profile_error(&profile_service, "Failed to unregister project", e))?;
+            writeln!(
+                out,
                 "Successfully unregistered project '{}' from profile '{}'",
                 project_url, profile
-            );
+            )?;
         }
         Commands::ListProfiles => {
             let profiles = profile_service.list_profiles();
             if profiles.is_empty() {
-                println!("No profiles found");
+                writeln!(out, "No profiles found")?;
             } else {
-                println!("Profiles:");
+                writeln!(out, "Profiles:")?;
                 for profile in profiles {
-                    println!("  - {}", profile);
+                    writeln!(out, "  - {}", profile)?;
                 }
             }
         }
         Commands::ListRepos { profile } => {
             let repos = profile_service
                 .list_repositories(&ProfileName::from(profile.as_str()))
-                .map_err(|e| anyhow::anyhow!("Failed to list repositories: {}", e))?;
+                .map_err(|e| profile_error(&profile_service, "Failed to list repositories", e))?;
             if repos.is_empty() {
-                println!("No repositories found in profile '{}'", profile);
+                writeln!(out, "No repositories found in profile '{}'", profile)?;
             } else {
-                println!("Repositories in profile '{}':", profile);
+                writeln!(out, "Repositories in profile '{}':", profile)?;
                 for repo in repos {
-                    println!("  - {}", repo);
+                    writeln!(out, "  - {}", repo.to_url())?;
                 }
             }
         }
         Commands::ListProjects { profile } => {
             let projects = profile_service
                 .list_projects(&ProfileName::from(profile.as_str()))
-                .map_err(|e| anyhow::anyhow!("Failed to list projects: {}", e))?;
+                .map_err(|e| profile_error(&profile_service, "Failed to list projects", e))?;
             if projects.is_empty() {
-                println!("No projects found in profile '{}'", profile);
+                writeln!(out, "No projects found in profile '{}'", profile)?;
             } else {
-                println!("Projects in profile '{}':", profile);
+                writeln!(out, "Projects in profile '{}':", profile)?;
                 for project in projects {
-                    println!("  - {}", project);
+                    writeln!(out, "  - {}", project.to_url())?;
                 }
             }
         }
         Commands::CreateProfile { name, description } => {
             profile_service
                 .create_profile(&ProfileName::from(name.as_str()), description)
-                .map_err(|e| anyhow::anyhow!("Failed to create profile: {}", e))?;
-            println!("Successfully created profile '{}'", name);
+                .map_err(|e| profile_error(&profile_service, "Failed to create profile", e))?;
+            writeln!(out, "Successfully created profile '{}'", name)?;
+        }
+        Commands::SetProfileDescription { name, description } => {
+            let profile_name = ProfileName::from(name.as_str());
+            profile_service
+                .update_profile_description(&profile_name, description)
+                .map_err(|e| {
+                    profile_error(&profile_service, "Failed to set profile description", e)
+                })?;
+            let profile_info = profile_service
+                .get_profile_info(&profile_name)
+                .map_err(|e| profile_error(&profile_service, "Failed to get profile info", e))?;
+            match profile_info.description {
+                Some(description) => {
+                    writeln!(out, "Description of profile '{}': {}", name, description)?
+                }
+                None => writeln!(out, "Cleared the description of profile '{}'", name)?,
+            }
         }
         Commands::DeleteProfile { name } => {
             profile_service
                 .delete_profile(&ProfileName::from(name.as_str()))
-                .map_err(|e| anyhow::anyhow!("Failed to delete profile: {}", e))?;
-            println!("Successfully deleted profile '{}'", name);
+                .map_err(|e| profile_error(&profile_service, "Failed to delete profile", e))?;
+            writeln!(out, "Successfully deleted profile '{}'", name)?;
+        }
+        Commands::ClearProfile { name, scope } => {
+            let summary = profile_service
+                .clear_profile(&ProfileName::from(name.as_str()), scope.into())
+                .map_err(|e| profile_error(&profile_service, "Failed to clear profile", e))?;
+            writeln!(
+                out,
+                "Cleared profile '{}': removed {} repositories, {} projects, {} branch groups",
+                name,
+                summary.repositories_removed,
+                summary.projects_removed,
+                summary.groups_removed
+            )?;
+        }
+        Commands::ValidateProfile { profile, prune } => {
+            let github_client = batch_github_client(
+                &github_token,
+                cli.request_timeout.map(Duration::from_secs),
+                cli.max_concurrency,
+            )?;
+            let report = functions::profile::validate_profile(&github_client, profile)
+                .await
+                .map_err(|e| anyhow::anyhow!(e))?;
+            writeln!(out, "{}", profile_validation_report_markdown(&report).0)?;
+
+            if prune {
+                let removed = functions::profile::prune_profile(&report)
+                    .await
+                    .map_err(|e| anyhow::anyhow!(e))?;
+                writeln!(
+                    out,
+                    "Unregistered {} not-found entr{} from profile '{}'",
+                    removed,
+                    if removed == 1 { "y" } else { "ies" },
+                    report.profile_name
+                )?;
+            }
+        }
+        Commands::SetProfileDefaults {
+            profile,
+            query,
+            limit,
+        } => {
+            let profile_name = ProfileName::from(profile.as_str());
+            profile_service
+                .set_profile_defaults(&profile_name, query, limit)
+                .map_err(|e| {
+                    profile_error(&profile_service, "Failed to set profile defaults", e)
+                })?;
+            let profile_info = profile_service
+                .get_profile_info(&profile_name)
+                .map_err(|e| profile_error(&profile_service, "Failed to get profile info", e))?;
+            writeln!(out, "Search defaults of profile '{}':", profile)?;
+            writeln!(
+                out,
+                "  query: {}",
+                profile_info
+                    .default_search_query
+                    .as_deref()
+                    .unwrap_or("(not set)")
+            )?;
+            writeln!(
+                out,
+                "  limit: {}",
+                profile_info
+                    .default_search_limit
+                    .map(|limit| limit.to_string())
+                    .unwrap_or_else(|| "(not set)".to_string())
+            )?;
         }
         Commands::RegisterGroup {
             pairs,
@@ -440,14 +1064,15 @@ async fn main() -> Result<()> {
                     parsed_pairs,
                     description,
                 )
-                .map_err(|e| anyhow::anyhow!("Failed to register group: {}", e))?;
+                .map_err(|e| profile_error(&profile_service, "Failed to register group", e))?;
 
-            println!(
+            writeln!(
+                out,
                 "Successfully registered group '{}' to profile '{}' with {} branches",
                 final_group_name,
                 profile,
                 pairs.len()
-            );
+            )?;
         }
         Commands::UnregisterGroup {
             group_name,
@@ -458,14 +1083,15 @@ async fn main() -> Result<()> {
                     &ProfileName::from(profile.as_str()),
                     &GroupName::from(group_name.as_str()),
                 )
-                .map_err(|e| anyhow::anyhow!("Failed to unregister group: {}", e))?;
+                .map_err(|e| profile_error(&profile_service, "Failed to unregister group", e))?;
 
-            println!(
+            writeln!(
+                out,
                 "Successfully unregistered group '{}' from profile '{}' (removed {} branches)",
                 group_name,
                 profile,
                 removed_group.pairs.len()
-            );
+            )?;
         }
         Commands::AddBranchToBranchGroup {
             group_name,
@@ -482,15 +1108,18 @@ async fn main() -> Result<()> {
                         &GroupName::from(group_name.as_str()),
                         branch_specifier,
                     )
-                    .map_err(|e| anyhow::anyhow!("Failed to add branch to group: {}", e))?;
+                    .map_err(|e| {
+                        profile_error(&profile_service, "Failed to add branch to group", e)
+                    })?;
             }
 
-            println!(
+            writeln!(
+                out,
                 "Successfully added {} branches to group '{}' in profile '{}'",
                 branch_specifiers.len(),
                 group_name,
                 profile
-            );
+            )?;
         }
         Commands::RemoveBranchFromBranchGroup {
             group_name,
@@ -507,15 +1136,18 @@ async fn main() -> Result<()> {
                         &GroupName::from(group_name.as_str()),
                         branch_specifier,
                     )
-                    .map_err(|e| anyhow::anyhow!("Failed to remove branch from group: {}", e))?;
+                    .map_err(|e| {
+                        profile_error(&profile_service, "Failed to remove branch from group", e)
+                    })?;
             }
 
-            println!(
+            writeln!(
+                out,
                 "Successfully removed {} branches from group '{}' in profile '{}'",
                 branch_specifiers.len(),
                 group_name,
                 profile
-            );
+            )?;
         }
         Commands::RenameGroup {
             old_name,
@@ -528,24 +1160,47 @@ async fn main() -> Result<()> {
                     &GroupName::from(old_name.as_str()),
                     GroupName::from(new_name.as_str()),
                 )
-                .map_err(|e| anyhow::anyhow!("Failed to rename group: {}", e))?;
+                .map_err(|e| profile_error(&profile_service, "Failed to rename group", e))?;
 
-            println!(
+            writeln!(
+                out,
                 "Successfully renamed group '{}' to '{}' in profile '{}'",
                 old_name, new_name, profile
-            );
+            )?;
+        }
+        Commands::CloneGroup {
+            source_name,
+            destination_name,
+            profile,
+        } => {
+            let group = profile_service
+                .clone_repository_branch_group(
+                    &ProfileName::from(profile.as_str()),
+                    &GroupName::from(source_name.as_str()),
+                    GroupName::from(destination_name.as_str()),
+                )
+                .map_err(|e| profile_error(&profile_service, "Failed to clone group", e))?;
+
+            writeln!(
+                out,
+                "Successfully cloned group '{}' to '{}' ({} branches) in profile '{}'",
+                source_name,
+                group.name,
+                group.pair_count(),
+                profile
+            )?;
         }
         Commands::ListBranchGroups { profile } => {
             let group_names = profile_service
                 .list_repository_branch_groups(&ProfileName::from(profile.as_str()))
-                .map_err(|e| anyhow::anyhow!("Failed to list groups: {}", e))?;
+                .map_err(|e| profile_error(&profile_service, "Failed to list groups", e))?;
 
             match cli.format {
                 OutputFormat::Json => {
                     let json_output = serde_json::to_string_pretty(&group_names)?;
-                    println!("{}", json_output);
+                    writeln!(out, "{}", json_output)?;
                 }
-                OutputFormat::Markdown => {
+                _ => {
                     // Get full group details for description display
                     let mut groups = Vec::new();
                     for group_name in group_names {
@@ -554,12 +1209,14 @@ async fn main() -> Result<()> {
                                 &ProfileName::from(profile.as_str()),
                                 &group_name,
                             )
-                            .map_err(|e| anyhow::anyhow!("Failed to get group details: {}", e))?;
+                            .map_err(|e| {
+                                profile_error(&profile_service, "Failed to get group details", e)
+                            })?;
                         groups.push(group);
                     }
                     let formatted =
                         repository_branch_group_list_with_descriptions_markdown(&groups, &profile);
-                    println!("{}", formatted.0);
+                    writeln!(out, "{}", formatted.0)?;
                 }
             }
         }
@@ -572,39 +1229,46 @@ async fn main() -> Result<()> {
                     &ProfileName::from(profile.as_str()),
                     &GroupName::from(group_name.as_str()),
                 )
-                .map_err(|e| anyhow::anyhow!("Failed to get group: {}", e))?;
+                .map_err(|e| profile_error(&profile_service, "Failed to get group", e))?;
 
             match cli.format {
                 OutputFormat::Json => {
                     let json_output = serde_json::to_string_pretty(&group)?;
-                    println!("{}", json_output);
+                    writeln!(out, "{}", json_output)?;
                 }
-                OutputFormat::Markdown => {
+                _ => {
                     let formatted =
                         repository_branch_group_markdown_with_timezone(&group, timezone.as_ref());
-                    println!("{}", formatted.0);
+                    writeln!(out, "{}", formatted.0)?;
                 }
             }
         }
-        Commands::CleanupGroups { days, profile } => {
+        Commands::CleanupGroups {
+            days,
+            profile,
+            dry_run,
+        } => {
             let removed_groups = profile_service
-                .remove_groups_older_than(&ProfileName::from(profile.as_str()), days)
-                .map_err(|e| anyhow::anyhow!("Failed to cleanup groups: {}", e))?;
+                .remove_groups_older_than(&ProfileName::from(profile.as_str()), days, dry_run)
+                .map_err(|e| profile_error(&profile_service, "Failed to cleanup groups", e))?;
 
             if removed_groups.is_empty() {
-                println!(
+                writeln!(
+                    out,
                     "No groups older than {} days found in profile '{}'",
                     days, profile
-                );
+                )?;
             } else {
-                println!(
-                    "Removed {} groups older than {} days from profile '{}':",
+                writeln!(
+                    out,
+                    "{} {} groups older than {} days from profile '{}':",
+                    if dry_run { "Would remove" } else { "Removed" },
                     removed_groups.len(),
                     days,
                     profile
-                );
+                )?;
                 for group_name in &removed_groups {
-                    println!("  - {}", group_name);
+                    writeln!(out, "  - {}", group_name)?;
                 }
             }
         }
@@ -614,43 +1278,69 @@ async fn main() -> Result<()> {
             repository_url,
             limit,
             output,
+            sort,
+            since,
+            until,
+            state,
+            watch,
         } => {
-            handle_search_command(SearchParams {
-                query: &query,
-                profile: &profile,
-                repository_url: &repository_url,
-                limit,
-                format: &cli.format,
-                output_option: &output.into(),
-                github_token: &github_token,
-                timezone: &timezone,
-            })
+            handle_search_command(
+                &mut out,
+                SearchParams {
+                    query: query.as_deref(),
+                    profile: &profile,
+                    repository_url: &repository_url,
+                    limit,
+                    sort: sort.map(Into::into),
+                    since: since.as_deref(),
+                    until: until.as_deref(),
+                    state: state.into(),
+                    format: &cli.format,
+                    output_option: &output.into(),
+                    output_file: cli.output_file.as_deref(),
+                    github_token: &github_token,
+                    timezone: &timezone,
+                    render_options: &render_options,
+                    watch: watch.map(Duration::from_secs),
+                    fields: &cli.fields,
+                },
+            )
             .await?;
         }
         Commands::GetProjectResources {
             project_url,
             profile,
             output,
+            deduplicate,
+            field_filters,
         } => {
             handle_get_project_resources_command(
+                &mut out,
                 &project_url,
                 &profile,
                 &cli.format,
                 &output.into(),
+                deduplicate.into(),
+                &field_filters,
                 &github_token,
                 &timezone,
                 &mut profile_service,
+                cli.max_concurrency,
             )
             .await?;
         }
         Commands::GetIssues { urls } => {
             let issue_urls: Vec<IssueUrl> = urls.iter().map(|url| IssueUrl(url.clone())).collect();
             handle_get_issues_command(
+                &mut out,
                 issue_urls,
                 &cli.format,
+                &cli.fields,
                 &github_token,
                 &timezone,
+                &render_options,
                 cli.request_timeout.map(Duration::from_secs),
+                cli.max_concurrency,
             )
             .await?;
         }
@@ -658,6 +1348,36 @@ async fn main() -> Result<()> {
             let pull_request_urls: Vec<PullRequestUrl> =
                 urls.iter().map(|url| PullRequestUrl(url.clone())).collect();
             handle_get_pull_requests_command(
+                &mut out,
+                pull_request_urls,
+                &cli.format,
+                &cli.fields,
+                &github_token,
+                &timezone,
+                &render_options,
+                cli.request_timeout.map(Duration::from_secs),
+                cli.max_concurrency,
+            )
+            .await?;
+        }
+        Commands::GetPullRequestReviews { urls } => {
+            let pull_request_urls: Vec<PullRequestUrl> =
+                urls.iter().map(|url| PullRequestUrl(url.clone())).collect();
+            handle_get_pull_request_reviews_command(
+                &mut out,
+                pull_request_urls,
+                &cli.format,
+                &github_token,
+                &timezone,
+                cli.request_timeout.map(Duration::from_secs),
+            )
+            .await?;
+        }
+        Commands::GetPullRequestCommits { urls } => {
+            let pull_request_urls: Vec<PullRequestUrl> =
+                urls.iter().map(|url| PullRequestUrl(url.clone())).collect();
+            handle_get_pull_request_commits_command(
+                &mut out,
                 pull_request_urls,
                 &cli.format,
                 &github_token,
@@ -670,10 +1390,12 @@ async fn main() -> Result<()> {
             let pull_request_urls: Vec<PullRequestUrl> =
                 urls.iter().map(|url| PullRequestUrl(url.clone())).collect();
             handle_get_pull_request_diffs_command(
+                &mut out,
                 pull_request_urls,
                 &cli.format,
                 &github_token,
                 cli.request_timeout.map(Duration::from_secs),
+                cli.max_concurrency,
             )
             .await?;
         }
@@ -681,10 +1403,13 @@ async fn main() -> Result<()> {
             let pull_request_urls: Vec<PullRequestUrl> =
                 urls.iter().map(|url| PullRequestUrl(url.clone())).collect();
             handle_get_pull_request_diff_stats_command(
+                &mut out,
                 pull_request_urls,
                 &cli.format,
+                &render_options,
                 &github_token,
                 cli.request_timeout.map(Duration::from_secs),
+                cli.max_concurrency,
             )
             .await?;
         }
@@ -693,13 +1418,18 @@ async fn main() -> Result<()> {
             file_path,
             skip,
             limit,
+            max_lines,
+            hunk_index,
         } => {
             let pr_url = PullRequestUrl(pull_request_url);
             handle_get_pull_request_diff_contents_command(
+                &mut out,
                 pr_url,
                 file_path,
                 skip,
                 limit,
+                max_lines,
+                hunk_index,
                 &cli.format,
                 &github_token,
                 cli.request_timeout.map(Duration::from_secs),
@@ -710,17 +1440,47 @@ async fn main() -> Result<()> {
             urls,
             showing_release_limit,
             showing_milestone_limit,
+            show_label_colors,
         } => {
             let repository_urls: Vec<RepositoryUrl> =
                 urls.iter().map(|url| RepositoryUrl(url.clone())).collect();
             handle_get_repositories_command(
+                &mut out,
                 repository_urls,
                 &cli.format,
+                &cli.fields,
                 &github_token,
                 &timezone,
                 cli.request_timeout.map(Duration::from_secs),
+                cli.max_concurrency,
                 showing_release_limit,
                 showing_milestone_limit,
+                show_label_colors,
+            )
+            .await?;
+        }
+        Commands::GetRepositoryContributors { url, limit } => {
+            handle_get_repository_contributors_command(
+                &mut out,
+                RepositoryUrl(url),
+                limit,
+                &cli.format,
+                &render_options,
+                &github_token,
+                cli.request_timeout.map(Duration::from_secs),
+            )
+            .await?;
+        }
+        Commands::GetRepositoryStats { urls } => {
+            handle_get_repository_stats_command(
+                &mut out,
+                urls.into_iter().map(RepositoryUrl).collect(),
+                &cli.format,
+                &github_token,
+                &timezone,
+                &render_options,
+                cli.request_timeout.map(Duration::from_secs),
+                cli.max_concurrency,
             )
             .await?;
         }
@@ -728,44 +1488,75 @@ async fn main() -> Result<()> {
             let project_urls: Vec<ProjectUrl> =
                 urls.iter().map(|url| ProjectUrl(url.clone())).collect();
             handle_get_projects_command(
+                &mut out,
                 project_urls,
                 &cli.format,
                 &github_token,
                 &timezone,
                 cli.request_timeout.map(Duration::from_secs),
+                cli.max_concurrency,
+            )
+            .await?;
+        }
+        Commands::GetRateLimitStatus => {
+            handle_get_rate_limit_status_command(
+                &mut out,
+                &cli.format,
+                &github_token,
+                &timezone,
+                cli.request_timeout.map(Duration::from_secs),
             )
             .await?;
         }
+        Commands::AuthCheck => {
+            handle_auth_check_command(
+                &mut out,
+                &cli.format,
+                &github_token,
+                cli.request_timeout.map(Duration::from_secs),
+            )
+            .await?;
+        }
+        Commands::Schema { command } => {
+            let json_output = serde_json::to_string_pretty(&command.output_schema())?;
+            writeln!(out, "{}", json_output)?;
+        }
     }
 
-    Ok(())
-}
+    // Streamed results were already written; only notices are left in `out`
+    if streams_output {
+        eprint!("{}", out);
+        return Ok(());
+    }
 
-/// Parse repository URL into RepositoryId
-fn parse_repository_url(url: &str) -> Result<RepositoryId> {
-    // Simple URL parsing for GitHub URLs
-    // This is a basic implementation - the actual parsing should be in the domain type
-    if let Some(captures) = regex::Regex::new(r"https://github\.com/([^/]+)/([^/]+?)(?:\.git)?/?$")
-        .unwrap()
-        .captures(url)
-    {
-        let owner = captures.get(1).unwrap().as_str();
-        let repo_name = captures.get(2).unwrap().as_str();
+    write_output(&out, cli.output_file.as_deref())
+}
 
-        Ok(RepositoryId {
-            owner: Owner::from(owner),
-            repository_name: RepositoryName::from(repo_name),
-        })
-    } else {
-        Err(anyhow::anyhow!("Invalid repository URL format: {}", url))
+/// Write command results to the --output-file path, or to stdout when none is given
+///
+/// The file is created or truncated and a short confirmation is printed to stderr, so
+/// stdout stays empty when writing to a file.
+fn write_output(output: &str, output_file: Option<&Path>) -> Result<()> {
+    match output_file {
+        Some(path) => {
+            std::fs::write(path, output)
+                .with_context(|| format!("Failed to write output to {}", path.display()))?;
+            eprintln!("Wrote {} bytes to {}", output.len(), path.display());
+        }
+        None => {
+            let mut stdout = std::io::stdout().lock();
+            stdout.write_all(output.as_bytes())?;
+            stdout.flush()?;
+        }
     }
+    Ok(())
 }
 
 /// Parse project URL into ProjectId
 fn parse_project_url(url: &str) -> Result<ProjectId> {
     let project_url = ProjectUrl(url.to_string());
     let (owner, number, project_type) = ProjectId::parse_url(&project_url)
-        .map_err(|e| anyhow::anyhow!("Failed to parse project URL: {}", e))?;
+        .map_err(|e| CliError::InvalidUrl(format!("Failed to parse project URL: {}", e)))?;
 
     Ok(ProjectId::new(
         Owner::from(owner),
@@ -776,105 +1567,539 @@ fn parse_project_url(url: &str) -> Result<ProjectId> {
 
 /// Search command parameters
 struct SearchParams<'a> {
-    query: &'a str,
+    query: Option<&'a str>,
     profile: &'a str,
     repository_url: &'a Option<String>,
-    limit: usize,
+    limit: Option<usize>,
+    sort: Option<SearchSort>,
+    since: Option<&'a str>,
+    until: Option<&'a str>,
+    state: ResourceState,
     format: &'a OutputFormat,
     output_option: &'a OutputOption,
+    output_file: Option<&'a Path>,
     github_token: &'a Option<String>,
     timezone: &'a Option<TimezoneOffset>,
+    render_options: &'a RenderOptions,
+    watch: Option<Duration>,
+    fields: &'a [String],
 }
 
-/// Handle search command
-async fn handle_search_command(params: SearchParams<'_>) -> Result<()> {
-    let github_client = GitHubClient::new(params.github_token.clone(), None)
-        .map_err(|e| anyhow::anyhow!("Failed to create GitHub client: {}", e))?;
-
-    // Get profile service to load repositories
-    let config_dir = default_profile_config_dir()
-        .map_err(|e| anyhow::anyhow!("Failed to get config directory: {}", e))?;
-    let profile_service = ProfileService::new(config_dir)
-        .map_err(|e| anyhow::anyhow!("Failed to initialize profile service: {}", e))?;
+/// Append --since/--until bounds as `created:` qualifiers, resolving relative dates in the timezone
+///
+/// A `created:` qualifier already in the query takes precedence over the flags.
+fn apply_created_date_range(
+    query: SearchQuery,
+    since: Option<&str>,
+    until: Option<&str>,
+    timezone: &Option<TimezoneOffset>,
+) -> Result<SearchQuery> {
+    if since.is_none() && until.is_none() {
+        return Ok(query);
+    }
+    if query.has_qualifier("created") {
+        eprintln!(
+            "Warning: the query already contains a created: qualifier; ignoring --since/--until"
+        );
+        return Ok(query);
+    }
 
-    let repositories = if let Some(repo_str) = params.repository_url {
-        // Parse single repository
-        let repo_id = parse_repository_url(repo_str)?;
-        vec![repo_id]
-    } else {
-        // Get all repositories from profile
-        profile_service
-            .list_repositories(&ProfileName::from(params.profile))
-            .map_err(|e| anyhow::anyhow!("Failed to list repositories: {}", e))?
+    let offset = timezone
+        .as_ref()
+        .map(|tz| tz.to_fixed_offset())
+        .unwrap_or_else(|| chrono::FixedOffset::east_opt(0).unwrap());
+    let today = chrono::Utc::now().with_timezone(&offset).date_naive();
+
+    let parse = |input: Option<&str>| {
+        input
+            .map(|date| parse_search_date(date, today))
+            .transpose()
+            .map_err(|e| anyhow::anyhow!(e))
     };
+    Ok(query.with_created_range(parse(since)?, parse(until)?))
+}
 
-    if repositories.is_empty() {
-        println!("No repositories found. Please register repositories first.");
-        return Ok(());
-    }
+/// Repositories searched at once when streaming JSONL results
+const JSONL_SEARCH_CONCURRENCY: usize = 10;
 
-    // Search for resources
-    let search_query = SearchQuery::new(params.query.to_string());
-    let search_result = functions::search::search_resources(
-        &github_client,
+/// A search result as one JSONL record, tagged with `"type": "issue"` or `"type": "pull_request"`
+#[derive(serde::Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum JsonlSearchResult<'a> {
+    Issue(&'a Issue),
+    PullRequest(&'a PullRequest),
+}
+
+impl<'a> From<&'a IssueOrPullrequest> for JsonlSearchResult<'a> {
+    fn from(result: &'a IssueOrPullrequest) -> Self {
+        match result {
+            IssueOrPullrequest::Issue(issue) => JsonlSearchResult::Issue(issue),
+            IssueOrPullrequest::PullRequest(pull_request) => {
+                JsonlSearchResult::PullRequest(pull_request)
+            }
+        }
+    }
+}
+
+/// Serialize `value` as pretty JSON, keeping only the `--fields` keys of each record
+///
+/// Records are the elements of the top-level array, or with `grouped` the elements of
+/// the arrays keyed by repository. Search results tagged as `{"Issue": {..}}` are
+/// projected inside the tag. Requested fields that no record has are reported on stderr.
+fn json_with_fields<T: serde::Serialize>(
+    value: &T,
+    fields: &[String],
+    grouped: bool,
+) -> Result<String> {
+    let mut json = serde_json::to_value(value)?;
+    if !fields.is_empty() {
+        let mut projection = FieldProjection::new(fields);
+        match &mut json {
+            serde_json::Value::Object(groups) if grouped => {
+                for records in groups.values_mut() {
+                    projection.apply(records);
+                }
+            }
+            json => projection.apply(json),
+        }
+        let unknown = projection.unknown_fields();
+        if !unknown.is_empty() {
+            eprintln!("Warning: ignoring unknown --fields: {}", unknown.join(", "));
+        }
+    }
+    Ok(serde_json::to_string_pretty(&json)?)
+}
+
+/// Projection of JSON records onto the fields requested with `--fields`
+struct FieldProjection<'a> {
+    fields: &'a [String],
+    matched: HashSet<&'a str>,
+    records: usize,
+}
+
+impl<'a> FieldProjection<'a> {
+    fn new(fields: &'a [String]) -> Self {
+        Self {
+            fields,
+            matched: HashSet::new(),
+            records: 0,
+        }
+    }
+
+    /// Keep only the requested keys of the record, or of each record in an array
+    fn apply(&mut self, value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Array(records) => {
+                for record in records {
+                    self.apply(record);
+                }
+            }
+            serde_json::Value::Object(record) => {
+                if record.len() == 1 {
+                    if let Some(tagged) = record
+                        .iter_mut()
+                        .find(|(tag, _)| matches!(tag.as_str(), "Issue" | "PullRequest"))
+                        .map(|(_, tagged)| tagged)
+                    {
+                        self.apply(tagged);
+                        return;
+                    }
+                }
+                self.records += 1;
+                let fields = self.fields;
+                record.retain(|key, _| match fields.iter().find(|field| *field == key) {
+                    Some(field) => {
+                        self.matched.insert(field.as_str());
+                        true
+                    }
+                    None => false,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    /// Requested fields that none of the projected records had
+    ///
+    /// Nothing is reported when there were no records to project.
+    fn unknown_fields(&self) -> Vec<&'a str> {
+        if self.records == 0 {
+            return Vec::new();
+        }
+        self.fields
+            .iter()
+            .map(String::as_str)
+            .filter(|field| !self.matched.contains(field))
+            .collect()
+    }
+}
+
+/// Serialize a search result as a single line of JSON
+fn jsonl_line(result: &IssueOrPullrequest) -> Result<String> {
+    Ok(serde_json::to_string(&JsonlSearchResult::from(result))?)
+}
+
+/// Search each repository separately and write its results as JSON lines as soon as
+/// that repository's search completes, instead of collecting every result first
+async fn stream_search_results_jsonl(
+    github_client: &GitHubClient,
+    repositories: Vec<RepositoryId>,
+    query: SearchQuery,
+    limit: usize,
+    sort: Option<SearchSort>,
+    output_file: Option<&Path>,
+) -> Result<()> {
+    use futures::stream::{self, StreamExt};
+
+    let mut writer: Box<dyn std::io::Write> = match output_file {
+        Some(path) => Box::new(std::io::BufWriter::new(
+            std::fs::File::create(path)
+                .with_context(|| format!("Failed to create output file {}", path.display()))?,
+        )),
+        None => Box::new(std::io::stdout()),
+    };
+
+    let mut searches = stream::iter(repositories.into_iter().map(|repository_id| {
+        let query = query.clone();
+        async move {
+            functions::search::search_resources(
+                github_client,
+                vec![repository_id],
+                query,
+                Some(limit as u32),
+                None,
+                sort,
+            )
+            .await
+        }
+    }))
+    .buffer_unordered(JSONL_SEARCH_CONCURRENCY);
+
+    let mut written = 0;
+    while let Some(search_result) = searches.next().await {
+        for result in &search_result?.results {
+            writeln!(writer, "{}", jsonl_line(result)?)?;
+            written += 1;
+        }
+        writer.flush()?;
+    }
+
+    if let Some(path) = output_file {
+        eprintln!("Wrote {} results to {}", written, path.display());
+    }
+    Ok(())
+}
+
+/// Format a search result as light or rich markdown; the table option falls back to light
+fn search_result_markdown(
+    result: &IssueOrPullrequest,
+    output_option: &OutputOption,
+    timezone: Option<&TimezoneOffset>,
+    render_options: &RenderOptions,
+) -> String {
+    match result {
+        IssueOrPullrequest::Issue(issue) => match output_option {
+            OutputOption::Light | OutputOption::Table => {
+                issue_body_markdown_light_with_body_truncation(
+                    issue,
+                    timezone,
+                    DEFAULT_LIGHT_BODY_LENGTH,
+                    render_options,
+                )
+                .0
+            }
+            OutputOption::Rich => {
+                issue_body_markdown_with_comment_range(
+                    issue,
+                    timezone,
+                    None,
+                    None,
+                    false,
+                    render_options,
+                )
+                .0
+            }
+        },
+        IssueOrPullrequest::PullRequest(pr) => match output_option {
+            OutputOption::Light | OutputOption::Table => {
+                pull_request_body_markdown_light_with_body_truncation(
+                    pr,
+                    timezone,
+                    DEFAULT_LIGHT_BODY_LENGTH,
+                    render_options,
+                )
+                .0
+            }
+            OutputOption::Rich => {
+                pull_request_body_markdown_with_comment_filter(pr, timezone, false, render_options)
+                    .0
+            }
+        },
+    }
+}
+
+/// Shortest interval between two polls of `search --watch`
+const MIN_WATCH_INTERVAL_SECS: u64 = 10;
+
+/// Search results already printed by `search --watch`, keyed by repository and number
+///
+/// Issues and pull requests share one number sequence per repository, so the pair
+/// identifies a result of either kind.
+#[derive(Debug, Default)]
+struct SeenSearchResults {
+    seen: HashSet<(RepositoryId, u32)>,
+}
+
+impl SeenSearchResults {
+    /// Keep only the results not seen before, in their original order, and remember them
+    fn retain_new(&mut self, results: Vec<IssueOrPullrequest>) -> Vec<IssueOrPullrequest> {
+        results
+            .into_iter()
+            .filter(|result| self.seen.insert(search_result_key(result)))
+            .collect()
+    }
+
+    fn len(&self) -> usize {
+        self.seen.len()
+    }
+}
+
+/// Repository and number of a search result
+fn search_result_key(result: &IssueOrPullrequest) -> (RepositoryId, u32) {
+    match result {
+        IssueOrPullrequest::Issue(issue) => {
+            (issue.issue_id.git_repository.clone(), issue.issue_id.number)
+        }
+        IssueOrPullrequest::PullRequest(pr) => (
+            pr.pull_request_id.git_repository.clone(),
+            pr.pull_request_id.number,
+        ),
+    }
+}
+
+/// Check the output format and interval of `search --watch`
+///
+/// Results are streamed as they appear, so only formats that can be written one result
+/// at a time are accepted. A JSON array would not be valid until the watch ends, so
+/// `jsonl` is the JSON format for watching.
+fn validate_watch_options(format: &OutputFormat, interval: Duration) -> Result<()> {
+    if !matches!(format, OutputFormat::Markdown | OutputFormat::Jsonl) {
+        return Err(anyhow::anyhow!(
+            "--watch supports the markdown and jsonl formats only"
+        ));
+    }
+    if interval < Duration::from_secs(MIN_WATCH_INTERVAL_SECS) {
+        return Err(anyhow::anyhow!(
+            "--watch interval must be at least {} seconds",
+            MIN_WATCH_INTERVAL_SECS
+        ));
+    }
+    Ok(())
+}
+
+/// Poll a search every `interval` and write results as they first appear, until Ctrl-C
+///
+/// Every poll runs one search per repository through the client's retry and rate
+/// limit backoff. A poll that still fails is reported on stderr and retried on the
+/// next interval instead of ending the watch.
+async fn watch_search_results(
+    github_client: &GitHubClient,
+    repositories: Vec<RepositoryId>,
+    query: SearchQuery,
+    limit: usize,
+    params: &SearchParams<'_>,
+    interval: Duration,
+) -> Result<()> {
+    validate_watch_options(params.format, interval)?;
+
+    // Newest first, so results created since the last poll fall within the limit
+    let sort = params.sort.or(Some(SearchSort::CreatedDesc));
+
+    let mut writer: Box<dyn std::io::Write> = match params.output_file {
+        Some(path) => Box::new(std::io::BufWriter::new(
+            std::fs::File::create(path)
+                .with_context(|| format!("Failed to create output file {}", path.display()))?,
+        )),
+        None => Box::new(std::io::stdout()),
+    };
+
+    eprintln!(
+        "Watching {} repositories every {}s, press Ctrl-C to stop",
+        repositories.len(),
+        interval.as_secs()
+    );
+
+    let mut seen = SeenSearchResults::default();
+    loop {
+        let search = functions::search::search_resources(
+            github_client,
+            repositories.clone(),
+            query.clone(),
+            Some(limit as u32),
+            None,
+            sort,
+        );
+        let search_result = tokio::select! {
+            search_result = search => search_result,
+            _ = tokio::signal::ctrl_c() => break,
+        };
+
+        match search_result {
+            Ok(search_result) => {
+                for result in seen.retain_new(search_result.results) {
+                    match params.format {
+                        OutputFormat::Jsonl => {
+                            writeln!(writer, "{}", jsonl_line(&result)?)?;
+                        }
+                        _ => {
+                            let formatted = search_result_markdown(
+                                &result,
+                                params.output_option,
+                                params.timezone.as_ref(),
+                                params.render_options,
+                            );
+                            writeln!(writer, "{}", formatted)?;
+                            writeln!(writer, "---")?;
+                        }
+                    }
+                }
+                writer.flush()?;
+            }
+            Err(e) => eprintln!("Search failed, retrying in {}s: {}", interval.as_secs(), e),
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(interval) => {}
+            _ = tokio::signal::ctrl_c() => break,
+        }
+    }
+
+    eprintln!("Stopped watching after {} result(s)", seen.len());
+    Ok(())
+}
+
+/// Handle search command
+async fn handle_search_command(out: &mut String, params: SearchParams<'_>) -> Result<()> {
+    let github_client = GitHubClient::new(params.github_token.clone(), None)
+        .context("Failed to create GitHub client")?;
+
+    // Get profile service to load repositories
+    let config_dir = default_profile_config_dir().context("Failed to get config directory")?;
+    let profile_service =
+        ProfileService::new(config_dir).context("Failed to initialize profile service")?;
+
+    let repositories = if let Some(repo_str) = params.repository_url {
+        // Parse single repository
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repo_str.to_string()))
+            .map_err(CliError::InvalidUrl)?;
+        vec![repo_id]
+    } else {
+        // Get all repositories from profile
+        profile_service
+            .list_repositories(&ProfileName::from(params.profile))
+            .map_err(|e| profile_error(&profile_service, "Failed to list repositories", e))?
+    };
+
+    if repositories.is_empty() {
+        writeln!(
+            out,
+            "No repositories found. Please register repositories first."
+        )?;
+        return Ok(());
+    }
+
+    // Omitted query and limit fall back to the profile defaults, then to the built-in ones
+    let profile_info = profile_service
+        .get_profile_info(&ProfileName::from(params.profile))
+        .ok();
+    let (query, limit) = functions::search::apply_profile_search_defaults(
+        profile_info.as_ref(),
+        params.query.map(str::to_string),
+        params.limit,
+    );
+    let query = query.unwrap_or_else(|| DEFAULT_SEARCH_QUERY.to_string());
+    let (limit, limit_note) = functions::search::clamp_search_limit(limit);
+    if let Some(note) = limit_note {
+        eprintln!("{}", note);
+    }
+
+    // Search for resources
+    let search_query = apply_created_date_range(
+        SearchQuery::new(query).with_state(params.state),
+        params.since,
+        params.until,
+        params.timezone,
+    )?;
+    if let Some(interval) = params.watch {
+        return watch_search_results(
+            &github_client,
+            repositories,
+            search_query,
+            limit,
+            &params,
+            interval,
+        )
+        .await;
+    }
+    if matches!(params.format, OutputFormat::Jsonl) {
+        return stream_search_results_jsonl(
+            &github_client,
+            repositories,
+            search_query,
+            limit,
+            params.sort,
+            params.output_file,
+        )
+        .await;
+    }
+    let search_result = functions::search::search_resources(
+        &github_client,
         repositories,
         search_query,
-        Some(params.limit as u32),
+        Some(limit as u32),
         None,
+        params.sort,
     )
     .await?;
 
     // Output results
     match params.format {
         OutputFormat::Json => {
-            let json_output = serde_json::to_string_pretty(&search_result.results)?;
-            println!("{}", json_output);
+            let json_output = json_with_fields(&search_result.results, params.fields, false)?;
+            writeln!(out, "{}", json_output)?;
+        }
+        OutputFormat::Csv => {
+            write!(
+                out,
+                "{}",
+                search_results_csv_with_timezone(&search_result.results, params.timezone.as_ref())
+            )?;
         }
-        OutputFormat::Markdown => {
+        OutputFormat::Jsonl => unreachable!("JSONL search results are streamed"),
+        OutputFormat::Markdown | OutputFormat::Html => {
             if search_result.results.is_empty() {
-                println!("No results found.");
+                writeln!(out, "No results found.")?;
+            } else if matches!(params.output_option, OutputOption::Table) {
+                write!(
+                    out,
+                    "{}",
+                    search_results_table_markdown(
+                        &search_result.results,
+                        params.timezone.as_ref(),
+                        params.render_options,
+                    )
+                    .0
+                )?;
             } else {
-                for result in search_result.results {
-                    let formatted = match result {
-                        github_insight::types::IssueOrPullrequest::Issue(issue) => {
-                            match params.output_option {
-                                OutputOption::Light => {
-                                    issue_body_markdown_with_timezone_light(
-                                        &issue,
-                                        params.timezone.as_ref(),
-                                    )
-                                    .0
-                                }
-                                OutputOption::Rich => {
-                                    issue_body_markdown_with_timezone(
-                                        &issue,
-                                        params.timezone.as_ref(),
-                                    )
-                                    .0
-                                }
-                            }
-                        }
-                        github_insight::types::IssueOrPullrequest::PullRequest(pr) => {
-                            match params.output_option {
-                                OutputOption::Light => {
-                                    pull_request_body_markdown_with_timezone_light(
-                                        &pr,
-                                        params.timezone.as_ref(),
-                                    )
-                                    .0
-                                }
-                                OutputOption::Rich => {
-                                    pull_request_body_markdown_with_timezone(
-                                        &pr,
-                                        params.timezone.as_ref(),
-                                    )
-                                    .0
-                                }
-                            }
-                        }
-                    };
-                    println!("{}", formatted);
-                    println!("---");
+                for result in &search_result.results {
+                    let formatted = search_result_markdown(
+                        result,
+                        params.output_option,
+                        params.timezone.as_ref(),
+                        params.render_options,
+                    );
+                    writeln!(out, "{}", formatted)?;
+                    writeln!(out, "---")?;
                 }
             }
         }
@@ -884,63 +2109,90 @@ async fn handle_search_command(params: SearchParams<'_>) -> Result<()> {
 }
 
 /// Handle get project resources command
+#[allow(clippy::too_many_arguments)]
 async fn handle_get_project_resources_command(
+    out: &mut String,
     project_url: &Option<String>,
     profile: &str,
     format: &OutputFormat,
     output_option: &OutputOption,
+    deduplication: ProjectResourceDeduplication,
+    field_filters: &[(String, String)],
     github_token: &Option<String>,
     timezone: &Option<TimezoneOffset>,
     profile_service: &mut ProfileService,
+    max_concurrency: Option<usize>,
 ) -> Result<()> {
-    let github_client = GitHubClient::new(github_token.clone(), None)
-        .map_err(|e| anyhow::anyhow!("Failed to create GitHub client: {}", e))?;
+    let github_client = batch_github_client(github_token, None, max_concurrency)?;
 
-    let project_resources = if let Some(project_url_str) = project_url {
+    let DeduplicatedProjectResources {
+        resources: project_resources,
+        duplicates_collapsed,
+    } = if let Some(project_url_str) = project_url {
         // Get resources for specific project
+        parse_project_url(project_url_str)?;
         let project_url = ProjectUrl(project_url_str.clone());
-        functions::project::get_project_resources(&github_client, project_url)
-            .await
-            .map_err(|e| anyhow::anyhow!("Failed to get project resources: {}", e))?
+        let resources =
+            functions::project::get_project_resources(&github_client, project_url, field_filters)
+                .await
+                .context("Failed to get project resources")?;
+        deduplication.apply(resources)
     } else {
         // Get resources for all projects in profile
         let project_ids = profile_service
             .list_projects(&ProfileName::from(profile))
-            .map_err(|e| anyhow::anyhow!("Failed to list projects: {}", e))?;
+            .map_err(|e| profile_error(profile_service, "Failed to list projects", e))?;
 
         if project_ids.is_empty() {
-            println!("No projects found in profile '{}'", profile);
+            writeln!(out, "No projects found in profile '{}'", profile)?;
             return Ok(());
         }
 
-        functions::project::get_multiple_project_resources(&github_client, project_ids)
-            .await
-            .map_err(|e| anyhow::anyhow!("Failed to get project resources: {}", e))?
+        functions::project::get_multiple_project_resources(
+            &github_client,
+            project_ids,
+            deduplication,
+            field_filters,
+            ProjectFetchLimits::default(),
+            &[],
+        )
+        .await
+        .map(|(resources, _)| resources)
+        .context("Failed to get project resources")?
     };
 
+    if duplicates_collapsed > 0 {
+        eprintln!(
+            "{} duplicate project items collapsed ({})",
+            duplicates_collapsed, deduplication
+        );
+    }
+
     // Output results
     match format {
         OutputFormat::Json => {
             let json_output = serde_json::to_string_pretty(&project_resources)?;
-            println!("{}", json_output);
+            writeln!(out, "{}", json_output)?;
         }
-        OutputFormat::Markdown => {
+        _ => {
             if project_resources.is_empty() {
-                println!("No project resources found.");
+                writeln!(out, "No project resources found.")?;
             } else {
                 for resource in project_resources {
                     let formatted = match output_option {
-                        OutputOption::Light => project_resource_body_markdown_with_timezone_light(
-                            &resource,
-                            timezone.as_ref(),
-                        ),
+                        OutputOption::Light | OutputOption::Table => {
+                            project_resource_body_markdown_with_timezone_light(
+                                &resource,
+                                timezone.as_ref(),
+                            )
+                        }
                         OutputOption::Rich => project_resource_body_markdown_with_timezone(
                             &resource,
                             timezone.as_ref(),
                         ),
                     };
-                    println!("{}", formatted.0);
-                    println!("---");
+                    writeln!(out, "{}", formatted.0)?;
+                    writeln!(out, "---")?;
                 }
             }
         }
@@ -949,37 +2201,73 @@ async fn handle_get_project_resources_command(
     Ok(())
 }
 
+/// Create the GitHub client of a batch command, bounded by --max-concurrency when given
+///
+/// The timeout applies to each request, so a lower concurrency makes a batch take
+/// longer overall without making any single request more likely to time out.
+fn batch_github_client(
+    github_token: &Option<String>,
+    request_timeout: Option<Duration>,
+    max_concurrency: Option<usize>,
+) -> Result<GitHubClient> {
+    let github_client = GitHubClient::new(github_token.clone(), request_timeout)
+        .context("Failed to create GitHub client")?;
+    Ok(match max_concurrency {
+        Some(max_concurrency) => github_client.with_max_concurrency(max_concurrency),
+        None => github_client,
+    })
+}
+
 /// Handle get issues command
+#[allow(clippy::too_many_arguments)]
 async fn handle_get_issues_command(
+    out: &mut String,
     issue_urls: Vec<IssueUrl>,
     format: &OutputFormat,
+    fields: &[String],
     github_token: &Option<String>,
     timezone: &Option<TimezoneOffset>,
+    render_options: &RenderOptions,
     request_timeout: Option<Duration>,
+    max_concurrency: Option<usize>,
 ) -> Result<()> {
-    let github_client = GitHubClient::new(github_token.clone(), request_timeout)
-        .map_err(|e| anyhow::anyhow!("Failed to create GitHub client: {}", e))?;
+    let github_client = batch_github_client(github_token, request_timeout, max_concurrency)?;
 
-    let issues_by_repo = functions::issue::get_issues_details(&github_client, issue_urls).await?;
+    let issues_by_repo =
+        functions::issue::get_issues_details(&github_client, issue_urls, None).await?;
 
     // Output results
     match format {
         OutputFormat::Json => {
-            let json_output = serde_json::to_string_pretty(&issues_by_repo)?;
-            println!("{}", json_output);
+            let json_output = json_with_fields(&issues_by_repo, fields, true)?;
+            writeln!(out, "{}", json_output)?;
         }
-        OutputFormat::Markdown => {
+        OutputFormat::Html => {
+            for issue in issues_by_repo.into_values().flatten() {
+                let formatted =
+                    issue_body_html_with_timezone(&issue, timezone.as_ref(), render_options);
+                writeln!(out, "{}", formatted.0)?;
+            }
+        }
+        _ => {
             let mut found_issues = false;
             for (_repo_id, issues) in issues_by_repo {
                 for issue in issues {
-                    let formatted = issue_body_markdown_with_timezone(&issue, timezone.as_ref());
-                    println!("{}", formatted.0);
-                    println!("---");
+                    let formatted = issue_body_markdown_with_comment_range(
+                        &issue,
+                        timezone.as_ref(),
+                        None,
+                        None,
+                        false,
+                        render_options,
+                    );
+                    writeln!(out, "{}", formatted.0)?;
+                    writeln!(out, "---")?;
                     found_issues = true;
                 }
             }
             if !found_issues {
-                println!("No issues found for the provided URLs.");
+                writeln!(out, "No issues found for the provided URLs.")?;
             }
         }
     }
@@ -988,15 +2276,19 @@ async fn handle_get_issues_command(
 }
 
 /// Handle get pull requests command
+#[allow(clippy::too_many_arguments)]
 async fn handle_get_pull_requests_command(
+    out: &mut String,
     pull_request_urls: Vec<PullRequestUrl>,
     format: &OutputFormat,
+    fields: &[String],
     github_token: &Option<String>,
     timezone: &Option<TimezoneOffset>,
+    render_options: &RenderOptions,
     request_timeout: Option<Duration>,
+    max_concurrency: Option<usize>,
 ) -> Result<()> {
-    let github_client = GitHubClient::new(github_token.clone(), request_timeout)
-        .map_err(|e| anyhow::anyhow!("Failed to create GitHub client: {}", e))?;
+    let github_client = batch_github_client(github_token, request_timeout, max_concurrency)?;
 
     let pull_requests_by_repo =
         functions::pull_request::get_pull_requests_details(&github_client, pull_request_urls)
@@ -1005,22 +2297,111 @@ async fn handle_get_pull_requests_command(
     // Output results
     match format {
         OutputFormat::Json => {
-            let json_output = serde_json::to_string_pretty(&pull_requests_by_repo)?;
-            println!("{}", json_output);
+            let json_output = json_with_fields(&pull_requests_by_repo, fields, true)?;
+            writeln!(out, "{}", json_output)?;
         }
-        OutputFormat::Markdown => {
+        OutputFormat::Html => {
+            for pr in pull_requests_by_repo.into_values().flatten() {
+                let formatted =
+                    pull_request_body_html_with_timezone(&pr, timezone.as_ref(), render_options);
+                writeln!(out, "{}", formatted.0)?;
+            }
+        }
+        _ => {
             let mut found_prs = false;
             for (_repo_id, pull_requests) in pull_requests_by_repo {
                 for pr in pull_requests {
-                    let formatted =
-                        pull_request_body_markdown_with_timezone(&pr, timezone.as_ref());
-                    println!("{}", formatted.0);
-                    println!("---");
+                    let formatted = pull_request_body_markdown_with_comment_filter(
+                        &pr,
+                        timezone.as_ref(),
+                        false,
+                        render_options,
+                    );
+                    writeln!(out, "{}", formatted.0)?;
+                    writeln!(out, "---")?;
                     found_prs = true;
                 }
             }
             if !found_prs {
-                println!("No pull requests found for the provided URLs.");
+                writeln!(out, "No pull requests found for the provided URLs.")?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle get pull request reviews command
+async fn handle_get_pull_request_reviews_command(
+    out: &mut String,
+    pull_request_urls: Vec<PullRequestUrl>,
+    format: &OutputFormat,
+    github_token: &Option<String>,
+    timezone: &Option<TimezoneOffset>,
+    request_timeout: Option<Duration>,
+) -> Result<()> {
+    let github_client = GitHubClient::new(github_token.clone(), request_timeout)
+        .context("Failed to create GitHub client")?;
+
+    let reviews =
+        functions::pull_request::get_pull_request_reviews(&github_client, pull_request_urls)
+            .await?;
+
+    // Output results
+    match format {
+        OutputFormat::Json => {
+            let json_output = serde_json::to_string_pretty(&reviews)?;
+            writeln!(out, "{}", json_output)?;
+        }
+        _ => {
+            use github_insight::formatter::pull_request::pull_request_reviews_markdown_with_timezone;
+            for pr_reviews in &reviews {
+                let formatted =
+                    pull_request_reviews_markdown_with_timezone(pr_reviews, timezone.as_ref());
+                writeln!(out, "{}", formatted.0)?;
+                writeln!(out, "---")?;
+            }
+            if reviews.is_empty() {
+                writeln!(out, "No pull request reviews found for the provided URLs.")?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle get pull request commits command
+async fn handle_get_pull_request_commits_command(
+    out: &mut String,
+    pull_request_urls: Vec<PullRequestUrl>,
+    format: &OutputFormat,
+    github_token: &Option<String>,
+    timezone: &Option<TimezoneOffset>,
+    request_timeout: Option<Duration>,
+) -> Result<()> {
+    let github_client = GitHubClient::new(github_token.clone(), request_timeout)
+        .context("Failed to create GitHub client")?;
+
+    let commits =
+        functions::pull_request::get_pull_request_commits(&github_client, pull_request_urls)
+            .await?;
+
+    // Output results
+    match format {
+        OutputFormat::Json => {
+            let json_output = serde_json::to_string_pretty(&commits)?;
+            writeln!(out, "{}", json_output)?;
+        }
+        _ => {
+            use github_insight::formatter::pull_request::pull_request_commits_markdown_with_timezone;
+            for pr_commits in &commits {
+                let formatted =
+                    pull_request_commits_markdown_with_timezone(pr_commits, timezone.as_ref());
+                writeln!(out, "{}", formatted.0)?;
+                writeln!(out, "---")?;
+            }
+            if commits.is_empty() {
+                writeln!(out, "No pull request commits found for the provided URLs.")?;
             }
         }
     }
@@ -1030,13 +2411,14 @@ async fn handle_get_pull_requests_command(
 
 /// Handle get pull request diffs command
 async fn handle_get_pull_request_diffs_command(
+    out: &mut String,
     pull_request_urls: Vec<PullRequestUrl>,
     format: &OutputFormat,
     github_token: &Option<String>,
     request_timeout: Option<Duration>,
+    max_concurrency: Option<usize>,
 ) -> Result<()> {
-    let github_client = GitHubClient::new(github_token.clone(), request_timeout)
-        .map_err(|e| anyhow::anyhow!("Failed to create GitHub client: {}", e))?;
+    let github_client = batch_github_client(github_token, request_timeout, max_concurrency)?;
 
     let diffs_by_repo =
         functions::pull_request::get_pull_request_code_diffs(&github_client, pull_request_urls)
@@ -1046,21 +2428,21 @@ async fn handle_get_pull_request_diffs_command(
     match format {
         OutputFormat::Json => {
             let json_output = serde_json::to_string_pretty(&diffs_by_repo)?;
-            println!("{}", json_output);
+            writeln!(out, "{}", json_output)?;
         }
-        OutputFormat::Markdown => {
+        _ => {
             use github_insight::formatter::pull_request_diff_markdown;
             let mut found_diffs = false;
             for (repo_id, pr_diffs) in diffs_by_repo {
                 for (pr_number, diff) in pr_diffs {
                     let formatted = pull_request_diff_markdown(&repo_id, pr_number, &diff);
-                    println!("{}", formatted.0);
-                    println!("---");
+                    writeln!(out, "{}", formatted.0)?;
+                    writeln!(out, "---")?;
                     found_diffs = true;
                 }
             }
             if !found_diffs {
-                println!("No pull request diffs found for the provided URLs.");
+                writeln!(out, "No pull request diffs found for the provided URLs.")?;
             }
         }
     }
@@ -1070,13 +2452,15 @@ async fn handle_get_pull_request_diffs_command(
 
 /// Handle get pull request diff stats command
 async fn handle_get_pull_request_diff_stats_command(
+    out: &mut String,
     pull_request_urls: Vec<PullRequestUrl>,
     format: &OutputFormat,
+    render_options: &RenderOptions,
     github_token: &Option<String>,
     request_timeout: Option<Duration>,
+    max_concurrency: Option<usize>,
 ) -> Result<()> {
-    let github_client = GitHubClient::new(github_token.clone(), request_timeout)
-        .map_err(|e| anyhow::anyhow!("Failed to create GitHub client: {}", e))?;
+    let github_client = batch_github_client(github_token, request_timeout, max_concurrency)?;
 
     let files_by_repo =
         functions::pull_request::get_pull_request_files_stats(&github_client, pull_request_urls)
@@ -1098,21 +2482,29 @@ async fn handle_get_pull_request_diff_stats_command(
                 }
             }
             let json_output = serde_json::to_string_pretty(&results)?;
-            println!("{}", json_output);
+            writeln!(out, "{}", json_output)?;
         }
-        OutputFormat::Markdown => {
+        _ => {
             use github_insight::formatter::pull_request_file_stats_markdown;
             let mut found_stats = false;
             for (repo_id, pr_files) in files_by_repo {
                 for (pr_number, files) in pr_files {
-                    let formatted = pull_request_file_stats_markdown(&repo_id, pr_number, &files);
-                    println!("{}", formatted.0);
-                    println!("---");
+                    let formatted = pull_request_file_stats_markdown(
+                        &repo_id,
+                        pr_number,
+                        &files,
+                        render_options,
+                    );
+                    writeln!(out, "{}", formatted.0)?;
+                    writeln!(out, "---")?;
                     found_stats = true;
                 }
             }
             if !found_stats {
-                println!("No pull request file statistics found for the provided URLs.");
+                writeln!(
+                    out,
+                    "No pull request file statistics found for the provided URLs."
+                )?;
             }
         }
     }
@@ -1121,17 +2513,21 @@ async fn handle_get_pull_request_diff_stats_command(
 }
 
 /// Handle get pull request diff contents command
+#[allow(clippy::too_many_arguments)]
 async fn handle_get_pull_request_diff_contents_command(
+    out: &mut String,
     pull_request_url: PullRequestUrl,
     file_path: String,
     skip: Option<u32>,
     limit: Option<u32>,
+    max_lines: Option<u32>,
+    hunk_index: Option<u32>,
     format: &OutputFormat,
     github_token: &Option<String>,
     request_timeout: Option<Duration>,
 ) -> Result<()> {
     let github_client = GitHubClient::new(github_token.clone(), request_timeout)
-        .map_err(|e| anyhow::anyhow!("Failed to create GitHub client: {}", e))?;
+        .context("Failed to create GitHub client")?;
 
     let diff_content = functions::pull_request::get_pull_request_diff_contents(
         &github_client,
@@ -1139,6 +2535,8 @@ async fn handle_get_pull_request_diff_contents_command(
         file_path.clone(),
         skip,
         limit,
+        max_lines,
+        hunk_index,
     )
     .await?;
 
@@ -1151,11 +2549,12 @@ async fn handle_get_pull_request_diff_contents_command(
                 "file_path": file_path,
                 "skip": skip,
                 "limit": limit,
+                "hunk_index": hunk_index,
                 "diff_content": diff_content,
             });
-            println!("{}", serde_json::to_string_pretty(&json_output)?);
+            writeln!(out, "{}", serde_json::to_string_pretty(&json_output)?)?;
         }
-        OutputFormat::Markdown => {
+        _ => {
             use github_insight::formatter::pull_request_diff_contents_markdown;
             let formatted = pull_request_diff_contents_markdown(
                 &pull_request_url,
@@ -1164,7 +2563,7 @@ async fn handle_get_pull_request_diff_contents_command(
                 skip,
                 limit,
             );
-            println!("{}", formatted.0);
+            writeln!(out, "{}", formatted.0)?;
         }
     }
 
@@ -1172,17 +2571,21 @@ async fn handle_get_pull_request_diff_contents_command(
 }
 
 /// Handle get repositories command
+#[allow(clippy::too_many_arguments)]
 async fn handle_get_repositories_command(
+    out: &mut String,
     repository_urls: Vec<RepositoryUrl>,
     format: &OutputFormat,
+    fields: &[String],
     github_token: &Option<String>,
     timezone: &Option<TimezoneOffset>,
     request_timeout: Option<Duration>,
+    max_concurrency: Option<usize>,
     showing_release_limit: Option<usize>,
     showing_milestone_limit: Option<usize>,
+    show_label_colors: bool,
 ) -> Result<()> {
-    let github_client = GitHubClient::new(github_token.clone(), request_timeout)
-        .map_err(|e| anyhow::anyhow!("Failed to create GitHub client: {}", e))?;
+    let github_client = batch_github_client(github_token, request_timeout, max_concurrency)?;
 
     let repositories =
         functions::repository::get_multiple_repository_details(&github_client, repository_urls)
@@ -1191,12 +2594,12 @@ async fn handle_get_repositories_command(
     // Output results
     match format {
         OutputFormat::Json => {
-            let json_output = serde_json::to_string_pretty(&repositories)?;
-            println!("{}", json_output);
+            let json_output = json_with_fields(&repositories, fields, false)?;
+            writeln!(out, "{}", json_output)?;
         }
-        OutputFormat::Markdown => {
+        _ => {
             if repositories.is_empty() {
-                println!("No repositories found for the provided URLs.");
+                writeln!(out, "No repositories found for the provided URLs.")?;
             } else {
                 for repo in repositories {
                     let markdown_content = repository_body_markdown_with_timezone(
@@ -1204,8 +2607,9 @@ async fn handle_get_repositories_command(
                         timezone.as_ref(),
                         showing_release_limit,
                         showing_milestone_limit,
+                        show_label_colors,
                     );
-                    println!("{}", markdown_content.0);
+                    writeln!(out, "{}", markdown_content.0)?;
                 }
             }
         }
@@ -1214,36 +2618,110 @@ async fn handle_get_repositories_command(
     Ok(())
 }
 
+/// Handle get repository contributors command
+async fn handle_get_repository_contributors_command(
+    out: &mut String,
+    repository_url: RepositoryUrl,
+    limit: usize,
+    format: &OutputFormat,
+    render_options: &RenderOptions,
+    github_token: &Option<String>,
+    request_timeout: Option<Duration>,
+) -> Result<()> {
+    let github_client = GitHubClient::new(github_token.clone(), request_timeout)
+        .context("Failed to create GitHub client")?;
+
+    let (repository_id, contributors) =
+        functions::repository::get_repository_contributors(&github_client, repository_url, limit)
+            .await
+            .context("Failed to get repository contributors")?;
+
+    match format {
+        OutputFormat::Json => {
+            let json_output = serde_json::to_string_pretty(&contributors)?;
+            writeln!(out, "{}", json_output)?;
+        }
+        _ => {
+            let formatted =
+                repository_contributors_markdown(&repository_id, &contributors, render_options);
+            writeln!(out, "{}", formatted.0)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle get repository stats command
+#[allow(clippy::too_many_arguments)]
+async fn handle_get_repository_stats_command(
+    out: &mut String,
+    repository_urls: Vec<RepositoryUrl>,
+    format: &OutputFormat,
+    github_token: &Option<String>,
+    timezone: &Option<TimezoneOffset>,
+    render_options: &RenderOptions,
+    request_timeout: Option<Duration>,
+    max_concurrency: Option<usize>,
+) -> Result<()> {
+    if repository_urls.is_empty() {
+        return Err(anyhow::anyhow!("At least one repository URL is required"));
+    }
+
+    let github_client = batch_github_client(github_token, request_timeout, max_concurrency)?;
+
+    let stats = functions::repository::get_repository_stats(&github_client, repository_urls)
+        .await
+        .context("Failed to get repository stats")?;
+
+    match format {
+        OutputFormat::Json => {
+            let json_output = serde_json::to_string_pretty(&stats)?;
+            writeln!(out, "{}", json_output)?;
+        }
+        _ => {
+            let formatted =
+                repository_stats_markdown_with_timezone(&stats, timezone.as_ref(), render_options);
+            writeln!(out, "{}", formatted.0)?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Handle get projects command
 async fn handle_get_projects_command(
+    out: &mut String,
     project_urls: Vec<ProjectUrl>,
     format: &OutputFormat,
     github_token: &Option<String>,
     timezone: &Option<TimezoneOffset>,
     request_timeout: Option<Duration>,
+    max_concurrency: Option<usize>,
 ) -> Result<()> {
-    let github_client = GitHubClient::new(github_token.clone(), request_timeout)
-        .map_err(|e| anyhow::anyhow!("Failed to create GitHub client: {}", e))?;
+    for project_url in &project_urls {
+        parse_project_url(&project_url.0)?;
+    }
+    let github_client = batch_github_client(github_token, request_timeout, max_concurrency)?;
 
     let projects = functions::project::get_projects_details(&github_client, project_urls)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to get project details: {}", e))?;
+        .context("Failed to get project details")?;
 
     // Output results
     match format {
         OutputFormat::Json => {
             let json_output = serde_json::to_string_pretty(&projects)?;
-            println!("{}", json_output);
+            writeln!(out, "{}", json_output)?;
         }
-        OutputFormat::Markdown => {
+        _ => {
             if projects.is_empty() {
-                println!("No projects found for the provided URLs.");
+                writeln!(out, "No projects found for the provided URLs.")?;
             } else {
                 for project in projects {
                     let markdown_content =
                         project_body_markdown_with_timezone(&project, timezone.as_ref());
-                    println!("{}", markdown_content.0);
-                    println!("---");
+                    writeln!(out, "{}", markdown_content.0)?;
+                    writeln!(out, "---")?;
                 }
             }
         }
@@ -1251,3 +2729,488 @@ async fn handle_get_projects_command(
 
     Ok(())
 }
+
+/// Handle get rate limit status command
+async fn handle_get_rate_limit_status_command(
+    out: &mut String,
+    format: &OutputFormat,
+    github_token: &Option<String>,
+    timezone: &Option<TimezoneOffset>,
+    request_timeout: Option<Duration>,
+) -> Result<()> {
+    let github_client = GitHubClient::new(github_token.clone(), request_timeout)
+        .context("Failed to create GitHub client")?;
+
+    let status = functions::rate_limit::get_rate_limit_status(&github_client).await?;
+
+    match format {
+        OutputFormat::Json => {
+            let json_output = serde_json::to_string_pretty(&status)?;
+            writeln!(out, "{}", json_output)?;
+        }
+        _ => {
+            let formatted = rate_limit_status_markdown_with_timezone(&status, timezone.as_ref());
+            writeln!(out, "{}", formatted.0)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle auth check command
+///
+/// A missing or rejected token is reported as an `AuthRequired` error, so scripts
+/// can check the token by exit code.
+async fn handle_auth_check_command(
+    out: &mut String,
+    format: &OutputFormat,
+    github_token: &Option<String>,
+    request_timeout: Option<Duration>,
+) -> Result<()> {
+    let github_client = GitHubClient::new(github_token.clone(), request_timeout)
+        .context("Failed to create GitHub client")?;
+
+    let status = functions::auth::check_auth(&github_client).await?;
+
+    match &status {
+        AuthStatus::NoToken => {
+            return Err(CliError::AuthRequired(
+                "Not authenticated: no GitHub token is configured. Pass --github-token or set GITHUB_TOKEN or GITHUB_INSIGHT_GITHUB_TOKEN".to_string(),
+            )
+            .into());
+        }
+        AuthStatus::InvalidToken { message } => {
+            return Err(CliError::AuthRequired(format!(
+                "Invalid token: GitHub rejected the configured token: {}",
+                message
+            ))
+            .into());
+        }
+        AuthStatus::Authenticated { .. } => {}
+    }
+
+    match format {
+        OutputFormat::Json => {
+            let json_output = serde_json::to_string_pretty(&status)?;
+            writeln!(out, "{}", json_output)?;
+        }
+        _ => {
+            writeln!(out, "{}", auth_status_markdown(&status).0)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Maximum edit distance for suggesting an existing profile name
+const PROFILE_SUGGESTION_MAX_DISTANCE: usize = 2;
+
+/// Convert a profile service error into a `CliError`, adding a
+/// "did you mean" hint when a mistyped profile name is close to an existing one
+fn profile_error(
+    profile_service: &ProfileService,
+    context: &str,
+    error: ProfileServiceError,
+) -> anyhow::Error {
+    let suggestion = match &error {
+        ProfileServiceError::ProfileNotFound(name) => {
+            suggest_profile_name(name, &profile_service.list_profiles()).cloned()
+        }
+        _ => None,
+    };
+    let message = match suggestion {
+        Some(suggestion) => format!("{}: {} (did you mean '{}'?)", context, error, suggestion),
+        None => format!("{}: {}", context, error),
+    };
+    CliError::from_profile_service_error(&error, message).into()
+}
+
+/// Closest profile name within `PROFILE_SUGGESTION_MAX_DISTANCE` edits of `name`
+fn suggest_profile_name<'a>(name: &str, profiles: &'a [ProfileName]) -> Option<&'a ProfileName> {
+    profiles
+        .iter()
+        .map(|profile| (levenshtein_distance(name, profile.value()), profile))
+        .filter(|(distance, _)| (1..=PROFILE_SUGGESTION_MAX_DISTANCE).contains(distance))
+        .min_by(|(a_distance, a), (b_distance, b)| {
+            a_distance
+                .cmp(b_distance)
+                .then_with(|| a.value().cmp(b.value()))
+        })
+        .map(|(_, profile)| profile)
+}
+
+/// Levenshtein edit distance between two strings, counted in characters
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b_chars.len()).collect();
+    let mut current = vec![0; b_chars.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b_chars.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use github_insight::services::{DEFAULT_MAX_CONCURRENCY, MultiResourceFetcher};
+
+    fn profiles(names: &[&str]) -> Vec<ProfileName> {
+        names.iter().map(|name| ProfileName::from(*name)).collect()
+    }
+
+    #[test]
+    fn test_resolve_default_profile() {
+        assert_eq!(resolve_default_profile(None), "default");
+        assert_eq!(resolve_default_profile(Some(" ".to_string())), "default");
+        assert_eq!(resolve_default_profile(Some("work".to_string())), "work");
+    }
+
+    #[test]
+    fn test_explicit_profile_flag_wins_over_default() {
+        let cli = Cli::try_parse_from([
+            "github-insight-cli",
+            "register-repo",
+            "https://github.com/owner/repo",
+            "--profile",
+            "explicit",
+        ])
+        .unwrap();
+
+        match cli.command {
+            Commands::RegisterRepo { profile, .. } => assert_eq!(profile, "explicit"),
+            _ => panic!("expected register-repo"),
+        }
+    }
+
+    #[test]
+    fn test_thousands_separator_flag_defaults_off() {
+        let cli = Cli::try_parse_from([
+            "github-insight-cli",
+            "get-repository-stats",
+            "https://github.com/owner/repo",
+        ])
+        .unwrap();
+        assert!(!cli.thousands_separator);
+
+        let cli = Cli::try_parse_from([
+            "github-insight-cli",
+            "get-repository-stats",
+            "https://github.com/owner/repo",
+            "--thousands-separator",
+        ])
+        .unwrap();
+        assert!(cli.thousands_separator);
+    }
+
+    #[test]
+    fn test_watch_and_jsonl_searches_stream_output() {
+        let parse =
+            |args: &[&str]| Cli::try_parse_from(["github-insight-cli"].iter().chain(args)).unwrap();
+
+        // Watching writes the output file itself, so run() must not overwrite it
+        assert!(streams_output(&parse(&[
+            "search",
+            "--watch",
+            "30",
+            "--output-file",
+            "results.md",
+        ])));
+        assert!(streams_output(&parse(&["--format", "jsonl", "search"])));
+        assert!(!streams_output(&parse(&[
+            "search",
+            "--output-file",
+            "results.md"
+        ])));
+        assert!(!streams_output(&parse(&[
+            "get-issues",
+            "https://github.com/owner/repo/issues/1",
+        ])));
+    }
+
+    #[test]
+    fn test_watch_rejects_formats_that_cannot_stream() {
+        let interval = Duration::from_secs(MIN_WATCH_INTERVAL_SECS);
+        assert!(validate_watch_options(&OutputFormat::Markdown, interval).is_ok());
+        assert!(validate_watch_options(&OutputFormat::Jsonl, interval).is_ok());
+        for format in [OutputFormat::Json, OutputFormat::Csv, OutputFormat::Html] {
+            let error = validate_watch_options(&format, interval).unwrap_err();
+            assert!(error.to_string().contains("markdown and jsonl"));
+        }
+
+        let error =
+            validate_watch_options(&OutputFormat::Jsonl, Duration::from_secs(1)).unwrap_err();
+        assert!(error.to_string().contains("at least"));
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrency_flag_is_threaded_into_fetchers() {
+        let cli = Cli::try_parse_from([
+            "github-insight-cli",
+            "get-issues",
+            "https://github.com/owner/repo/issues/1",
+            "--max-concurrency",
+            "3",
+        ])
+        .unwrap();
+        assert_eq!(cli.max_concurrency, Some(3));
+
+        let github_client = batch_github_client(&None, None, cli.max_concurrency).unwrap();
+        assert_eq!(
+            MultiResourceFetcher::new(github_client).max_concurrency(),
+            3
+        );
+
+        // Without the flag the fetchers keep their default
+        let github_client = batch_github_client(&None, None, None).unwrap();
+        assert_eq!(
+            MultiResourceFetcher::new(github_client).max_concurrency(),
+            DEFAULT_MAX_CONCURRENCY
+        );
+    }
+
+    #[test]
+    fn test_jsonl_line_is_tagged_single_line_json() {
+        let mut issue = watched_issue("repo", 42);
+        if let IssueOrPullrequest::Issue(issue) = &mut issue {
+            issue.title = "Crash on startup".to_string();
+            issue.body = Some("Line one\nLine two".to_string());
+        }
+
+        let line = jsonl_line(&issue).unwrap();
+        assert!(!line.contains('\n'));
+
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["type"], "issue");
+        assert_eq!(value["title"], "Crash on startup");
+        assert_eq!(value["issue_id"]["number"], 42);
+    }
+
+    #[test]
+    fn test_field_projection_keeps_requested_keys() {
+        let results = vec![watched_issue("repo", 7)];
+        let mut json = serde_json::to_value(&results).unwrap();
+        let fields = vec![
+            "title".to_string(),
+            "state".to_string(),
+            "no_such_field".to_string(),
+        ];
+
+        let mut projection = FieldProjection::new(&fields);
+        projection.apply(&mut json);
+
+        let issue = json[0]["Issue"].as_object().unwrap();
+        let mut keys: Vec<&str> = issue.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        assert_eq!(keys, vec!["state", "title"]);
+        assert_eq!(issue["title"], "Issue 7");
+        assert_eq!(projection.unknown_fields(), vec!["no_such_field"]);
+    }
+
+    #[test]
+    fn test_json_with_fields_projects_grouped_records() {
+        let mut issues_by_repo = BTreeMap::new();
+        issues_by_repo.insert("owner/repo".to_string(), vec![watched_issue("repo", 7)]);
+
+        let json: serde_json::Value = serde_json::from_str(
+            &json_with_fields(&issues_by_repo, &["title".to_string()], true).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({"owner/repo": [{"Issue": {"title": "Issue 7"}}]})
+        );
+    }
+
+    fn watched_issue(repository_name: &str, number: u32) -> IssueOrPullrequest {
+        IssueOrPullrequest::Issue(Issue::fixture(
+            repository_name,
+            number,
+            &format!("Issue {}", number),
+        ))
+    }
+
+    fn keys(results: &[IssueOrPullrequest]) -> Vec<(String, u32)> {
+        results
+            .iter()
+            .map(|result| {
+                let (repository_id, number) = search_result_key(result);
+                (repository_id.full_name(), number)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_seen_search_results_keeps_only_new_results() {
+        let mut seen = SeenSearchResults::default();
+
+        let first_poll = seen.retain_new(vec![watched_issue("repo", 2), watched_issue("repo", 1)]);
+        assert_eq!(
+            keys(&first_poll),
+            vec![("owner/repo".to_string(), 2), ("owner/repo".to_string(), 1)]
+        );
+
+        // The same number in another repository is a different result
+        let second_poll = seen.retain_new(vec![
+            watched_issue("repo", 3),
+            watched_issue("other", 2),
+            watched_issue("repo", 2),
+            watched_issue("repo", 1),
+        ]);
+        assert_eq!(
+            keys(&second_poll),
+            vec![
+                ("owner/repo".to_string(), 3),
+                ("owner/other".to_string(), 2)
+            ]
+        );
+
+        assert!(
+            seen.retain_new(vec![watched_issue("repo", 3), watched_issue("repo", 3)])
+                .is_empty()
+        );
+        assert_eq!(seen.len(), 4);
+    }
+
+    #[test]
+    fn test_color_choice_enabled() {
+        assert!(!ColorChoice::Auto.enabled(false));
+        assert!(ColorChoice::Auto.enabled(true));
+        assert!(ColorChoice::Always.enabled(false));
+        assert!(!ColorChoice::Never.enabled(true));
+    }
+
+    #[test]
+    fn test_levenshtein_distance() {
+        assert_eq!(levenshtein_distance("work", "work"), 0);
+        assert_eq!(levenshtein_distance("wrok", "work"), 2);
+        assert_eq!(levenshtein_distance("wor", "work"), 1);
+        assert_eq!(levenshtein_distance("", "abc"), 3);
+        assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_suggest_profile_name_for_near_misses() {
+        let profiles = profiles(&["default", "work", "personal"]);
+
+        let suggest = |name: &str| suggest_profile_name(name, &profiles).map(|p| p.value());
+        assert_eq!(suggest("wrok"), Some("work"));
+        assert_eq!(suggest("works"), Some("work"));
+        assert_eq!(suggest("defualt"), Some("default"));
+        assert_eq!(suggest("persnal"), Some("personal"));
+        assert_eq!(suggest("oss"), None);
+        assert_eq!(suggest("work"), None);
+    }
+
+    #[test]
+    fn test_cli_error_from_profile_service_errors() {
+        let not_found = anyhow::Error::new(ProfileServiceError::GroupNotFound("g".to_string()));
+        assert!(matches!(
+            CliError::from_error(&not_found),
+            CliError::NotFound(_)
+        ));
+
+        let exists = anyhow::Error::new(ProfileServiceError::ProfileAlreadyExists(
+            "work".to_string(),
+        ));
+        assert!(matches!(CliError::from_error(&exists), CliError::Other(_)));
+
+        // Errors already converted by profile_error keep their category and message
+        let converted: anyhow::Error = CliError::from_profile_service_error(
+            &ProfileServiceError::ProfileNotFound("wrok".to_string()),
+            "Failed to list repositories: Profile 'wrok' not found".to_string(),
+        )
+        .into();
+        let error = CliError::from_error(&converted);
+        assert_eq!(error.exit_code(), 2);
+        assert_eq!(
+            error.to_string(),
+            "Failed to list repositories: Profile 'wrok' not found"
+        );
+    }
+
+    #[test]
+    fn test_cli_error_from_github_client_errors() {
+        let classify = |error: anyhow::Error| CliError::from_error(&error).exit_code();
+
+        assert_eq!(
+            classify(anyhow::anyhow!(ApiRetryableError::RateLimit).context("Failed to get issues")),
+            4
+        );
+        assert_eq!(
+            classify(
+                anyhow::anyhow!(ApiRetryableError::from_rest_status(401, "Bad credentials"))
+                    .context("Failed to get issues")
+            ),
+            3
+        );
+        assert_eq!(
+            classify(anyhow::anyhow!(
+                "Failed to get project details: Non-retryable error: Permission denied: Resource not accessible by integration"
+            )),
+            3
+        );
+        assert_eq!(
+            classify(anyhow::anyhow!(
+                "Non-retryable error: Resource not found: Could not resolve to a Repository with the name 'owner/missing'."
+            )),
+            2
+        );
+        assert_eq!(
+            classify(
+                anyhow::anyhow!(ApiRetryableError::Network(
+                    "GraphQL request timed out after 30s".to_string()
+                ))
+                .context("Failed to get issues")
+            ),
+            5
+        );
+        // Wording alone no longer decides the category of client errors
+        assert_eq!(
+            classify(anyhow::anyhow!(
+                "Retryable error: GraphQL server error: the request timed out"
+            )),
+            1
+        );
+        assert_eq!(
+            classify(anyhow::anyhow!(
+                "Failed to write output to out.md: disk full"
+            )),
+            1
+        );
+    }
+
+    #[test]
+    fn test_cli_error_invalid_url() {
+        let error = parse_project_url("https://example.com/not-a-project").unwrap_err();
+        assert!(matches!(
+            CliError::from_error(&error),
+            CliError::InvalidUrl(_)
+        ));
+        assert_eq!(CliError::from_error(&error).exit_code(), 6);
+
+        let error = anyhow::Error::new(InvalidUrlError("missing number".to_string()))
+            .context("Failed to parse pull request URL https://github.com/owner/repo/pulls")
+            .context("Failed to get pull requests");
+        let cli_error = CliError::from_error(&error);
+        assert!(matches!(cli_error, CliError::InvalidUrl(_)));
+        assert_eq!(
+            cli_error.to_string(),
+            "Failed to get pull requests: Failed to parse pull request URL https://github.com/owner/repo/pulls: missing number"
+        );
+
+        // Messages merely mentioning a URL field are not URL errors
+        let error = anyhow::anyhow!(
+            "Failed to get issues: Non-retryable error: JSON parsing error: invalid type for field `html_url`"
+        );
+        assert!(matches!(CliError::from_error(&error), CliError::Other(_)));
+    }
+}
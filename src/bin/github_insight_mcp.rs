@@ -64,7 +64,12 @@ HTTP/SSE MODE USAGE:
 1. Start server: github-insight-mcp http --address 0.0.0.0:8080
 2. Connect to http://localhost:8080/sse for Server-Sent Events
 3. Send MCP requests via HTTP POST to /mcp
-4. Receive responses via SSE stream"
+4. Receive responses via SSE stream
+
+WEBSOCKET MODE USAGE:
+1. Start server: github-insight-mcp websocket --address 0.0.0.0:8081
+2. Connect to ws://localhost:8081
+3. Exchange MCP JSON-RPC messages as text frames, one message per frame"
 )]
 #[command(propagate_version = true)]
 #[command(disable_version_flag = true)]
@@ -111,6 +116,28 @@ enum Commands {
         #[arg(short = 'z', long)]
         timezone: Option<String>,
 
+        /// Profile name for database isolation and configuration management (default: "default")
+        #[arg(short = 'p', long)]
+        profile: Option<String>,
+    },
+    /// Run the server over WebSocket for clients that prefer a persistent bidirectional connection
+    Websocket {
+        /// Address to bind the WebSocket server to
+        #[arg(short, long, default_value = "0.0.0.0:8081")]
+        address: String,
+
+        /// Enable debug logging for troubleshooting and development
+        #[arg(short, long)]
+        debug: bool,
+
+        /// GitHub personal access token for API authentication (overrides GITHUB_INSIGHT_GITHUB_TOKEN environment variable)
+        #[arg(short = 't', long)]
+        github_token: Option<String>,
+
+        /// Timezone for datetime formatting in output - supports standard timezones (e.g., "JST", "+09:00", "America/New_York", "UTC")
+        #[arg(short = 'z', long)]
+        timezone: Option<String>,
+
         /// Profile name for database isolation and configuration management (default: "default")
         #[arg(short = 'p', long)]
         profile: Option<String>,
@@ -163,6 +190,22 @@ async fn main() -> Result<()> {
 
             run_http_server(address, debug, github_token, timezone, profile).await
         }
+        Commands::Websocket {
+            address,
+            debug,
+            github_token,
+            timezone,
+            profile,
+        } => {
+            // Use github_token directly or get from environment
+            let github_token =
+                github_token.or_else(|| std::env::var("GITHUB_INSIGHT_GITHUB_TOKEN").ok());
+
+            // Parse timezone if provided, otherwise use local timezone
+            let timezone = parse_timezone_or_default(timezone);
+
+            run_websocket_server(address, debug, github_token, timezone, profile).await
+        }
     }
 }
 
@@ -208,3 +251,41 @@ async fn run_http_server(
 
     Ok(())
 }
+
+async fn run_websocket_server(
+    address: String,
+    debug: bool,
+    github_token: Option<String>,
+    timezone: Option<String>,
+    profile_name: Option<String>,
+) -> Result<()> {
+    // Setup tracing
+    let level = if debug { "debug" } else { "info" };
+
+    tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| format!("{},{}", level, env!("CARGO_CRATE_NAME")).into()),
+        )
+        .with(tracing_subscriber::fmt::layer().with_ansi(false)) // Disable ANSI color codes
+        .init();
+
+    // Parse socket address
+    let addr: SocketAddr = address.parse()?;
+
+    tracing::info!("GitHub Insight WebSocket server listening on ws://{}", addr);
+
+    if github_token.is_some() {
+        tracing::info!("Using GitHub token from command line arguments");
+    }
+
+    let app = github_insight::transport::websocket::WebSocketServerApp::new(
+        addr,
+        github_token,
+        timezone,
+        profile_name.map(|p| ProfileName::from(p.as_str())),
+    );
+    app.serve().await?;
+
+    Ok(())
+}
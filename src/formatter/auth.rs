@@ -0,0 +1,59 @@
+use crate::types::AuthStatus;
+
+use super::MarkdownContent;
+
+/// Format the result of a token check into markdown
+pub fn auth_status_markdown(status: &AuthStatus) -> MarkdownContent {
+    let mut content = String::from("# GitHub Authentication\n");
+    match status {
+        AuthStatus::NoToken => {
+            content.push_str("status: not authenticated\n");
+            content.push_str(
+                "\nNo GitHub token is configured. Pass `--github-token` or set `GITHUB_INSIGHT_GITHUB_TOKEN`.\n",
+            );
+        }
+        AuthStatus::Authenticated { login, scopes } => {
+            content.push_str("status: authenticated\n");
+            content.push_str(&format!("login: {}\n", login));
+            match scopes {
+                Some(scopes) if scopes.is_empty() => content.push_str("scopes: (none)\n"),
+                Some(scopes) => content.push_str(&format!("scopes: {}\n", scopes.join(", "))),
+                None => content.push_str("scopes: (not reported for this token type)\n"),
+            }
+        }
+        AuthStatus::InvalidToken { message } => {
+            content.push_str("status: invalid token\n");
+            content.push_str(&format!(
+                "\nGitHub rejected the configured token: {}\n",
+                message
+            ));
+        }
+    }
+
+    MarkdownContent(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auth_status_markdown_authenticated() {
+        let status = AuthStatus::Authenticated {
+            login: "octocat".to_string(),
+            scopes: Some(vec!["repo".to_string(), "read:org".to_string()]),
+        };
+        let markdown = auth_status_markdown(&status);
+
+        assert!(markdown.0.contains("status: authenticated"));
+        assert!(markdown.0.contains("login: octocat"));
+        assert!(markdown.0.contains("scopes: repo, read:org"));
+    }
+
+    #[test]
+    fn test_auth_status_markdown_no_token() {
+        let markdown = auth_status_markdown(&AuthStatus::NoToken);
+        assert!(markdown.0.contains("status: not authenticated"));
+        assert!(markdown.0.contains("No GitHub token is configured"));
+    }
+}
@@ -0,0 +1,136 @@
+use crate::types::{IssueOrPullrequest, RepositoryId};
+
+use super::{TimezoneOffset, format_datetime_with_timezone_offset};
+
+/// Column names of the search results CSV
+pub const SEARCH_RESULT_CSV_HEADER: [&str; 10] = [
+    "repository",
+    "number",
+    "type",
+    "title",
+    "state",
+    "author",
+    "created_at",
+    "updated_at",
+    "comment_count",
+    "url",
+];
+
+/// Format issue and pull request search results as CSV with a header row
+///
+/// Datetime columns are converted to the given timezone (UTC when `None`).
+pub fn search_results_csv_with_timezone(
+    results: &[IssueOrPullrequest],
+    timezone: Option<&TimezoneOffset>,
+) -> String {
+    let mut content = csv_row(
+        SEARCH_RESULT_CSV_HEADER
+            .iter()
+            .map(|column| column.to_string()),
+    );
+
+    for result in results {
+        let row = match result {
+            IssueOrPullrequest::Issue(issue) => [
+                repository_name(&issue.issue_id.git_repository),
+                issue.issue_id.number.to_string(),
+                "issue".to_string(),
+                issue.title.clone(),
+                issue.state.to_string(),
+                issue.author.clone(),
+                format_datetime_with_timezone_offset(issue.created_at, timezone),
+                format_datetime_with_timezone_offset(issue.updated_at, timezone),
+                issue.comments_count.to_string(),
+                issue.issue_id.url(),
+            ],
+            IssueOrPullrequest::PullRequest(pr) => [
+                repository_name(&pr.pull_request_id.git_repository),
+                pr.pull_request_id.number.to_string(),
+                "pull_request".to_string(),
+                pr.title.clone(),
+                pr.state.to_string(),
+                pr.author
+                    .as_ref()
+                    .map(|author| author.to_string())
+                    .unwrap_or_default(),
+                format_datetime_with_timezone_offset(pr.created_at, timezone),
+                format_datetime_with_timezone_offset(pr.updated_at, timezone),
+                pr.comments_count.to_string(),
+                pr.pull_request_id.url(),
+            ],
+        };
+        content.push_str(&csv_row(row));
+    }
+
+    content
+}
+
+fn repository_name(repository_id: &RepositoryId) -> String {
+    format!("{}/{}", repository_id.owner, repository_id.repository_name)
+}
+
+/// Join fields into a CSV line terminated by CRLF as recommended by RFC 4180
+fn csv_row(fields: impl IntoIterator<Item = String>) -> String {
+    let mut row = fields
+        .into_iter()
+        .map(|field| escape_csv_field(&field))
+        .collect::<Vec<_>>()
+        .join(",");
+    row.push_str("\r\n");
+    row
+}
+
+/// Quote a field when it contains a delimiter, quote, or line break, doubling inner quotes
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Issue;
+
+    fn issue(title: &str) -> Issue {
+        let mut issue = Issue::fixture("repo", 42, title);
+        issue.comments_count = 3;
+        issue
+    }
+
+    #[test]
+    fn test_escape_csv_field() {
+        assert_eq!(escape_csv_field("plain"), "plain");
+        assert_eq!(escape_csv_field("a, b"), "\"a, b\"");
+        assert_eq!(escape_csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape_csv_field("line\nbreak"), "\"line\nbreak\"");
+    }
+
+    #[test]
+    fn test_search_results_csv_header_and_rows() {
+        let results = vec![IssueOrPullrequest::Issue(issue("Crash, when \"empty\""))];
+        let csv = search_results_csv_with_timezone(&results, None);
+        let lines: Vec<&str> = csv.split("\r\n").collect();
+
+        assert_eq!(
+            lines[0],
+            "repository,number,type,title,state,author,created_at,updated_at,comment_count,url"
+        );
+        assert_eq!(
+            lines[1],
+            "owner/repo,42,issue,\"Crash, when \"\"empty\"\"\",OPEN,alice,\
+             2025-01-02 03:04:05 UTC,2025-01-02 03:04:05 UTC,3,\
+             https://github.com/owner/repo/issues/42"
+        );
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[2], "");
+    }
+
+    #[test]
+    fn test_search_results_csv_empty_has_header_only() {
+        let csv = search_results_csv_with_timezone(&[], None);
+        assert_eq!(csv.lines().count(), 1);
+    }
+}
@@ -0,0 +1,32 @@
+use crate::types::Discussion;
+
+use super::{MarkdownContent, TimezoneOffset, format_datetime_with_timezone_offset};
+
+/// Format a discussion search result into lightweight markdown with timezone conversion
+pub fn discussion_markdown_with_timezone_light(
+    discussion: &Discussion,
+    timezone: Option<&TimezoneOffset>,
+) -> MarkdownContent {
+    let mut content = String::new();
+
+    content.push_str(&format!("# {}\n", discussion.title));
+    match &discussion.category {
+        Some(category) => content.push_str(&format!(
+            "**Discussion** in {} ({})\n",
+            category, discussion.answer_status
+        )),
+        None => content.push_str(&format!("**Discussion** ({})\n", discussion.answer_status)),
+    }
+    content.push_str(&format!("**URL:** {}\n\n", discussion.url));
+
+    if let Some(author) = &discussion.author {
+        content.push_str(&format!("**Author:** `{}`\n", author));
+    }
+    content.push_str(&format!("**Comments:** {}\n", discussion.comments_count));
+    content.push_str(&format!(
+        "**Updated:** {}\n",
+        format_datetime_with_timezone_offset(discussion.updated_at, timezone)
+    ));
+
+    MarkdownContent(content)
+}
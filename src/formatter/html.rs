@@ -0,0 +1,341 @@
+//! HTML formatting functionality
+//!
+//! Renders issues and pull requests as HTML fragments for embedding in web pages.
+//! All user content is escaped; markdown bodies are converted to HTML with raw HTML
+//! blocks and tags escaped rather than passed through, and links with script-capable
+//! schemes are neutralized.
+
+use pulldown_cmark::{CowStr, Event, Options, Parser, Tag, html};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Issue, IssueOrPullrequestId, PullRequest, User};
+
+use super::{RenderOptions, TimezoneOffset, timestamp_text};
+
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct HtmlContent(pub String);
+
+/// URL schemes that must not appear in rendered links and images
+const UNSAFE_URL_SCHEMES: [&str; 3] = ["javascript:", "vbscript:", "data:"];
+
+/// Escape text for use in HTML element content and attribute values
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// Convert a GitHub markdown body to sanitized HTML
+///
+/// Tables, strikethrough, and task lists are supported. Raw HTML in the markdown is
+/// rendered as escaped text, and links or images with `javascript:`, `vbscript:`, or
+/// `data:` URLs point to `#` instead.
+pub fn markdown_to_html(markdown: &str) -> String {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let events = Parser::new_ext(markdown, options).map(|event| match event {
+        Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+        Event::Start(Tag::Link {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Link {
+            link_type,
+            dest_url: safe_url(dest_url),
+            title,
+            id,
+        }),
+        Event::Start(Tag::Image {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Image {
+            link_type,
+            dest_url: safe_url(dest_url),
+            title,
+            id,
+        }),
+        event => event,
+    });
+
+    let mut content = String::new();
+    html::push_html(&mut content, events);
+    content
+}
+
+fn safe_url(url: CowStr<'_>) -> CowStr<'_> {
+    let normalized: String = url
+        .chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    if UNSAFE_URL_SCHEMES
+        .iter()
+        .any(|scheme| normalized.starts_with(scheme))
+    {
+        CowStr::Borrowed("#")
+    } else {
+        url
+    }
+}
+
+/// Format an issue into an HTML fragment with timezone conversion
+pub fn issue_body_html_with_timezone(
+    issue: &Issue,
+    timezone: Option<&TimezoneOffset>,
+    options: &RenderOptions,
+) -> HtmlContent {
+    let mut content = String::from("<article class=\"issue\">\n");
+
+    content.push_str(&format!("<h1>ISSUE: {}</h1>\n", escape_html(&issue.title)));
+    content.push_str("<ul class=\"metadata\">\n");
+    push_list_item(&mut content, "author", &escape_html(&issue.author));
+    push_list_item(
+        &mut content,
+        "status",
+        &escape_html(&issue.state.to_string()),
+    );
+    push_list_item(&mut content, "url", &link_html(&issue.issue_id.url()));
+    push_list_item(
+        &mut content,
+        "created",
+        &timestamp_text(issue.created_at, timezone, options),
+    );
+    push_list_item(
+        &mut content,
+        "updated",
+        &timestamp_text(issue.updated_at, timezone, options),
+    );
+    if let Some(closed_at) = issue.closed_at {
+        push_list_item(
+            &mut content,
+            "closed",
+            &timestamp_text(closed_at, timezone, options),
+        );
+    }
+    content.push_str("</ul>\n");
+
+    push_linked_resources(&mut content, &issue.linked_resources);
+    push_name_list(&mut content, "labels", issue.labels.iter().cloned());
+    push_name_list(&mut content, "assignee", issue.assignees.iter().cloned());
+
+    content.push_str("<h2>body</h2>\n");
+    if let Some(body) = &issue.body {
+        content.push_str(&markdown_to_html(body));
+    }
+
+    if !issue.comments.is_empty() {
+        content.push_str("<h2>comments</h2>\n");
+        for comment in &issue.comments {
+            push_comment(
+                &mut content,
+                comment.author.as_ref(),
+                &timestamp_text(comment.created_at, timezone, options),
+                &comment.body,
+            );
+        }
+    }
+
+    content.push_str("</article>\n");
+    HtmlContent(content)
+}
+
+/// Format a pull request into an HTML fragment with timezone conversion
+pub fn pull_request_body_html_with_timezone(
+    pr: &PullRequest,
+    timezone: Option<&TimezoneOffset>,
+    options: &RenderOptions,
+) -> HtmlContent {
+    let mut content = String::from("<article class=\"pull-request\">\n");
+
+    content.push_str(&format!("<h1>PR: {}</h1>\n", escape_html(&pr.title)));
+    content.push_str("<ul class=\"metadata\">\n");
+    push_list_item(&mut content, "author", &author_html(pr.author.as_ref()));
+    push_list_item(&mut content, "status", &escape_html(&pr.state.to_string()));
+    push_list_item(&mut content, "url", &link_html(&pr.pull_request_id.url()));
+    if !pr.head_branch.is_empty() && !pr.base_branch.is_empty() {
+        push_list_item(
+            &mut content,
+            "branch",
+            &format!(
+                "{} → {}",
+                escape_html(&pr.head_branch),
+                escape_html(&pr.base_branch)
+            ),
+        );
+    }
+    if pr.draft {
+        push_list_item(&mut content, "flags", "DRAFT");
+    }
+    push_list_item(
+        &mut content,
+        "created",
+        &timestamp_text(pr.created_at, timezone, options),
+    );
+    push_list_item(
+        &mut content,
+        "updated",
+        &timestamp_text(pr.updated_at, timezone, options),
+    );
+    if let Some(merged_at) = pr.merged_at {
+        push_list_item(
+            &mut content,
+            "merged",
+            &timestamp_text(merged_at, timezone, options),
+        );
+    }
+    content.push_str("</ul>\n");
+
+    push_linked_resources(&mut content, &pr.linked_resources);
+    push_name_list(
+        &mut content,
+        "labels",
+        pr.labels.iter().map(|label| label.name().to_string()),
+    );
+    push_name_list(
+        &mut content,
+        "assignee",
+        pr.assignees.iter().map(|assignee| assignee.to_string()),
+    );
+
+    content.push_str("<h2>body</h2>\n");
+    if let Some(body) = &pr.body {
+        content.push_str(&markdown_to_html(body));
+    }
+
+    content.push_str("<h2>comments</h2>\n");
+    if pr.comments.is_empty() {
+        content.push_str("<p>(No comments)</p>\n");
+    }
+    for comment in &pr.comments {
+        push_comment(
+            &mut content,
+            comment.author.as_ref(),
+            &timestamp_text(comment.created_at, timezone, options),
+            &comment.body,
+        );
+    }
+
+    content.push_str("</article>\n");
+    HtmlContent(content)
+}
+
+/// Append a `<li>` with a bold key; `value_html` must already be escaped
+fn push_list_item(content: &mut String, key: &str, value_html: &str) {
+    content.push_str(&format!(
+        "<li><strong>{}:</strong> {}</li>\n",
+        key, value_html
+    ));
+}
+
+fn push_name_list(content: &mut String, heading: &str, names: impl Iterator<Item = String>) {
+    let items: Vec<String> = names
+        .map(|name| format!("<li>{}</li>\n", escape_html(&name)))
+        .collect();
+    if items.is_empty() {
+        return;
+    }
+    content.push_str(&format!("<h2>{}</h2>\n<ul>\n", heading));
+    content.push_str(&items.concat());
+    content.push_str("</ul>\n");
+}
+
+fn push_linked_resources(content: &mut String, linked_resources: &[IssueOrPullrequestId]) {
+    if linked_resources.is_empty() {
+        return;
+    }
+    content.push_str("<h2>linked resources</h2>\n<ul>\n");
+    for linked in linked_resources {
+        let (kind, url) = match linked {
+            IssueOrPullrequestId::IssueId(issue_id) => ("Issue", issue_id.url()),
+            IssueOrPullrequestId::PullrequestId(pr_id) => ("PR", pr_id.url()),
+        };
+        content.push_str(&format!("<li>{}: {}</li>\n", kind, link_html(&url)));
+    }
+    content.push_str("</ul>\n");
+}
+
+fn push_comment(content: &mut String, author: Option<&User>, created: &str, body: &str) {
+    content.push_str("<section class=\"comment\">\n");
+    content.push_str(&format!(
+        "<h3>author: {}</h3>\n<p>created: {}</p>\n",
+        author_html(author),
+        created
+    ));
+    content.push_str(&markdown_to_html(body));
+    content.push_str("</section>\n");
+}
+
+fn author_html(author: Option<&User>) -> String {
+    match author {
+        Some(user) => escape_html(user.as_str()),
+        None => "Unknown ⚠️".to_string(),
+    }
+}
+
+fn link_html(url: &str) -> String {
+    let url = escape_html(url);
+    format!("<a href=\"{}\">{}</a>", url, url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{IssueComment, IssueCommentNumber};
+
+    fn issue(title: &str, body: &str) -> Issue {
+        let mut issue = Issue::fixture("repo", 42, title);
+        issue.body = Some(body.to_string());
+        issue
+    }
+
+    #[test]
+    fn test_issue_body_html_escapes_script() {
+        let mut issue = issue(
+            "<script>alert('title')</script>",
+            "Before\n\n<script>alert('body')</script>\n\nInline <script>alert(1)</script> tag",
+        );
+        issue.comments.push(IssueComment {
+            comment_number: IssueCommentNumber::new(1),
+            body: "<img src=x onerror=alert(1)>".to_string(),
+            author: None,
+            created_at: issue.created_at,
+            updated_at: issue.created_at,
+            author_is_bot: false,
+        });
+
+        let html = issue_body_html_with_timezone(&issue, None, &RenderOptions::default()).0;
+
+        assert!(!html.contains("<script>"));
+        assert!(!html.contains("<img"));
+        assert!(
+            html.contains("<h1>ISSUE: &lt;script&gt;alert(&#39;title&#39;)&lt;/script&gt;</h1>")
+        );
+        assert!(html.contains("&lt;script&gt;alert('body')&lt;/script&gt;"));
+        assert!(html.contains("&lt;img src=x onerror=alert(1)&gt;"));
+    }
+
+    #[test]
+    fn test_markdown_to_html_renders_code_blocks_and_links() {
+        let html = markdown_to_html(
+            "See [the docs](https://example.com/docs?a=1&b=2).\n\n```rust\nlet x = a < b;\n```\n",
+        );
+
+        assert!(html.contains("<a href=\"https://example.com/docs?a=1&amp;b=2\">the docs</a>"));
+        assert!(
+            html.contains("<pre><code class=\"language-rust\">let x = a &lt; b;\n</code></pre>")
+        );
+    }
+
+    #[test]
+    fn test_markdown_to_html_neutralizes_script_urls() {
+        let html = markdown_to_html("[click](javascript:alert(1)) ![img]( JavaScript:alert(2))");
+
+        assert!(!html.to_ascii_lowercase().contains("javascript:"));
+        assert!(html.contains("<a href=\"#\">click</a>"));
+    }
+}
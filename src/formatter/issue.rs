@@ -1,21 +1,49 @@
-use crate::types::Issue;
+use crate::types::{
+    Issue, IssueLinkSummary, IssueOrPullrequestId, IssueTimeline, LinkSummaryEntry, TimelineEvent,
+};
 
-use super::{MarkdownContent, TimezoneOffset, format_datetime_with_timezone_offset};
-
-/// Maximum number of characters to display in the body of an issue in light format
-const MAX_BODY_LENGTH: usize = 100;
+use super::{
+    DEFAULT_LIGHT_BODY_LENGTH, MarkdownContent, RenderOptions, TimezoneOffset,
+    format_date_with_timezone_offset, format_datetime_with_timezone_offset,
+    mentioned_resource_urls, push_light_body, reactions_text, state_text, timestamp_text,
+};
 
 /// Format an issue into markdown with timezone conversion
 pub fn issue_body_markdown_with_timezone(
     issue: &Issue,
     timezone: Option<&TimezoneOffset>,
+) -> MarkdownContent {
+    issue_body_markdown_with_comment_range(
+        issue,
+        timezone,
+        None,
+        None,
+        false,
+        &RenderOptions::default(),
+    )
+}
+
+/// Format an issue into markdown, rendering only a range of its comments
+///
+/// `comment_skip` comments are skipped from the beginning and at most `comment_limit`
+/// comments are rendered after that. When comments are left out, a footer notes how
+/// many were omitted. A skip beyond the number of comments renders a notice instead
+/// of comments. With `exclude_bot_comments`, comments by bots are dropped before the
+/// range is applied and a note says how many were hidden.
+pub fn issue_body_markdown_with_comment_range(
+    issue: &Issue,
+    timezone: Option<&TimezoneOffset>,
+    comment_skip: Option<u32>,
+    comment_limit: Option<u32>,
+    exclude_bot_comments: bool,
+    options: &RenderOptions,
 ) -> MarkdownContent {
     let mut content = String::new();
 
     // Header
     content.push_str(&format!("# ISSUE: {}\n", issue.title));
     content.push_str(&format!("author: {}\n", issue.author));
-    content.push_str(&format!("status: {}\n", issue.state));
+    content.push_str(&format!("status: {}\n", state_text(issue.state, options)));
     content.push_str(&format!("url: {}\n", issue.issue_id.url()));
     content.push_str(&format!(
         "Repository Url: {}\n",
@@ -25,18 +53,21 @@ pub fn issue_body_markdown_with_timezone(
     // Date information
     content.push_str(&format!(
         "created: {}\n",
-        format_datetime_with_timezone_offset(issue.created_at, timezone)
+        timestamp_text(issue.created_at, timezone, options)
     ));
     content.push_str(&format!(
         "updated: {}\n",
-        format_datetime_with_timezone_offset(issue.updated_at, timezone)
+        timestamp_text(issue.updated_at, timezone, options)
     ));
     if let Some(closed_at) = issue.closed_at {
         content.push_str(&format!(
             "closed: {}\n",
-            format_datetime_with_timezone_offset(closed_at, timezone)
+            timestamp_text(closed_at, timezone, options)
         ));
     }
+    if let Some(reactions) = &issue.reactions {
+        content.push_str(&format!("reactions: {}\n", reactions_text(reactions)));
+    }
     content.push('\n');
 
     // Linked resources (Issues and Pull Requests)
@@ -83,7 +114,33 @@ pub fn issue_body_markdown_with_timezone(
     // Comments
     if !issue.comments.is_empty() {
         content.push_str("## comments\n");
-        for comment in &issue.comments {
+        let comments: Vec<_> = issue
+            .comments
+            .iter()
+            .filter(|comment| !(exclude_bot_comments && comment.author_is_bot))
+            .collect();
+        let hidden_bot_comments = issue.comments.len() - comments.len();
+        if hidden_bot_comments > 0 {
+            content.push_str(&format!(
+                "({} bot comment(s) hidden)\n",
+                hidden_bot_comments
+            ));
+            if comments.is_empty() {
+                return MarkdownContent(content);
+            }
+        }
+        let total = comments.len();
+        let skip = comment_skip.unwrap_or(0) as usize;
+        if skip > 0 && skip >= total {
+            content.push_str(&format!(
+                "(comment_skip {} exceeds the {} comment(s) of this issue; no comments shown)\n",
+                skip, total
+            ));
+            return MarkdownContent(content);
+        }
+        let shown = comment_limit.map_or(total - skip, |limit| (limit as usize).min(total - skip));
+
+        for comment in comments.iter().skip(skip).take(shown) {
             let author_display = match &comment.author {
                 Some(user) => user.as_str().to_string(),
                 None => "Unknown ⚠️".to_string(),
@@ -91,28 +148,60 @@ pub fn issue_body_markdown_with_timezone(
             content.push_str(&format!("### author: {}\n", author_display));
             content.push_str(&format!(
                 "created: {}\n",
-                format_datetime_with_timezone_offset(comment.created_at, timezone)
+                timestamp_text(comment.created_at, timezone, options)
             ));
             content.push_str(&format!(
                 "updated: {}\n",
-                format_datetime_with_timezone_offset(comment.updated_at, timezone)
+                timestamp_text(comment.updated_at, timezone, options)
             ));
             content.push_str(&format!("\n{}\n\n", comment.body));
         }
+
+        if shown == 0 {
+            content.push_str(&format!(
+                "(no comments shown; {} comment(s) omitted)\n",
+                total
+            ));
+        } else if shown < total {
+            content.push_str(&format!(
+                "(showing comments {}-{} of {}; {} comment(s) omitted)\n",
+                skip + 1,
+                skip + shown,
+                total,
+                total - shown
+            ));
+        }
     }
 
     MarkdownContent(content)
 }
 
 pub fn issue_body_markdown_with_timezone_light(
+    issue: &Issue,
+    timezone: Option<&TimezoneOffset>,
+) -> MarkdownContent {
+    issue_body_markdown_light_with_body_truncation(
+        issue,
+        timezone,
+        DEFAULT_LIGHT_BODY_LENGTH,
+        &RenderOptions::default(),
+    )
+}
+
+/// Format an issue in light format with the body cut to `body_truncate_len` characters
+///
+/// A `body_truncate_len` of 0 omits the body.
+pub fn issue_body_markdown_light_with_body_truncation(
     issue: &Issue,
     _timezone: Option<&TimezoneOffset>,
+    body_truncate_len: usize,
+    options: &RenderOptions,
 ) -> MarkdownContent {
     let mut content = String::new();
 
     // Lightweight header - title and status only
     content.push_str(&format!("# {}\n", issue.title));
-    content.push_str(&format!("**{}**\n", issue.state));
+    content.push_str(&format!("**{}**\n", state_text(issue.state, options)));
     content.push_str(&format!("**URL:** {}\n\n", issue.issue_id.url()));
 
     // Assignees
@@ -123,16 +212,14 @@ pub fn issue_body_markdown_with_timezone_light(
         content.push('\n');
     }
 
-    // Body only if present, truncated to MAX_BODY_LENGTH characters
+    // Labels
+    if !issue.labels.is_empty() {
+        content.push_str(&format!("**Labels:** {}\n", issue.labels.join(", ")));
+    }
+
+    // Body only if present, truncated to body_truncate_len characters
     if let Some(body) = &issue.body {
-        if body.chars().count() > MAX_BODY_LENGTH {
-            let truncated: String = body.chars().take(MAX_BODY_LENGTH).collect();
-            content.push_str(&truncated);
-            content.push_str("...\n\n");
-        } else {
-            content.push_str(body);
-            content.push_str("\n\n");
-        }
+        push_light_body(&mut content, body, body_truncate_len);
     }
 
     // Comment count
@@ -148,5 +235,426 @@ pub fn issue_body_markdown_with_timezone_light(
         content.push_str(&format!("**Linked:** {}\n", urls.join(",")));
     }
 
+    // Issues and pull requests mentioned in the body
+    if let Some(body) = &issue.body {
+        let mentioned = mentioned_resource_urls(
+            body,
+            &IssueOrPullrequestId::IssueId(issue.issue_id.clone()),
+            &issue.linked_resources,
+        );
+        if !mentioned.is_empty() {
+            content.push_str(&format!("**Mentions:** {}\n", mentioned.join(",")));
+        }
+    }
+
+    MarkdownContent(content)
+}
+
+/// Format a categorized summary of the links in an issue thread
+pub fn issue_link_summary_markdown(summary: &IssueLinkSummary) -> MarkdownContent {
+    let mut content = String::new();
+
+    content.push_str(&format!("# Links in: {}\n", summary.issue_title));
+    content.push_str(&format!("url: {}\n\n", summary.issue_id.url()));
+
+    let sections = [
+        ("Issues", &summary.issues),
+        ("Pull Requests", &summary.pull_requests),
+        ("Commits", &summary.commits),
+        ("File Permalinks", &summary.file_permalinks),
+    ];
+    for (heading, entries) in sections {
+        if entries.is_empty() {
+            continue;
+        }
+        content.push_str(&format!("## {}\n", heading));
+        for entry in entries {
+            content.push_str(&link_summary_entry_line(entry));
+        }
+        content.push('\n');
+    }
+
+    let url_sections = [
+        ("Other GitHub Links", &summary.other_github_links),
+        ("External Links", &summary.external_links),
+    ];
+    for (heading, urls) in url_sections {
+        if urls.is_empty() {
+            continue;
+        }
+        content.push_str(&format!("## {}\n", heading));
+        for url in urls {
+            content.push_str(&format!("- {}\n", url));
+        }
+        content.push('\n');
+    }
+
+    let total_links = summary.issues.len()
+        + summary.pull_requests.len()
+        + summary.commits.len()
+        + summary.file_permalinks.len()
+        + summary.other_github_links.len()
+        + summary.external_links.len();
+    if total_links == 0 {
+        content.push_str("(No links found)\n");
+    }
+
+    MarkdownContent(content)
+}
+
+fn link_summary_entry_line(entry: &LinkSummaryEntry) -> String {
+    match (&entry.title, &entry.detail) {
+        (Some(title), Some(detail)) => format!("- {} ({}) - {}\n", title, detail, entry.url),
+        (Some(title), None) => format!("- {} - {}\n", title, entry.url),
+        _ => format!("- (not found) - {}\n", entry.url),
+    }
+}
+
+/// Format the cross-reference timeline of an issue with timezone conversion
+///
+/// Lists each event with its timestamp, kind, and the linked issue or pull request
+/// including its state, followed by the pull requests that will close the issue.
+pub fn issue_timeline_markdown_with_timezone(
+    timeline: &IssueTimeline,
+    timezone: Option<&TimezoneOffset>,
+) -> MarkdownContent {
+    let mut content = issue_timeline_header(timeline);
+
+    if timeline.events.is_empty() {
+        content.push_str("(No timeline events)\n");
+        return MarkdownContent(content);
+    }
+
+    content.push_str("## Events\n");
+    for event in &timeline.events {
+        content.push_str(&format!(
+            "- {} **{}** [{}] {} - {}{}\n",
+            format_datetime_with_timezone_offset(event.created_at, timezone),
+            event.kind,
+            event.state,
+            event.title,
+            event.resource.url(),
+            will_close_marker(event)
+        ));
+    }
+
+    let closing: Vec<&TimelineEvent> = timeline
+        .events
+        .iter()
+        .filter(|event| event.will_close_target)
+        .collect();
+    if !closing.is_empty() {
+        content.push_str("\n## Will Close This Issue\n");
+        for event in closing {
+            content.push_str(&format!(
+                "- {} ({}) - {}\n",
+                event.title,
+                event.state,
+                event.resource.url()
+            ));
+        }
+    }
+
     MarkdownContent(content)
 }
+
+/// Format the cross-reference timeline of an issue in light format
+///
+/// Lists each event with its date, kind, and the linked issue or pull request.
+pub fn issue_timeline_markdown_with_timezone_light(
+    timeline: &IssueTimeline,
+    timezone: Option<&TimezoneOffset>,
+) -> MarkdownContent {
+    let mut content = issue_timeline_header(timeline);
+
+    if timeline.events.is_empty() {
+        content.push_str("(No timeline events)\n");
+        return MarkdownContent(content);
+    }
+
+    for event in &timeline.events {
+        content.push_str(&format!(
+            "- {} {} {} - {}{}\n",
+            format_date_with_timezone_offset(event.created_at, timezone),
+            event.kind,
+            event.title,
+            event.resource.url(),
+            will_close_marker(event)
+        ));
+    }
+
+    MarkdownContent(content)
+}
+
+fn issue_timeline_header(timeline: &IssueTimeline) -> String {
+    format!(
+        "# Timeline: {}\nurl: {}\n\n",
+        timeline.issue_title,
+        timeline.issue_id.url()
+    )
+}
+
+fn will_close_marker(event: &TimelineEvent) -> &'static str {
+    if event.will_close_target {
+        " (will close)"
+    } else {
+        ""
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{IssueComment, IssueCommentNumber, ReactionSummary, User};
+    use chrono::{TimeZone, Utc};
+
+    fn issue() -> Issue {
+        let mut issue = Issue::fixture("repo", 42, "Crash on startup");
+        issue.body = Some("Steps to reproduce".to_string());
+        issue
+    }
+
+    #[test]
+    fn test_issue_body_markdown_colors_state_only() {
+        let mut issue = issue();
+        issue.body = Some("status: OPEN".to_string());
+
+        let plain = issue_body_markdown_with_timezone(&issue, None);
+        let uncolored = issue_body_markdown_with_comment_range(
+            &issue,
+            None,
+            None,
+            None,
+            false,
+            &RenderOptions {
+                color: false,
+                ..Default::default()
+            },
+        );
+        assert_eq!(uncolored.0.as_bytes(), plain.0.as_bytes());
+
+        let colored = issue_body_markdown_with_comment_range(
+            &issue,
+            None,
+            None,
+            None,
+            false,
+            &RenderOptions {
+                color: true,
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            colored
+                .0
+                .replacen("status: \x1b[32mOPEN\x1b[0m\n", "status: OPEN\n", 1),
+            plain.0
+        );
+
+        let light = issue_body_markdown_light_with_body_truncation(
+            &issue,
+            None,
+            DEFAULT_LIGHT_BODY_LENGTH,
+            &RenderOptions {
+                color: true,
+                ..Default::default()
+            },
+        );
+        assert!(light.0.contains("**\x1b[32mOPEN\x1b[0m**\n"));
+    }
+
+    #[test]
+    fn test_issue_body_markdown_with_reactions() {
+        let reactions = ReactionSummary {
+            thumbs_up: 5,
+            hooray: 2,
+            eyes: 1,
+            ..Default::default()
+        };
+        let markdown = issue_body_markdown_with_timezone(&issue().with_reactions(reactions), None);
+
+        let reactions_line = markdown
+            .0
+            .lines()
+            .find(|line| line.starts_with("reactions:"))
+            .expect("Reactions line should be rendered");
+        assert_eq!(reactions_line, "reactions: 👍 5 🎉 2 👀 1");
+    }
+
+    #[test]
+    fn test_issue_body_markdown_reactions_omitted_or_none() {
+        let markdown = issue_body_markdown_with_timezone(&issue(), None);
+        assert!(!markdown.0.contains("reactions:"));
+
+        let markdown = issue_body_markdown_with_timezone(
+            &issue().with_reactions(ReactionSummary::default()),
+            None,
+        );
+        assert!(markdown.0.contains("reactions: none\n"));
+    }
+
+    #[test]
+    fn test_issue_body_markdown_light_lists_mentioned_resources() {
+        let mut issue = issue();
+        issue.body = Some(
+            "Same crash as https://github.com/owner/repo/issues/7, which was reported long before this one and still reproduces on the latest release. See also https://github.com/other/project/issues/12 and https://github.com/owner/repo/issues/7 again."
+                .to_string(),
+        );
+        issue.labels = vec!["bug".to_string(), "crash".to_string()];
+
+        let markdown = issue_body_markdown_with_timezone_light(&issue, None);
+
+        assert!(markdown.0.contains("**Labels:** bug, crash\n"));
+        assert!(markdown.0.contains(
+            "**Mentions:** https://github.com/owner/repo/issues/7,https://github.com/other/project/issues/12\n"
+        ));
+    }
+
+    fn issue_with_comments(count: u64) -> Issue {
+        let created_at = Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap();
+        let mut issue = issue();
+        issue.comments = (1..=count)
+            .map(|number| {
+                IssueComment::new(
+                    IssueCommentNumber::new(number),
+                    format!("comment body {}", number),
+                    None,
+                    created_at,
+                    created_at,
+                )
+            })
+            .collect();
+        issue
+    }
+
+    #[test]
+    fn test_issue_body_markdown_comment_range() {
+        let markdown = issue_body_markdown_with_comment_range(
+            &issue_with_comments(5),
+            None,
+            Some(1),
+            Some(2),
+            false,
+            &RenderOptions::default(),
+        );
+
+        assert!(!markdown.0.contains("comment body 1\n"));
+        assert!(markdown.0.contains("comment body 2\n"));
+        assert!(markdown.0.contains("comment body 3\n"));
+        assert!(!markdown.0.contains("comment body 4\n"));
+        assert!(
+            markdown
+                .0
+                .contains("(showing comments 2-3 of 5; 3 comment(s) omitted)\n")
+        );
+
+        let markdown = issue_body_markdown_with_timezone(&issue_with_comments(5), None);
+        assert!(markdown.0.contains("comment body 5\n"));
+        assert!(!markdown.0.contains("omitted"));
+    }
+
+    #[test]
+    fn test_issue_body_markdown_comment_skip_exceeds_total() {
+        let markdown = issue_body_markdown_with_comment_range(
+            &issue_with_comments(2),
+            None,
+            Some(5),
+            None,
+            false,
+            &RenderOptions::default(),
+        );
+
+        assert!(!markdown.0.contains("comment body"));
+        assert!(markdown.0.contains(
+            "(comment_skip 5 exceeds the 2 comment(s) of this issue; no comments shown)"
+        ));
+    }
+
+    #[test]
+    fn test_issue_body_markdown_excludes_bot_comments() {
+        let created_at = Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap();
+        let mut issue = issue();
+        issue.comments = vec![
+            IssueComment::new(
+                IssueCommentNumber::new(1),
+                "human feedback".to_string(),
+                Some(User::from("alice")),
+                created_at,
+                created_at,
+            ),
+            IssueComment::new(
+                IssueCommentNumber::new(2),
+                "bumped dependency".to_string(),
+                Some(User::from("dependabot[bot]")),
+                created_at,
+                created_at,
+            ),
+            IssueComment {
+                author_is_bot: true,
+                ..IssueComment::new(
+                    IssueCommentNumber::new(3),
+                    "CI passed".to_string(),
+                    Some(User::from("ci-app")),
+                    created_at,
+                    created_at,
+                )
+            },
+        ];
+
+        let markdown = issue_body_markdown_with_comment_range(
+            &issue,
+            None,
+            None,
+            None,
+            true,
+            &RenderOptions::default(),
+        );
+        assert!(markdown.0.contains("human feedback"));
+        assert!(!markdown.0.contains("bumped dependency"));
+        assert!(!markdown.0.contains("CI passed"));
+        assert!(markdown.0.contains("(2 bot comment(s) hidden)\n"));
+
+        let markdown = issue_body_markdown_with_timezone(&issue, None);
+        assert!(markdown.0.contains("bumped dependency"));
+        assert!(markdown.0.contains("CI passed"));
+        assert!(!markdown.0.contains("hidden"));
+    }
+
+    #[test]
+    fn test_issue_light_body_truncates_cjk_on_char_boundary() {
+        let mut issue = issue();
+        issue.body = Some("起動時にクラッシュします。再現手順を以下に示します。".to_string());
+
+        let markdown = issue_body_markdown_light_with_body_truncation(
+            &issue,
+            None,
+            5,
+            &RenderOptions::default(),
+        );
+        assert!(markdown.0.contains("\n起動時にク...\n\n"));
+        assert!(!markdown.0.contains("起動時にクラ"));
+
+        // A length at or beyond the body keeps it whole
+        let markdown = issue_body_markdown_light_with_body_truncation(
+            &issue,
+            None,
+            26,
+            &RenderOptions::default(),
+        );
+        assert!(
+            markdown
+                .0
+                .contains("起動時にクラッシュします。再現手順を以下に示します。\n\n")
+        );
+        assert!(!markdown.0.contains("..."));
+
+        let markdown = issue_body_markdown_light_with_body_truncation(
+            &issue,
+            None,
+            0,
+            &RenderOptions::default(),
+        );
+        assert!(!markdown.0.contains("起動"));
+        assert!(markdown.0.contains("**Comments:** 0"));
+    }
+}
@@ -1,26 +1,42 @@
+pub mod auth;
+pub mod csv;
+pub mod discussion;
+pub mod html;
 pub mod issue;
+pub mod profile;
 pub mod project;
 pub mod project_resource;
 pub mod pull_request;
 pub mod pull_request_diff;
 pub mod pull_request_diff_contents;
 pub mod pull_request_file_stats;
+pub mod rate_limit;
 pub mod repository;
 pub mod repository_branch_group;
+pub mod search;
+pub mod user_activity;
 
 use chrono::{DateTime, FixedOffset, Local, Utc};
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumIter, EnumString};
 
+pub use auth::*;
+pub use csv::*;
+pub use discussion::*;
+pub use html::*;
 pub use issue::*;
+pub use profile::*;
 pub use project::*;
 pub use project_resource::*;
 pub use pull_request::*;
 pub use pull_request_diff::*;
 pub use pull_request_diff_contents::*;
 pub use pull_request_file_stats::*;
+pub use rate_limit::*;
 pub use repository::*;
 pub use repository_branch_group::*;
+pub use search::*;
+pub use user_activity::*;
 
 /// Common timezone abbreviations with their UTC offsets
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumString, Display, EnumIter)]
@@ -39,6 +55,14 @@ pub enum TimezoneAbbreviation {
     Pdt,
     #[strum(serialize = "BST")]
     Bst,
+    #[strum(serialize = "CET")]
+    Cet,
+    #[strum(serialize = "CEST")]
+    Cest,
+    #[strum(serialize = "IST")]
+    Ist,
+    #[strum(serialize = "AEST")]
+    Aest,
 }
 
 impl TimezoneAbbreviation {
@@ -50,13 +74,19 @@ impl TimezoneAbbreviation {
             Self::Est => -5,
             Self::Pst => -8,
             Self::Pdt => -7,
-            Self::Bst => 1,
+            Self::Bst | Self::Cet => 1,
+            Self::Cest => 2,
+            Self::Ist => 5,
+            Self::Aest => 10,
         }
     }
 
     /// Get the offset minutes for this timezone abbreviation (usually 0)
     pub fn offset_minutes(&self) -> i32 {
-        0 // All supported abbreviations are on hour boundaries
+        match self {
+            Self::Ist => 30,
+            _ => 0,
+        }
     }
 
     /// Create a TimezoneOffset from this abbreviation
@@ -75,7 +105,7 @@ pub struct MarkdownContent(pub String);
 /// during `cargo test` execution due to extensive timezone data loading).
 ///
 /// This lightweight implementation supports:
-/// - Common timezone abbreviations (UTC, JST, EST, PST, PDT, BST, GMT)
+/// - Common timezone abbreviations (UTC, JST, EST, PST, PDT, BST, GMT, CET, CEST, IST, AEST)
 /// - Offset format strings like "+09:00", "-05:30"
 /// - Conversion to chrono's FixedOffset for datetime calculations
 ///
@@ -167,6 +197,152 @@ impl std::fmt::Display for TimezoneOffset {
     }
 }
 
+/// Presentation options of markdown output beyond the timezone
+///
+/// The default renders plain markdown, as the MCP tools return it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// Color issue and pull request states with ANSI codes (green OPEN, red CLOSED,
+    /// purple MERGED), for markdown written to a terminal
+    pub color: bool,
+    /// Group the digits of counts with commas, e.g. `1,234,567`
+    pub thousands_separator: bool,
+    /// Render issue, pull request, and comment timestamps relative to now, e.g.
+    /// "3 days ago", instead of absolute in the timezone
+    pub relative_time: bool,
+}
+
+const ANSI_GREEN: &str = "\x1b[32m";
+const ANSI_RED: &str = "\x1b[31m";
+const ANSI_PURPLE: &str = "\x1b[35m";
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// Render an issue or pull request state, colored when `options.color` is set
+fn state_text(state: impl std::fmt::Display, options: &RenderOptions) -> String {
+    let state = state.to_string();
+    let ansi = match state.as_str() {
+        _ if !options.color => return state,
+        "OPEN" => ANSI_GREEN,
+        "CLOSED" => ANSI_RED,
+        "MERGED" => ANSI_PURPLE,
+        _ => return state,
+    };
+    format!("{}{}{}", ansi, state, ANSI_RESET)
+}
+
+/// Maximum number of issues and pull requests mentioned in a body to list in light format
+const MAX_LIGHT_MENTIONS: usize = 10;
+
+/// Default number of characters of an issue or pull request body shown in light format
+pub const DEFAULT_LIGHT_BODY_LENGTH: usize = 100;
+
+/// Append a body truncated to `max_chars` characters, for light format
+///
+/// Characters rather than bytes are counted, so multibyte UTF-8 text is never split.
+/// A truncated body ends with `...`, and `max_chars` of 0 omits the body.
+fn push_light_body(content: &mut String, body: &str, max_chars: usize) {
+    if max_chars == 0 {
+        return;
+    }
+    match body.char_indices().nth(max_chars) {
+        Some((end, _)) => {
+            content.push_str(&body[..end]);
+            content.push_str("...\n\n");
+        }
+        None => {
+            content.push_str(body);
+            content.push_str("\n\n");
+        }
+    }
+}
+
+/// URLs of the issues and pull requests mentioned in a body, for light format
+///
+/// The resource itself and resources already in `linked_resources` are skipped. At most
+/// `MAX_LIGHT_MENTIONS` URLs are returned, in order of first mention.
+fn mentioned_resource_urls(
+    body: &str,
+    own_id: &crate::types::IssueOrPullrequestId,
+    linked_resources: &[crate::types::IssueOrPullrequestId],
+) -> Vec<String> {
+    let mut mentioned: Vec<crate::types::IssueOrPullrequestId> = Vec::new();
+    for id in crate::types::IssueOrPullrequestId::extract_resource_url_from_text(body) {
+        if &id != own_id && !linked_resources.contains(&id) && !mentioned.contains(&id) {
+            mentioned.push(id);
+        }
+    }
+    mentioned
+        .iter()
+        .take(MAX_LIGHT_MENTIONS)
+        .map(|id| id.url())
+        .collect()
+}
+
+/// Format a count with comma thousands separators, e.g. `1234567` as `1,234,567`
+pub fn format_count(n: u64) -> String {
+    let digits = n.to_string();
+    let mut formatted = String::with_capacity(digits.len() + digits.len() / 3);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index) % 3 == 0 {
+            formatted.push(',');
+        }
+        formatted.push(digit);
+    }
+    formatted
+}
+
+/// Format a count, grouped only when `options.thousands_separator` is set
+fn display_count(n: impl Into<u64>, options: &RenderOptions) -> String {
+    let n = n.into();
+    if options.thousands_separator {
+        format_count(n)
+    } else {
+        n.to_string()
+    }
+}
+
+/// Render the non-zero reaction counts, e.g. "👍 3 🎉 1", or "none"
+fn reactions_text(reactions: &crate::types::ReactionSummary) -> String {
+    if reactions.total() == 0 {
+        return "none".to_string();
+    }
+
+    reactions
+        .counts()
+        .iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(emoji, count)| format!("{} {}", emoji, count))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Format how long before or after `now` a datetime is, e.g. "3 days ago" or "in 2 hours"
+///
+/// Differences under a minute render as "just now". Months count as 30 days and years
+/// as 365 days.
+pub fn format_relative_time(dt: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let seconds = (now - dt).num_seconds();
+    let elapsed = seconds.unsigned_abs();
+    if elapsed < 60 {
+        return "just now".to_string();
+    }
+
+    let (amount, unit) = match elapsed {
+        s if s < 3_600 => (s / 60, "minute"),
+        s if s < 86_400 => (s / 3_600, "hour"),
+        s if s < 30 * 86_400 => (s / 86_400, "day"),
+        s if s < 365 * 86_400 => (s / (30 * 86_400), "month"),
+        s => (s / (365 * 86_400), "year"),
+    };
+    let span = format!("{} {}{}", amount, unit, if amount == 1 { "" } else { "s" });
+
+    if seconds >= 0 {
+        format!("{} ago", span)
+    } else {
+        format!("in {}", span)
+    }
+}
+
 /// Format a UTC datetime with the specified timezone offset.
 /// If timezone is None, defaults to UTC.
 pub fn format_datetime_with_timezone_offset(
@@ -184,6 +360,20 @@ pub fn format_datetime_with_timezone_offset(
     }
 }
 
+/// Format an issue, pull request, or comment timestamp, relative to now when
+/// `options.relative_time` is set
+fn timestamp_text(
+    dt: DateTime<Utc>,
+    timezone: Option<&TimezoneOffset>,
+    options: &RenderOptions,
+) -> String {
+    if options.relative_time {
+        format_relative_time(dt, Utc::now())
+    } else {
+        format_datetime_with_timezone_offset(dt, timezone)
+    }
+}
+
 /// Format a UTC date with the specified timezone offset (date only, no time).
 /// If timezone is None, defaults to UTC.
 pub fn format_date_with_timezone_offset(
@@ -200,3 +390,87 @@ pub fn format_date_with_timezone_offset(
         None => dt.format("%Y-%m-%d UTC").to_string(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_parse_half_hour_abbreviation() {
+        let ist = TimezoneOffset::parse("IST").unwrap();
+        assert_eq!(ist.offset_seconds, 19800);
+        assert_eq!(ist.name, "IST");
+    }
+
+    #[test]
+    fn test_parse_european_and_asia_pacific_abbreviations() {
+        assert_eq!(TimezoneOffset::parse("CET").unwrap().offset_seconds, 3600);
+        assert_eq!(TimezoneOffset::parse("CEST").unwrap().offset_seconds, 7200);
+        assert_eq!(TimezoneOffset::parse("AEST").unwrap().offset_seconds, 36000);
+    }
+
+    #[test]
+    fn test_format_relative_time_boundaries() {
+        let now = Utc::now();
+        let ago =
+            |seconds: i64| format_relative_time(now - chrono::Duration::seconds(seconds), now);
+
+        assert_eq!(ago(0), "just now");
+        assert_eq!(ago(59), "just now");
+        assert_eq!(ago(60), "1 minute ago");
+        assert_eq!(ago(59 * 60 + 59), "59 minutes ago");
+        assert_eq!(ago(3_600), "1 hour ago");
+        assert_eq!(ago(23 * 3_600), "23 hours ago");
+        assert_eq!(ago(86_400), "1 day ago");
+        assert_eq!(ago(29 * 86_400), "29 days ago");
+        assert_eq!(ago(30 * 86_400), "1 month ago");
+        assert_eq!(ago(364 * 86_400), "12 months ago");
+        assert_eq!(ago(365 * 86_400), "1 year ago");
+        assert_eq!(ago(3 * 365 * 86_400), "3 years ago");
+    }
+
+    #[test]
+    fn test_format_relative_time_future() {
+        let now = Utc::now();
+        let ahead =
+            |seconds: i64| format_relative_time(now + chrono::Duration::seconds(seconds), now);
+
+        assert_eq!(ahead(30), "just now");
+        assert_eq!(ahead(2 * 3_600), "in 2 hours");
+        assert_eq!(ahead(86_400), "in 1 day");
+    }
+
+    #[test]
+    fn test_format_count_groups_thousands() {
+        assert_eq!(format_count(0), "0");
+        assert_eq!(format_count(999), "999");
+        assert_eq!(format_count(1000), "1,000");
+        assert_eq!(format_count(1234567), "1,234,567");
+    }
+
+    #[test]
+    fn test_timestamp_text_relative_only_when_requested() {
+        let jst = TimezoneOffset::parse("JST").unwrap();
+        let dt = Utc::now() - chrono::Duration::days(3);
+        let relative = RenderOptions {
+            relative_time: true,
+            ..Default::default()
+        };
+
+        assert_eq!(timestamp_text(dt, Some(&jst), &relative), "3 days ago");
+        assert!(timestamp_text(dt, Some(&jst), &RenderOptions::default()).ends_with(" JST"));
+        // Timestamps outside issues, pull requests, and comments stay absolute
+        assert!(format_datetime_with_timezone_offset(dt, Some(&jst)).ends_with(" JST"));
+    }
+
+    #[test]
+    fn test_abbreviation_round_trips_through_display() {
+        for tz_abbr in TimezoneAbbreviation::iter() {
+            assert_eq!(
+                tz_abbr.to_string().parse::<TimezoneAbbreviation>().unwrap(),
+                tz_abbr
+            );
+        }
+    }
+}
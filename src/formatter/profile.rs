@@ -0,0 +1,107 @@
+use crate::formatter::MarkdownContent;
+use crate::types::{ProfileValidationReport, RegistrationStatus};
+
+/// A report section title and the statuses listed under it
+type StatusSection = (&'static str, fn(&RegistrationStatus) -> bool);
+
+/// Format a profile validation report as markdown
+///
+/// Registered resources are grouped into valid, not found, and access denied
+/// sections. A section for other failures is added only when a fetch failed for
+/// another reason.
+pub fn profile_validation_report_markdown(report: &ProfileValidationReport) -> MarkdownContent {
+    let mut content = String::new();
+
+    content.push_str(&format!(
+        "# Validation of profile '{}'\n\n",
+        report.profile_name
+    ));
+
+    if report.repositories.is_empty() && report.projects.is_empty() {
+        content.push_str("(No repositories or projects registered in this profile)\n");
+        return MarkdownContent(content);
+    }
+
+    let sections: [StatusSection; 3] = [
+        ("Valid", |status| *status == RegistrationStatus::Valid),
+        ("Not Found", |status| {
+            *status == RegistrationStatus::NotFound
+        }),
+        ("Access Denied", |status| {
+            *status == RegistrationStatus::AccessDenied
+        }),
+    ];
+    for (title, matches) in sections {
+        let urls: Vec<String> = report
+            .entries()
+            .filter(|(_, status)| matches(status))
+            .map(|(url, _)| url)
+            .collect();
+        content.push_str(&format!("## {} ({})\n", title, urls.len()));
+        for url in urls {
+            content.push_str(&format!("- {}\n", url));
+        }
+        content.push('\n');
+    }
+
+    let failures: Vec<(String, &String)> = report
+        .entries()
+        .filter_map(|(url, status)| match status {
+            RegistrationStatus::Failed(message) => Some((url, message)),
+            _ => None,
+        })
+        .collect();
+    if !failures.is_empty() {
+        content.push_str(&format!("## Failed ({})\n", failures.len()));
+        for (url, message) in failures {
+            content.push_str(&format!("- {}: {}\n", url, message));
+        }
+        content.push('\n');
+    }
+
+    MarkdownContent(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Owner, ProfileName, ProjectId, ProjectNumber, ProjectType, RepositoryId};
+
+    #[test]
+    fn test_profile_validation_report_markdown_groups_entries() {
+        let report = ProfileValidationReport {
+            profile_name: ProfileName::from("work"),
+            repositories: vec![
+                (
+                    RepositoryId::new("owner", "alive"),
+                    RegistrationStatus::Valid,
+                ),
+                (
+                    RepositoryId::new("owner", "gone"),
+                    RegistrationStatus::NotFound,
+                ),
+                (
+                    RepositoryId::new("owner", "flaky"),
+                    RegistrationStatus::Failed("timeout".to_string()),
+                ),
+            ],
+            projects: vec![(
+                ProjectId::new(
+                    Owner::new("org".to_string()),
+                    ProjectNumber::new(5),
+                    ProjectType::Organization,
+                ),
+                RegistrationStatus::AccessDenied,
+            )],
+        };
+
+        let markdown = profile_validation_report_markdown(&report).0;
+
+        assert!(markdown.contains("## Valid (1)\n- https://github.com/owner/alive\n"));
+        assert!(markdown.contains("## Not Found (1)\n- https://github.com/owner/gone\n"));
+        assert!(
+            markdown.contains("## Access Denied (1)\n- https://github.com/orgs/org/projects/5\n")
+        );
+        assert!(markdown.contains("## Failed (1)\n- https://github.com/owner/flaky: timeout\n"));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::formatter::{MarkdownContent, TimezoneOffset, format_datetime_with_timezone_offset};
-use crate::types::Project;
+use crate::types::{Project, ProjectField, ProjectRepositories, ProjectResource, ProjectRoadmap};
 
 pub fn project_body_markdown(project: &Project) -> MarkdownContent {
     project_body_markdown_with_timezone(project, None)
@@ -41,3 +41,124 @@ pub fn project_body_markdown_with_timezone(
 
     MarkdownContent(content)
 }
+
+/// Format the source repositories of a project as a markdown table
+pub fn project_repositories_markdown(
+    project_url: &str,
+    project_repositories: &ProjectRepositories,
+) -> MarkdownContent {
+    let mut content = String::new();
+
+    content.push_str(&format!("# Repositories of {}\n\n", project_url));
+
+    if project_repositories.repositories.is_empty() {
+        content.push_str("(No repository items in this project)\n");
+    } else {
+        content.push_str("| Repository | Items |\n");
+        content.push_str("|---|---|\n");
+        for repository in &project_repositories.repositories {
+            content.push_str(&format!(
+                "| {} | {} |\n",
+                repository.repository_id.url(),
+                repository.item_count
+            ));
+        }
+    }
+
+    if project_repositories.draft_issue_count > 0 {
+        content.push_str(&format!(
+            "\nDraft issues (no repository): {}\n",
+            project_repositories.draft_issue_count
+        ));
+    }
+
+    MarkdownContent(content)
+}
+
+/// Format the field definitions of a project as a markdown table
+///
+/// Options of single select fields are listed as `name (option id)` so they can be
+/// used in field value updates.
+pub fn project_field_definitions_markdown(
+    project_url: &str,
+    fields: &[ProjectField],
+) -> MarkdownContent {
+    let mut content = String::new();
+
+    content.push_str(&format!("# Fields of {}\n\n", project_url));
+
+    if fields.is_empty() {
+        content.push_str("(No fields in this project)\n");
+        return MarkdownContent(content);
+    }
+
+    content.push_str("| Field | Field ID | Data Type | Options |\n");
+    content.push_str("|---|---|---|---|\n");
+    for field in fields {
+        let options = field
+            .options
+            .iter()
+            .map(|option| format!("{} ({})", option.name, option.option_id))
+            .collect::<Vec<_>>()
+            .join(", ");
+        content.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            field.field_name, field.field_id, field.data_type, options
+        ));
+    }
+
+    MarkdownContent(content)
+}
+
+/// Format a project roadmap as markdown, one section per iteration or month
+///
+/// Items without a value for the grouping field are listed last under "Unscheduled".
+pub fn project_roadmap_markdown(project_url: &str, roadmap: &ProjectRoadmap) -> MarkdownContent {
+    let mut content = String::new();
+
+    content.push_str(&format!(
+        "# Roadmap of {} by {}\n\n",
+        project_url, roadmap.field_name
+    ));
+
+    if roadmap.buckets.is_empty() && roadmap.unscheduled.is_empty() {
+        content.push_str("(No items in this project)\n");
+        return MarkdownContent(content);
+    }
+
+    for bucket in &roadmap.buckets {
+        content.push_str(&format!(
+            "## {} (from {}, {} items)\n",
+            bucket.title,
+            bucket.start_date.format("%Y-%m-%d"),
+            bucket.items.len()
+        ));
+        push_roadmap_items(&mut content, &bucket.items);
+        content.push('\n');
+    }
+
+    if !roadmap.unscheduled.is_empty() {
+        content.push_str(&format!(
+            "## Unscheduled ({} items)\n",
+            roadmap.unscheduled.len()
+        ));
+        push_roadmap_items(&mut content, &roadmap.unscheduled);
+    }
+
+    MarkdownContent(content)
+}
+
+fn push_roadmap_items(content: &mut String, items: &[ProjectResource]) {
+    for item in items {
+        let title = item.title.as_deref().unwrap_or("(untitled)");
+        let status = item
+            .column_name
+            .as_deref()
+            .map(|column_name| format!(" [{}]", column_name))
+            .unwrap_or_default();
+        match item.content_url() {
+            Some(url) => content.push_str(&format!("- {} {}{}\n", title, url, status)),
+            None => content.push_str(&format!("- {} (draft){}\n", title, status)),
+        }
+    }
+}
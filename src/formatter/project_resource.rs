@@ -92,6 +92,15 @@ pub fn project_resource_body_markdown_with_timezone(
                         values.join(", ")
                     ));
                 }
+                crate::types::project::ProjectFieldValue::Iteration(iteration) => {
+                    content.push_str(&format!(
+                        "- {}: {} (type: Iteration, starts {}, {} days)\n",
+                        custom_field.field_name,
+                        iteration.title,
+                        iteration.start_date.format("%Y-%m-%d"),
+                        iteration.duration_days
+                    ));
+                }
             }
             content.push('\n');
         }
@@ -1,14 +1,30 @@
-use crate::types::PullRequest;
+use crate::types::{
+    CheckConclusion, IssueOrPullrequestId, PullRequest, PullRequestChecks, PullRequestCommits,
+    PullRequestReviews,
+};
 
-use super::{MarkdownContent, TimezoneOffset, format_datetime_with_timezone_offset};
-
-/// Maximum number of characters to display in the body of a pull request in light format
-const MAX_BODY_LENGTH: usize = 100;
+use super::{
+    DEFAULT_LIGHT_BODY_LENGTH, MarkdownContent, RenderOptions, TimezoneOffset, display_count,
+    format_datetime_with_timezone_offset, mentioned_resource_urls, push_light_body, reactions_text,
+    state_text, timestamp_text,
+};
 
 /// Format a pull request into markdown with timezone conversion
 pub fn pull_request_body_markdown_with_timezone(
     pr: &PullRequest,
     timezone: Option<&TimezoneOffset>,
+) -> MarkdownContent {
+    pull_request_body_markdown_with_comment_filter(pr, timezone, false, &RenderOptions::default())
+}
+
+/// Format a pull request into markdown, optionally dropping comments written by bots
+///
+/// With `exclude_bot_comments`, a note says how many bot comments were hidden.
+pub fn pull_request_body_markdown_with_comment_filter(
+    pr: &PullRequest,
+    timezone: Option<&TimezoneOffset>,
+    exclude_bot_comments: bool,
+    options: &RenderOptions,
 ) -> MarkdownContent {
     let mut content = String::new();
 
@@ -19,7 +35,11 @@ pub fn pull_request_body_markdown_with_timezone(
         None => "Unknown ⚠️".to_string(),
     };
     content.push_str(&format!("author: {}\n", author_display));
-    content.push_str(&format!("status: {}\n", pr.state));
+    content.push_str(&format!(
+        "status: {}{}\n",
+        state_text(pr.state, options),
+        draft_badge(pr)
+    ));
     content.push_str(&format!("url: {}\n", pr.pull_request_id.url()));
     content.push_str(&format!(
         "Repository Url: {}\n",
@@ -29,24 +49,27 @@ pub fn pull_request_body_markdown_with_timezone(
     // Date information
     content.push_str(&format!(
         "created: {}\n",
-        format_datetime_with_timezone_offset(pr.created_at, timezone)
+        timestamp_text(pr.created_at, timezone, options)
     ));
     content.push_str(&format!(
         "updated: {}\n",
-        format_datetime_with_timezone_offset(pr.updated_at, timezone)
+        timestamp_text(pr.updated_at, timezone, options)
     ));
     if let Some(closed_at) = pr.closed_at {
         content.push_str(&format!(
             "closed: {}\n",
-            format_datetime_with_timezone_offset(closed_at, timezone)
+            timestamp_text(closed_at, timezone, options)
         ));
     }
     if let Some(merged_at) = pr.merged_at {
         content.push_str(&format!(
             "merged: {}\n",
-            format_datetime_with_timezone_offset(merged_at, timezone)
+            timestamp_text(merged_at, timezone, options)
         ));
     }
+    if let Some(reactions) = &pr.reactions {
+        content.push_str(&format!("reactions: {}\n", reactions_text(reactions)));
+    }
     content.push('\n');
 
     // Linked resources (Issues and Pull Requests)
@@ -92,16 +115,21 @@ pub fn pull_request_body_markdown_with_timezone(
     }
 
     // Reviewers (HIGH priority)
-    if !pr.reviewers.is_empty() {
+    if !pr.reviewers.is_empty() || pr.review_decision.is_some() {
         content.push_str("## reviewers\n");
-        content.push_str(&format!(
-            "- Reviewed by: {}\n",
-            pr.reviewers
-                .iter()
-                .map(|u| u.as_str())
-                .collect::<Vec<_>>()
-                .join(", ")
-        ));
+        if let Some(review_decision) = &pr.review_decision {
+            content.push_str(&format!("- Review decision: {}\n", review_decision));
+        }
+        if !pr.reviewers.is_empty() {
+            content.push_str(&format!(
+                "- Reviewed by: {}\n",
+                pr.reviewers
+                    .iter()
+                    .map(|u| u.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
         content.push('\n');
     }
 
@@ -110,20 +138,14 @@ pub fn pull_request_body_markdown_with_timezone(
         content.push_str("## stats\n");
         content.push_str(&format!(
             "- Changes: +{} -{} files:{} commits:{}\n",
-            pr.additions, pr.deletions, pr.changed_files, pr.commits_count
+            display_count(pr.additions, options),
+            display_count(pr.deletions, options),
+            display_count(pr.changed_files, options),
+            display_count(pr.commits_count, options)
         ));
         content.push('\n');
     }
 
-    // Flags (HIGH priority)
-    if pr.draft {
-        content.push_str("## flags\n");
-        if pr.draft {
-            content.push_str("- Status: DRAFT\n");
-        }
-        content.push('\n');
-    }
-
     // Milestone (LOW priority)
     if let Some(milestone_id) = &pr.milestone_id {
         content.push_str("## milestone\n");
@@ -140,8 +162,20 @@ pub fn pull_request_body_markdown_with_timezone(
 
     // Comments
     content.push_str("## comments\n");
-    if !pr.comments.is_empty() {
-        for comment in &pr.comments {
+    let comments: Vec<_> = pr
+        .comments
+        .iter()
+        .filter(|comment| !(exclude_bot_comments && comment.author_is_bot))
+        .collect();
+    let hidden_bot_comments = pr.comments.len() - comments.len();
+    if hidden_bot_comments > 0 {
+        content.push_str(&format!(
+            "({} bot comment(s) hidden)\n",
+            hidden_bot_comments
+        ));
+    }
+    if !comments.is_empty() {
+        for comment in comments {
             let author_display = match &comment.author {
                 Some(user) => user.as_str().to_string(),
                 None => "Unknown ⚠️".to_string(),
@@ -149,11 +183,11 @@ pub fn pull_request_body_markdown_with_timezone(
             content.push_str(&format!("### author: {}\n", author_display));
             content.push_str(&format!(
                 "created: {}\n",
-                format_datetime_with_timezone_offset(comment.created_at, timezone)
+                timestamp_text(comment.created_at, timezone, options)
             ));
             content.push_str(&format!(
                 "updated: {}\n",
-                format_datetime_with_timezone_offset(comment.updated_at, timezone)
+                timestamp_text(comment.updated_at, timezone, options)
             ));
             content.push_str(&format!("\n{}\n\n", comment.body));
         }
@@ -182,11 +216,11 @@ pub fn pull_request_body_markdown_with_timezone(
             content.push_str(&format!("author: {}\n", author_display));
             content.push_str(&format!(
                 "created: {}\n",
-                format_datetime_with_timezone_offset(review_comment.created_at, timezone)
+                timestamp_text(review_comment.created_at, timezone, options)
             ));
             content.push_str(&format!(
                 "updated: {}\n",
-                format_datetime_with_timezone_offset(review_comment.updated_at, timezone)
+                timestamp_text(review_comment.updated_at, timezone, options)
             ));
 
             // Status
@@ -216,30 +250,56 @@ pub fn pull_request_body_markdown_with_timezone(
 }
 
 pub fn pull_request_body_markdown_with_timezone_light(
+    pr: &PullRequest,
+    timezone: Option<&TimezoneOffset>,
+) -> MarkdownContent {
+    pull_request_body_markdown_light_with_body_truncation(
+        pr,
+        timezone,
+        DEFAULT_LIGHT_BODY_LENGTH,
+        &RenderOptions::default(),
+    )
+}
+
+/// Format a pull request in light format with the body cut to `body_truncate_len` characters
+///
+/// A `body_truncate_len` of 0 omits the body.
+pub fn pull_request_body_markdown_light_with_body_truncation(
     pr: &PullRequest,
     _timezone: Option<&TimezoneOffset>,
+    body_truncate_len: usize,
+    options: &RenderOptions,
 ) -> MarkdownContent {
     let mut content = String::new();
 
     // Lightweight header - title and status only
     content.push_str(&format!("# {}\n", pr.title));
-    content.push_str(&format!("**{}**\n", pr.state));
+    content.push_str(&format!(
+        "**{}**{}\n",
+        state_text(pr.state, options),
+        draft_badge(pr)
+    ));
     content.push_str(&format!("**URL:** {}\n\n", pr.pull_request_id.url()));
     // Author
     if let Some(author) = &pr.author {
         content.push_str(&format!("**Author:** `{}`\n", author));
     }
 
-    // Body only if present, truncated to MAX_BODY_LENGTH characters
+    // Assignees
+    if !pr.assignees.is_empty() {
+        let assignees: Vec<String> = pr.assignees.iter().map(|a| format!("`{}`", a)).collect();
+        content.push_str(&format!("**Assignees:** {}\n", assignees.join(" ")));
+    }
+
+    // Labels
+    if !pr.labels.is_empty() {
+        let labels: Vec<&str> = pr.labels.iter().map(|label| label.name()).collect();
+        content.push_str(&format!("**Labels:** {}\n", labels.join(", ")));
+    }
+
+    // Body only if present, truncated to body_truncate_len characters
     if let Some(body) = &pr.body {
-        if body.chars().count() > MAX_BODY_LENGTH {
-            let truncated: String = body.chars().take(MAX_BODY_LENGTH).collect();
-            content.push_str(&truncated);
-            content.push_str("...\n\n");
-        } else {
-            content.push_str(body);
-            content.push_str("\n\n");
-        }
+        push_light_body(&mut content, body, body_truncate_len);
     }
 
     // Comment count
@@ -257,5 +317,335 @@ pub fn pull_request_body_markdown_with_timezone_light(
         content.push_str(&format!("**Linked:** {}\n", urls.join(",")));
     }
 
+    // Issues and pull requests mentioned in the body
+    if let Some(body) = &pr.body {
+        let mentioned = mentioned_resource_urls(
+            body,
+            &IssueOrPullrequestId::PullrequestId(pr.pull_request_id.clone()),
+            &pr.linked_resources,
+        );
+        if !mentioned.is_empty() {
+            content.push_str(&format!("**Mentions:** {}\n", mentioned.join(",")));
+        }
+    }
+
     MarkdownContent(content)
 }
+
+/// Format pull requests as a light markdown table for review-queue listings
+pub fn pull_request_list_table_markdown(
+    prs: &[PullRequest],
+    timezone: Option<&TimezoneOffset>,
+) -> MarkdownContent {
+    let mut content = String::new();
+
+    content.push_str("| PR | Title | Author | Draft | Review | Requested reviewers | Updated |\n");
+    content.push_str("|---|---|---|---|---|---|---|\n");
+
+    for pr in prs {
+        let author = pr
+            .author
+            .as_ref()
+            .map(|user| user.as_str().to_string())
+            .unwrap_or_else(|| "Unknown".to_string());
+        let review = pr
+            .review_decision
+            .map(|decision| decision.to_string())
+            .unwrap_or_else(|| "-".to_string());
+        let requested_reviewers = if pr.requested_reviewers.is_empty() {
+            "-".to_string()
+        } else {
+            pr.requested_reviewers
+                .iter()
+                .map(|u| u.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        content.push_str(&format!(
+            "| [#{}]({}) | {} | {} | {} | {} | {} | {} |\n",
+            pr.pull_request_id.number,
+            pr.pull_request_id.url(),
+            pr.title.replace('|', "\\|"),
+            author,
+            if pr.draft { "yes" } else { "no" },
+            review,
+            requested_reviewers,
+            format_datetime_with_timezone_offset(pr.updated_at, timezone)
+        ));
+    }
+
+    MarkdownContent(content)
+}
+
+/// Format the reviews of a pull request into markdown with timezone conversion
+///
+/// Lists each review with its submission time, state, reviewer, and body, preceded
+/// by the number of approving reviews.
+pub fn pull_request_reviews_markdown_with_timezone(
+    pr_reviews: &PullRequestReviews,
+    timezone: Option<&TimezoneOffset>,
+) -> MarkdownContent {
+    let mut content = format!(
+        "# Reviews: {}\napprovals: {}\n\n",
+        pr_reviews.pull_request_id.url(),
+        pr_reviews.approval_count()
+    );
+
+    if pr_reviews.reviews.is_empty() {
+        content.push_str("(No reviews)\n");
+        return MarkdownContent(content);
+    }
+
+    for review in &pr_reviews.reviews {
+        let reviewer = review
+            .reviewer
+            .as_ref()
+            .map(|user| user.as_str().to_string())
+            .unwrap_or_else(|| "Unknown".to_string());
+        let submitted_at = review
+            .submitted_at
+            .map(|dt| format_datetime_with_timezone_offset(dt, timezone))
+            .unwrap_or_else(|| "not submitted".to_string());
+        content.push_str(&format!(
+            "- {} **{}** by {}\n",
+            submitted_at, review.state, reviewer
+        ));
+        if !review.body.trim().is_empty() {
+            for line in review.body.trim().lines() {
+                content.push_str(&format!("  > {}\n", line));
+            }
+        }
+    }
+
+    MarkdownContent(content)
+}
+
+/// Format the commits of a pull request as a markdown list
+///
+/// Each commit is shown with its short SHA, the first line of its message, its
+/// author, and its authored date.
+pub fn pull_request_commits_markdown_with_timezone(
+    pr_commits: &PullRequestCommits,
+    timezone: Option<&TimezoneOffset>,
+) -> MarkdownContent {
+    let mut content = format!(
+        "# Commits: {}\ncommits: {}\n\n",
+        pr_commits.pull_request_id.url(),
+        pr_commits.commits.len()
+    );
+
+    if pr_commits.commits.is_empty() {
+        content.push_str("(No commits)\n");
+        return MarkdownContent(content);
+    }
+
+    for commit in &pr_commits.commits {
+        let authored_at = commit
+            .authored_at
+            .map(|dt| format_datetime_with_timezone_offset(dt, timezone))
+            .unwrap_or_else(|| "unknown date".to_string());
+        content.push_str(&format!(
+            "- `{}` {} ({}, {})\n",
+            commit.short_sha(),
+            commit.message_headline(),
+            commit.author.as_deref().unwrap_or("Unknown"),
+            authored_at
+        ));
+    }
+
+    MarkdownContent(content)
+}
+
+/// Format the CI checks of a pull request's head commit as a markdown checklist
+///
+/// Passed checks are ticked; every check is prefixed with a symbol for its conclusion
+/// and linked to its details page when available.
+pub fn pull_request_checks_markdown(pr_checks: &PullRequestChecks) -> MarkdownContent {
+    let mut content = format!("# Checks: {}\n", pr_checks.pull_request_id.url());
+    if let Some(head_sha) = &pr_checks.head_sha {
+        content.push_str(&format!(
+            "head: `{}`\n",
+            head_sha.get(..7).unwrap_or(head_sha)
+        ));
+    }
+
+    let Some(state) = pr_checks.state.filter(|_| !pr_checks.checks.is_empty()) else {
+        content.push_str("\n(No checks: no CI is configured for the head commit)\n");
+        return MarkdownContent(content);
+    };
+
+    content.push_str(&format!(
+        "overall: {} {} ({} passed, {} failed, {} pending)\n\n",
+        check_symbol(state),
+        state,
+        pr_checks.count(CheckConclusion::Success),
+        pr_checks.count(CheckConclusion::Failure),
+        pr_checks.count(CheckConclusion::Pending)
+    ));
+
+    for check in &pr_checks.checks {
+        let checkbox = if check.conclusion == CheckConclusion::Success {
+            "x"
+        } else {
+            " "
+        };
+        let name = match &check.details_url {
+            Some(url) => format!("[{}]({})", check.name, url),
+            None => check.name.clone(),
+        };
+        content.push_str(&format!(
+            "- [{}] {} {} ({})\n",
+            checkbox,
+            check_symbol(check.conclusion),
+            name,
+            check.conclusion
+        ));
+    }
+
+    MarkdownContent(content)
+}
+
+/// Symbol shown in front of a check conclusion
+fn check_symbol(conclusion: CheckConclusion) -> &'static str {
+    match conclusion {
+        CheckConclusion::Success => "✅",
+        CheckConclusion::Failure => "❌",
+        CheckConclusion::Pending => "⏳",
+        CheckConclusion::Neutral => "⚪",
+        CheckConclusion::Skipped => "⏭️",
+    }
+}
+
+/// Badge appended to the state of draft pull requests
+fn draft_badge(pr: &PullRequest) -> &'static str {
+    if pr.draft { " (DRAFT)" } else { "" }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{CommitSummary, PullRequestCheck, PullRequestId, RepositoryId};
+    use chrono::{TimeZone, Utc};
+
+    fn pull_request_commits(commits: Vec<CommitSummary>) -> PullRequestCommits {
+        PullRequestCommits {
+            pull_request_id: PullRequestId::new(RepositoryId::new("owner", "repo"), 7),
+            commits,
+        }
+    }
+
+    #[test]
+    fn test_pull_request_body_markdown_with_reactions() {
+        let node: crate::github::graphql::graphql_types::PullRequestNode = serde_json::from_str(
+            r#"{
+                "number": 7,
+                "title": "Add parser",
+                "body": null,
+                "state": "OPEN",
+                "createdAt": "2025-01-02T03:04:05Z",
+                "updatedAt": "2025-01-02T03:04:05Z",
+                "url": "https://github.com/owner/repo/pull/7",
+                "comments": { "nodes": [], "totalCount": 0 }
+            }"#,
+        )
+        .expect("valid pull request node");
+        let pr = PullRequest::try_from((node, RepositoryId::new("owner", "repo")))
+            .unwrap()
+            .with_reactions(crate::types::ReactionSummary {
+                thumbs_up: 4,
+                rocket: 1,
+                ..Default::default()
+            });
+
+        let markdown = pull_request_body_markdown_with_timezone(&pr, None);
+        assert!(markdown.0.contains("reactions: 👍 4 🚀 1\n"));
+
+        // Draft pull requests keep the colored state ahead of their badge
+        let draft = PullRequest { draft: true, ..pr };
+        let markdown = pull_request_body_markdown_with_comment_filter(
+            &draft,
+            None,
+            false,
+            &RenderOptions {
+                color: true,
+                ..Default::default()
+            },
+        );
+        assert!(markdown.0.contains("status: \x1b[32mOPEN\x1b[0m (DRAFT)\n"));
+    }
+
+    #[test]
+    fn test_pull_request_commits_markdown() {
+        let markdown = pull_request_commits_markdown_with_timezone(
+            &pull_request_commits(vec![CommitSummary {
+                sha: "0123456789abcdef0123456789abcdef01234567".to_string(),
+                message: "Fix parser panic\n\nThe tokenizer skipped the last byte.".to_string(),
+                author: Some("alice".to_string()),
+                authored_at: Some(Utc.with_ymd_and_hms(2025, 3, 4, 5, 6, 7).unwrap()),
+            }]),
+            None,
+        );
+
+        let commit_line = markdown
+            .0
+            .lines()
+            .find(|line| line.starts_with("- "))
+            .expect("Commit line should be rendered");
+        assert!(commit_line.starts_with("- `0123456` Fix parser panic (alice, 2025-03-04"));
+        assert!(!markdown.0.contains("tokenizer"));
+    }
+
+    #[test]
+    fn test_pull_request_commits_markdown_without_commits() {
+        let markdown =
+            pull_request_commits_markdown_with_timezone(&pull_request_commits(vec![]), None);
+        assert!(markdown.0.contains("commits: 0\n"));
+        assert!(markdown.0.contains("(No commits)"));
+    }
+
+    #[test]
+    fn test_pull_request_checks_markdown() {
+        let pr_checks = PullRequestChecks {
+            pull_request_id: PullRequestId::new(RepositoryId::new("owner", "repo"), 7),
+            head_sha: Some("0123456789abcdef".to_string()),
+            state: Some(CheckConclusion::Failure),
+            checks: vec![
+                PullRequestCheck {
+                    name: "build".to_string(),
+                    conclusion: CheckConclusion::Success,
+                    details_url: Some("https://github.com/owner/repo/actions/runs/1".to_string()),
+                },
+                PullRequestCheck {
+                    name: "test".to_string(),
+                    conclusion: CheckConclusion::Failure,
+                    details_url: None,
+                },
+            ],
+        };
+
+        let content = pull_request_checks_markdown(&pr_checks).0;
+        assert!(content.contains("head: `0123456`"));
+        assert!(content.contains("overall: ❌ failure (1 passed, 1 failed, 0 pending)"));
+        assert!(
+            content.contains(
+                "- [x] ✅ [build](https://github.com/owner/repo/actions/runs/1) (success)"
+            )
+        );
+        assert!(content.contains("- [ ] ❌ test (failure)"));
+    }
+
+    #[test]
+    fn test_pull_request_checks_markdown_without_ci() {
+        let pr_checks = PullRequestChecks {
+            pull_request_id: PullRequestId::new(RepositoryId::new("owner", "repo"), 7),
+            head_sha: Some("abc".to_string()),
+            state: None,
+            checks: Vec::new(),
+        };
+
+        let content = pull_request_checks_markdown(&pr_checks).0;
+        assert!(content.contains("(No checks: no CI is configured for the head commit)"));
+        assert!(!content.contains("overall:"));
+    }
+}
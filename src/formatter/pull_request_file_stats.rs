@@ -1,6 +1,12 @@
 use crate::types::{PullRequestFile, PullRequestNumber, RepositoryId};
 
-use super::MarkdownContent;
+use super::{MarkdownContent, RenderOptions, display_count};
+
+/// Number of files listed in the churn ranking of the diff stats summary
+const TOP_CHURN_FILE_LIMIT: usize = 5;
+
+/// Maximum width of the `+`/`-` histogram bar of `pull_request_diff_stat_markdown`
+const DIFF_STAT_BAR_WIDTH: usize = 40;
 
 /// Format pull request file statistics into markdown
 ///
@@ -12,6 +18,7 @@ use super::MarkdownContent;
 /// * `repository_id` - The repository identifier
 /// * `pr_number` - The pull request number
 /// * `files` - Vector of file metadata including statistics
+/// * `options` - Presentation options, e.g. thousands separators for the counts
 ///
 /// # Returns
 ///
@@ -20,6 +27,7 @@ pub fn pull_request_file_stats_markdown(
     repository_id: &RepositoryId,
     pr_number: PullRequestNumber,
     files: &[PullRequestFile],
+    options: &RenderOptions,
 ) -> MarkdownContent {
     let mut content = String::new();
 
@@ -43,7 +51,10 @@ pub fn pull_request_file_stats_markdown(
 
     content.push_str(&format!(
         "**Summary:** {} file(s) changed, +{} additions, -{} deletions, {} total changes\n\n",
-        file_count, total_additions, total_deletions, total_changes
+        display_count(file_count as u64, options),
+        display_count(total_additions, options),
+        display_count(total_deletions, options),
+        display_count(total_changes, options)
     ));
 
     // File list table
@@ -59,7 +70,84 @@ pub fn pull_request_file_stats_markdown(
 
         content.push_str(&format!(
             "| {} | {} | +{} | -{} | {} |\n",
-            filename, file.status, file.additions, file.deletions, file.changes
+            filename,
+            file.status,
+            display_count(file.additions, options),
+            display_count(file.deletions, options),
+            display_count(file.changes, options)
+        ));
+    }
+
+    content.push('\n');
+
+    MarkdownContent(content)
+}
+
+/// Format an aggregated summary of the file statistics of one or more pull requests
+///
+/// Totals files changed, additions, and deletions across all given pull requests and
+/// ranks the top 5 files by churn (additions + deletions). Intended as a header block
+/// before the per-pull-request listings of `pull_request_file_stats_markdown`.
+pub fn pull_request_diff_stats_summary_markdown(
+    files_by_pull_request: &[(RepositoryId, PullRequestNumber, Vec<PullRequestFile>)],
+    options: &RenderOptions,
+) -> MarkdownContent {
+    let mut content = String::from("## Diff Stats Summary\n\n");
+
+    let all_files: Vec<(&RepositoryId, PullRequestNumber, &PullRequestFile)> =
+        files_by_pull_request
+            .iter()
+            .flat_map(|(repository_id, pr_number, files)| {
+                files
+                    .iter()
+                    .map(move |file| (repository_id, *pr_number, file))
+            })
+            .collect();
+
+    let total_additions: u32 = all_files.iter().map(|(_, _, f)| f.additions).sum();
+    let total_deletions: u32 = all_files.iter().map(|(_, _, f)| f.deletions).sum();
+
+    content.push_str(&format!(
+        "- Pull requests: {}\n",
+        files_by_pull_request.len()
+    ));
+    content.push_str(&format!("- Files changed: {}\n", all_files.len()));
+    content.push_str(&format!(
+        "- Additions: +{}\n",
+        display_count(total_additions, options)
+    ));
+    content.push_str(&format!(
+        "- Deletions: -{}\n\n",
+        display_count(total_deletions, options)
+    ));
+
+    if all_files.is_empty() {
+        return MarkdownContent(content);
+    }
+
+    let mut ranked = all_files;
+    ranked.sort_by(|(_, _, a), (_, _, b)| {
+        (b.additions + b.deletions)
+            .cmp(&(a.additions + a.deletions))
+            .then_with(|| a.filename.cmp(&b.filename))
+    });
+
+    content.push_str(&format!(
+        "### Top {} Files by Churn\n\n",
+        TOP_CHURN_FILE_LIMIT.min(ranked.len())
+    ));
+    content.push_str("| File | Pull Request | Churn | Additions | Deletions |\n");
+    content.push_str("|------|--------------|-------|-----------|-----------|\n");
+
+    for (repository_id, pr_number, file) in ranked.into_iter().take(TOP_CHURN_FILE_LIMIT) {
+        content.push_str(&format!(
+            "| {} | {}#{} | {} | +{} | -{} |\n",
+            file.filename,
+            repository_id.full_name(),
+            pr_number.value(),
+            file.additions + file.deletions,
+            file.additions,
+            file.deletions
         ));
     }
 
@@ -68,6 +156,80 @@ pub fn pull_request_file_stats_markdown(
     MarkdownContent(content)
 }
 
+/// Format the files of a pull request as a `git diff --stat` style block
+///
+/// Each file gets one line `path | +N -M` followed by a histogram bar of `+` and `-`,
+/// with paths and counts aligned in columns. Bars are scaled down so that the file
+/// with the most changes fits `DIFF_STAT_BAR_WIDTH`; any change shows at least one
+/// character. A total line closes the block.
+pub fn pull_request_diff_stat_markdown(
+    pull_request_url: &str,
+    files: &[PullRequestFile],
+) -> MarkdownContent {
+    let mut content = format!("## Diff stat: {}\n\n", pull_request_url);
+
+    if files.is_empty() {
+        content.push_str("No files changed.\n");
+        return MarkdownContent(content);
+    }
+
+    let path_width = files
+        .iter()
+        .map(|f| f.filename.chars().count())
+        .max()
+        .unwrap_or(0);
+    let additions_width = files
+        .iter()
+        .map(|f| f.additions.to_string().len())
+        .max()
+        .unwrap_or(0);
+    let deletions_width = files
+        .iter()
+        .map(|f| f.deletions.to_string().len())
+        .max()
+        .unwrap_or(0);
+    let max_changes = files
+        .iter()
+        .map(|f| f.additions + f.deletions)
+        .max()
+        .unwrap_or(0) as usize;
+    let scale = |count: u32| {
+        let count = count as usize;
+        if max_changes <= DIFF_STAT_BAR_WIDTH || count == 0 {
+            count
+        } else {
+            (count * DIFF_STAT_BAR_WIDTH / max_changes).max(1)
+        }
+    };
+
+    content.push_str("```text\n");
+    for file in files {
+        content.push_str(&format!(
+            " {:<path_width$} | +{:>additions_width$} -{:>deletions_width$} {}{}\n",
+            file.filename,
+            file.additions,
+            file.deletions,
+            "+".repeat(scale(file.additions)),
+            "-".repeat(scale(file.deletions)),
+        ));
+    }
+
+    let total_additions: u32 = files.iter().map(|f| f.additions).sum();
+    let total_deletions: u32 = files.iter().map(|f| f.deletions).sum();
+    content.push_str(&format!(
+        " {} file{} changed, {} insertion{}(+), {} deletion{}(-)\n",
+        files.len(),
+        if files.len() == 1 { "" } else { "s" },
+        total_additions,
+        if total_additions == 1 { "" } else { "s" },
+        total_deletions,
+        if total_deletions == 1 { "" } else { "s" },
+    ));
+    content.push_str("```\n");
+
+    MarkdownContent(content)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -96,6 +258,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_pull_request_diff_stat_markdown_aligns_histogram() {
+        let files = vec![
+            create_test_file("src/main.rs", "modified", 10, 5),
+            create_test_file("README.md", "modified", 3, 1),
+            create_test_file("src/github/client.rs", "added", 120, 0),
+        ];
+
+        let result =
+            pull_request_diff_stat_markdown("https://github.com/owner/repo/pull/123", &files);
+
+        let bar = |plus: usize, minus: usize| format!("{}{}", "+".repeat(plus), "-".repeat(minus));
+        let expected = format!(
+            "## Diff stat: https://github.com/owner/repo/pull/123\n\n```text\n \
+             src/main.rs          | + 10 -5 {}\n \
+             README.md            | +  3 -1 {}\n \
+             src/github/client.rs | +120 -0 {}\n \
+             3 files changed, 133 insertions(+), 6 deletions(-)\n```\n",
+            // Scaled to the 120 changes of the largest file
+            bar(3, 1),
+            bar(1, 1),
+            bar(40, 0),
+        );
+        assert_eq!(result.0, expected);
+    }
+
     #[test]
     fn test_pull_request_file_stats_markdown() {
         let repo_id = RepositoryId::new("owner".to_string(), "repo".to_string());
@@ -106,7 +294,12 @@ mod tests {
             create_test_file("src/lib.rs", "added", 50, 0),
         ];
 
-        let result = pull_request_file_stats_markdown(&repo_id, pr_number, &files);
+        let result = pull_request_file_stats_markdown(
+            &repo_id,
+            pr_number,
+            &files,
+            &RenderOptions::default(),
+        );
 
         assert!(
             result
@@ -133,7 +326,12 @@ mod tests {
         let pr_number = PullRequestNumber::new(456);
         let files = vec![];
 
-        let result = pull_request_file_stats_markdown(&repo_id, pr_number, &files);
+        let result = pull_request_file_stats_markdown(
+            &repo_id,
+            pr_number,
+            &files,
+            &RenderOptions::default(),
+        );
 
         assert!(
             result
@@ -143,6 +341,67 @@ mod tests {
         assert!(result.0.contains("No files changed."));
     }
 
+    #[test]
+    fn test_pull_request_diff_stats_summary_markdown() {
+        let repo_id = RepositoryId::new("owner".to_string(), "repo".to_string());
+        let files_by_pull_request = vec![
+            (
+                repo_id.clone(),
+                PullRequestNumber::new(1),
+                vec![
+                    create_test_file("src/main.rs", "modified", 10, 5),
+                    create_test_file("README.md", "modified", 3, 1),
+                    create_test_file("src/lib.rs", "added", 50, 0),
+                ],
+            ),
+            (
+                repo_id,
+                PullRequestNumber::new(2),
+                vec![
+                    create_test_file("Cargo.toml", "modified", 1, 1),
+                    create_test_file("src/cli.rs", "modified", 20, 20),
+                    create_test_file("docs/guide.md", "added", 7, 0),
+                ],
+            ),
+        ];
+
+        let result = pull_request_diff_stats_summary_markdown(
+            &files_by_pull_request,
+            &RenderOptions::default(),
+        );
+        let lines: Vec<&str> = result.0.lines().collect();
+
+        assert!(result.0.contains("- Pull requests: 2"));
+        assert!(result.0.contains("- Files changed: 6"));
+        assert!(result.0.contains("- Additions: +91"));
+        assert!(result.0.contains("- Deletions: -27"));
+        assert!(result.0.contains("### Top 5 Files by Churn"));
+
+        let table_rows: Vec<&str> = lines
+            .iter()
+            .copied()
+            .filter(|line| line.starts_with("| ") && !line.starts_with("| File"))
+            .collect();
+        assert_eq!(
+            table_rows,
+            vec![
+                "| src/lib.rs | owner/repo#1 | 50 | +50 | -0 |",
+                "| src/cli.rs | owner/repo#2 | 40 | +20 | -20 |",
+                "| src/main.rs | owner/repo#1 | 15 | +10 | -5 |",
+                "| docs/guide.md | owner/repo#2 | 7 | +7 | -0 |",
+                "| README.md | owner/repo#1 | 4 | +3 | -1 |",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pull_request_diff_stats_summary_markdown_empty() {
+        let result = pull_request_diff_stats_summary_markdown(&[], &RenderOptions::default());
+
+        assert!(result.0.contains("- Files changed: 0"));
+        assert!(!result.0.contains("Top"));
+    }
+
     #[test]
     fn test_pull_request_file_stats_markdown_with_rename() {
         let repo_id = RepositoryId::new("owner".to_string(), "repo".to_string());
@@ -151,7 +410,12 @@ mod tests {
         file.previous_filename = Some("src/old_name.rs".to_string());
         let files = vec![file];
 
-        let result = pull_request_file_stats_markdown(&repo_id, pr_number, &files);
+        let result = pull_request_file_stats_markdown(
+            &repo_id,
+            pr_number,
+            &files,
+            &RenderOptions::default(),
+        );
 
         assert!(result.0.contains("src/old_name.rs → src/new_name.rs"));
         assert!(result.0.contains("renamed"));
@@ -0,0 +1,63 @@
+use crate::types::RateLimitStatus;
+
+use super::{MarkdownContent, TimezoneOffset, format_datetime_with_timezone_offset};
+
+/// Format the GraphQL API rate limit status into markdown with timezone conversion
+pub fn rate_limit_status_markdown_with_timezone(
+    status: &RateLimitStatus,
+    timezone: Option<&TimezoneOffset>,
+) -> MarkdownContent {
+    let mut content = String::from("# GitHub GraphQL Rate Limit\n");
+    content.push_str(&format!(
+        "remaining: {} / {}\n",
+        status.remaining, status.limit
+    ));
+    content.push_str(&format!("used: {}\n", status.used));
+    content.push_str(&format!(
+        "reset_at: {}\n",
+        format_datetime_with_timezone_offset(status.reset_at, timezone)
+    ));
+    content.push_str(&format!("query_cost: {}\n", status.cost));
+
+    if status.is_exhausted() {
+        content.push_str(
+            "\n**Rate limit exhausted.** Requests will be throttled until the reset time.\n",
+        );
+    }
+
+    MarkdownContent(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn status(remaining: u32) -> RateLimitStatus {
+        RateLimitStatus {
+            limit: 5000,
+            remaining,
+            used: 5000 - remaining,
+            cost: 1,
+            reset_at: Utc.with_ymd_and_hms(2025, 1, 2, 3, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_rate_limit_status_markdown_in_timezone() {
+        let jst = TimezoneOffset::parse("JST").unwrap();
+        let markdown = rate_limit_status_markdown_with_timezone(&status(4200), Some(&jst));
+
+        assert!(markdown.0.contains("remaining: 4200 / 5000"));
+        assert!(markdown.0.contains("used: 800"));
+        assert!(markdown.0.contains("reset_at: 2025-01-02 12:00:00 JST"));
+        assert!(!markdown.0.contains("exhausted"));
+    }
+
+    #[test]
+    fn test_rate_limit_status_markdown_exhausted() {
+        let markdown = rate_limit_status_markdown_with_timezone(&status(0), None);
+        assert!(markdown.0.contains("reset_at: 2025-01-02 03:00:00 UTC"));
+        assert!(markdown.0.contains("Rate limit exhausted"));
+    }
+}
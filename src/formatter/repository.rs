@@ -1,27 +1,93 @@
 use crate::formatter::{
-    MarkdownContent, TimezoneOffset, format_date_with_timezone_offset,
-    format_datetime_with_timezone_offset,
+    MarkdownContent, RenderOptions, TimezoneOffset, display_count,
+    format_date_with_timezone_offset, format_datetime_with_timezone_offset,
 };
-use crate::types::GithubRepository;
+use crate::types::{
+    Contributor, GithubRepository, ReleaseWithAssets, RepositoryId, RepositoryStats, label::Label,
+};
+
+use super::html::escape_html;
 
 // Limit to 10 releases by default
 const DEFAULT_RELEASE_LIMIT: usize = 10;
 // Limit to 10 milestones by default
 const DEFAULT_MILESTONE_LIMIT: usize = 10;
 
+/// Render a label name, wrapped in a span with the label's color when requested
+///
+/// Labels without a valid 6-digit hex color fall back to the plain name.
+fn label_markdown(label: &Label, color: Option<&str>, show_label_colors: bool) -> String {
+    match color.filter(|color| show_label_colors && is_hex_color(color)) {
+        Some(color) => format!(
+            "<span style=\"color:#{}\">{}</span>",
+            color.to_ascii_lowercase(),
+            escape_html(label.name())
+        ),
+        None => label.name().to_string(),
+    }
+}
+
+fn is_hex_color(color: &str) -> bool {
+    color.len() == 6 && color.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Share of each language in percent, largest first
+///
+/// Returns an empty list when the repository has no detected code.
+pub fn language_percentages(languages: &[(String, u64)]) -> Vec<(String, f64)> {
+    let total: u64 = languages.iter().map(|(_, bytes)| bytes).sum();
+    if total == 0 {
+        return Vec::new();
+    }
+
+    let mut percentages: Vec<(String, f64)> = languages
+        .iter()
+        .map(|(name, bytes)| (name.clone(), *bytes as f64 * 100.0 / total as f64))
+        .collect();
+    percentages.sort_by(|a, b| b.1.total_cmp(&a.1));
+    percentages
+}
+
+/// Format the language breakdown of a repository as a markdown section
+pub fn repository_languages_markdown(languages: &[(String, u64)]) -> MarkdownContent {
+    let mut content = String::from("## Languages\n");
+    let percentages = language_percentages(languages);
+    if percentages.is_empty() {
+        content.push_str("(No languages detected)\n");
+    }
+    for (name, percentage) in percentages {
+        content.push_str(&format!("- {}: {:.1}%\n", name, percentage));
+    }
+    MarkdownContent(content)
+}
+
 pub fn repository_body_markdown_with_timezone(
     repository: &GithubRepository,
     timezone: Option<&TimezoneOffset>,
     showing_release_limit: Option<usize>,
     showing_milestone_limit: Option<usize>,
+    show_label_colors: bool,
 ) -> MarkdownContent {
     let mut content = String::new();
 
+    if repository.is_archived {
+        content.push_str("**ARCHIVED** - this repository is read-only\n\n");
+    }
+
     // URLs
     content.push_str("## URL\n");
     content.push_str(&format!("{}\n", repository.git_repository_id.url()));
     content.push('\n');
 
+    // Pinned issues (if any)
+    if !repository.pinned_issues.is_empty() {
+        content.push_str("## Pinned Issues\n");
+        for issue_id in &repository.pinned_issues {
+            content.push_str(&format!("- {}\n", issue_id.url()));
+        }
+        content.push('\n');
+    }
+
     // Description
     content.push_str("## Description\n");
     if let Some(description) = &repository.description {
@@ -29,6 +95,17 @@ pub fn repository_body_markdown_with_timezone(
     }
     content.push('\n');
 
+    // Topics (if any)
+    if !repository.topics.is_empty() {
+        content.push_str("## Topics\n");
+        let tags: Vec<String> = repository
+            .topics
+            .iter()
+            .map(|topic| format!("`{}`", topic))
+            .collect();
+        content.push_str(&format!("{}\n", tags.join(" ")));
+    }
+
     // Repository Information
     content.push_str("## Default Branch\n");
     if let Some(default_branch) = &repository.default_branch {
@@ -47,7 +124,10 @@ pub fn repository_body_markdown_with_timezone(
     if !repository.labels.is_empty() {
         content.push_str("\n## Labels\n");
         for label in &repository.labels {
-            content.push_str(&format!("- {}\n", label.name()));
+            content.push_str(&format!(
+                "- {}\n",
+                label_markdown(label, repository.label_color(label), show_label_colors)
+            ));
         }
     }
 
@@ -173,3 +253,276 @@ pub fn repository_body_markdown_with_timezone(
 
     MarkdownContent(content)
 }
+
+/// Format repository contributors as a markdown table ranked by contribution count
+pub fn repository_contributors_markdown(
+    repository_id: &RepositoryId,
+    contributors: &[Contributor],
+    options: &RenderOptions,
+) -> MarkdownContent {
+    let mut content = format!("# Contributors of {}\n", repository_id.url());
+
+    if contributors.is_empty() {
+        content.push_str("No contributors found.\n");
+        return MarkdownContent(content);
+    }
+
+    content.push_str("| Rank | Login | Contributions | Avatar |\n");
+    content.push_str("|---|---|---|---|\n");
+
+    let mut ranked: Vec<&Contributor> = contributors.iter().collect();
+    ranked.sort_by(|a, b| b.contributions.cmp(&a.contributions));
+
+    for (index, contributor) in ranked.iter().enumerate() {
+        let avatar = if contributor.avatar_url.is_empty() {
+            "-".to_string()
+        } else {
+            format!("[avatar]({})", contributor.avatar_url)
+        };
+        content.push_str(&format!(
+            "| {} | [{}](https://github.com/{}) | {} | {} |\n",
+            index + 1,
+            contributor.login,
+            contributor.login,
+            display_count(contributor.contributions, options),
+            avatar
+        ));
+    }
+
+    MarkdownContent(content)
+}
+
+/// Format issue and pull request counts of repositories into a compact markdown summary
+pub fn repository_stats_markdown_with_timezone(
+    stats: &[RepositoryStats],
+    timezone: Option<&TimezoneOffset>,
+    options: &RenderOptions,
+) -> MarkdownContent {
+    let mut content = String::new();
+
+    for (index, repository) in stats.iter().enumerate() {
+        if index > 0 {
+            content.push('\n');
+        }
+        content.push_str(&format!("## {}\n", repository.repository_id.full_name()));
+        content.push_str(&format!(
+            "issues: {} open / {} closed\n",
+            display_count(repository.open_issues, options),
+            display_count(repository.closed_issues, options)
+        ));
+        content.push_str(&format!(
+            "pull_requests: {} open / {} merged / {} closed\n",
+            display_count(repository.open_pull_requests, options),
+            display_count(repository.merged_pull_requests, options),
+            display_count(repository.closed_pull_requests, options)
+        ));
+        content.push_str(&format!(
+            "stars: {} | forks: {}\n",
+            display_count(repository.stargazers, options),
+            display_count(repository.forks, options)
+        ));
+        if let Some(pushed_at) = repository.pushed_at {
+            content.push_str(&format!(
+                "last_push: {}\n",
+                format_datetime_with_timezone_offset(pushed_at, timezone)
+            ));
+        }
+    }
+
+    MarkdownContent(content)
+}
+
+/// Format releases of a repository with their assets into markdown with timezone conversion
+///
+/// Each release gets a heading with its name and tag, its published date, and a table of
+/// assets with size, download count, and download URL.
+pub fn repository_releases_markdown_with_timezone(
+    repository_id: &RepositoryId,
+    releases: &[ReleaseWithAssets],
+    timezone: Option<&TimezoneOffset>,
+) -> MarkdownContent {
+    let mut content = format!("# Releases of {}\n", repository_id.url());
+
+    if releases.is_empty() {
+        content.push_str("No releases found.\n");
+        return MarkdownContent(content);
+    }
+
+    for ReleaseWithAssets { release, assets } in releases {
+        content.push_str(&format!("\n## {} (`{}`)", release.name, release.tag_name));
+        if release.is_prerelease {
+            content.push_str(" [pre-release]");
+        }
+        if release.is_draft {
+            content.push_str(" [draft]");
+        }
+        content.push('\n');
+
+        match release.published_at {
+            Some(published_at) => content.push_str(&format!(
+                "published: {}\n",
+                format_datetime_with_timezone_offset(published_at, timezone)
+            )),
+            None => content.push_str("published: (not published)\n"),
+        }
+        content.push_str(&format!("url: {}\n", release.url));
+
+        if assets.is_empty() {
+            content.push_str("(No assets)\n");
+            continue;
+        }
+        content.push_str("\n| Asset | Size | Downloads | Download URL |\n");
+        content.push_str("|---|---|---|---|\n");
+        for asset in assets {
+            content.push_str(&format!(
+                "| {} | {} | {} | {} |\n",
+                asset.name,
+                format_size(asset.size),
+                asset.download_count,
+                asset.download_url
+            ));
+        }
+    }
+
+    MarkdownContent(content)
+}
+
+/// Format a byte count with a binary unit, e.g. `1.5 MiB`
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KiB");
+        assert_eq!(format_size(5 * 1024 * 1024), "5.0 MiB");
+    }
+
+    #[test]
+    fn test_language_percentages_sorted_and_sum_to_100() {
+        let languages = vec![
+            ("Shell".to_string(), 1_000),
+            ("Rust".to_string(), 7_000),
+            ("Python".to_string(), 2_000),
+        ];
+
+        let percentages = language_percentages(&languages);
+        let names: Vec<&str> = percentages.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["Rust", "Python", "Shell"]);
+        assert!(percentages.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+
+        let sum: f64 = percentages.iter().map(|(_, percentage)| percentage).sum();
+        assert!((sum - 100.0).abs() < 1e-9);
+
+        let markdown = repository_languages_markdown(&languages);
+        assert!(
+            markdown
+                .0
+                .contains("- Rust: 70.0%\n- Python: 20.0%\n- Shell: 10.0%")
+        );
+    }
+
+    #[test]
+    fn test_repository_languages_markdown_without_languages() {
+        assert!(language_percentages(&[]).is_empty());
+        assert!(
+            repository_languages_markdown(&[])
+                .0
+                .contains("(No languages detected)")
+        );
+    }
+
+    fn contributor(login: &str, contributions: u32) -> Contributor {
+        Contributor {
+            login: login.to_string(),
+            contributions,
+            avatar_url: format!("https://avatars.githubusercontent.com/{}", login),
+        }
+    }
+
+    #[test]
+    fn test_repository_contributors_markdown_sorted_by_contributions() {
+        let repository_id = RepositoryId::new("owner", "repo");
+        let contributors = vec![contributor("bob", 3), contributor("alice", 42)];
+        let markdown = repository_contributors_markdown(
+            &repository_id,
+            &contributors,
+            &RenderOptions::default(),
+        );
+        let lines: Vec<&str> = markdown.0.lines().collect();
+
+        assert_eq!(lines[0], "# Contributors of https://github.com/owner/repo");
+        assert_eq!(
+            lines[3],
+            "| 1 | [alice](https://github.com/alice) | 42 | [avatar](https://avatars.githubusercontent.com/alice) |"
+        );
+        assert!(lines[4].starts_with("| 2 | [bob]"));
+    }
+
+    #[test]
+    fn test_repository_contributors_markdown_with_thousands_separator() {
+        let repository_id = RepositoryId::new("owner", "repo");
+        let contributors = vec![contributor("alice", 12345)];
+
+        let plain = repository_contributors_markdown(
+            &repository_id,
+            &contributors,
+            &RenderOptions::default(),
+        );
+        assert!(plain.0.contains("| 12345 |"));
+
+        let grouped = repository_contributors_markdown(
+            &repository_id,
+            &contributors,
+            &RenderOptions {
+                thousands_separator: true,
+                ..Default::default()
+            },
+        );
+        assert!(grouped.0.contains("| 12,345 |"));
+    }
+
+    #[test]
+    fn test_label_markdown_with_colors() {
+        let colored = Label::new("bug".to_string());
+        let uncolored = Label::new("help wanted".to_string());
+
+        assert_eq!(
+            label_markdown(&colored, Some("D73A4A"), true),
+            "<span style=\"color:#d73a4a\">bug</span>"
+        );
+        assert_eq!(label_markdown(&uncolored, None, true), "help wanted");
+        assert_eq!(label_markdown(&colored, Some("D73A4A"), false), "bug");
+    }
+
+    #[test]
+    fn test_label_markdown_ignores_invalid_color() {
+        let label = Label::new("<b>".to_string());
+        assert_eq!(label_markdown(&label, Some("red\"><x"), true), "<b>");
+    }
+
+    #[test]
+    fn test_repository_contributors_markdown_empty() {
+        let markdown = repository_contributors_markdown(
+            &RepositoryId::new("owner", "repo"),
+            &[],
+            &RenderOptions::default(),
+        );
+        assert!(markdown.0.contains("No contributors found."));
+    }
+}
@@ -3,7 +3,10 @@
 //! This module provides formatting capabilities for repository branch groups,
 //! supporting both markdown and JSON output formats with timezone-aware datetime display.
 
-use crate::types::{GroupName, RepositoryBranchGroup, RepositoryBranchPair};
+use crate::types::{
+    BranchGroupDiff, BranchGroupStatus, GroupName, GroupReleaseNotes, ProfileBranchGroups,
+    RepositoryBranchGroup, RepositoryBranchPair,
+};
 
 use super::{MarkdownContent, TimezoneOffset, format_datetime_with_timezone_offset};
 
@@ -85,7 +88,7 @@ pub fn repository_branch_group_markdown_with_timezone(
             content.push_str(&format!(
                 "\n- {} | branch:{}",
                 pair.repository_id.url(),
-                pair.branch.as_str()
+                pair.branch.display_name()
             ));
         }
     }
@@ -118,7 +121,7 @@ pub fn repository_branch_group_markdown_with_timezone_light(
             content.push_str(&format!(
                 "\n- {} | branch:{}",
                 pair.repository_id.url(),
-                pair.branch.as_str()
+                pair.branch.display_name()
             ));
         }
     }
@@ -145,7 +148,7 @@ pub fn repository_branch_pair_markdown(pair: &RepositoryBranchPair) -> MarkdownC
     let content = format!(
         "{} | branch:{}",
         pair.repository_id.url(),
-        pair.branch.as_str()
+        pair.branch.display_name()
     );
     MarkdownContent(content)
 }
@@ -194,3 +197,171 @@ pub fn repository_branch_groups_markdown_with_timezone_light(
 
     MarkdownContent(content)
 }
+
+/// Format repository branch groups of multiple profiles as a markdown table
+///
+/// Profiles without groups get a single row with no group name unless `hide_empty` is set.
+pub fn repository_branch_groups_across_profiles_markdown(
+    profile_groups: &[ProfileBranchGroups],
+    timezone: Option<&TimezoneOffset>,
+    hide_empty: bool,
+) -> MarkdownContent {
+    let mut rows = Vec::new();
+    for entry in profile_groups {
+        if entry.groups.is_empty() {
+            if !hide_empty {
+                rows.push(format!("| {} | - | 0 | - |", entry.profile_name));
+            }
+            continue;
+        }
+        for group in &entry.groups {
+            rows.push(format!(
+                "| {} | {} | {} | {} |",
+                entry.profile_name,
+                group.name,
+                group.pair_count(),
+                format_datetime_with_timezone_offset(group.created_at, timezone)
+            ));
+        }
+    }
+
+    if rows.is_empty() {
+        return MarkdownContent("No repository branch groups found in any profile.".to_string());
+    }
+
+    let mut content = String::from("| Profile | Group | Branches | Created |\n");
+    content.push_str("|---------|-------|----------|---------|\n");
+    content.push_str(&rows.join("\n"));
+    content.push('\n');
+
+    MarkdownContent(content)
+}
+
+/// Format the difference between two repository branch groups into markdown
+///
+/// Lists the pairs only in the first group, only in the second group, and common to both,
+/// each as `repository_url | branch:branch_name`.
+pub fn repository_branch_group_diff_markdown(diff: &BranchGroupDiff) -> MarkdownContent {
+    let mut content = format!(
+        "# Branch group diff: {} vs {}\n",
+        diff.group_a, diff.group_b
+    );
+
+    let sections = [
+        (format!("Only in {}", diff.group_a), &diff.only_in_a),
+        (format!("Only in {}", diff.group_b), &diff.only_in_b),
+        ("Common".to_string(), &diff.common),
+    ];
+    for (title, pairs) in sections {
+        content.push_str(&format!("\n## {} ({})\n", title, pairs.len()));
+        if pairs.is_empty() {
+            content.push_str("(none)\n");
+        }
+        for pair in pairs {
+            content.push_str(&format!(
+                "- {} | branch:{}\n",
+                pair.repository_id.url(),
+                pair.branch.display_name()
+            ));
+        }
+    }
+
+    MarkdownContent(content)
+}
+
+/// Format the live state of a repository branch group as a markdown table
+///
+/// Each branch gets a row with its latest commit, commit date, and how many commits it
+/// is ahead of and behind the default branch. Deleted branches are marked "missing" and
+/// branches whose status could not be fetched show the error.
+pub fn branch_group_status_markdown(
+    status: &BranchGroupStatus,
+    timezone: Option<&TimezoneOffset>,
+) -> MarkdownContent {
+    let mut content = format!("# Branch group status: {}\n\n", status.group_name);
+
+    if status.branches.is_empty() {
+        content.push_str("No branches in this group.\n");
+        return MarkdownContent(content);
+    }
+
+    content.push_str(
+        "| Repository | Branch | Status | Latest commit | Committed | Base | Ahead | Behind |\n",
+    );
+    content.push_str(
+        "|------------|--------|--------|---------------|-----------|------|-------|--------|\n",
+    );
+    for (pair, result) in &status.branches {
+        let row = match result {
+            Ok(branch_status) => match &branch_status.head {
+                Some(head) => format!(
+                    "ok | {} | {} | {} | {} | {}",
+                    head.short_sha(),
+                    head.committed_at
+                        .map(|date| format_datetime_with_timezone_offset(date, timezone))
+                        .unwrap_or_else(|| "-".to_string()),
+                    branch_status.default_branch.as_str(),
+                    head.ahead_by,
+                    head.behind_by
+                ),
+                None => format!(
+                    "missing | - | - | {} | - | -",
+                    branch_status.default_branch.as_str()
+                ),
+            },
+            Err(error) => format!("error: {} | - | - | - | - | -", error.replace('|', "\\|")),
+        };
+        content.push_str(&format!(
+            "| {} | {} | {} |\n",
+            pair.repository_id,
+            pair.branch.display_name(),
+            row
+        ));
+    }
+
+    MarkdownContent(content)
+}
+
+/// Format the merged pull requests of a repository branch group as release notes
+///
+/// Each branch gets a section listing its pull requests with title, number, and author
+/// in merge order. Branches without merged pull requests are noted as "No changes."
+pub fn group_release_notes_markdown(notes: &GroupReleaseNotes) -> MarkdownContent {
+    let mut content = format!(
+        "# Release notes: {} (merged since {})\n",
+        notes.group_name,
+        notes.since.format("%Y-%m-%d")
+    );
+
+    if notes.branches.is_empty() {
+        content.push_str("\nNo branches in this group.\n");
+        return MarkdownContent(content);
+    }
+
+    for (pair, result) in &notes.branches {
+        content.push_str(&format!(
+            "\n## {} ({})\n",
+            pair.repository_id.full_name(),
+            pair.branch.display_name()
+        ));
+        match result {
+            Ok(pull_requests) if pull_requests.is_empty() => content.push_str("No changes.\n"),
+            Ok(pull_requests) => {
+                for pr in pull_requests {
+                    let author = pr
+                        .author
+                        .as_ref()
+                        .map(|author| format!(" by @{}", author))
+                        .unwrap_or_default();
+                    content.push_str(&format!(
+                        "- {} (#{}){}\n",
+                        pr.title, pr.pull_request_id.number, author
+                    ));
+                }
+            }
+            Err(error) => content.push_str(&format!("Failed to search pull requests: {}\n", error)),
+        }
+    }
+
+    MarkdownContent(content)
+}
@@ -0,0 +1,125 @@
+//! Search result table formatting
+//!
+//! Renders issue and pull request search results as a single compact markdown table,
+//! for scanning many results at once.
+
+use crate::types::{IssueOrPullrequest, RepositoryId};
+
+use super::{MarkdownContent, RenderOptions, TimezoneOffset, timestamp_text};
+
+/// Maximum number of title characters shown in a table row
+const MAX_TABLE_TITLE_LENGTH: usize = 60;
+
+/// Format issue and pull request search results as one markdown table
+///
+/// Columns are number, type, state, title, author, and updated time (converted to the
+/// given timezone, or relative to now with `options.relative_time`). Long titles are truncated with an ellipsis and pipe characters are
+/// escaped so they do not break the table.
+pub fn search_results_table_markdown(
+    results: &[IssueOrPullrequest],
+    timezone: Option<&TimezoneOffset>,
+    options: &RenderOptions,
+) -> MarkdownContent {
+    let mut content = String::from("| Number | Type | State | Title | Author | Updated |\n");
+    content.push_str("|--------|------|-------|-------|--------|---------|\n");
+
+    for result in results {
+        let (number, kind, state, title, author, updated_at) = match result {
+            IssueOrPullrequest::Issue(issue) => (
+                resource_number(&issue.issue_id.git_repository, issue.issue_id.number),
+                "issue",
+                issue.state.to_string(),
+                &issue.title,
+                issue.author.clone(),
+                issue.updated_at,
+            ),
+            IssueOrPullrequest::PullRequest(pr) => (
+                resource_number(
+                    &pr.pull_request_id.git_repository,
+                    pr.pull_request_id.number,
+                ),
+                "pull request",
+                if pr.draft {
+                    format!("{} (draft)", pr.state)
+                } else {
+                    pr.state.to_string()
+                },
+                &pr.title,
+                pr.author
+                    .as_ref()
+                    .map(|author| author.to_string())
+                    .unwrap_or_default(),
+                pr.updated_at,
+            ),
+        };
+        content.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} |\n",
+            number,
+            kind,
+            state,
+            escape_table_cell(&truncate_title(title)),
+            escape_table_cell(&author),
+            timestamp_text(updated_at, timezone, options)
+        ));
+    }
+
+    MarkdownContent(content)
+}
+
+/// Reference such as `owner/repo#42`, unique across the searched repositories
+fn resource_number(repository_id: &RepositoryId, number: u32) -> String {
+    format!("{}#{}", repository_id.full_name(), number)
+}
+
+/// Shorten a title to `MAX_TABLE_TITLE_LENGTH` characters, ending it with an ellipsis
+fn truncate_title(title: &str) -> String {
+    if title.chars().count() > MAX_TABLE_TITLE_LENGTH {
+        let truncated: String = title.chars().take(MAX_TABLE_TITLE_LENGTH - 1).collect();
+        format!("{}…", truncated.trim_end())
+    } else {
+        title.to_string()
+    }
+}
+
+/// Escape pipes and flatten line breaks so text stays inside one table cell
+fn escape_table_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Issue;
+
+    fn issue(number: u32, title: &str) -> IssueOrPullrequest {
+        IssueOrPullrequest::Issue(Issue::fixture("repo", number, title))
+    }
+
+    #[test]
+    fn test_search_results_table_escapes_pipes() {
+        let results = vec![issue(42, "Parse a | b as an or-pattern")];
+        let table = search_results_table_markdown(&results, None, &RenderOptions::default()).0;
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            "| Number | Type | State | Title | Author | Updated |"
+        );
+        assert_eq!(
+            lines[2],
+            "| owner/repo#42 | issue | OPEN | Parse a \\| b as an or-pattern | alice | 2025-01-02 03:04:05 UTC |"
+        );
+    }
+
+    #[test]
+    fn test_search_results_table_truncates_long_titles() {
+        let title = format!("{}|{}", "a".repeat(50), "b".repeat(50));
+        let results = vec![issue(7, &title)];
+        let table = search_results_table_markdown(&results, None, &RenderOptions::default()).0;
+        let row = table.lines().nth(2).unwrap();
+
+        let expected_title = format!("{}\\|{}…", "a".repeat(50), "b".repeat(8));
+        assert!(row.contains(&format!("| {} |", expected_title)));
+        assert!(!row.contains(&"b".repeat(9)));
+    }
+}
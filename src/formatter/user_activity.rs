@@ -0,0 +1,136 @@
+use crate::formatter::MarkdownContent;
+use crate::types::{UserActivity, UserActivityItem};
+
+/// Format a user activity summary as markdown
+///
+/// Counts come first, followed by the linked items of each group. A note lists the
+/// repositories whose results were cut off at the per-repository limit.
+pub fn user_activity_markdown(activity: &UserActivity) -> MarkdownContent {
+    let mut content = format!(
+        "# Activity of {} in {} repositories\n\n",
+        activity.username, activity.repository_count
+    );
+
+    content.push_str(&format!(
+        "- authored issues: {} open, {} closed\n",
+        activity.authored_open_issues.len(),
+        activity.authored_closed_issues.len()
+    ));
+    content.push_str(&format!(
+        "- authored pull requests: {} open, {} merged\n",
+        activity.authored_open_pull_requests.len(),
+        activity.authored_merged_pull_requests.len()
+    ));
+    content.push_str(&format!(
+        "- assigned open items: {}\n\n",
+        activity.assigned_open_items.len()
+    ));
+
+    if !activity.truncated_repositories.is_empty() {
+        let repositories: Vec<String> = activity
+            .truncated_repositories
+            .iter()
+            .map(|repository_id| repository_id.full_name())
+            .collect();
+        content.push_str(&format!(
+            "**Truncated:** results were capped at {} per repository in {}; counts for these repositories are lower bounds.\n\n",
+            activity.per_repository_limit,
+            repositories.join(", ")
+        ));
+    }
+
+    let sections = [
+        ("Open Issues", &activity.authored_open_issues),
+        ("Closed Issues", &activity.authored_closed_issues),
+        ("Open Pull Requests", &activity.authored_open_pull_requests),
+        (
+            "Merged Pull Requests",
+            &activity.authored_merged_pull_requests,
+        ),
+        ("Assigned Open Items", &activity.assigned_open_items),
+    ];
+    for (title, items) in sections {
+        if items.is_empty() {
+            continue;
+        }
+        content.push_str(&format!("## {} ({})\n", title, items.len()));
+        for item in items {
+            content.push_str(&activity_item_line(item));
+        }
+        content.push('\n');
+    }
+
+    MarkdownContent(content)
+}
+
+fn activity_item_line(item: &UserActivityItem) -> String {
+    format!("- [{}]({})\n", item.title, item.url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::RepositoryId;
+
+    fn item(title: &str, url: &str) -> UserActivityItem {
+        UserActivityItem {
+            title: title.to_string(),
+            url: url.to_string(),
+        }
+    }
+
+    fn activity() -> UserActivity {
+        UserActivity {
+            username: "alice".to_string(),
+            repository_count: 2,
+            per_repository_limit: 30,
+            authored_open_issues: vec![item(
+                "Crash on startup",
+                "https://github.com/owner/repo/issues/42",
+            )],
+            authored_closed_issues: Vec::new(),
+            authored_open_pull_requests: Vec::new(),
+            authored_merged_pull_requests: vec![
+                item("Fix crash", "https://github.com/owner/repo/pull/43"),
+                item("Add docs", "https://github.com/owner/other/pull/5"),
+            ],
+            assigned_open_items: Vec::new(),
+            truncated_repositories: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_user_activity_markdown() {
+        let markdown = user_activity_markdown(&activity());
+
+        assert!(markdown.0.contains("# Activity of alice in 2 repositories"));
+        assert!(markdown.0.contains("- authored issues: 1 open, 0 closed"));
+        assert!(
+            markdown
+                .0
+                .contains("- authored pull requests: 0 open, 2 merged")
+        );
+        assert!(markdown.0.contains("- assigned open items: 0"));
+        assert!(markdown.0.contains("## Merged Pull Requests (2)"));
+        assert!(
+            markdown
+                .0
+                .contains("- [Crash on startup](https://github.com/owner/repo/issues/42)")
+        );
+        assert!(!markdown.0.contains("## Closed Issues"));
+        assert!(!markdown.0.contains("Truncated"));
+    }
+
+    #[test]
+    fn test_user_activity_markdown_notes_truncation() {
+        let mut activity = activity();
+        activity.truncated_repositories = vec![RepositoryId::new("owner", "repo")];
+
+        let markdown = user_activity_markdown(&activity);
+        assert!(
+            markdown
+                .0
+                .contains("**Truncated:** results were capped at 30 per repository in owner/repo")
+        );
+    }
+}
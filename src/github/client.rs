@@ -1,36 +1,70 @@
+use crate::github::cost_budget::GraphQLCostBudget;
+use crate::github::deprecation::DeprecationWarning;
 use crate::github::error::ApiRetryableError;
-use crate::types::{SearchCursor, SearchQuery, SearchResult, SearchResultPager};
+use crate::github::repository_cache::RepositoryCache;
+use crate::github::retry::RetryConfig;
+use crate::types::{SearchCursor, SearchQuery, SearchResult, SearchResultPager, SearchSort};
 
 use super::graphql::error::classify_graphql_error;
 use super::graphql::graphql_types::{GraphQLPayload, GraphQLResponse};
+use crate::github::graphql::commit::query::{CommitVariable, commit_query};
 use crate::github::graphql::graphql_types::GraphQLQuery;
-use crate::github::graphql::graphql_types::issue::MultipleIssuesResponse;
-use crate::github::graphql::graphql_types::project::ProjectResourcesResponse;
-use crate::github::graphql::graphql_types::pull_request::MultiplePullRequestsResponse;
-use crate::github::graphql::graphql_types::repository::RepositoryResponse;
+use crate::github::graphql::graphql_types::ViewerResponse;
+use crate::github::graphql::graphql_types::commit::CommitResponse;
+use crate::github::graphql::graphql_types::issue::{
+    IssueCommentsResponse, IssueTimelineResponse, MilestoneIssuesResponse, MultipleIssuesResponse,
+};
+use crate::github::graphql::graphql_types::project::{
+    ProjectFieldsNode, ProjectFieldsResponse, ProjectResourcesResponse,
+    UpdateProjectItemFieldResponse,
+};
+use crate::github::graphql::graphql_types::pull_request::{
+    MultiplePullRequestsResponse, PullRequestChecksResponse, PullRequestCommitsResponse,
+    PullRequestReviewsResponse,
+};
+use crate::github::graphql::graphql_types::rate_limit::{RateLimitCostNode, RateLimitResponse};
+use crate::github::graphql::graphql_types::repository::{
+    RepositoryLanguagesResponse, RepositoryReleasesResponse, RepositoryResponse,
+    RepositoryStatsResponse,
+};
 use crate::github::graphql::issue::{
-    IssueQueryLimitSize, MultipleIssueVariable, multi_issue_query,
+    IssueCommentsVariable, IssueQueryLimitSize, IssueTimelineVariable, MilestoneIssuesVariable,
+    MultipleIssueVariable, issue_comments_query, issue_timeline_query, milestone_issues_query,
+    multi_issue_query,
 };
 use crate::github::graphql::project::query::{
-    ProjectVariable, single_project_query, user_project_query,
+    ProjectQueryLimitSize, ProjectVariable, UpdateProjectItemFieldVariable, project_fields_query,
+    single_project_query, update_project_item_field_mutation, user_project_fields_query,
+    user_project_query,
 };
 use crate::github::graphql::pull_request::query::PullRequestQueryLimitSize;
 use crate::github::graphql::pull_request::query::{
-    MultiplePullRequestVariable, multi_pull_reqeust_query,
+    MultiplePullRequestVariable, PullRequestChecksVariable, PullRequestCommitsVariable,
+    PullRequestReviewsVariable, multi_pull_reqeust_query, pull_request_checks_query,
+    pull_request_commits_query, pull_request_reviews_query,
 };
-use crate::github::graphql::repository::query::{RepositoryVariable, repository_query};
-use crate::github::graphql::search::normalize_repo_search_query;
-use crate::github::graphql::search::{SearchVariable, search_query};
+use crate::github::graphql::rate_limit::rate_limit_query;
+use crate::github::graphql::repository::query::{
+    RepositoryReleasesVariable, RepositoryVariable, repository_languages_query, repository_query,
+    repository_releases_query, repository_stats_query,
+};
+use crate::github::graphql::search::{
+    SearchPaging, SearchVariable, discussion_search_query, normalize_discussion_search_query,
+    search_query,
+};
+use crate::github::graphql::search::{apply_search_sort, normalize_repo_search_query};
+use crate::github::graphql::viewer::viewer_query;
 use crate::types::ProjectResource;
 
 use anyhow::{Context, Result};
 use octocrab::Octocrab;
 use reqwest;
 use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
 use tokio::time::Duration;
 
 use tokio::time::sleep;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 
 /// Default maximum number of retry attempts for API operations
 pub const DEFAULT_MAX_RETRY_COUNT: u32 = 15;
@@ -38,8 +72,20 @@ pub const DEFAULT_MAX_RETRY_COUNT: u32 = 15;
 /// Maximum number of pull requests to fetch in a single chunk
 pub const PULL_REQUEST_CHUNK_SIZE: usize = 30;
 
+/// Maximum number of issues to fetch in a single chunk
+pub const ISSUE_CHUNK_SIZE: usize = 30;
+
 const DEFAULT_SEARCH_RESULT_PER_PAGE: u32 = 30;
 
+/// Default base URL for GitHub REST API requests
+pub const DEFAULT_REST_BASE_URL: &str = "https://api.github.com";
+
+/// Environment variable overriding the REST API base URL (e.g. for caching proxies)
+pub const REST_BASE_URL_ENV: &str = "GITHUB_INSIGHT_REST_BASE_URL";
+
+/// Environment variable overriding the GraphQL endpoint URL (e.g. for caching proxies)
+pub const GRAPHQL_ENDPOINT_ENV: &str = "GITHUB_INSIGHT_GRAPHQL_ENDPOINT";
+
 pub trait GraphQLExecutor {
     #[allow(async_fn_in_trait)]
     async fn execute_graphql<T: Serialize, R: for<'de> Deserialize<'de>>(
@@ -53,6 +99,20 @@ pub trait GraphQLExecutor {
 pub struct GitHubClient {
     pub(crate) client: octocrab::Octocrab,
     github_token: Option<String>,
+    /// Base URL for REST API requests, without trailing slash
+    rest_base_url: String,
+    /// Full GraphQL endpoint URL; `None` uses octocrab's default `/graphql` route
+    graphql_endpoint: Option<String>,
+    /// Most recent deprecation notice seen in a REST response, shared across clones
+    last_deprecation_warning: Arc<Mutex<Option<DeprecationWarning>>>,
+    /// Opt-in cache of `fetch_repository` results, shared across clones
+    repository_cache: Option<RepositoryCache>,
+    /// Retry policy and per-attempt timeout of GraphQL requests
+    retry_config: RetryConfig,
+    /// Accumulated GraphQL point cost and optional budget, shared across clones
+    cost_budget: GraphQLCostBudget,
+    /// Concurrency of batch fetches built on this client; `None` keeps their default
+    max_concurrency: Option<usize>,
 }
 
 impl GitHubClient {
@@ -79,12 +139,154 @@ impl GitHubClient {
 
         let client = builder.build().context("Failed to build GitHub client")?;
 
+        let rest_base_url = std::env::var(REST_BASE_URL_ENV)
+            .ok()
+            .filter(|url| !url.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_REST_BASE_URL.to_string());
+        let graphql_endpoint = std::env::var(GRAPHQL_ENDPOINT_ENV)
+            .ok()
+            .filter(|url| !url.trim().is_empty());
+
         Ok(Self {
             client,
             github_token: token,
+            rest_base_url: rest_base_url.trim_end_matches('/').to_string(),
+            graphql_endpoint,
+            last_deprecation_warning: Arc::new(Mutex::new(None)),
+            repository_cache: None,
+            retry_config: RetryConfig::default(),
+            cost_budget: GraphQLCostBudget::default(),
+            max_concurrency: None,
         })
     }
 
+    /// Creates a client whose GraphQL requests follow the given retry policy and timeout
+    ///
+    /// `GitHubClient::new` uses `RetryConfig::default()`: 3 retries starting at 500ms
+    /// and a 10 second timeout per attempt.
+    pub fn new_with_retry(
+        token: Option<String>,
+        timeout: Option<Duration>,
+        retry_config: RetryConfig,
+    ) -> Result<Self> {
+        let mut client = Self::new(token, timeout)?;
+        client.retry_config = retry_config;
+        Ok(client)
+    }
+
+    /// Returns the retry policy of GraphQL requests
+    pub fn retry_config(&self) -> &RetryConfig {
+        &self.retry_config
+    }
+
+    /// Overrides the base URL used for REST API requests (pull request diffs and files)
+    ///
+    /// Useful when REST traffic is routed through a proxy with its own path,
+    /// e.g. `https://proxy.example.com/github/rest`.
+    pub fn with_rest_base_url(mut self, rest_base_url: impl Into<String>) -> Self {
+        self.rest_base_url = rest_base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Overrides the full URL used for GraphQL requests
+    ///
+    /// Useful when GraphQL traffic is routed through a proxy with its own path,
+    /// e.g. `https://proxy.example.com/github/graphql`.
+    pub fn with_graphql_endpoint(mut self, graphql_endpoint: impl Into<String>) -> Self {
+        self.graphql_endpoint = Some(graphql_endpoint.into());
+        self
+    }
+
+    /// Caches `fetch_repository` results in memory for `ttl`
+    ///
+    /// Useful when many issues or pull requests of the same repository are processed
+    /// in one session. The cache is shared by clones of the returned client.
+    pub fn with_repository_cache(mut self, ttl: Duration) -> Self {
+        self.repository_cache = Some(RepositoryCache::new(ttl));
+        self
+    }
+
+    /// Stops multi-page project fetches once the accumulated GraphQL cost exceeds `max_total_cost`
+    ///
+    /// The cost reported by each query is accumulated from this call on, shared by
+    /// clones of the returned client. A fetch stopped by the budget returns the items
+    /// fetched so far with the cursor of the next page.
+    pub fn with_max_total_cost(mut self, max_total_cost: u64) -> Self {
+        self.cost_budget = GraphQLCostBudget::new(Some(max_total_cost));
+        self
+    }
+
+    /// Limits how many requests batch fetches built on this client run at the same time
+    ///
+    /// `MultiResourceFetcher::new` and the other multi-repository fetches pick this up,
+    /// which keeps large batches clear of GitHub secondary rate limits. Values below 1
+    /// are treated as 1.
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = Some(max_concurrency.max(1));
+        self
+    }
+
+    /// Returns the concurrency set with `with_max_concurrency`, if any
+    pub fn max_concurrency(&self) -> Option<usize> {
+        self.max_concurrency
+    }
+
+    /// Returns the accumulated GraphQL cost and budget of this client
+    pub fn cost_budget(&self) -> &GraphQLCostBudget {
+        &self.cost_budget
+    }
+
+    /// Returns the base URL used for REST API requests
+    pub fn rest_base_url(&self) -> &str {
+        &self.rest_base_url
+    }
+
+    /// Returns the GraphQL endpoint override, if any
+    pub fn graphql_endpoint(&self) -> Option<&str> {
+        self.graphql_endpoint.as_deref()
+    }
+
+    /// Returns the most recent `Deprecation`/`Sunset` notice returned by GitHub, if any
+    ///
+    /// Only REST responses are inspected; GraphQL responses are decoded by octocrab,
+    /// which does not expose their headers.
+    pub fn last_deprecation_warning(&self) -> Option<DeprecationWarning> {
+        self.last_deprecation_warning
+            .lock()
+            .ok()
+            .and_then(|warning| warning.clone())
+    }
+
+    /// Record deprecation headers of a REST response, logging each distinct notice once
+    fn check_deprecation_headers(&self, url: &str, response: &reqwest::Response) {
+        if let Some(warning) = DeprecationWarning::from_headers(url, response.headers()) {
+            warning.warn_once();
+            if let Ok(mut last) = self.last_deprecation_warning.lock() {
+                *last = Some(warning);
+            }
+        }
+    }
+
+    /// Log the point cost selected by `RATE_LIMIT_COST_FRAGMENT` and add it to the budget
+    ///
+    /// Responses of queries without the fragment are ignored.
+    fn record_graphql_cost(&self, query_name: &str, data: Option<&serde_json::Value>) {
+        let Some(rate_limit) = data
+            .and_then(|data| data.get("rateLimit"))
+            .and_then(|rate_limit| {
+                serde_json::from_value::<RateLimitCostNode>(rate_limit.clone()).ok()
+            })
+        else {
+            return;
+        };
+
+        let total = self.cost_budget.record(rate_limit.cost);
+        debug!(
+            "GraphQL {} cost {} point(s), {} remaining, {} accumulated",
+            query_name, rate_limit.cost, rate_limit.remaining, total
+        );
+    }
+
     /// Searches for issues and pull requests using GitHub's Search API via GraphQL.
     ///
     /// This method performs a unified search across both issues and pull requests within
@@ -96,6 +298,7 @@ impl GitHubClient {
     /// * `query` - Search query string that follows GitHub's search syntax
     /// * `per_page` - Optional number of results per page (default: 5, GitHub API maximum: 100)
     /// * `cursor` - Optional cursor for pagination to fetch subsequent pages
+    /// * `sort` - Optional sort order; `None` keeps GitHub's default best-match ordering
     ///
     /// # Returns
     ///
@@ -125,7 +328,7 @@ impl GitHubClient {
     /// let query = SearchQuery::new("is:open label:bug");
     ///
     /// // Search for open issues with bug label
-    /// let search_result = client.search_resources(repo_id.clone(), query.clone(), Some(10), None).await?;
+    /// let search_result = client.search_resources(repo_id.clone(), query.clone(), Some(10), None, None).await?;
     ///
     /// for result in search_result.issue_or_pull_requests {
     ///     match result {
@@ -143,7 +346,7 @@ impl GitHubClient {
     ///     if pager.has_next_page {
     ///         if let Some(cursor) = pager.next_page_cursor {
     ///             // Fetch next page
-    ///             let next_results = client.search_resources(repo_id, query, Some(10), Some(cursor)).await?;
+    ///             let next_results = client.search_resources(repo_id, query, Some(10), Some(cursor), None).await?;
     ///         }
     ///     }
     /// }
@@ -179,22 +382,49 @@ impl GitHubClient {
         query: SearchQuery,
         per_page: Option<u32>,
         cursor: Option<SearchCursor>,
+        sort: Option<SearchSort>,
+    ) -> Result<SearchResult> {
+        self.search_resources_page(repository_id, query, per_page, cursor, None, sort)
+            .await
+    }
+
+    /// Searches issues and pull requests in a repository, paging in either direction
+    ///
+    /// `after` pages forward like `search_resources`. `before` pages backward and
+    /// returns the `per_page` results preceding that cursor, as reported by the
+    /// `previous_page_cursor` of an earlier page; it takes precedence over `after`.
+    /// The returned pager is set whenever a next or previous page exists.
+    pub async fn search_resources_page(
+        &self,
+        repository_id: crate::types::RepositoryId,
+        query: SearchQuery,
+        per_page: Option<u32>,
+        after: Option<SearchCursor>,
+        before: Option<SearchCursor>,
+        sort: Option<SearchSort>,
     ) -> Result<SearchResult> {
         let per_page_value = per_page.unwrap_or(DEFAULT_SEARCH_RESULT_PER_PAGE); //default
-        let has_cursor = cursor.is_some();
+        let paging = match (&after, &before) {
+            (_, Some(_)) => SearchPaging::Before,
+            (Some(_), None) => SearchPaging::After,
+            (None, None) => SearchPaging::First,
+        };
 
-        let query = normalize_repo_search_query(query, &repository_id);
+        let query = apply_search_sort(normalize_repo_search_query(query, &repository_id), sort);
 
         let graphql_query = search_query(
             IssueQueryLimitSize::default(),
             PullRequestQueryLimitSize::default(),
-            has_cursor,
+            paging,
         );
 
         let variables = SearchVariable {
             query: query.as_str().to_string(),
             per_page: per_page_value,
-            cursor: cursor.as_ref().map(|c| c.0.clone()),
+            cursor: after
+                .filter(|_| paging == SearchPaging::After)
+                .map(|cursor| cursor.0),
+            before: before.map(|cursor| cursor.0),
         };
 
         let payload = GraphQLPayload {
@@ -244,7 +474,8 @@ impl GitHubClient {
         }
 
         // Create pagination information
-        let next_pager = if data.search.page_info.has_next_page {
+        let page_info = &data.search.page_info;
+        let next_pager = if page_info.has_next_page || page_info.has_previous_page {
             Some(data.search.page_info.into())
         } else {
             None
@@ -257,6 +488,150 @@ impl GitHubClient {
         })
     }
 
+    /// Searches discussions in a repository
+    ///
+    /// Runs a `type: DISCUSSION` search, which is separate from the issue and pull
+    /// request search. `repo:` and type qualifiers in the query are replaced with the
+    /// target repository. Returns at most `per_page` discussions from the first page.
+    pub async fn search_discussions(
+        &self,
+        repository_id: crate::types::RepositoryId,
+        query: SearchQuery,
+        per_page: Option<u32>,
+    ) -> Result<Vec<crate::types::Discussion>> {
+        let query = normalize_discussion_search_query(query, &repository_id);
+
+        let variables = SearchVariable {
+            query: query.as_str().to_string(),
+            per_page: per_page.unwrap_or(DEFAULT_SEARCH_RESULT_PER_PAGE),
+            cursor: None,
+            before: None,
+        };
+
+        let payload = GraphQLPayload {
+            query: GraphQLQuery(discussion_search_query()),
+            variables: Some(variables),
+        };
+
+        let response: GraphQLResponse<crate::github::graphql::graphql_types::SearchResponse> =
+            self.execute_graphql("discussion_search", payload).await?;
+
+        let data = response
+            .data
+            .ok_or_else(|| anyhow::anyhow!("No data in GraphQL discussion search response"))?;
+
+        Ok(data
+            .search
+            .nodes
+            .into_iter()
+            .filter_map(|node| match node {
+                crate::github::graphql::graphql_types::SearchResult::Discussion(node) => {
+                    Some(crate::types::Discussion::from(node))
+                }
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Fetches the GraphQL API rate limit budget of the authenticated client
+    ///
+    /// Returns the hourly point limit, remaining and used points, the cost of this
+    /// query, and when the current window resets. Useful to check the remaining budget
+    /// before running large searches or project fetches.
+    pub async fn fetch_rate_limit(&self) -> Result<crate::types::RateLimitStatus> {
+        let payload = GraphQLPayload::<()> {
+            query: GraphQLQuery(rate_limit_query()),
+            variables: None,
+        };
+
+        let response: GraphQLResponse<RateLimitResponse> =
+            self.execute_graphql("fetch_rate_limit", payload).await?;
+
+        let rate_limit = response
+            .data
+            .and_then(|data| data.rate_limit)
+            .ok_or_else(|| anyhow::anyhow!("No rateLimit in GraphQL response"))?;
+
+        Ok(rate_limit.into())
+    }
+
+    /// Returns true when the client was created with a GitHub token
+    pub fn has_token(&self) -> bool {
+        self.github_token.is_some()
+    }
+
+    /// Fetches the login of the user the configured token belongs to
+    ///
+    /// Uses the GraphQL `viewer` query, so it fails with a "Bad credentials" error
+    /// when the token is invalid or expired.
+    pub async fn fetch_viewer_login(&self) -> Result<String> {
+        let payload = GraphQLPayload::<()> {
+            query: GraphQLQuery(viewer_query()),
+            variables: None,
+        };
+
+        let response: GraphQLResponse<ViewerResponse> =
+            self.execute_graphql("fetch_viewer_login", payload).await?;
+
+        let viewer = response
+            .data
+            .and_then(|data| data.viewer)
+            .ok_or_else(|| anyhow::anyhow!("No viewer in GraphQL response"))?;
+
+        Ok(viewer.login)
+    }
+
+    /// Fetches the OAuth scopes granted to the configured token
+    ///
+    /// GitHub reports the scopes in the `X-OAuth-Scopes` header of REST responses.
+    /// Returns `None` when the header is absent, which is the case for fine-grained
+    /// personal access tokens and GitHub App tokens.
+    pub async fn fetch_token_scopes(&self) -> Result<Option<Vec<String>>> {
+        let url = format!("{}/user", self.rest_base_url);
+        let req_client = reqwest::Client::new();
+
+        let scopes = retry_with_backoff("fetch_token_scopes", Some(3), || {
+            let mut request = req_client
+                .get(&url)
+                .header("Accept", "application/vnd.github.v3+json")
+                .header("User-Agent", "github-insight");
+
+            if let Some(token) = &self.github_token {
+                request = request.header("Authorization", format!("Bearer {}", token));
+            }
+
+            let url = &url;
+            async move {
+                let response = request.send().await.map_err(|e| {
+                    ApiRetryableError::from_reqwest_error("Failed to fetch token scopes", e)
+                })?;
+                self.check_deprecation_headers(url, &response);
+
+                if !response.status().is_success() {
+                    let status = response.status().as_u16();
+                    let body = response.text().await.unwrap_or_default();
+                    return Err(ApiRetryableError::from_rest_status(status, &body));
+                }
+
+                Ok(response
+                    .headers()
+                    .get("x-oauth-scopes")
+                    .and_then(|value| value.to_str().ok())
+                    .map(|value| {
+                        value
+                            .split(',')
+                            .map(str::trim)
+                            .filter(|scope| !scope.is_empty())
+                            .map(str::to_string)
+                            .collect()
+                    }))
+            }
+        })
+        .await?;
+
+        Ok(scopes)
+    }
+
     /// Fetches multiple pull requests by their numbers
     pub async fn fetch_multiple_pull_requests_by_numbers(
         &self,
@@ -309,12 +684,16 @@ impl GitHubClient {
             .data
             .ok_or_else(|| anyhow::anyhow!("No data in GraphQL multiple_pullrequest response"))?;
 
+        let repository_archived = data.repository.is_archived.unwrap_or(false);
         let mut chunk_pull_requests = Vec::new();
         // Convert GraphQL response to domain objects
         for (pr_key, maybe_pr_node) in data.repository.pull_requests {
             if let Some(pr_node) = maybe_pr_node {
                 match crate::types::PullRequest::try_from((pr_node, repository_id.clone())) {
-                    Ok(pull_request) => chunk_pull_requests.push(pull_request),
+                    Ok(mut pull_request) => {
+                        pull_request.repository_archived = repository_archived;
+                        chunk_pull_requests.push(pull_request)
+                    }
                     Err(e) => {
                         warn!("Failed to convert pull request {}: {}", pr_key, e);
                         return Err(e);
@@ -328,17 +707,201 @@ impl GitHubClient {
         Ok(chunk_pull_requests)
     }
 
+    /// Fetches the reviews of a pull request using GraphQL
+    ///
+    /// Returns up to 100 reviews in submission order with reviewer login, state,
+    /// submission timestamp, and body. Reviews with unknown states are skipped.
+    pub async fn fetch_pull_request_reviews(
+        &self,
+        repository_id: crate::types::RepositoryId,
+        pr_number: crate::types::PullRequestNumber,
+    ) -> Result<Vec<crate::types::PullRequestReview>> {
+        let variables = PullRequestReviewsVariable {
+            owner: repository_id.owner.clone(),
+            repository_name: repository_id.repository_name.clone(),
+            pull_request_number: pr_number,
+        };
+
+        let payload = GraphQLPayload {
+            query: GraphQLQuery(pull_request_reviews_query()),
+            variables: Some(variables),
+        };
+
+        let response: GraphQLResponse<PullRequestReviewsResponse> = self
+            .execute_graphql("fetch_pull_request_reviews", payload)
+            .await?;
+
+        let data = response
+            .data
+            .ok_or_else(|| anyhow::anyhow!("No data in GraphQL pull request reviews response"))?;
+
+        let pr_node = data
+            .repository
+            .ok_or_else(|| anyhow::anyhow!("Repository not found: {}", repository_id))?
+            .pull_request
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Pull request not found: {}",
+                    crate::types::PullRequestId::new(repository_id.clone(), pr_number.value())
+                        .url()
+                )
+            })?;
+
+        Ok(pr_node
+            .reviews
+            .nodes
+            .iter()
+            .filter_map(|review| review.to_pull_request_review())
+            .collect())
+    }
+
+    /// Fetches all commits of a pull request using GraphQL
+    ///
+    /// Follows every page of the pull request's `commits` connection and returns the
+    /// commits oldest first with SHA, message, author, and authored date. A pull
+    /// request without commits yields an empty list.
+    pub async fn fetch_pull_request_commits(
+        &self,
+        repository_id: crate::types::RepositoryId,
+        pr_number: crate::types::PullRequestNumber,
+    ) -> Result<Vec<crate::types::CommitSummary>> {
+        let query = pull_request_commits_query();
+        let mut commits = Vec::new();
+        let mut cursor: Option<SearchCursor> = None;
+
+        loop {
+            let variables = PullRequestCommitsVariable {
+                owner: repository_id.owner.clone(),
+                repository_name: repository_id.repository_name.clone(),
+                pull_request_number: pr_number,
+                cursor: cursor.clone(),
+            };
+
+            let payload = GraphQLPayload {
+                query: GraphQLQuery(query.clone()),
+                variables: Some(variables),
+            };
+
+            let response: GraphQLResponse<PullRequestCommitsResponse> = self
+                .execute_graphql("fetch_pull_request_commits", payload)
+                .await?;
+
+            let data = response.data.ok_or_else(|| {
+                anyhow::anyhow!("No data in GraphQL pull request commits response")
+            })?;
+
+            let pr_node = data
+                .repository
+                .ok_or_else(|| anyhow::anyhow!("Repository not found: {}", repository_id))?
+                .pull_request
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Pull request not found: {}",
+                        crate::types::PullRequestId::new(repository_id.clone(), pr_number.value())
+                            .url()
+                    )
+                })?;
+
+            commits.extend(
+                pr_node
+                    .commits
+                    .nodes
+                    .into_iter()
+                    .map(|node| crate::types::CommitSummary::from(node.commit)),
+            );
+
+            let page_info = pr_node.commits.page_info;
+            match page_info.end_cursor {
+                Some(end_cursor) if page_info.has_next_page => {
+                    cursor = Some(SearchCursor(end_cursor));
+                }
+                _ => break,
+            }
+        }
+
+        Ok(commits)
+    }
+
+    /// Fetches the CI checks of the head commit of a pull request using GraphQL
+    ///
+    /// Reads the `statusCheckRollup` of the last commit, covering both check runs and
+    /// commit statuses with their name, conclusion, and details URL. A pull request
+    /// without CI configured yields no checks and no combined state.
+    pub async fn fetch_pull_request_checks(
+        &self,
+        repository_id: crate::types::RepositoryId,
+        pr_number: crate::types::PullRequestNumber,
+    ) -> Result<crate::types::PullRequestChecks> {
+        let variables = PullRequestChecksVariable {
+            owner: repository_id.owner.clone(),
+            repository_name: repository_id.repository_name.clone(),
+            pull_request_number: pr_number,
+        };
+
+        let payload = GraphQLPayload {
+            query: GraphQLQuery(pull_request_checks_query()),
+            variables: Some(variables),
+        };
+
+        let response: GraphQLResponse<PullRequestChecksResponse> = self
+            .execute_graphql("fetch_pull_request_checks", payload)
+            .await?;
+
+        let data = response
+            .data
+            .ok_or_else(|| anyhow::anyhow!("No data in GraphQL pull request checks response"))?;
+
+        let pull_request_id =
+            crate::types::PullRequestId::new(repository_id.clone(), pr_number.value());
+        let pr_node = data
+            .repository
+            .ok_or_else(|| anyhow::anyhow!("Repository not found: {}", repository_id))?
+            .pull_request
+            .ok_or_else(|| anyhow::anyhow!("Pull request not found: {}", pull_request_id.url()))?;
+
+        Ok(pr_node.into_pull_request_checks(pull_request_id))
+    }
+
     /// Fetches multiple issues by their numbers
+    ///
+    /// Issues are returned in the order of `issue_numbers`; numbers that are not found
+    /// or inaccessible are skipped with a warning. `comment_limit` bounds the number of
+    /// comments fetched inline with each issue (clamped to 100, the default when `None`).
     pub async fn fetch_multiple_issues_by_numbers(
         &self,
         repository_id: crate::types::RepositoryId,
         issue_numbers: &[crate::types::IssueNumber],
+        comment_limit: Option<u32>,
     ) -> Result<Vec<crate::types::Issue>> {
         if issue_numbers.is_empty() {
             return Ok(Vec::new());
         }
 
-        let query = multi_issue_query(issue_numbers, IssueQueryLimitSize::default());
+        let mut all_issues = Vec::new();
+
+        // Process issues in chunks to avoid GraphQL node and complexity limits
+        for chunk in issue_numbers.chunks(ISSUE_CHUNK_SIZE) {
+            let chunk_result = self
+                .fetch_issue_chunk(repository_id.clone(), chunk, comment_limit)
+                .await?;
+            all_issues.extend(chunk_result);
+        }
+
+        Ok(all_issues)
+    }
+
+    /// Fetches a single chunk of issues
+    async fn fetch_issue_chunk(
+        &self,
+        repository_id: crate::types::RepositoryId,
+        issue_numbers: &[crate::types::IssueNumber],
+        comment_limit: Option<u32>,
+    ) -> Result<Vec<crate::types::Issue>> {
+        let limit_size = match comment_limit {
+            Some(comment_limit) => IssueQueryLimitSize::default().with_comment_limit(comment_limit),
+            None => IssueQueryLimitSize::default(),
+        };
+        let query = multi_issue_query(issue_numbers, limit_size);
         let variables = MultipleIssueVariable {
             owner: repository_id.owner.clone(),
             repository_name: repository_id.repository_name.clone(),
@@ -359,12 +922,25 @@ impl GitHubClient {
             .data
             .ok_or_else(|| anyhow::anyhow!("No data in GraphQL multiple_issues response"))?;
 
-        let mut all_issues = Vec::new();
+        let repository_archived = data.repository.is_archived.unwrap_or(false);
+        // Aliases are `issue{index}`; sort by index to keep the requested order
+        let mut issue_entries: Vec<_> = data.repository.issues.into_iter().collect();
+        issue_entries.sort_by_key(|(issue_key, _)| {
+            issue_key
+                .trim_start_matches("issue")
+                .parse::<usize>()
+                .unwrap_or(usize::MAX)
+        });
+
+        let mut chunk_issues = Vec::new();
         // Convert GraphQL response to domain objects
-        for (issue_key, maybe_issue_node) in data.repository.issues {
+        for (issue_key, maybe_issue_node) in issue_entries {
             if let Some(issue_node) = maybe_issue_node {
                 match crate::types::Issue::try_from(issue_node) {
-                    Ok(issue) => all_issues.push(issue),
+                    Ok(mut issue) => {
+                        issue.repository_archived = repository_archived;
+                        chunk_issues.push(issue)
+                    }
                     Err(e) => {
                         warn!("Failed to convert issue {}: {}", issue_key, e);
                         return Err(e);
@@ -375,69 +951,241 @@ impl GitHubClient {
             }
         }
 
-        Ok(all_issues)
+        Ok(chunk_issues)
     }
 
-    /// Convert a project node to a vector of project resources
-    async fn convert_project_to_resources(
+    /// Fetches all issues of a milestone in the given state using GraphQL
+    ///
+    /// Pages through the milestone's issues until exhausted. A milestone number that
+    /// does not exist in the repository yields an empty list and a warning rather than
+    /// an error.
+    pub async fn fetch_issues_by_milestone(
         &self,
-        project: crate::github::graphql::graphql_types::project::ProjectNode,
-    ) -> Result<(
-        Vec<crate::types::ProjectResource>,
-        Option<SearchResultPager>,
-    )> {
-        let mut resources = Vec::new();
-        let mut pager = None;
+        repository_id: crate::types::RepositoryId,
+        milestone_number: crate::types::MilestoneNumber,
+        state: crate::types::IssueState,
+    ) -> Result<Vec<crate::types::Issue>> {
+        let query = milestone_issues_query(IssueQueryLimitSize::default());
+        let mut issues = Vec::new();
+        let mut cursor: Option<SearchCursor> = None;
 
-        if let Some(items) = project.items {
-            for item in items.nodes {
-                match ProjectResource::try_from(item.clone()) {
-                    Ok(resource) => resources.push(resource),
-                    Err(e) => {
-                        warn!(
-                            "Failed to convert project item to resource: {}. Item ID: {}, Content: {:?}",
-                            e, item.id, item.content
-                        );
-                        // Continue processing other items instead of failing the entire operation
-                    }
-                }
+        loop {
+            let variables = MilestoneIssuesVariable {
+                owner: repository_id.owner().clone(),
+                repository_name: repository_id.repo_name().clone(),
+                milestone_number,
+                states: vec![state.to_string()],
+                cursor: cursor.clone(),
+            };
+
+            let payload = GraphQLPayload {
+                query: GraphQLQuery(query.clone()),
+                variables: Some(variables),
+            };
+
+            let response: GraphQLResponse<MilestoneIssuesResponse> = self
+                .execute_graphql("fetch_issues_by_milestone", payload)
+                .await?;
+
+            let data = response
+                .data
+                .ok_or_else(|| anyhow::anyhow!("No data in GraphQL milestone issues response"))?;
+
+            let repository = data
+                .repository
+                .ok_or_else(|| anyhow::anyhow!("Repository not found: {}", repository_id))?;
+
+            let Some(milestone) = repository.milestone else {
+                warn!(
+                    "Milestone {} not found in {}; returning no issues",
+                    milestone_number, repository_id
+                );
+                return Ok(Vec::new());
+            };
+
+            for issue_node in milestone.issues.nodes {
+                issues.push(crate::types::Issue::try_from(issue_node)?);
             }
 
-            // Extract pagination information from items connection
-            if let Some(page_info) = items.page_info {
-                if page_info.has_next_page {
-                    pager = Some(page_info.into());
+            let page_info = milestone.issues.page_info;
+            match page_info.end_cursor {
+                Some(end_cursor) if page_info.has_next_page => {
+                    cursor = Some(SearchCursor(end_cursor));
                 }
+                _ => break,
             }
         }
 
-        Ok((resources, pager))
+        Ok(issues)
     }
 
-    /// Try to fetch project resources using user project query
-    async fn try_user_project_query(
+    /// Fetches the cross-reference timeline events of an issue using GraphQL
+    ///
+    /// Returns up to 100 cross-referenced, connected, and disconnected events in
+    /// chronological order. Events whose source is not an issue or pull request
+    /// are skipped.
+    pub async fn fetch_issue_timeline(
         &self,
-        project_id: &crate::types::ProjectId,
-        cursor: Option<SearchCursor>,
-    ) -> Result<(
-        Vec<crate::types::ProjectResource>,
-        Option<SearchResultPager>,
-    )> {
-        let user_start = std::time::Instant::now();
-        let user_query = user_project_query(project_id.project_number(), cursor);
-        let variables = ProjectVariable {
-            owner: project_id.owner().clone(),
+        issue_id: crate::types::IssueId,
+    ) -> Result<crate::types::IssueTimeline> {
+        let variables = IssueTimelineVariable {
+            owner: issue_id.git_repository.owner().clone(),
+            repository_name: issue_id.git_repository.repo_name().clone(),
+            issue_number: crate::types::IssueNumber::new(issue_id.number),
         };
 
         let payload = GraphQLPayload {
-            query: GraphQLQuery(user_query),
+            query: GraphQLQuery(issue_timeline_query()),
             variables: Some(variables),
         };
 
-        let response: GraphQLResponse<ProjectResourcesResponse> =
-            self.execute_graphql("project_resources", payload).await?;
+        let response: GraphQLResponse<IssueTimelineResponse> = self
+            .execute_graphql("fetch_issue_timeline", payload)
+            .await?;
 
-        info!("User project query took: {:?}", user_start.elapsed());
+        let data = response
+            .data
+            .ok_or_else(|| anyhow::anyhow!("No data in GraphQL issue timeline response"))?;
+
+        let issue_node = data
+            .repository
+            .ok_or_else(|| anyhow::anyhow!("Repository not found: {}", issue_id.git_repository))?
+            .issue
+            .ok_or_else(|| anyhow::anyhow!("Issue not found: {}", issue_id.url()))?;
+
+        let events = issue_node
+            .timeline_items
+            .nodes
+            .iter()
+            .filter_map(|item| item.to_timeline_event())
+            .collect();
+
+        Ok(crate::types::IssueTimeline {
+            issue_id,
+            issue_title: issue_node.title,
+            events,
+        })
+    }
+
+    /// Fetches one page of an issue's comments using GraphQL
+    ///
+    /// # Arguments
+    ///
+    /// * `repository_id` - The repository identifier containing owner and repository name
+    /// * `issue_number` - The issue whose comments are fetched
+    /// * `cursor` - Cursor returned by the previous page, or `None` for the first page
+    /// * `per_page` - Number of comments per page (clamped to 1..=100)
+    ///
+    /// # Returns
+    ///
+    /// Returns the comments of the page and a pager for the next page, which is `None`
+    /// when there are no further comments.
+    pub async fn fetch_issue_comments_paginated(
+        &self,
+        repository_id: crate::types::RepositoryId,
+        issue_number: crate::types::IssueNumber,
+        cursor: Option<SearchCursor>,
+        per_page: u32,
+    ) -> Result<(Vec<crate::types::IssueComment>, Option<SearchResultPager>)> {
+        let variables = IssueCommentsVariable {
+            owner: repository_id.owner().clone(),
+            repository_name: repository_id.repo_name().clone(),
+            issue_number,
+            per_page: per_page.clamp(1, 100),
+            cursor,
+        };
+
+        let payload = GraphQLPayload {
+            query: GraphQLQuery(issue_comments_query()),
+            variables: Some(variables),
+        };
+
+        let response: GraphQLResponse<IssueCommentsResponse> = self
+            .execute_graphql("fetch_issue_comments_paginated", payload)
+            .await?;
+
+        let data = response
+            .data
+            .ok_or_else(|| anyhow::anyhow!("No data in GraphQL issue comments response"))?;
+
+        let issue_node = data
+            .repository
+            .ok_or_else(|| anyhow::anyhow!("Repository not found: {}", repository_id))?
+            .issue
+            .ok_or_else(|| {
+                anyhow::anyhow!("Issue not found: {}#{}", repository_id, issue_number)
+            })?;
+
+        issue_node
+            .comments
+            .into_issue_comments_page()
+            .context(format!(
+                "Failed to convert comments of issue {}#{}",
+                repository_id, issue_number
+            ))
+    }
+
+    /// Convert a project node to a vector of project resources
+    async fn convert_project_to_resources(
+        &self,
+        project: crate::github::graphql::graphql_types::project::ProjectNode,
+    ) -> Result<(
+        Vec<crate::types::ProjectResource>,
+        Option<SearchResultPager>,
+    )> {
+        let mut resources = Vec::new();
+        let mut pager = None;
+
+        if let Some(items) = project.items {
+            for item in items.nodes {
+                match ProjectResource::try_from(item.clone()) {
+                    Ok(resource) => resources.push(resource),
+                    Err(e) => {
+                        warn!(
+                            "Failed to convert project item to resource: {}. Item ID: {}, Content: {:?}",
+                            e, item.id, item.content
+                        );
+                        // Continue processing other items instead of failing the entire operation
+                    }
+                }
+            }
+
+            // Extract pagination information from items connection
+            if let Some(page_info) = items.page_info {
+                if page_info.has_next_page {
+                    pager = Some(page_info.into());
+                }
+            }
+        }
+
+        Ok((resources, pager))
+    }
+
+    /// Try to fetch project resources using user project query
+    async fn try_user_project_query(
+        &self,
+        project_id: &crate::types::ProjectId,
+        limit_size: ProjectQueryLimitSize,
+        cursor: Option<SearchCursor>,
+    ) -> Result<(
+        Vec<crate::types::ProjectResource>,
+        Option<SearchResultPager>,
+    )> {
+        let user_start = std::time::Instant::now();
+        let user_query = user_project_query(project_id.project_number(), limit_size, cursor);
+        let variables = ProjectVariable {
+            owner: project_id.owner().clone(),
+        };
+
+        let payload = GraphQLPayload {
+            query: GraphQLQuery(user_query),
+            variables: Some(variables),
+        };
+
+        let response: GraphQLResponse<ProjectResourcesResponse> =
+            self.execute_graphql("project_resources", payload).await?;
+
+        info!("User project query took: {:?}", user_start.elapsed());
 
         if let Some(data) = response.data {
             if let Some(user) = data.user {
@@ -454,13 +1202,14 @@ impl GitHubClient {
     async fn try_organization_project_query(
         &self,
         project_id: &crate::types::ProjectId,
+        limit_size: ProjectQueryLimitSize,
         cursor: Option<SearchCursor>,
     ) -> Result<(
         Vec<crate::types::ProjectResource>,
         Option<SearchResultPager>,
     )> {
         let org_start = std::time::Instant::now();
-        let org_query = single_project_query(project_id.project_number(), cursor);
+        let org_query = single_project_query(project_id.project_number(), limit_size, cursor);
         let variables = ProjectVariable {
             owner: project_id.owner().clone(),
         };
@@ -489,94 +1238,170 @@ impl GitHubClient {
         ))
     }
 
-    /// Iteratively fetch all pages of project resources using pagination
+    /// Iteratively fetch pages of project resources until the last page or a cap
+    ///
+    /// Pages ask only for the items still allowed by `limits.max_items`, so the returned
+    /// cursor resumes right after the last returned item.
     async fn fetch_all_project_resources_with_pager(
         &self,
         project_id: &crate::types::ProjectId,
         is_user_project: bool,
-    ) -> Result<Vec<crate::types::ProjectResource>> {
+        limits: crate::types::ProjectFetchLimits,
+        start_cursor: Option<SearchCursor>,
+    ) -> Result<crate::types::ProjectResourcesFetch> {
+        let start_time = std::time::Instant::now();
         let mut all_resources = Vec::new();
-        let mut current_cursor = None;
+        let mut current_cursor = start_cursor;
+        let mut pages = 0;
 
         loop {
+            let item_limit = limits
+                .max_items
+                .map(|max_items| max_items.saturating_sub(all_resources.len()).clamp(1, 100))
+                .unwrap_or(100);
+            let limit_size = ProjectQueryLimitSize::default().with_item_limit(item_limit as u8);
+
             let (resources, pager) = if is_user_project {
-                self.try_user_project_query(project_id, current_cursor)
+                self.try_user_project_query(project_id, limit_size, current_cursor)
                     .await?
             } else {
-                self.try_organization_project_query(project_id, current_cursor)
+                self.try_organization_project_query(project_id, limit_size, current_cursor)
                     .await?
             };
 
             // Add current page resources to accumulated results
             all_resources.extend(resources);
+            pages += 1;
 
-            // Check if there's a next page
-            if let Some(pager) = pager {
-                if pager.has_next_page {
-                    if let Some(next_cursor) = pager.next_page_cursor {
-                        info!("Fetching next page for project {} with cursor", project_id);
-                        current_cursor = Some(next_cursor);
-                        continue;
-                    }
-                }
+            // Stop when there is no next page
+            let Some(next_cursor) = pager
+                .filter(|pager| pager.has_next_page)
+                .and_then(|pager| pager.next_page_cursor)
+            else {
+                break;
+            };
+
+            if self.cost_budget.is_exceeded() {
+                warn!(
+                    "Stopped fetching project {} after {} page(s) and {} item(s): GraphQL cost {} exceeds the budget of {}; more items remain",
+                    project_id,
+                    pages,
+                    all_resources.len(),
+                    self.cost_budget.spent(),
+                    self.cost_budget.max_total_cost().unwrap_or_default()
+                );
+                return Ok(crate::types::ProjectResourcesFetch {
+                    resources: all_resources,
+                    next_cursor: Some(next_cursor),
+                });
             }
 
-            // No more pages, break the loop
-            break;
+            if limits.is_reached(pages, all_resources.len(), start_time.elapsed()) {
+                warn!(
+                    "Stopped fetching project {} after {} page(s) and {} item(s); more items remain",
+                    project_id,
+                    pages,
+                    all_resources.len()
+                );
+                return Ok(crate::types::ProjectResourcesFetch {
+                    resources: all_resources,
+                    next_cursor: Some(next_cursor),
+                });
+            }
+
+            info!("Fetching next page for project {} with cursor", project_id);
+            current_cursor = Some(next_cursor);
         }
 
-        Ok(all_resources)
+        Ok(crate::types::ProjectResourcesFetch {
+            resources: all_resources,
+            next_cursor: None,
+        })
     }
 
+    /// Fetches all items of a project, following pagination
+    ///
+    /// When `field_filters` is not empty, only items having a matching value for every
+    /// `(field name, value)` pair are returned. Filtering happens client-side after all
+    /// pages are fetched; field names are compared case-insensitively.
     pub async fn fetch_all_project_resources(
         &self,
         project_id: crate::types::ProjectId,
+        field_filters: &[(String, String)],
     ) -> Result<Vec<crate::types::ProjectResource>> {
+        let fetch = self
+            .fetch_project_resources_with_limits(
+                project_id,
+                field_filters,
+                crate::types::ProjectFetchLimits::default(),
+                None,
+            )
+            .await?;
+        Ok(fetch.resources)
+    }
+
+    /// Fetches the items of a project page by page until the last page or a cap is reached
+    ///
+    /// When `limits` stop the fetch early, the items fetched so far are returned with
+    /// the cursor of the next page; pass it as `cursor` to resume. Field filters are
+    /// applied to the fetched items as in `fetch_all_project_resources`.
+    pub async fn fetch_project_resources_with_limits(
+        &self,
+        project_id: crate::types::ProjectId,
+        field_filters: &[(String, String)],
+        limits: crate::types::ProjectFetchLimits,
+        cursor: Option<SearchCursor>,
+    ) -> Result<crate::types::ProjectResourcesFetch> {
         let start_time = std::time::Instant::now();
         info!(
-            "Starting fetch_all_project_resources for project {}",
+            "Starting fetch_project_resources_with_limits for project {}",
             project_id
         );
 
         // Use project type to determine which query to try first
-        let all_resources = match project_id.project_type() {
-            crate::types::ProjectType::User => {
-                // Try user project first for user projects
-                match self
-                    .fetch_all_project_resources_with_pager(&project_id, true)
-                    .await
-                {
-                    Ok(resources) => resources,
-                    Err(_) => {
-                        // Fallback to organization query if user query fails
-                        self.fetch_all_project_resources_with_pager(&project_id, false)
-                            .await?
-                    }
-                }
-            }
-            crate::types::ProjectType::Organization => {
-                // Try organization project first for organization projects
-                match self
-                    .fetch_all_project_resources_with_pager(&project_id, false)
-                    .await
-                {
-                    Ok(resources) => resources,
-                    Err(_) => {
-                        // Fallback to user query if organization query fails
-                        self.fetch_all_project_resources_with_pager(&project_id, true)
-                            .await?
-                    }
-                }
+        let is_user_project = matches!(project_id.project_type(), crate::types::ProjectType::User);
+        let mut fetch = match self
+            .fetch_all_project_resources_with_pager(
+                &project_id,
+                is_user_project,
+                limits,
+                cursor.clone(),
+            )
+            .await
+        {
+            Ok(fetch) => fetch,
+            Err(_) => {
+                // Fallback to the other owner type if the first query fails
+                self.fetch_all_project_resources_with_pager(
+                    &project_id,
+                    !is_user_project,
+                    limits,
+                    cursor,
+                )
+                .await?
             }
         };
 
         info!(
-            "Total fetch_all_project_resources took: {:?}, fetched {} resources",
+            "Total fetch_project_resources_with_limits took: {:?}, fetched {} resources",
             start_time.elapsed(),
-            all_resources.len()
+            fetch.resources.len()
         );
 
-        Ok(all_resources)
+        if field_filters.is_empty() {
+            return Ok(fetch);
+        }
+
+        fetch
+            .resources
+            .retain(|resource| resource.matches_field_filters(field_filters));
+        info!(
+            "{} resources of project {} matched field filters",
+            fetch.resources.len(),
+            project_id
+        );
+
+        Ok(fetch)
     }
 
     /// Fetches a single project by its identifier
@@ -675,7 +1500,11 @@ impl GitHubClient {
         &self,
         project_id: &crate::types::ProjectId,
     ) -> Result<crate::github::graphql::graphql_types::project::ProjectNode> {
-        let query = user_project_query(project_id.project_number(), None);
+        let query = user_project_query(
+            project_id.project_number(),
+            ProjectQueryLimitSize::default(),
+            None,
+        );
         let variables = ProjectVariable {
             owner: project_id.owner().clone(),
         };
@@ -704,7 +1533,11 @@ impl GitHubClient {
         &self,
         project_id: &crate::types::ProjectId,
     ) -> Result<crate::github::graphql::graphql_types::project::ProjectNode> {
-        let query = single_project_query(project_id.project_number(), None);
+        let query = single_project_query(
+            project_id.project_number(),
+            ProjectQueryLimitSize::default(),
+            None,
+        );
         let variables = ProjectVariable {
             owner: project_id.owner().clone(),
         };
@@ -731,10 +1564,124 @@ impl GitHubClient {
         ))
     }
 
+    /// Fetches the field definitions of a project
+    ///
+    /// Returns every field with its id, name, and data type; single select fields also
+    /// carry their options with option ids, as needed for field value updates. Like
+    /// `fetch_project`, the query matching the project type is tried first and the
+    /// other owner type is used as a fallback.
+    pub async fn fetch_project_fields(
+        &self,
+        project_id: crate::types::ProjectId,
+    ) -> Result<Vec<crate::types::ProjectField>> {
+        let fields_node = match project_id.project_type() {
+            crate::types::ProjectType::User => {
+                match self.try_project_fields_query(&project_id, true).await {
+                    Ok(fields_node) => fields_node,
+                    Err(_) => self.try_project_fields_query(&project_id, false).await?,
+                }
+            }
+            crate::types::ProjectType::Organization => {
+                match self.try_project_fields_query(&project_id, false).await {
+                    Ok(fields_node) => fields_node,
+                    Err(_) => self.try_project_fields_query(&project_id, true).await?,
+                }
+            }
+        };
+
+        Ok(fields_node
+            .fields
+            .nodes
+            .into_iter()
+            .map(crate::types::ProjectField::from)
+            .collect())
+    }
+
+    /// Sets the value of a field of a project item
+    ///
+    /// Uses the `updateProjectV2ItemFieldValue` mutation. `project_id`, `item_id`, and
+    /// `field_id` are GraphQL node ids; single select values are option ids. This is a
+    /// write operation, so the client must have been created with a token.
+    pub async fn update_project_item_field(
+        &self,
+        project_id: &crate::types::ProjectNodeId,
+        item_id: &crate::types::ProjectItemId,
+        field_id: &crate::types::ProjectFieldId,
+        value: &crate::types::ProjectFieldUpdateValue,
+    ) -> Result<crate::types::ProjectItemId> {
+        if self.github_token.is_none() {
+            return Err(anyhow::anyhow!(ApiRetryableError::Unauthorized(
+                "Setting a project field value requires an authenticated GitHub token".to_string()
+            )));
+        }
+
+        let variables = UpdateProjectItemFieldVariable {
+            project_id: project_id.0.clone(),
+            item_id: item_id.0.clone(),
+            field_id: field_id.0.clone(),
+            value: value.into(),
+        };
+
+        let payload = GraphQLPayload {
+            query: GraphQLQuery(update_project_item_field_mutation()),
+            variables: Some(variables),
+        };
+
+        let response: GraphQLResponse<UpdateProjectItemFieldResponse> = self
+            .execute_graphql("update_project_item_field", payload)
+            .await?;
+
+        response
+            .data
+            .and_then(|data| data.update_project_v2_item_field_value)
+            .and_then(|payload| payload.project_v2_item)
+            .map(|item| crate::types::ProjectItemId(item.id))
+            .ok_or_else(|| anyhow::anyhow!("Project item {} was not updated", item_id))
+    }
+
+    /// Try to fetch the field definitions of a user or organization project
+    async fn try_project_fields_query(
+        &self,
+        project_id: &crate::types::ProjectId,
+        user_project: bool,
+    ) -> Result<ProjectFieldsNode> {
+        let query = if user_project {
+            user_project_fields_query(project_id.project_number())
+        } else {
+            project_fields_query(project_id.project_number())
+        };
+        let variables = ProjectVariable {
+            owner: project_id.owner().clone(),
+        };
+
+        let payload = GraphQLPayload {
+            query: GraphQLQuery(query),
+            variables: Some(variables),
+        };
+
+        let response: GraphQLResponse<ProjectFieldsResponse> = self
+            .execute_graphql("project_fields_fetch", payload)
+            .await?;
+
+        let owner = response.data.and_then(|data| {
+            if user_project {
+                data.user
+            } else {
+                data.organization
+            }
+        });
+
+        owner
+            .and_then(|owner| owner.project_v2)
+            .ok_or_else(|| anyhow::anyhow!("Project not found: {}", project_id))
+    }
+
     /// Fetches a single repository by its identifier
     ///
     /// This method retrieves comprehensive repository information including metadata,
     /// milestones, labels, and other properties using GitHub's GraphQL API.
+    /// When the client was built with `with_repository_cache`, results cached within
+    /// the TTL are returned without a request.
     ///
     /// # Arguments
     ///
@@ -781,6 +1728,15 @@ impl GitHubClient {
         &self,
         repository_id: crate::types::RepositoryId,
     ) -> Result<crate::types::GithubRepository> {
+        if let Some(repository) = self
+            .repository_cache
+            .as_ref()
+            .and_then(|cache| cache.get(&repository_id))
+        {
+            info!("Using cached repository metadata for {}", repository_id);
+            return Ok(repository);
+        }
+
         let query = repository_query();
         let variables = RepositoryVariable {
             owner: repository_id.owner().clone(),
@@ -809,9 +1765,73 @@ impl GitHubClient {
         let repository = crate::types::GithubRepository::try_from(repository_node)
             .context(format!("Failed to convert repository: {}", repository_id))?;
 
+        if let Some(cache) = &self.repository_cache {
+            cache.insert(repository_id, repository.clone());
+        }
+
         Ok(repository)
     }
 
+    /// Fetches a repository and reports whether GitHub redirected the request
+    ///
+    /// The GraphQL API resolves the old name of a renamed or transferred repository to
+    /// the current one. The result carries the canonical identifier reported by GitHub
+    /// so stale registrations can be updated.
+    pub async fn fetch_repository_following_redirects(
+        &self,
+        repository_id: crate::types::RepositoryId,
+    ) -> Result<crate::types::RepositoryFetchResult> {
+        let repository = self.fetch_repository(repository_id.clone()).await?;
+        let result = crate::types::RepositoryFetchResult::new(repository_id, repository);
+        if result.was_redirected {
+            info!(
+                "Repository {} was renamed or transferred to {}",
+                result.requested_id.full_name(),
+                result.canonical_id.full_name()
+            );
+        }
+        Ok(result)
+    }
+
+    /// Fetches lightweight commit metadata (headline, author, date) using GraphQL
+    ///
+    /// # Arguments
+    ///
+    /// * `repository_id` - The repository identifier containing owner and repository name
+    /// * `sha` - Full or abbreviated commit SHA
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(None)` when the SHA does not resolve to a commit in the repository.
+    pub async fn fetch_commit(
+        &self,
+        repository_id: crate::types::RepositoryId,
+        sha: &str,
+    ) -> Result<Option<crate::types::Commit>> {
+        let variables = CommitVariable {
+            owner: repository_id.owner().clone(),
+            repository_name: repository_id.repo_name().clone(),
+            expression: sha.to_string(),
+        };
+
+        let payload = GraphQLPayload {
+            query: GraphQLQuery(commit_query()),
+            variables: Some(variables),
+        };
+
+        let response: GraphQLResponse<CommitResponse> =
+            self.execute_graphql("fetch_commit", payload).await?;
+
+        let data = response
+            .data
+            .ok_or_else(|| anyhow::anyhow!("No data in GraphQL commit response"))?;
+
+        Ok(data
+            .repository
+            .and_then(|repository| repository.object)
+            .and_then(|object| object.into_commit(repository_id)))
+    }
+
     /// Fetches pull request diff in unified diff format using REST API
     ///
     /// This method retrieves the complete diff for a pull request using GitHub's REST API
@@ -859,7 +1879,8 @@ impl GitHubClient {
         pull_request_number: crate::types::PullRequestNumber,
     ) -> Result<String> {
         let url = format!(
-            "https://api.github.com/repos/{}/{}/pulls/{}",
+            "{}/repos/{}/{}/pulls/{}",
+            self.rest_base_url,
             repository_id.owner().as_str(),
             repository_id.repo_name().as_str(),
             pull_request_number.value()
@@ -881,6 +1902,7 @@ impl GitHubClient {
             .send()
             .await
             .context("Failed to fetch pull request diff")?;
+        self.check_deprecation_headers(&url, &response);
 
         let diff = response
             .text()
@@ -932,14 +1954,18 @@ impl GitHubClient {
     /// let pr_number = PullRequestNumber::new(12345);
     ///
     /// // Fetch file list (lightweight, no patch content)
-    /// let files = client.fetch_pull_request_files(repo_id, pr_number).await?;
+    /// let files = client.fetch_pull_request_files(repo_id.clone(), pr_number).await?;
     /// for file in &files {
     ///     println!("File: {} ({}, +{} -{} changes)",
     ///              file.filename, file.status, file.additions, file.deletions);
     ///
     ///     // Get individual file diff if needed
-    ///     let patch = client.fetch_pull_request_file_content(&file.contents_url).await?;
-    ///     println!("Patch:\n{}", patch);
+    ///     if let Some(patch) = client
+    ///         .fetch_pull_request_file_content(repo_id.clone(), pr_number, &file.filename)
+    ///         .await?
+    ///     {
+    ///         println!("Patch:\n{}", patch);
+    ///     }
     /// }
     /// # Ok(())
     /// # }
@@ -950,7 +1976,8 @@ impl GitHubClient {
         pull_request_number: crate::types::PullRequestNumber,
     ) -> Result<Vec<crate::types::PullRequestFile>> {
         let base_url = format!(
-            "https://api.github.com/repos/{}/{}/pulls/{}/files",
+            "{}/repos/{}/{}/pulls/{}/files",
+            self.rest_base_url,
             repository_id.owner().as_str(),
             repository_id.repo_name().as_str(),
             pull_request_number.value()
@@ -979,6 +2006,7 @@ impl GitHubClient {
                 .send()
                 .await
                 .context("Failed to fetch pull request files")?;
+            self.check_deprecation_headers(&url, &response);
 
             let mut files: Vec<crate::types::PullRequestFile> = response
                 .json()
@@ -1085,7 +2113,8 @@ impl GitHubClient {
         // Now fetch the patch content by making a separate request with per_page=1
         // and iterating through pages until we find the target file
         let base_url = format!(
-            "https://api.github.com/repos/{}/{}/pulls/{}/files",
+            "{}/repos/{}/{}/pulls/{}/files",
+            self.rest_base_url,
             repository_id.owner().as_str(),
             repository_id.repo_name().as_str(),
             pull_request_number.value()
@@ -1111,6 +2140,7 @@ impl GitHubClient {
                 .send()
                 .await
                 .context("Failed to fetch pull request files")?;
+            self.check_deprecation_headers(&url, &response);
 
             let files: Vec<crate::types::PullRequestFile> = response
                 .json()
@@ -1142,6 +2172,613 @@ impl GitHubClient {
             file_path
         ))
     }
+
+    /// Fetches the latest releases of a repository with their downloadable assets
+    ///
+    /// Releases are returned newest first. Pages of up to 100 releases are fetched until
+    /// `limit` releases are collected or no releases remain. At most 100 assets are
+    /// returned per release.
+    pub async fn fetch_repository_releases(
+        &self,
+        repository_id: crate::types::RepositoryId,
+        limit: usize,
+    ) -> Result<Vec<crate::types::ReleaseWithAssets>> {
+        let query = repository_releases_query();
+        let mut releases = Vec::new();
+        let mut cursor: Option<SearchCursor> = None;
+
+        while releases.len() < limit {
+            let variables = RepositoryReleasesVariable {
+                owner: repository_id.owner.clone(),
+                repository_name: repository_id.repository_name.clone(),
+                first: (limit - releases.len()).min(100) as u32,
+                cursor: cursor.clone(),
+            };
+
+            let payload = GraphQLPayload {
+                query: GraphQLQuery(query.clone()),
+                variables: Some(variables),
+            };
+
+            let response: GraphQLResponse<RepositoryReleasesResponse> = self
+                .execute_graphql("fetch_repository_releases", payload)
+                .await?;
+
+            let data = response
+                .data
+                .ok_or_else(|| anyhow::anyhow!("No data in GraphQL releases response"))?;
+
+            let connection = data
+                .repository
+                .ok_or_else(|| anyhow::anyhow!("Repository not found: {}", repository_id))?
+                .releases;
+
+            releases.extend(
+                connection
+                    .nodes
+                    .into_iter()
+                    .map(crate::types::ReleaseWithAssets::from),
+            );
+
+            let page_info = connection.page_info;
+            match page_info.end_cursor {
+                Some(end_cursor) if page_info.has_next_page => {
+                    cursor = Some(SearchCursor(end_cursor));
+                }
+                _ => break,
+            }
+        }
+
+        releases.truncate(limit);
+        Ok(releases)
+    }
+
+    /// Fetches the languages of a repository with the bytes of code written in each
+    ///
+    /// Languages are returned largest first. GitHub reports at most 100 languages.
+    pub async fn fetch_repository_languages(
+        &self,
+        repository_id: crate::types::RepositoryId,
+    ) -> Result<Vec<(String, u64)>> {
+        let variables = RepositoryVariable {
+            owner: repository_id.owner.clone(),
+            repository_name: repository_id.repository_name.clone(),
+        };
+
+        let payload = GraphQLPayload {
+            query: GraphQLQuery(repository_languages_query()),
+            variables: Some(variables),
+        };
+
+        let response: GraphQLResponse<RepositoryLanguagesResponse> = self
+            .execute_graphql("fetch_repository_languages", payload)
+            .await?;
+
+        let data = response
+            .data
+            .ok_or_else(|| anyhow::anyhow!("No data in GraphQL languages response"))?;
+
+        let mut languages: Vec<(String, u64)> = data
+            .repository
+            .ok_or_else(|| anyhow::anyhow!("Repository not found: {}", repository_id))?
+            .languages
+            .edges
+            .into_iter()
+            .map(|edge| (edge.node.name, edge.size))
+            .collect();
+        languages.sort_by(|a, b| b.1.cmp(&a.1));
+
+        Ok(languages)
+    }
+
+    /// Fetches issue and pull request counts by state, stargazers, and forks of a repository
+    ///
+    /// Only total counts are requested, which is much cheaper than listing the issues and
+    /// pull requests themselves.
+    pub async fn fetch_repository_stats(
+        &self,
+        repository_id: crate::types::RepositoryId,
+    ) -> Result<crate::types::RepositoryStats> {
+        let variables = RepositoryVariable {
+            owner: repository_id.owner.clone(),
+            repository_name: repository_id.repository_name.clone(),
+        };
+
+        let payload = GraphQLPayload {
+            query: GraphQLQuery(repository_stats_query()),
+            variables: Some(variables),
+        };
+
+        let response: GraphQLResponse<RepositoryStatsResponse> = self
+            .execute_graphql("fetch_repository_stats", payload)
+            .await?;
+
+        let data = response
+            .data
+            .ok_or_else(|| anyhow::anyhow!("No data in GraphQL repository stats response"))?;
+
+        let node = data
+            .repository
+            .ok_or_else(|| anyhow::anyhow!("Repository not found: {}", repository_id))?;
+
+        Ok(node.into_stats(repository_id))
+    }
+
+    /// Fetches the top contributors of a repository using the GitHub REST API.
+    ///
+    /// Contributors are returned sorted by contribution count, descending, and limited to
+    /// `limit` entries. GitHub answers `202 Accepted` while it computes the statistics of
+    /// a repository that has not been queried recently; such responses are retried with
+    /// backoff until the list is ready.
+    ///
+    /// # Arguments
+    ///
+    /// * `repository_id` - The repository identifier containing owner and repository name
+    /// * `limit` - Maximum number of contributors to return
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the repository is not accessible, the statistics are still
+    /// being computed after all retries, or the response cannot be parsed.
+    pub async fn fetch_repository_contributors(
+        &self,
+        repository_id: crate::types::RepositoryId,
+        limit: usize,
+    ) -> Result<Vec<crate::types::Contributor>> {
+        let base_url = format!(
+            "{}/repos/{}/{}/contributors",
+            self.rest_base_url,
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str(),
+        );
+
+        let req_client = reqwest::Client::new();
+        let per_page = limit.clamp(1, 100); // Maximum allowed by GitHub API
+        let mut contributors: Vec<crate::types::Contributor> = Vec::new();
+        let mut page = 1;
+
+        while contributors.len() < limit {
+            let url = format!("{}?per_page={}&page={}", base_url, per_page, page);
+
+            let page_contributors = retry_with_backoff("repository_contributors", Some(5), || {
+                let mut request = req_client
+                    .get(&url)
+                    .header("Accept", "application/vnd.github.v3+json")
+                    .header("User-Agent", "github-insight");
+
+                if let Some(token) = &self.github_token {
+                    request = request.header("Authorization", format!("Bearer {}", token));
+                }
+
+                let url = &url;
+                async move {
+                    let response = request.send().await.map_err(|e| {
+                        ApiRetryableError::from_reqwest_error(
+                            "Failed to fetch repository contributors",
+                            e,
+                        )
+                    })?;
+                    self.check_deprecation_headers(url, &response);
+
+                    match response.status().as_u16() {
+                        // Statistics are being computed in the background
+                        202 => Err(ApiRetryableError::Retryable(
+                            "GitHub is computing contributor statistics".to_string(),
+                        )),
+                        // Empty repositories have no contributors
+                        204 => Ok(Vec::new()),
+                        200..=299 => response
+                            .json::<Vec<crate::types::Contributor>>()
+                            .await
+                            .map_err(|e| {
+                                ApiRetryableError::NonRetryable(format!(
+                                    "Failed to parse repository contributors response: {}",
+                                    e
+                                ))
+                            }),
+                        status => {
+                            let body = response.text().await.unwrap_or_default();
+                            Err(ApiRetryableError::from_rest_status(status, &body))
+                        }
+                    }
+                }
+            })
+            .await?;
+
+            let page_count = page_contributors.len();
+            contributors.extend(page_contributors);
+
+            // If we got fewer contributors than per_page, we've reached the last page
+            if page_count < per_page {
+                break;
+            }
+
+            page += 1;
+        }
+
+        contributors.sort_by(|a, b| b.contributions.cmp(&a.contributions));
+        contributors.truncate(limit);
+
+        Ok(contributors)
+    }
+
+    /// Fetches the names of all branches of a repository using the GitHub REST API.
+    ///
+    /// # Arguments
+    ///
+    /// * `repository_id` - The repository identifier containing owner and repository name
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the repository is not accessible or the response cannot be
+    /// parsed.
+    pub async fn fetch_repository_branch_names(
+        &self,
+        repository_id: &crate::types::RepositoryId,
+    ) -> Result<Vec<String>> {
+        #[derive(Deserialize)]
+        struct BranchEntry {
+            name: String,
+        }
+
+        let base_url = format!(
+            "{}/repos/{}/{}/branches",
+            self.rest_base_url,
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str(),
+        );
+
+        let req_client = reqwest::Client::new();
+        let per_page = 100; // Maximum allowed by GitHub API
+        let mut branch_names = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let url = format!("{}?per_page={}&page={}", base_url, per_page, page);
+
+            let page_branches = retry_with_backoff("repository_branches", Some(3), || {
+                let mut request = req_client
+                    .get(&url)
+                    .header("Accept", "application/vnd.github.v3+json")
+                    .header("User-Agent", "github-insight");
+
+                if let Some(token) = &self.github_token {
+                    request = request.header("Authorization", format!("Bearer {}", token));
+                }
+
+                let url = &url;
+                async move {
+                    let response = request.send().await.map_err(|e| {
+                        ApiRetryableError::from_reqwest_error(
+                            "Failed to fetch repository branches",
+                            e,
+                        )
+                    })?;
+                    self.check_deprecation_headers(url, &response);
+
+                    let status = response.status().as_u16();
+                    if !response.status().is_success() {
+                        let body = response.text().await.unwrap_or_default();
+                        return Err(ApiRetryableError::from_rest_status(status, &body));
+                    }
+
+                    response.json::<Vec<BranchEntry>>().await.map_err(|e| {
+                        ApiRetryableError::NonRetryable(format!(
+                            "Failed to parse repository branches response: {}",
+                            e
+                        ))
+                    })
+                }
+            })
+            .await?;
+
+            let page_count = page_branches.len();
+            branch_names.extend(page_branches.into_iter().map(|branch| branch.name));
+
+            // If we got fewer branches than per_page, we've reached the last page
+            if page_count < per_page {
+                break;
+            }
+
+            page += 1;
+        }
+
+        Ok(branch_names)
+    }
+
+    /// Fetches the name of a repository's default branch
+    ///
+    /// Reads `defaultBranchRef` through `fetch_repository`, so the repository cache is
+    /// used when enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the repository is not accessible or has no default branch
+    /// (an empty repository).
+    pub async fn fetch_default_branch(
+        &self,
+        repository_id: &crate::types::RepositoryId,
+    ) -> Result<crate::types::Branch> {
+        self.fetch_repository(repository_id.clone())
+            .await?
+            .default_branch
+            .ok_or_else(|| anyhow::anyhow!("Repository {} has no default branch", repository_id))
+    }
+
+    /// Replaces a `Branch::default_marker()` in a pair with the repository's default branch
+    ///
+    /// Pairs naming a branch are returned unchanged without a request.
+    pub async fn resolve_default_branch(
+        &self,
+        pair: crate::types::RepositoryBranchPair,
+    ) -> Result<crate::types::RepositoryBranchPair> {
+        if !pair.branch.is_default_marker() {
+            return Ok(pair);
+        }
+        let branch = self.fetch_default_branch(&pair.repository_id).await?;
+        Ok(crate::types::RepositoryBranchPair::new(
+            pair.repository_id,
+            branch,
+        ))
+    }
+
+    /// Fetches the latest commit of a branch and how far it diverges from the default
+    /// branch, using the GitHub REST repository, branch, and compare endpoints.
+    ///
+    /// # Arguments
+    ///
+    /// * `repository_id` - The repository identifier containing owner and repository name
+    /// * `branch` - The branch to inspect
+    ///
+    /// # Returns
+    ///
+    /// Returns a status without a head when the branch no longer exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the repository is not accessible or a response cannot be
+    /// parsed.
+    pub async fn fetch_branch_status(
+        &self,
+        repository_id: &crate::types::RepositoryId,
+        branch: &crate::types::Branch,
+    ) -> Result<crate::types::BranchStatus> {
+        #[derive(Deserialize)]
+        struct RepositoryEntry {
+            default_branch: String,
+        }
+
+        #[derive(Deserialize)]
+        struct BranchEntry {
+            commit: BranchCommit,
+        }
+
+        #[derive(Deserialize)]
+        struct BranchCommit {
+            sha: String,
+            commit: CommitDetail,
+        }
+
+        #[derive(Deserialize)]
+        struct CommitDetail {
+            committer: Option<CommitSignature>,
+        }
+
+        #[derive(Deserialize)]
+        struct CommitSignature {
+            date: Option<chrono::DateTime<chrono::Utc>>,
+        }
+
+        #[derive(Deserialize)]
+        struct CompareEntry {
+            ahead_by: u32,
+            behind_by: u32,
+        }
+
+        let repository_url = format!(
+            "{}/repos/{}/{}",
+            self.rest_base_url,
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str(),
+        );
+
+        let repository: RepositoryEntry = self
+            .fetch_rest_json_or_none("repository", &repository_url)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Repository {} not found", repository_id))?;
+        let default_branch = crate::types::Branch::new(repository.default_branch);
+
+        let missing = crate::types::BranchStatus {
+            repository_id: repository_id.clone(),
+            branch: branch.clone(),
+            default_branch: default_branch.clone(),
+            head: None,
+        };
+
+        let branch_url = format!("{}/branches/{}", repository_url, branch.as_str());
+        let Some(branch_entry) = self
+            .fetch_rest_json_or_none::<BranchEntry>("branch", &branch_url)
+            .await?
+        else {
+            return Ok(missing);
+        };
+
+        let compare_url = format!(
+            "{}/compare/{}...{}",
+            repository_url,
+            default_branch.as_str(),
+            branch.as_str()
+        );
+        let Some(compare) = self
+            .fetch_rest_json_or_none::<CompareEntry>("branch_compare", &compare_url)
+            .await?
+        else {
+            return Ok(missing);
+        };
+
+        Ok(crate::types::BranchStatus {
+            head: Some(crate::types::BranchHead {
+                sha: branch_entry.commit.sha,
+                committed_at: branch_entry
+                    .commit
+                    .commit
+                    .committer
+                    .and_then(|committer| committer.date),
+                ahead_by: compare.ahead_by,
+                behind_by: compare.behind_by,
+            }),
+            ..missing
+        })
+    }
+
+    /// Fetches and parses a REST resource, returning `None` when GitHub answers 404
+    async fn fetch_rest_json_or_none<T: for<'de> Deserialize<'de>>(
+        &self,
+        operation: &str,
+        url: &str,
+    ) -> Result<Option<T>> {
+        let req_client = reqwest::Client::new();
+
+        let entry = retry_with_backoff(operation, Some(3), || {
+            let mut request = req_client
+                .get(url)
+                .header("Accept", "application/vnd.github.v3+json")
+                .header("User-Agent", "github-insight");
+
+            if let Some(token) = &self.github_token {
+                request = request.header("Authorization", format!("Bearer {}", token));
+            }
+
+            async move {
+                let response = request.send().await.map_err(|e| {
+                    ApiRetryableError::from_reqwest_error(
+                        &format!("Failed to fetch {}", operation),
+                        e,
+                    )
+                })?;
+                self.check_deprecation_headers(url, &response);
+
+                let status = response.status().as_u16();
+                if status == 404 {
+                    return Ok(None);
+                }
+                if !response.status().is_success() {
+                    let body = response.text().await.unwrap_or_default();
+                    return Err(ApiRetryableError::from_rest_status(status, &body));
+                }
+
+                response.json::<T>().await.map(Some).map_err(|e| {
+                    ApiRetryableError::NonRetryable(format!(
+                        "Failed to parse {} response: {}",
+                        operation, e
+                    ))
+                })
+            }
+        })
+        .await?;
+
+        Ok(entry)
+    }
+
+    /// Fetches the reaction counts of an issue using the GitHub REST API.
+    ///
+    /// Pull request numbers are accepted as well, since GitHub serves pull requests
+    /// through the issues endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `repository_id` - The repository identifier containing owner and repository name
+    /// * `issue_number` - The issue or pull request number
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the issue is not accessible or the response cannot be parsed.
+    pub async fn fetch_issue_reactions(
+        &self,
+        repository_id: &crate::types::RepositoryId,
+        issue_number: crate::types::IssueNumber,
+    ) -> Result<crate::types::ReactionSummary> {
+        #[derive(Deserialize)]
+        struct IssueEntry {
+            #[serde(default)]
+            reactions: ReactionRollup,
+        }
+
+        #[derive(Default, Deserialize)]
+        struct ReactionRollup {
+            #[serde(rename = "+1", default)]
+            thumbs_up: u32,
+            #[serde(rename = "-1", default)]
+            thumbs_down: u32,
+            #[serde(default)]
+            laugh: u32,
+            #[serde(default)]
+            hooray: u32,
+            #[serde(default)]
+            confused: u32,
+            #[serde(default)]
+            heart: u32,
+            #[serde(default)]
+            rocket: u32,
+            #[serde(default)]
+            eyes: u32,
+        }
+
+        let url = format!(
+            "{}/repos/{}/{}/issues/{}",
+            self.rest_base_url,
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str(),
+            issue_number.value(),
+        );
+
+        let req_client = reqwest::Client::new();
+
+        let issue = retry_with_backoff("issue_reactions", Some(3), || {
+            let mut request = req_client
+                .get(&url)
+                .header("Accept", "application/vnd.github.v3+json")
+                .header("User-Agent", "github-insight");
+
+            if let Some(token) = &self.github_token {
+                request = request.header("Authorization", format!("Bearer {}", token));
+            }
+
+            let url = &url;
+            async move {
+                let response = request.send().await.map_err(|e| {
+                    ApiRetryableError::from_reqwest_error("Failed to fetch issue reactions", e)
+                })?;
+                self.check_deprecation_headers(url, &response);
+
+                let status = response.status().as_u16();
+                if !response.status().is_success() {
+                    let body = response.text().await.unwrap_or_default();
+                    return Err(ApiRetryableError::from_rest_status(status, &body));
+                }
+
+                response.json::<IssueEntry>().await.map_err(|e| {
+                    ApiRetryableError::NonRetryable(format!(
+                        "Failed to parse issue reactions response: {}",
+                        e
+                    ))
+                })
+            }
+        })
+        .await?;
+
+        let reactions = issue.reactions;
+        Ok(crate::types::ReactionSummary {
+            thumbs_up: reactions.thumbs_up,
+            thumbs_down: reactions.thumbs_down,
+            laugh: reactions.laugh,
+            hooray: reactions.hooray,
+            confused: reactions.confused,
+            heart: reactions.heart,
+            rocket: reactions.rocket,
+            eyes: reactions.eyes,
+        })
+    }
 }
 
 impl GraphQLExecutor for GitHubClient {
@@ -1150,8 +2787,9 @@ impl GraphQLExecutor for GitHubClient {
         query_name: &str,
         payload: GraphQLPayload<T>,
     ) -> Result<GraphQLResponse<R>> {
-        // Use retry logic for GraphQL requests (3 retries for faster failure)
-        let result = retry_with_backoff(query_name, Some(3), || async {
+        // Use retry logic for GraphQL requests (few retries by default for faster failure)
+        let retry_config = self.retry_config;
+        let result = retry_with_config(query_name, &retry_config, || async {
             info!(
                 "Starting GraphQL request with payload: {}",
                 serde_json::to_string_pretty(&payload)
@@ -1161,15 +2799,25 @@ impl GraphQLExecutor for GitHubClient {
             let start_time = std::time::Instant::now();
 
             // Add timeout to prevent indefinite hanging
-            let timeout_duration = std::time::Duration::from_secs(10); // 10 secs timeout
+            let timeout_duration = retry_config.request_timeout;
+
+            // Send to the overridden endpoint when configured, otherwise to octocrab's default
+            let request = async {
+                let response: octocrab::Result<GraphQLResponse<serde_json::Value>> =
+                    match &self.graphql_endpoint {
+                        Some(endpoint) => self.client.post(endpoint, Some(&payload)).await,
+                        None => self.client.graphql(&payload).await,
+                    };
+                response
+            };
 
-            let response: GraphQLResponse<R> =
-                tokio::time::timeout(timeout_duration, self.client.graphql(&payload))
+            let response: GraphQLResponse<serde_json::Value> =
+                tokio::time::timeout(timeout_duration, request)
                     .await
                     .map_err(|_| {
                         let duration = start_time.elapsed();
                         error!("GraphQL request timed out after {:?}", duration);
-                        ApiRetryableError::Retryable(format!(
+                        ApiRetryableError::Network(format!(
                             "GraphQL request timed out after {:?}",
                             duration
                         ))
@@ -1182,9 +2830,13 @@ impl GraphQLExecutor for GitHubClient {
             // Check for GraphQL errors within the retry loop
             if let Some(errors) = &response.errors {
                 if !errors.is_empty() {
+                    // Prefix messages with the GitHub error type so it can be classified
                     let error_msg = errors
                         .iter()
-                        .map(|e| e.message.as_str())
+                        .map(|e| match &e.error_type {
+                            Some(error_type) => format!("[{}] {}", error_type, e.message),
+                            None => e.message.clone(),
+                        })
                         .collect::<Vec<_>>()
                         .join(", ");
 
@@ -1195,7 +2847,23 @@ impl GraphQLExecutor for GitHubClient {
                 }
             }
 
-            Ok(response)
+            self.record_graphql_cost(query_name, response.data.as_ref());
+
+            let data = response
+                .data
+                .map(serde_json::from_value::<R>)
+                .transpose()
+                .map_err(|e| {
+                    ApiRetryableError::NonRetryable(format!(
+                        "Failed to decode GraphQL response of {}: {}",
+                        query_name, e
+                    ))
+                })?;
+
+            Ok(GraphQLResponse {
+                data,
+                errors: response.errors,
+            })
         })
         .await?;
 
@@ -1208,12 +2876,29 @@ pub(crate) async fn retry_with_backoff<F, Fut, T>(
     max_retry_count: Option<u32>,
     execute_operation: F,
 ) -> Result<T>
+where
+    F: Fn() -> Fut,
+    Fut: std::future::Future<Output = std::result::Result<T, ApiRetryableError>>,
+{
+    let retry_config = RetryConfig {
+        max_retries: max_retry_count.unwrap_or(DEFAULT_MAX_RETRY_COUNT),
+        ..RetryConfig::default()
+    };
+    retry_with_config(operation_name, &retry_config, execute_operation).await
+}
+
+/// Run an operation, retrying retryable errors with the backoff of `retry_config`
+pub(crate) async fn retry_with_config<F, Fut, T>(
+    operation_name: &str,
+    retry_config: &RetryConfig,
+    execute_operation: F,
+) -> Result<T>
 where
     F: Fn() -> Fut,
     Fut: std::future::Future<Output = std::result::Result<T, ApiRetryableError>>,
 {
     let mut attempt = 0;
-    let max_retries = max_retry_count.unwrap_or(DEFAULT_MAX_RETRY_COUNT);
+    let max_retries = retry_config.max_retries;
 
     loop {
         match execute_operation().await {
@@ -1235,7 +2920,7 @@ where
                 );
 
                 match e {
-                    ApiRetryableError::NonRetryable(_) => {
+                    ApiRetryableError::NonRetryable(_) | ApiRetryableError::Unauthorized(_) => {
                         tracing::warn!(
                             "Operation {} returned non-retryable error, failing immediately: {}",
                             operation_name,
@@ -1246,9 +2931,7 @@ where
                     ApiRetryableError::RateLimit => {
                         if attempt < max_retries {
                             attempt += 1;
-                            let backoff_delay = Duration::from_millis(
-                                (1000_u64).saturating_mul(2_u64.saturating_pow(attempt - 1)),
-                            );
+                            let backoff_delay = retry_config.rate_limit_backoff_delay(attempt);
 
                             tracing::warn!(
                                 "Rate limit hit for {}, attempt {}/{}, backing off for {:?}",
@@ -1269,12 +2952,10 @@ where
                             return Err(anyhow::anyhow!(e));
                         }
                     }
-                    ApiRetryableError::Retryable(_) => {
+                    ApiRetryableError::Retryable(_) | ApiRetryableError::Network(_) => {
                         if attempt < max_retries {
                             attempt += 1;
-                            let backoff_delay = Duration::from_millis(
-                                (500_u64).saturating_mul(2_u64.saturating_pow(attempt - 1)),
-                            );
+                            let backoff_delay = retry_config.backoff_delay(attempt);
 
                             tracing::warn!(
                                 "Retryable error for {}, attempt {}/{}, backing off for {:?}",
@@ -0,0 +1,80 @@
+//! Accounting of GraphQL point cost
+//!
+//! GitHub charges every GraphQL query a point cost against the hourly rate limit.
+//! Queries selecting `rateLimit { cost }` report their cost, which is added up here.
+//! With a `max_total_cost`, multi-page fetches stop once the accumulated cost exceeds
+//! the budget. The accumulated cost is shared by all clones of a `GitHubClient`.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Accumulated GraphQL cost with an optional budget, shared across clones
+#[derive(Debug, Clone, Default)]
+pub struct GraphQLCostBudget {
+    max_total_cost: Option<u64>,
+    spent: Arc<AtomicU64>,
+}
+
+impl GraphQLCostBudget {
+    pub fn new(max_total_cost: Option<u64>) -> Self {
+        Self {
+            max_total_cost,
+            spent: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Returns the budget, or `None` when the cost is only accounted
+    pub fn max_total_cost(&self) -> Option<u64> {
+        self.max_total_cost
+    }
+
+    /// Adds the cost of one request and returns the accumulated cost
+    pub fn record(&self, cost: u64) -> u64 {
+        self.spent.fetch_add(cost, Ordering::Relaxed) + cost
+    }
+
+    /// Returns the cost accumulated so far
+    pub fn spent(&self) -> u64 {
+        self.spent.load(Ordering::Relaxed)
+    }
+
+    /// Whether the accumulated cost is above the budget
+    pub fn is_exceeded(&self) -> bool {
+        self.max_total_cost
+            .is_some_and(|max_total_cost| self.spent() > max_total_cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_accumulates_cost_against_budget() {
+        let budget = GraphQLCostBudget::new(Some(10));
+        assert_eq!(budget.record(4), 4);
+        assert_eq!(budget.record(6), 10);
+        assert!(!budget.is_exceeded());
+
+        assert_eq!(budget.record(1), 11);
+        assert!(budget.is_exceeded());
+    }
+
+    #[test]
+    fn test_clones_share_accumulated_cost() {
+        let budget = GraphQLCostBudget::new(Some(5));
+        let clone = budget.clone();
+        clone.record(3);
+        budget.record(3);
+        assert_eq!(clone.spent(), 6);
+        assert!(clone.is_exceeded());
+    }
+
+    #[test]
+    fn test_without_budget_is_never_exceeded() {
+        let budget = GraphQLCostBudget::default();
+        budget.record(u32::MAX as u64);
+        assert_eq!(budget.spent(), u32::MAX as u64);
+        assert!(!budget.is_exceeded());
+    }
+}
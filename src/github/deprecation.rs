@@ -0,0 +1,142 @@
+//! Detection of GitHub API deprecation and sunset response headers
+//!
+//! GitHub announces retiring REST endpoints with the `Deprecation` and `Sunset`
+//! headers (RFC 8594), optionally pointing to details via a `Link` header with
+//! `rel="deprecation"` or `rel="sunset"`.
+
+use once_cell::sync::Lazy;
+use reqwest::header::HeaderMap;
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+/// Warnings already logged in this process, so each is emitted only once
+static WARNED: Lazy<Mutex<HashSet<String>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// Deprecation notice attached to a GitHub API response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeprecationWarning {
+    /// Request URL the headers were returned for
+    pub url: String,
+    /// Value of the `Deprecation` header
+    pub deprecation: Option<String>,
+    /// Value of the `Sunset` header (date the endpoint will be removed)
+    pub sunset: Option<String>,
+    /// Link to deprecation or sunset details
+    pub link: Option<String>,
+}
+
+impl DeprecationWarning {
+    /// Extract a deprecation warning from response headers, if any
+    pub fn from_headers(url: &str, headers: &HeaderMap) -> Option<Self> {
+        let header_value = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+
+        let deprecation = header_value("Deprecation");
+        let sunset = header_value("Sunset");
+        if deprecation.is_none() && sunset.is_none() {
+            return None;
+        }
+
+        let link = headers
+            .get_all("Link")
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .find(|entry| entry.contains("rel=\"deprecation\"") || entry.contains("rel=\"sunset\""))
+            .and_then(|entry| {
+                let start = entry.find('<')?;
+                let end = entry.find('>')?;
+                (start < end).then(|| entry[start + 1..end].to_string())
+            });
+
+        Some(Self {
+            url: url.to_string(),
+            deprecation,
+            sunset,
+            link,
+        })
+    }
+
+    /// Log the warning unless the same warning was already logged in this process
+    pub fn warn_once(&self) {
+        let message = self.to_string();
+        let first_time = WARNED
+            .lock()
+            .map(|mut warned| warned.insert(message.clone()))
+            .unwrap_or(false);
+        if first_time {
+            tracing::warn!("{}", message);
+        }
+    }
+}
+
+impl std::fmt::Display for DeprecationWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Strip query parameters so paginated requests produce the same warning
+        let endpoint = self.url.split('?').next().unwrap_or(&self.url);
+        write!(f, "GitHub API deprecation notice for {}", endpoint)?;
+        if let Some(deprecation) = &self.deprecation {
+            write!(f, " (deprecated: {})", deprecation)?;
+        }
+        if let Some(sunset) = &self.sunset {
+            write!(f, " (sunset: {})", sunset)?;
+        }
+        if let Some(link) = &self.link {
+            write!(f, " - see {}", link)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_no_deprecation_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("Content-Type", HeaderValue::from_static("application/json"));
+        assert!(DeprecationWarning::from_headers("https://api.github.com/x", &headers).is_none());
+    }
+
+    #[test]
+    fn test_sunset_with_link() {
+        let mut headers = HeaderMap::new();
+        headers.insert("Deprecation", HeaderValue::from_static("@1688169599"));
+        headers.insert(
+            "Sunset",
+            HeaderValue::from_static("Sat, 01 Nov 2025 00:00:00 GMT"),
+        );
+        headers.insert(
+            "Link",
+            HeaderValue::from_static(
+                "<https://api.github.com/x?page=2>; rel=\"next\", <https://docs.github.com/changes>; rel=\"deprecation\"",
+            ),
+        );
+
+        let warning = DeprecationWarning::from_headers(
+            "https://api.github.com/repos/o/r/pulls/1/files?per_page=100&page=1",
+            &headers,
+        )
+        .unwrap();
+        assert_eq!(warning.deprecation.as_deref(), Some("@1688169599"));
+        assert_eq!(
+            warning.sunset.as_deref(),
+            Some("Sat, 01 Nov 2025 00:00:00 GMT")
+        );
+        assert_eq!(
+            warning.link.as_deref(),
+            Some("https://docs.github.com/changes")
+        );
+        assert_eq!(
+            warning.to_string(),
+            "GitHub API deprecation notice for https://api.github.com/repos/o/r/pulls/1/files (deprecated: @1688169599) (sunset: Sat, 01 Nov 2025 00:00:00 GMT) - see https://docs.github.com/changes"
+        );
+    }
+}
@@ -5,7 +5,11 @@ pub enum ApiRetryableError {
     Retryable(String),
     /// Rate limiting errors (429) - retryable with backoff
     RateLimit,
-    /// Client errors that should not be retried (4xx except 429)
+    /// Connection failures and timeouts - retryable
+    Network(String),
+    /// Missing or rejected credentials (401) - not retried
+    Unauthorized(String),
+    /// Client errors that should not be retried (4xx except 401 and 429)
     NonRetryable(String),
 }
 
@@ -51,6 +55,10 @@ impl ApiRetryableError {
                             Self::NonRetryable(detailed_error)
                         }
                     }
+                    401 => {
+                        tracing::error!("Unauthorized (401): {}", detailed_error);
+                        Self::Unauthorized(detailed_error)
+                    }
                     400..=499 => {
                         tracing::error!(
                             "Non-retryable client error ({}): {}",
@@ -81,13 +89,13 @@ impl ApiRetryableError {
                 // HTTP layer error - likely retryable
                 let error_msg = format!("HTTP layer error: {}", error);
                 tracing::warn!("HTTP layer error - will retry: {}", error_msg);
-                Self::Retryable(error_msg)
+                Self::Network(error_msg)
             }
             octocrab::Error::Hyper { .. } => {
                 // Lower level HTTP error - likely retryable
                 let error_msg = format!("Hyper HTTP error: {}", error);
                 tracing::warn!("Hyper error - will retry: {}", error_msg);
-                Self::Retryable(error_msg)
+                Self::Network(error_msg)
             }
             octocrab::Error::Json { .. } => {
                 // JSON parsing error - not retryable
@@ -121,14 +129,71 @@ impl ApiRetryableError {
     }
 }
 
+impl ApiRetryableError {
+    /// Classify a failed REST response by its status code and body
+    pub fn from_rest_status(status: u16, message: &str) -> Self {
+        let detailed_error = format!(
+            "GitHub API error - Status: {}, Message: {}",
+            status, message
+        );
+        match status {
+            401 => Self::Unauthorized(detailed_error),
+            429 => Self::RateLimit,
+            403 if message.contains("rate limit") => Self::RateLimit,
+            500..=599 => Self::Retryable(detailed_error),
+            _ => Self::NonRetryable(detailed_error),
+        }
+    }
+
+    /// Classify a REST request that failed before a response arrived
+    ///
+    /// Timeouts and connection failures become `Network`; anything else, such as a
+    /// malformed request, is retried as a generic error.
+    pub fn from_reqwest_error(context: &str, error: reqwest::Error) -> Self {
+        let message = format!("{}: {}", context, error);
+        if error.is_timeout() || error.is_connect() {
+            Self::Network(message)
+        } else {
+            Self::Retryable(message)
+        }
+    }
+}
+
 impl std::fmt::Display for ApiRetryableError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Retryable(msg) => write!(f, "Retryable error: {}", msg),
             Self::RateLimit => write!(f, "Rate limit error"),
+            Self::Network(msg) => write!(f, "Network error: {}", msg),
+            Self::Unauthorized(msg) => write!(f, "Unauthorized: {}", msg),
             Self::NonRetryable(msg) => write!(f, "Non-retryable error: {}", msg),
         }
     }
 }
 
 impl std::error::Error for ApiRetryableError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_rest_status() {
+        assert!(matches!(
+            ApiRetryableError::from_rest_status(401, "Bad credentials"),
+            ApiRetryableError::Unauthorized(_)
+        ));
+        assert_eq!(
+            ApiRetryableError::from_rest_status(403, "API rate limit exceeded"),
+            ApiRetryableError::RateLimit
+        );
+        assert!(matches!(
+            ApiRetryableError::from_rest_status(403, "Resource not accessible"),
+            ApiRetryableError::NonRetryable(_)
+        ));
+        assert!(matches!(
+            ApiRetryableError::from_rest_status(502, "Bad gateway"),
+            ApiRetryableError::Retryable(_)
+        ));
+    }
+}
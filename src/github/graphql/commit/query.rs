@@ -0,0 +1,34 @@
+use crate::types::{Owner, RepositoryName};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitVariable {
+    pub owner: Owner,
+    pub repository_name: RepositoryName,
+    /// Commit SHA (full or abbreviated)
+    pub expression: String,
+}
+
+pub fn commit_query() -> String {
+    r#"
+        query($owner: String!, $repository_name: String!, $expression: String!) {
+            repository(owner: $owner, name: $repository_name) {
+                object(expression: $expression) {
+                    __typename
+                    ... on Commit {
+                        oid
+                        messageHeadline
+                        committedDate
+                        author {
+                            name
+                            user {
+                                login
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    "#
+    .to_string()
+}
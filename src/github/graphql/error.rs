@@ -1,7 +1,48 @@
 use crate::github::error::ApiRetryableError;
 
+/// Message fragments of GitHub GraphQL errors for resources that do not exist
+const NOT_FOUND_PATTERNS: &[&str] = &["NOT_FOUND", "Could not resolve to"];
+
+/// Message fragments of GitHub GraphQL errors for resources the token cannot access
+const FORBIDDEN_PATTERNS: &[&str] = &[
+    "FORBIDDEN",
+    "Resource not accessible by",
+    "OAuth App access restrictions",
+    "does not have permission",
+];
+
+/// Message fragments of GitHub GraphQL errors for invalid queries
+const QUERY_VALIDATION_PATTERNS: &[&str] = &[
+    "validation",
+    "syntax",
+    "Parse error on",
+    "doesn't exist on type",
+    "doesn't accept argument",
+    "was provided invalid value",
+    "has an invalid value",
+    "is required, but it was not provided",
+];
+
+fn contains_any(error_msg: &str, patterns: &[&str]) -> bool {
+    patterns.iter().any(|pattern| error_msg.contains(pattern))
+}
+
+/// Whether an error message reports a resource that does not exist
+pub fn is_not_found_error(error_msg: &str) -> bool {
+    contains_any(error_msg, NOT_FOUND_PATTERNS) || error_msg.contains("not found")
+}
+
+/// Whether an error message reports a resource the token cannot access
+pub fn is_permission_error(error_msg: &str) -> bool {
+    contains_any(error_msg, FORBIDDEN_PATTERNS) || error_msg.contains("Permission denied")
+}
+
 /// Classifies GraphQL errors for retry handling.
 ///
+/// Permanent errors (missing resources, insufficient permissions, and invalid
+/// queries) are classified as non-retryable so they fail immediately instead
+/// of after backoff delays.
+///
 /// # Arguments
 ///
 /// * `error_msg` - The GraphQL error message to classify
@@ -24,29 +65,31 @@ pub fn classify_graphql_error(error_msg: &str) -> ApiRetryableError {
         // Rate limit errors should be retried with backoff
         tracing::warn!("GraphQL rate limit error - will retry: {}", error_msg);
         ApiRetryableError::RateLimit
-    } else if error_msg.contains("timeout") || error_msg.contains("server error") {
-        // Server-side errors should be retried
-        tracing::warn!("GraphQL server error - will retry: {}", error_msg);
-        ApiRetryableError::Retryable(format!("GraphQL server error: {}", error_msg))
-    } else if error_msg.contains("Could not resolve to a PullRequest")
-        || error_msg.contains("Could not resolve to an Issue")
-    {
+    } else if contains_any(error_msg, NOT_FOUND_PATTERNS) {
         // These indicate non-existent resources, should not be retried but handled gracefully
         tracing::info!(
             "GraphQL resource not found - treating as non-retryable: {}",
             error_msg
         );
         ApiRetryableError::NonRetryable(format!("Resource not found: {}", error_msg))
+    } else if contains_any(error_msg, FORBIDDEN_PATTERNS) {
+        // Permission errors will not resolve by retrying with the same token
+        tracing::error!("GraphQL permission error - not retryable: {}", error_msg);
+        ApiRetryableError::NonRetryable(format!("Permission denied: {}", error_msg))
     } else if error_msg.contains("Expected NAME")
         || error_msg.contains("Expected one of SCHEMA, SCALAR")
     {
         // These specific GraphQL parsing errors can be transient - retry them
         tracing::warn!("GraphQL parsing error - will retry: {}", error_msg);
         ApiRetryableError::Retryable(format!("GraphQL parsing error: {}", error_msg))
-    } else if error_msg.contains("validation") || error_msg.contains("syntax") {
+    } else if contains_any(error_msg, QUERY_VALIDATION_PATTERNS) {
         // Query validation errors are typically client-side issues
         tracing::error!("GraphQL validation error - not retryable: {}", error_msg);
         ApiRetryableError::NonRetryable(format!("GraphQL validation error: {}", error_msg))
+    } else if error_msg.contains("timeout") || error_msg.contains("server error") {
+        // Server-side errors should be retried
+        tracing::warn!("GraphQL server error - will retry: {}", error_msg);
+        ApiRetryableError::Retryable(format!("GraphQL server error: {}", error_msg))
     } else {
         // Default to retryable for unknown GraphQL errors to improve reliability
         tracing::warn!(
@@ -56,3 +99,109 @@ pub fn classify_graphql_error(error_msg: &str) -> ApiRetryableError {
         ApiRetryableError::Retryable(format!("GraphQL error: {}", error_msg))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_non_retryable(error_msg: &str) -> bool {
+        matches!(
+            classify_graphql_error(error_msg),
+            ApiRetryableError::NonRetryable(_)
+        )
+    }
+
+    fn is_retryable(error_msg: &str) -> bool {
+        matches!(
+            classify_graphql_error(error_msg),
+            ApiRetryableError::Retryable(_)
+        )
+    }
+
+    #[test]
+    fn test_not_found_errors_are_non_retryable() {
+        assert!(is_non_retryable(
+            "[NOT_FOUND] Could not resolve to a PullRequest with the number of 99999."
+        ));
+        assert!(is_non_retryable(
+            "Could not resolve to an Issue with the number of 12345."
+        ));
+        assert!(is_non_retryable(
+            "[NOT_FOUND] Could not resolve to a Repository with the name 'owner/missing'."
+        ));
+        assert!(is_non_retryable(
+            "Could not resolve to a ProjectV2 with the number 42."
+        ));
+        assert!(is_non_retryable(
+            "Could not resolve to a node with the global id of 'PVT_invalid'"
+        ));
+    }
+
+    #[test]
+    fn test_forbidden_errors_are_non_retryable() {
+        assert!(is_non_retryable(
+            "[FORBIDDEN] Resource not accessible by integration"
+        ));
+        assert!(is_non_retryable(
+            "Resource not accessible by personal access token"
+        ));
+        assert!(is_non_retryable(
+            "Although you appear to have the correct authorization credentials, the `acme` organization has enabled OAuth App access restrictions, meaning that data access to third-parties is limited."
+        ));
+    }
+
+    #[test]
+    fn test_query_validation_errors_are_non_retryable() {
+        assert!(is_non_retryable(
+            "Field 'reviewDecisions' doesn't exist on type 'PullRequest'"
+        ));
+        assert!(is_non_retryable("Parse error on \"}\" (RCURLY) at [3, 5]"));
+        assert!(is_non_retryable(
+            "Variable $number of type Int! was provided invalid value"
+        ));
+        assert!(is_non_retryable(
+            "Field 'issues' doesn't accept argument 'limit'"
+        ));
+        assert!(is_non_retryable(
+            "Argument 'first' on Field 'issues' has an invalid value (abc). Expected type 'Int'."
+        ));
+    }
+
+    #[test]
+    fn test_permanent_errors_mentioning_timeout_are_non_retryable() {
+        assert!(is_non_retryable(
+            "Field 'timeout' doesn't exist on type 'Repository'"
+        ));
+    }
+
+    #[test]
+    fn test_transient_errors_are_retryable() {
+        assert!(is_retryable("Something went wrong: timeout"));
+        assert!(is_retryable("Internal server error"));
+        assert!(is_retryable(
+            "A query attribute must be specified and must be a string."
+        ));
+        assert!(is_retryable(
+            "Expected NAME, actual: RCURLY (\"}\") at [1, 2]"
+        ));
+        assert!(is_retryable("Something unexpected happened"));
+        assert_eq!(
+            classify_graphql_error("API rate limit exceeded for user ID 1."),
+            ApiRetryableError::RateLimit
+        );
+    }
+
+    #[test]
+    fn test_not_found_and_permission_errors() {
+        assert!(is_not_found_error(
+            "Resource not found: Could not resolve to a Repository with the name 'owner/gone'."
+        ));
+        assert!(is_not_found_error("User project not found: octocat/1"));
+        assert!(!is_not_found_error("GraphQL request timed out"));
+
+        assert!(is_permission_error(
+            "Permission denied: Resource not accessible by integration"
+        ));
+        assert!(!is_permission_error("Resource not found: NOT_FOUND"));
+    }
+}
@@ -14,6 +14,28 @@ pub struct CommentsConnection {
     pub page_info: Option<PageInfo>,
 }
 
+impl CommentsConnection {
+    /// Convert one page of comments into issue comments and the pager for the next page
+    ///
+    /// The pager is `None` when there are no further pages.
+    pub fn into_issue_comments_page(
+        self,
+    ) -> Result<(Vec<IssueComment>, Option<SearchResultPager>), anyhow::Error> {
+        let comments = self
+            .nodes
+            .into_iter()
+            .map(IssueComment::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let pager = self
+            .page_info
+            .filter(|page_info| page_info.has_next_page)
+            .map(SearchResultPager::from);
+
+        Ok((comments, pager))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CommentNode {
@@ -36,6 +58,7 @@ impl TryFrom<CommentNode> for crate::types::PullRequestComment {
             .author
             .as_ref()
             .map(|a| crate::types::User::from(a.login.clone()));
+        let author_is_bot = comment_node.author.as_ref().is_some_and(|a| a.is_bot());
 
         // Extract comment ID from GitHub comment URL
         let comment_number = if let Some(ref url) = comment_node.url {
@@ -57,6 +80,7 @@ impl TryFrom<CommentNode> for crate::types::PullRequestComment {
             author,
             created_at: comment_node.created_at,
             updated_at: comment_node.updated_at,
+            author_is_bot,
         })
     }
 }
@@ -70,6 +94,7 @@ impl TryFrom<CommentNode> for crate::types::IssueComment {
             .author
             .as_ref()
             .map(|a| crate::types::User::from(a.login.clone()));
+        let author_is_bot = comment_node.author.as_ref().is_some_and(|a| a.is_bot());
 
         // Extract comment ID from GitHub comment URL
         let comment_number = if let Some(ref url) = comment_node.url {
@@ -91,6 +116,115 @@ impl TryFrom<CommentNode> for crate::types::IssueComment {
             author,
             created_at: comment_node.created_at,
             updated_at: comment_node.updated_at,
+            author_is_bot,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn comments_connection(json: &str) -> CommentsConnection {
+        serde_json::from_str(json).expect("valid comments connection JSON")
+    }
+
+    #[test]
+    fn test_into_issue_comments_page_empty() {
+        let connection = comments_connection(
+            r#"{
+                "nodes": [],
+                "totalCount": 0,
+                "pageInfo": { "hasNextPage": false, "endCursor": null }
+            }"#,
+        );
+
+        let (comments, pager) = connection.into_issue_comments_page().unwrap();
+        assert!(comments.is_empty());
+        assert!(pager.is_none());
+    }
+
+    #[test]
+    fn test_into_issue_comments_page_single_page() {
+        let connection = comments_connection(
+            r#"{
+                "nodes": [
+                    {
+                        "id": "IC_1",
+                        "body": "First comment",
+                        "createdAt": "2025-01-01T00:00:00Z",
+                        "updatedAt": "2025-01-01T00:00:00Z",
+                        "url": "https://github.com/owner/repo/issues/1#issuecomment-101",
+                        "author": { "login": "alice" }
+                    },
+                    {
+                        "id": "IC_2",
+                        "body": "Second comment",
+                        "createdAt": "2025-01-02T00:00:00Z",
+                        "updatedAt": "2025-01-02T00:00:00Z",
+                        "url": "https://github.com/owner/repo/issues/1#issuecomment-102",
+                        "author": null
+                    }
+                ],
+                "totalCount": 2,
+                "pageInfo": { "hasNextPage": false, "endCursor": "Y3Vyc29yOjI=" }
+            }"#,
+        );
+
+        let (comments, pager) = connection.into_issue_comments_page().unwrap();
+        assert_eq!(comments.len(), 2);
+        assert_eq!(comments[0].comment_number, IssueCommentNumber(101));
+        assert_eq!(comments[0].body, "First comment");
+        assert_eq!(comments[1].comment_number, IssueCommentNumber(102));
+        assert!(comments[1].author.is_none());
+        assert!(pager.is_none());
+    }
+
+    #[test]
+    fn test_into_issue_comments_page_with_next_page() {
+        let connection = comments_connection(
+            r#"{
+                "nodes": [],
+                "totalCount": 5,
+                "pageInfo": { "hasNextPage": true, "endCursor": "Y3Vyc29yOjI=" }
+            }"#,
+        );
+
+        let (_, pager) = connection.into_issue_comments_page().unwrap();
+        let pager = pager.expect("pager for the next page");
+        assert!(pager.has_next_page);
+        assert_eq!(pager.next_page_cursor.unwrap().0, "Y3Vyc29yOjI=");
+    }
+
+    #[test]
+    fn test_into_issue_comments_page_detects_bot_authors() {
+        let connection = comments_connection(
+            r#"{
+                "nodes": [
+                    {
+                        "id": "IC_1",
+                        "body": "Coverage report",
+                        "createdAt": "2025-01-01T00:00:00Z",
+                        "updatedAt": "2025-01-01T00:00:00Z",
+                        "url": "https://github.com/owner/repo/issues/1#issuecomment-101",
+                        "author": { "login": "codecov", "__typename": "Bot" }
+                    },
+                    {
+                        "id": "IC_2",
+                        "body": "Looks good",
+                        "createdAt": "2025-01-02T00:00:00Z",
+                        "updatedAt": "2025-01-02T00:00:00Z",
+                        "url": "https://github.com/owner/repo/issues/1#issuecomment-102",
+                        "author": { "login": "alice", "__typename": "User" }
+                    }
+                ],
+                "totalCount": 2,
+                "pageInfo": { "hasNextPage": false, "endCursor": null }
+            }"#,
+        );
+
+        let (comments, _) = connection.into_issue_comments_page().unwrap();
+        assert!(comments[0].author_is_bot);
+        assert!(!comments[1].author_is_bot);
+    }
+}
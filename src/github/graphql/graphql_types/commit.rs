@@ -0,0 +1,55 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::github::graphql::graphql_types::Author;
+use crate::types::{Commit, CommitId, RepositoryId};
+
+/// GraphQL response for commit query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitResponse {
+    pub repository: Option<CommitRepositoryNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitRepositoryNode {
+    pub object: Option<CommitObjectNode>,
+}
+
+/// Git object resolved from an expression; commit fields are absent for other object types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitObjectNode {
+    #[serde(rename = "__typename")]
+    pub typename: String,
+    #[serde(default)]
+    pub oid: Option<String>,
+    #[serde(rename = "messageHeadline", default)]
+    pub message_headline: Option<String>,
+    #[serde(rename = "committedDate", default)]
+    pub committed_date: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub author: Option<CommitAuthorNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitAuthorNode {
+    pub name: Option<String>,
+    pub user: Option<Author>,
+}
+
+impl CommitObjectNode {
+    /// Convert to a domain commit, returning `None` if the object is not a commit
+    pub fn into_commit(self, repository_id: RepositoryId) -> Option<Commit> {
+        if self.typename != "Commit" {
+            return None;
+        }
+        let author = self
+            .author
+            .and_then(|author| author.user.map(|user| user.login).or(author.name));
+        Some(Commit {
+            commit_id: CommitId::new(repository_id, self.oid?),
+            message_headline: self.message_headline.unwrap_or_default(),
+            author,
+            committed_at: self.committed_date,
+        })
+    }
+}
@@ -0,0 +1,139 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::github::graphql::graphql_types::repository::Repository;
+use crate::github::graphql::graphql_types::user::Author;
+use crate::types::{Discussion, DiscussionAnswerStatus, RepositoryId};
+
+/// Discussion node of a `type: DISCUSSION` search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscussionNode {
+    pub number: i32,
+    pub title: String,
+    pub url: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub author: Option<Author>,
+    pub category: Option<DiscussionCategoryNode>,
+    /// Null for discussions in categories that do not accept answers
+    #[serde(default)]
+    pub is_answered: Option<bool>,
+    pub comments: DiscussionCommentsConnection,
+    pub repository: Repository,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscussionCategoryNode {
+    pub name: String,
+    pub is_answerable: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscussionCommentsConnection {
+    #[serde(rename = "totalCount")]
+    pub total_count: i32,
+}
+
+impl From<DiscussionNode> for Discussion {
+    fn from(node: DiscussionNode) -> Self {
+        let answerable = node
+            .category
+            .as_ref()
+            .is_some_and(|category| category.is_answerable);
+        let answer_status = match node.is_answered {
+            _ if !answerable => DiscussionAnswerStatus::NotAnswerable,
+            Some(true) => DiscussionAnswerStatus::Answered,
+            _ => DiscussionAnswerStatus::Unanswered,
+        };
+
+        Discussion {
+            repository_id: RepositoryId::new(node.repository.owner.login, node.repository.name),
+            number: node.number as u32,
+            title: node.title,
+            url: node.url,
+            category: node.category.map(|category| category.name),
+            answer_status,
+            author: node.author.map(|author| author.login),
+            comments_count: node.comments.total_count as u32,
+            created_at: node.created_at,
+            updated_at: node.updated_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::graphql::graphql_types::{SearchResponse, SearchResult};
+
+    #[test]
+    fn test_discussion_search_node_to_discussion() {
+        let response: SearchResponse = serde_json::from_str(
+            r#"{
+                "search": {
+                    "nodes": [
+                        {
+                            "__typename": "Discussion",
+                            "number": 12,
+                            "title": "How do I configure the proxy?",
+                            "url": "https://github.com/owner/repo/discussions/12",
+                            "createdAt": "2025-01-02T03:04:05Z",
+                            "updatedAt": "2025-01-03T03:04:05Z",
+                            "author": { "login": "alice" },
+                            "category": { "name": "Q&A", "isAnswerable": true },
+                            "isAnswered": true,
+                            "comments": { "totalCount": 4 },
+                            "repository": { "owner": { "login": "owner" }, "name": "repo" }
+                        },
+                        {
+                            "__typename": "Discussion",
+                            "number": 13,
+                            "title": "v2 released",
+                            "url": "https://github.com/owner/repo/discussions/13",
+                            "createdAt": "2025-01-04T03:04:05Z",
+                            "updatedAt": "2025-01-04T03:04:05Z",
+                            "author": null,
+                            "category": { "name": "Announcements", "isAnswerable": false },
+                            "isAnswered": null,
+                            "comments": { "totalCount": 0 },
+                            "repository": { "owner": { "login": "owner" }, "name": "repo" }
+                        }
+                    ],
+                    "pageInfo": { "hasNextPage": false, "endCursor": null }
+                }
+            }"#,
+        )
+        .expect("valid discussion search response");
+
+        let discussions: Vec<Discussion> = response
+            .search
+            .nodes
+            .into_iter()
+            .filter_map(|node| match node {
+                SearchResult::Discussion(node) => Some(Discussion::from(node)),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(discussions.len(), 2);
+        assert_eq!(
+            discussions[0].repository_id,
+            RepositoryId::new("owner", "repo")
+        );
+        assert_eq!(discussions[0].number, 12);
+        assert_eq!(discussions[0].category.as_deref(), Some("Q&A"));
+        assert_eq!(
+            discussions[0].answer_status,
+            DiscussionAnswerStatus::Answered
+        );
+        assert_eq!(discussions[0].author.as_deref(), Some("alice"));
+        assert_eq!(discussions[0].comments_count, 4);
+        assert_eq!(
+            discussions[1].answer_status,
+            DiscussionAnswerStatus::NotAnswerable
+        );
+        assert_eq!(discussions[1].author, None);
+    }
+}
@@ -167,6 +167,8 @@ impl TryFrom<IssueNode> for crate::types::Issue {
             milestone_id: milestone_number,
             locked: issue_node.locked.unwrap_or(false),
             linked_resources,
+            reactions: None,
+            repository_archived: false,
         })
     }
 }
@@ -179,6 +181,58 @@ pub struct MultipleIssuesResponse {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MultipleIssuesRepository {
+    #[serde(rename = "isArchived", default)]
+    pub is_archived: Option<bool>,
     #[serde(flatten)]
     pub issues: std::collections::HashMap<String, Option<IssueNode>>,
 }
+
+/// GraphQL response for a single page of the issues of a milestone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MilestoneIssuesResponse {
+    pub repository: Option<MilestoneIssuesRepository>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MilestoneIssuesRepository {
+    pub milestone: Option<MilestoneIssuesNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MilestoneIssuesNode {
+    pub issues: IssuesConnection,
+}
+
+/// GraphQL response for a single page of issue comments
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueCommentsResponse {
+    pub repository: Option<IssueCommentsRepository>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueCommentsRepository {
+    pub issue: Option<IssueCommentsNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueCommentsNode {
+    pub comments: CommentsConnection,
+}
+
+/// GraphQL response for the timeline events of an issue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueTimelineResponse {
+    pub repository: Option<IssueTimelineRepository>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueTimelineRepository {
+    pub issue: Option<IssueTimelineNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueTimelineNode {
+    pub title: String,
+    #[serde(rename = "timelineItems")]
+    pub timeline_items: TimelineItemsConnection,
+}
@@ -1,8 +1,11 @@
 mod comment;
+pub mod commit;
+pub mod discussion;
 pub mod issue;
 pub mod pager;
 pub mod project;
 pub mod pull_request;
+pub mod rate_limit;
 pub mod repository;
 mod search;
 mod timeline;
@@ -11,10 +14,13 @@ mod user;
 use serde::{Deserialize, Serialize};
 
 pub use comment::*;
+pub use commit::*;
+pub use discussion::*;
 pub use issue::*;
 pub use pager::*;
 pub use project::*;
 pub use pull_request::*;
+pub use rate_limit::*;
 pub use repository::*;
 pub use search::*;
 pub use timeline::*;
@@ -39,6 +45,9 @@ pub struct GraphQLResponse<T> {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GraphQLError {
     pub message: String,
+    /// GitHub error type such as NOT_FOUND or FORBIDDEN
+    #[serde(rename = "type", default)]
+    pub error_type: Option<String>,
     #[serde(default)]
     pub locations: Vec<serde_json::Value>,
     #[serde(default)]
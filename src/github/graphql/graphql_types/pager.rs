@@ -6,6 +6,12 @@ pub struct PageInfo {
     pub has_next_page: bool,
     #[serde(rename = "endCursor")]
     pub end_cursor: Option<String>,
+    /// Selected by the search query; `false` for queries that do not select it
+    #[serde(rename = "hasPreviousPage", default)]
+    pub has_previous_page: bool,
+    /// Selected by the search query; `None` for queries that do not select it
+    #[serde(rename = "startCursor", default)]
+    pub start_cursor: Option<String>,
 }
 
 impl From<PageInfo> for crate::types::SearchResultPager {
@@ -13,6 +19,44 @@ impl From<PageInfo> for crate::types::SearchResultPager {
         Self {
             next_page_cursor: page_info.end_cursor.map(crate::types::SearchCursor),
             has_next_page: page_info.has_next_page,
+            previous_page_cursor: page_info.start_cursor.map(crate::types::SearchCursor),
+            has_previous_page: page_info.has_previous_page,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::SearchResultPager;
+
+    #[test]
+    fn test_page_info_with_previous_page_into_pager() {
+        let page_info: PageInfo = serde_json::from_str(
+            r#"{
+                "hasNextPage": true,
+                "endCursor": "Y3Vyc29yOjIw",
+                "hasPreviousPage": true,
+                "startCursor": "Y3Vyc29yOjEx"
+            }"#,
+        )
+        .unwrap();
+
+        let pager = SearchResultPager::from(page_info);
+        assert!(pager.has_next_page);
+        assert_eq!(pager.next_page_cursor.unwrap().0, "Y3Vyc29yOjIw");
+        assert!(pager.has_previous_page);
+        assert_eq!(pager.previous_page_cursor.unwrap().0, "Y3Vyc29yOjEx");
+    }
+
+    #[test]
+    fn test_page_info_without_previous_page_fields() {
+        let page_info: PageInfo =
+            serde_json::from_str(r#"{ "hasNextPage": false, "endCursor": null }"#).unwrap();
+
+        let pager = SearchResultPager::from(page_info);
+        assert!(!pager.has_next_page);
+        assert!(!pager.has_previous_page);
+        assert!(pager.previous_page_cursor.is_none());
+    }
+}
@@ -1,11 +1,12 @@
 use crate::github::graphql::graphql_types::pager::PageInfo;
 use crate::github::graphql::graphql_types::user::{AssigneesConnection, UserNode};
-use crate::types::{User, label::Label};
+use crate::types::{self, User, label::Label};
 use crate::types::{
     issue::IssueId,
     project::{
         Project, ProjectCustomFieldValue, ProjectFieldId, ProjectFieldName, ProjectFieldValue,
-        ProjectId, ProjectItemId, ProjectNodeId, ProjectOriginalResource, ProjectResource,
+        ProjectId, ProjectItemId, ProjectIteration, ProjectNodeId, ProjectOriginalResource,
+        ProjectResource,
     },
     pull_request::PullRequestId,
     repository::{RepositoryId, RepositoryUrl},
@@ -195,6 +196,14 @@ pub enum FieldValue {
         field: FieldRef,
         date: Option<String>,
     },
+    #[serde(rename = "ProjectV2ItemFieldIterationValue")]
+    Iteration {
+        field: FieldRef,
+        title: Option<String>,
+        #[serde(rename = "startDate")]
+        start_date: Option<String>,
+        duration: Option<u32>,
+    },
     #[serde(other)]
     Other,
 }
@@ -227,6 +236,75 @@ pub struct FieldRef {
     pub name: String,
 }
 
+/// Response of the `updateProjectV2ItemFieldValue` mutation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateProjectItemFieldResponse {
+    #[serde(rename = "updateProjectV2ItemFieldValue")]
+    pub update_project_v2_item_field_value: Option<UpdateProjectItemFieldPayload>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateProjectItemFieldPayload {
+    #[serde(rename = "projectV2Item")]
+    pub project_v2_item: Option<UpdatedProjectItemNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdatedProjectItemNode {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectFieldsResponse {
+    pub organization: Option<ProjectFieldsOwnerNode>,
+    pub user: Option<ProjectFieldsOwnerNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectFieldsOwnerNode {
+    #[serde(rename = "projectV2")]
+    pub project_v2: Option<ProjectFieldsNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectFieldsNode {
+    pub fields: ProjectFieldDefinitionsConnection,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectFieldDefinitionsConnection {
+    pub nodes: Vec<ProjectFieldDefinitionNode>,
+}
+
+/// Field definition node; every field type implements `ProjectV2FieldCommon`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectFieldDefinitionNode {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "dataType")]
+    pub data_type: String,
+    pub options: Option<Vec<SingleSelectOption>>,
+}
+
+impl From<ProjectFieldDefinitionNode> for types::project::ProjectField {
+    fn from(node: ProjectFieldDefinitionNode) -> Self {
+        Self {
+            field_id: ProjectFieldId(node.id),
+            field_name: ProjectFieldName(node.name),
+            data_type: node.data_type,
+            options: node
+                .options
+                .unwrap_or_default()
+                .into_iter()
+                .map(|option| types::project::ProjectFieldOption {
+                    option_id: option.id,
+                    name: option.name,
+                })
+                .collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SingleSelectOption {
     pub id: String,
@@ -355,6 +433,36 @@ impl TryFrom<ProjectItem> for ProjectResource {
                             }
                         }
                     }
+                    FieldValue::Iteration {
+                        field,
+                        title,
+                        start_date,
+                        duration,
+                    } => {
+                        if let (Some(title), Some(start_date)) = (title, start_date) {
+                            match chrono::NaiveDate::parse_from_str(&start_date, "%Y-%m-%d") {
+                                Ok(start_date) => {
+                                    custom_field_values.push(ProjectCustomFieldValue {
+                                        field_id: ProjectFieldId(field.id.clone()),
+                                        field_name: ProjectFieldName(field.name.clone()),
+                                        value: ProjectFieldValue::Iteration(ProjectIteration {
+                                            title,
+                                            start_date,
+                                            duration_days: duration.unwrap_or_default(),
+                                        }),
+                                    });
+                                }
+                                Err(e) => {
+                                    tracing::warn!(
+                                        "Failed to parse iteration start date {}: {} - {}",
+                                        field.name,
+                                        start_date,
+                                        e
+                                    );
+                                }
+                            }
+                        }
+                    }
                     FieldValue::Other => {
                         // Skip unsupported field value types
                         tracing::debug!("Skipping unsupported field value type: Other");
@@ -7,7 +7,11 @@ use crate::github::graphql::graphql_types::timeline::TimelineItemsConnection;
 use crate::github::graphql::graphql_types::user::{AssigneesConnection, Author};
 use crate::github::graphql::graphql_types::{LabelsConnection, MilestoneNode};
 use crate::types::label::Label;
-use crate::types::{IssueOrPullrequestId, PullRequest, PullRequestId, PullRequestState, User};
+use crate::types::{
+    CheckConclusion, CommitSummary, IssueOrPullrequestId, PullRequest, PullRequestCheck,
+    PullRequestChecks, PullRequestId, PullRequestReview, PullRequestReviewDecision,
+    PullRequestReviewState, PullRequestState, User,
+};
 
 const MERGEABLE_VALUE: &str = "MERGEABLE";
 const CONFLICTING_VALUE: &str = "CONFLICTING";
@@ -65,6 +69,8 @@ pub struct PullRequestNode {
     pub locked: Option<bool>,
     #[serde(rename = "isDraft")]
     pub is_draft: Option<bool>,
+    #[serde(rename = "reviewDecision", default)]
+    pub review_decision: Option<String>,
     pub comments: CommentsConnection,
     pub reviews: Option<ReviewsConnection>,
     #[serde(rename = "reviewThreads")]
@@ -191,6 +197,7 @@ impl TryFrom<(PullRequestNode, crate::types::RepositoryId)> for PullRequest {
             PullRequestId::new(git_repository_id, pull_request_node.number as u32);
 
         // Parse comments from GraphQL response
+        let comments_count = pull_request_node.comments.total_count as u32;
         let comments: Result<Vec<_>, _> = pull_request_node
             .comments
             .nodes
@@ -277,6 +284,7 @@ impl TryFrom<(PullRequestNode, crate::types::RepositoryId)> for PullRequest {
             deletions: pull_request_node.deletions.unwrap_or(0) as u32,
             changed_files: pull_request_node.changed_files.unwrap_or(0) as u32,
             comments,
+            comments_count,
             review_thread_comments,
             milestone_id: milestone_number,
             draft: pull_request_node.is_draft.unwrap_or(false),
@@ -288,7 +296,13 @@ impl TryFrom<(PullRequestNode, crate::types::RepositoryId)> for PullRequest {
                     CONFLICTING_VALUE => Some(false),
                     _ => None,
                 }),
+            review_decision: pull_request_node
+                .review_decision
+                .as_ref()
+                .and_then(|s| s.parse::<PullRequestReviewDecision>().ok()),
             linked_resources,
+            reactions: None,
+            repository_archived: false,
         })
     }
 }
@@ -376,10 +390,250 @@ pub struct ReviewNode {
     pub body: Option<String>,
     #[serde(rename = "createdAt")]
     pub created_at: DateTime<Utc>,
+    #[serde(rename = "submittedAt", default)]
+    pub submitted_at: Option<DateTime<Utc>>,
     pub author: Option<Author>,
     pub url: Option<String>,
 }
 
+impl ReviewNode {
+    /// Convert to a pull request review, skipping unknown review states
+    pub fn to_pull_request_review(&self) -> Option<PullRequestReview> {
+        let state = self.state.parse::<PullRequestReviewState>().ok()?;
+        Some(PullRequestReview {
+            reviewer: self
+                .author
+                .as_ref()
+                .map(|author| User::from(author.login.as_str())),
+            state,
+            submitted_at: self.submitted_at,
+            body: self.body.clone().unwrap_or_default(),
+        })
+    }
+}
+
+/// Response structure for the pull request reviews query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestReviewsResponse {
+    pub repository: Option<PullRequestReviewsRepository>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestReviewsRepository {
+    #[serde(rename = "pullRequest")]
+    pub pull_request: Option<PullRequestReviewsNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestReviewsNode {
+    pub reviews: ReviewsConnection,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestCommitsResponse {
+    pub repository: Option<PullRequestCommitsRepository>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestCommitsRepository {
+    #[serde(rename = "pullRequest")]
+    pub pull_request: Option<PullRequestCommitsNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestCommitsNode {
+    pub commits: PullRequestCommitsConnection,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestCommitsConnection {
+    pub nodes: Vec<PullRequestCommitNode>,
+    #[serde(rename = "pageInfo")]
+    pub page_info: PageInfo,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestCommitNode {
+    pub commit: PullRequestCommitDetails,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestCommitDetails {
+    pub oid: String,
+    pub message: String,
+    #[serde(rename = "authoredDate")]
+    pub authored_date: Option<DateTime<Utc>>,
+    pub author: Option<PullRequestCommitAuthor>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestCommitAuthor {
+    pub name: Option<String>,
+    pub user: Option<Author>,
+}
+
+impl From<PullRequestCommitDetails> for CommitSummary {
+    fn from(commit: PullRequestCommitDetails) -> Self {
+        Self {
+            sha: commit.oid,
+            message: commit.message,
+            author: commit
+                .author
+                .and_then(|author| author.user.map(|user| user.login).or(author.name)),
+            authored_at: commit.authored_date,
+        }
+    }
+}
+
+/// Response structure for the pull request checks query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestChecksResponse {
+    pub repository: Option<PullRequestChecksRepository>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestChecksRepository {
+    #[serde(rename = "pullRequest")]
+    pub pull_request: Option<PullRequestChecksNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestChecksNode {
+    pub commits: HeadCommitConnection,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeadCommitConnection {
+    pub nodes: Vec<HeadCommitNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeadCommitNode {
+    pub commit: HeadCommit,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeadCommit {
+    pub oid: String,
+    /// `None` when no checks or statuses were reported for the commit
+    #[serde(rename = "statusCheckRollup")]
+    pub status_check_rollup: Option<StatusCheckRollupNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusCheckRollupNode {
+    pub state: String,
+    pub contexts: StatusCheckContextConnection,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusCheckContextConnection {
+    pub nodes: Vec<StatusCheckContextNode>,
+}
+
+/// A check run or a commit status, distinguished by `__typename`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "__typename")]
+pub enum StatusCheckContextNode {
+    CheckRun {
+        name: String,
+        status: String,
+        /// `None` until the run has completed
+        conclusion: Option<String>,
+        #[serde(rename = "detailsUrl")]
+        details_url: Option<String>,
+    },
+    StatusContext {
+        context: String,
+        state: String,
+        #[serde(rename = "targetUrl")]
+        target_url: Option<String>,
+    },
+}
+
+impl StatusCheckContextNode {
+    pub fn to_pull_request_check(&self) -> PullRequestCheck {
+        match self {
+            StatusCheckContextNode::CheckRun {
+                name,
+                conclusion,
+                details_url,
+                ..
+            } => PullRequestCheck {
+                name: name.clone(),
+                conclusion: check_run_conclusion(conclusion.as_deref()),
+                details_url: details_url.clone(),
+            },
+            StatusCheckContextNode::StatusContext {
+                context,
+                state,
+                target_url,
+            } => PullRequestCheck {
+                name: context.clone(),
+                conclusion: status_state_conclusion(state),
+                details_url: target_url.clone(),
+            },
+        }
+    }
+}
+
+impl PullRequestChecksNode {
+    /// Convert the head commit's rollup into the checks of `pull_request_id`
+    ///
+    /// A pull request whose head commit has no rollup has no CI configured and yields
+    /// no checks and no combined state.
+    pub fn into_pull_request_checks(self, pull_request_id: PullRequestId) -> PullRequestChecks {
+        let head_commit = self
+            .commits
+            .nodes
+            .into_iter()
+            .last()
+            .map(|node| node.commit);
+        let head_sha = head_commit.as_ref().map(|commit| commit.oid.clone());
+        let rollup = head_commit.and_then(|commit| commit.status_check_rollup);
+
+        PullRequestChecks {
+            pull_request_id,
+            head_sha,
+            state: rollup
+                .as_ref()
+                .map(|rollup| status_state_conclusion(&rollup.state)),
+            checks: rollup
+                .map(|rollup| {
+                    rollup
+                        .contexts
+                        .nodes
+                        .iter()
+                        .map(StatusCheckContextNode::to_pull_request_check)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Map a `CheckConclusionState`, where `None` means the run has not completed
+fn check_run_conclusion(conclusion: Option<&str>) -> CheckConclusion {
+    match conclusion {
+        None => CheckConclusion::Pending,
+        Some("SUCCESS") => CheckConclusion::Success,
+        Some("NEUTRAL") => CheckConclusion::Neutral,
+        Some("SKIPPED") | Some("STALE") => CheckConclusion::Skipped,
+        // FAILURE, TIMED_OUT, CANCELLED, ACTION_REQUIRED, STARTUP_FAILURE
+        Some(_) => CheckConclusion::Failure,
+    }
+}
+
+/// Map a `StatusState` of a commit status or of the whole rollup
+fn status_state_conclusion(state: &str) -> CheckConclusion {
+    match state {
+        "SUCCESS" => CheckConclusion::Success,
+        "PENDING" | "EXPECTED" => CheckConclusion::Pending,
+        // FAILURE, ERROR
+        _ => CheckConclusion::Failure,
+    }
+}
+
 /// Response structure for multiple pull requests query
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MultiplePullRequestsResponse {
@@ -388,6 +642,189 @@ pub struct MultiplePullRequestsResponse {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MultiplePullRequestsRepository {
+    #[serde(rename = "isArchived", default)]
+    pub is_archived: Option<bool>,
     #[serde(flatten)]
     pub pull_requests: std::collections::HashMap<String, Option<PullRequestNode>>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn review_node(json: &str) -> ReviewNode {
+        serde_json::from_str(json).expect("valid review node")
+    }
+
+    #[test]
+    fn test_review_node_to_pull_request_review() {
+        let node = review_node(
+            r#"{
+                "id": "PRR_1",
+                "state": "CHANGES_REQUESTED",
+                "body": "Please add tests",
+                "createdAt": "2025-01-02T03:04:05Z",
+                "submittedAt": "2025-01-02T03:10:00Z",
+                "url": "https://github.com/owner/repo/pull/7#pullrequestreview-1",
+                "author": { "login": "alice" }
+            }"#,
+        );
+
+        let review = node.to_pull_request_review().unwrap();
+        assert_eq!(review.state, PullRequestReviewState::ChangesRequested);
+        assert_eq!(review.reviewer, Some(User::from("alice")));
+        assert_eq!(review.body, "Please add tests");
+        assert_eq!(
+            review.submitted_at.unwrap().to_rfc3339(),
+            "2025-01-02T03:10:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_pull_request_node_is_draft() {
+        let node: PullRequestNode = serde_json::from_str(
+            r#"{
+                "number": 7,
+                "title": "WIP: new parser",
+                "body": null,
+                "state": "OPEN",
+                "createdAt": "2025-01-02T03:04:05Z",
+                "updatedAt": "2025-01-02T03:04:05Z",
+                "url": "https://github.com/owner/repo/pull/7",
+                "isDraft": true,
+                "comments": { "nodes": [], "totalCount": 0 }
+            }"#,
+        )
+        .expect("valid pull request node");
+
+        let pr = PullRequest::try_from((
+            node.clone(),
+            crate::types::RepositoryId::new("owner", "repo"),
+        ))
+        .unwrap();
+        assert!(pr.draft);
+
+        let ready = PullRequestNode {
+            is_draft: Some(false),
+            ..node
+        };
+        let pr = PullRequest::try_from((ready, crate::types::RepositoryId::new("owner", "repo")))
+            .unwrap();
+        assert!(!pr.draft);
+    }
+
+    #[test]
+    fn test_pull_request_comments_count_uses_total_count() {
+        let node: PullRequestNode = serde_json::from_str(
+            r#"{
+                "number": 8,
+                "title": "Busy discussion",
+                "body": null,
+                "state": "OPEN",
+                "createdAt": "2025-01-02T03:04:05Z",
+                "updatedAt": "2025-01-02T03:04:05Z",
+                "url": "https://github.com/owner/repo/pull/8",
+                "comments": { "nodes": [], "totalCount": 42 }
+            }"#,
+        )
+        .expect("valid pull request node");
+
+        let pr = PullRequest::try_from((node, crate::types::RepositoryId::new("owner", "repo")))
+            .unwrap();
+        assert!(pr.comments.is_empty());
+        assert_eq!(pr.comments_count, 42);
+    }
+
+    #[test]
+    fn test_pending_review_without_author() {
+        let node = review_node(
+            r#"{
+                "id": "PRR_2",
+                "state": "PENDING",
+                "body": null,
+                "createdAt": "2025-01-02T03:04:05Z",
+                "submittedAt": null,
+                "url": null,
+                "author": null
+            }"#,
+        );
+
+        let review = node.to_pull_request_review().unwrap();
+        assert_eq!(review.state, PullRequestReviewState::Pending);
+        assert!(review.reviewer.is_none());
+        assert!(review.submitted_at.is_none());
+        assert_eq!(review.body, "");
+    }
+
+    fn pull_request_id() -> PullRequestId {
+        PullRequestId::new(crate::types::RepositoryId::new("owner", "repo"), 7)
+    }
+
+    #[test]
+    fn test_pull_request_checks_node_with_mixed_checks() {
+        let node: PullRequestChecksNode = serde_json::from_str(
+            r#"{
+                "commits": { "nodes": [{ "commit": {
+                    "oid": "0123456789abcdef",
+                    "statusCheckRollup": {
+                        "state": "FAILURE",
+                        "contexts": { "nodes": [
+                            { "__typename": "CheckRun", "name": "build", "status": "COMPLETED",
+                              "conclusion": "SUCCESS", "detailsUrl": "https://github.com/owner/repo/actions/runs/1" },
+                            { "__typename": "CheckRun", "name": "test", "status": "COMPLETED",
+                              "conclusion": "TIMED_OUT", "detailsUrl": null },
+                            { "__typename": "CheckRun", "name": "lint", "status": "IN_PROGRESS",
+                              "conclusion": null, "detailsUrl": null },
+                            { "__typename": "StatusContext", "context": "ci/circleci",
+                              "state": "ERROR", "targetUrl": "https://circleci.com/gh/owner/repo/1" }
+                        ] }
+                    }
+                } }] }
+            }"#,
+        )
+        .expect("valid pull request checks node");
+
+        let checks = node.into_pull_request_checks(pull_request_id());
+        assert_eq!(checks.head_sha.as_deref(), Some("0123456789abcdef"));
+        assert_eq!(checks.state, Some(CheckConclusion::Failure));
+        assert_eq!(
+            checks.checks,
+            vec![
+                PullRequestCheck {
+                    name: "build".to_string(),
+                    conclusion: CheckConclusion::Success,
+                    details_url: Some("https://github.com/owner/repo/actions/runs/1".to_string()),
+                },
+                PullRequestCheck {
+                    name: "test".to_string(),
+                    conclusion: CheckConclusion::Failure,
+                    details_url: None,
+                },
+                PullRequestCheck {
+                    name: "lint".to_string(),
+                    conclusion: CheckConclusion::Pending,
+                    details_url: None,
+                },
+                PullRequestCheck {
+                    name: "ci/circleci".to_string(),
+                    conclusion: CheckConclusion::Failure,
+                    details_url: Some("https://circleci.com/gh/owner/repo/1".to_string()),
+                },
+            ]
+        );
+        assert_eq!(checks.count(CheckConclusion::Failure), 2);
+    }
+
+    #[test]
+    fn test_pull_request_checks_node_without_ci() {
+        let node: PullRequestChecksNode = serde_json::from_str(
+            r#"{ "commits": { "nodes": [{ "commit": { "oid": "abc", "statusCheckRollup": null } }] } }"#,
+        )
+        .expect("valid pull request checks node");
+
+        let checks = node.into_pull_request_checks(pull_request_id());
+        assert_eq!(checks.head_sha.as_deref(), Some("abc"));
+        assert_eq!(checks.state, None);
+        assert!(checks.checks.is_empty());
+    }
+}
@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::RateLimitStatus;
+
+/// GraphQL response for the rate limit query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitResponse {
+    #[serde(rename = "rateLimit")]
+    pub rate_limit: Option<RateLimitNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitNode {
+    pub limit: u32,
+    pub cost: u32,
+    pub remaining: u32,
+    pub used: u32,
+    #[serde(rename = "resetAt")]
+    pub reset_at: DateTime<Utc>,
+}
+
+/// Point cost of a query and the points left, selected by `RATE_LIMIT_COST_FRAGMENT`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RateLimitCostNode {
+    pub cost: u64,
+    pub remaining: u64,
+}
+
+impl From<RateLimitNode> for RateLimitStatus {
+    fn from(node: RateLimitNode) -> Self {
+        Self {
+            limit: node.limit,
+            remaining: node.remaining,
+            used: node.used,
+            cost: node.cost,
+            reset_at: node.reset_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limit_response_into_status() {
+        let response: RateLimitResponse = serde_json::from_str(
+            r#"{
+                "rateLimit": {
+                    "limit": 5000,
+                    "cost": 1,
+                    "remaining": 4999,
+                    "used": 1,
+                    "resetAt": "2025-01-02T03:04:05Z"
+                }
+            }"#,
+        )
+        .expect("valid rate limit response");
+
+        let status = RateLimitStatus::from(response.rate_limit.unwrap());
+        assert_eq!(status.limit, 5000);
+        assert_eq!(status.remaining, 4999);
+        assert_eq!(status.used, 1);
+        assert_eq!(status.cost, 1);
+        assert_eq!(status.reset_at.to_rfc3339(), "2025-01-02T03:04:05+00:00");
+        assert!(!status.is_exhausted());
+    }
+}
@@ -1,4 +1,9 @@
 use crate::github::graphql::graphql_types::LabelsConnection;
+use crate::github::graphql::graphql_types::pager::PageInfo;
+use crate::types::{
+    ReleaseAsset, ReleaseWithAssets, RepositoryId, RepositoryRelease, RepositoryStats,
+};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// Wrapper type for milestone numbers providing type safety
@@ -45,6 +50,8 @@ pub struct RepositoryResponse {
 pub struct RepositoryNode {
     pub name: String,
     pub description: Option<String>,
+    #[serde(rename = "isArchived", default)]
+    pub is_archived: Option<bool>,
     #[serde(rename = "primaryLanguage")]
     pub primary_language: Option<PrimaryLanguage>,
     #[serde(rename = "createdAt")]
@@ -56,6 +63,10 @@ pub struct RepositoryNode {
     pub milestones: MilestonesConnection,
     pub labels: LabelsConnection,
     pub owner: RepositoryOwner,
+    #[serde(rename = "repositoryTopics", default)]
+    pub repository_topics: Option<RepositoryTopicsConnection>,
+    #[serde(rename = "pinnedIssues", default)]
+    pub pinned_issues: Option<PinnedIssuesConnection>,
     #[serde(rename = "mentionableUsers")]
     pub mentionable_users: MentionableUsersConnection,
     pub releases: ReleasesConnection,
@@ -66,6 +77,29 @@ pub struct PrimaryLanguage {
     pub name: String,
 }
 
+/// GraphQL response for the languages of a repository
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryLanguagesResponse {
+    pub repository: Option<RepositoryLanguagesNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryLanguagesNode {
+    pub languages: LanguageConnection,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LanguageConnection {
+    pub edges: Vec<LanguageEdge>,
+}
+
+/// A repository language with the bytes of code written in it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LanguageEdge {
+    pub size: u64,
+    pub node: PrimaryLanguage,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BranchRef {
     pub name: String,
@@ -84,6 +118,36 @@ pub struct MilestoneNode {
     pub due_on: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryTopicsConnection {
+    pub nodes: Vec<RepositoryTopicNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryTopicNode {
+    pub topic: TopicNode,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicNode {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PinnedIssuesConnection {
+    pub nodes: Vec<PinnedIssueNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PinnedIssueNode {
+    pub issue: PinnedIssueRef,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PinnedIssueRef {
+    pub number: u32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MentionableUsersConnection {
     pub nodes: Vec<MentionableUserNode>,
@@ -132,3 +196,145 @@ pub struct ReleaseAuthor {
     pub login: String,
     pub name: Option<String>,
 }
+
+/// GraphQL response for a page of repository releases with their assets
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryReleasesResponse {
+    pub repository: Option<RepositoryReleasesNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryReleasesNode {
+    pub releases: ReleasesWithAssetsConnection,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleasesWithAssetsConnection {
+    pub nodes: Vec<ReleaseWithAssetsNode>,
+    #[serde(rename = "pageInfo")]
+    pub page_info: PageInfo,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseWithAssetsNode {
+    #[serde(flatten)]
+    pub release: ReleaseNode,
+    #[serde(rename = "releaseAssets")]
+    pub release_assets: ReleaseAssetsConnection,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseAssetsConnection {
+    pub nodes: Vec<ReleaseAssetNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseAssetNode {
+    pub name: String,
+    pub size: u64,
+    pub download_count: u64,
+    pub download_url: String,
+}
+
+impl From<ReleaseWithAssetsNode> for ReleaseWithAssets {
+    fn from(node: ReleaseWithAssetsNode) -> Self {
+        ReleaseWithAssets {
+            release: RepositoryRelease::from(node.release),
+            assets: node
+                .release_assets
+                .nodes
+                .into_iter()
+                .map(|asset| ReleaseAsset {
+                    name: asset.name,
+                    size: asset.size,
+                    download_count: asset.download_count,
+                    download_url: asset.download_url,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// GraphQL response for the counts of a repository
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryStatsResponse {
+    pub repository: Option<RepositoryStatsNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryStatsNode {
+    pub open_issues: TotalCount,
+    pub closed_issues: TotalCount,
+    pub open_pull_requests: TotalCount,
+    pub closed_pull_requests: TotalCount,
+    pub merged_pull_requests: TotalCount,
+    pub stargazer_count: u64,
+    pub fork_count: u64,
+    pub pushed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TotalCount {
+    #[serde(rename = "totalCount")]
+    pub total_count: u64,
+}
+
+impl RepositoryStatsNode {
+    /// Convert into `RepositoryStats` of the queried repository
+    pub fn into_stats(self, repository_id: RepositoryId) -> RepositoryStats {
+        RepositoryStats {
+            repository_id,
+            open_issues: self.open_issues.total_count,
+            closed_issues: self.closed_issues.total_count,
+            open_pull_requests: self.open_pull_requests.total_count,
+            closed_pull_requests: self.closed_pull_requests.total_count,
+            merged_pull_requests: self.merged_pull_requests.total_count,
+            stargazers: self.stargazer_count,
+            forks: self.fork_count,
+            pushed_at: self.pushed_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repository_stats_response_into_stats() {
+        let response: RepositoryStatsResponse = serde_json::from_str(
+            r#"{
+                "repository": {
+                    "openIssues": {"totalCount": 12},
+                    "closedIssues": {"totalCount": 340},
+                    "openPullRequests": {"totalCount": 3},
+                    "closedPullRequests": {"totalCount": 20},
+                    "mergedPullRequests": {"totalCount": 400},
+                    "stargazerCount": 1200,
+                    "forkCount": 80,
+                    "pushedAt": "2025-01-02T03:04:05Z"
+                }
+            }"#,
+        )
+        .expect("valid repository stats response");
+
+        let stats = response
+            .repository
+            .unwrap()
+            .into_stats(RepositoryId::new("owner", "repo"));
+        assert_eq!(stats.repository_id, RepositoryId::new("owner", "repo"));
+        assert_eq!(stats.open_issues, 12);
+        assert_eq!(stats.closed_issues, 340);
+        assert_eq!(stats.open_pull_requests, 3);
+        assert_eq!(stats.closed_pull_requests, 20);
+        assert_eq!(stats.merged_pull_requests, 400);
+        assert_eq!(stats.stargazers, 1200);
+        assert_eq!(stats.forks, 80);
+        assert_eq!(
+            stats.pushed_at.unwrap().to_rfc3339(),
+            "2025-01-02T03:04:05+00:00"
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::github::graphql::graphql_types::{IssueNode, PageInfo, PullRequestNode};
+use crate::github::graphql::graphql_types::{DiscussionNode, IssueNode, PageInfo, PullRequestNode};
 
 /// GraphQL response structures for Search API
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +22,9 @@ pub enum SearchResult {
     Issue(IssueNode),
     #[serde(rename = "PullRequest")]
     PullRequest(PullRequestNode),
+    /// Only returned by `type: DISCUSSION` searches
+    #[serde(rename = "Discussion")]
+    Discussion(DiscussionNode),
     #[serde(other)]
     Other,
 }
@@ -5,7 +5,9 @@ use strum::{Display, EnumString};
 
 use crate::github::graphql::graphql_types::pager::PageInfo;
 use crate::github::graphql::graphql_types::repository::{Repository, RepositoryOwner};
-use crate::types::{IssueId, IssueOrPullrequestId, PullRequestId, RepositoryId};
+use crate::types::{
+    IssueId, IssueOrPullrequestId, PullRequestId, RepositoryId, TimelineEvent, TimelineEventKind,
+};
 
 /// Timeline event types from GraphQL API
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumString, Display)]
@@ -94,6 +96,59 @@ pub enum TimelineItem {
     Other,
 }
 
+impl TimelineItem {
+    /// Convert to a timeline event, skipping unsupported events and sources
+    pub fn to_timeline_event(&self) -> Option<TimelineEvent> {
+        let (kind, created_at, linked, will_close_target) = match self {
+            TimelineItem::CrossReferenced {
+                created_at,
+                source: Some(source),
+                will_close_target,
+            } => (
+                TimelineEventKind::CrossReferenced,
+                created_at,
+                source
+                    .title_and_state()
+                    .zip(Option::<IssueOrPullrequestId>::from(source.clone())),
+                will_close_target.unwrap_or(false),
+            ),
+            TimelineItem::Connected {
+                created_at,
+                subject: Some(subject),
+            } => (
+                TimelineEventKind::Connected,
+                created_at,
+                subject
+                    .title_and_state()
+                    .zip(Option::<IssueOrPullrequestId>::from(subject.clone())),
+                false,
+            ),
+            TimelineItem::Disconnected {
+                created_at,
+                subject: Some(subject),
+            } => (
+                TimelineEventKind::Disconnected,
+                created_at,
+                subject
+                    .title_and_state()
+                    .zip(Option::<IssueOrPullrequestId>::from(subject.clone())),
+                false,
+            ),
+            _ => return None,
+        };
+        let ((title, state), resource) = linked?;
+
+        Some(TimelineEvent {
+            kind,
+            created_at: *created_at,
+            resource,
+            title: title.to_string(),
+            state: state.to_string(),
+            will_close_target,
+        })
+    }
+}
+
 impl<'de> Deserialize<'de> for TimelineItem {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -192,6 +247,16 @@ pub enum CrossReferenceSource {
     Other,
 }
 
+impl CrossReferenceSource {
+    fn title_and_state(&self) -> Option<(&str, &str)> {
+        match self {
+            CrossReferenceSource::Issue { title, state, .. }
+            | CrossReferenceSource::PullRequest { title, state, .. } => Some((title, state)),
+            CrossReferenceSource::Other => None,
+        }
+    }
+}
+
 impl From<CrossReferenceSource> for Option<IssueOrPullrequestId> {
     fn from(source: CrossReferenceSource) -> Self {
         match source {
@@ -418,6 +483,16 @@ impl<'de> Deserialize<'de> for ConnectedSubject {
     }
 }
 
+impl ConnectedSubject {
+    fn title_and_state(&self) -> Option<(&str, &str)> {
+        match self {
+            ConnectedSubject::Issue { title, state, .. }
+            | ConnectedSubject::PullRequest { title, state, .. } => Some((title, state)),
+            ConnectedSubject::Other => None,
+        }
+    }
+}
+
 impl From<ConnectedSubject> for Option<IssueOrPullrequestId> {
     fn from(subject: ConnectedSubject) -> Self {
         match subject {
@@ -439,3 +514,60 @@ impl From<ConnectedSubject> for Option<IssueOrPullrequestId> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timeline_item(json: &str) -> TimelineItem {
+        serde_json::from_str(json).expect("valid timeline item JSON")
+    }
+
+    #[test]
+    fn test_cross_referenced_pull_request_to_timeline_event() {
+        let item = timeline_item(
+            r#"{
+                "__typename": "CrossReferencedEvent",
+                "createdAt": "2025-01-02T03:04:05Z",
+                "source": {
+                    "__typename": "PullRequest",
+                    "number": 7,
+                    "title": "Fix the crash",
+                    "url": "https://github.com/owner/repo/pull/7",
+                    "state": "OPEN",
+                    "repository": { "owner": { "login": "owner" }, "name": "repo" }
+                },
+                "willCloseTarget": true
+            }"#,
+        );
+
+        let event = item.to_timeline_event().expect("timeline event");
+        assert_eq!(event.kind, TimelineEventKind::CrossReferenced);
+        assert_eq!(event.title, "Fix the crash");
+        assert_eq!(event.state, "OPEN");
+        assert!(event.will_close_target);
+        assert_eq!(
+            event.resource,
+            IssueOrPullrequestId::PullrequestId(PullRequestId::new(
+                RepositoryId::new("owner", "repo"),
+                7
+            ))
+        );
+    }
+
+    #[test]
+    fn test_unsupported_items_are_skipped() {
+        let other = timeline_item(r#"{ "__typename": "LabeledEvent" }"#);
+        assert!(other.to_timeline_event().is_none());
+
+        let discussion_source = timeline_item(
+            r#"{
+                "__typename": "CrossReferencedEvent",
+                "createdAt": "2025-01-02T03:04:05Z",
+                "source": { "__typename": "Discussion" },
+                "willCloseTarget": false
+            }"#,
+        );
+        assert!(discussion_source.to_timeline_event().is_none());
+    }
+}
@@ -10,6 +10,16 @@ pub struct UserNode {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Author {
     pub login: String,
+    /// GraphQL type of the actor (`User`, `Bot`, ...), when the query selects it
+    #[serde(rename = "__typename", default)]
+    pub typename: Option<String>,
+}
+
+impl Author {
+    /// Returns true when the actor is a bot, by GraphQL type or by a `[bot]` login suffix
+    pub fn is_bot(&self) -> bool {
+        self.typename.as_deref() == Some("Bot") || crate::types::User::is_bot_login(&self.login)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,3 +31,13 @@ pub struct AssigneesConnection {
 pub struct AssigneeNode {
     pub login: String,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewerResponse {
+    pub viewer: Option<ViewerNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewerNode {
+    pub login: String,
+}
@@ -1,8 +1,15 @@
-use crate::types::{IssueNumber, Owner, RepositoryName};
+use crate::github::graphql::rate_limit::RATE_LIMIT_COST_FRAGMENT;
+use crate::types::{IssueNumber, MilestoneNumber, Owner, RepositoryName, SearchCursor};
 use serde::{Deserialize, Serialize};
 
 const DEFAULT_LIMIT: u8 = 100;
 
+/// Largest `first` argument GitHub accepts on a connection
+const GITHUB_MAX_PAGE_SIZE: u8 = 100;
+
+/// Issues per page of a milestone; kept small since each issue includes its comments
+const MILESTONE_ISSUES_PER_PAGE: u8 = 25;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct IssueQueryLimitSize {
     assignee_limit: u8,
@@ -21,6 +28,16 @@ impl Default for IssueQueryLimitSize {
     }
 }
 
+impl IssueQueryLimitSize {
+    /// Sets the number of comments fetched inline with each issue
+    ///
+    /// Values above GitHub's page size maximum (100) are clamped.
+    pub fn with_comment_limit(mut self, comment_limit: u32) -> Self {
+        self.comment_limit = comment_limit.min(GITHUB_MAX_PAGE_SIZE as u32) as u8;
+        self
+    }
+}
+
 pub fn issue_query_body(limit_size: IssueQueryLimitSize) -> String {
     let IssueQueryLimitSize {
         assignee_limit,
@@ -65,6 +82,7 @@ pub fn issue_query_body(limit_size: IssueQueryLimitSize) -> String {
                         url
                         author {{
                           login
+                          __typename
                         }}
                       }}
                       totalCount
@@ -137,10 +155,13 @@ pub fn multi_issue_query(issue_numbers: &[IssueNumber], limit_size: IssueQueryLi
         r#"
              query($owner: String!, $repository_name: String!) {{
                  repository(owner: $owner, name: $repository_name) {{
+                     isArchived
                      {}
                  }}
+                 {}
              }}"#,
-        each_issue_queries.join("\n")
+        each_issue_queries.join("\n"),
+        RATE_LIMIT_COST_FRAGMENT
     )
 }
 
@@ -189,3 +210,123 @@ pub fn issue_search_query(limit_size: IssueQueryLimitSize, with_cursor: bool) ->
         )
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueCommentsVariable {
+    pub owner: Owner,
+    pub repository_name: RepositoryName,
+    pub issue_number: IssueNumber,
+    pub per_page: u32,
+    pub cursor: Option<SearchCursor>,
+}
+
+/// Query for one page of an issue's comments, starting after `$cursor` when given
+pub fn issue_comments_query() -> String {
+    r#"
+        query($owner: String!, $repository_name: String!, $issue_number: Int!, $per_page: Int!, $cursor: String) {
+            repository(owner: $owner, name: $repository_name) {
+                issue(number: $issue_number) {
+                    comments(first: $per_page, after: $cursor) {
+                        nodes {
+                            id
+                            body
+                            createdAt
+                            updatedAt
+                            url
+                            author {
+                                login
+                                __typename
+                            }
+                        }
+                        totalCount
+                        pageInfo {
+                            hasNextPage
+                            endCursor
+                        }
+                    }
+                }
+            }
+        }
+    "#
+    .to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueTimelineVariable {
+    pub owner: Owner,
+    pub repository_name: RepositoryName,
+    pub issue_number: IssueNumber,
+}
+
+/// Query for the cross-reference timeline events of an issue
+pub fn issue_timeline_query() -> String {
+    format!(
+        r#"
+        query($owner: String!, $repository_name: String!, $issue_number: Int!) {{
+            repository(owner: $owner, name: $repository_name) {{
+                issue(number: $issue_number) {{
+                    title
+                    {}
+                }}
+            }}
+        }}
+    "#,
+        crate::github::graphql::timeline::timeline_items_query(DEFAULT_LIMIT)
+    )
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MilestoneIssuesVariable {
+    pub owner: Owner,
+    pub repository_name: RepositoryName,
+    pub milestone_number: MilestoneNumber,
+    /// GraphQL `IssueState` values, e.g. `OPEN`
+    pub states: Vec<String>,
+    pub cursor: Option<SearchCursor>,
+}
+
+/// Query for one page of the issues of a milestone, starting after `$cursor` when given
+pub fn milestone_issues_query(limit_size: IssueQueryLimitSize) -> String {
+    format!(
+        r#"
+        query($owner: String!, $repository_name: String!, $milestone_number: Int!, $states: [IssueState!], $cursor: String) {{
+            repository(owner: $owner, name: $repository_name) {{
+                milestone(number: $milestone_number) {{
+                    issues(first: {}, states: $states, after: $cursor) {{
+                        {}
+                }}
+            }}
+        }}
+    "#,
+        MILESTONE_ISSUES_PER_PAGE,
+        issue_connection_query_body(limit_size)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multi_issue_query_uses_comment_limit() {
+        let issue_numbers = [IssueNumber::new(1), IssueNumber::new(2)];
+        let query = multi_issue_query(
+            &issue_numbers,
+            IssueQueryLimitSize::default().with_comment_limit(5),
+        );
+
+        assert!(query.contains("comments(first: 5)"));
+        assert!(!query.contains("comments(first: 100)"));
+    }
+
+    #[test]
+    fn test_comment_limit_is_clamped_to_github_maximum() {
+        let query = multi_issue_query(
+            &[IssueNumber::new(1)],
+            IssueQueryLimitSize::default().with_comment_limit(300),
+        );
+
+        assert!(query.contains("comments(first: 100)"));
+        assert!(!query.contains("comments(first: 300)"));
+    }
+}
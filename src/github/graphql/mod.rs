@@ -1,8 +1,11 @@
+pub mod commit;
 pub mod error;
 pub mod graphql_types;
 pub mod issue;
 pub mod project;
 pub mod pull_request;
+pub mod rate_limit;
 pub mod repository;
 pub mod search;
 pub mod timeline;
+pub mod viewer;
@@ -1,4 +1,5 @@
-use crate::types::{Owner, ProjectNumber, SearchCursor};
+use crate::github::graphql::rate_limit::RATE_LIMIT_COST_FRAGMENT;
+use crate::types::{Owner, ProjectFieldUpdateValue, ProjectNumber, SearchCursor};
 use serde::{Deserialize, Serialize};
 
 const DEFAULT_LIMIT: u8 = 100;
@@ -23,6 +24,16 @@ impl Default for ProjectQueryLimitSize {
     }
 }
 
+impl ProjectQueryLimitSize {
+    /// Same limits with a different number of items per page, capped at 100
+    pub fn with_item_limit(self, item_limit: u8) -> Self {
+        Self {
+            item_limit: item_limit.min(DEFAULT_ITEM_IMIT),
+            ..self
+        }
+    }
+}
+
 fn project_query_body(limit_size: ProjectQueryLimitSize, cursor: Option<SearchCursor>) -> String {
     let ProjectQueryLimitSize {
         item_limit,
@@ -161,6 +172,17 @@ fn project_query_body(limit_size: ProjectQueryLimitSize, cursor: Option<SearchCu
                             }}
                             date
                           }}
+                          ... on ProjectV2ItemFieldIterationValue {{
+                            field {{
+                              ... on ProjectV2FieldCommon {{
+                                id
+                                name
+                              }}
+                            }}
+                            title
+                            startDate
+                            duration
+                          }}
                         }}
                       }}
                     }}
@@ -200,29 +222,41 @@ pub struct ProjectVariable {
     pub owner: Owner,
 }
 
-pub fn single_project_query(project_number: ProjectNumber, cursor: Option<SearchCursor>) -> String {
+pub fn single_project_query(
+    project_number: ProjectNumber,
+    limit_size: ProjectQueryLimitSize,
+    cursor: Option<SearchCursor>,
+) -> String {
     format!(
         r#"
              query($owner: String!) {{
                  organization(login: $owner) {{
                      {}
                  }}
+                 {}
              }}
         "#,
-        single_project_query_body(project_number, ProjectQueryLimitSize::default(), cursor)
+        single_project_query_body(project_number, limit_size, cursor),
+        RATE_LIMIT_COST_FRAGMENT
     )
 }
 
-pub fn user_project_query(project_number: ProjectNumber, cursor: Option<SearchCursor>) -> String {
+pub fn user_project_query(
+    project_number: ProjectNumber,
+    limit_size: ProjectQueryLimitSize,
+    cursor: Option<SearchCursor>,
+) -> String {
     format!(
         r#"
              query($owner: String!) {{
                  user(login: $owner) {{
                      {}
                  }}
+                 {}
              }}
         "#,
-        single_project_query_body(project_number, ProjectQueryLimitSize::default(), cursor)
+        single_project_query_body(project_number, limit_size, cursor),
+        RATE_LIMIT_COST_FRAGMENT
     )
 }
 
@@ -262,9 +296,11 @@ pub fn multi_project_query(project_numbers: &[ProjectNumber]) -> String {
                  organization(login: $owner) {{
                      {}
                  }}
+                 {}
              }}
         "#,
-        each_project_queries.join("\n")
+        each_project_queries.join("\n"),
+        RATE_LIMIT_COST_FRAGMENT
     )
 }
 
@@ -283,8 +319,123 @@ pub fn multi_user_project_query(project_numbers: &[ProjectNumber]) -> String {
                  user(login: $owner) {{
                      {}
                  }}
+                 {}
              }}
         "#,
-        each_project_queries.join("\n")
+        each_project_queries.join("\n"),
+        RATE_LIMIT_COST_FRAGMENT
     )
 }
+
+fn project_fields_query_body(project_number: ProjectNumber) -> String {
+    format!(
+        r#"
+        projectV2(number: {}) {{
+            fields(first: {}) {{
+                nodes {{
+                    __typename
+                    ... on ProjectV2FieldCommon {{
+                        id
+                        name
+                        dataType
+                    }}
+                    ... on ProjectV2SingleSelectField {{
+                        options {{
+                            id
+                            name
+                        }}
+                    }}
+                }}
+            }}
+        }} "#,
+        project_number.value(),
+        DEFAULT_LIMIT,
+    )
+}
+
+/// Query for the field definitions of an organization project
+pub fn project_fields_query(project_number: ProjectNumber) -> String {
+    format!(
+        r#"
+             query($owner: String!) {{
+                 organization(login: $owner) {{
+                     {}
+                 }}
+             }}
+        "#,
+        project_fields_query_body(project_number)
+    )
+}
+
+/// Query for the field definitions of a user project
+pub fn user_project_fields_query(project_number: ProjectNumber) -> String {
+    format!(
+        r#"
+             query($owner: String!) {{
+                 user(login: $owner) {{
+                     {}
+                 }}
+             }}
+        "#,
+        project_fields_query_body(project_number)
+    )
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateProjectItemFieldVariable {
+    pub project_id: String,
+    pub item_id: String,
+    pub field_id: String,
+    pub value: ProjectV2FieldValueInput,
+}
+
+/// `ProjectV2FieldValue` input object; exactly one member is set
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectV2FieldValueInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub single_select_option_id: Option<String>,
+}
+
+impl From<&ProjectFieldUpdateValue> for ProjectV2FieldValueInput {
+    fn from(value: &ProjectFieldUpdateValue) -> Self {
+        match value {
+            ProjectFieldUpdateValue::Text(text) => Self {
+                text: Some(text.clone()),
+                ..Self::default()
+            },
+            ProjectFieldUpdateValue::Number(number) => Self {
+                number: Some(*number),
+                ..Self::default()
+            },
+            ProjectFieldUpdateValue::Date(date) => Self {
+                date: Some(date.format("%Y-%m-%d").to_string()),
+                ..Self::default()
+            },
+            ProjectFieldUpdateValue::SingleSelectOptionId(option_id) => Self {
+                single_select_option_id: Some(option_id.clone()),
+                ..Self::default()
+            },
+        }
+    }
+}
+
+/// Mutation setting the value of a field of a project item
+pub fn update_project_item_field_mutation() -> String {
+    r#"
+        mutation($project_id: ID!, $item_id: ID!, $field_id: ID!, $value: ProjectV2FieldValue!) {
+            updateProjectV2ItemFieldValue(input: {projectId: $project_id, itemId: $item_id, fieldId: $field_id, value: $value}) {
+                projectV2Item {
+                    id
+                }
+            }
+        }
+    "#
+    .to_string()
+}
@@ -1,5 +1,5 @@
 use crate::{
-    github::graphql::timeline::timeline_items_query,
+    github::graphql::{rate_limit::RATE_LIMIT_COST_FRAGMENT, timeline::timeline_items_query},
     types::{Owner, PullRequestNumber, RepositoryName, SearchCursor},
 };
 use serde::{Deserialize, Serialize};
@@ -96,6 +96,7 @@ pub fn pull_request_query_body(limit_size: PullRequestQueryLimitSize) -> String
                     }}
                     locked
                     isDraft
+                    reviewDecision
                     comments(first: {}) {{
                       nodes {{
                         id
@@ -105,6 +106,7 @@ pub fn pull_request_query_body(limit_size: PullRequestQueryLimitSize) -> String
                         url
                         author {{
                           login
+                          __typename
                         }}
                       }}
                       totalCount
@@ -201,10 +203,142 @@ pub fn multi_pull_reqeust_query(
         r#"
              query($owner: String!, $repository_name: String!) {{
                  repository(owner: $owner, name: $repository_name) {{
+                     isArchived
                      {}
                  }}
+                 {}
              }}"#,
-        each_pr_queries.join("\n")
+        each_pr_queries.join("\n"),
+        RATE_LIMIT_COST_FRAGMENT
+    )
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestReviewsVariable {
+    pub owner: Owner,
+    pub repository_name: RepositoryName,
+    pub pull_request_number: PullRequestNumber,
+}
+
+/// Query for the submitted reviews of a pull request
+pub fn pull_request_reviews_query() -> String {
+    format!(
+        r#"
+        query($owner: String!, $repository_name: String!, $pull_request_number: Int!) {{
+            repository(owner: $owner, name: $repository_name) {{
+                pullRequest(number: $pull_request_number) {{
+                    reviews(first: {}) {{
+                        nodes {{
+                            id
+                            state
+                            body
+                            createdAt
+                            submittedAt
+                            url
+                            author {{
+                                login
+                            }}
+                        }}
+                        totalCount
+                    }}
+                }}
+            }}
+        }}
+    "#,
+        DEFAULT_LIMIT
+    )
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestCommitsVariable {
+    pub owner: Owner,
+    pub repository_name: RepositoryName,
+    pub pull_request_number: PullRequestNumber,
+    pub cursor: Option<SearchCursor>,
+}
+
+/// Query for one page of the commits of a pull request, starting after `$cursor` when given
+pub fn pull_request_commits_query() -> String {
+    format!(
+        r#"
+        query($owner: String!, $repository_name: String!, $pull_request_number: Int!, $cursor: String) {{
+            repository(owner: $owner, name: $repository_name) {{
+                pullRequest(number: $pull_request_number) {{
+                    commits(first: {}, after: $cursor) {{
+                        nodes {{
+                            commit {{
+                                oid
+                                message
+                                authoredDate
+                                author {{
+                                    name
+                                    user {{
+                                        login
+                                    }}
+                                }}
+                            }}
+                        }}
+                        pageInfo {{
+                            hasNextPage
+                            endCursor
+                        }}
+                    }}
+                }}
+            }}
+        }}
+    "#,
+        DEFAULT_LIMIT
+    )
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestChecksVariable {
+    pub owner: Owner,
+    pub repository_name: RepositoryName,
+    pub pull_request_number: PullRequestNumber,
+}
+
+/// Query for the status check rollup of the head commit of a pull request
+///
+/// Both check runs (GitHub Actions, check apps) and legacy commit statuses are selected,
+/// up to `DEFAULT_LIMIT` of them.
+pub fn pull_request_checks_query() -> String {
+    format!(
+        r#"
+        query($owner: String!, $repository_name: String!, $pull_request_number: Int!) {{
+            repository(owner: $owner, name: $repository_name) {{
+                pullRequest(number: $pull_request_number) {{
+                    commits(last: 1) {{
+                        nodes {{
+                            commit {{
+                                oid
+                                statusCheckRollup {{
+                                    state
+                                    contexts(first: {}) {{
+                                        nodes {{
+                                            __typename
+                                            ... on CheckRun {{
+                                                name
+                                                status
+                                                conclusion
+                                                detailsUrl
+                                            }}
+                                            ... on StatusContext {{
+                                                context
+                                                state
+                                                targetUrl
+                                            }}
+                                        }}
+                                    }}
+                                }}
+                            }}
+                        }}
+                    }}
+                }}
+            }}
+        }}
+    "#,
+        DEFAULT_LIMIT
     )
 }
 
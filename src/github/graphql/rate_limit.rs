@@ -0,0 +1,21 @@
+/// Query for the rate limit budget of the authenticated client
+pub fn rate_limit_query() -> String {
+    r#"
+        query {
+            rateLimit {
+                limit
+                cost
+                remaining
+                used
+                resetAt
+            }
+        }
+    "#
+    .to_string()
+}
+
+/// Selection of the point cost of a query, added next to the root field of costly queries
+///
+/// `execute_graphql` reads it from the response to log the cost of each request and to
+/// account it against the client's `GraphQLCostBudget`.
+pub const RATE_LIMIT_COST_FRAGMENT: &str = "rateLimit { cost remaining }";
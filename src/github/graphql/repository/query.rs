@@ -1,4 +1,4 @@
-use crate::types::{Owner, RepositoryName};
+use crate::types::{Owner, RepositoryName, SearchCursor};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,6 +13,7 @@ pub fn repository_query() -> String {
             repository(owner: $owner, name: $repository_name) {
                 name
                 description
+                isArchived
                 primaryLanguage {
                     name
                 }
@@ -37,6 +38,20 @@ pub fn repository_query() -> String {
                 owner {
                     login
                 }
+                repositoryTopics(first: 20) {
+                    nodes {
+                        topic {
+                            name
+                        }
+                    }
+                }
+                pinnedIssues(first: 3) {
+                    nodes {
+                        issue {
+                            number
+                        }
+                    }
+                }
                 mentionableUsers(first: 100) {
                     nodes {
                         login
@@ -65,3 +80,101 @@ pub fn repository_query() -> String {
     "#
     .to_string()
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryReleasesVariable {
+    pub owner: Owner,
+    pub repository_name: RepositoryName,
+    pub first: u32,
+    pub cursor: Option<SearchCursor>,
+}
+
+/// Query for one page of releases, newest first, with up to 100 assets each
+pub fn repository_releases_query() -> String {
+    r#"
+        query($owner: String!, $repository_name: String!, $first: Int!, $cursor: String) {
+            repository(owner: $owner, name: $repository_name) {
+                releases(first: $first, after: $cursor, orderBy: {field: CREATED_AT, direction: DESC}) {
+                    nodes {
+                        name
+                        tagName
+                        description
+                        createdAt
+                        publishedAt
+                        isPrerelease
+                        isDraft
+                        author {
+                            login
+                            name
+                        }
+                        url
+                        releaseAssets(first: 100) {
+                            nodes {
+                                name
+                                size
+                                downloadCount
+                                downloadUrl
+                            }
+                        }
+                    }
+                    pageInfo {
+                        hasNextPage
+                        endCursor
+                    }
+                }
+            }
+        }
+    "#
+    .to_string()
+}
+
+/// Query for the languages of a repository with their sizes in bytes, largest first
+pub fn repository_languages_query() -> String {
+    r#"
+        query($owner: String!, $repository_name: String!) {
+            repository(owner: $owner, name: $repository_name) {
+                languages(first: 100, orderBy: {field: SIZE, direction: DESC}) {
+                    edges {
+                        size
+                        node {
+                            name
+                        }
+                    }
+                }
+            }
+        }
+    "#
+    .to_string()
+}
+
+/// Query for issue and pull request counts by state, stargazers, and forks of a repository
+///
+/// Only `totalCount`s are selected, so this costs a single point regardless of how
+/// many issues and pull requests the repository has.
+pub fn repository_stats_query() -> String {
+    r#"
+        query($owner: String!, $repository_name: String!) {
+            repository(owner: $owner, name: $repository_name) {
+                openIssues: issues(states: OPEN) {
+                    totalCount
+                }
+                closedIssues: issues(states: CLOSED) {
+                    totalCount
+                }
+                openPullRequests: pullRequests(states: OPEN) {
+                    totalCount
+                }
+                closedPullRequests: pullRequests(states: CLOSED) {
+                    totalCount
+                }
+                mergedPullRequests: pullRequests(states: MERGED) {
+                    totalCount
+                }
+                stargazerCount
+                forkCount
+                pushedAt
+            }
+        }
+    "#
+    .to_string()
+}
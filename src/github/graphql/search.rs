@@ -3,21 +3,36 @@ use std::sync::LazyLock;
 use regex::Regex;
 use serde::Serialize;
 
-use crate::types::{RepositoryId, SearchQuery};
+use crate::types::{RepositoryId, SearchQuery, SearchSort};
 
 use super::issue::{IssueQueryLimitSize, issue_query_body};
 use super::pull_request::{PullRequestQueryLimitSize, pull_request_query_body};
+use super::rate_limit::RATE_LIMIT_COST_FRAGMENT;
 #[derive(Debug, Clone, Serialize)]
 pub struct SearchVariable {
     pub query: String,
     pub per_page: u32,
     pub cursor: Option<String>,
+    /// Cursor to page backward from; only declared by `SearchPaging::Before` queries
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+}
+
+/// Which page of results a search query selects
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchPaging {
+    /// The first `$per_page` results
+    First,
+    /// The `$per_page` results after `$cursor`
+    After,
+    /// The `$per_page` results before `$before`
+    Before,
 }
 
 pub fn search_query(
     issue_limit_size: IssueQueryLimitSize,
     pull_request_limit_size: PullRequestQueryLimitSize,
-    with_cursor: bool,
+    paging: SearchPaging,
 ) -> String {
     let inner_query = format!(
         r#"
@@ -49,37 +64,96 @@ pub fn search_query(
             pageInfo {{
                 hasNextPage
                 endCursor
+                hasPreviousPage
+                startCursor
             }}
         "#,
         issue_query_body(issue_limit_size),
         pull_request_query_body(pull_request_limit_size)
     );
 
-    if with_cursor {
-        format!(
+    match paging {
+        SearchPaging::After => format!(
             r#"
         query($query: String!, $per_page: Int!, $cursor: String) {{
             search(query: $query, type: ISSUE, first: $per_page, after: $cursor) {{
                 {}
             }}
+            {}
         }}"#,
-            inner_query
-        )
-    } else {
-        format!(
+            inner_query, RATE_LIMIT_COST_FRAGMENT
+        ),
+        SearchPaging::Before => format!(
+            r#"
+        query($query: String!, $per_page: Int!, $before: String) {{
+            search(query: $query, type: ISSUE, last: $per_page, before: $before) {{
+                {}
+            }}
+            {}
+        }}"#,
+            inner_query, RATE_LIMIT_COST_FRAGMENT
+        ),
+        SearchPaging::First => format!(
             r#"
         query($query: String!, $per_page: Int!) {{
             search(query: $query, type: ISSUE, first: $per_page) {{
                 {}
             }}
+            {}
         }}"#,
-            inner_query
-        )
+            inner_query, RATE_LIMIT_COST_FRAGMENT
+        ),
     }
 }
 
+/// Query of a `type: DISCUSSION` search
+///
+/// Discussions are not returned by `type: ISSUE` searches, so they are searched
+/// separately from issues and pull requests.
+pub fn discussion_search_query() -> String {
+    r#"
+        query($query: String!, $per_page: Int!, $cursor: String) {
+            search(query: $query, type: DISCUSSION, first: $per_page, after: $cursor) {
+                nodes {
+                    __typename
+                    ... on Discussion {
+                        number
+                        title
+                        url
+                        createdAt
+                        updatedAt
+                        author {
+                            login
+                        }
+                        category {
+                            name
+                            isAnswerable
+                        }
+                        isAnswered
+                        comments {
+                            totalCount
+                        }
+                        repository {
+                            owner {
+                                login
+                            }
+                            name
+                        }
+                    }
+                }
+                pageInfo {
+                    hasNextPage
+                    endCursor
+                }
+            }
+        }"#
+    .to_string()
+}
+
 static REPO_PATTERN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\brepo:[^\s]+").unwrap());
 
+static SORT_PATTERN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\bsort:[^\s]+").unwrap());
+
 /// Normalizes a repository search query for GitHub GraphQL API.
 ///
 /// This function ensures that the search query targets the specified repository by:
@@ -173,3 +247,47 @@ pub fn normalize_repo_search_query(
     };
     SearchQuery(search_query)
 }
+
+static TYPE_QUALIFIER_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\bis:(discussion|issue|pr)\b").unwrap());
+
+/// Normalizes a discussion search query for a repository
+///
+/// Like `normalize_repo_search_query`, any `repo:` qualifiers are replaced with the
+/// target repository. The `is:discussion`, `is:issue`, and `is:pr` type qualifiers are
+/// removed since the search type already selects discussions.
+pub fn normalize_discussion_search_query(
+    query: SearchQuery,
+    repository_id: &RepositoryId,
+) -> SearchQuery {
+    let cleaned_query = REPO_PATTERN.replace_all(&query.0, "");
+    let cleaned_query = TYPE_QUALIFIER_PATTERN.replace_all(&cleaned_query, "");
+    let mut search_query = format!(
+        "repo:{}/{}",
+        repository_id.owner, repository_id.repository_name
+    );
+    for token in cleaned_query.split_whitespace() {
+        search_query.push(' ');
+        search_query.push_str(token);
+    }
+    SearchQuery(search_query)
+}
+
+/// Applies a sort order to a search query.
+///
+/// GitHub's GraphQL `search` field has no ordering argument, so the order is expressed
+/// with a `sort:` qualifier in the query text. Any `sort:` qualifiers already in the
+/// query are replaced. When `sort` is `None` the query is returned unchanged and
+/// GitHub's default best-match ordering applies.
+pub fn apply_search_sort(query: SearchQuery, sort: Option<SearchSort>) -> SearchQuery {
+    let Some(sort) = sort else {
+        return query;
+    };
+
+    let cleaned_query = SORT_PATTERN.replace_all(&query.0, "");
+    let cleaned_query = cleaned_query
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    SearchQuery(format!("{} {}", cleaned_query, sort.qualifier()))
+}
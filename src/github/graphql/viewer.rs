@@ -0,0 +1,11 @@
+/// Query for the login of the user the token belongs to
+pub fn viewer_query() -> String {
+    r#"
+        query {
+            viewer {
+                login
+            }
+        }
+    "#
+    .to_string()
+}
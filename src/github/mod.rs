@@ -1,6 +1,12 @@
 pub mod client;
+pub mod cost_budget;
+pub mod deprecation;
 pub mod error;
 pub mod graphql;
+pub mod repository_cache;
+pub mod retry;
 
 pub use client::GitHubClient;
+pub use cost_budget::GraphQLCostBudget;
 pub use graphql::graphql_types;
+pub use retry::RetryConfig;
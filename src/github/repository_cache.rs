@@ -0,0 +1,121 @@
+//! In-memory cache of repository metadata
+//!
+//! Fetching issues and pull requests across the same repository looks up the
+//! repository's labels, milestones, and mentionable users repeatedly. The cache keeps
+//! `GithubRepository` results per `RepositoryId` for a fixed time-to-live and is shared
+//! by all clones of a `GitHubClient`.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::types::{GithubRepository, RepositoryId};
+
+/// Repository metadata cache with a fixed time-to-live, shared across clones
+#[derive(Debug, Clone)]
+pub struct RepositoryCache {
+    ttl: Duration,
+    entries: Arc<Mutex<HashMap<RepositoryId, (Instant, GithubRepository)>>>,
+}
+
+impl RepositoryCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Returns the time-to-live of cached entries
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Returns the cached repository if it was stored within the TTL, evicting it otherwise
+    pub fn get(&self, repository_id: &RepositoryId) -> Option<GithubRepository> {
+        let mut entries = self.entries.lock().ok()?;
+        match entries.get(repository_id) {
+            Some((stored_at, repository)) if stored_at.elapsed() < self.ttl => {
+                Some(repository.clone())
+            }
+            Some(_) => {
+                entries.remove(repository_id);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Stores a repository, replacing any previous entry
+    pub fn insert(&self, repository_id: RepositoryId, repository: GithubRepository) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(repository_id, (Instant::now(), repository));
+        }
+    }
+
+    /// Removes all cached repositories
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn repository(repository_id: &RepositoryId) -> GithubRepository {
+        GithubRepository::new(
+            repository_id.clone(),
+            Some("cached".to_string()),
+            None,
+            Utc::now(),
+            Utc::now(),
+            Vec::new(),
+            None,
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            false,
+        )
+    }
+
+    #[test]
+    fn test_get_within_ttl() {
+        let cache = RepositoryCache::new(Duration::from_secs(60));
+        let repository_id = RepositoryId::new("owner", "repo");
+        assert!(cache.get(&repository_id).is_none());
+
+        cache.insert(repository_id.clone(), repository(&repository_id));
+        let cached = cache.get(&repository_id).expect("cached repository");
+        assert_eq!(cached.git_repository_id, repository_id);
+        assert!(cache.get(&RepositoryId::new("owner", "other")).is_none());
+    }
+
+    #[test]
+    fn test_expired_entries_are_evicted() {
+        let cache = RepositoryCache::new(Duration::ZERO);
+        let repository_id = RepositoryId::new("owner", "repo");
+
+        cache.insert(repository_id.clone(), repository(&repository_id));
+        assert!(cache.get(&repository_id).is_none());
+        assert!(cache.entries.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_clones_share_entries() {
+        let cache = RepositoryCache::new(Duration::from_secs(60));
+        let clone = cache.clone();
+        let repository_id = RepositoryId::new("owner", "repo");
+
+        clone.insert(repository_id.clone(), repository(&repository_id));
+        assert!(cache.get(&repository_id).is_some());
+
+        cache.clear();
+        assert!(clone.get(&repository_id).is_none());
+    }
+}
@@ -0,0 +1,110 @@
+//! Retry and timeout settings for GitHub API requests
+
+use std::time::Duration;
+
+/// Default number of retries of a failed GraphQL request
+pub const DEFAULT_GRAPHQL_MAX_RETRIES: u32 = 3;
+
+/// Default delay before the first retry of a retryable error
+pub const DEFAULT_RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Default timeout of a single GraphQL request
+pub const DEFAULT_GRAPHQL_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Retry policy and request timeout for GraphQL requests of a `GitHubClient`
+///
+/// Retryable errors are retried after `base_delay`, doubling on every further attempt.
+/// Rate limit errors back off twice as long.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// Maximum number of retries after the first attempt
+    pub max_retries: u32,
+    /// Delay before the first retry of a retryable error
+    pub base_delay: Duration,
+    /// Timeout of a single request attempt
+    pub request_timeout: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: DEFAULT_GRAPHQL_MAX_RETRIES,
+            base_delay: DEFAULT_RETRY_BASE_DELAY,
+            request_timeout: DEFAULT_GRAPHQL_REQUEST_TIMEOUT,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Delay before retry `attempt` (1-based) of a retryable error
+    pub fn backoff_delay(&self, attempt: u32) -> Duration {
+        exponential_delay(self.base_delay, attempt)
+    }
+
+    /// Delay before retry `attempt` (1-based) after hitting a rate limit
+    pub fn rate_limit_backoff_delay(&self, attempt: u32) -> Duration {
+        exponential_delay(self.base_delay.saturating_mul(2), attempt)
+    }
+}
+
+/// `base` doubled for every attempt after the first, saturating on overflow
+fn exponential_delay(base: Duration, attempt: u32) -> Duration {
+    let factor = 2_u32.saturating_pow(attempt.saturating_sub(1));
+    base.saturating_mul(factor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_backoff_delays() {
+        let config = RetryConfig::default();
+
+        assert_eq!(config.backoff_delay(1), Duration::from_millis(500));
+        assert_eq!(config.backoff_delay(3), Duration::from_millis(2000));
+        assert_eq!(
+            config.rate_limit_backoff_delay(1),
+            Duration::from_millis(1000)
+        );
+        assert_eq!(
+            config.rate_limit_backoff_delay(3),
+            Duration::from_millis(4000)
+        );
+    }
+
+    #[test]
+    fn test_backoff_delays_with_custom_base_delay() {
+        let config = RetryConfig {
+            base_delay: Duration::from_millis(50),
+            ..RetryConfig::default()
+        };
+
+        let delays: Vec<Duration> = (1..=4)
+            .map(|attempt| config.backoff_delay(attempt))
+            .collect();
+        assert_eq!(
+            delays,
+            vec![
+                Duration::from_millis(50),
+                Duration::from_millis(100),
+                Duration::from_millis(200),
+                Duration::from_millis(400),
+            ]
+        );
+        assert_eq!(
+            config.rate_limit_backoff_delay(2),
+            Duration::from_millis(200)
+        );
+    }
+
+    #[test]
+    fn test_backoff_delay_saturates() {
+        let config = RetryConfig {
+            base_delay: Duration::from_secs(u64::MAX / 2),
+            ..RetryConfig::default()
+        };
+
+        assert_eq!(config.backoff_delay(64), Duration::MAX);
+    }
+}
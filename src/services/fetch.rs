@@ -1,22 +1,79 @@
 use anyhow::Result;
 use futures::stream::{self, StreamExt};
 use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
 
 use crate::github::GitHubClient;
 use crate::types::{
-    GithubRepository, Issue, IssueNumber, Project, ProjectId, ProjectResource, PullRequest,
-    PullRequestNumber, RepositoryId,
+    GithubRepository, Issue, IssueNumber, Project, ProjectFetchLimits, ProjectId, ProjectResource,
+    ProjectResourcesFetch, PullRequest, PullRequestNumber, RepositoryId, SearchCursor,
 };
 
+/// Diffs fetched for one repository, keyed by pull request number
+type RepositoryDiffs = (RepositoryId, Vec<(PullRequestNumber, String)>);
+
+/// Changed files fetched for one repository, keyed by pull request number
+type RepositoryFiles = (
+    RepositoryId,
+    Vec<(PullRequestNumber, Vec<crate::types::PullRequestFile>)>,
+);
+
+/// Default number of repositories fetched concurrently
+pub const DEFAULT_MAX_CONCURRENCY: usize = 10;
+
 /// Coordinates batch fetching of multiple resources
 pub struct MultiResourceFetcher {
     github_client: GitHubClient,
+    max_concurrency: usize,
+    /// Number of comments fetched inline with each issue; GitHub's maximum when `None`
+    issue_comment_limit: Option<u32>,
+    /// Bounds in-flight repository fetches across all batch calls of this fetcher
+    ///
+    /// The semaphore is never closed, so acquiring a permit only waits for a free slot.
+    permits: Arc<Semaphore>,
 }
 
 impl MultiResourceFetcher {
     /// Creates a new MultiResourceFetcher instance
+    ///
+    /// The concurrency is taken from `GitHubClient::max_concurrency`, falling back to
+    /// `DEFAULT_MAX_CONCURRENCY`.
     pub fn new(github_client: GitHubClient) -> Self {
-        Self { github_client }
+        let max_concurrency = github_client
+            .max_concurrency()
+            .unwrap_or(DEFAULT_MAX_CONCURRENCY);
+        Self {
+            github_client,
+            max_concurrency,
+            issue_comment_limit: None,
+            permits: Arc::new(Semaphore::new(max_concurrency)),
+        }
+    }
+
+    /// Limits the number of repositories fetched at the same time
+    ///
+    /// Lower values help to stay clear of GitHub secondary rate limits on large
+    /// profiles. Values below 1 are treated as 1.
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        let max_concurrency = max_concurrency.max(1);
+        self.max_concurrency = max_concurrency;
+        self.permits = Arc::new(Semaphore::new(max_concurrency));
+        self
+    }
+
+    /// Returns the maximum number of repositories fetched at the same time
+    pub fn max_concurrency(&self) -> usize {
+        self.max_concurrency
+    }
+
+    /// Limits the number of comments fetched inline with each issue
+    ///
+    /// Smaller values make issue fetches cheaper at the cost of completeness.
+    /// Values above GitHub's maximum (100) are clamped.
+    pub fn with_issue_comment_limit(mut self, comment_limit: Option<u32>) -> Self {
+        self.issue_comment_limit = comment_limit;
+        self
     }
 
     /// Fetches multiple issues by repository
@@ -38,10 +95,17 @@ impl MultiResourceFetcher {
                 .into_iter()
                 .map(|(repo_id, issue_numbers)| {
                     let github_client = self.github_client.clone();
+                    let permits = self.permits.clone();
+                    let comment_limit = self.issue_comment_limit;
 
                     async move {
+                        let _permit = permits.acquire().await;
                         match github_client
-                            .fetch_multiple_issues_by_numbers(repo_id.clone(), &issue_numbers)
+                            .fetch_multiple_issues_by_numbers(
+                                repo_id.clone(),
+                                &issue_numbers,
+                                comment_limit,
+                            )
                             .await
                         {
                             Ok(issues) => Ok((repo_id, issues)),
@@ -54,7 +118,7 @@ impl MultiResourceFetcher {
                 });
 
         let results: Vec<Result<(RepositoryId, Vec<Issue>)>> = stream::iter(fetch_futures)
-            .buffer_unordered(10) // Process up to 10 repositories concurrently
+            .buffer_unordered(self.max_concurrency)
             .collect()
             .await;
 
@@ -82,8 +146,10 @@ impl MultiResourceFetcher {
         // Fetch PRs from all repositories concurrently
         let fetch_futures = pr_numbers_of_repositories.into_iter().map(|(repo_id, pr_numbers)| {
             let github_client = self.github_client.clone();
+            let permits = self.permits.clone();
 
             async move {
+                let _permit = permits.acquire().await;
                 match github_client
                     .fetch_multiple_pull_requests_by_numbers(
                         repo_id.clone(),
@@ -104,7 +170,7 @@ impl MultiResourceFetcher {
         });
 
         let results: Vec<Result<(RepositoryId, Vec<PullRequest>)>> = stream::iter(fetch_futures)
-            .buffer_unordered(10) // Process up to 10 repositories concurrently
+            .buffer_unordered(self.max_concurrency)
             .collect()
             .await;
 
@@ -121,6 +187,7 @@ impl MultiResourceFetcher {
     /// # Arguments
     ///
     /// * `project_id` - The project identifier containing owner, number, and project type
+    /// * `field_filters` - `(field name, value)` pairs every returned item must match
     ///
     /// # Returns
     ///
@@ -128,9 +195,26 @@ impl MultiResourceFetcher {
     pub async fn fetch_project_resources(
         &self,
         project_id: ProjectId,
+        field_filters: &[(String, String)],
     ) -> Result<Vec<ProjectResource>> {
         self.github_client
-            .fetch_all_project_resources(project_id)
+            .fetch_all_project_resources(project_id, field_filters)
+            .await
+    }
+
+    /// Fetches the resources of a GitHub project until the last page or a cap of `limits`
+    ///
+    /// When a cap stops the fetch early, the result carries the cursor of the next page,
+    /// which resumes the fetch when passed back as `start_cursor`.
+    pub async fn fetch_project_resources_with_limits(
+        &self,
+        project_id: ProjectId,
+        field_filters: &[(String, String)],
+        limits: ProjectFetchLimits,
+        start_cursor: Option<SearchCursor>,
+    ) -> Result<ProjectResourcesFetch> {
+        self.github_client
+            .fetch_project_resources_with_limits(project_id, field_filters, limits, start_cursor)
             .await
     }
 
@@ -178,8 +262,10 @@ impl MultiResourceFetcher {
             .into_iter()
             .map(|(repo_id, pr_numbers)| {
                 let github_client = self.github_client.clone();
+                let permits = self.permits.clone();
 
                 async move {
+                    let _permit = permits.acquire().await;
                     let mut repo_diffs = Vec::new();
 
                     // Fetch each PR diff sequentially to avoid overwhelming the API
@@ -207,11 +293,10 @@ impl MultiResourceFetcher {
                 }
             });
 
-        let results: Vec<Result<(RepositoryId, Vec<(PullRequestNumber, String)>)>> =
-            stream::iter(fetch_futures)
-                .buffer_unordered(10) // Process up to 10 repositories concurrently
-                .collect()
-                .await;
+        let results: Vec<Result<RepositoryDiffs>> = stream::iter(fetch_futures)
+            .buffer_unordered(self.max_concurrency)
+            .collect()
+            .await;
 
         let diffs_by_repo: BTreeMap<RepositoryId, Vec<(PullRequestNumber, String)>> = results
             .into_iter()
@@ -231,8 +316,10 @@ impl MultiResourceFetcher {
             .into_iter()
             .map(|(repo_id, pr_numbers)| {
                 let github_client = self.github_client.clone();
+                let permits = self.permits.clone();
 
                 async move {
+                    let _permit = permits.acquire().await;
                     let mut repo_files = Vec::new();
 
                     // Fetch each PR file stats sequentially to avoid overwhelming the API
@@ -260,13 +347,8 @@ impl MultiResourceFetcher {
                 }
             });
 
-        let results: Vec<
-            Result<(
-                RepositoryId,
-                Vec<(PullRequestNumber, Vec<crate::types::PullRequestFile>)>,
-            )>,
-        > = stream::iter(fetch_futures)
-            .buffer_unordered(10) // Process up to 10 repositories concurrently
+        let results: Vec<Result<RepositoryFiles>> = stream::iter(fetch_futures)
+            .buffer_unordered(self.max_concurrency)
             .collect()
             .await;
 
@@ -1,7 +1,9 @@
 mod fetch;
 mod profile;
 mod search;
+mod sync;
 
 pub use fetch::*;
 pub use profile::*;
 pub use search::*;
+pub use sync::*;
@@ -4,14 +4,18 @@
 //! It manages repositories and projects within profiles, providing the core business
 //! logic for profile-based organization of GitHub resources.
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::path::PathBuf;
 
 use crate::types::{
-    GroupName, ProfileInfo, ProfileName, ProjectId, RepositoryBranchGroup, RepositoryBranchPair,
+    ClearScope, GroupName, ProfileBranchGroups, ProfileClearSummary, ProfileInfo,
+    ProfileMergeSummary, ProfileName, ProjectId, RepositoryBranchGroup, RepositoryBranchPair,
     RepositoryId,
 };
 
+/// Suffix appended to a merged group name that collides with a target group
+const MERGED_GROUP_SUFFIX: &str = "-merged";
+
 /// Profile management service for handling repository and project organization
 #[derive(Debug, Clone)]
 pub struct ProfileService {
@@ -249,7 +253,7 @@ impl ProfileService {
         Ok(())
     }
 
-    /// List all repositories in a profile
+    /// List all repositories in a profile, sorted by owner and name
     pub fn list_repositories(
         &self,
         profile_name: &ProfileName,
@@ -259,10 +263,35 @@ impl ProfileService {
             .get(profile_name)
             .ok_or_else(|| ProfileServiceError::ProfileNotFound(profile_name.to_string()))?;
 
-        Ok(profile.repositories().clone())
+        let mut repositories = profile.repositories().clone();
+        repositories
+            .sort_by(|a, b| (&a.owner, &a.repository_name).cmp(&(&b.owner, &b.repository_name)));
+        Ok(repositories)
+    }
+
+    /// List the repositories registered in any of the given profiles, without duplicates
+    ///
+    /// An empty `profile_names` selects every profile. Repositories are sorted by owner
+    /// and name.
+    pub fn list_repositories_across_profiles(
+        &self,
+        profile_names: &[ProfileName],
+    ) -> Result<Vec<RepositoryId>, ProfileServiceError> {
+        let profile_names = if profile_names.is_empty() {
+            self.list_profiles()
+        } else {
+            profile_names.to_vec()
+        };
+
+        let mut repositories = BTreeSet::new();
+        for profile_name in &profile_names {
+            repositories.extend(self.list_repositories(profile_name)?);
+        }
+
+        Ok(repositories.into_iter().collect())
     }
 
-    /// List all projects in a profile
+    /// List all projects in a profile, sorted by owner and number
     pub fn list_projects(
         &self,
         profile_name: &ProfileName,
@@ -272,7 +301,9 @@ impl ProfileService {
             .get(profile_name)
             .ok_or_else(|| ProfileServiceError::ProfileNotFound(profile_name.to_string()))?;
 
-        Ok(profile.projects().clone())
+        let mut projects = profile.projects().clone();
+        projects.sort_by(|a, b| (&a.owner, a.number.0).cmp(&(&b.owner, b.number.0)));
+        Ok(projects)
     }
 
     /// Register a repository branch group to a profile
@@ -375,6 +406,43 @@ impl ProfileService {
         Ok(())
     }
 
+    /// Add repository branch pairs to an existing group, skipping pairs already in it
+    ///
+    /// Returns the pairs that were newly added, in the given order. The profile is only
+    /// persisted when at least one pair is added.
+    pub fn add_pairs_to_group(
+        &mut self,
+        profile_name: &ProfileName,
+        group_name: &GroupName,
+        pairs: Vec<RepositoryBranchPair>,
+    ) -> Result<Vec<RepositoryBranchPair>, ProfileServiceError> {
+        let added_pairs = {
+            let profile = self
+                .profiles
+                .get_mut(profile_name)
+                .ok_or_else(|| ProfileServiceError::ProfileNotFound(profile_name.to_string()))?;
+
+            let group = profile
+                .get_repository_branch_group_mut(group_name)
+                .ok_or_else(|| ProfileServiceError::GroupNotFound(group_name.to_string()))?;
+
+            let mut added_pairs = Vec::new();
+            for pair in pairs {
+                if !group.pairs.contains(&pair) {
+                    group.add_pair(pair.clone());
+                    added_pairs.push(pair);
+                }
+            }
+            added_pairs
+        };
+
+        if !added_pairs.is_empty() {
+            self.update_profile_timestamp(profile_name)?;
+        }
+
+        Ok(added_pairs)
+    }
+
     /// Remove a repository branch pair from a group
     pub fn remove_pair_from_group(
         &mut self,
@@ -421,7 +489,7 @@ impl ProfileService {
 
             profile
                 .rename_repository_branch_group(old_name, new_name)
-                .map_err(|e| ProfileServiceError::InvalidGroupName(e))?;
+                .map_err(ProfileServiceError::InvalidGroupName)?;
         }
 
         // Update profile info and persist
@@ -430,6 +498,47 @@ impl ProfileService {
         Ok(())
     }
 
+    /// Copy a repository branch group under a new name
+    ///
+    /// The new group gets the pairs and description of the source group and fresh
+    /// timestamps. Returns the new group.
+    pub fn clone_repository_branch_group(
+        &mut self,
+        profile_name: &ProfileName,
+        source_name: &GroupName,
+        destination_name: GroupName,
+    ) -> Result<RepositoryBranchGroup, ProfileServiceError> {
+        let group = {
+            let profile = self
+                .profiles
+                .get_mut(profile_name)
+                .ok_or_else(|| ProfileServiceError::ProfileNotFound(profile_name.to_string()))?;
+
+            let source = profile
+                .get_repository_branch_group(source_name)
+                .ok_or_else(|| ProfileServiceError::GroupNotFound(source_name.to_string()))?;
+
+            if profile.has_repository_branch_group(&destination_name) {
+                return Err(ProfileServiceError::GroupAlreadyExists(
+                    destination_name.to_string(),
+                ));
+            }
+
+            let group = RepositoryBranchGroup::new_with_description(
+                Some(destination_name),
+                source.pairs.clone(),
+                source.description.clone(),
+            );
+            profile.add_repository_branch_group(group.clone());
+            group
+        };
+
+        // Update profile info and persist
+        self.update_profile_timestamp(profile_name)?;
+
+        Ok(group)
+    }
+
     /// List all repository branch groups in a profile
     pub fn list_repository_branch_groups(
         &self,
@@ -464,11 +573,36 @@ impl ProfileService {
             .ok_or_else(|| ProfileServiceError::GroupNotFound(group_name.to_string()))
     }
 
+    /// List repository branch groups of every profile, sorted by profile name then group name
+    ///
+    /// Profiles without groups are included with an empty group list.
+    pub fn list_repository_branch_groups_across_profiles(&self) -> Vec<ProfileBranchGroups> {
+        let mut profile_groups: Vec<ProfileBranchGroups> = self
+            .profiles
+            .iter()
+            .map(|(profile_name, profile)| {
+                let mut groups: Vec<RepositoryBranchGroup> =
+                    profile.repository_branch_groups.values().cloned().collect();
+                groups.sort_by(|a, b| a.name.0.cmp(&b.name.0));
+                ProfileBranchGroups {
+                    profile_name: profile_name.clone(),
+                    groups,
+                }
+            })
+            .collect();
+        profile_groups.sort_by(|a, b| a.profile_name.0.cmp(&b.profile_name.0));
+        profile_groups
+    }
+
     /// Remove repository branch groups older than N days
+    ///
+    /// With `dry_run`, returns the groups that would be removed without modifying
+    /// or persisting the profile.
     pub fn remove_groups_older_than(
         &mut self,
         profile_name: &ProfileName,
         days: i64,
+        dry_run: bool,
     ) -> Result<Vec<GroupName>, ProfileServiceError> {
         let removed_groups = {
             let profile = self
@@ -476,6 +610,9 @@ impl ProfileService {
                 .get_mut(profile_name)
                 .ok_or_else(|| ProfileServiceError::ProfileNotFound(profile_name.to_string()))?;
 
+            if dry_run {
+                return Ok(profile.groups_older_than(days));
+            }
             profile.remove_groups_older_than(days)
         };
 
@@ -529,6 +666,241 @@ impl ProfileService {
         Ok(())
     }
 
+    /// Remove all repositories, projects, and/or branch groups of a profile
+    ///
+    /// The profile itself is kept and persisted once after all entries in `scope` are
+    /// removed. Returns how many entries of each kind were removed.
+    pub fn clear_profile(
+        &mut self,
+        profile_name: &ProfileName,
+        scope: ClearScope,
+    ) -> Result<ProfileClearSummary, ProfileServiceError> {
+        let mut summary = ProfileClearSummary::default();
+        {
+            let profile = self
+                .profiles
+                .get_mut(profile_name)
+                .ok_or_else(|| ProfileServiceError::ProfileNotFound(profile_name.to_string()))?;
+
+            if scope.includes_repositories() {
+                summary.repositories_removed = profile.repositories.len();
+                profile.repositories.clear();
+            }
+            if scope.includes_projects() {
+                summary.projects_removed = profile.projects.len();
+                profile.projects.clear();
+            }
+            if scope.includes_groups() {
+                summary.groups_removed = profile.repository_branch_groups.len();
+                profile.repository_branch_groups.clear();
+            }
+        }
+
+        // Update profile info and persist
+        self.update_profile_timestamp(profile_name)?;
+
+        Ok(summary)
+    }
+
+    /// Replace the description of an existing profile
+    ///
+    /// `None` or a blank description clears it. Registrations are kept.
+    pub fn update_profile_description(
+        &mut self,
+        profile_name: &ProfileName,
+        description: Option<String>,
+    ) -> Result<(), ProfileServiceError> {
+        {
+            let profile = self
+                .profiles
+                .get_mut(profile_name)
+                .ok_or_else(|| ProfileServiceError::ProfileNotFound(profile_name.to_string()))?;
+            profile.description = description
+                .map(|description| description.trim().to_string())
+                .filter(|description| !description.is_empty());
+        }
+
+        // Update profile info and persist
+        self.update_profile_timestamp(profile_name)?;
+
+        Ok(())
+    }
+
+    /// Set the default search query and limit of a profile
+    ///
+    /// `None` leaves the current value unchanged; an empty query or a limit of 0 clears
+    /// the default so searches fall back to the built-in defaults again.
+    pub fn set_profile_defaults(
+        &mut self,
+        profile_name: &ProfileName,
+        search_query: Option<String>,
+        search_limit: Option<usize>,
+    ) -> Result<(), ProfileServiceError> {
+        {
+            let profile = self
+                .profiles
+                .get_mut(profile_name)
+                .ok_or_else(|| ProfileServiceError::ProfileNotFound(profile_name.to_string()))?;
+            if let Some(query) = search_query {
+                let query = query.trim();
+                profile.default_search_query = (!query.is_empty()).then(|| query.to_string());
+            }
+            if let Some(limit) = search_limit {
+                profile.default_search_limit = (limit > 0).then_some(limit);
+            }
+        }
+
+        // Update profile info and persist
+        self.update_profile_timestamp(profile_name)?;
+
+        Ok(())
+    }
+
+    /// Export a profile as a JSON snapshot
+    ///
+    /// The snapshot includes the description, repositories, projects, and branch groups
+    /// with their timestamps, and can be restored with `import_profile`.
+    pub fn export_profile(
+        &self,
+        profile_name: &ProfileName,
+    ) -> Result<String, ProfileServiceError> {
+        let profile = self
+            .profiles
+            .get(profile_name)
+            .ok_or_else(|| ProfileServiceError::ProfileNotFound(profile_name.to_string()))?;
+
+        serde_json::to_string_pretty(profile)
+            .map_err(|e| ProfileServiceError::SerializationError(e.to_string()))
+    }
+
+    /// Import a profile from a JSON snapshot created by `export_profile`
+    ///
+    /// The profile keeps its exported name unless `new_name` is given. Importing over an
+    /// existing profile is rejected; use `merge_profiles` to combine profiles instead.
+    pub fn import_profile(
+        &mut self,
+        json: &str,
+        new_name: Option<ProfileName>,
+    ) -> Result<ProfileName, ProfileServiceError> {
+        let mut profile: ProfileInfo = serde_json::from_str(json)
+            .map_err(|e| ProfileServiceError::SerializationError(e.to_string()))?;
+
+        if let Some(new_name) = new_name {
+            profile.name = new_name;
+        }
+        let name = profile.name.clone();
+
+        // Validate profile name
+        self.validate_profile_name(&name)?;
+
+        // Check if profile already exists
+        if self.profiles.contains_key(&name) {
+            return Err(ProfileServiceError::ProfileAlreadyExists(name.to_string()));
+        }
+
+        // Persist to disk
+        self.save_profile(&name, &profile)?;
+        self.profiles.insert(name.clone(), profile);
+
+        Ok(name)
+    }
+
+    /// Merge repositories, projects, and branch groups of `source` into `target`
+    ///
+    /// Repositories and projects already registered in the target are skipped. A branch
+    /// group whose name is taken in the target is renamed with a `-merged` suffix (and a
+    /// counter if that is taken too). The source is deleted afterwards when
+    /// `delete_source` is set; the default profile cannot be used as a deleted source.
+    pub fn merge_profiles(
+        &mut self,
+        source: &ProfileName,
+        target: &ProfileName,
+        delete_source: bool,
+    ) -> Result<ProfileMergeSummary, ProfileServiceError> {
+        if source == target {
+            return Err(ProfileServiceError::InvalidProfileName(
+                "Cannot merge a profile into itself".to_string(),
+            ));
+        }
+        if delete_source && source == &ProfileName::default() {
+            return Err(ProfileServiceError::InvalidProfileName(
+                "Cannot delete default profile".to_string(),
+            ));
+        }
+
+        let source_profile = self
+            .profiles
+            .get(source)
+            .cloned()
+            .ok_or_else(|| ProfileServiceError::ProfileNotFound(source.to_string()))?;
+
+        let mut summary = ProfileMergeSummary::default();
+        {
+            let target_profile = self
+                .profiles
+                .get_mut(target)
+                .ok_or_else(|| ProfileServiceError::ProfileNotFound(target.to_string()))?;
+
+            for repository_id in source_profile.repositories {
+                if target_profile.has_repository(&repository_id) {
+                    summary.repositories_skipped += 1;
+                } else {
+                    target_profile.add_repository(repository_id);
+                    summary.repositories_merged += 1;
+                }
+            }
+
+            for project_id in source_profile.projects {
+                if target_profile.has_project(&project_id) {
+                    summary.projects_skipped += 1;
+                } else {
+                    target_profile.add_project(project_id);
+                    summary.projects_merged += 1;
+                }
+            }
+
+            // Merge groups in name order so renamed suffixes are deterministic
+            let mut groups: Vec<RepositoryBranchGroup> = source_profile
+                .repository_branch_groups
+                .into_values()
+                .collect();
+            groups.sort_by(|a, b| a.name.0.cmp(&b.name.0));
+
+            for mut group in groups {
+                if target_profile.has_repository_branch_group(&group.name) {
+                    let original_name = group.name.clone();
+                    let new_name = Self::merged_group_name(target_profile, &original_name);
+                    group.rename(new_name.clone());
+                    summary.renamed_groups.push((original_name, new_name));
+                }
+                target_profile.add_repository_branch_group(group);
+                summary.groups_merged += 1;
+            }
+        }
+
+        // Update profile info and persist
+        self.update_profile_timestamp(target)?;
+
+        if delete_source {
+            self.delete_profile(source)?;
+            summary.source_deleted = true;
+        }
+
+        Ok(summary)
+    }
+
+    /// Find a free group name in `profile` by appending the merge suffix to `name`
+    fn merged_group_name(profile: &ProfileInfo, name: &GroupName) -> GroupName {
+        let base = format!("{}{}", name, MERGED_GROUP_SUFFIX);
+        let mut candidate = GroupName::from(base.as_str());
+        let mut counter = 2;
+        while profile.has_repository_branch_group(&candidate) {
+            candidate = GroupName::from(format!("{}-{}", base, counter));
+            counter += 1;
+        }
+        candidate
+    }
+
     /// Get or create a profile (used internally)
     fn get_or_create_profile(
         &mut self,
@@ -682,6 +1054,7 @@ mod tests {
         RepositoryId,
         repository::{Owner, RepositoryName},
     };
+    use chrono::{DateTime, Utc};
     use tempfile::TempDir;
 
     #[test]
@@ -691,6 +1064,149 @@ mod tests {
         assert!(service.is_ok());
     }
 
+    #[test]
+    fn test_list_repository_branch_groups_across_profiles() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut service = ProfileService::new(temp_dir.path().to_path_buf()).unwrap();
+        service
+            .create_profile(&ProfileName::from("work"), None)
+            .unwrap();
+        service
+            .create_profile(&ProfileName::from("archive"), None)
+            .unwrap();
+
+        let pair = RepositoryBranchPair::new(
+            RepositoryId::new("owner", "repo"),
+            crate::types::Branch::new("main"),
+        );
+        for group_name in ["zeta", "alpha"] {
+            service
+                .register_repository_branch_group(
+                    &ProfileName::from("work"),
+                    Some(GroupName::from(group_name)),
+                    vec![pair.clone()],
+                )
+                .unwrap();
+        }
+
+        let overview = service.list_repository_branch_groups_across_profiles();
+        let profile_names: Vec<&str> = overview
+            .iter()
+            .map(|entry| entry.profile_name.0.as_str())
+            .collect();
+        assert_eq!(profile_names, vec!["archive", "default", "work"]);
+        assert!(overview[0].groups.is_empty());
+
+        let work_groups: Vec<&str> = overview[2]
+            .groups
+            .iter()
+            .map(|group| group.name.0.as_str())
+            .collect();
+        assert_eq!(work_groups, vec!["alpha", "zeta"]);
+    }
+
+    #[test]
+    fn test_update_profile_description_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let profile_name = ProfileName::from("work");
+        {
+            let mut service = ProfileService::new(temp_dir.path().to_path_buf()).unwrap();
+            service
+                .create_profile(&profile_name, Some("Work repositories".to_string()))
+                .unwrap();
+            service
+                .register_repository(&profile_name, RepositoryId::new("owner", "repo"))
+                .unwrap();
+            service
+                .update_profile_description(
+                    &profile_name,
+                    Some("Repositories of the platform team".to_string()),
+                )
+                .unwrap();
+        }
+
+        let mut service = ProfileService::new(temp_dir.path().to_path_buf()).unwrap();
+        let profile = service.get_profile_info(&profile_name).unwrap();
+        assert_eq!(
+            profile.description.as_deref(),
+            Some("Repositories of the platform team")
+        );
+        assert_eq!(profile.repositories.len(), 1);
+
+        service
+            .update_profile_description(&profile_name, None)
+            .unwrap();
+        let profile = ProfileService::new(temp_dir.path().to_path_buf())
+            .unwrap()
+            .get_profile_info(&profile_name)
+            .unwrap();
+        assert_eq!(profile.description, None);
+
+        assert!(matches!(
+            service.update_profile_description(&ProfileName::from("missing"), None),
+            Err(ProfileServiceError::ProfileNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_profile_defaults_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let profile_name = ProfileName::from("work");
+        {
+            let mut service = ProfileService::new(temp_dir.path().to_path_buf()).unwrap();
+            service.create_profile(&profile_name, None).unwrap();
+            service
+                .set_profile_defaults(
+                    &profile_name,
+                    Some("is:pr state:open".to_string()),
+                    Some(50),
+                )
+                .unwrap();
+        }
+
+        let mut service = ProfileService::new(temp_dir.path().to_path_buf()).unwrap();
+        let profile = service.get_profile_info(&profile_name).unwrap();
+        assert_eq!(
+            profile.default_search_query.as_deref(),
+            Some("is:pr state:open")
+        );
+        assert_eq!(profile.default_search_limit, Some(50));
+
+        // Omitted values are kept, empty query and zero limit clear the defaults
+        service
+            .set_profile_defaults(&profile_name, None, Some(0))
+            .unwrap();
+        let profile = ProfileService::new(temp_dir.path().to_path_buf())
+            .unwrap()
+            .get_profile_info(&profile_name)
+            .unwrap();
+        assert_eq!(
+            profile.default_search_query.as_deref(),
+            Some("is:pr state:open")
+        );
+        assert_eq!(profile.default_search_limit, None);
+
+        service
+            .set_profile_defaults(&profile_name, Some(String::new()), None)
+            .unwrap();
+        let profile = ProfileService::new(temp_dir.path().to_path_buf())
+            .unwrap()
+            .get_profile_info(&profile_name)
+            .unwrap();
+        assert_eq!(profile.default_search_query, None);
+
+        // Defaults are never set on a profile that does not exist
+        assert!(matches!(
+            service.set_profile_defaults(&ProfileName::from("missing"), None, Some(10)),
+            Err(ProfileServiceError::ProfileNotFound(_))
+        ));
+        assert!(
+            service
+                .get_profile_info(&ProfileName::from("missing"))
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_create_and_list_profiles() {
         let temp_dir = TempDir::new().unwrap();
@@ -881,6 +1397,87 @@ mod tests {
         assert_eq!(group.name, new_name);
     }
 
+    #[test]
+    fn test_repository_branch_group_clone() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut service = ProfileService::new(temp_dir.path().to_path_buf()).unwrap();
+        let profile_name = ProfileName::from("default");
+
+        let pairs = vec![
+            RepositoryBranchPair::new(
+                RepositoryId::new("owner", "api"),
+                crate::types::Branch::new("release-1"),
+            ),
+            RepositoryBranchPair::new(
+                RepositoryId::new("owner", "web"),
+                crate::types::Branch::new("main"),
+            ),
+        ];
+        let source_name = service
+            .register_repository_branch_group_with_description(
+                &profile_name,
+                Some(GroupName::from("release")),
+                pairs.clone(),
+                Some("Release branches".to_string()),
+            )
+            .unwrap();
+
+        // Backdate the source group so the clone's timestamp is clearly its own
+        let backdated = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        {
+            let source = service
+                .profiles
+                .get_mut(&profile_name)
+                .unwrap()
+                .get_repository_branch_group_mut(&source_name)
+                .unwrap();
+            source.created_at = backdated;
+            source.updated_at = backdated;
+        }
+
+        let clone = service
+            .clone_repository_branch_group(
+                &profile_name,
+                &source_name,
+                GroupName::from("release-snapshot"),
+            )
+            .unwrap();
+        assert_eq!(clone.name, GroupName::from("release-snapshot"));
+        assert_eq!(clone.pairs, pairs);
+        assert_eq!(clone.description(), Some("Release branches"));
+        assert!(clone.created_at > backdated);
+
+        // The clone is independent of its source
+        service
+            .remove_pair_from_group(&profile_name, &clone.name, &pairs[0])
+            .unwrap();
+        let source = service
+            .get_repository_branch_group(&profile_name, &source_name)
+            .unwrap();
+        assert_eq!(source.pairs, pairs);
+        assert_eq!(source.created_at, backdated);
+
+        // Cloning onto an existing name fails
+        let result = service.clone_repository_branch_group(
+            &profile_name,
+            &source_name,
+            GroupName::from("release-snapshot"),
+        );
+        assert!(matches!(
+            result,
+            Err(ProfileServiceError::GroupAlreadyExists(name)) if name == "release-snapshot"
+        ));
+
+        let result = service.clone_repository_branch_group(
+            &profile_name,
+            &GroupName::from("missing"),
+            GroupName::from("other"),
+        );
+        assert!(matches!(result, Err(ProfileServiceError::GroupNotFound(_))));
+    }
+
     #[test]
     fn test_repository_branch_group_cleanup_by_date() {
         let temp_dir = TempDir::new().unwrap();
@@ -905,13 +1502,13 @@ mod tests {
 
         // Cleanup groups older than 1 day (should not remove the newly created group)
         let removed_groups = service
-            .remove_groups_older_than(&ProfileName::from("default"), 1)
+            .remove_groups_older_than(&ProfileName::from("default"), 1, false)
             .unwrap();
         assert_eq!(removed_groups.len(), 0);
 
         // Cleanup groups older than 0 days (should remove all groups)
         let removed_groups = service
-            .remove_groups_older_than(&ProfileName::from("default"), 0)
+            .remove_groups_older_than(&ProfileName::from("default"), 0, false)
             .unwrap();
         assert_eq!(removed_groups.len(), 1);
         assert_eq!(removed_groups[0], group_name);
@@ -2,7 +2,8 @@ use anyhow::Result;
 
 use crate::github::GitHubClient;
 use crate::types::{
-    RepositoryId, SearchCursorByRepository, SearchQuery, SearchResult, SearchResultWithCursors,
+    Discussion, RepositoryId, SearchCursorByRepository, SearchQuery, SearchResult,
+    SearchResultWithCursors, SearchSort,
 };
 
 /// Service for performing searches across GitHub data.
@@ -19,6 +20,38 @@ impl SearchService {
         Self { github_client }
     }
 
+    /// Searches discussions across multiple repositories
+    ///
+    /// Repositories whose search fails are skipped with a warning. Discussions are
+    /// returned grouped by repository in the given repository order.
+    pub async fn search_discussions(
+        &self,
+        repos: Vec<RepositoryId>,
+        query: SearchQuery,
+        per_page: Option<u32>,
+    ) -> Vec<Discussion> {
+        use futures::stream::{self, StreamExt};
+
+        let search_futures = repos.into_iter().map(|repo_id| {
+            let github_client = self.github_client.clone();
+            let query = query.clone();
+
+            async move {
+                github_client
+                    .search_discussions(repo_id.clone(), query, per_page)
+                    .await
+                    .inspect_err(|e| {
+                        tracing::warn!("Failed to search discussions in {}: {}", repo_id, e)
+                    })
+            }
+        });
+
+        let results: Vec<Result<Vec<Discussion>>> =
+            stream::iter(search_futures).buffered(10).collect().await;
+
+        results.into_iter().flatten().flatten().collect()
+    }
+
     /// Searches for issues and pull requests across multiple repositories
     pub async fn search_resources(
         &self,
@@ -26,6 +59,36 @@ impl SearchService {
         query: SearchQuery,
         per_page: Option<u32>,
         cursors: Option<Vec<SearchCursorByRepository>>,
+        sort: Option<SearchSort>,
+    ) -> Result<SearchResultWithCursors> {
+        self.search_resource_pages(repos, query, per_page, cursors, false, sort)
+            .await
+    }
+
+    /// Fetches the pages preceding the given cursors across multiple repositories
+    ///
+    /// `before_cursors` are the `previous_page_cursor`s of earlier pages. The returned
+    /// cursors point further back, to repositories that still have previous pages.
+    pub async fn search_resources_backward(
+        &self,
+        repos: Vec<RepositoryId>,
+        query: SearchQuery,
+        per_page: Option<u32>,
+        before_cursors: Vec<SearchCursorByRepository>,
+        sort: Option<SearchSort>,
+    ) -> Result<SearchResultWithCursors> {
+        self.search_resource_pages(repos, query, per_page, Some(before_cursors), true, sort)
+            .await
+    }
+
+    async fn search_resource_pages(
+        &self,
+        repos: Vec<RepositoryId>,
+        query: SearchQuery,
+        per_page: Option<u32>,
+        cursors: Option<Vec<SearchCursorByRepository>>,
+        backward: bool,
+        sort: Option<SearchSort>,
     ) -> Result<SearchResultWithCursors> {
         use futures::stream::{self, StreamExt};
         use std::collections::HashMap;
@@ -47,8 +110,13 @@ impl SearchService {
             let cursor = cursor_map.get(&repo_id).cloned();
 
             async move {
+                let (after, before) = if backward {
+                    (None, cursor)
+                } else {
+                    (cursor, None)
+                };
                 match github_client
-                    .search_resources(repo_id.clone(), query, per_page, cursor)
+                    .search_resources_page(repo_id.clone(), query, per_page, after, before, sort)
                     .await
                 {
                     Ok(search_result) => Ok(search_result),
@@ -74,10 +142,14 @@ impl SearchService {
 
             // Track pagination info for each repository
             if let Some(pager) = search_result.next_pager {
-                if pager.has_next_page {
+                let (has_page, page_cursor) = if backward {
+                    (pager.has_previous_page, pager.previous_page_cursor)
+                } else {
+                    (pager.has_next_page, pager.next_page_cursor)
+                };
+                if has_page {
                     next_cursors.push(SearchCursorByRepository {
-                        cursor: pager
-                            .next_page_cursor
+                        cursor: page_cursor
                             .unwrap_or_else(|| crate::types::SearchCursor("".to_string())),
                         repository_id: search_result.repository_id,
                     });
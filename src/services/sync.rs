@@ -0,0 +1,72 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+
+use crate::github::GitHubClient;
+use crate::types::{IssueOrPullrequest, RepositoryId, SearchQuery, SearchSort};
+
+/// Number of search results requested per page during an incremental sync
+const SYNC_PAGE_SIZE: u32 = 50;
+
+/// Service for incrementally synchronizing repository issues and pull requests
+///
+/// Uses `updated_at` watermarks so that only resources changed since the last
+/// sync are fetched instead of the whole repository.
+pub struct SyncService {
+    github_client: GitHubClient,
+}
+
+impl SyncService {
+    /// Creates a new sync service with the given GitHub client
+    pub fn new(github_client: GitHubClient) -> Self {
+        Self { github_client }
+    }
+
+    /// Fetches the issues and pull requests of a repository updated at or after `since`
+    ///
+    /// Follows all result pages of an `updated:>=<since>` search, oldest update first.
+    /// Returns the resources together with the new watermark: the latest `updated_at`
+    /// seen, or `since` itself when nothing changed. Because the qualifier is
+    /// inclusive, resources updated exactly at the watermark are returned again on
+    /// the next sync.
+    pub async fn sync_repository_incremental(
+        &self,
+        repository_id: RepositoryId,
+        since: DateTime<Utc>,
+    ) -> Result<(Vec<IssueOrPullrequest>, DateTime<Utc>)> {
+        let query = SearchQuery::new(format!("updated:>={}", since.format("%Y-%m-%dT%H:%M:%SZ")));
+
+        let mut resources = Vec::new();
+        let mut cursor = None;
+        loop {
+            let search_result = self
+                .github_client
+                .search_resources(
+                    repository_id.clone(),
+                    query.clone(),
+                    Some(SYNC_PAGE_SIZE),
+                    cursor,
+                    Some(SearchSort::UpdatedAsc),
+                )
+                .await?;
+            resources.extend(search_result.issue_or_pull_requests);
+
+            match search_result.next_pager {
+                Some(pager) if pager.has_next_page && pager.next_page_cursor.is_some() => {
+                    cursor = pager.next_page_cursor;
+                }
+                _ => break,
+            }
+        }
+
+        let watermark = sync_watermark(&resources, since);
+        Ok((resources, watermark))
+    }
+}
+
+/// The latest `updated_at` among the resources, never earlier than `since`
+pub fn sync_watermark(resources: &[IssueOrPullrequest], since: DateTime<Utc>) -> DateTime<Utc> {
+    resources
+        .iter()
+        .map(IssueOrPullrequest::updated_at)
+        .fold(since, DateTime::max)
+}
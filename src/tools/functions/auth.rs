@@ -0,0 +1,50 @@
+use anyhow::Result;
+
+use crate::github::GitHubClient;
+use crate::types::AuthStatus;
+
+/// Validate the client's token and report the user it belongs to
+///
+/// Returns `AuthStatus::NoToken` without calling GitHub when no token is configured.
+/// A rejected token is reported as `AuthStatus::InvalidToken`; other failures such as
+/// network errors are returned as errors. Scopes are best effort and left empty when
+/// they cannot be read.
+pub async fn check_auth(github_client: &GitHubClient) -> Result<AuthStatus> {
+    if !github_client.has_token() {
+        return Ok(AuthStatus::NoToken);
+    }
+
+    let login = match github_client.fetch_viewer_login().await {
+        Ok(login) => login,
+        Err(e) if is_bad_credentials_error(&e.to_string()) => {
+            return Ok(AuthStatus::InvalidToken {
+                message: e.to_string(),
+            });
+        }
+        Err(e) => return Err(e),
+    };
+
+    let scopes = github_client.fetch_token_scopes().await.ok().flatten();
+
+    Ok(AuthStatus::Authenticated { login, scopes })
+}
+
+fn is_bad_credentials_error(message: &str) -> bool {
+    let lowercase = message.to_lowercase();
+    lowercase.contains("bad credentials") || lowercase.contains("status: 401")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_bad_credentials_error() {
+        assert!(is_bad_credentials_error(
+            "Non-retryable error: GitHub API error - Status: 401, Message: Bad credentials"
+        ));
+        assert!(!is_bad_credentials_error(
+            "Retryable error: connection reset"
+        ));
+    }
+}
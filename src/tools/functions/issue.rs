@@ -1,13 +1,23 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use futures::stream::{self, StreamExt};
 use std::collections::BTreeMap;
 
 use crate::github::GitHubClient;
-use crate::services::MultiResourceFetcher;
-use crate::types::{Issue, IssueId, IssueNumber, IssueUrl, RepositoryId};
+use crate::services::{DEFAULT_MAX_CONCURRENCY, MultiResourceFetcher};
+use crate::types::{
+    ExtractedLinks, GitHubReference, InvalidUrlError, Issue, IssueId, IssueLinkSummary,
+    IssueNumber, IssueState, IssueTimeline, IssueUrl, LinkSummaryEntry, MilestoneNumber,
+    PullRequestUrl, ReactionSummary, RepositoryId, RepositoryUrl,
+};
 
+/// Fetch issues by URL, grouped by repository
+///
+/// `inline_comment_limit` bounds the comments fetched with each issue (clamped to 100);
+/// `None` fetches up to GitHub's maximum.
 pub async fn get_issues_details(
     github_client: &GitHubClient,
     issue_urls: Vec<IssueUrl>,
+    inline_comment_limit: Option<u32>,
 ) -> Result<BTreeMap<RepositoryId, Vec<Issue>>> {
     // Convert URLs to IssueIds and group by repository
     let mut issue_ids_by_repo: BTreeMap<RepositoryId, Vec<IssueNumber>> = BTreeMap::new();
@@ -22,7 +32,8 @@ pub async fn get_issues_details(
                     .push(issue_number);
             }
             Err(e) => {
-                return Err(anyhow::anyhow!("Failed to parse issue URL {}: {}", url, e));
+                return Err(anyhow::Error::new(InvalidUrlError(e))
+                    .context(format!("Failed to parse issue URL {}", url)));
             }
         }
     }
@@ -32,6 +43,266 @@ pub async fn get_issues_details(
         issue_ids_by_repo.into_iter().collect();
 
     // Create MultiResourceFetcher and fetch issues
-    let fetcher = MultiResourceFetcher::new(github_client.clone());
-    fetcher.fetch_issues(issue_ids_of_repositories).await
+    let fetcher = MultiResourceFetcher::new(github_client.clone())
+        .with_issue_comment_limit(inline_comment_limit);
+    let issues_by_repo = fetcher.fetch_issues(issue_ids_of_repositories).await?;
+
+    Ok(attach_reactions(
+        github_client,
+        issues_by_repo,
+        |issue: &Issue| issue.issue_id.number,
+        Issue::with_reactions,
+    )
+    .await)
+}
+
+/// Fetch a single issue, or `None` when it does not exist or is inaccessible
+pub async fn get_issue(github_client: &GitHubClient, issue_id: IssueId) -> Result<Option<Issue>> {
+    let issues_by_repo =
+        get_issues_details(github_client, vec![IssueUrl(issue_id.url())], None).await?;
+    Ok(issues_by_repo.into_values().flatten().next())
+}
+
+/// Fetch reaction counts for each issue or pull request and attach them
+///
+/// Reactions are a secondary signal, so a resource whose reactions cannot be fetched
+/// is kept without them. Each resource takes one REST request, bounded by the client's
+/// max concurrency.
+pub(crate) async fn attach_reactions<T>(
+    github_client: &GitHubClient,
+    resources_by_repo: BTreeMap<RepositoryId, Vec<T>>,
+    resource_number: impl Fn(&T) -> u32,
+    with_reactions: impl Fn(T, ReactionSummary) -> T,
+) -> BTreeMap<RepositoryId, Vec<T>> {
+    let max_concurrency = github_client
+        .max_concurrency()
+        .unwrap_or(DEFAULT_MAX_CONCURRENCY);
+    let mut result = BTreeMap::new();
+
+    for (repository_id, resources) in resources_by_repo {
+        let resources = stream::iter(resources)
+            .map(|resource| {
+                let repository_id = &repository_id;
+                let resource_number = &resource_number;
+                let with_reactions = &with_reactions;
+                async move {
+                    let number = resource_number(&resource);
+                    match github_client
+                        .fetch_issue_reactions(repository_id, IssueNumber::new(number))
+                        .await
+                    {
+                        Ok(reactions) => with_reactions(resource, reactions),
+                        Err(e) => {
+                            tracing::warn!(
+                                "Failed to fetch reactions of {}#{}: {}",
+                                repository_id.url(),
+                                number,
+                                e
+                            );
+                            resource
+                        }
+                    }
+                }
+            })
+            .buffered(max_concurrency)
+            .collect::<Vec<_>>()
+            .await;
+        result.insert(repository_id, resources);
+    }
+
+    result
+}
+
+/// Resolve and summarize all links in an issue and its comments
+///
+/// GitHub issues, pull requests, and commits are fetched for their titles; file
+/// permalinks are summarized from the URL itself. Other GitHub pages and external
+/// links are listed without fetching. References that cannot be fetched are kept
+/// with no title.
+pub async fn summarize_issue_links(
+    github_client: &GitHubClient,
+    issue_url: IssueUrl,
+) -> Result<IssueLinkSummary> {
+    let issue_id = IssueId::parse_url(&issue_url)
+        .map_err(InvalidUrlError)
+        .with_context(|| format!("Failed to parse issue URL {}", issue_url))?;
+
+    let issue = get_issues_details(github_client, vec![issue_url.clone()], None)
+        .await?
+        .into_values()
+        .flatten()
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("Issue not found: {}", issue_url))?;
+
+    let mut links = ExtractedLinks::default();
+    if let Some(body) = &issue.body {
+        links.extend_from_text(body);
+    }
+    for comment in &issue.comments {
+        links.extend_from_text(&comment.body);
+    }
+
+    let mut issue_urls = Vec::new();
+    let mut pull_request_urls = Vec::new();
+    let mut commits = Vec::new();
+    let mut file_permalinks = Vec::new();
+    for reference in links.references {
+        match reference {
+            // Skip self references
+            GitHubReference::Issue(linked_issue_id) if linked_issue_id == issue_id => {}
+            GitHubReference::Issue(linked_issue_id) => issue_urls.push(linked_issue_id.url()),
+            GitHubReference::PullRequest(pr_id) => pull_request_urls.push(pr_id.url()),
+            GitHubReference::Commit(commit_id) => {
+                let commit = github_client
+                    .fetch_commit(commit_id.git_repository.clone(), &commit_id.sha)
+                    .await
+                    .unwrap_or_else(|e| {
+                        tracing::warn!("Failed to fetch commit {}: {}", commit_id.url(), e);
+                        None
+                    });
+                commits.push(match commit {
+                    Some(commit) => LinkSummaryEntry {
+                        url: commit_id.url(),
+                        title: Some(commit.message_headline),
+                        detail: Some(match commit.author {
+                            Some(author) => format!("{} by {}", commit_id.short_sha(), author),
+                            None => commit_id.short_sha().to_string(),
+                        }),
+                    },
+                    None => LinkSummaryEntry {
+                        url: commit_id.url(),
+                        title: None,
+                        detail: None,
+                    },
+                });
+            }
+            GitHubReference::FilePermalink(permalink) => {
+                let detail = match (permalink.start_line, permalink.end_line) {
+                    (Some(start), Some(end)) => {
+                        format!("lines {}-{} at {}", start, end, permalink.git_ref)
+                    }
+                    (Some(start), None) => format!("line {} at {}", start, permalink.git_ref),
+                    _ => format!("at {}", permalink.git_ref),
+                };
+                file_permalinks.push(LinkSummaryEntry {
+                    url: permalink.url(),
+                    title: Some(permalink.path.clone()),
+                    detail: Some(detail),
+                });
+            }
+        }
+    }
+
+    let issues = if issue_urls.is_empty() {
+        Vec::new()
+    } else {
+        let fetched: Vec<Issue> = get_issues_details(
+            github_client,
+            issue_urls.iter().cloned().map(IssueUrl).collect(),
+            None,
+        )
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!("Failed to fetch linked issues: {}", e);
+            BTreeMap::new()
+        })
+        .into_values()
+        .flatten()
+        .collect();
+        issue_urls
+            .into_iter()
+            .map(|url| {
+                let found = fetched.iter().find(|issue| issue.issue_id.url() == url);
+                LinkSummaryEntry {
+                    url,
+                    title: found.map(|issue| issue.title.clone()),
+                    detail: found.map(|issue| issue.state.to_string()),
+                }
+            })
+            .collect()
+    };
+
+    let pull_requests = if pull_request_urls.is_empty() {
+        Vec::new()
+    } else {
+        let fetched: Vec<_> = crate::tools::functions::pull_request::get_pull_requests_details(
+            github_client,
+            pull_request_urls
+                .iter()
+                .cloned()
+                .map(PullRequestUrl)
+                .collect(),
+        )
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!("Failed to fetch linked pull requests: {}", e);
+            BTreeMap::new()
+        })
+        .into_values()
+        .flatten()
+        .collect();
+        pull_request_urls
+            .into_iter()
+            .map(|url| {
+                let found = fetched.iter().find(|pr| pr.pull_request_id.url() == url);
+                LinkSummaryEntry {
+                    url,
+                    title: found.map(|pr| pr.title.clone()),
+                    detail: found.map(|pr| pr.state.to_string()),
+                }
+            })
+            .collect()
+    };
+
+    Ok(IssueLinkSummary {
+        issue_id,
+        issue_title: issue.title,
+        issues,
+        pull_requests,
+        commits,
+        file_permalinks,
+        other_github_links: links.other_github_urls,
+        external_links: links.external_urls,
+    })
+}
+
+/// Fetch the cross-reference timeline events of issues
+///
+/// Timelines are returned in the order of the given URLs.
+pub async fn get_issue_timelines(
+    github_client: &GitHubClient,
+    issue_urls: Vec<IssueUrl>,
+) -> Result<Vec<IssueTimeline>> {
+    let issue_ids = issue_urls
+        .iter()
+        .map(|url| {
+            IssueId::parse_url(url)
+                .map_err(InvalidUrlError)
+                .with_context(|| format!("Failed to parse issue URL {}", url))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut timelines = Vec::with_capacity(issue_ids.len());
+    for issue_id in issue_ids {
+        timelines.push(github_client.fetch_issue_timeline(issue_id).await?);
+    }
+    Ok(timelines)
+}
+
+/// Fetch the issues of a repository milestone in the given state
+///
+/// Returns an empty list when the milestone does not exist.
+pub async fn get_milestone_issues(
+    github_client: &GitHubClient,
+    repository_url: RepositoryUrl,
+    milestone_number: MilestoneNumber,
+    state: IssueState,
+) -> Result<Vec<Issue>> {
+    let repository_id = RepositoryId::parse_url(&repository_url)
+        .map_err(InvalidUrlError)
+        .with_context(|| format!("Failed to parse repository URL {}", repository_url))?;
+
+    github_client
+        .fetch_issues_by_milestone(repository_id, milestone_number, state)
+        .await
 }
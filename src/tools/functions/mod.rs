@@ -1,8 +1,11 @@
 //! Tool function implementations organized by functionality
 
+pub mod auth;
 pub mod issue;
 pub mod profile;
 pub mod project;
 pub mod pull_request;
+pub mod rate_limit;
 pub mod repository;
+pub mod resource;
 pub mod search;
@@ -4,11 +4,19 @@
 //! including creating, listing, and deleting profiles, as well as managing
 //! repositories and projects within profiles.
 
-use crate::services::{ProfileService, default_profile_config_dir};
+use chrono::NaiveDate;
+use futures::stream::{self, StreamExt};
+
+use crate::github::GitHubClient;
+use crate::github::graphql::error::{is_not_found_error, is_permission_error};
+use crate::services::{DEFAULT_MAX_CONCURRENCY, ProfileService, default_profile_config_dir};
+use crate::tools::functions::search;
 use crate::types::profile::ProfileInfo;
 use crate::types::{
-    GroupName, ProfileName, ProjectId, ProjectUrl, RepositoryBranchGroup, RepositoryBranchPair,
-    RepositoryId, RepositoryUrl,
+    Branch, BranchGroupDiff, BranchGroupStatus, BranchPattern, GroupName, GroupReleaseNotes,
+    IssueOrPullrequest, ProfileBranchGroups, ProfileName, ProfileValidationReport, ProjectId,
+    ProjectUrl, PullRequest, RegistrationStatus, RepositoryBranchGroup, RepositoryBranchPair,
+    RepositoryId, RepositoryUrl, SearchQuery,
 };
 
 /// Create a new profile
@@ -124,6 +132,28 @@ pub async fn list_repositories(profile_name: String) -> Result<Vec<RepositoryUrl
     Ok(repository_urls)
 }
 
+/// List the repositories registered in any of the given profiles, without duplicates
+///
+/// An empty `profile_names` selects every profile.
+pub async fn list_repositories_across_profiles(
+    profile_names: Vec<String>,
+) -> Result<Vec<RepositoryId>, String> {
+    let config_dir = default_profile_config_dir()
+        .map_err(|e| format!("Failed to get config directory: {}", e))?;
+
+    let service = ProfileService::new(config_dir)
+        .map_err(|e| format!("Failed to create profile service: {}", e))?;
+
+    let profile_names: Vec<ProfileName> = profile_names
+        .iter()
+        .map(|name| ProfileName::from(name.as_str()))
+        .collect();
+
+    service
+        .list_repositories_across_profiles(&profile_names)
+        .map_err(|e| format!("Failed to list repositories: {}", e))
+}
+
 /// Register a project to a profile
 pub async fn register_project(profile_name: String, project_id: ProjectId) -> Result<(), String> {
     let config_dir = default_profile_config_dir()
@@ -198,6 +228,120 @@ pub async fn get_profile_info(profile_name: String) -> Result<ProfileInfo, Strin
     Ok(profile_info)
 }
 
+/// Set the default search query and limit of a profile
+///
+/// Returns the profile with its updated defaults.
+pub async fn set_profile_defaults(
+    profile_name: String,
+    search_query: Option<String>,
+    search_limit: Option<usize>,
+) -> Result<ProfileInfo, String> {
+    let config_dir = default_profile_config_dir()
+        .map_err(|e| format!("Failed to get config directory: {}", e))?;
+
+    let mut service = ProfileService::new(config_dir)
+        .map_err(|e| format!("Failed to create profile service: {}", e))?;
+
+    let profile_name = ProfileName::from(profile_name.as_str());
+
+    service
+        .set_profile_defaults(&profile_name, search_query, search_limit)
+        .map_err(|e| format!("Failed to set profile defaults: {}", e))?;
+
+    service
+        .get_profile_info(&profile_name)
+        .map_err(|e| format!("Failed to get profile info: {}", e))
+}
+
+/// Check that every repository and project registered in a profile can still be fetched
+///
+/// Fetches each resource with at most `DEFAULT_MAX_CONCURRENCY` requests in flight and
+/// classifies failures as not found, access denied, or other errors. The profile is
+/// not modified.
+pub async fn validate_profile(
+    github_client: &GitHubClient,
+    profile_name: String,
+) -> Result<ProfileValidationReport, String> {
+    let profile_info = get_profile_info(profile_name).await?;
+
+    let repositories = stream::iter(profile_info.repositories)
+        .map(|repository_id| async move {
+            let status = registration_status(
+                github_client
+                    .fetch_repository(repository_id.clone())
+                    .await
+                    .map(|_| ()),
+            );
+            (repository_id, status)
+        })
+        .buffered(DEFAULT_MAX_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+    let projects = stream::iter(profile_info.projects)
+        .map(|project_id| async move {
+            let status = registration_status(
+                github_client
+                    .fetch_project(project_id.clone())
+                    .await
+                    .map(|_| ()),
+            );
+            (project_id, status)
+        })
+        .buffered(DEFAULT_MAX_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+    Ok(ProfileValidationReport {
+        profile_name: profile_info.name,
+        repositories,
+        projects,
+    })
+}
+
+fn registration_status(result: anyhow::Result<()>) -> RegistrationStatus {
+    match result {
+        Ok(()) => RegistrationStatus::Valid,
+        Err(e) => {
+            let message = format!("{:#}", e);
+            if is_permission_error(&message) {
+                RegistrationStatus::AccessDenied
+            } else if is_not_found_error(&message) {
+                RegistrationStatus::NotFound
+            } else {
+                RegistrationStatus::Failed(message)
+            }
+        }
+    }
+}
+
+/// Unregister the repositories and projects a validation found to no longer exist
+///
+/// Returns the number of removed registrations. Inaccessible or failed entries are kept.
+pub async fn prune_profile(report: &ProfileValidationReport) -> Result<usize, String> {
+    let config_dir = default_profile_config_dir()
+        .map_err(|e| format!("Failed to get config directory: {}", e))?;
+
+    let mut service = ProfileService::new(config_dir)
+        .map_err(|e| format!("Failed to create profile service: {}", e))?;
+
+    let mut removed = 0;
+    for repository_id in report.not_found_repositories() {
+        service
+            .unregister_repository(&report.profile_name, repository_id)
+            .map_err(|e| format!("Failed to unregister repository: {}", e))?;
+        removed += 1;
+    }
+    for project_id in report.not_found_projects() {
+        service
+            .unregister_project(&report.profile_name, project_id)
+            .map_err(|e| format!("Failed to unregister project: {}", e))?;
+        removed += 1;
+    }
+
+    Ok(removed)
+}
+
 /// Register a repository branch group to a profile
 pub async fn register_repository_branch_group(
     profile_name: String,
@@ -288,6 +432,72 @@ pub async fn add_branch_to_branch_group(
     Ok(())
 }
 
+/// Add the branches matching a pattern in the profile's registered repositories to a group
+///
+/// Branch names of each registered repository (optionally narrowed to repository URLs
+/// containing `repository_filter`) are fetched from GitHub and matched against
+/// `branch_pattern`. Pairs already in the group are skipped. Returns the newly added pairs.
+pub async fn add_branches_by_pattern(
+    client: &GitHubClient,
+    profile_name: String,
+    group_name: String,
+    branch_pattern: String,
+    repository_filter: Option<String>,
+) -> Result<Vec<RepositoryBranchPair>, String> {
+    let config_dir = default_profile_config_dir()
+        .map_err(|e| format!("Failed to get config directory: {}", e))?;
+
+    let mut service = ProfileService::new(config_dir)
+        .map_err(|e| format!("Failed to create profile service: {}", e))?;
+
+    let profile_name = ProfileName::from(profile_name.as_str());
+    let group_name = GroupName::from(group_name.as_str());
+
+    let branch_pattern = BranchPattern::parse(&branch_pattern)
+        .map_err(|e| format!("Failed to parse branch pattern: {}", e))?;
+
+    // Fail before any GitHub request when the group does not exist
+    service
+        .get_repository_branch_group(&profile_name, &group_name)
+        .map_err(|e| format!("Failed to get repository branch group: {}", e))?;
+
+    let repository_filter = repository_filter
+        .map(|filter| filter.trim().to_lowercase())
+        .filter(|filter| !filter.is_empty());
+
+    let repositories: Vec<RepositoryId> = service
+        .list_repositories(&profile_name)
+        .map_err(|e| format!("Failed to list repositories: {}", e))?
+        .into_iter()
+        .filter(|repository_id| match &repository_filter {
+            Some(filter) => repository_id.url().to_lowercase().contains(filter),
+            None => true,
+        })
+        .collect();
+
+    let mut matching_pairs = Vec::new();
+    for repository_id in repositories {
+        let branch_names = client
+            .fetch_repository_branch_names(&repository_id)
+            .await
+            .map_err(|e| format!("Failed to fetch branches of {}: {}", repository_id.url(), e))?;
+
+        let mut matched: Vec<String> = branch_names
+            .into_iter()
+            .filter(|branch_name| branch_pattern.matches(branch_name))
+            .collect();
+        matched.sort();
+
+        matching_pairs.extend(matched.into_iter().map(|branch_name| {
+            RepositoryBranchPair::new(repository_id.clone(), Branch::new(branch_name))
+        }));
+    }
+
+    service
+        .add_pairs_to_group(&profile_name, &group_name, matching_pairs)
+        .map_err(|e| format!("Failed to add branches to group: {}", e))
+}
+
 /// Remove repository branches from a group
 pub async fn remove_branch_from_branch_group(
     profile_name: String,
@@ -349,16 +559,9 @@ pub async fn list_repository_branch_groups(
     let service = ProfileService::new(config_dir)
         .map_err(|e| format!("Failed to create profile service: {}", e))?;
 
-    let groups = service
-        .list_repository_branch_groups(&profile_name)
-        .map_err(|e| format!("Failed to list repository branch groups: {}", e))?;
-
-    let group_names = groups
-        .into_iter()
-        .map(|group_name| crate::types::GroupName::from(group_name))
-        .collect();
-
-    Ok(group_names)
+    service
+        .list_repository_branch_groups(profile_name)
+        .map_err(|e| format!("Failed to list repository branch groups: {}", e))
 }
 
 /// Get a specific repository branch group
@@ -382,10 +585,179 @@ pub async fn get_repository_branch_group(
     Ok(group)
 }
 
+/// Fetch the live state of every branch in a repository branch group
+///
+/// Fetches each branch with at most `DEFAULT_MAX_CONCURRENCY` requests in flight. Pairs
+/// registered without a branch are reported for the repository's default branch. A
+/// branch whose status cannot be fetched keeps its error message instead of failing
+/// the whole group.
+pub async fn get_branch_group_status(
+    github_client: &GitHubClient,
+    profile_name: String,
+    group_name: String,
+) -> Result<BranchGroupStatus, String> {
+    let group = get_repository_branch_group(profile_name, group_name).await?;
+
+    let branches = stream::iter(group.pairs)
+        .map(|pair| async move {
+            let pair = match github_client.resolve_default_branch(pair.clone()).await {
+                Ok(pair) => pair,
+                Err(e) => return (pair, Err(format!("{:#}", e))),
+            };
+            let status = github_client
+                .fetch_branch_status(&pair.repository_id, &pair.branch)
+                .await
+                .map_err(|e| format!("{:#}", e));
+            (pair, status)
+        })
+        .buffered(DEFAULT_MAX_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+    Ok(BranchGroupStatus {
+        group_name: group.name,
+        branches,
+    })
+}
+
+/// Maximum number of search result pages fetched per branch for release notes
+const RELEASE_NOTES_MAX_PAGES: usize = 10;
+
+/// Collect the pull requests merged into each branch of a repository branch group
+///
+/// Searches `is:pr is:merged base:<branch> merged:>=<since>` in each branch's
+/// repository, optionally restricted to pull requests carrying one of `labels`, and
+/// orders each branch's pull requests by merge time. Pairs registered without a branch
+/// use the repository's default branch. Branches are searched with at most
+/// `DEFAULT_MAX_CONCURRENCY` requests in flight.
+pub async fn generate_group_release_notes(
+    github_client: &GitHubClient,
+    profile_name: String,
+    group_name: String,
+    since: NaiveDate,
+    labels: Vec<String>,
+) -> Result<GroupReleaseNotes, String> {
+    let group = get_repository_branch_group(profile_name, group_name).await?;
+    let labels = &labels;
+
+    let branches = stream::iter(group.pairs)
+        .map(|pair| async move {
+            let pair = match github_client.resolve_default_branch(pair.clone()).await {
+                Ok(pair) => pair,
+                Err(e) => return (pair, Err(format!("{:#}", e))),
+            };
+            let query = SearchQuery::merged_pull_requests(&pair.branch, since, labels);
+            let pull_requests = search_merged_pull_requests(github_client, &pair, query)
+                .await
+                .map_err(|e| format!("{:#}", e));
+            (pair, pull_requests)
+        })
+        .buffered(DEFAULT_MAX_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+    Ok(GroupReleaseNotes {
+        group_name: group.name,
+        since,
+        branches,
+    })
+}
+
+/// Fetch every page of merged pull requests matching `query` in the pair's repository
+async fn search_merged_pull_requests(
+    github_client: &GitHubClient,
+    pair: &RepositoryBranchPair,
+    query: SearchQuery,
+) -> anyhow::Result<Vec<PullRequest>> {
+    let mut pull_requests = Vec::new();
+    let mut cursors = None;
+
+    for _ in 0..RELEASE_NOTES_MAX_PAGES {
+        let page = search::search_resources(
+            github_client,
+            vec![pair.repository_id.clone()],
+            query.clone(),
+            Some(100),
+            cursors,
+            None,
+        )
+        .await?;
+
+        pull_requests.extend(page.results.into_iter().filter_map(|result| match result {
+            IssueOrPullrequest::PullRequest(pr) => Some(pr),
+            IssueOrPullrequest::Issue(_) => None,
+        }));
+
+        if page.cursors.is_empty() {
+            break;
+        }
+        cursors = Some(page.cursors);
+    }
+
+    pull_requests.sort_by_key(|pr| pr.merged_at);
+    Ok(pull_requests)
+}
+
+/// Copy a repository branch group of a profile under a new name
+pub async fn clone_repository_branch_group(
+    profile_name: String,
+    source_group_name: String,
+    destination_group_name: String,
+) -> Result<RepositoryBranchGroup, String> {
+    let config_dir = default_profile_config_dir()
+        .map_err(|e| format!("Failed to get config directory: {}", e))?;
+
+    let mut service = ProfileService::new(config_dir)
+        .map_err(|e| format!("Failed to create profile service: {}", e))?;
+
+    let profile_name = ProfileName::from(profile_name.as_str());
+    let source_group_name = GroupName::from(source_group_name.as_str());
+    let destination_group_name = GroupName::from(destination_group_name.as_str());
+
+    service
+        .clone_repository_branch_group(&profile_name, &source_group_name, destination_group_name)
+        .map_err(|e| format!("Failed to clone repository branch group: {}", e))
+}
+
+/// Compare two repository branch groups of a profile
+///
+/// Returns the pairs only in `group_a`, only in `group_b`, and common to both.
+pub async fn diff_repository_branch_groups(
+    profile_name: String,
+    group_a: String,
+    group_b: String,
+) -> Result<BranchGroupDiff, String> {
+    let config_dir = default_profile_config_dir()
+        .map_err(|e| format!("Failed to get config directory: {}", e))?;
+
+    let service = ProfileService::new(config_dir)
+        .map_err(|e| format!("Failed to create profile service: {}", e))?;
+
+    let profile_name = ProfileName::from(profile_name.as_str());
+    let get_group = |group_name: &str| {
+        service
+            .get_repository_branch_group(&profile_name, &GroupName::from(group_name))
+            .map_err(|e| {
+                format!(
+                    "Failed to get repository branch group in profile '{}': {}",
+                    profile_name, e
+                )
+            })
+    };
+
+    let group_a = get_group(&group_a)?;
+    let group_b = get_group(&group_b)?;
+
+    Ok(group_a.diff(&group_b))
+}
+
 /// Remove repository branch groups older than N days
+///
+/// With `dry_run`, returns the groups that would be removed and leaves the profile untouched.
 pub async fn cleanup_repository_branch_groups(
     profile_name: String,
     days: i64,
+    dry_run: bool,
 ) -> Result<Vec<String>, String> {
     let config_dir = default_profile_config_dir()
         .map_err(|e| format!("Failed to get config directory: {}", e))?;
@@ -396,7 +768,7 @@ pub async fn cleanup_repository_branch_groups(
     let profile_name = ProfileName::from(profile_name.as_str());
 
     let removed_groups = service
-        .remove_groups_older_than(&profile_name, days)
+        .remove_groups_older_than(&profile_name, days, dry_run)
         .map_err(|e| format!("Failed to cleanup repository branch groups: {}", e))?;
 
     let removed_group_names = removed_groups
@@ -418,16 +790,27 @@ pub async fn list_repository_branch_groups_with_details(
         .map_err(|e| format!("Failed to create profile service: {}", e))?;
 
     let group_names = service
-        .list_repository_branch_groups(&profile_name)
+        .list_repository_branch_groups(profile_name)
         .map_err(|e| format!("Failed to list repository branch groups: {}", e))?;
 
     let mut groups = Vec::new();
     for group_name in group_names {
         let group = service
-            .get_repository_branch_group(&profile_name, &group_name)
+            .get_repository_branch_group(profile_name, &group_name)
             .map_err(|e| format!("Failed to get repository branch group: {}", e))?;
         groups.push(group);
     }
 
     Ok(groups)
 }
+
+/// List repository branch groups of all profiles, sorted by profile name then group name
+pub async fn list_branch_groups_across_profiles() -> Result<Vec<ProfileBranchGroups>, String> {
+    let config_dir = default_profile_config_dir()
+        .map_err(|e| format!("Failed to get config directory: {}", e))?;
+
+    let service = ProfileService::new(config_dir)
+        .map_err(|e| format!("Failed to create profile service: {}", e))?;
+
+    Ok(service.list_repository_branch_groups_across_profiles())
+}
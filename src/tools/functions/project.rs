@@ -5,12 +5,21 @@ use crate::{
     github::GitHubClient,
     services::MultiResourceFetcher,
     types::repository::Owner,
-    types::{Project, ProjectId, ProjectNumber, ProjectResource, ProjectUrl},
+    types::{
+        DeduplicatedProjectResources, Project, ProjectFetchLimits, ProjectField, ProjectFieldId,
+        ProjectFieldUpdateValue, ProjectId, ProjectItemId, ProjectNumber, ProjectRepositories,
+        ProjectResource, ProjectResourceDeduplication, ProjectRoadmap, ProjectUrl,
+        SearchCursorByProject,
+    },
 };
 
+/// Fetch resources of a project
+///
+/// Only items matching every `(field name, value)` pair of `field_filters` are returned.
 pub async fn get_project_resources(
     github_client: &GitHubClient,
     project_url: ProjectUrl,
+    field_filters: &[(String, String)],
 ) -> Result<Vec<ProjectResource>, McpError> {
     // Parse project URL to extract project ID components
     let (owner_str, number, project_type) = ProjectId::parse_url(&project_url).map_err(|e| {
@@ -27,24 +36,167 @@ pub async fn get_project_resources(
     // Create MultiResourceFetcher and fetch project resources
     let fetcher = MultiResourceFetcher::new(github_client.clone());
     fetcher
-        .fetch_project_resources(project_id)
+        .fetch_project_resources(project_id, field_filters)
         .await
         .map_err(|e| {
             McpError::internal_error(format!("Failed to fetch project resources: {}", e), None)
         })
 }
 
+/// Get the distinct repositories a project draws its items from
+///
+/// Aggregates the project's items by source repository, sorted by item count descending.
+pub async fn get_project_repositories(
+    github_client: &GitHubClient,
+    project_url: ProjectUrl,
+) -> Result<ProjectRepositories, McpError> {
+    let resources = get_project_resources(github_client, project_url, &[]).await?;
+    Ok(ProjectRepositories::from_resources(&resources))
+}
+
+/// Fetch the field definitions of a project, including single select options
+pub async fn get_project_field_definitions(
+    github_client: &GitHubClient,
+    project_url: ProjectUrl,
+) -> Result<Vec<ProjectField>, McpError> {
+    let (owner_str, number, project_type) = ProjectId::parse_url(&project_url).map_err(|e| {
+        McpError::invalid_params(format!("Failed to parse project URL: {}", e), None)
+    })?;
+
+    let project_id = ProjectId::new(
+        Owner::new(owner_str),
+        ProjectNumber::new(number),
+        project_type,
+    );
+
+    github_client
+        .fetch_project_fields(project_id)
+        .await
+        .map_err(|e| {
+            McpError::internal_error(format!("Failed to fetch project fields: {}", e), None)
+        })
+}
+
+/// Build a roadmap of a project by grouping its items on an iteration or date field
+///
+/// `group_by_field` is resolved case-insensitively against the project's field
+/// definitions and must be an iteration or date field.
+pub async fn get_project_roadmap(
+    github_client: &GitHubClient,
+    project_url: ProjectUrl,
+    group_by_field: &str,
+) -> Result<ProjectRoadmap, McpError> {
+    let fields = get_project_field_definitions(github_client, project_url.clone()).await?;
+
+    let field = fields
+        .iter()
+        .find(|field| field.field_name.eq_ignore_ascii_case(group_by_field.trim()))
+        .ok_or_else(|| {
+            let available = fields
+                .iter()
+                .map(|field| field.field_name.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            McpError::invalid_params(
+                format!(
+                    "Field '{}' not found in project. Available fields: {}",
+                    group_by_field, available
+                ),
+                None,
+            )
+        })?;
+
+    if !matches!(field.data_type.as_str(), "ITERATION" | "DATE") {
+        return Err(McpError::invalid_params(
+            format!(
+                "Field '{}' has data type {}; group_by_field must be an iteration or date field",
+                field.field_name, field.data_type
+            ),
+            None,
+        ));
+    }
+
+    let resources = get_project_resources(github_client, project_url, &[]).await?;
+    Ok(ProjectRoadmap::from_resources(
+        &field.field_name.0,
+        resources,
+    ))
+}
+
+/// Set the value of a field of a project item
+///
+/// Resolves the project's node id from its URL, then updates the field. Returns the id
+/// of the updated item.
+pub async fn set_project_field_value(
+    github_client: &GitHubClient,
+    project_url: ProjectUrl,
+    item_id: ProjectItemId,
+    field_id: ProjectFieldId,
+    value: ProjectFieldUpdateValue,
+) -> Result<ProjectItemId, McpError> {
+    let (owner_str, number, project_type) = ProjectId::parse_url(&project_url).map_err(|e| {
+        McpError::invalid_params(format!("Failed to parse project URL: {}", e), None)
+    })?;
+
+    let project_id = ProjectId::new(
+        Owner::new(owner_str),
+        ProjectNumber::new(number),
+        project_type,
+    );
+
+    let project = github_client
+        .fetch_project(project_id)
+        .await
+        .map_err(|e| McpError::internal_error(format!("Failed to fetch project: {}", e), None))?;
+
+    github_client
+        .update_project_item_field(&project.project_node_id, &item_id, &field_id, &value)
+        .await
+        .map_err(|e| {
+            McpError::internal_error(format!("Failed to set project field value: {}", e), None)
+        })
+}
+
+/// Fetch resources of multiple projects
+///
+/// The same issue or pull request may be added to several projects. `deduplication`
+/// controls whether such items are returned as-is (`Raw`) or collapsed by the URL of
+/// the underlying content; the number of collapsed items is reported in the result.
+/// Items not matching every `(field name, value)` pair of `field_filters` are dropped
+/// before deduplication. `limits` apply to each project; projects stopped early by a
+/// cap are returned with the cursor of their next page. Projects with a cursor in
+/// `start_cursors` resume from it instead of the first page.
 pub async fn get_multiple_project_resources(
     github_client: &GitHubClient,
     project_ids: Vec<ProjectId>,
-) -> Result<Vec<ProjectResource>, McpError> {
+    deduplication: ProjectResourceDeduplication,
+    field_filters: &[(String, String)],
+    limits: ProjectFetchLimits,
+    start_cursors: &[SearchCursorByProject],
+) -> Result<(DeduplicatedProjectResources, Vec<SearchCursorByProject>), McpError> {
     let fetcher = MultiResourceFetcher::new(github_client.clone());
     let mut all_resources = Vec::new();
+    let mut cursors = Vec::new();
 
     for project_id in project_ids {
-        match fetcher.fetch_project_resources(project_id.clone()).await {
-            Ok(project_resources) => {
-                all_resources.extend(project_resources);
+        let start_cursor = start_cursors
+            .iter()
+            .find(|start| start.project_id == project_id)
+            .map(|start| start.cursor.clone());
+        match fetcher
+            .fetch_project_resources_with_limits(
+                project_id.clone(),
+                field_filters,
+                limits,
+                start_cursor,
+            )
+            .await
+        {
+            Ok(fetch) => {
+                all_resources.extend(fetch.resources);
+                if let Some(cursor) = fetch.next_cursor {
+                    cursors.push(SearchCursorByProject { cursor, project_id });
+                }
             }
             Err(e) => {
                 tracing::warn!(
@@ -56,7 +208,7 @@ pub async fn get_multiple_project_resources(
         }
     }
 
-    Ok(all_resources)
+    Ok((deduplication.apply(all_resources), cursors))
 }
 
 pub async fn get_projects_details(
@@ -1,9 +1,16 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use std::collections::BTreeMap;
 
 use crate::github::GitHubClient;
 use crate::services::MultiResourceFetcher;
-use crate::types::{PullRequest, PullRequestId, PullRequestNumber, PullRequestUrl, RepositoryId};
+use crate::tools::functions::issue::attach_reactions;
+use crate::tools::functions::search::search_resources;
+use crate::types::{
+    DEFAULT_DIFF_MAX_LINES, InvalidUrlError, IssueOrPullrequest, PullRequest, PullRequestChecks,
+    PullRequestCommits, PullRequestId, PullRequestListFilter, PullRequestNumber,
+    PullRequestReviews, PullRequestUrl, RepositoryId, RepositoryUrl, count_patch_changes,
+    diff_hunk_at, is_generated_file_path, truncate_diff_lines,
+};
 
 pub async fn get_pull_requests_details(
     github_client: &GitHubClient,
@@ -23,7 +30,8 @@ pub async fn get_pull_requests_details(
                     .push(pull_request_number);
             }
             Err(e) => {
-                return Err(anyhow::anyhow!("Failed to parse issue URL {}: {}", url, e));
+                return Err(anyhow::Error::new(InvalidUrlError(e))
+                    .context(format!("Failed to parse issue URL {}", url)));
             }
         }
     }
@@ -34,9 +42,18 @@ pub async fn get_pull_requests_details(
 
     // Create MultiResourceFetcher and fetch issues
     let fetcher = MultiResourceFetcher::new(github_client.clone());
-    fetcher
+    let pull_requests_by_repo = fetcher
         .fetch_pull_requests(pull_request_ids_of_repositories)
-        .await
+        .await?;
+
+    // Pull requests are issues in the REST API, so their reactions come from the same endpoint
+    Ok(attach_reactions(
+        github_client,
+        pull_requests_by_repo,
+        |pull_request: &PullRequest| pull_request.pull_request_id.number,
+        PullRequest::with_reactions,
+    )
+    .await)
 }
 
 pub async fn get_pull_request_code_diffs(
@@ -57,11 +74,8 @@ pub async fn get_pull_request_code_diffs(
                     .push(pull_request_number);
             }
             Err(e) => {
-                return Err(anyhow::anyhow!(
-                    "Failed to parse pull request URL {}: {}",
-                    url,
-                    e
-                ));
+                return Err(anyhow::Error::new(InvalidUrlError(e))
+                    .context(format!("Failed to parse pull request URL {}", url)));
             }
         }
     }
@@ -95,11 +109,8 @@ pub async fn get_pull_request_files_stats(
                     .push(pull_request_number);
             }
             Err(e) => {
-                return Err(anyhow::anyhow!(
-                    "Failed to parse pull request URL {}: {}",
-                    url,
-                    e
-                ));
+                return Err(anyhow::Error::new(InvalidUrlError(e))
+                    .context(format!("Failed to parse pull request URL {}", url)));
             }
         }
     }
@@ -115,6 +126,23 @@ pub async fn get_pull_request_files_stats(
         .await
 }
 
+/// Get the changed files of a single pull request with their addition and deletion counts
+pub async fn get_pull_request_diff_stat(
+    github_client: &GitHubClient,
+    pull_request_url: PullRequestUrl,
+) -> Result<Vec<crate::types::PullRequestFile>> {
+    let pull_request_id = PullRequestId::parse_url(&pull_request_url)
+        .map_err(InvalidUrlError)
+        .with_context(|| format!("Failed to parse pull request URL {}", pull_request_url))?;
+
+    github_client
+        .fetch_pull_request_files(
+            pull_request_id.git_repository,
+            PullRequestNumber::new(pull_request_id.number),
+        )
+        .await
+}
+
 /// Get the diff content of a specific file from a pull request
 ///
 /// # Arguments
@@ -124,26 +152,36 @@ pub async fn get_pull_request_files_stats(
 /// * `file_path` - File path within the repository
 /// * `skip` - Optional number of lines to skip from the beginning
 /// * `limit` - Optional maximum number of lines to return
+/// * `max_lines` - Optional safety cap on returned lines (default: `DEFAULT_DIFF_MAX_LINES`)
+/// * `hunk_index` - Optional 0-based index of a single `@@ ... @@` hunk to return instead
+///   of a line range; cannot be combined with `skip` or `limit`
 ///
 /// # Returns
 ///
 /// Returns the diff content as a String. If skip/limit is specified, only returns
-/// the requested portion of the diff.
+/// the requested portion of the diff; if `hunk_index` is specified, only the complete
+/// hunk at that index including its header. Output longer than `max_lines` is truncated
+/// with a notice. Likely generated files (lockfiles, minified assets) are summarized
+/// to their change stats unless skip, limit, or hunk_index is specified.
 pub async fn get_pull_request_diff_contents(
     github_client: &GitHubClient,
     pull_request_url: PullRequestUrl,
     file_path: String,
     skip: Option<u32>,
     limit: Option<u32>,
+    max_lines: Option<u32>,
+    hunk_index: Option<u32>,
 ) -> Result<String> {
+    if hunk_index.is_some() && (skip.is_some() || limit.is_some()) {
+        return Err(anyhow::anyhow!(
+            "hunk_index cannot be combined with skip or limit"
+        ));
+    }
+
     // Parse URL to get repository and PR number
-    let pull_request_id = PullRequestId::parse_url(&pull_request_url).map_err(|e| {
-        anyhow::anyhow!(
-            "Failed to parse pull request URL {}: {}",
-            pull_request_url,
-            e
-        )
-    })?;
+    let pull_request_id = PullRequestId::parse_url(&pull_request_url)
+        .map_err(InvalidUrlError)
+        .with_context(|| format!("Failed to parse pull request URL {}", pull_request_url))?;
 
     let pull_request_number = PullRequestNumber::new(pull_request_id.number);
 
@@ -162,9 +200,27 @@ pub async fn get_pull_request_diff_contents(
             )
         })?;
 
+    let max_lines = max_lines.unwrap_or(DEFAULT_DIFF_MAX_LINES) as usize;
+
+    // A whole hunk keeps the returned diff syntactically valid
+    if let Some(hunk_index) = hunk_index {
+        let hunk = diff_hunk_at(&patch, hunk_index as usize)
+            .map_err(|e| anyhow::anyhow!("{} in file '{}'", e, file_path))?;
+        return Ok(truncate_diff_lines(&hunk, max_lines));
+    }
+
     // If no skip/limit is specified, return the entire patch
     if skip.is_none() && limit.is_none() {
-        return Ok(patch);
+        if is_generated_file_path(&file_path) {
+            let (additions, deletions) = count_patch_changes(&patch);
+            return Ok(format!(
+                "(file diff summarized: +{} -{} in {} lines; likely generated. Specify skip or limit to view the contents)",
+                additions,
+                deletions,
+                patch.lines().count()
+            ));
+        }
+        return Ok(truncate_diff_lines(&patch, max_lines));
     }
 
     // Filter lines based on skip and limit
@@ -189,5 +245,136 @@ pub async fn get_pull_request_diff_contents(
     };
 
     let filtered_lines = &lines[start_idx..end_idx];
-    Ok(filtered_lines.join("\n"))
+    Ok(truncate_diff_lines(&filtered_lines.join("\n"), max_lines))
+}
+
+/// List open pull requests of a repository filtered by review state, draft status,
+/// author, and requested reviewer
+///
+/// Filters are translated into GitHub search qualifiers, then the review fields of
+/// each returned pull request are checked to drop stale search index matches.
+pub async fn list_pull_requests(
+    github_client: &GitHubClient,
+    repository_url: RepositoryUrl,
+    filter: PullRequestListFilter,
+    limit: Option<u32>,
+) -> Result<Vec<PullRequest>> {
+    let repository_id = RepositoryId::parse_url(&repository_url)
+        .map_err(InvalidUrlError)
+        .with_context(|| format!("Failed to parse repository URL {}", repository_url))?;
+
+    let search_result = search_resources(
+        github_client,
+        vec![repository_id],
+        filter.to_search_query(),
+        limit,
+        None,
+        None,
+    )
+    .await?;
+
+    let pull_requests = search_result
+        .results
+        .into_iter()
+        .filter_map(|result| match result {
+            IssueOrPullrequest::PullRequest(pr) => Some(pr),
+            IssueOrPullrequest::Issue(_) => None,
+        })
+        .filter(|pr| filter.matches(pr))
+        .collect();
+
+    Ok(pull_requests)
+}
+
+/// Fetch the reviews of pull requests
+///
+/// Reviews are returned in the order of the given URLs.
+pub async fn get_pull_request_reviews(
+    github_client: &GitHubClient,
+    pull_request_urls: Vec<PullRequestUrl>,
+) -> Result<Vec<PullRequestReviews>> {
+    let pull_request_ids = pull_request_urls
+        .iter()
+        .map(|url| {
+            PullRequestId::parse_url(url)
+                .map_err(InvalidUrlError)
+                .with_context(|| format!("Failed to parse pull request URL {}", url))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut all_reviews = Vec::with_capacity(pull_request_ids.len());
+    for pull_request_id in pull_request_ids {
+        let reviews = github_client
+            .fetch_pull_request_reviews(
+                pull_request_id.git_repository.clone(),
+                PullRequestNumber::new(pull_request_id.number),
+            )
+            .await?;
+        all_reviews.push(PullRequestReviews {
+            pull_request_id,
+            reviews,
+        });
+    }
+    Ok(all_reviews)
+}
+
+/// Fetch the commits of pull requests
+///
+/// Commits are returned in the order of the given URLs, oldest commit first.
+pub async fn get_pull_request_commits(
+    github_client: &GitHubClient,
+    pull_request_urls: Vec<PullRequestUrl>,
+) -> Result<Vec<PullRequestCommits>> {
+    let pull_request_ids = pull_request_urls
+        .iter()
+        .map(|url| {
+            PullRequestId::parse_url(url)
+                .map_err(InvalidUrlError)
+                .with_context(|| format!("Failed to parse pull request URL {}", url))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut all_commits = Vec::with_capacity(pull_request_ids.len());
+    for pull_request_id in pull_request_ids {
+        let commits = github_client
+            .fetch_pull_request_commits(
+                pull_request_id.git_repository.clone(),
+                PullRequestNumber::new(pull_request_id.number),
+            )
+            .await?;
+        all_commits.push(PullRequestCommits {
+            pull_request_id,
+            commits,
+        });
+    }
+    Ok(all_commits)
+}
+
+/// Fetch the CI checks of the head commits of pull requests
+///
+/// Checks are returned in the order of the given URLs.
+pub async fn get_pull_request_checks(
+    github_client: &GitHubClient,
+    pull_request_urls: Vec<PullRequestUrl>,
+) -> Result<Vec<PullRequestChecks>> {
+    let pull_request_ids = pull_request_urls
+        .iter()
+        .map(|url| {
+            PullRequestId::parse_url(url)
+                .map_err(InvalidUrlError)
+                .with_context(|| format!("Failed to parse pull request URL {}", url))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut all_checks = Vec::with_capacity(pull_request_ids.len());
+    for pull_request_id in pull_request_ids {
+        let checks = github_client
+            .fetch_pull_request_checks(
+                pull_request_id.git_repository.clone(),
+                PullRequestNumber::new(pull_request_id.number),
+            )
+            .await?;
+        all_checks.push(checks);
+    }
+    Ok(all_checks)
 }
@@ -0,0 +1,9 @@
+use anyhow::Result;
+
+use crate::github::GitHubClient;
+use crate::types::RateLimitStatus;
+
+/// Fetch the GraphQL API rate limit budget of the client's token
+pub async fn get_rate_limit_status(github_client: &GitHubClient) -> Result<RateLimitStatus> {
+    github_client.fetch_rate_limit().await
+}
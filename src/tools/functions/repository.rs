@@ -1,20 +1,41 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use futures::stream::{self, StreamExt};
+use std::collections::BTreeMap;
 
 use crate::github::GitHubClient;
-use crate::services::MultiResourceFetcher;
-use crate::types::{GithubRepository, RepositoryId, RepositoryUrl};
+use crate::services::{DEFAULT_MAX_CONCURRENCY, MultiResourceFetcher};
+use crate::types::{
+    Contributor, GithubRepository, InvalidUrlError, Issue, IssueNumber, ReleaseWithAssets,
+    RepositoryFetchResult, RepositoryId, RepositoryStats, RepositoryUrl,
+};
 
 pub async fn get_multiple_repository_details(
     github_client: &GitHubClient,
     repository_urls: Vec<RepositoryUrl>,
 ) -> Result<Vec<GithubRepository>> {
+    Ok(
+        get_multiple_repository_details_with_redirects(github_client, repository_urls)
+            .await?
+            .into_iter()
+            .map(|result| result.repository)
+            .collect(),
+    )
+}
+
+/// Fetch repositories by URL, noting the ones GitHub resolved to a new name
+///
+/// Repositories that cannot be fetched are logged and left out of the result.
+pub async fn get_multiple_repository_details_with_redirects(
+    github_client: &GitHubClient,
+    repository_urls: Vec<RepositoryUrl>,
+) -> Result<Vec<RepositoryFetchResult>> {
     // Parse URLs to repository IDs first
     let repository_ids: Result<Vec<RepositoryId>, anyhow::Error> = repository_urls
         .iter()
         .map(|url| {
             RepositoryId::parse_url(url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL {}: {}", url, e))
+                .map_err(InvalidUrlError)
+                .with_context(|| format!("Failed to parse repository URL {}", url))
         })
         .collect();
 
@@ -24,18 +45,23 @@ pub async fn get_multiple_repository_details(
     let fetch_futures = repository_ids.into_iter().map(|repo_id| {
         let github_client = github_client.clone();
         async move {
-            let fetcher = MultiResourceFetcher::new(github_client);
-            fetcher.fetch_repository(repo_id).await
+            github_client
+                .fetch_repository_following_redirects(repo_id)
+                .await
         }
     });
 
-    let results: Vec<Result<GithubRepository>> = stream::iter(fetch_futures)
-        .buffer_unordered(10) // Process up to 10 repositories concurrently
+    let results: Vec<Result<RepositoryFetchResult>> = stream::iter(fetch_futures)
+        .buffer_unordered(
+            github_client
+                .max_concurrency()
+                .unwrap_or(DEFAULT_MAX_CONCURRENCY),
+        )
         .collect()
         .await;
 
     // Collect successful results and log errors
-    let repositories: Vec<GithubRepository> = results
+    let repositories: Vec<RepositoryFetchResult> = results
         .into_iter()
         .filter_map(|result| match result {
             Ok(repo) => Some(repo),
@@ -48,3 +74,104 @@ pub async fn get_multiple_repository_details(
 
     Ok(repositories)
 }
+
+/// Fetch the issues pinned by maintainers for the given repositories
+///
+/// Repositories without pinned issues are omitted from the result.
+pub async fn get_pinned_issues(
+    github_client: &GitHubClient,
+    repository_urls: Vec<RepositoryUrl>,
+) -> Result<BTreeMap<RepositoryId, Vec<Issue>>> {
+    let repositories = get_multiple_repository_details(github_client, repository_urls).await?;
+
+    let issue_ids_of_repositories: Vec<(RepositoryId, Vec<IssueNumber>)> = repositories
+        .into_iter()
+        .filter(|repo| !repo.pinned_issues.is_empty())
+        .map(|repo| {
+            let issue_numbers = repo
+                .pinned_issues
+                .iter()
+                .map(|issue_id| IssueNumber::new(issue_id.number))
+                .collect();
+            (repo.git_repository_id, issue_numbers)
+        })
+        .collect();
+
+    if issue_ids_of_repositories.is_empty() {
+        return Ok(BTreeMap::new());
+    }
+
+    let fetcher = MultiResourceFetcher::new(github_client.clone());
+    fetcher.fetch_issues(issue_ids_of_repositories).await
+}
+
+/// Fetch the top contributors of a repository, sorted by contribution count
+pub async fn get_repository_contributors(
+    github_client: &GitHubClient,
+    repository_url: RepositoryUrl,
+    limit: usize,
+) -> Result<(RepositoryId, Vec<Contributor>)> {
+    let repository_id = RepositoryId::parse_url(&repository_url)
+        .map_err(InvalidUrlError)
+        .with_context(|| format!("Failed to parse repository URL {}", repository_url))?;
+
+    let contributors = github_client
+        .fetch_repository_contributors(repository_id.clone(), limit)
+        .await?;
+
+    Ok((repository_id, contributors))
+}
+
+/// Get issue and pull request counts of repositories, in the order of `repository_urls`
+pub async fn get_repository_stats(
+    github_client: &GitHubClient,
+    repository_urls: Vec<RepositoryUrl>,
+) -> Result<Vec<RepositoryStats>> {
+    let repository_ids = repository_urls
+        .iter()
+        .map(|url| {
+            RepositoryId::parse_url(url)
+                .map_err(InvalidUrlError)
+                .with_context(|| format!("Failed to parse repository URL {}", url))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    stream::iter(repository_ids)
+        .map(|repository_id| github_client.fetch_repository_stats(repository_id))
+        .buffered(
+            github_client
+                .max_concurrency()
+                .unwrap_or(DEFAULT_MAX_CONCURRENCY),
+        )
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect()
+}
+
+/// Get the latest releases of a repository with their downloadable assets
+pub async fn get_repository_releases(
+    github_client: &GitHubClient,
+    repository_url: RepositoryUrl,
+    limit: usize,
+) -> Result<(RepositoryId, Vec<ReleaseWithAssets>)> {
+    let repository_id = RepositoryId::parse_url(&repository_url)
+        .map_err(InvalidUrlError)
+        .with_context(|| format!("Failed to parse repository URL {}", repository_url))?;
+
+    let releases = github_client
+        .fetch_repository_releases(repository_id.clone(), limit)
+        .await?;
+
+    Ok((repository_id, releases))
+}
+
+/// Get the languages of a repository with their sizes in bytes, largest first
+pub async fn get_repository_languages(
+    github_client: &GitHubClient,
+    repository_id: RepositoryId,
+) -> Result<Vec<(String, u64)>> {
+    github_client
+        .fetch_repository_languages(repository_id)
+        .await
+}
@@ -0,0 +1,98 @@
+use anyhow::Result;
+use std::collections::HashMap;
+
+use crate::github::GitHubClient;
+use crate::tools::functions::{issue, pull_request};
+use crate::types::{
+    IssueOrPullrequest, IssueOrPullrequestId, IssueUrl, PullRequestUrl, ResourcesDetails,
+};
+
+/// Fetch issues and pull requests from a mixed list of URLs
+///
+/// Each URL is classified by its path, and issues and pull requests are fetched in
+/// batches grouped by repository. Resources are returned in the order of the input
+/// URLs. URLs that cannot be parsed or fetched are reported in `errors` instead of
+/// failing the whole call.
+pub async fn get_resources_details(
+    github_client: &GitHubClient,
+    urls: Vec<String>,
+) -> Result<ResourcesDetails> {
+    let mut errors = Vec::new();
+    let mut requested: Vec<(String, IssueOrPullrequestId)> = Vec::new();
+    for url in urls {
+        match IssueOrPullrequestId::parse_url(url.trim()) {
+            Ok(id) => requested.push((url, id)),
+            Err(e) => errors.push((url, e)),
+        }
+    }
+
+    let mut issue_urls = Vec::new();
+    let mut pull_request_urls = Vec::new();
+    for (_, id) in &requested {
+        match id {
+            IssueOrPullrequestId::IssueId(issue_id) => issue_urls.push(IssueUrl(issue_id.url())),
+            IssueOrPullrequestId::PullrequestId(pull_request_id) => {
+                pull_request_urls.push(PullRequestUrl(pull_request_id.url()))
+            }
+        }
+    }
+
+    let (issues, pull_requests) = futures::join!(
+        async {
+            if issue_urls.is_empty() {
+                return Ok(Default::default());
+            }
+            issue::get_issues_details(github_client, issue_urls, None).await
+        },
+        async {
+            if pull_request_urls.is_empty() {
+                return Ok(Default::default());
+            }
+            pull_request::get_pull_requests_details(github_client, pull_request_urls).await
+        },
+    );
+
+    let mut fetched: HashMap<IssueOrPullrequestId, IssueOrPullrequest> = HashMap::new();
+    let issue_error = match issues {
+        Ok(issues_by_repo) => {
+            for issue in issues_by_repo.into_values().flatten() {
+                fetched.insert(
+                    IssueOrPullrequestId::IssueId(issue.issue_id.clone()),
+                    IssueOrPullrequest::Issue(issue),
+                );
+            }
+            None
+        }
+        Err(e) => Some(e.to_string()),
+    };
+    let pull_request_error = match pull_requests {
+        Ok(pull_requests_by_repo) => {
+            for pull_request in pull_requests_by_repo.into_values().flatten() {
+                fetched.insert(
+                    IssueOrPullrequestId::PullrequestId(pull_request.pull_request_id.clone()),
+                    IssueOrPullrequest::PullRequest(pull_request),
+                );
+            }
+            None
+        }
+        Err(e) => Some(e.to_string()),
+    };
+
+    let mut resources = Vec::new();
+    for (url, id) in requested {
+        if let Some(resource) = fetched.get(&id) {
+            resources.push(resource.clone());
+            continue;
+        }
+        let batch_error = match id {
+            IssueOrPullrequestId::IssueId(_) => &issue_error,
+            IssueOrPullrequestId::PullrequestId(_) => &pull_request_error,
+        };
+        let reason = batch_error
+            .clone()
+            .unwrap_or_else(|| "Not found".to_string());
+        errors.push((url, reason));
+    }
+
+    Ok(ResourcesDetails { resources, errors })
+}
@@ -1,8 +1,61 @@
-use anyhow::Result;
+use std::collections::BTreeSet;
+
+use anyhow::{Result, bail};
 
 use crate::github::GitHubClient;
 use crate::services::SearchService;
-use crate::types::{RepositoryId, SearchCursorByRepository, SearchQuery, SearchResultWithCursors};
+use crate::types::{
+    DEFAULT_SEARCH_LIMIT, Discussion, IssueOrPullrequest, IssueState, MAX_SEARCH_LIMIT,
+    ProfileInfo, PullRequestState, RepositoryId, SearchCursorByRepository, SearchQuery,
+    SearchResultWithCursors, SearchSort, UserActivity, UserActivityItem,
+};
+
+/// Fill an omitted query or limit from the defaults stored in a profile
+///
+/// A limit of 0 counts as omitted. It is kept when the profile has no default limit,
+/// so `clamp_search_limit` can report it.
+pub fn apply_profile_search_defaults(
+    profile: Option<&ProfileInfo>,
+    query: Option<String>,
+    limit: Option<usize>,
+) -> (Option<String>, Option<usize>) {
+    let Some(profile) = profile else {
+        return (query, limit);
+    };
+    (
+        query.or_else(|| profile.default_search_query.clone()),
+        limit
+            .filter(|limit| *limit > 0)
+            .or(profile.default_search_limit)
+            .or(limit),
+    )
+}
+
+/// Resolve the result limit of a search
+///
+/// Missing or zero limits fall back to `DEFAULT_SEARCH_LIMIT`, and limits above
+/// `MAX_SEARCH_LIMIT` are capped. Returns the limit to use and a note for the response
+/// when the requested limit was changed.
+pub fn clamp_search_limit(limit: Option<usize>) -> (usize, Option<String>) {
+    match limit {
+        None => (DEFAULT_SEARCH_LIMIT, None),
+        Some(0) => (
+            DEFAULT_SEARCH_LIMIT,
+            Some(format!(
+                "Note: limit 0 is not allowed; using the default limit of {}.",
+                DEFAULT_SEARCH_LIMIT
+            )),
+        ),
+        Some(limit) if limit > MAX_SEARCH_LIMIT => (
+            MAX_SEARCH_LIMIT,
+            Some(format!(
+                "Note: limit {} exceeds the maximum of {}; showing at most {} results per repository.",
+                limit, MAX_SEARCH_LIMIT, MAX_SEARCH_LIMIT
+            )),
+        ),
+        Some(limit) => (limit, None),
+    }
+}
 
 /// Search for issues and pull requests across multiple repositories
 pub async fn search_resources(
@@ -11,10 +64,197 @@ pub async fn search_resources(
     query: SearchQuery,
     per_page: Option<u32>,
     cursors: Option<Vec<SearchCursorByRepository>>,
+    sort: Option<SearchSort>,
+) -> Result<SearchResultWithCursors> {
+    let search_service = SearchService::new(github_client.clone());
+
+    search_service
+        .search_resources(repos, query, per_page, cursors, sort)
+        .await
+}
+
+/// Fetch the pages preceding `before_cursors` across multiple repositories
+///
+/// Forward-only callers keep using `search_resources`; the cursors returned here
+/// page further back.
+pub async fn search_resources_backward(
+    github_client: &GitHubClient,
+    repos: Vec<RepositoryId>,
+    query: SearchQuery,
+    per_page: Option<u32>,
+    before_cursors: Vec<SearchCursorByRepository>,
+    sort: Option<SearchSort>,
 ) -> Result<SearchResultWithCursors> {
     let search_service = SearchService::new(github_client.clone());
 
     search_service
-        .search_resources(repos, query, per_page, cursors)
+        .search_resources_backward(repos, query, per_page, before_cursors, sort)
         .await
 }
+
+/// Search for discussions across multiple repositories
+pub async fn search_discussions(
+    github_client: &GitHubClient,
+    repos: Vec<RepositoryId>,
+    query: SearchQuery,
+    per_page: Option<u32>,
+) -> Vec<Discussion> {
+    let search_service = SearchService::new(github_client.clone());
+
+    search_service
+        .search_discussions(repos, query, per_page)
+        .await
+}
+
+/// Summarize the issues and pull requests a user authored or is assigned to
+///
+/// Runs `author:` and `assignee:` searches across `repos`, each capped at
+/// `per_repository_limit` results per repository. `username` may be `@me` for the
+/// authenticated user.
+pub async fn get_user_activity(
+    github_client: &GitHubClient,
+    username: &str,
+    repos: Vec<RepositoryId>,
+    per_repository_limit: u32,
+) -> Result<UserActivity> {
+    let username = username.trim();
+    if username.is_empty() || username.contains(char::is_whitespace) {
+        bail!("Invalid GitHub username: '{}'", username);
+    }
+
+    let search = |query: String| {
+        search_resources(
+            github_client,
+            repos.clone(),
+            SearchQuery::new(query),
+            Some(per_repository_limit),
+            None,
+            Some(SearchSort::UpdatedDesc),
+        )
+    };
+    let (authored_issues, authored_pull_requests, assigned) = futures::try_join!(
+        search(format!("is:issue author:{}", username)),
+        search(format!("is:pr author:{}", username)),
+        search(format!("assignee:{} state:open", username)),
+    )?;
+
+    let truncated_repositories: BTreeSet<RepositoryId> =
+        [&authored_issues, &authored_pull_requests, &assigned]
+            .iter()
+            .flat_map(|result| result.cursors.iter())
+            .map(|cursor| cursor.repository_id.clone())
+            .collect();
+
+    let mut activity = UserActivity {
+        username: username.to_string(),
+        repository_count: repos.len(),
+        per_repository_limit,
+        authored_open_issues: Vec::new(),
+        authored_closed_issues: Vec::new(),
+        authored_open_pull_requests: Vec::new(),
+        authored_merged_pull_requests: Vec::new(),
+        assigned_open_items: Vec::new(),
+        truncated_repositories: truncated_repositories.into_iter().collect(),
+    };
+
+    for result in by_most_recent_update(authored_issues.results) {
+        if let IssueOrPullrequest::Issue(issue) = &result {
+            match issue.state {
+                IssueState::Open => activity.authored_open_issues.push(activity_item(&result)),
+                IssueState::Closed => activity.authored_closed_issues.push(activity_item(&result)),
+            }
+        }
+    }
+    for result in by_most_recent_update(authored_pull_requests.results) {
+        if let IssueOrPullrequest::PullRequest(pull_request) = &result {
+            match pull_request.state {
+                PullRequestState::Open => activity
+                    .authored_open_pull_requests
+                    .push(activity_item(&result)),
+                PullRequestState::Merged => activity
+                    .authored_merged_pull_requests
+                    .push(activity_item(&result)),
+                PullRequestState::Closed => {}
+            }
+        }
+    }
+    activity.assigned_open_items = by_most_recent_update(assigned.results)
+        .iter()
+        .map(activity_item)
+        .collect();
+
+    Ok(activity)
+}
+
+fn by_most_recent_update(mut results: Vec<IssueOrPullrequest>) -> Vec<IssueOrPullrequest> {
+    results.sort_by_key(|result| std::cmp::Reverse(result.updated_at()));
+    results
+}
+
+fn activity_item(result: &IssueOrPullrequest) -> UserActivityItem {
+    match result {
+        IssueOrPullrequest::Issue(issue) => UserActivityItem {
+            title: issue.title.clone(),
+            url: issue.issue_id.url(),
+        },
+        IssueOrPullrequest::PullRequest(pull_request) => UserActivityItem {
+            title: pull_request.title.clone(),
+            url: pull_request.pull_request_id.url(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ProfileName;
+
+    #[test]
+    fn test_apply_profile_search_defaults() {
+        let mut profile = ProfileInfo::new(ProfileName::from("work"), None);
+        profile.default_search_query = Some("label:bug".to_string());
+        profile.default_search_limit = Some(50);
+
+        assert_eq!(
+            apply_profile_search_defaults(Some(&profile), None, None),
+            (Some("label:bug".to_string()), Some(50))
+        );
+        assert_eq!(
+            apply_profile_search_defaults(Some(&profile), Some("is:pr".to_string()), Some(0)),
+            (Some("is:pr".to_string()), Some(50))
+        );
+        assert_eq!(
+            apply_profile_search_defaults(Some(&profile), None, Some(10)),
+            (Some("label:bug".to_string()), Some(10))
+        );
+
+        // Without profile defaults a limit of 0 is kept for clamp_search_limit to report
+        let profile = ProfileInfo::new(ProfileName::from("work"), None);
+        assert_eq!(
+            apply_profile_search_defaults(Some(&profile), None, Some(0)),
+            (None, Some(0))
+        );
+        assert_eq!(
+            apply_profile_search_defaults(None, None, Some(0)),
+            (None, Some(0))
+        );
+    }
+
+    #[test]
+    fn test_clamp_search_limit() {
+        assert_eq!(clamp_search_limit(None), (DEFAULT_SEARCH_LIMIT, None));
+        assert_eq!(clamp_search_limit(Some(50)), (50, None));
+        assert_eq!(clamp_search_limit(Some(100)), (100, None));
+
+        let (limit, note) = clamp_search_limit(Some(0));
+        assert_eq!(limit, 30);
+        assert!(note.unwrap().contains("default limit of 30"));
+
+        let (limit, note) = clamp_search_limit(Some(500));
+        assert_eq!(limit, 100);
+        assert!(
+            note.unwrap()
+                .contains("limit 500 exceeds the maximum of 100")
+        );
+    }
+}
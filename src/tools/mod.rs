@@ -10,8 +10,11 @@
 //! - Find related resources through cross-references and semantic similarity
 //! - Support for multiple filtering options and hybrid search
 
-use crate::formatter::TimezoneOffset;
-use crate::types::{ProfileName, SearchCursorByRepository};
+use crate::formatter::{RenderOptions, TimezoneOffset};
+use crate::types::{
+    DEFAULT_SEARCH_LIMIT, DEFAULT_SEARCH_QUERY, ProfileName, SearchCursorByProject,
+    SearchCursorByRepository,
+};
 use anyhow::Result;
 use rmcp::{Error as McpError, ServerHandler, model::*, tool};
 
@@ -33,9 +36,6 @@ pub struct GitInsightTools {
     timezone: Option<TimezoneOffset>,
 }
 
-const DEFAULT_SEARCH_LIMIT: usize = 30;
-const DEFAULT_SEARCH_QUERY: &str = "state:open";
-
 fn default_search_limit() -> usize {
     DEFAULT_SEARCH_LIMIT
 }
@@ -101,56 +101,277 @@ impl GitInsightTools {
         )]
         #[schemars(default)]
         output_option: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional deduplication of items that wrap the same issue or pull request across projects (raw/keep_first/merge, default: raw). keep_first keeps the first item; merge also adds custom field values of the collapsed items. The number of collapsed items is reported."
+        )]
+        #[schemars(default)]
+        deduplicate: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional custom field filters in 'Field=Value' form. Only items matching every filter are returned; field names are case-insensitive and items without a filtered field are excluded. Examples: ['Status=In Progress'], ['Status=Todo', 'Priority=High']"
+        )]
+        #[schemars(default)]
+        field_filters: Option<Vec<String>>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional maximum number of items to fetch per project (before field filters). Large boards stop paging once reached and the result notes that more items remain, followed by 'Next page cursors'. Examples: 100, 500"
+        )]
+        #[schemars(default)]
+        max_items: Option<usize>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional cursors by project to resume fetching projects stopped by max_items. Pass back the 'Next page cursors' of the previous response; projects without a cursor start from the first item."
+        )]
+        #[schemars(default)]
+        cursors: Option<Vec<SearchCursorByProject>>,
     ) -> Result<CallToolResult, McpError> {
         tools_interface::get_project_resources::get_project_resources(
             &self.github_token,
             &self.timezone,
             project_urls,
             output_option,
+            deduplicate,
+            field_filters,
+            max_items,
+            cursors,
         )
         .await
     }
 
     #[tool(
-        description = "Get issues by their numbers from specified repositories. Returns detailed issue information including comments, formatted as markdown with comprehensive details including title, body, labels, assignees, creation/update dates, and all comments with timestamps."
+        description = "Get issues by their numbers from specified repositories. Returns detailed issue information including comments, formatted as markdown with comprehensive details including title, body, labels, assignees, creation/update dates, reaction counts, and comments with timestamps. Use comment_skip and comment_limit to page through issues with many comments. Issues from archived repositories are preceded by a warning."
     )]
+    #[allow(clippy::too_many_arguments)]
     async fn get_issues_details(
         &self,
         #[tool(param)]
         #[schemars(
-            description = "Issue URLs to fetch. Examples: ['https://github.com/rust-lang/rust/issues/12345', 'https://github.com/tokio-rs/tokio/issues/5678']. To get issue URLs from repositories in the current profile, use list_repository_urls_in_current_profile to get repository URLs and pass them to this parameter."
+            description = "Issue URLs to fetch. Examples: ['https://github.com/rust-lang/rust/issues/12345', 'https://github.com/tokio-rs/tokio/issues/5678']. At most 50 URLs per call (configurable with GITHUB_INSIGHT_MAX_URLS_PER_CALL); split larger lists into batches. To get issue URLs from repositories in the current profile, use list_repository_urls_in_current_profile to get repository URLs and pass them to this parameter."
         )]
         issue_urls: Vec<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional number of comments to skip from the beginning of each issue. If not specified, starts from the first comment (comment_skip=0)."
+        )]
+        #[schemars(default)]
+        comment_skip: Option<u32>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional maximum number of comments to render per issue. If not specified, renders all comments from the comment_skip position to the end. A footer notes how many comments were omitted."
+        )]
+        #[schemars(default)]
+        comment_limit: Option<u32>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional number of comments fetched from GitHub with each issue (max 100, larger values are clamped). Lower values make large batches faster; comments beyond this limit are not available to comment_skip/comment_limit. Default: 100"
+        )]
+        #[schemars(default)]
+        inline_comment_limit: Option<u32>,
+        #[tool(param)]
+        #[schemars(
+            description = "Render timestamps relative to now, e.g. '3 days ago' or 'in 2 hours' (default: false, absolute timestamps in the configured timezone)."
+        )]
+        #[schemars(default)]
+        relative_time: Option<bool>,
+        #[tool(param)]
+        #[schemars(
+            description = "Hide comments authored by bot accounts such as CI or dependency bots (default: false). The number of hidden comments is noted in the output."
+        )]
+        #[schemars(default)]
+        exclude_bot_comments: Option<bool>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional response format (markdown/json, default: markdown). json returns an array of the issues, including their comments narrowed by comment_skip, comment_limit and exclude_bot_comments as pretty-printed JSON for programmatic use instead of markdown."
+        )]
+        #[schemars(default)]
+        response_format: Option<String>,
     ) -> Result<CallToolResult, McpError> {
         tools_interface::get_issues_details::get_issues_details(
+            &self.github_token,
+            &self.timezone,
+            &RenderOptions {
+                relative_time: relative_time.unwrap_or(false),
+                ..Default::default()
+            },
+            issue_urls,
+            comment_skip,
+            comment_limit,
+            inline_comment_limit,
+            exclude_bot_comments.unwrap_or(false),
+            response_format,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Get a single issue by its URL as structured JSON: the serialized issue with title, body, state, labels, assignees, created/updated/closed timestamps, comment count, and comments. A convenience over get_issues_details for single-item workflows; pull request URLs are rejected with a pointer to get_pull_request_details."
+    )]
+    async fn get_issue(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Issue URL to fetch. Example: 'https://github.com/rust-lang/rust/issues/12345'"
+        )]
+        issue_url: String,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::get_issue::get_issue(&self.github_token, issue_url).await
+    }
+
+    #[tool(
+        description = "Get the cross-reference timeline of issues: the issues and pull requests that referenced each issue or were manually linked to it, with event type (cross-referenced/connected/disconnected), timestamp, title, and URL. Pull requests that will close the issue when merged are marked '(will close)'. Issues without such events are reported as having no timeline events."
+    )]
+    async fn get_issue_timeline(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Issue URLs to fetch timelines for. Examples: ['https://github.com/rust-lang/rust/issues/12345']"
+        )]
+        issue_urls: Vec<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional output format (light/rich, default: light). Light lists date, event type, title, and URL; rich adds full timestamps, the state of each linked resource, and a section of pull requests that will close the issue."
+        )]
+        #[schemars(default)]
+        output_option: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::get_issue_timeline::get_issue_timeline(
             &self.github_token,
             &self.timezone,
             issue_urls,
+            output_option,
         )
         .await
     }
 
     #[tool(
-        description = "Get pull requests by their URLs from specified repositories. Returns detailed pull request information including comments, formatted as markdown with comprehensive details including title, body, labels, assignees, creation/update dates, review status, and all comments with timestamps."
+        description = "Resolve and summarize all links in an issue thread. Extracts links from the issue body and comments and returns a categorized summary: linked issues and pull requests with titles and states, commits with headlines and authors, file permalinks with line ranges, other GitHub links, and external links (not fetched). Useful to gather the context of a discussion in a single call."
+    )]
+    async fn summarize_issue_links(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Issue URL. Example: 'https://github.com/rust-lang/rust/issues/12345'"
+        )]
+        issue_url: String,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::summarize_issue_links::summarize_issue_links(&self.github_token, issue_url)
+            .await
+    }
+
+    #[tool(
+        description = "Get pull requests by their URLs from specified repositories. Returns detailed pull request information including comments, formatted as markdown with comprehensive details including title, body, labels, assignees, creation/update dates, review status, reaction counts, and all comments with timestamps. Pull requests from archived repositories are preceded by a warning."
     )]
     async fn get_pull_request_details(
         &self,
         #[tool(param)]
         #[schemars(
-            description = "Pull request URLs to fetch. Examples: ['https://github.com/rust-lang/rust/pull/98765', 'https://github.com/tokio-rs/tokio/pull/4321']. To get pull request URLs from repositories in the current profile, use list_repository_urls_in_current_profile to get repository URLs and pass them to this parameter."
+            description = "Pull request URLs to fetch. Examples: ['https://github.com/rust-lang/rust/pull/98765', 'https://github.com/tokio-rs/tokio/pull/4321']. At most 50 URLs per call (configurable with GITHUB_INSIGHT_MAX_URLS_PER_CALL); split larger lists into batches. To get pull request URLs from repositories in the current profile, use list_repository_urls_in_current_profile to get repository URLs and pass them to this parameter."
         )]
         pull_request_urls: Vec<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Render timestamps relative to now, e.g. '3 days ago' or 'in 2 hours' (default: false, absolute timestamps in the configured timezone)."
+        )]
+        #[schemars(default)]
+        relative_time: Option<bool>,
+        #[tool(param)]
+        #[schemars(
+            description = "Hide comments authored by bot accounts such as CI or dependency bots (default: false). The number of hidden comments is noted in the output."
+        )]
+        #[schemars(default)]
+        exclude_bot_comments: Option<bool>,
     ) -> Result<CallToolResult, McpError> {
         tools_interface::get_pull_request_details::get_pull_request_details(
             &self.github_token,
             &self.timezone,
+            &RenderOptions {
+                relative_time: relative_time.unwrap_or(false),
+                ..Default::default()
+            },
+            pull_request_urls,
+            exclude_bot_comments.unwrap_or(false),
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Get issues and pull requests from a mixed list of URLs in one call. Each URL is classified as an issue or pull request, both kinds are fetched in batches grouped by repository, and the results are returned in input order with the same details as get_issues_details and get_pull_request_details. URLs that cannot be parsed or fetched are listed in a trailing 'Errors' section instead of failing the call."
+    )]
+    async fn get_resources_details(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Issue and pull request URLs in any order. Example: ['https://github.com/rust-lang/rust/issues/12345', 'https://github.com/tokio-rs/tokio/pull/4321']"
+        )]
+        urls: Vec<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::get_resources_details::get_resources_details(
+            &self.github_token,
+            &self.timezone,
+            urls,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Get the reviews of pull requests by their URLs. Returns, for each pull request, the number of approvals and every review with its submission time, state (APPROVED, CHANGES_REQUESTED, COMMENTED, DISMISSED, PENDING), reviewer login, and body. Use this to count approvals or see who requested changes without fetching full pull request details."
+    )]
+    async fn get_pull_request_reviews(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Pull request URLs to fetch reviews for. Examples: ['https://github.com/rust-lang/rust/pull/98765', 'https://github.com/tokio-rs/tokio/pull/4321']"
+        )]
+        pull_request_urls: Vec<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::get_pull_request_reviews::get_pull_request_reviews(
+            &self.github_token,
+            &self.timezone,
+            pull_request_urls,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Get the commits of pull requests by their URLs. Returns, for each pull request, every commit (all pages) with its short SHA, the first line of its message, author, and authored date, without the diff. Use this to review the commit history of a pull request."
+    )]
+    async fn get_pull_request_commits(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Pull request URLs to fetch commits for. Examples: ['https://github.com/rust-lang/rust/pull/98765', 'https://github.com/tokio-rs/tokio/pull/4321']"
+        )]
+        pull_request_urls: Vec<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::get_pull_request_commits::get_pull_request_commits(
+            &self.github_token,
+            &self.timezone,
+            pull_request_urls,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Get the CI checks of pull requests by their URLs. Returns, for each pull request, the combined check state of its head commit and a markdown checklist of every check run and commit status with a pass/fail symbol, conclusion (success/failure/pending/neutral/skipped), and a link to its details. Pull requests without CI configured are reported as having no checks."
+    )]
+    async fn get_pull_request_checks(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Pull request URLs to fetch checks for. Examples: ['https://github.com/rust-lang/rust/pull/98765', 'https://github.com/tokio-rs/tokio/pull/4321']"
+        )]
+        pull_request_urls: Vec<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::get_pull_request_checks::get_pull_request_checks(
+            &self.github_token,
             pull_request_urls,
         )
         .await
     }
 
     #[tool(
-        description = "Get pull request file statistics by their URLs. Returns file-level change statistics (additions, deletions, changes) for each pull request without the actual diff content. Use this for quick overview of changed files and their modification counts."
+        description = "Get pull request file statistics by their URLs. Returns file-level change statistics (additions, deletions, changes) for each pull request without the actual diff content, preceded by a summary of total files changed, additions, deletions, and the top 5 files by churn. Use this for quick overview of changed files and their modification counts."
     )]
     async fn get_pull_request_code_diff_stats(
         &self,
@@ -168,8 +389,9 @@ impl GitInsightTools {
     }
 
     #[tool(
-        description = "Get the diff content of a specific file from a pull request. Returns the unified diff patch for the specified file. Optionally supports line range filtering to get specific portions of the diff."
+        description = "Get the diff content of a specific file from a pull request. Returns the unified diff patch for the specified file. Optionally supports line range filtering to get specific portions of the diff, or hunk_index to get one complete hunk. With stat_only, returns a git 'diff --stat' style overview of every file in the pull request instead."
     )]
+    #[allow(clippy::too_many_arguments)]
     async fn get_pull_request_diff_contents(
         &self,
         #[tool(param)]
@@ -179,9 +401,10 @@ impl GitInsightTools {
         pull_request_url: String,
         #[tool(param)]
         #[schemars(
-            description = "File path within the repository. Example: 'src/main.rs', 'README.md'"
+            description = "File path within the repository. Example: 'src/main.rs', 'README.md'. Required unless stat_only is set."
         )]
-        file_path: String,
+        #[schemars(default)]
+        file_path: Option<String>,
         #[tool(param)]
         #[schemars(
             description = "Optional number of lines to skip from the beginning of the diff. If not specified, starts from the first line (skip=0)."
@@ -194,6 +417,24 @@ impl GitInsightTools {
         )]
         #[schemars(default)]
         limit: Option<u32>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional safety cap on the number of returned lines (default: 1000). Longer diffs are truncated with a notice. Likely generated files such as lockfiles and minified assets are summarized to their change stats unless skip or limit is specified."
+        )]
+        #[schemars(default)]
+        max_lines: Option<u32>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional 0-based index of a diff hunk. Returns the complete '@@ ... @@' hunk at that index, including its header, so the returned diff stays valid. Cannot be combined with skip or limit; an out-of-range index returns an error with the number of hunks."
+        )]
+        #[schemars(default)]
+        hunk_index: Option<u32>,
+        #[tool(param)]
+        #[schemars(
+            description = "Return a 'diff --stat' style overview of the whole pull request instead of one file's patch: one aligned 'path | +N -M' line with a +/- histogram per file, and a total (default: false). file_path, skip, limit, max_lines and hunk_index are ignored."
+        )]
+        #[schemars(default)]
+        stat_only: Option<bool>,
     ) -> Result<CallToolResult, McpError> {
         tools_interface::get_pull_request_diff_contents::get_pull_request_diff_contents(
             &self.github_token,
@@ -201,12 +442,65 @@ impl GitInsightTools {
             file_path,
             skip,
             limit,
+            max_lines,
+            hunk_index,
+            stat_only.unwrap_or(false),
+        )
+        .await
+    }
+
+    #[tool(
+        description = "List open pull requests of a repository filtered by review state, draft status, author, and requested reviewer. Returns a light markdown table with PR number, title, author, draft flag, review decision, requested reviewers, and last update. Useful for review queues such as 'approved and ready to merge' or 'awaiting first review'."
+    )]
+    async fn list_pull_requests(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL to list pull requests from. Example: 'https://github.com/rust-lang/rust'"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional review state filter: 'approved', 'changes_requested', 'required', or 'none' (no reviews yet)."
+        )]
+        #[schemars(default)]
+        review_state: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional draft filter. true lists only draft pull requests, false lists only pull requests ready for review."
+        )]
+        #[schemars(default)]
+        draft: Option<bool>,
+        #[tool(param)]
+        #[schemars(description = "Optional author login filter. Example: 'octocat'")]
+        #[schemars(default)]
+        author: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional login of a user whose review is requested. Example: 'octocat'"
+        )]
+        #[schemars(default)]
+        reviewer: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Maximum number of pull requests (default 30, max 100)")]
+        #[schemars(default)]
+        limit: Option<usize>,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::list_pull_requests::list_pull_requests(
+            &self.github_token,
+            &self.timezone,
+            repository_url,
+            review_state,
+            draft,
+            author,
+            reviewer,
+            limit,
         )
         .await
     }
 
     #[tool(
-        description = "Get repository details by URLs. Returns detailed repository information formatted as markdown with comprehensive metadata including URL, description, default branch, mentionable users, labels, milestones, releases (with configurable limit), and timestamps."
+        description = "Get repository details by URLs. Returns detailed repository information formatted as markdown with comprehensive metadata including URL, pinned issues, description, topics, default branch, mentionable users, labels (optionally in their colors), milestones, releases (with configurable limit), and timestamps. Archived repositories are marked with an ARCHIVED badge."
     )]
     async fn get_repository_details(
         &self,
@@ -227,6 +521,24 @@ impl GitInsightTools {
         )]
         #[schemars(default)]
         showing_milestone_limit: Option<usize>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional flag to render each label in its GitHub color as an inline HTML span (default: false). Labels without a color are rendered as plain text."
+        )]
+        #[schemars(default)]
+        show_label_colors: Option<bool>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional flag to append a 'Languages' section with the share of each language in percent, largest first (default: false). Costs one extra API request per repository."
+        )]
+        #[schemars(default)]
+        show_language_breakdown: Option<bool>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional response format (markdown/json, default: markdown). json returns an array of the repositories as pretty-printed JSON for programmatic use instead of markdown; with show_language_breakdown each repository carries a 'languages' list of byte counts."
+        )]
+        #[schemars(default)]
+        response_format: Option<String>,
     ) -> Result<CallToolResult, McpError> {
         tools_interface::get_repository_details::get_repository_details(
             &self.github_token,
@@ -234,91 +546,523 @@ impl GitInsightTools {
             repository_urls,
             showing_release_limit,
             showing_milestone_limit,
+            show_label_colors,
+            show_language_breakdown,
+            response_format,
         )
         .await
     }
 
     #[tool(
-        description = "Get project details by their URLs. Returns detailed project information formatted as markdown with comprehensive metadata including title, description, creation/update dates, project node ID, and other project properties. The project node ID can be used for project updates."
+        description = "Get pinned issues of repositories by URLs. Returns the issues maintainers have pinned (up to 3 per repository) formatted as light markdown with title, status, URL, and truncated body. Use get_issues_details to get full details including comments."
     )]
-    async fn get_project_details(
+    async fn get_pinned_issues(
         &self,
         #[tool(param)]
         #[schemars(
-            description = "Project URLs to fetch. Examples: ['https://github.com/users/username/projects/1', 'https://github.com/orgs/orgname/projects/5']. To get project URLs from the current profile, use list_project_urls_in_current_profile to get project URLs and pass them to this parameter."
+            description = "Repository URLs to fetch pinned issues from. Examples: ['https://github.com/rust-lang/rust', 'https://github.com/tokio-rs/tokio']. To get repository URLs from the current profile, use list_repository_urls_in_current_profile to get repository URLs and pass them to this parameter."
         )]
-        project_urls: Vec<String>,
+        repository_urls: Vec<String>,
     ) -> Result<CallToolResult, McpError> {
-        tools_interface::get_project_details::get_project_details(
+        tools_interface::get_pinned_issues::get_pinned_issues(
             &self.github_token,
             &self.timezone,
-            project_urls,
+            repository_urls,
         )
         .await
     }
 
     #[tool(
-        description = "Search for issues, PRs, and projects across multiple repositories. The 'github_search_query' parameter is optional and defaults to open issues and PRs. When 'repository_urls' is provided, searches in those repositories. Comprehensive search across multiple resource types. Use get_issues_details and get_pull_request_details functions to get more detailed information. Note: Pagination with cursors is currently disabled - results are returned in a single response."
+        description = "Get the issues of a repository milestone. Returns the issues in the requested state (default: open) formatted as light markdown with title, status, URL, and truncated body. Useful for tracking milestone completion; milestone numbers are listed by get_repository_details. A milestone that does not exist returns a notice instead of an error."
     )]
-    async fn search_in_repositories(
+    async fn get_milestone_issues(
         &self,
         #[tool(param)]
         #[schemars(
-            description = "Search query text (optional, default: open issues and PRs). Supports GitHub search syntax. Examples: 'is:pr state:open', 'is:issue label:bug', 'authentication error', 'head:feature-branch', 'is:pr author:username', 'is:issue assignee:username', 'created:2024-01-01..2024-12-31'. Note: Any repo:owner/name specifications in the query will be overridden when searching specific repositories. IMPORTANT: To search both issues and PRs, use space-separated qualifiers like 'is:issue is:pr' (NOT 'is:issue OR is:pr' - explicit OR operator is not supported in GitHub search API)."
-        )]
-        #[schemars(default = "default_search_query")]
-        github_search_query: Option<String>,
-        #[tool(param)]
-        #[schemars(
-            description = "Repository URLs to search in (e.g., ['https://github.com/owner/repo1', 'https://github.com/owner/repo2']). To search repositories from the current profile, use list_repository_urls_in_current_profile to get repository URLs and pass them to this parameter."
-        )]
-        repository_urls: Vec<String>,
-        #[tool(param)]
-        #[schemars(
-            description = "Result limit per repository (default 30, max 100). Examples: 10, 50"
+            description = "Repository URL the milestone belongs to. Example: 'https://github.com/rust-lang/rust'"
         )]
-        #[schemars(default = "default_search_limit")]
-        limit: Option<usize>,
+        repository_url: String,
         #[tool(param)]
-        #[schemars(
-            description = "Optional search cursors by repository for pagination. Each cursor is associated with a specific repository. Example: [{'cursor': 'Y3Vyc29yOjE=', 'repository_id': {'owner': 'rust-lang', 'repository_name': 'rust'}}]"
-        )]
-        cursors: Option<Vec<SearchCursorByRepository>>,
+        #[schemars(description = "Milestone number. Example: 42")]
+        milestone_number: u64,
         #[tool(param)]
-        #[schemars(
-            description = "Optional output format for search results (light/rich, default: light). Light format provides minimal information (title, status, URL, assignees/author, truncated body up to 100 chars, comment count, linked resources), rich format provides comprehensive details (full body, all comments, timestamps, labels, etc.)."
-        )]
+        #[schemars(description = "Optional issue state filter: 'open' (default) or 'closed'")]
         #[schemars(default)]
-        output_option: Option<String>,
+        state: Option<String>,
     ) -> Result<CallToolResult, McpError> {
-        tools_interface::search_in_repositories::search_in_repositories(
+        tools_interface::get_milestone_issues::get_milestone_issues(
             &self.github_token,
             &self.timezone,
-            github_search_query,
-            repository_urls,
-            limit,
-            cursors,
-            output_option,
+            repository_url,
+            milestone_number,
+            state,
         )
         .await
     }
 
     #[tool(
-        description = "List all repository URLs registered in the current profile. Returns an array of repository URLs for repositories managed by the profile. Example return value: [\"https://github.com/rust-lang/rust\", \"https://github.com/tokio-rs/tokio\"]"
+        description = "Get project details by their URLs. Returns detailed project information formatted as markdown with comprehensive metadata including title, description, creation/update dates, project node ID, and other project properties. The project node ID can be used for project updates."
     )]
-    async fn list_repository_urls_in_current_profile(&self) -> Result<CallToolResult, McpError> {
-        tools_interface::list_repository_urls_in_current_profile::list_repository_urls_in_current_profile(
-            &self.profile_name,
+    async fn get_project_details(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Project URLs to fetch. Examples: ['https://github.com/users/username/projects/1', 'https://github.com/orgs/orgname/projects/5']. To get project URLs from the current profile, use list_project_urls_in_current_profile to get project URLs and pass them to this parameter."
+        )]
+        project_urls: Vec<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::get_project_details::get_project_details(
+            &self.github_token,
+            &self.timezone,
+            project_urls,
         )
         .await
     }
 
     #[tool(
-        description = "List all project URLs registered in the current profile. Returns an array of project URLs for projects managed by the profile. Example return value: [\"https://github.com/users/username/projects/1\", \"https://github.com/orgs/orgname/projects/5\"]"
+        description = "Get the repositories a project draws its items from. Returns the distinct source repositories of the project's issues and pull requests with item counts per repository, sorted by item count descending. Draft issues are counted separately. Useful to understand the scope of a cross-repository project."
     )]
-    async fn list_project_urls_in_current_profile(&self) -> Result<CallToolResult, McpError> {
-        tools_interface::list_project_urls_in_current_profile::list_project_urls_in_current_profile(
+    async fn get_project_repositories(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Project URL. Example: 'https://github.com/users/username/projects/1'. To get project URLs from the current profile, use list_project_urls_in_current_profile."
+        )]
+        project_url: String,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::get_project_repositories::get_project_repositories(
+            &self.github_token,
+            project_url,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Get the custom field definitions of a project. Returns every field with its field ID, name, and data type (e.g. TEXT, NUMBER, DATE, SINGLE_SELECT, ITERATION), plus the options and option IDs of single select fields, formatted as a markdown table. Use the IDs to update project field values."
+    )]
+    async fn get_project_field_definitions(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Project URL. Example: 'https://github.com/orgs/orgname/projects/5'. To get project URLs from the current profile, use list_project_urls_in_current_profile."
+        )]
+        project_url: String,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::get_project_field_definitions::get_project_field_definitions(
+            &self.github_token,
+            project_url,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Get a roadmap of a project. Items are grouped into time buckets by an iteration field (one bucket per iteration) or a date field (one bucket per month), ordered by start date and rendered as markdown. Items without a value for the field are listed under 'Unscheduled'. Use get_project_field_definitions to find iteration and date fields."
+    )]
+    async fn get_project_roadmap(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Project URL. Example: 'https://github.com/orgs/orgname/projects/5'. To get project URLs from the current profile, use list_project_urls_in_current_profile."
+        )]
+        project_url: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Name of the iteration or date field to group items by (case-insensitive). Examples: 'Sprint', 'Iteration', 'Target Date'"
+        )]
+        group_by_field: String,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::get_project_roadmap::get_project_roadmap(
+            &self.github_token,
+            project_url,
+            group_by_field,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Set the value of a custom field of a project item. Supports text, number, date, and single select fields. This is a write operation and requires an authenticated GitHub token. Use get_project_field_definitions for field IDs and option IDs, and get_project_resources for item IDs."
+    )]
+    async fn set_project_field_value(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Project URL. Example: 'https://github.com/orgs/orgname/projects/5'"
+        )]
+        project_url: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Project item ID as shown by get_project_resources. Example: 'PVTI_lADOBq6Ed84A'"
+        )]
+        item_id: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Field ID as shown by get_project_field_definitions. Example: 'PVTSSF_lADOBq6Ed84A'"
+        )]
+        field_id: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Type of the value: 'text', 'number', 'date', or 'single_select'"
+        )]
+        value_type: String,
+        #[tool(param)]
+        #[schemars(
+            description = "New value. Dates use YYYY-MM-DD; single select values are option IDs from get_project_field_definitions. Examples: 'Blocked on review', '3', '2025-03-31', 'f75ad846'"
+        )]
+        value: String,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::set_project_field_value::set_project_field_value(
+            &self.github_token,
+            project_url,
+            item_id,
+            field_id,
+            value_type,
+            value,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Search for issues, PRs, and projects across multiple repositories. The 'github_search_query' parameter is optional and defaults to open issues and PRs. When 'repository_urls' is provided, searches in those repositories. Comprehensive search across multiple resource types. Use get_issues_details and get_pull_request_details functions to get more detailed information. Note: Pagination with cursors is currently disabled - results are returned in a single response."
+    )]
+    #[allow(clippy::too_many_arguments)]
+    async fn search_in_repositories(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Search query text (optional, default: the current profile's default query set with set_profile_defaults, otherwise open issues and PRs). Supports GitHub search syntax. Examples: 'is:pr state:open', 'is:issue label:bug', 'authentication error', 'head:feature-branch', 'is:pr author:username', 'is:issue assignee:username', 'created:2024-01-01..2024-12-31'. Note: Any repo:owner/name specifications in the query will be overridden when searching specific repositories. IMPORTANT: To search both issues and PRs, use space-separated qualifiers like 'is:issue is:pr' (NOT 'is:issue OR is:pr' - explicit OR operator is not supported in GitHub search API)."
+        )]
+        #[schemars(default)]
+        github_search_query: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URLs to search in (e.g., ['https://github.com/owner/repo1', 'https://github.com/owner/repo2']). To search repositories from the current profile, use list_repository_urls_in_current_profile to get repository URLs and pass them to this parameter."
+        )]
+        repository_urls: Vec<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Result limit per repository (default: the current profile's default limit, otherwise 30; max 100). 0 falls back to the default and larger values are capped at 100, with a note in the response. Examples: 10, 50"
+        )]
+        #[schemars(default)]
+        limit: Option<usize>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional search cursors by repository for pagination. Each cursor is associated with a specific repository, which must be one of repository_urls. Pass back the 'Next page cursors' of the previous response. Example: [{'cursor': 'Y3Vyc29yOjE=', 'repository_id': {'owner': 'rust-lang', 'repository_name': 'rust'}}]"
+        )]
+        cursors: Option<Vec<SearchCursorByRepository>>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional output format for search results (light/rich/table, default: light). Light format provides minimal information (title, status, URL, assignees/author, labels, truncated body up to body_truncate_len chars (default 100), comment count, linked resources, and up to 10 issues/PRs mentioned in the body), rich format provides comprehensive details (full body, all comments, timestamps, labels, etc.), and table renders all results as one markdown table with number, type, state, truncated title, author, and updated time."
+        )]
+        #[schemars(default)]
+        output_option: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional sort order per repository (created_asc/created_desc/updated_asc/updated_desc/comments_asc/comments_desc). Omit for GitHub's default best-match ordering. Overrides any sort: qualifier in the query."
+        )]
+        #[schemars(default)]
+        sort: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Also search GitHub Discussions matching the query (default: false). Discussions are listed after issues and PRs with title, URL, category, answer status, author, and comment count. A query containing 'is:discussion' searches only discussions, even without this flag. Discussion results are not paginated."
+        )]
+        #[schemars(default)]
+        include_discussions: Option<bool>,
+        #[tool(param)]
+        #[schemars(
+            description = "Render timestamps relative to now, e.g. '3 days ago' or 'in 2 hours' (default: false, absolute timestamps in the configured timezone)."
+        )]
+        #[schemars(default)]
+        relative_time: Option<bool>,
+        #[tool(param)]
+        #[schemars(
+            description = "Merge the results of all repositories into a single list ordered by 'sort' (default: false, results grouped by repository). Without a sort, the most recently updated items across all repositories come first."
+        )]
+        #[schemars(default)]
+        merge_and_sort: Option<bool>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional number of body characters shown per result in light format (default: 100). 0 omits the body. Bodies are cut on character boundaries, so multibyte text is never split."
+        )]
+        #[schemars(default)]
+        body_truncate_len: Option<usize>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional response format (markdown/json, default: markdown). json returns one object with 'results', 'next_page_cursors', and (when searched) 'discussions' as pretty-printed JSON for programmatic use instead of markdown; output_option and body_truncate_len are ignored."
+        )]
+        #[schemars(default)]
+        response_format: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional labels whose results are listed first, in the order given, e.g. ['P0', 'P1']. Results carrying none of them keep their order after the prioritized ones. Labels are matched case-insensitively."
+        )]
+        #[schemars(default)]
+        priority_labels: Option<Vec<String>>,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::search_in_repositories::search_in_repositories(
+            &self.github_token,
+            &self.timezone,
+            &RenderOptions {
+                relative_time: relative_time.unwrap_or(false),
+                ..Default::default()
+            },
+            &self.profile_name,
+            github_search_query,
+            repository_urls,
+            limit,
+            cursors,
+            output_option,
+            sort,
+            include_discussions,
+            merge_and_sort.unwrap_or(false),
+            body_truncate_len,
+            response_format,
+            priority_labels.unwrap_or_default(),
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Search for issues and PRs in the repositories registered in several profiles at once. Repositories of the given profiles (all profiles when 'profile_names' is empty) are collected, de-duplicated, and searched together. Results are grouped by repository under a '## owner/repo' heading, followed by next page cursors keyed by repository when more results exist."
+    )]
+    async fn search_across_profiles(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Profile names whose repositories to search. Empty searches the repositories of all profiles. Examples: ['work', 'oss'], []"
+        )]
+        #[schemars(default)]
+        profile_names: Vec<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Search query text (optional, default: open issues and PRs). Supports GitHub search syntax. Examples: 'is:pr state:open', 'is:issue label:bug', 'authentication error'. Any repo:owner/name specifications in the query are overridden."
+        )]
+        #[schemars(default = "default_search_query")]
+        github_search_query: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Result limit per repository (default 30, max 100). 0 falls back to the default and larger values are capped at 100, with a note in the response. Examples: 10, 50"
+        )]
+        #[schemars(default = "default_search_limit")]
+        limit: Option<usize>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional search cursors by repository for pagination, as returned by a previous call. Example: [{'cursor': 'Y3Vyc29yOjE=', 'repository_id': {'owner': 'rust-lang', 'repository_name': 'rust'}}]"
+        )]
+        cursors: Option<Vec<SearchCursorByRepository>>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional output format for search results (light/rich/table, default: light). Table renders the results of each repository as one compact markdown table."
+        )]
+        #[schemars(default)]
+        output_option: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional sort order per repository (created_asc/created_desc/updated_asc/updated_desc/comments_asc/comments_desc). Omit for GitHub's default best-match ordering."
+        )]
+        #[schemars(default)]
+        sort: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::search_across_profiles::search_across_profiles(
+            &self.github_token,
+            &self.timezone,
+            profile_names,
+            github_search_query,
+            limit,
+            cursors,
+            output_option,
+            sort,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Summarize a user's activity across the repositories registered in a profile: counts and links of the issues they authored (open/closed), the pull requests they authored (open/merged), and the open issues and PRs assigned to them. Results are capped per repository and the response notes which repositories were truncated."
+    )]
+    async fn get_user_activity(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "GitHub username, or '@me' for the authenticated user (requires a GitHub token). Examples: 'octocat', '@me'"
+        )]
+        username: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional profile name whose repositories to summarize (default: the current profile). Example: 'work'"
+        )]
+        #[schemars(default)]
+        profile_name: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Maximum results per repository for each search (default 30, max 100). Repositories with more matches are listed as truncated. Examples: 10, 50"
+        )]
+        #[schemars(default = "default_search_limit")]
+        limit_per_repository: Option<usize>,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::get_user_activity::get_user_activity(
+            &self.github_token,
+            &self.profile_name,
+            username,
+            profile_name,
+            limit_per_repository,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Check that the repositories and projects registered in a profile are still reachable. Each registered resource is fetched and the report groups the URLs into 'Valid', 'Not Found' (deleted or renamed), and 'Access Denied' sections; other failures such as network errors are listed separately. The profile is not modified."
+    )]
+    async fn validate_profile(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional profile name to validate (default: the current profile). Example: 'work'"
+        )]
+        #[schemars(default)]
+        profile_name: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::validate_profile::validate_profile(
+            &self.github_token,
+            &self.profile_name,
+            profile_name,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Store a default search query and result limit in a profile. search_in_repositories uses them when the caller omits 'github_search_query' or 'limit', before falling back to open issues and PRs with a limit of 30. Omitted parameters keep their current value; an empty query or a limit of 0 clears the stored default.\n\nOutput: Returns the profile name and its resulting defaults as JSON."
+    )]
+    async fn set_profile_defaults(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional profile name to update (default: the current profile). Example: 'work'"
+        )]
+        #[schemars(default)]
+        profile_name: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional default search query. Empty string clears it. Examples: 'is:pr state:open', 'is:issue label:bug'"
+        )]
+        #[schemars(default)]
+        search_query: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional default result limit per repository (max 100). 0 clears it. Examples: 10, 50"
+        )]
+        #[schemars(default)]
+        search_limit: Option<usize>,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::set_profile_defaults::set_profile_defaults(
             &self.profile_name,
+            profile_name,
+            search_query,
+            search_limit,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "List all repository URLs registered in the current profile. Returns an array of repository URLs for repositories managed by the profile. Example return value: [\"https://github.com/rust-lang/rust\", \"https://github.com/tokio-rs/tokio\"]"
+    )]
+    async fn list_repository_urls_in_current_profile(&self) -> Result<CallToolResult, McpError> {
+        tools_interface::list_repository_urls_in_current_profile::list_repository_urls_in_current_profile(
+            &self.profile_name,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "List all project URLs registered in the current profile. Returns an array of project URLs for projects managed by the profile. Example return value: [\"https://github.com/users/username/projects/1\", \"https://github.com/orgs/orgname/projects/5\"]"
+    )]
+    async fn list_project_urls_in_current_profile(&self) -> Result<CallToolResult, McpError> {
+        tools_interface::list_project_urls_in_current_profile::list_project_urls_in_current_profile(
+            &self.profile_name,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Get the GitHub GraphQL API rate limit status of the configured token. Returns remaining and used points, the hourly limit, the cost of the status query, and when the budget resets (in the configured timezone). Check this before large searches or project fetches to avoid throttling."
+    )]
+    async fn get_rate_limit_status(&self) -> Result<CallToolResult, McpError> {
+        tools_interface::get_rate_limit_status::get_rate_limit_status(
+            &self.github_token,
+            &self.timezone,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Check whether the configured GitHub token is valid. Returns the login of the authenticated user and the OAuth scopes granted to the token (when GitHub reports them), states explicitly when no token is configured, or reports that GitHub rejected the token. Use this to diagnose authentication errors from other tools."
+    )]
+    async fn auth_check(&self) -> Result<CallToolResult, McpError> {
+        tools_interface::auth_check::auth_check(&self.github_token).await
+    }
+
+    #[tool(
+        description = "Get the top contributors of a repository by URL. Returns a markdown table ranked by contribution count (commits to the default branch) with login, contribution count, and avatar URL."
+    )]
+    async fn get_repository_contributors(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL to list contributors of. Example: 'https://github.com/rust-lang/rust'"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Maximum number of contributors (default 10). Examples: 5, 25")]
+        #[schemars(default)]
+        limit: Option<usize>,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::get_repository_contributors::get_repository_contributors(
+            &self.github_token,
+            repository_url,
+            limit,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Get issue and pull request counts of repositories without listing them. Returns a short markdown summary per repository with open/closed issue counts, open/merged/closed pull request counts, stargazers, forks, and the last push time (in the configured timezone). Much cheaper than enumerating issues; use it for dashboards and quick health checks."
+    )]
+    async fn get_repository_stats(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URLs to get counts for. Example: ['https://github.com/rust-lang/rust', 'https://github.com/tokio-rs/tokio']"
+        )]
+        repository_urls: Vec<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::get_repository_stats::get_repository_stats(
+            &self.github_token,
+            &self.timezone,
+            repository_urls,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Get the latest releases of a repository by URL with their assets. Returns markdown with the tag, name, published date, and pre-release flag of each release, and a table of assets with name, size, download count, and download URL."
+    )]
+    async fn get_repository_releases(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL to list releases of. Example: 'https://github.com/rust-lang/rust'"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Maximum number of releases, newest first (default 10). Examples: 3, 50"
+        )]
+        #[schemars(default)]
+        limit: Option<usize>,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::get_repository_releases::get_repository_releases(
+            &self.github_token,
+            &self.timezone,
+            repository_url,
+            limit,
         )
         .await
     }
@@ -340,7 +1084,7 @@ impl GitInsightTools {
         group_name: Option<String>,
         #[tool(param)]
         #[schemars(
-            description = "Branch specifiers in format 'repo_url@branch'. Examples: ['https://github.com/owner/repo@main', 'https://github.com/owner/repo@develop']"
+            description = "Branch specifiers in format 'repo_url@branch', split on the last '@'; branch names may contain '/'. 'repo_url@' or a bare 'repo_url' tracks the repository's default branch. Examples: ['https://github.com/owner/repo@main', 'https://github.com/owner/repo@feature/x']"
         )]
         pairs: Vec<String>,
         #[tool(param)]
@@ -367,10 +1111,173 @@ impl GitInsightTools {
         #[schemars(description = "Profile name containing the group. Example: 'default', 'work'")]
         profile_name: String,
         #[tool(param)]
-        #[schemars(description = "Group name to remove. Example: 'feature-branch-group'")]
+        #[schemars(description = "Group name to remove. Example: 'feature-branch-group'")]
+        group_name: String,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::repository_branch_group::unregister_repository_branch_group(
+            profile_name,
+            group_name,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Add branches to an existing group. Allows expanding group membership by adding new branches.\n\nEach branch specifier follows the format 'repository_url@branch_name'. Multiple branches can be added in a single operation.\n\nOutput: Returns success confirmation message upon completion."
+    )]
+    async fn add_branch_to_branch_group(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "Profile name containing the group. Example: 'default'")]
+        profile_name: String,
+        #[tool(param)]
+        #[schemars(description = "Group name to add branches to. Example: 'feature-branch-group'")]
+        group_name: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URLs and their branches in format 'repo_url@branch' ('repo_url@' for the default branch). Examples: ['https://github.com/owner/repo@feature-x']"
+        )]
+        branch_specifiers: Vec<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::repository_branch_group::add_branch_to_branch_group(
+            profile_name,
+            group_name,
+            branch_specifiers,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Add branches matching a name pattern to an existing group. Branches are resolved from the repositories registered in the profile, so a branch such as 'main' can be added across all of them at once.\n\nThe pattern is a glob matched against the whole branch name ('*' matches any characters, '?' a single character), or a regular expression when wrapped in slashes (e.g. '/^release-\\d+$/'). Branches already in the group are skipped.\n\nOutput: Returns a JSON array of the newly added branches, each containing:\n- repository_id: Repository owner and name\n- branch: Branch name"
+    )]
+    async fn add_branches_by_pattern(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Profile name containing the group and the repositories. Example: 'default'"
+        )]
+        profile_name: String,
+        #[tool(param)]
+        #[schemars(description = "Group name to add branches to. Example: 'release-branches'")]
+        group_name: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Branch name glob, or regex wrapped in slashes. Examples: 'main', 'release-*', '/^hotfix-\\d+$/'"
+        )]
+        branch_pattern: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional text a repository URL must contain to be searched (case-insensitive). Example: 'github.com/tokio-rs/'"
+        )]
+        #[schemars(default)]
+        repository_filter: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::repository_branch_group::add_branches_by_pattern(
+            &self.github_token,
+            profile_name,
+            group_name,
+            branch_pattern,
+            repository_filter,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Remove branches from a group. Allows reducing group membership by removing specific branches.\n\nEach branch specifier follows the format 'repository_url@branch_name'. Multiple branches can be removed in a single operation.\n\nOutput: Returns success confirmation message upon completion."
+    )]
+    async fn remove_branch_from_branch_group(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "Profile name containing the group. Example: 'default'")]
+        profile_name: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Group name to remove branches from. Example: 'feature-branch-group'"
+        )]
+        group_name: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URLs and their branches in format 'repo_url@branch'. Examples: ['https://github.com/owner/repo@old-feature']"
+        )]
+        branch_specifiers: Vec<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::repository_branch_group::remove_branch_from_branch_group(
+            profile_name,
+            group_name,
+            branch_specifiers,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Rename a repository branch group. Changes the group's identifier while preserving all branches and metadata.\n\nOutput: Returns success confirmation message upon completion."
+    )]
+    async fn rename_repository_branch_group(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "Profile name containing the group. Example: 'default'")]
+        profile_name: String,
+        #[tool(param)]
+        #[schemars(description = "Current group name. Example: 'old-group-name'")]
+        old_name: String,
+        #[tool(param)]
+        #[schemars(description = "New group name. Example: 'new-group-name'")]
+        new_name: String,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::repository_branch_group::rename_repository_branch_group(
+            profile_name,
+            old_name,
+            new_name,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "List all repository branch groups in a profile. Shows all groups available for management operations.\n\nRepository branch groups organize collections of branches, enabling batch operations across multiple repositories and branches.\n\nOutput: Returns formatted markdown list showing:\n- Profile name\n- All group names in the profile\n- Message if no groups exist"
+    )]
+    async fn show_repository_branch_groups(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "Profile name to list groups from. Example: 'default', 'work'")]
+        profile_name: String,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::repository_branch_group::show_repository_branch_groups(profile_name).await
+    }
+
+    #[tool(
+        description = "List repository branch groups of all profiles in one table. Useful for finding groups when you don't remember which profile they were registered in.\n\nOutput: Returns a markdown table sorted by profile then group name with columns:\n- Profile: Profile name\n- Group: Group name ('-' for profiles without groups)\n- Branches: Number of branches in the group\n- Created: Group creation timestamp"
+    )]
+    async fn list_branch_groups_across_profiles(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Omit profiles that have no repository branch groups (default: false)"
+        )]
+        #[schemars(default)]
+        hide_empty: Option<bool>,
+    ) -> Result<CallToolResult, McpError> {
+        tools_interface::repository_branch_group::list_branch_groups_across_profiles(
+            &self.timezone,
+            hide_empty,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Show details of a specific repository branch group. Returns comprehensive information about the group and all its branches.\n\nRepository branch groups contain collections of branches. Each branch is a repository URL paired with a specific branch name. This allows for organized management of related branches across multiple repositories.\n\nOutput: Returns formatted markdown with:\n- Group name and creation timestamp\n- List of all branches in format 'repository_url | branch:branch_name'\n- Each branch shows the full GitHub repository URL and the associated branch name"
+    )]
+    async fn get_repository_branch_group(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "Profile name containing the group. Example: 'default'")]
+        profile_name: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Group name to show details for. Example: 'feature-branch-group'"
+        )]
         group_name: String,
     ) -> Result<CallToolResult, McpError> {
-        tools_interface::repository_branch_group::unregister_repository_branch_group(
+        tools_interface::repository_branch_group::get_repository_branch_group(
+            &self.timezone,
             profile_name,
             group_name,
         )
@@ -378,112 +1285,102 @@ impl GitInsightTools {
     }
 
     #[tool(
-        description = "Add branches to an existing group. Allows expanding group membership by adding new branches.\n\nEach branch specifier follows the format 'repository_url@branch_name'. Multiple branches can be added in a single operation.\n\nOutput: Returns success confirmation message upon completion."
+        description = "Show the live state of every branch in a repository branch group. For each branch, fetches whether it still exists, its latest commit SHA and commit date, and how many commits it is ahead of and behind the repository's default branch. Branches are fetched concurrently.\n\nOutput: Returns a markdown table with columns Repository, Branch, Status (ok/missing/error), Latest commit, Committed, Base (default branch), Ahead, and Behind. Deleted branches are marked 'missing'."
     )]
-    async fn add_branch_to_branch_group(
+    async fn get_branch_group_status(
         &self,
         #[tool(param)]
         #[schemars(description = "Profile name containing the group. Example: 'default'")]
         profile_name: String,
         #[tool(param)]
-        #[schemars(description = "Group name to add branches to. Example: 'feature-branch-group'")]
-        group_name: String,
-        #[tool(param)]
         #[schemars(
-            description = "Repository URLs and their branches in format 'repo_url@branch'. Examples: ['https://github.com/owner/repo@feature-x']"
+            description = "Group name to show the branch status for. Example: 'feature-branch-group'"
         )]
-        branch_specifiers: Vec<String>,
+        group_name: String,
     ) -> Result<CallToolResult, McpError> {
-        tools_interface::repository_branch_group::add_branch_to_branch_group(
+        tools_interface::repository_branch_group::get_branch_group_status(
+            &self.github_token,
+            &self.timezone,
             profile_name,
             group_name,
-            branch_specifiers,
         )
         .await
     }
 
     #[tool(
-        description = "Remove branches from a group. Allows reducing group membership by removing specific branches.\n\nEach branch specifier follows the format 'repository_url@branch_name'. Multiple branches can be removed in a single operation.\n\nOutput: Returns success confirmation message upon completion."
+        description = "Generate release notes for a repository branch group. For each branch in the group, searches the pull requests merged into that branch since the given date (is:pr is:merged base:<branch> merged:>=<date>) and lists them by merge time with title, number, and author. Optionally only includes pull requests carrying one of the given labels.\n\nOutput: Returns markdown with one section per branch ('owner/repo (branch)'). Branches without merged pull requests are noted as 'No changes.'"
     )]
-    async fn remove_branch_from_branch_group(
+    async fn generate_group_release_notes(
         &self,
         #[tool(param)]
         #[schemars(description = "Profile name containing the group. Example: 'default'")]
         profile_name: String,
         #[tool(param)]
+        #[schemars(description = "Group name to generate release notes for. Example: 'release'")]
+        group_name: String,
+        #[tool(param)]
         #[schemars(
-            description = "Group name to remove branches from. Example: 'feature-branch-group'"
+            description = "Earliest merge date to include, as YYYY-MM-DD or days ago (e.g. '14d'). Examples: '2024-03-01', '30d'"
         )]
-        group_name: String,
+        since: String,
         #[tool(param)]
         #[schemars(
-            description = "Repository URLs and their branches in format 'repo_url@branch'. Examples: ['https://github.com/owner/repo@old-feature']"
+            description = "Only include pull requests with at least one of these labels (default: all merged pull requests). Example: ['feature', 'fix']"
         )]
-        branch_specifiers: Vec<String>,
+        #[schemars(default)]
+        labels: Option<Vec<String>>,
     ) -> Result<CallToolResult, McpError> {
-        tools_interface::repository_branch_group::remove_branch_from_branch_group(
+        tools_interface::repository_branch_group::generate_group_release_notes(
+            &self.github_token,
+            &self.timezone,
             profile_name,
             group_name,
-            branch_specifiers,
+            since,
+            labels,
         )
         .await
     }
 
     #[tool(
-        description = "Rename a repository branch group. Changes the group's identifier while preserving all branches and metadata.\n\nOutput: Returns success confirmation message upon completion."
+        description = "Copy a repository branch group under a new name. Useful for snapshotting a group, for example this release's branches, before editing it. The new group gets all branches and the description of the source group with a fresh creation timestamp. Fails if the destination group already exists.\n\nOutput: Returns the new group information as JSON, including:\n- name: New group name\n- pairs: Array of copied branches\n- description: Copied description\n- created_at: When the copy was created\n- updated_at: When the copy was last modified"
     )]
-    async fn rename_repository_branch_group(
+    async fn clone_branch_group(
         &self,
         #[tool(param)]
         #[schemars(description = "Profile name containing the group. Example: 'default'")]
         profile_name: String,
         #[tool(param)]
-        #[schemars(description = "Current group name. Example: 'old-group-name'")]
-        old_name: String,
+        #[schemars(description = "Name of the group to copy. Example: 'release'")]
+        source_group_name: String,
         #[tool(param)]
-        #[schemars(description = "New group name. Example: 'new-group-name'")]
-        new_name: String,
+        #[schemars(description = "Name of the new group. Example: 'release-snapshot'")]
+        destination_group_name: String,
     ) -> Result<CallToolResult, McpError> {
-        tools_interface::repository_branch_group::rename_repository_branch_group(
+        tools_interface::repository_branch_group::clone_branch_group(
             profile_name,
-            old_name,
-            new_name,
+            source_group_name,
+            destination_group_name,
         )
         .await
     }
 
     #[tool(
-        description = "List all repository branch groups in a profile. Shows all groups available for management operations.\n\nRepository branch groups organize collections of branches, enabling batch operations across multiple repositories and branches.\n\nOutput: Returns formatted markdown list showing:\n- Profile name\n- All group names in the profile\n- Message if no groups exist"
+        description = "Compare two repository branch groups of a profile. Useful for checking which branches differ between, for example, a 'release-candidate' group and a 'production' group.\n\nOutput: Returns formatted markdown with three sections:\n- Only in the first group: branches missing from the second group\n- Only in the second group: branches missing from the first group\n- Common: branches in both groups\nEach branch is listed as 'repository_url | branch:branch_name'."
     )]
-    async fn show_repository_branch_groups(
+    async fn diff_branch_groups(
         &self,
         #[tool(param)]
-        #[schemars(description = "Profile name to list groups from. Example: 'default', 'work'")]
+        #[schemars(description = "Profile name containing both groups. Example: 'default'")]
         profile_name: String,
-    ) -> Result<CallToolResult, McpError> {
-        tools_interface::repository_branch_group::show_repository_branch_groups(profile_name).await
-    }
-
-    #[tool(
-        description = "Show details of a specific repository branch group. Returns comprehensive information about the group and all its branches.\n\nRepository branch groups contain collections of branches. Each branch is a repository URL paired with a specific branch name. This allows for organized management of related branches across multiple repositories.\n\nOutput: Returns formatted markdown with:\n- Group name and creation timestamp\n- List of all branches in format 'repository_url | branch:branch_name'\n- Each branch shows the full GitHub repository URL and the associated branch name"
-    )]
-    async fn get_repository_branch_group(
-        &self,
         #[tool(param)]
-        #[schemars(description = "Profile name containing the group. Example: 'default'")]
-        profile_name: String,
+        #[schemars(description = "First group name. Example: 'release-candidate'")]
+        group_a: String,
         #[tool(param)]
-        #[schemars(
-            description = "Group name to show details for. Example: 'feature-branch-group'"
-        )]
-        group_name: String,
+        #[schemars(description = "Second group name. Example: 'production'")]
+        group_b: String,
     ) -> Result<CallToolResult, McpError> {
-        tools_interface::repository_branch_group::get_repository_branch_group(
-            &self.timezone,
-            profile_name,
-            group_name,
-        )
-        .await
+        tools_interface::repository_branch_group::diff_branch_groups(profile_name, group_a, group_b)
+            .await
     }
 
     #[tool(
@@ -499,10 +1396,17 @@ impl GitInsightTools {
             description = "Number of days - groups older than this will be removed. Example: 30, 7"
         )]
         days: i64,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional flag to preview the cleanup (default: false). When true, returns the groups that would be removed, in the same shape as a real run, without removing anything."
+        )]
+        #[schemars(default)]
+        dry_run: Option<bool>,
     ) -> Result<CallToolResult, McpError> {
         tools_interface::repository_branch_group::cleanup_repository_branch_groups(
             profile_name,
             days,
+            dry_run,
         )
         .await
     }
@@ -541,15 +1445,31 @@ Examples:
 
 // Get resources with rich format (default)
 {{"name": "get_project_resources", "arguments": {{"output_option": "rich"}}}}
+
+// Collapse issues that appear on multiple projects, merging their field values
+{{"name": "get_project_resources", "arguments": {{"project_urls": ["https://github.com/users/username/projects/1", "https://github.com/users/username/projects/2"], "deduplicate": "merge"}}}}
+
+// Only items whose Status field is In Progress
+{{"name": "get_project_resources", "arguments": {{"project_urls": ["https://github.com/users/username/projects/1"], "field_filters": ["Status=In Progress"]}}}}
+
+// Fetch at most 200 items of a huge board
+{{"name": "get_project_resources", "arguments": {{"project_urls": ["https://github.com/orgs/orgname/projects/5"], "max_items": 200}}}}
 ```
 
 ### 2. get_issues_details
-Get issues by their URLs from specified repositories. Returns detailed issue information including comments, formatted as markdown with comprehensive details including title, body, labels, assignees, creation/update dates, and all comments with timestamps.
+Get issues by their URLs from specified repositories. Returns detailed issue information including comments, formatted as markdown with comprehensive details including title, body, labels, assignees, creation/update dates, reaction counts, and all comments with timestamps.
 
 Examples:
 ```json
 // Get specific issues by URLs
 {{"name": "get_issues_details", "arguments": {{"issue_urls": ["https://github.com/rust-lang/rust/issues/12345", "https://github.com/tokio-rs/tokio/issues/5678"]}}}}
+
+// Render only the first 20 comments of a long discussion, then the next 20
+{{"name": "get_issues_details", "arguments": {{"issue_urls": ["https://github.com/rust-lang/rust/issues/12345"], "comment_limit": 20}}}}
+{{"name": "get_issues_details", "arguments": {{"issue_urls": ["https://github.com/rust-lang/rust/issues/12345"], "comment_skip": 20, "comment_limit": 20}}}}
+
+// Fetch at most the first 5 comments of each issue to speed up large batches
+{{"name": "get_issues_details", "arguments": {{"issue_urls": ["https://github.com/rust-lang/rust/issues/12345", "https://github.com/tokio-rs/tokio/issues/5678"], "inline_comment_limit": 5}}}}
 ```
 
 ### 3. get_pull_request_details
@@ -562,7 +1482,7 @@ Examples:
 ```
 
 ### 4. get_pull_request_code_diff_stats
-Get pull request file statistics by their URLs. Returns file-level change statistics (additions, deletions, changes) for each pull request without the actual diff content. Use this for quick overview of changed files and their modification counts.
+Get pull request file statistics by their URLs. Returns file-level change statistics (additions, deletions, changes) for each pull request without the actual diff content, preceded by a summary of total files changed, additions, deletions, and the top 5 files by churn. Use this for quick overview of changed files and their modification counts.
 
 Examples:
 ```json
@@ -571,7 +1491,7 @@ Examples:
 ```
 
 ### 5. get_pull_request_diff_contents
-Get the diff content of a specific file from a pull request. Returns the unified diff patch for the specified file. Supports optional skip/limit filtering to retrieve specific portions of the diff.
+Get the diff content of a specific file from a pull request. Returns the unified diff patch for the specified file. Supports optional skip/limit filtering to retrieve specific portions of the diff, or `hunk_index` to retrieve one complete `@@ ... @@` hunk. Output longer than `max_lines` (default: 1000) is truncated with a notice, and likely generated files (lockfiles, minified assets) are summarized to their change stats unless skip, limit, or hunk_index is specified.
 
 Examples:
 ```json
@@ -580,6 +1500,15 @@ Examples:
 
 // Get diff with skip and limit (skip first 10 lines, return next 40 lines)
 {{"name": "get_pull_request_diff_contents", "arguments": {{"pull_request_url": "https://github.com/rust-lang/rust/pull/98765", "file_path": "src/lib.rs", "skip": 10, "limit": 40}}}}
+
+// Raise the safety cap for a large hand-written file
+{{"name": "get_pull_request_diff_contents", "arguments": {{"pull_request_url": "https://github.com/rust-lang/rust/pull/98765", "file_path": "src/parser.rs", "max_lines": 5000}}}}
+
+// Get the second hunk of a file, header included
+{{"name": "get_pull_request_diff_contents", "arguments": {{"pull_request_url": "https://github.com/rust-lang/rust/pull/98765", "file_path": "src/lib.rs", "hunk_index": 1}}}}
+
+// Overview of every changed file, like `git diff --stat` (file_path not needed)
+{{"name": "get_pull_request_diff_contents", "arguments": {{"pull_request_url": "https://github.com/rust-lang/rust/pull/98765", "stat_only": true}}}}
 ```
 
 ### 6. get_project_details
@@ -604,6 +1533,12 @@ Examples:
 
 // Get repository details with custom release limit
 {{"name": "get_repository_details", "arguments": {{"repository_urls": ["https://github.com/rust-lang/rust"], "showing_release_limit": 5}}}}
+
+// Render labels in their GitHub colors
+{{"name": "get_repository_details", "arguments": {{"repository_urls": ["https://github.com/rust-lang/rust"], "show_label_colors": true}}}}
+
+// Include the language breakdown in percent
+{{"name": "get_repository_details", "arguments": {{"repository_urls": ["https://github.com/rust-lang/rust"], "show_language_breakdown": true}}}}
 ```
 
 ### 8. search_in_repositories
@@ -625,12 +1560,48 @@ Examples:
     "limit": 20
 }}}}
 
+// Compact table for scanning many results
+{{"name": "search_in_repositories", "arguments": {{
+    "github_search_query": "is:issue state:open",
+    "repository_urls": ["https://github.com/tokio-rs/tokio"],
+    "output_option": "table",
+    "limit": 100
+}}}}
+
+// Most recently updated first
+{{"name": "search_in_repositories", "arguments": {{
+    "github_search_query": "is:issue state:open",
+    "repository_urls": ["https://github.com/tokio-rs/tokio"],
+    "sort": "updated_desc"
+}}}}
+
+// Most recently updated items across all repositories as one list
+{{"name": "search_in_repositories", "arguments": {{
+    "repository_urls": ["https://github.com/tokio-rs/tokio", "https://github.com/tokio-rs/axum"],
+    "merge_and_sort": true
+}}}}
+
+// Priority-labeled items first, in the order of the labels
+{{"name": "search_in_repositories", "arguments": {{
+    "repository_urls": ["https://github.com/tokio-rs/tokio"],
+    "priority_labels": ["P0", "P1"]
+}}}}
+
 // Search with pagination cursors
 {{"name": "search_in_repositories", "arguments": {{
     "github_search_query": "performance",
     "repository_urls": ["https://github.com/rust-lang/rust"],
     "cursors": [{{"repository_id": {{"owner": "rust-lang", "repository_name": "rust"}}, "cursor": "Y3Vyc29yOnYyOpK5"}}]
 }}}}
+
+// Search discussions only (use include_discussions: true to search them alongside issues and PRs)
+{{"name": "search_in_repositories", "arguments": {{
+    "github_search_query": "is:discussion proxy configuration",
+    "repository_urls": ["https://github.com/tokio-rs/tokio"]
+}}}}
+
+// Structured JSON instead of markdown (also on get_issues_details and get_repository_details)
+{{"name": "search_in_repositories", "arguments": {{"repository_urls": ["https://github.com/tokio-rs/tokio"], "response_format": "json"}}}}
 ```
 
 ### 9. list_repository_urls_in_current_profile
@@ -728,6 +1699,304 @@ Examples:
 ```json
 // Clean up groups older than 30 days
 {{"name": "cleanup_repository_branch_groups", "arguments": {{"profile_name": "default", "days": 30}}}}
+
+// Preview which groups a 30 day cleanup would remove
+{{"name": "cleanup_repository_branch_groups", "arguments": {{"profile_name": "default", "days": 30, "dry_run": true}}}}
+```
+
+### 19. get_pinned_issues
+Get pinned issues of repositories by URLs. Returns the issues maintainers have pinned (up to 3 per repository) in light format.
+
+Examples:
+```json
+// Get pinned issues of a repository
+{{"name": "get_pinned_issues", "arguments": {{"repository_urls": ["https://github.com/rust-lang/rust"]}}}}
+```
+
+### 20. list_pull_requests
+List open pull requests of a repository filtered by review state (approved, changes_requested, required, none), draft status, author, and requested reviewer. Returns a light markdown table.
+
+Examples:
+```json
+// Approved pull requests ready to merge
+{{"name": "list_pull_requests", "arguments": {{"repository_url": "https://github.com/rust-lang/rust", "review_state": "approved", "draft": false}}}}
+
+// Pull requests awaiting my first review
+{{"name": "list_pull_requests", "arguments": {{"repository_url": "https://github.com/rust-lang/rust", "review_state": "none", "reviewer": "octocat"}}}}
+```
+
+### 21. get_project_repositories
+Get the distinct repositories a project draws its items from, with item counts per repository sorted by count descending. Draft issues are counted separately.
+
+Examples:
+```json
+{{"name": "get_project_repositories", "arguments": {{"project_url": "https://github.com/orgs/orgname/projects/5"}}}}
+```
+
+### 22. summarize_issue_links
+Resolve and summarize all links in an issue thread. Linked issues, pull requests, and commits are fetched for their titles; file permalinks are shown with their line ranges; other GitHub links and external links are listed without fetching.
+
+Examples:
+```json
+{{"name": "summarize_issue_links", "arguments": {{"issue_url": "https://github.com/rust-lang/rust/issues/12345"}}}}
+```
+
+### 23. get_issue_timeline
+Get the cross-reference timeline of issues: issues and pull requests that referenced each issue or were manually linked to it, with event type and timestamp. Pull requests that will close the issue when merged are marked.
+
+Examples:
+```json
+// Which pull requests will close this issue?
+{{"name": "get_issue_timeline", "arguments": {{"issue_urls": ["https://github.com/rust-lang/rust/issues/12345"]}}}}
+
+// Rich format with states of linked resources
+{{"name": "get_issue_timeline", "arguments": {{"issue_urls": ["https://github.com/rust-lang/rust/issues/12345"], "output_option": "rich"}}}}
+```
+
+### 24. list_branch_groups_across_profiles
+List repository branch groups of all profiles as a table with profile, group name, branch count, and creation timestamp.
+
+Examples:
+```json
+// Overview of groups in every profile
+{{"name": "list_branch_groups_across_profiles", "arguments": {{}}}}
+
+// Only profiles that have groups
+{{"name": "list_branch_groups_across_profiles", "arguments": {{"hide_empty": true}}}}
+```
+
+### 25. get_pull_request_reviews
+Get the reviews of pull requests by URLs. Returns the number of approvals and each review's state, reviewer, submission time, and body.
+
+Examples:
+```json
+// Count approvals of a pull request
+{{"name": "get_pull_request_reviews", "arguments": {{"pull_request_urls": ["https://github.com/rust-lang/rust/pull/98765"]}}}}
+```
+
+### 26. get_rate_limit_status
+Get the GraphQL API rate limit status of the configured token: remaining and used points, the hourly limit, and the reset time.
+
+Examples:
+```json
+// Check the remaining budget before a large search
+{{"name": "get_rate_limit_status", "arguments": {{}}}}
+```
+
+### 27. get_repository_contributors
+Get the top contributors of a repository ranked by contribution count, with login, contribution count, and avatar URL.
+
+Examples:
+```json
+// Top 5 contributors
+{{"name": "get_repository_contributors", "arguments": {{"repository_url": "https://github.com/rust-lang/rust", "limit": 5}}}}
+```
+
+### 28. get_milestone_issues
+Get the issues of a repository milestone in the requested state (default: open). A milestone that does not exist returns a notice instead of an error.
+
+Examples:
+```json
+// Open issues left in a milestone
+{{"name": "get_milestone_issues", "arguments": {{"repository_url": "https://github.com/rust-lang/rust", "milestone_number": 42}}}}
+
+// Issues already closed in the milestone
+{{"name": "get_milestone_issues", "arguments": {{"repository_url": "https://github.com/rust-lang/rust", "milestone_number": 42, "state": "closed"}}}}
+```
+
+### 29. add_branches_by_pattern
+Add branches matching a glob (or a regex wrapped in slashes) from the repositories registered in the profile to an existing group. Branches already in the group are skipped.
+
+Examples:
+```json
+// Add 'main' of every registered repository
+{{"name": "add_branches_by_pattern", "arguments": {{"profile_name": "default", "group_name": "release", "branch_pattern": "main"}}}}
+
+// Add release branches of one owner's repositories
+{{"name": "add_branches_by_pattern", "arguments": {{"profile_name": "default", "group_name": "release", "branch_pattern": "release-*", "repository_filter": "github.com/owner/"}}}}
+```
+
+### 30. search_across_profiles
+Search issues and PRs in the repositories of several profiles at once (all profiles when `profile_names` is empty). Repositories registered in more than one profile are searched once, and results are grouped by repository.
+
+Examples:
+```json
+// Open bugs in every registered repository
+{{"name": "search_across_profiles", "arguments": {{"github_search_query": "is:issue label:bug"}}}}
+
+// Recently updated PRs of two profiles
+{{"name": "search_across_profiles", "arguments": {{"profile_names": ["work", "oss"], "github_search_query": "is:pr", "sort": "updated_desc", "limit": 10}}}}
+```
+
+### 31. get_project_field_definitions
+Get the custom field definitions of a project: field IDs, names, data types, and the option IDs of single select fields. Works for both user and organization projects.
+
+Examples:
+```json
+// Fields of an organization project
+{{"name": "get_project_field_definitions", "arguments": {{"project_url": "https://github.com/orgs/orgname/projects/5"}}}}
+```
+
+### 32. validate_profile
+Check that the repositories and projects registered in a profile can still be fetched. The report groups registered URLs into "Valid", "Not Found", and "Access Denied" sections; other failures are listed under "Failed". The profile is not modified.
+
+Examples:
+```json
+// Validate the current profile
+{{"name": "validate_profile", "arguments": {{}}}}
+
+// Validate another profile
+{{"name": "validate_profile", "arguments": {{"profile_name": "work"}}}}
+```
+
+### 33. get_pull_request_commits
+Get the commits of pull requests by URLs: short SHA, first line of the message, author, and authored date of every commit, without the diff.
+
+Examples:
+```json
+// Commit history of a pull request
+{{"name": "get_pull_request_commits", "arguments": {{"pull_request_urls": ["https://github.com/rust-lang/rust/pull/98765"]}}}}
+```
+
+### 34. diff_branch_groups
+Compare two repository branch groups of a profile. Returns the branches only in the first group, only in the second group, and common to both.
+
+Examples:
+```json
+// Compare a release candidate group with the production group
+{{"name": "diff_branch_groups", "arguments": {{"profile_name": "default", "group_a": "release-candidate", "group_b": "production"}}}}
+```
+
+### 35. clone_branch_group
+Copy a repository branch group under a new name, keeping its branches and description. Fails if the destination group already exists.
+
+Examples:
+```json
+// Snapshot the release group before editing it
+{{"name": "clone_branch_group", "arguments": {{"profile_name": "default", "source_group_name": "release", "destination_group_name": "release-snapshot"}}}}
+```
+
+### 36. get_repository_releases
+Get the latest releases of a repository, newest first, with the tag, name, published date, pre-release flag, and assets (name, size, download count, download URL) of each release.
+
+Examples:
+```json
+// Latest 3 releases with their downloadable assets
+{{"name": "get_repository_releases", "arguments": {{"repository_url": "https://github.com/rust-lang/rust", "limit": 3}}}}
+```
+
+### 37. set_project_field_value
+Set a text, number, date, or single select field of a project item. Requires an authenticated GitHub token. Look up field IDs and option IDs with get_project_field_definitions and item IDs with get_project_resources.
+
+Examples:
+```json
+// Move an item to the "Done" column (single select option ID)
+{{"name": "set_project_field_value", "arguments": {{"project_url": "https://github.com/orgs/orgname/projects/5", "item_id": "PVTI_lADOBq6Ed84A", "field_id": "PVTSSF_lADOBq6Ed84A", "value_type": "single_select", "value": "f75ad846"}}}}
+
+// Set a due date
+{{"name": "set_project_field_value", "arguments": {{"project_url": "https://github.com/orgs/orgname/projects/5", "item_id": "PVTI_lADOBq6Ed84A", "field_id": "PVTF_lADOBq6Ed84A", "value_type": "date", "value": "2025-03-31"}}}}
+```
+
+### 38. get_user_activity
+Summarize what a user authored or is assigned to across the repositories of a profile (default: the current profile): open/closed issues, open/merged pull requests, and assigned open items, with links. Results are capped per repository and truncated repositories are noted. `@me` refers to the authenticated user and requires a GitHub token.
+
+Examples:
+```json
+// Activity of a team member in the current profile
+{{"name": "get_user_activity", "arguments": {{"username": "octocat"}}}}
+
+// Your own activity in the "work" profile
+{{"name": "get_user_activity", "arguments": {{"username": "@me", "profile_name": "work", "limit_per_repository": 50}}}}
+```
+
+### 39. get_resources_details
+Get issues and pull requests from a mixed list of URLs without splitting them between get_issues_details and get_pull_request_details. Results follow the input order; URLs that cannot be parsed or fetched are listed in a trailing "Errors" section.
+
+Examples:
+```json
+// Issues and pull requests referenced in a discussion
+{{"name": "get_resources_details", "arguments": {{"urls": ["https://github.com/rust-lang/rust/issues/12345", "https://github.com/rust-lang/rust/pull/98765"]}}}}
+```
+
+### 40. get_project_roadmap
+Group the items of a project into a roadmap by an iteration field (one section per iteration) or a date field (one section per month), ordered by start date. Items without a value for the field are listed under "Unscheduled".
+
+Examples:
+```json
+// Roadmap by sprint
+{{"name": "get_project_roadmap", "arguments": {{"project_url": "https://github.com/orgs/orgname/projects/5", "group_by_field": "Sprint"}}}}
+
+// Roadmap by month of a date field
+{{"name": "get_project_roadmap", "arguments": {{"project_url": "https://github.com/orgs/orgname/projects/5", "group_by_field": "Target Date"}}}}
+```
+
+### 41. set_profile_defaults
+Store a default search query and limit in a profile (default: the current profile). search_in_repositories uses them when its query or limit is omitted; the built-in defaults (open issues and PRs, 30 results) apply only when neither is set. An empty query or a limit of 0 clears the stored value.
+
+Examples:
+```json
+// Search open pull requests by default
+{{"name": "set_profile_defaults", "arguments": {{"search_query": "is:pr state:open", "search_limit": 50}}}}
+
+// Clear the default query of the "work" profile
+{{"name": "set_profile_defaults", "arguments": {{"profile_name": "work", "search_query": ""}}}}
+```
+
+### 42. get_branch_group_status
+Show the live state of each branch in a repository branch group: whether it still exists, its latest commit and commit date, and how far it is ahead of and behind the repository's default branch. Deleted branches are marked "missing".
+
+Examples:
+```json
+// Check which branches of a release group are stale
+{{"name": "get_branch_group_status", "arguments": {{"profile_name": "default", "group_name": "release-candidates"}}}}
+```
+
+### 43. auth_check
+Check the configured GitHub token: report the authenticated login and the token's OAuth scopes, say explicitly that no token is configured, or report that GitHub rejected the token. Scopes are not reported for fine-grained tokens.
+
+Examples:
+```json
+// Verify the token before running other tools
+{{"name": "auth_check", "arguments": {{}}}}
+```
+
+### 44. generate_group_release_notes
+Generate release notes for a repository branch group: the pull requests merged into each branch since a date (YYYY-MM-DD or days ago such as "14d"), listed by merge time with title, number, and author. An optional label list keeps only pull requests carrying one of the labels. Branches without merged pull requests are noted as "No changes."
+
+Examples:
+```json
+// Release notes for the last two weeks
+{{"name": "generate_group_release_notes", "arguments": {{"profile_name": "default", "group_name": "release", "since": "14d"}}}}
+
+// Only features and fixes merged since a date
+{{"name": "generate_group_release_notes", "arguments": {{"profile_name": "default", "group_name": "release", "since": "2024-03-01", "labels": ["feature", "fix"]}}}}
+```
+
+### 45. get_repository_stats
+Get open/closed issue counts, open/merged/closed pull request counts, stargazers, forks, and the last push time of repositories. Only total counts are fetched, so this is much cheaper than listing issues.
+
+Examples:
+```json
+// Quick health check of two repositories
+{{"name": "get_repository_stats", "arguments": {{"repository_urls": ["https://github.com/rust-lang/rust", "https://github.com/tokio-rs/tokio"]}}}}
+```
+
+### 46. get_pull_request_checks
+Get the CI checks of the head commit of pull requests: the combined state and a checklist of every check run and commit status with its conclusion and details link. Pull requests without CI report "no checks".
+
+Examples:
+```json
+// Is CI green on this pull request?
+{{"name": "get_pull_request_checks", "arguments": {{"pull_request_urls": ["https://github.com/rust-lang/rust/pull/98765"]}}}}
+```
+
+### 47. get_issue
+Get a single issue as JSON (the serialized issue: title, body, state, labels, assignees, timestamps, comment count, and comments). Use get_issues_details for several issues or markdown output; a pull request URL is rejected in favor of get_pull_request_details.
+
+Examples:
+```json
+// Structured data of one issue
+{{"name": "get_issue", "arguments": {{"issue_url": "https://github.com/rust-lang/rust/issues/12345"}}}}
 ```
 
 ## Common Workflows
@@ -735,29 +2004,58 @@ Examples:
 1. **Profile Management**:
    - Use list_repository_urls_in_current_profile to get all repository URLs registered in the current profile
    - Use list_project_urls_in_current_profile to get all project URLs registered in the current profile
+   - Use validate_profile to find registrations of deleted, renamed, or inaccessible repositories and projects
+   - Use set_profile_defaults to store the query and limit search_in_repositories uses when they are omitted
 
 2. **Repository Search**:
    - Use search_in_repositories to find issues/PRs by keywords across specific repositories
    - Support for pagination using cursors for large result sets
-   - Choose between light and rich output formats
+   - Choose between light and rich output formats, or table for a compact overview of many results
+   - Use search_across_profiles to search the repositories of several profiles without collecting their URLs first
+   - Use get_user_activity for a per-person summary of authored and assigned issues and PRs
+   - Use get_rate_limit_status to check the remaining API budget before large searches
+   - Use auth_check to confirm the token is valid when requests fail with authentication errors
 
 3. **Specific Resource Access**:
    - Use get_issues_details to get detailed issue information with comments
+   - Use get_issue to get one issue as structured JSON
+   - Use summarize_issue_links to gather everything an issue discussion links to
+   - Use get_resources_details to fetch a mixed list of issue and pull request URLs in input order
+   - Use get_issue_timeline to trace which pull requests reference or will close an issue
    - Use get_pull_request_details to get detailed pull request information with comments and code review threads
+   - Use get_pull_request_reviews to count approvals and see review states per reviewer
+   - Use get_pull_request_commits to list the commits of a pull request without its diff
+   - Use get_pull_request_checks to see whether CI passes on a pull request before reviewing it
    - Use get_pull_request_code_diff_stats to get file-level change statistics (additions, deletions, changes) without diff content
    - Use get_pull_request_diff_contents to get the actual diff content for specific files in pull requests, with optional line range filtering
+   - Use get_pinned_issues to see the issues maintainers consider most important
+   - Use get_milestone_issues to track the open and closed issues of a release milestone
+   - Use get_repository_contributors to see who contributes most to a repository
+   - Use get_repository_stats for open/closed issue and pull request counts without listing them
+   - Use get_repository_releases to find release assets and their download counts
+   - Use list_pull_requests to build review queues filtered by review state, draft status, author, or reviewer
 
 4. **Project Management**:
    - Use get_project_resources to access project boards and associated resources
    - Fetch from all projects in profile or specific project URLs
+   - Use get_project_repositories to see which repositories feed a project
+   - Use get_project_field_definitions to look up field IDs and single select option IDs
+   - Use get_project_roadmap to see items grouped by iteration or by month of a date field
+   - Use set_project_field_value with those IDs to update a field of a project item (requires a token)
    - Choose between light and rich output formats (default: rich)
 
 5. **Repository Branch Group Management**:
    - Use register_repository_branch_group to create groups of branches
    - Use show_repository_branch_groups to see all groups in a profile
+   - Use list_branch_groups_across_profiles to find groups without knowing their profile
    - Use get_repository_branch_group to get detailed information about a specific group
+   - Use get_branch_group_status to see which branches of a group are missing, ahead, or behind their default branch
+   - Use generate_group_release_notes to collect the pull requests merged into a group's branches since a date
    - Use add_branch_to_branch_group and remove_branch_from_branch_group to modify group membership
+   - Use add_branches_by_pattern to add a branch such as 'main' across all registered repositories at once
    - Use rename_repository_branch_group to change group names
+   - Use clone_branch_group to snapshot a group before editing it
+   - Use diff_branch_groups to see which branches differ between two groups
    - Use cleanup_repository_branch_groups to remove old temporary groups
 
 6. **Output Formatting**:
@@ -0,0 +1,26 @@
+use crate::formatter::auth_status_markdown;
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+/// Check whether the configured token is valid
+///
+/// Reports the authenticated login and token scopes, or explains that no token is
+/// configured or that GitHub rejected it.
+pub async fn auth_check(github_token: &Option<String>) -> Result<CallToolResult, McpError> {
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    let status = functions::auth::check_auth(&github_client)
+        .await
+        .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+
+    let formatted = auth_status_markdown(&status);
+
+    Ok(CallToolResult {
+        content: vec![Content::text(formatted.0)],
+        is_error: Some(false),
+    })
+}
@@ -0,0 +1,74 @@
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::{IssueId, IssueUrl, PullRequestId, PullRequestUrl};
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+use super::json_content;
+
+/// Get a single issue by its URL as structured JSON
+///
+/// A thin convenience over `get_issues_details` for single-item workflows: the
+/// issue is returned as the serialized `Issue`, with title, body, state, labels,
+/// assignees, timestamps, comment count, and comments. A pull request URL is
+/// rejected with a pointer to `get_pull_request_details`.
+pub async fn get_issue(
+    github_token: &Option<String>,
+    issue_url: String,
+) -> Result<CallToolResult, McpError> {
+    let issue_id = parse_issue_url(&issue_url)?;
+
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    let issue = functions::issue::get_issue(&github_client, issue_id)
+        .await
+        .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?
+        .ok_or_else(|| {
+            McpError::invalid_params(
+                format!("Issue not found or inaccessible: {}", issue_url),
+                None,
+            )
+        })?;
+
+    Ok(CallToolResult {
+        content: vec![json_content(&issue)?],
+        is_error: Some(false),
+    })
+}
+
+/// Parse an issue URL, explaining which tool to use when it is a pull request URL
+fn parse_issue_url(issue_url: &str) -> Result<IssueId, McpError> {
+    IssueId::parse_url(&IssueUrl(issue_url.to_string())).map_err(|e| {
+        if PullRequestId::parse_url(&PullRequestUrl(issue_url.to_string())).is_ok() {
+            McpError::invalid_params(
+                format!(
+                    "'{}' is a pull request URL, not an issue URL. Use get_pull_request_details to fetch pull requests.",
+                    issue_url
+                ),
+                None,
+            )
+        } else {
+            McpError::invalid_params(format!("Invalid issue URL '{}': {}", issue_url, e), None)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_issue_url() {
+        let issue_id = parse_issue_url("https://github.com/owner/repo/issues/42").unwrap();
+        assert_eq!(issue_id.number, 42);
+
+        let error = parse_issue_url("https://github.com/owner/repo/pull/42").unwrap_err();
+        assert!(error.message.contains("is a pull request URL"));
+        assert!(error.message.contains("get_pull_request_details"));
+
+        let error = parse_issue_url("https://example.com/not-an-issue").unwrap_err();
+        assert!(error.message.contains("Invalid issue URL"));
+    }
+}
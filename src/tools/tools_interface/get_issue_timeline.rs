@@ -0,0 +1,62 @@
+use crate::formatter::{
+    TimezoneOffset,
+    issue::{issue_timeline_markdown_with_timezone, issue_timeline_markdown_with_timezone_light},
+};
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::{IssueUrl, OutputOption};
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+/// Get the cross-reference timeline events of issues by their URLs
+///
+/// Returns, for each issue, a markdown list of the issues and pull requests that
+/// referenced it or were linked to it, with event type and timestamp. Pull requests
+/// that will close the issue when merged are marked.
+pub async fn get_issue_timeline(
+    github_token: &Option<String>,
+    timezone: &Option<TimezoneOffset>,
+    issue_urls: Vec<String>,
+    output_option: Option<String>,
+) -> Result<CallToolResult, McpError> {
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    if issue_urls.is_empty() {
+        return Err(McpError::invalid_request(
+            "issue_urls cannot be empty. Please provide at least one issue URL.".to_string(),
+            None,
+        ));
+    }
+
+    let format = output_option
+        .map(|option_str| option_str.parse::<OutputOption>().unwrap_or_default())
+        .unwrap_or_default();
+
+    let issue_urls: Vec<IssueUrl> = issue_urls.into_iter().map(IssueUrl).collect();
+
+    let timelines = functions::issue::get_issue_timelines(&github_client, issue_urls)
+        .await
+        .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+
+    let content = timelines
+        .iter()
+        .map(|timeline| {
+            let formatted = match format {
+                OutputOption::Light | OutputOption::Table => {
+                    issue_timeline_markdown_with_timezone_light(timeline, timezone.as_ref())
+                }
+                OutputOption::Rich => {
+                    issue_timeline_markdown_with_timezone(timeline, timezone.as_ref())
+                }
+            };
+            Content::text(formatted.0)
+        })
+        .collect();
+
+    Ok(CallToolResult {
+        content,
+        is_error: Some(false),
+    })
+}
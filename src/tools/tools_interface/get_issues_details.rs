@@ -1,20 +1,45 @@
-use crate::formatter::{TimezoneOffset, issue::issue_body_markdown_with_timezone};
+use crate::formatter::{
+    RenderOptions, TimezoneOffset, issue::issue_body_markdown_with_comment_range,
+};
 use crate::github::GitHubClient;
 use crate::tools::functions;
-use crate::types::IssueUrl;
+use crate::types::{Issue, IssueUrl, ResponseFormat};
 use anyhow::Result;
 use rmcp::{Error as McpError, model::*};
 
+use super::{
+    archived_repository_warning, check_url_count, json_content, max_urls_per_call,
+    parse_response_format,
+};
+
 /// Get issues by their URLs from specified repositories
 ///
 /// Returns detailed issue information including comments, formatted as markdown
 /// with comprehensive details including title, body, labels, assignees,
-/// creation/update dates, and all comments with timestamps.
+/// creation/update dates, and comments with timestamps. `comment_skip` and
+/// `comment_limit` select the range of comments rendered per issue, while
+/// `inline_comment_limit` bounds how many comments are fetched in the first place.
+/// When `exclude_bot_comments` is set, comments authored by bot accounts are hidden.
+/// Calls with more than `max_urls_per_call()` URLs are rejected before any request.
+/// Issues from an archived repository are preceded by a warning.
+///
+/// With `response_format` set to json, the issues are returned as a JSON array whose
+/// comments are narrowed by the same bot filter and comment range.
+#[allow(clippy::too_many_arguments)]
 pub async fn get_issues_details(
     github_token: &Option<String>,
     timezone: &Option<TimezoneOffset>,
+    render_options: &RenderOptions,
     issue_urls: Vec<String>,
+    comment_skip: Option<u32>,
+    comment_limit: Option<u32>,
+    inline_comment_limit: Option<u32>,
+    exclude_bot_comments: bool,
+    response_format: Option<String>,
 ) -> Result<CallToolResult, McpError> {
+    let response_format = parse_response_format(response_format)?;
+    check_url_count(issue_urls.len(), max_urls_per_call(), "issue")?;
+
     let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
         McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
     })?;
@@ -23,16 +48,41 @@ pub async fn get_issues_details(
     let issue_urls: Vec<IssueUrl> = issue_urls.into_iter().map(IssueUrl).collect();
 
     // Fetch issues using the existing function
-    let issues_by_repo = functions::issue::get_issues_details(&github_client, issue_urls)
-        .await
-        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let issues_by_repo =
+        functions::issue::get_issues_details(&github_client, issue_urls, inline_comment_limit)
+            .await
+            .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+
+    if response_format == ResponseFormat::Json {
+        let issues: Vec<Issue> = issues_by_repo
+            .into_values()
+            .flatten()
+            .map(|issue| select_comments(issue, comment_skip, comment_limit, exclude_bot_comments))
+            .collect();
+        return Ok(CallToolResult {
+            content: vec![json_content(&issues)?],
+            is_error: Some(false),
+        });
+    }
 
     // Format all issues as markdown
     let mut content_vec = Vec::new();
 
-    for (_repo_id, issues) in issues_by_repo {
+    for (repo_id, issues) in issues_by_repo {
+        if issues.iter().any(|issue| issue.repository_archived) {
+            content_vec.push(Content::text(archived_repository_warning(
+                &repo_id, "issues",
+            )));
+        }
         for issue in issues {
-            let formatted = issue_body_markdown_with_timezone(&issue, timezone.as_ref());
+            let formatted = issue_body_markdown_with_comment_range(
+                &issue,
+                timezone.as_ref(),
+                comment_skip,
+                comment_limit,
+                exclude_bot_comments,
+                render_options,
+            );
             content_vec.push(Content::text(formatted.0));
         }
     }
@@ -48,3 +98,20 @@ pub async fn get_issues_details(
         is_error: Some(false),
     })
 }
+
+/// Keep only the comments the markdown output would render
+fn select_comments(
+    mut issue: Issue,
+    comment_skip: Option<u32>,
+    comment_limit: Option<u32>,
+    exclude_bot_comments: bool,
+) -> Issue {
+    issue.comments = issue
+        .comments
+        .into_iter()
+        .filter(|comment| !(exclude_bot_comments && comment.author_is_bot))
+        .skip(comment_skip.unwrap_or(0) as usize)
+        .take(comment_limit.map_or(usize::MAX, |limit| limit as usize))
+        .collect();
+    issue
+}
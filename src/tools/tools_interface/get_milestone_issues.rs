@@ -0,0 +1,62 @@
+use crate::formatter::{TimezoneOffset, issue::issue_body_markdown_with_timezone_light};
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::{IssueState, MilestoneNumber, RepositoryUrl};
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+/// Get the issues of a repository milestone
+///
+/// Returns the issues in the requested state (default: open) formatted as light
+/// markdown. A milestone that does not exist yields a notice instead of an error.
+pub async fn get_milestone_issues(
+    github_token: &Option<String>,
+    timezone: &Option<TimezoneOffset>,
+    repository_url: String,
+    milestone_number: u64,
+    state: Option<String>,
+) -> Result<CallToolResult, McpError> {
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    // Convert String to IssueState
+    let state = match state {
+        Some(state) => state.to_uppercase().parse::<IssueState>().map_err(|_| {
+            McpError::invalid_params(
+                format!("Invalid state '{}'. Expected one of: open, closed", state),
+                None,
+            )
+        })?,
+        None => IssueState::Open,
+    };
+
+    let issues = functions::issue::get_milestone_issues(
+        &github_client,
+        RepositoryUrl(repository_url),
+        MilestoneNumber::new(milestone_number),
+        state,
+    )
+    .await
+    .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+
+    let mut content_vec: Vec<Content> = issues
+        .iter()
+        .map(|issue| {
+            Content::text(issue_body_markdown_with_timezone_light(issue, timezone.as_ref()).0)
+        })
+        .collect();
+
+    if content_vec.is_empty() {
+        content_vec.push(Content::text(format!(
+            "No {} issues found in milestone {}.",
+            state.to_string().to_lowercase(),
+            milestone_number
+        )));
+    }
+
+    Ok(CallToolResult {
+        content: content_vec,
+        is_error: Some(false),
+    })
+}
@@ -0,0 +1,58 @@
+use crate::formatter::{TimezoneOffset, issue::issue_body_markdown_with_timezone_light};
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+/// Get pinned issues of repositories by URLs
+///
+/// Returns the issues maintainers have pinned (up to 3 per repository),
+/// formatted as light markdown.
+pub async fn get_pinned_issues(
+    github_token: &Option<String>,
+    timezone: &Option<TimezoneOffset>,
+    repository_urls: Vec<String>,
+) -> Result<CallToolResult, McpError> {
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    // Check if repository_urls is empty and return error
+    if repository_urls.is_empty() {
+        return Err(McpError::invalid_request(
+            "repository_urls cannot be empty. Please provide at least one repository URL."
+                .to_string(),
+            None,
+        ));
+    }
+
+    let repository_urls = repository_urls
+        .into_iter()
+        .map(crate::types::RepositoryUrl)
+        .collect::<Vec<_>>();
+
+    let issues_by_repo = functions::repository::get_pinned_issues(&github_client, repository_urls)
+        .await
+        .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+
+    // Format all pinned issues as markdown
+    let mut content_vec = Vec::new();
+
+    for (_repo_id, issues) in issues_by_repo {
+        for issue in issues {
+            let formatted = issue_body_markdown_with_timezone_light(&issue, timezone.as_ref());
+            content_vec.push(Content::text(formatted.0));
+        }
+    }
+
+    if content_vec.is_empty() {
+        content_vec.push(Content::text(
+            "No pinned issues found for the provided repositories.".to_string(),
+        ));
+    }
+
+    Ok(CallToolResult {
+        content: content_vec,
+        is_error: Some(false),
+    })
+}
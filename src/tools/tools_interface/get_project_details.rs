@@ -25,7 +25,7 @@ pub async fn get_project_details(
     // Fetch projects using the existing function
     let projects = functions::project::get_projects_details(&github_client, project_urls)
         .await
-        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+        .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
 
     // Format all projects as markdown
     let mut content_vec = Vec::new();
@@ -0,0 +1,32 @@
+use crate::formatter::project::project_field_definitions_markdown;
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::ProjectUrl;
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+/// Get the custom field definitions of a project
+///
+/// Returns every field with its id, name, and data type, plus the option ids of
+/// single select fields, formatted as a markdown table.
+pub async fn get_project_field_definitions(
+    github_token: &Option<String>,
+    project_url: String,
+) -> Result<CallToolResult, McpError> {
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    let fields = functions::project::get_project_field_definitions(
+        &github_client,
+        ProjectUrl(project_url.clone()),
+    )
+    .await?;
+
+    let formatted = project_field_definitions_markdown(&project_url, &fields);
+
+    Ok(CallToolResult {
+        content: vec![Content::text(formatted.0)],
+        is_error: Some(false),
+    })
+}
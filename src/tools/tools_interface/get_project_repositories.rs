@@ -0,0 +1,32 @@
+use crate::formatter::project::project_repositories_markdown;
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::ProjectUrl;
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+/// Get the repositories a project draws its items from
+///
+/// Returns the distinct source repositories of the project's issues and pull requests
+/// with item counts per repository, sorted by item count descending.
+pub async fn get_project_repositories(
+    github_token: &Option<String>,
+    project_url: String,
+) -> Result<CallToolResult, McpError> {
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    let project_repositories = functions::project::get_project_repositories(
+        &github_client,
+        ProjectUrl(project_url.clone()),
+    )
+    .await?;
+
+    let formatted = project_repositories_markdown(&project_url, &project_repositories);
+
+    Ok(CallToolResult {
+        content: vec![Content::text(formatted.0)],
+        is_error: Some(false),
+    })
+}
@@ -7,7 +7,10 @@ use crate::formatter::{
 };
 use crate::github::GitHubClient;
 use crate::tools::functions;
-use crate::types::{OutputOption, ProjectUrl};
+use crate::types::{
+    OutputOption, ProjectFetchLimits, ProjectResourceDeduplication, ProjectUrl,
+    SearchCursorByProject, parse_project_field_filter,
+};
 use anyhow::Result;
 use rmcp::{Error as McpError, model::*};
 
@@ -16,11 +19,21 @@ use rmcp::{Error as McpError, model::*};
 /// Returns all project resources as markdown array including title, description,
 /// resource counts, and timestamps. Each project resource includes field IDs that
 /// can be used for project field updates. This tool fetches all resources without pagination.
+/// Items wrapping the same issue or pull request across projects can be collapsed
+/// with `deduplicate` (raw, keep_first, merge). `field_filters` in `Field=Value` form
+/// narrow the result to items matching every filter. `max_items` caps the items fetched
+/// per project; capped projects are reported with a cursor that resumes them when
+/// passed back in `cursors`, so the result is not mistaken as complete.
+#[allow(clippy::too_many_arguments)]
 pub async fn get_project_resources(
     github_token: &Option<String>,
     timezone: &Option<TimezoneOffset>,
     project_urls: Vec<String>,
     output_option: Option<String>,
+    deduplicate: Option<String>,
+    field_filters: Option<Vec<String>>,
+    max_items: Option<usize>,
+    cursors: Option<Vec<SearchCursorByProject>>,
 ) -> Result<CallToolResult, McpError> {
     let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
         McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
@@ -43,6 +56,41 @@ pub async fn get_project_resources(
         OutputOption::Rich
     };
 
+    // Convert String to ProjectResourceDeduplication, defaulting to raw
+    let deduplication = deduplicate
+        .map(|mode| {
+            mode.parse::<ProjectResourceDeduplication>().map_err(|_| {
+                McpError::invalid_params(
+                    format!(
+                        "Invalid deduplicate '{}'. Expected one of: raw, keep_first, merge",
+                        mode
+                    ),
+                    None,
+                )
+            })
+        })
+        .transpose()?
+        .unwrap_or_default();
+
+    let field_filters = field_filters
+        .unwrap_or_default()
+        .iter()
+        .map(|filter| {
+            parse_project_field_filter(filter).map_err(|e| McpError::invalid_params(e, None))
+        })
+        .collect::<Result<Vec<_>, McpError>>()?;
+
+    let limits = match max_items {
+        Some(0) => {
+            return Err(McpError::invalid_params(
+                "max_items must be at least 1".to_string(),
+                None,
+            ));
+        }
+        Some(max_items) => ProjectFetchLimits::max_items(max_items),
+        None => ProjectFetchLimits::default(),
+    };
+
     let mut content_vec = Vec::new();
 
     // Convert strings to ProjectId
@@ -63,17 +111,48 @@ pub async fn get_project_resources(
     }
 
     // Fetch resources for specified projects
-    let project_resources =
-        functions::project::get_multiple_project_resources(&github_client, project_ids)
-            .await
-            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let (deduplicated, capped_projects) = functions::project::get_multiple_project_resources(
+        &github_client,
+        project_ids,
+        deduplication,
+        &field_filters,
+        limits,
+        &cursors.unwrap_or_default(),
+    )
+    .await
+    .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
 
-    for project_resource in project_resources {
+    for capped in &capped_projects {
+        content_vec.push(Content::text(format!(
+            "*Stopped after max_items for project {}; more items remain*",
+            capped.project_id
+        )));
+    }
+    if !capped_projects.is_empty() {
+        let cursors_json = serde_json::to_string_pretty(&capped_projects).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize cursors: {}", e), None)
+        })?;
+        content_vec.push(Content::text(format!(
+            "Next page cursors:\n```json\n{}\n```",
+            cursors_json
+        )));
+    }
+
+    if deduplicated.duplicates_collapsed > 0 {
+        content_vec.push(Content::text(format!(
+            "*{} duplicate project items collapsed ({})*",
+            deduplicated.duplicates_collapsed, deduplication
+        )));
+    }
+
+    for project_resource in deduplicated.resources {
         let formatted = match format {
-            OutputOption::Light => project_resource_body_markdown_with_timezone_light(
-                &project_resource,
-                timezone.as_ref(),
-            ),
+            OutputOption::Light | OutputOption::Table => {
+                project_resource_body_markdown_with_timezone_light(
+                    &project_resource,
+                    timezone.as_ref(),
+                )
+            }
             OutputOption::Rich => {
                 project_resource_body_markdown_with_timezone(&project_resource, timezone.as_ref())
             }
@@ -0,0 +1,34 @@
+use crate::formatter::project::project_roadmap_markdown;
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::ProjectUrl;
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+/// Get a roadmap of a project grouped by an iteration or date field
+///
+/// Items are grouped by iteration title or by month of the date, ordered by start
+/// date; items without a value for the field are listed under "Unscheduled".
+pub async fn get_project_roadmap(
+    github_token: &Option<String>,
+    project_url: String,
+    group_by_field: String,
+) -> Result<CallToolResult, McpError> {
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    let roadmap = functions::project::get_project_roadmap(
+        &github_client,
+        ProjectUrl(project_url.clone()),
+        &group_by_field,
+    )
+    .await?;
+
+    let formatted = project_roadmap_markdown(&project_url, &roadmap);
+
+    Ok(CallToolResult {
+        content: vec![Content::text(formatted.0)],
+        is_error: Some(false),
+    })
+}
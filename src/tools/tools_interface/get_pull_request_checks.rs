@@ -0,0 +1,45 @@
+use crate::formatter::pull_request::pull_request_checks_markdown;
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::PullRequestUrl;
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+/// Get the CI checks of pull requests by their URLs
+///
+/// Returns, for each pull request, the combined state of its head commit and a
+/// checklist of every check run and commit status with its conclusion and details URL.
+pub async fn get_pull_request_checks(
+    github_token: &Option<String>,
+    pull_request_urls: Vec<String>,
+) -> Result<CallToolResult, McpError> {
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    if pull_request_urls.is_empty() {
+        return Err(McpError::invalid_request(
+            "pull_request_urls cannot be empty. Please provide at least one pull request URL."
+                .to_string(),
+            None,
+        ));
+    }
+
+    let pull_request_urls: Vec<PullRequestUrl> =
+        pull_request_urls.into_iter().map(PullRequestUrl).collect();
+
+    let checks =
+        functions::pull_request::get_pull_request_checks(&github_client, pull_request_urls)
+            .await
+            .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+
+    let content = checks
+        .iter()
+        .map(|pr_checks| Content::text(pull_request_checks_markdown(pr_checks).0))
+        .collect();
+
+    Ok(CallToolResult {
+        content,
+        is_error: Some(false),
+    })
+}
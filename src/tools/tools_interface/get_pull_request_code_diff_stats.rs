@@ -1,4 +1,7 @@
-use crate::formatter::pull_request_file_stats::pull_request_file_stats_markdown;
+use crate::formatter::RenderOptions;
+use crate::formatter::pull_request_file_stats::{
+    pull_request_diff_stats_summary_markdown, pull_request_file_stats_markdown,
+};
 use crate::github::GitHubClient;
 use crate::tools::functions;
 use crate::types::PullRequestUrl;
@@ -8,8 +11,9 @@ use rmcp::{Error as McpError, model::*};
 /// Get pull request file statistics by their URLs
 ///
 /// Returns file-level change statistics (additions, deletions, changes) for each
-/// pull request without the actual diff content. Use this for quick overview of
-/// changed files and their modification counts.
+/// pull request without the actual diff content, preceded by a summary of the totals
+/// and the files with the most churn. Use this for quick overview of changed files
+/// and their modification counts.
 pub async fn get_pull_request_code_diff_stats(
     github_token: &Option<String>,
     pull_request_urls: Vec<String>,
@@ -26,16 +30,32 @@ pub async fn get_pull_request_code_diff_stats(
     let files_by_repo =
         functions::pull_request::get_pull_request_files_stats(&github_client, pull_request_urls)
             .await
-            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+            .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
 
-    // Format all file stats as markdown using the formatter
+    let files_by_pull_request: Vec<_> = files_by_repo
+        .into_iter()
+        .flat_map(|(repo_id, pr_files)| {
+            pr_files
+                .into_iter()
+                .map(move |(pr_number, files)| (repo_id.clone(), pr_number, files))
+        })
+        .collect();
+
+    // Aggregated summary first, then the per-file listing of each pull request
     let mut content_vec = Vec::new();
 
-    for (repo_id, pr_files) in files_by_repo {
-        for (pr_number, files) in pr_files {
-            let formatted = pull_request_file_stats_markdown(&repo_id, pr_number, &files);
-            content_vec.push(Content::text(formatted.0));
-        }
+    if !files_by_pull_request.is_empty() {
+        let summary = pull_request_diff_stats_summary_markdown(
+            &files_by_pull_request,
+            &RenderOptions::default(),
+        );
+        content_vec.push(Content::text(summary.0));
+    }
+
+    for (repo_id, pr_number, files) in &files_by_pull_request {
+        let formatted =
+            pull_request_file_stats_markdown(repo_id, *pr_number, files, &RenderOptions::default());
+        content_vec.push(Content::text(formatted.0));
     }
 
     if content_vec.is_empty() {
@@ -0,0 +1,50 @@
+use crate::formatter::{TimezoneOffset, pull_request::pull_request_commits_markdown_with_timezone};
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::PullRequestUrl;
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+/// Get the commits of pull requests by their URLs
+///
+/// Returns, for each pull request, every commit with its short SHA, the first line
+/// of its message, author, and authored date.
+pub async fn get_pull_request_commits(
+    github_token: &Option<String>,
+    timezone: &Option<TimezoneOffset>,
+    pull_request_urls: Vec<String>,
+) -> Result<CallToolResult, McpError> {
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    if pull_request_urls.is_empty() {
+        return Err(McpError::invalid_request(
+            "pull_request_urls cannot be empty. Please provide at least one pull request URL."
+                .to_string(),
+            None,
+        ));
+    }
+
+    let pull_request_urls: Vec<PullRequestUrl> =
+        pull_request_urls.into_iter().map(PullRequestUrl).collect();
+
+    let commits =
+        functions::pull_request::get_pull_request_commits(&github_client, pull_request_urls)
+            .await
+            .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+
+    let content = commits
+        .iter()
+        .map(|pr_commits| {
+            let formatted =
+                pull_request_commits_markdown_with_timezone(pr_commits, timezone.as_ref());
+            Content::text(formatted.0)
+        })
+        .collect();
+
+    Ok(CallToolResult {
+        content,
+        is_error: Some(false),
+    })
+}
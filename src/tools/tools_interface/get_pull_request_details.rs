@@ -1,20 +1,31 @@
-use crate::formatter::{TimezoneOffset, pull_request::pull_request_body_markdown_with_timezone};
+use crate::formatter::{
+    RenderOptions, TimezoneOffset, pull_request::pull_request_body_markdown_with_comment_filter,
+};
 use crate::github::GitHubClient;
 use crate::tools::functions;
 use crate::types::PullRequestUrl;
 use anyhow::Result;
 use rmcp::{Error as McpError, model::*};
 
+use super::{archived_repository_warning, check_url_count, max_urls_per_call};
+
 /// Get pull requests by their URLs from specified repositories
 ///
 /// Returns detailed pull request information including comments, formatted as markdown
 /// with comprehensive details including title, body, labels, assignees,
 /// creation/update dates, review status, and all comments with timestamps.
+/// When `exclude_bot_comments` is set, comments authored by bot accounts are hidden.
+/// Calls with more than `max_urls_per_call()` URLs are rejected before any request.
+/// Pull requests from an archived repository are preceded by a warning.
 pub async fn get_pull_request_details(
     github_token: &Option<String>,
     timezone: &Option<TimezoneOffset>,
+    render_options: &RenderOptions,
     pull_request_urls: Vec<String>,
+    exclude_bot_comments: bool,
 ) -> Result<CallToolResult, McpError> {
+    check_url_count(pull_request_urls.len(), max_urls_per_call(), "pull request")?;
+
     let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
         McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
     })?;
@@ -27,15 +38,28 @@ pub async fn get_pull_request_details(
     let pull_requests_by_repo =
         functions::pull_request::get_pull_requests_details(&github_client, pull_request_urls)
             .await
-            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+            .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
 
     // Format all pull requests as markdown
     let mut content_vec = Vec::new();
 
-    for (_repo_id, pull_requests) in pull_requests_by_repo {
+    for (repo_id, pull_requests) in pull_requests_by_repo {
+        if pull_requests
+            .iter()
+            .any(|pull_request| pull_request.repository_archived)
+        {
+            content_vec.push(Content::text(archived_repository_warning(
+                &repo_id,
+                "pull requests",
+            )));
+        }
         for pull_request in pull_requests {
-            let formatted =
-                pull_request_body_markdown_with_timezone(&pull_request, timezone.as_ref());
+            let formatted = pull_request_body_markdown_with_comment_filter(
+                &pull_request,
+                timezone.as_ref(),
+                exclude_bot_comments,
+                render_options,
+            );
             content_vec.push(Content::text(formatted.0));
         }
     }
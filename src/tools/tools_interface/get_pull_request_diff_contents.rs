@@ -1,3 +1,4 @@
+use crate::formatter::pull_request_file_stats::pull_request_diff_stat_markdown;
 use crate::github::GitHubClient;
 use crate::tools::functions;
 use crate::types::PullRequestUrl;
@@ -7,18 +8,50 @@ use rmcp::{Error as McpError, model::*};
 /// Get the diff content of a specific file from a pull request
 ///
 /// Returns the unified diff patch for the specified file. Supports optional
-/// skip/limit filtering to retrieve specific portions of the diff.
+/// skip/limit filtering to retrieve specific portions of the diff, or `hunk_index`
+/// to retrieve one complete hunk. Diffs longer than `max_lines` are truncated, and
+/// likely generated files are summarized.
+///
+/// With `stat_only`, returns a `git diff --stat` style overview of every file in the
+/// pull request instead, and `file_path` may be omitted.
+#[allow(clippy::too_many_arguments)]
 pub async fn get_pull_request_diff_contents(
     github_token: &Option<String>,
     pull_request_url: String,
-    file_path: String,
+    file_path: Option<String>,
     skip: Option<u32>,
     limit: Option<u32>,
+    max_lines: Option<u32>,
+    hunk_index: Option<u32>,
+    stat_only: bool,
 ) -> Result<CallToolResult, McpError> {
     let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
         McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
     })?;
 
+    if stat_only {
+        let files = functions::pull_request::get_pull_request_diff_stat(
+            &github_client,
+            PullRequestUrl(pull_request_url.clone()),
+        )
+        .await
+        .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+
+        return Ok(CallToolResult {
+            content: vec![Content::text(
+                pull_request_diff_stat_markdown(&pull_request_url, &files).0,
+            )],
+            is_error: Some(false),
+        });
+    }
+
+    let file_path = file_path.ok_or_else(|| {
+        McpError::invalid_params(
+            "file_path is required unless stat_only is set".to_string(),
+            None,
+        )
+    })?;
+
     // Convert string to PullRequestUrl
     let pull_request_url = PullRequestUrl(pull_request_url);
 
@@ -29,9 +62,11 @@ pub async fn get_pull_request_diff_contents(
         file_path.clone(),
         skip,
         limit,
+        max_lines,
+        hunk_index,
     )
     .await
-    .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
 
     // Format as markdown code block
     let formatted = format!(
@@ -0,0 +1,50 @@
+use crate::formatter::{TimezoneOffset, pull_request::pull_request_reviews_markdown_with_timezone};
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::PullRequestUrl;
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+/// Get the reviews of pull requests by their URLs
+///
+/// Returns, for each pull request, the number of approvals followed by every review
+/// with its submission time, state, reviewer, and body.
+pub async fn get_pull_request_reviews(
+    github_token: &Option<String>,
+    timezone: &Option<TimezoneOffset>,
+    pull_request_urls: Vec<String>,
+) -> Result<CallToolResult, McpError> {
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    if pull_request_urls.is_empty() {
+        return Err(McpError::invalid_request(
+            "pull_request_urls cannot be empty. Please provide at least one pull request URL."
+                .to_string(),
+            None,
+        ));
+    }
+
+    let pull_request_urls: Vec<PullRequestUrl> =
+        pull_request_urls.into_iter().map(PullRequestUrl).collect();
+
+    let reviews =
+        functions::pull_request::get_pull_request_reviews(&github_client, pull_request_urls)
+            .await
+            .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+
+    let content = reviews
+        .iter()
+        .map(|pr_reviews| {
+            let formatted =
+                pull_request_reviews_markdown_with_timezone(pr_reviews, timezone.as_ref());
+            Content::text(formatted.0)
+        })
+        .collect();
+
+    Ok(CallToolResult {
+        content,
+        is_error: Some(false),
+    })
+}
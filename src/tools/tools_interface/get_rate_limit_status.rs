@@ -0,0 +1,29 @@
+use crate::formatter::{TimezoneOffset, rate_limit::rate_limit_status_markdown_with_timezone};
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+/// Get the GraphQL API rate limit status of the configured token
+///
+/// Returns remaining and used points, the hourly limit, and the reset time rendered
+/// in the configured timezone.
+pub async fn get_rate_limit_status(
+    github_token: &Option<String>,
+    timezone: &Option<TimezoneOffset>,
+) -> Result<CallToolResult, McpError> {
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    let status = functions::rate_limit::get_rate_limit_status(&github_client)
+        .await
+        .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+
+    let formatted = rate_limit_status_markdown_with_timezone(&status, timezone.as_ref());
+
+    Ok(CallToolResult {
+        content: vec![Content::text(formatted.0)],
+        is_error: Some(false),
+    })
+}
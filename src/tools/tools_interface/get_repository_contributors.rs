@@ -0,0 +1,40 @@
+use crate::formatter::RenderOptions;
+use crate::formatter::repository::repository_contributors_markdown;
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::RepositoryUrl;
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+const DEFAULT_CONTRIBUTOR_LIMIT: usize = 10;
+
+/// Get the top contributors of a repository
+///
+/// Returns a markdown table of contributors ranked by contribution count.
+pub async fn get_repository_contributors(
+    github_token: &Option<String>,
+    repository_url: String,
+    limit: Option<usize>,
+) -> Result<CallToolResult, McpError> {
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    let limit = limit.unwrap_or(DEFAULT_CONTRIBUTOR_LIMIT);
+
+    let (repository_id, contributors) = functions::repository::get_repository_contributors(
+        &github_client,
+        RepositoryUrl(repository_url),
+        limit,
+    )
+    .await
+    .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+
+    let formatted =
+        repository_contributors_markdown(&repository_id, &contributors, &RenderOptions::default());
+
+    Ok(CallToolResult {
+        content: vec![Content::text(formatted.0)],
+        is_error: Some(false),
+    })
+}
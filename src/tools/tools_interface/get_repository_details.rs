@@ -1,21 +1,42 @@
-use crate::formatter::{TimezoneOffset, repository::repository_body_markdown_with_timezone};
+use crate::formatter::{
+    TimezoneOffset,
+    repository::{repository_body_markdown_with_timezone, repository_languages_markdown},
+};
 use crate::github::GitHubClient;
 use crate::tools::functions;
+use crate::types::{GithubRepository, RepositoryFetchResult, RepositoryId, ResponseFormat};
 use anyhow::Result;
 use rmcp::{Error as McpError, model::*};
+use serde::Serialize;
+
+use super::{json_content, parse_response_format};
 
 /// Get repository details by URLs
 ///
 /// Returns detailed repository information formatted as markdown with comprehensive
 /// metadata including URL, description, default branch, mentionable users, labels,
-/// milestones, releases (with configurable limit), and timestamps.
+/// milestones, releases (with configurable limit), and timestamps. When
+/// `show_label_colors` is set, labels are rendered in their GitHub color. When
+/// `show_language_breakdown` is set, the share of each language is appended.
+///
+/// With `response_format` set to json, the repositories are returned as a JSON array,
+/// carrying their language byte counts when `show_language_breakdown` is set.
+///
+/// Repositories requested by an old name are fetched under their new name, with a
+/// note (or a `redirected_from` field in JSON) so stale registrations can be updated.
+#[allow(clippy::too_many_arguments)]
 pub async fn get_repository_details(
     github_token: &Option<String>,
     timezone: &Option<TimezoneOffset>,
     repository_urls: Vec<String>,
     showing_release_limit: Option<usize>,
     showing_milestone_limit: Option<usize>,
+    show_label_colors: Option<bool>,
+    show_language_breakdown: Option<bool>,
+    response_format: Option<String>,
 ) -> Result<CallToolResult, McpError> {
+    let response_format = parse_response_format(response_format)?;
+
     let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
         McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
     })?;
@@ -35,22 +56,72 @@ pub async fn get_repository_details(
         .collect::<Vec<_>>();
 
     // Fetch repositories using the multiple repositories function
-    let repositories =
-        functions::repository::get_multiple_repository_details(&github_client, repository_urls)
-            .await
-            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let repositories = functions::repository::get_multiple_repository_details_with_redirects(
+        &github_client,
+        repository_urls,
+    )
+    .await
+    .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+
+    if response_format == ResponseFormat::Json {
+        let mut details = Vec::new();
+        for fetched in &repositories {
+            let repository = &fetched.repository;
+            let languages = if show_language_breakdown.unwrap_or(false) {
+                let languages = functions::repository::get_repository_languages(
+                    &github_client,
+                    repository.git_repository_id.clone(),
+                )
+                .await
+                .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+                Some(
+                    languages
+                        .into_iter()
+                        .map(|(language, bytes)| LanguageBytes { language, bytes })
+                        .collect(),
+                )
+            } else {
+                None
+            };
+            details.push(RepositoryDetailsJson {
+                repository,
+                redirected_from: fetched.was_redirected.then_some(&fetched.requested_id),
+                languages,
+            });
+        }
+        return Ok(CallToolResult {
+            content: vec![json_content(&details)?],
+            is_error: Some(false),
+        });
+    }
 
     // Format all repositories as markdown
     let mut content_vec = Vec::new();
 
-    for repository in repositories {
+    for fetched in repositories {
+        if let Some(note) = redirect_note(&fetched) {
+            content_vec.push(Content::text(note));
+        }
+        let repository = fetched.repository;
         let formatted = repository_body_markdown_with_timezone(
             &repository,
             timezone.as_ref(),
             showing_release_limit,
             showing_milestone_limit,
+            show_label_colors.unwrap_or(false),
         );
-        content_vec.push(Content::text(formatted.0));
+        let mut text = formatted.0;
+        if show_language_breakdown.unwrap_or(false) {
+            let languages = functions::repository::get_repository_languages(
+                &github_client,
+                repository.git_repository_id.clone(),
+            )
+            .await
+            .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+            text.push('\n');
+            text.push_str(&repository_languages_markdown(&languages).0);
+        }
+        content_vec.push(Content::text(text));
     }
 
     if content_vec.is_empty() {
@@ -64,3 +135,32 @@ pub async fn get_repository_details(
         is_error: Some(false),
     })
 }
+
+/// JSON form of one repository of `get_repository_details`
+#[derive(Serialize)]
+struct RepositoryDetailsJson<'a> {
+    #[serde(flatten)]
+    repository: &'a GithubRepository,
+    /// Identifier the repository was requested by, when it was renamed or transferred
+    #[serde(skip_serializing_if = "Option::is_none")]
+    redirected_from: Option<&'a RepositoryId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    languages: Option<Vec<LanguageBytes>>,
+}
+
+#[derive(Serialize)]
+struct LanguageBytes {
+    language: String,
+    bytes: u64,
+}
+
+/// Note for a repository that GitHub resolved from an old name, `None` otherwise
+fn redirect_note(fetched: &RepositoryFetchResult) -> Option<String> {
+    fetched.was_redirected.then(|| {
+        format!(
+            "Note: {} now redirects to {} (the repository was renamed or transferred). Update registrations that still use the old URL.",
+            fetched.requested_id.url(),
+            fetched.canonical_id.url()
+        )
+    })
+}
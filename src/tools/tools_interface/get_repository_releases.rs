@@ -0,0 +1,41 @@
+use crate::formatter::{TimezoneOffset, repository::repository_releases_markdown_with_timezone};
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::RepositoryUrl;
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+const DEFAULT_RELEASE_LIMIT: usize = 10;
+
+/// Get the latest releases of a repository with their assets
+///
+/// Returns markdown with the tag, name, published date, pre-release flag, and a table of
+/// assets (name, size, download count, download URL) for each release.
+pub async fn get_repository_releases(
+    github_token: &Option<String>,
+    timezone: &Option<TimezoneOffset>,
+    repository_url: String,
+    limit: Option<usize>,
+) -> Result<CallToolResult, McpError> {
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    let limit = limit.unwrap_or(DEFAULT_RELEASE_LIMIT);
+
+    let (repository_id, releases) = functions::repository::get_repository_releases(
+        &github_client,
+        RepositoryUrl(repository_url),
+        limit,
+    )
+    .await
+    .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+
+    let formatted =
+        repository_releases_markdown_with_timezone(&repository_id, &releases, timezone.as_ref());
+
+    Ok(CallToolResult {
+        content: vec![Content::text(formatted.0)],
+        is_error: Some(false),
+    })
+}
@@ -0,0 +1,48 @@
+use crate::formatter::{
+    RenderOptions, TimezoneOffset, repository::repository_stats_markdown_with_timezone,
+};
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::RepositoryUrl;
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+/// Get issue and pull request counts of repositories
+///
+/// Returns a short markdown summary per repository with open/closed issue counts,
+/// open/merged/closed pull request counts, stars, forks, and the last push time.
+pub async fn get_repository_stats(
+    github_token: &Option<String>,
+    timezone: &Option<TimezoneOffset>,
+    repository_urls: Vec<String>,
+) -> Result<CallToolResult, McpError> {
+    if repository_urls.is_empty() {
+        return Err(McpError::invalid_request(
+            "repository_urls cannot be empty. Please provide at least one repository URL."
+                .to_string(),
+            None,
+        ));
+    }
+
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    let stats = functions::repository::get_repository_stats(
+        &github_client,
+        repository_urls.into_iter().map(RepositoryUrl).collect(),
+    )
+    .await
+    .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+
+    let formatted = repository_stats_markdown_with_timezone(
+        &stats,
+        timezone.as_ref(),
+        &RenderOptions::default(),
+    );
+
+    Ok(CallToolResult {
+        content: vec![Content::text(formatted.0)],
+        is_error: Some(false),
+    })
+}
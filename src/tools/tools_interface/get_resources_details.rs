@@ -0,0 +1,68 @@
+use crate::formatter::{
+    TimezoneOffset, issue::issue_body_markdown_with_timezone,
+    pull_request::pull_request_body_markdown_with_timezone,
+};
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::IssueOrPullrequest;
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+/// Get issues and pull requests from a mixed list of URLs
+///
+/// Returns the same detailed markdown as `get_issues_details` and
+/// `get_pull_request_details`, in the order of the input URLs. URLs that could not
+/// be parsed or fetched are listed in a trailing errors section.
+pub async fn get_resources_details(
+    github_token: &Option<String>,
+    timezone: &Option<TimezoneOffset>,
+    urls: Vec<String>,
+) -> Result<CallToolResult, McpError> {
+    if urls.is_empty() {
+        return Err(McpError::invalid_request(
+            "urls cannot be empty. Please provide at least one issue or pull request URL."
+                .to_string(),
+            None,
+        ));
+    }
+
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    let details = functions::resource::get_resources_details(&github_client, urls)
+        .await
+        .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+
+    let mut content_vec = Vec::new();
+    for resource in &details.resources {
+        let formatted = match resource {
+            IssueOrPullrequest::Issue(issue) => {
+                issue_body_markdown_with_timezone(issue, timezone.as_ref())
+            }
+            IssueOrPullrequest::PullRequest(pull_request) => {
+                pull_request_body_markdown_with_timezone(pull_request, timezone.as_ref())
+            }
+        };
+        content_vec.push(Content::text(formatted.0));
+    }
+
+    if content_vec.is_empty() && details.errors.is_empty() {
+        content_vec.push(Content::text(
+            "No issues or pull requests found for the provided URLs.".to_string(),
+        ));
+    }
+
+    if !details.errors.is_empty() {
+        let mut errors = format!("## Errors ({})\n", details.errors.len());
+        for (url, reason) in &details.errors {
+            errors.push_str(&format!("- {}: {}\n", url, reason));
+        }
+        content_vec.push(Content::text(errors));
+    }
+
+    Ok(CallToolResult {
+        content: content_vec,
+        is_error: Some(false),
+    })
+}
@@ -0,0 +1,71 @@
+use crate::formatter::user_activity_markdown;
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::ProfileName;
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+use crate::tools::functions::search::clamp_search_limit;
+
+/// Summarize a user's issues and pull requests across the repositories of a profile
+///
+/// Uses the current profile unless `profile_name` is given. The `@me` alias refers
+/// to the authenticated user, so it requires a GitHub token.
+pub async fn get_user_activity(
+    github_token: &Option<String>,
+    current_profile_name: &Option<ProfileName>,
+    username: String,
+    profile_name: Option<String>,
+    limit_per_repository: Option<usize>,
+) -> Result<CallToolResult, McpError> {
+    if username.trim().eq_ignore_ascii_case("@me") && github_token.is_none() {
+        return Err(McpError::invalid_params(
+            "The '@me' alias refers to the authenticated user and requires a GitHub token"
+                .to_string(),
+            None,
+        ));
+    }
+
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    let (limit, limit_note) = clamp_search_limit(limit_per_repository);
+    let profile_name = profile_name
+        .unwrap_or_else(|| current_profile_name.clone().unwrap_or_default().to_string());
+
+    let repository_ids =
+        functions::profile::list_repositories_across_profiles(vec![profile_name.clone()])
+            .await
+            .map_err(|e| McpError::invalid_request(e, None))?;
+
+    if repository_ids.is_empty() {
+        return Ok(CallToolResult {
+            content: vec![Content::text(format!(
+                "No repositories are registered in profile '{}'.",
+                profile_name
+            ))],
+            is_error: Some(false),
+        });
+    }
+
+    let activity = functions::search::get_user_activity(
+        &github_client,
+        &username,
+        repository_ids,
+        limit as u32,
+    )
+    .await
+    .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+
+    let mut content_vec = Vec::new();
+    if let Some(note) = limit_note {
+        content_vec.push(Content::text(note));
+    }
+    content_vec.push(Content::text(user_activity_markdown(&activity).0));
+
+    Ok(CallToolResult {
+        content: content_vec,
+        is_error: Some(false),
+    })
+}
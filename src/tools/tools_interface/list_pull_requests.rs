@@ -0,0 +1,72 @@
+use crate::formatter::{TimezoneOffset, pull_request::pull_request_list_table_markdown};
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::{PullRequestListFilter, RepositoryUrl, ReviewStateFilter};
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+const DEFAULT_LIST_LIMIT: usize = 30;
+
+/// List open pull requests of a repository filtered by review state
+///
+/// Returns a light markdown table of the matching pull requests including
+/// draft status, review decision, and requested reviewers.
+#[allow(clippy::too_many_arguments)]
+pub async fn list_pull_requests(
+    github_token: &Option<String>,
+    timezone: &Option<TimezoneOffset>,
+    repository_url: String,
+    review_state: Option<String>,
+    draft: Option<bool>,
+    author: Option<String>,
+    reviewer: Option<String>,
+    limit: Option<usize>,
+) -> Result<CallToolResult, McpError> {
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    // Convert String to ReviewStateFilter
+    let review_state = review_state
+        .map(|state| {
+            state.parse::<ReviewStateFilter>().map_err(|_| {
+                McpError::invalid_params(
+                    format!(
+                        "Invalid review_state '{}'. Expected one of: approved, changes_requested, required, none",
+                        state
+                    ),
+                    None,
+                )
+            })
+        })
+        .transpose()?;
+
+    let filter = PullRequestListFilter {
+        review_state,
+        draft,
+        author,
+        reviewer,
+    };
+
+    let limit = limit.unwrap_or(DEFAULT_LIST_LIMIT);
+
+    let pull_requests = functions::pull_request::list_pull_requests(
+        &github_client,
+        RepositoryUrl(repository_url),
+        filter,
+        Some(limit as u32),
+    )
+    .await
+    .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+
+    let content = if pull_requests.is_empty() {
+        Content::text("No pull requests found matching the filters.".to_string())
+    } else {
+        Content::text(pull_request_list_table_markdown(&pull_requests, timezone.as_ref()).0)
+    };
+
+    Ok(CallToolResult {
+        content: vec![content],
+        is_error: Some(false),
+    })
+}
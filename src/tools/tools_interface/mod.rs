@@ -1,11 +1,156 @@
+use crate::types::{RepositoryId, ResponseFormat};
+use rmcp::{Error as McpError, model::Content};
+use serde::Serialize;
+
+pub mod auth_check;
+pub mod get_issue;
+pub mod get_issue_timeline;
 pub mod get_issues_details;
+pub mod get_milestone_issues;
+pub mod get_pinned_issues;
 pub mod get_project_details;
+pub mod get_project_field_definitions;
+pub mod get_project_repositories;
 pub mod get_project_resources;
+pub mod get_project_roadmap;
+pub mod get_pull_request_checks;
 pub mod get_pull_request_code_diff_stats;
+pub mod get_pull_request_commits;
 pub mod get_pull_request_details;
 pub mod get_pull_request_diff_contents;
+pub mod get_pull_request_reviews;
+pub mod get_rate_limit_status;
+pub mod get_repository_contributors;
 pub mod get_repository_details;
+pub mod get_repository_releases;
+pub mod get_repository_stats;
+pub mod get_resources_details;
+pub mod get_user_activity;
 pub mod list_project_urls_in_current_profile;
+pub mod list_pull_requests;
 pub mod list_repository_urls_in_current_profile;
 pub mod repository_branch_group;
+pub mod search_across_profiles;
 pub mod search_in_repositories;
+pub mod set_profile_defaults;
+pub mod set_project_field_value;
+pub mod summarize_issue_links;
+pub mod validate_profile;
+
+/// Default maximum number of URLs a single detail tool call accepts
+pub const DEFAULT_MAX_URLS_PER_CALL: usize = 50;
+/// Environment variable overriding `DEFAULT_MAX_URLS_PER_CALL`
+pub const MAX_URLS_PER_CALL_ENV: &str = "GITHUB_INSIGHT_MAX_URLS_PER_CALL";
+
+/// Maximum number of URLs per detail tool call, from the environment or the default
+///
+/// Values that are not a positive integer fall back to the default.
+pub(super) fn max_urls_per_call() -> usize {
+    parse_max_urls_per_call(std::env::var(MAX_URLS_PER_CALL_ENV).ok().as_deref())
+}
+
+fn parse_max_urls_per_call(value: Option<&str>) -> usize {
+    value
+        .and_then(|value| value.trim().parse::<usize>().ok())
+        .filter(|max| *max > 0)
+        .unwrap_or(DEFAULT_MAX_URLS_PER_CALL)
+}
+
+/// Reject a call with more URLs than `max_urls`
+///
+/// Every URL costs API requests, so a single oversized call could exhaust the rate
+/// limit. `kind` names the URLs in the error, e.g. "issue".
+pub(super) fn check_url_count(
+    url_count: usize,
+    max_urls: usize,
+    kind: &str,
+) -> Result<(), McpError> {
+    if url_count <= max_urls {
+        return Ok(());
+    }
+    Err(McpError::invalid_params(
+        format!(
+            "Too many {} URLs: {} given, but at most {} are accepted per call. Split them into batches of {} or fewer (the cap can be raised with the {} environment variable).",
+            kind, url_count, max_urls, max_urls, MAX_URLS_PER_CALL_ENV
+        ),
+        None,
+    ))
+}
+
+/// Warning placed ahead of the items fetched from an archived repository
+///
+/// `kind` names the items, e.g. "issues".
+pub(super) fn archived_repository_warning(repository_id: &RepositoryId, kind: &str) -> String {
+    format!(
+        "Warning: {} is archived (read-only); its {} can no longer be updated and may be out of date.",
+        repository_id.full_name(),
+        kind
+    )
+}
+
+/// Parse the `response_format` parameter of the tools that can answer in JSON
+pub(super) fn parse_response_format(
+    response_format: Option<String>,
+) -> Result<ResponseFormat, McpError> {
+    response_format
+        .map(|format| {
+            format.parse::<ResponseFormat>().map_err(|_| {
+                McpError::invalid_params(
+                    format!(
+                        "Invalid response_format '{}'. Expected one of: markdown, json",
+                        format
+                    ),
+                    None,
+                )
+            })
+        })
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+/// Serialize a tool response as pretty-printed JSON text content
+pub(super) fn json_content<T: Serialize + ?Sized>(value: &T) -> Result<Content, McpError> {
+    let json = serde_json::to_string_pretty(value).map_err(|e| {
+        McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+    })?;
+    Ok(Content::text(json))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_url_count_enforces_cap() {
+        assert!(check_url_count(0, 50, "issue").is_ok());
+        assert!(check_url_count(50, 50, "issue").is_ok());
+
+        let error = check_url_count(51, 50, "issue").unwrap_err();
+        assert!(error.message.contains("Too many issue URLs: 51 given"));
+        assert!(error.message.contains("batches of 50 or fewer"));
+    }
+
+    #[test]
+    fn test_archived_repository_warning_names_repository() {
+        let warning = archived_repository_warning(&RepositoryId::new("owner", "repo"), "issues");
+        assert_eq!(
+            warning,
+            "Warning: owner/repo is archived (read-only); its issues can no longer be updated and may be out of date."
+        );
+    }
+
+    #[test]
+    fn test_parse_max_urls_per_call() {
+        assert_eq!(parse_max_urls_per_call(None), DEFAULT_MAX_URLS_PER_CALL);
+        assert_eq!(parse_max_urls_per_call(Some("200")), 200);
+        assert_eq!(parse_max_urls_per_call(Some(" 10 ")), 10);
+        assert_eq!(
+            parse_max_urls_per_call(Some("0")),
+            DEFAULT_MAX_URLS_PER_CALL
+        );
+        assert_eq!(
+            parse_max_urls_per_call(Some("many")),
+            DEFAULT_MAX_URLS_PER_CALL
+        );
+    }
+}
@@ -1,12 +1,16 @@
 use crate::formatter::{
     TimezoneOffset,
     repository_branch_group::{
+        branch_group_status_markdown, group_release_notes_markdown,
+        repository_branch_group_diff_markdown,
         repository_branch_group_list_with_descriptions_markdown,
         repository_branch_group_markdown_with_timezone,
+        repository_branch_groups_across_profiles_markdown,
     },
 };
+use crate::github::GitHubClient;
 use crate::tools::functions;
-use crate::types::ProfileName;
+use crate::types::{ProfileName, parse_search_date};
 use anyhow::Result;
 use rmcp::{Error as McpError, model::*};
 use serde_json;
@@ -86,6 +90,42 @@ pub async fn add_branch_to_branch_group(
     })
 }
 
+/// Add branches matching a pattern to an existing group
+///
+/// Resolves branches of the profile's registered repositories whose names match the
+/// glob or regex pattern and adds them, skipping branches already in the group. Returns
+/// the newly added branches as JSON.
+pub async fn add_branches_by_pattern(
+    github_token: &Option<String>,
+    profile_name: String,
+    group_name: String,
+    branch_pattern: String,
+    repository_filter: Option<String>,
+) -> Result<CallToolResult, McpError> {
+    let client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    let added_pairs = functions::profile::add_branches_by_pattern(
+        &client,
+        profile_name,
+        group_name,
+        branch_pattern,
+        repository_filter,
+    )
+    .await
+    .map_err(|e| McpError::internal_error(e, None))?;
+
+    let content = Content::text(serde_json::to_string_pretty(&added_pairs).map_err(|e| {
+        McpError::internal_error(format!("Failed to serialize result: {}", e), None)
+    })?);
+
+    Ok(CallToolResult {
+        content: vec![content],
+        is_error: Some(false),
+    })
+}
+
 /// Remove branches from a group
 ///
 /// Allows reducing group membership by removing specific branches. Returns success
@@ -156,6 +196,32 @@ pub async fn show_repository_branch_groups(
     })
 }
 
+/// List repository branch groups of all profiles
+///
+/// Returns a markdown table with profile name, group name, branch count, and creation
+/// timestamp, sorted by profile then group name. Profiles without groups are listed
+/// unless `hide_empty` is set.
+pub async fn list_branch_groups_across_profiles(
+    timezone: &Option<TimezoneOffset>,
+    hide_empty: Option<bool>,
+) -> Result<CallToolResult, McpError> {
+    let profile_groups = functions::profile::list_branch_groups_across_profiles()
+        .await
+        .map_err(|e| McpError::internal_error(e, None))?;
+
+    let formatted = repository_branch_groups_across_profiles_markdown(
+        &profile_groups,
+        timezone.as_ref(),
+        hide_empty.unwrap_or(false),
+    );
+    let content = Content::text(formatted.0);
+
+    Ok(CallToolResult {
+        content: vec![content],
+        is_error: Some(false),
+    })
+}
+
 /// Show details of a specific repository branch group
 ///
 /// Returns comprehensive information about the group and all its branches in formatted
@@ -178,17 +244,140 @@ pub async fn get_repository_branch_group(
     })
 }
 
+/// Show the live state of every branch in a repository branch group
+///
+/// Returns a markdown table with each branch's latest commit and its divergence from
+/// the repository's default branch. Deleted branches are marked "missing".
+pub async fn get_branch_group_status(
+    github_token: &Option<String>,
+    timezone: &Option<TimezoneOffset>,
+    profile_name: String,
+    group_name: String,
+) -> Result<CallToolResult, McpError> {
+    let client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    let status = functions::profile::get_branch_group_status(&client, profile_name, group_name)
+        .await
+        .map_err(|e| McpError::internal_error(e, None))?;
+
+    let formatted = branch_group_status_markdown(&status, timezone.as_ref());
+
+    Ok(CallToolResult {
+        content: vec![Content::text(formatted.0)],
+        is_error: Some(false),
+    })
+}
+
+/// Generate release notes from the pull requests merged into a branch group
+///
+/// `since` is a `YYYY-MM-DD` date or a number of days ago such as `14d`, counted from
+/// today in the configured timezone. Returns
+/// markdown with one section per branch listing its merged pull requests.
+pub async fn generate_group_release_notes(
+    github_token: &Option<String>,
+    timezone: &Option<TimezoneOffset>,
+    profile_name: String,
+    group_name: String,
+    since: String,
+    labels: Option<Vec<String>>,
+) -> Result<CallToolResult, McpError> {
+    let offset = timezone
+        .as_ref()
+        .map(|tz| tz.to_fixed_offset())
+        .unwrap_or_else(|| chrono::FixedOffset::east_opt(0).unwrap());
+    let today = chrono::Utc::now().with_timezone(&offset).date_naive();
+    let since = parse_search_date(&since, today).map_err(|e| McpError::invalid_params(e, None))?;
+
+    let client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    let notes = functions::profile::generate_group_release_notes(
+        &client,
+        profile_name,
+        group_name,
+        since,
+        labels.unwrap_or_default(),
+    )
+    .await
+    .map_err(|e| McpError::internal_error(e, None))?;
+
+    let formatted = group_release_notes_markdown(&notes);
+
+    Ok(CallToolResult {
+        content: vec![Content::text(formatted.0)],
+        is_error: Some(false),
+    })
+}
+
+/// Copy a repository branch group under a new name
+///
+/// The new group gets all branches and the description of the source group, with a
+/// fresh creation timestamp. Returns the new group information as JSON.
+pub async fn clone_branch_group(
+    profile_name: String,
+    source_group_name: String,
+    destination_group_name: String,
+) -> Result<CallToolResult, McpError> {
+    let group = functions::profile::clone_repository_branch_group(
+        profile_name,
+        source_group_name,
+        destination_group_name,
+    )
+    .await
+    .map_err(|e| McpError::internal_error(e, None))?;
+
+    let content = Content::text(serde_json::to_string_pretty(&group).map_err(|e| {
+        McpError::internal_error(format!("Failed to serialize result: {}", e), None)
+    })?);
+
+    Ok(CallToolResult {
+        content: vec![content],
+        is_error: Some(false),
+    })
+}
+
+/// Compare two repository branch groups of a profile
+///
+/// Returns formatted markdown listing the branches only in the first group, only in
+/// the second group, and common to both.
+pub async fn diff_branch_groups(
+    profile_name: String,
+    group_a: String,
+    group_b: String,
+) -> Result<CallToolResult, McpError> {
+    let diff = functions::profile::diff_repository_branch_groups(profile_name, group_a, group_b)
+        .await
+        .map_err(|e| McpError::invalid_request(e, None))?;
+
+    let formatted = repository_branch_group_diff_markdown(&diff);
+    let content = Content::text(formatted.0);
+
+    Ok(CallToolResult {
+        content: vec![content],
+        is_error: Some(false),
+    })
+}
+
 /// Remove repository branch groups older than N days
 ///
 /// Useful for cleaning up temporary or outdated groups automatically. Returns JSON
-/// array of removed groups with their details.
+/// array of removed groups with their details. With `dry_run`, returns the same
+/// array for the groups that would be removed without removing them.
 pub async fn cleanup_repository_branch_groups(
     profile_name: String,
     days: i64,
+    dry_run: Option<bool>,
 ) -> Result<CallToolResult, McpError> {
-    let removed_groups = functions::profile::cleanup_repository_branch_groups(profile_name, days)
-        .await
-        .map_err(|e| McpError::internal_error(e, None))?;
+    let removed_groups = functions::profile::cleanup_repository_branch_groups(
+        profile_name,
+        days,
+        dry_run.unwrap_or(false),
+    )
+    .await
+    .map_err(|e| McpError::internal_error(e, None))?;
 
     let content = Content::text(serde_json::to_string_pretty(&removed_groups).map_err(|e| {
         McpError::internal_error(format!("Failed to serialize result: {}", e), None)
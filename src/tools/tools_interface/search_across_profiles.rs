@@ -0,0 +1,117 @@
+use std::collections::BTreeMap;
+
+use crate::formatter::{RenderOptions, TimezoneOffset};
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::{
+    DEFAULT_SEARCH_QUERY, IssueOrPullrequest, OutputOption, RepositoryId, SearchCursorByRepository,
+    SearchQuery,
+};
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+use crate::tools::functions::search::clamp_search_limit;
+
+use super::search_in_repositories::{
+    next_page_cursors_content, parse_sort, search_results_content,
+};
+
+/// Search for issues and PRs in the repositories of several profiles
+///
+/// Collects the repositories registered in the given profiles (all profiles when
+/// empty), de-duplicates them, and searches the union. Results are grouped by
+/// repository, each under a heading with the repository name.
+#[allow(clippy::too_many_arguments)]
+pub async fn search_across_profiles(
+    github_token: &Option<String>,
+    timezone: &Option<TimezoneOffset>,
+    profile_names: Vec<String>,
+    github_search_query: Option<String>,
+    limit: Option<usize>,
+    cursors: Option<Vec<SearchCursorByRepository>>,
+    output_option: Option<String>,
+    sort: Option<String>,
+) -> Result<CallToolResult, McpError> {
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    let (limit, limit_note) = clamp_search_limit(limit);
+    let format = output_option
+        .map(|option| option.parse::<OutputOption>().unwrap_or_default())
+        .unwrap_or_default();
+    let sort = parse_sort(sort)?;
+    let query =
+        SearchQuery::new(github_search_query.unwrap_or_else(|| DEFAULT_SEARCH_QUERY.to_string()));
+
+    let repository_ids = functions::profile::list_repositories_across_profiles(profile_names)
+        .await
+        .map_err(|e| McpError::invalid_request(e, None))?;
+
+    if repository_ids.is_empty() {
+        return Ok(CallToolResult {
+            content: vec![Content::text(
+                "No repositories are registered in the selected profiles.".to_string(),
+            )],
+            is_error: Some(false),
+        });
+    }
+
+    let search_results = functions::search::search_resources(
+        &github_client,
+        repository_ids,
+        query,
+        Some(limit as u32),
+        cursors,
+        sort,
+    )
+    .await
+    .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+
+    let mut results_by_repository: BTreeMap<RepositoryId, Vec<IssueOrPullrequest>> =
+        BTreeMap::new();
+    for result in search_results.results {
+        let repository_id = match &result {
+            IssueOrPullrequest::Issue(issue) => issue.issue_id.git_repository.clone(),
+            IssueOrPullrequest::PullRequest(pr) => pr.pull_request_id.git_repository.clone(),
+        };
+        results_by_repository
+            .entry(repository_id)
+            .or_default()
+            .push(result);
+    }
+
+    let mut content_vec = Vec::new();
+
+    if let Some(note) = limit_note {
+        content_vec.push(Content::text(note));
+    }
+
+    if results_by_repository.is_empty() {
+        content_vec.push(Content::text("No results found.".to_string()));
+    }
+
+    for (repository_id, results) in &results_by_repository {
+        content_vec.push(Content::text(format!(
+            "## {} ({} result(s))",
+            repository_id.full_name(),
+            results.len()
+        )));
+        content_vec.extend(search_results_content(
+            results,
+            &format,
+            timezone.as_ref(),
+            &RenderOptions::default(),
+            None,
+        ));
+    }
+
+    if let Some(cursors_content) = next_page_cursors_content(&search_results.cursors)? {
+        content_vec.push(cursors_content);
+    }
+
+    Ok(CallToolResult {
+        content: content_vec,
+        is_error: Some(false),
+    })
+}
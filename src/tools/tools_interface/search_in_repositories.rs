@@ -1,38 +1,79 @@
 use crate::formatter::{
-    TimezoneOffset,
-    issue::{issue_body_markdown_with_timezone, issue_body_markdown_with_timezone_light},
+    DEFAULT_LIGHT_BODY_LENGTH, RenderOptions, TimezoneOffset,
+    discussion::discussion_markdown_with_timezone_light,
+    issue::{
+        issue_body_markdown_light_with_body_truncation, issue_body_markdown_with_comment_range,
+    },
     pull_request::{
-        pull_request_body_markdown_with_timezone, pull_request_body_markdown_with_timezone_light,
+        pull_request_body_markdown_light_with_body_truncation,
+        pull_request_body_markdown_with_comment_filter,
     },
+    search::search_results_table_markdown,
 };
 use crate::github::GitHubClient;
 use crate::tools::functions;
-use crate::types::{OutputOption, SearchCursorByRepository, SearchQuery};
+use crate::types::{
+    DEFAULT_SEARCH_QUERY, Discussion, IssueOrPullrequest, OutputOption, ProfileName, RepositoryId,
+    ResponseFormat, SearchCursorByRepository, SearchQuery, SearchSort,
+};
 use anyhow::Result;
 use rmcp::{Error as McpError, model::*};
+use serde::Serialize;
 use serde_json;
 
-const DEFAULT_SEARCH_LIMIT: usize = 30;
-const DEFAULT_SEARCH_QUERY: &str = "state:open";
+use super::{json_content, parse_response_format};
 
 /// Search for issues, PRs, and projects across multiple repositories
 ///
 /// Comprehensive search across multiple resource types with support for specific
-/// repository targeting and advanced pagination.
+/// repository targeting and advanced pagination. Results follow GitHub's best-match
+/// ordering unless `sort` is given.
+///
+/// Discussions are searched as well when `include_discussions` is set, and instead of
+/// issues and PRs when the query contains `is:discussion`. They are listed after the
+/// other results in light format, without pagination.
+///
+/// An omitted query or limit falls back to the defaults stored in the current profile,
+/// and then to `DEFAULT_SEARCH_QUERY` / `DEFAULT_SEARCH_LIMIT`.
+///
+/// Results are listed repository by repository unless `merge_and_sort` is set, in which
+/// case the results of all repositories are merged into one list ordered by `sort`
+/// (most recently updated first when no sort is given).
+///
+/// `body_truncate_len` sets how many characters of each body the light format shows
+/// (default: `DEFAULT_LIGHT_BODY_LENGTH`); 0 omits the body.
+///
+/// `priority_labels` moves results carrying any of the listed labels to the front, in
+/// the order the labels are given; the remaining results keep their order.
+///
+/// With `response_format` set to json, the results, next page cursors and discussions
+/// are returned as one JSON object instead, and `output_option` is ignored.
+#[allow(clippy::too_many_arguments)]
 pub async fn search_in_repositories(
     github_token: &Option<String>,
     timezone: &Option<TimezoneOffset>,
+    render_options: &RenderOptions,
+    current_profile_name: &Option<ProfileName>,
     github_search_query: Option<String>,
     repository_urls: Vec<String>,
     limit: Option<usize>,
     cursors: Option<Vec<SearchCursorByRepository>>,
     output_option: Option<String>,
+    sort: Option<String>,
+    include_discussions: Option<bool>,
+    merge_and_sort: bool,
+    body_truncate_len: Option<usize>,
+    response_format: Option<String>,
+    priority_labels: Vec<String>,
 ) -> Result<CallToolResult, McpError> {
     let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
         McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
     })?;
 
-    let limit = limit.unwrap_or(DEFAULT_SEARCH_LIMIT);
+    let (github_search_query, limit) =
+        apply_current_profile_search_defaults(current_profile_name, github_search_query, limit)
+            .await;
+    let (limit, limit_note) = functions::search::clamp_search_limit(limit);
 
     // Convert String to OutputOption
     let format = if let Some(option_str) = output_option {
@@ -41,6 +82,9 @@ pub async fn search_in_repositories(
         OutputOption::default()
     };
 
+    let sort = parse_sort(sort)?;
+    let response_format = parse_response_format(response_format)?;
+
     // Convert String to SearchQuery, using default if not provided
     let query_string = github_search_query.unwrap_or_else(|| DEFAULT_SEARCH_QUERY.to_string());
     let query = SearchQuery::new(query_string);
@@ -66,55 +110,97 @@ pub async fn search_in_repositories(
     }
     let repository_urls = repo_ids;
 
-    // Search across repositories
-    let search_results = functions::search::search_resources(
-        &github_client,
-        repository_urls,
-        query,
-        Some(limit as u32),
-        cursors,
-    )
-    .await
-    .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    if let Some(cursors) = &cursors {
+        validate_cursor_repositories(cursors, &repository_urls)
+            .map_err(|e| McpError::invalid_request(e, None))?;
+    }
+
+    let discussions_only = query.targets_discussions();
+    let discussions = if discussions_only || include_discussions.unwrap_or(false) {
+        Some(
+            functions::search::search_discussions(
+                &github_client,
+                repository_urls.clone(),
+                query.clone(),
+                Some(limit as u32),
+            )
+            .await,
+        )
+    } else {
+        None
+    };
+
+    let mut results = Vec::new();
+    let mut next_cursors = Vec::new();
+    if !discussions_only {
+        // Search across repositories
+        let search_results = functions::search::search_resources(
+            &github_client,
+            repository_urls,
+            query,
+            Some(limit as u32),
+            cursors,
+            sort,
+        )
+        .await
+        .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+
+        results = search_results.results;
+        next_cursors = search_results.cursors;
+        if merge_and_sort {
+            merge_and_sort_results(&mut results, sort);
+        }
+        prioritize_by_labels(&mut results, &priority_labels);
+    }
+
+    if response_format == ResponseFormat::Json {
+        let response = SearchResponseJson {
+            note: limit_note,
+            results: &results,
+            next_page_cursors: &next_cursors,
+            discussions: discussions.as_deref(),
+        };
+        return Ok(CallToolResult {
+            content: vec![json_content(&response)?],
+            is_error: Some(false),
+        });
+    }
 
     // Format results as markdown
     let mut content_vec = Vec::new();
 
-    if search_results.results.is_empty() {
-        content_vec.push(Content::text("No results found.".to_string()));
-    } else {
-        for result in search_results.results {
-            let formatted = match result {
-                crate::types::IssueOrPullrequest::Issue(issue) => match format {
-                    OutputOption::Light => {
-                        issue_body_markdown_with_timezone_light(&issue, timezone.as_ref()).0
-                    }
-                    OutputOption::Rich => {
-                        issue_body_markdown_with_timezone(&issue, timezone.as_ref()).0
-                    }
-                },
-                crate::types::IssueOrPullrequest::PullRequest(pr) => match format {
-                    OutputOption::Light => {
-                        pull_request_body_markdown_with_timezone_light(&pr, timezone.as_ref()).0
-                    }
-                    OutputOption::Rich => {
-                        pull_request_body_markdown_with_timezone(&pr, timezone.as_ref()).0
-                    }
-                },
-            };
-            content_vec.push(Content::text(formatted));
+    if let Some(note) = limit_note {
+        content_vec.push(Content::text(note));
+    }
+
+    if !discussions_only {
+        if results.is_empty() {
+            content_vec.push(Content::text("No results found.".to_string()));
+        } else {
+            content_vec.extend(search_results_content(
+                &results,
+                &format,
+                timezone.as_ref(),
+                render_options,
+                body_truncate_len,
+            ));
+        }
+
+        // Add cursor information as JSON
+        if let Some(cursors_content) = next_page_cursors_content(&next_cursors)? {
+            content_vec.push(cursors_content);
         }
     }
 
-    // Add cursor information as JSON
-    if !search_results.cursors.is_empty() {
-        let cursors_json = serde_json::to_string_pretty(&search_results.cursors).map_err(|e| {
-            McpError::internal_error(format!("Failed to serialize cursors: {}", e), None)
-        })?;
-        content_vec.push(Content::text(format!(
-            "Next page cursors:\n```json\n{}\n```",
-            cursors_json
-        )));
+    if let Some(discussions) = discussions {
+        if discussions.is_empty() {
+            content_vec.push(Content::text("No discussions found.".to_string()));
+        }
+        for discussion in &discussions {
+            content_vec.push(Content::text(
+                discussion_markdown_with_timezone_light(discussion, timezone.as_ref()).0,
+            ));
+        }
     }
 
     Ok(CallToolResult {
@@ -122,3 +208,347 @@ pub async fn search_in_repositories(
         is_error: Some(false),
     })
 }
+
+/// JSON response of `search_in_repositories`
+#[derive(Serialize)]
+struct SearchResponseJson<'a> {
+    /// Note about a changed limit, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+    results: &'a [IssueOrPullrequest],
+    next_page_cursors: &'a [SearchCursorByRepository],
+    /// Matching discussions, present only when discussions were searched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discussions: Option<&'a [Discussion]>,
+}
+
+/// Fill an omitted query or limit from the defaults stored in the current profile
+///
+/// A missing or unreadable profile leaves both values as given.
+async fn apply_current_profile_search_defaults(
+    current_profile_name: &Option<ProfileName>,
+    query: Option<String>,
+    limit: Option<usize>,
+) -> (Option<String>, Option<usize>) {
+    if query.is_some() && limit.is_some_and(|limit| limit > 0) {
+        return (query, limit);
+    }
+
+    let profile_name = current_profile_name.clone().unwrap_or_default().to_string();
+    let profile = functions::profile::get_profile_info(profile_name)
+        .await
+        .ok();
+    functions::search::apply_profile_search_defaults(profile.as_ref(), query, limit)
+}
+
+/// Parse the `sort` parameter of the search tools
+pub(super) fn parse_sort(sort: Option<String>) -> Result<Option<SearchSort>, McpError> {
+    sort.map(|sort| {
+        sort.parse::<SearchSort>().map_err(|_| {
+            McpError::invalid_params(
+                format!(
+                    "Invalid sort '{}'. Expected one of: created_asc, created_desc, updated_asc, updated_desc, comments_asc, comments_desc",
+                    sort
+                ),
+                None,
+            )
+        })
+    })
+    .transpose()
+}
+
+/// Format search results as one table, or as one light or rich markdown block each
+///
+/// `body_truncate_len` is the number of body characters shown in light format.
+pub(super) fn search_results_content(
+    results: &[IssueOrPullrequest],
+    format: &OutputOption,
+    timezone: Option<&TimezoneOffset>,
+    render_options: &RenderOptions,
+    body_truncate_len: Option<usize>,
+) -> Vec<Content> {
+    let body_truncate_len = body_truncate_len.unwrap_or(DEFAULT_LIGHT_BODY_LENGTH);
+    match format {
+        OutputOption::Table => vec![Content::text(
+            search_results_table_markdown(results, timezone, render_options).0,
+        )],
+        OutputOption::Light | OutputOption::Rich => results
+            .iter()
+            .map(|result| {
+                Content::text(format_search_result(
+                    result,
+                    format,
+                    timezone,
+                    render_options,
+                    body_truncate_len,
+                ))
+            })
+            .collect(),
+    }
+}
+
+/// Format a single search result as light or rich markdown
+///
+/// The table format has no per-result form and falls back to light.
+fn format_search_result(
+    result: &IssueOrPullrequest,
+    format: &OutputOption,
+    timezone: Option<&TimezoneOffset>,
+    render_options: &RenderOptions,
+    body_truncate_len: usize,
+) -> String {
+    match result {
+        IssueOrPullrequest::Issue(issue) => match format {
+            OutputOption::Light | OutputOption::Table => {
+                issue_body_markdown_light_with_body_truncation(
+                    issue,
+                    timezone,
+                    body_truncate_len,
+                    render_options,
+                )
+                .0
+            }
+            OutputOption::Rich => {
+                issue_body_markdown_with_comment_range(
+                    issue,
+                    timezone,
+                    None,
+                    None,
+                    false,
+                    render_options,
+                )
+                .0
+            }
+        },
+        IssueOrPullrequest::PullRequest(pr) => match format {
+            OutputOption::Light | OutputOption::Table => {
+                pull_request_body_markdown_light_with_body_truncation(
+                    pr,
+                    timezone,
+                    body_truncate_len,
+                    render_options,
+                )
+                .0
+            }
+            OutputOption::Rich => {
+                pull_request_body_markdown_with_comment_filter(pr, timezone, false, render_options)
+                    .0
+            }
+        },
+    }
+}
+
+/// Order results collected from several repositories as one list
+///
+/// Without a sort the globally most recently updated results come first. The sort is
+/// stable, so results that compare equal keep their per-repository order.
+fn merge_and_sort_results(results: &mut [IssueOrPullrequest], sort: Option<SearchSort>) {
+    let sort = sort.unwrap_or(SearchSort::UpdatedDesc);
+    results.sort_by(|a, b| sort.compare(a, b));
+}
+
+/// Move results carrying any of `priority_labels` to the front
+///
+/// Results are ranked by the first of `priority_labels` they carry, compared
+/// case-insensitively like GitHub label names. The sort is stable, so results of the
+/// same rank, including those without any priority label, keep their order.
+fn prioritize_by_labels(results: &mut [IssueOrPullrequest], priority_labels: &[String]) {
+    if priority_labels.is_empty() {
+        return;
+    }
+    results.sort_by_key(|result| {
+        let labels = result.label_names();
+        priority_labels
+            .iter()
+            .position(|priority| {
+                labels
+                    .iter()
+                    .any(|label| label.eq_ignore_ascii_case(priority))
+            })
+            .unwrap_or(priority_labels.len())
+    });
+}
+
+/// Check that every cursor belongs to one of the searched repositories
+///
+/// A cursor for another repository would be silently ignored, returning the first
+/// page again instead of the page the caller asked for.
+fn validate_cursor_repositories(
+    cursors: &[SearchCursorByRepository],
+    repository_ids: &[RepositoryId],
+) -> Result<(), String> {
+    let unknown: Vec<String> = cursors
+        .iter()
+        .filter(|cursor| !repository_ids.contains(&cursor.repository_id))
+        .map(|cursor| cursor.repository_id.to_string())
+        .collect();
+
+    if unknown.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "cursors were given for repositories that are not in repository_urls: {}",
+            unknown.join(", ")
+        ))
+    }
+}
+
+/// Render next page cursors as a JSON block, or `None` when there are no further pages
+pub(super) fn next_page_cursors_content(
+    cursors: &[SearchCursorByRepository],
+) -> Result<Option<Content>, McpError> {
+    if cursors.is_empty() {
+        return Ok(None);
+    }
+
+    let cursors_json = serde_json::to_string_pretty(cursors).map_err(|e| {
+        McpError::internal_error(format!("Failed to serialize cursors: {}", e), None)
+    })?;
+    Ok(Some(Content::text(format!(
+        "Next page cursors:\n```json\n{}\n```",
+        cursors_json
+    ))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Issue;
+    use chrono::{DateTime, TimeZone, Utc};
+
+    fn issue(repository_name: &str, number: u32, updated_at: DateTime<Utc>) -> IssueOrPullrequest {
+        let mut issue = Issue::fixture(repository_name, number, &format!("Issue {}", number));
+        issue.updated_at = updated_at;
+        issue.comments_count = number;
+        IssueOrPullrequest::Issue(issue)
+    }
+
+    fn urls(results: &[IssueOrPullrequest]) -> Vec<String> {
+        results
+            .iter()
+            .map(|result| match result {
+                IssueOrPullrequest::Issue(issue) => issue.issue_id.url(),
+                IssueOrPullrequest::PullRequest(pr) => pr.pull_request_id.url(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_and_sort_results_across_repositories() {
+        let day = |day: u32| Utc.with_ymd_and_hms(2025, 3, day, 12, 0, 0).unwrap();
+        // Grouped per repository, each group newest first
+        let mut results = vec![
+            issue("first", 1, day(20)),
+            issue("first", 2, day(5)),
+            issue("second", 3, day(25)),
+            issue("second", 4, day(10)),
+        ];
+
+        merge_and_sort_results(&mut results, None);
+        assert_eq!(
+            urls(&results),
+            vec![
+                "https://github.com/owner/second/issues/3",
+                "https://github.com/owner/first/issues/1",
+                "https://github.com/owner/second/issues/4",
+                "https://github.com/owner/first/issues/2",
+            ]
+        );
+
+        merge_and_sort_results(&mut results, Some(SearchSort::CommentsDesc));
+        assert_eq!(
+            urls(&results),
+            vec![
+                "https://github.com/owner/second/issues/4",
+                "https://github.com/owner/second/issues/3",
+                "https://github.com/owner/first/issues/2",
+                "https://github.com/owner/first/issues/1",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_prioritize_by_labels_is_stable() {
+        let day = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+        let labeled = |number: u32, labels: &[&str]| {
+            let mut result = issue("repo", number, day);
+            if let IssueOrPullrequest::Issue(issue) = &mut result {
+                issue.labels = labels.iter().map(|label| label.to_string()).collect();
+            }
+            result
+        };
+        let mut results = vec![
+            labeled(1, &["bug"]),
+            labeled(2, &["P1"]),
+            labeled(3, &[]),
+            labeled(4, &["p0", "bug"]),
+            labeled(5, &["P1", "docs"]),
+            labeled(6, &["docs"]),
+            labeled(7, &["P0"]),
+        ];
+
+        prioritize_by_labels(&mut results, &["P0".to_string(), "P1".to_string()]);
+        let numbers: Vec<String> = urls(&results)
+            .iter()
+            .map(|url| url.rsplit('/').next().unwrap().to_string())
+            .collect();
+        assert_eq!(numbers, vec!["4", "7", "2", "5", "1", "3", "6"]);
+
+        // No priority labels keeps the current order
+        let prioritized = urls(&results);
+        prioritize_by_labels(&mut results, &[]);
+        assert_eq!(urls(&results), prioritized);
+    }
+
+    #[test]
+    fn test_search_response_json_is_parseable() {
+        let updated_at = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+        let results = vec![issue("repo", 7, updated_at)];
+        let cursors = vec![
+            SearchCursorByRepository::new("https://github.com/owner/repo", "Y3Vyc29yOjE=").unwrap(),
+        ];
+        let response = SearchResponseJson {
+            note: None,
+            results: &results,
+            next_page_cursors: &cursors,
+            discussions: None,
+        };
+
+        let json = serde_json::to_string_pretty(&response).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["results"].as_array().unwrap().len(), 1);
+        assert_eq!(value["results"][0]["Issue"]["title"], "Issue 7");
+        assert_eq!(value["next_page_cursors"][0]["cursor"], "Y3Vyc29yOjE=");
+        assert!(value.get("note").is_none());
+        assert!(value.get("discussions").is_none());
+    }
+
+    #[test]
+    fn test_validate_cursor_repositories() {
+        let repository_ids = vec![
+            RepositoryId::new("owner", "repo"),
+            RepositoryId::new("owner", "other"),
+        ];
+        let cursor = |url: &str| SearchCursorByRepository::new(url, "Y3Vyc29yOjE=").unwrap();
+
+        assert!(
+            validate_cursor_repositories(
+                &[cursor("https://github.com/owner/repo")],
+                &repository_ids
+            )
+            .is_ok()
+        );
+
+        let error = validate_cursor_repositories(
+            &[
+                cursor("https://github.com/owner/other"),
+                cursor("https://github.com/someone/else"),
+            ],
+            &repository_ids,
+        )
+        .unwrap_err();
+        assert!(error.contains("someone/else"));
+        assert!(!error.contains("owner/other"));
+    }
+}
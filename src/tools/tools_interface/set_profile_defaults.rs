@@ -0,0 +1,38 @@
+use crate::tools::functions;
+use crate::types::ProfileName;
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+/// Set the default search query and limit of a profile
+///
+/// Uses the current profile unless `profile_name` is given. Omitted values are left
+/// unchanged, and an empty query or a limit of 0 clears the stored default. Returns the
+/// resulting defaults as JSON.
+pub async fn set_profile_defaults(
+    current_profile_name: &Option<ProfileName>,
+    profile_name: Option<String>,
+    search_query: Option<String>,
+    search_limit: Option<usize>,
+) -> Result<CallToolResult, McpError> {
+    let profile_name = profile_name
+        .unwrap_or_else(|| current_profile_name.clone().unwrap_or_default().to_string());
+
+    let profile =
+        functions::profile::set_profile_defaults(profile_name, search_query, search_limit)
+            .await
+            .map_err(|e| McpError::invalid_request(e, None))?;
+
+    let json = serde_json::json!({
+        "profile_name": profile.name,
+        "default_search_query": profile.default_search_query,
+        "default_search_limit": profile.default_search_limit,
+    });
+    let json_string = serde_json::to_string_pretty(&json).map_err(|e| {
+        McpError::internal_error(format!("Failed to serialize profile defaults: {}", e), None)
+    })?;
+
+    Ok(CallToolResult {
+        content: vec![Content::text(json_string)],
+        is_error: Some(false),
+    })
+}
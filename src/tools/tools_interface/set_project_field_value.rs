@@ -0,0 +1,48 @@
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::{ProjectFieldId, ProjectFieldUpdateValue, ProjectItemId, ProjectUrl};
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+/// Set the value of a text, number, date, or single select field of a project item
+///
+/// This is a write operation and is rejected up front when no GitHub token is configured.
+pub async fn set_project_field_value(
+    github_token: &Option<String>,
+    project_url: String,
+    item_id: String,
+    field_id: String,
+    value_type: String,
+    value: String,
+) -> Result<CallToolResult, McpError> {
+    if github_token.is_none() {
+        return Err(McpError::invalid_request(
+            "set_project_field_value requires an authenticated GitHub token. Set GITHUB_INSIGHT_GITHUB_TOKEN or pass --github-token when starting the server.".to_string(),
+            None,
+        ));
+    }
+
+    let value = ProjectFieldUpdateValue::parse(&value_type, &value)
+        .map_err(|e| McpError::invalid_params(e, None))?;
+
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    let updated_item_id = functions::project::set_project_field_value(
+        &github_client,
+        ProjectUrl(project_url.clone()),
+        ProjectItemId(item_id),
+        ProjectFieldId(field_id.clone()),
+        value,
+    )
+    .await?;
+
+    Ok(CallToolResult {
+        content: vec![Content::text(format!(
+            "Set field {} of project item {} in {}",
+            field_id, updated_item_id, project_url
+        ))],
+        is_error: Some(false),
+    })
+}
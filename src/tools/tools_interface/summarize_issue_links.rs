@@ -0,0 +1,29 @@
+use crate::formatter::issue::issue_link_summary_markdown;
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::IssueUrl;
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+/// Resolve and summarize all links in an issue thread
+///
+/// Extracts links from the issue body and comments, fetches titles of linked
+/// issues, pull requests, and commits, and returns them grouped by category.
+/// External links are listed without fetching.
+pub async fn summarize_issue_links(
+    github_token: &Option<String>,
+    issue_url: String,
+) -> Result<CallToolResult, McpError> {
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    let summary = functions::issue::summarize_issue_links(&github_client, IssueUrl(issue_url))
+        .await
+        .map_err(|e| McpError::internal_error(format!("{:#}", e), None))?;
+
+    Ok(CallToolResult {
+        content: vec![Content::text(issue_link_summary_markdown(&summary).0)],
+        is_error: Some(false),
+    })
+}
@@ -0,0 +1,35 @@
+use crate::formatter::profile::profile_validation_report_markdown;
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::ProfileName;
+use anyhow::Result;
+use rmcp::{Error as McpError, model::*};
+
+/// Check that the repositories and projects registered in a profile are reachable
+///
+/// Uses the current profile unless `profile_name` is given. Returns a markdown report
+/// grouping the registered URLs into valid, not found, and access denied entries.
+/// The profile is not modified.
+pub async fn validate_profile(
+    github_token: &Option<String>,
+    current_profile_name: &Option<ProfileName>,
+    profile_name: Option<String>,
+) -> Result<CallToolResult, McpError> {
+    let github_client = GitHubClient::new(github_token.clone(), None).map_err(|e| {
+        McpError::internal_error(format!("Failed to create GitHub client: {}", e), None)
+    })?;
+
+    let profile_name = profile_name
+        .unwrap_or_else(|| current_profile_name.clone().unwrap_or_default().to_string());
+
+    let report = functions::profile::validate_profile(&github_client, profile_name)
+        .await
+        .map_err(|e| McpError::invalid_request(e, None))?;
+
+    let formatted = profile_validation_report_markdown(&report);
+
+    Ok(CallToolResult {
+        content: vec![Content::text(formatted.0)],
+        is_error: Some(false),
+    })
+}
@@ -1,10 +1,13 @@
 //! Transport layer implementations for MCP server
 //!
 //! This module provides different transport mechanisms for running
-//! the MCP server, including stdio and SSE (Server-Sent Events).
+//! the MCP server, including stdio, SSE (Server-Sent Events), and WebSocket.
 
 /// SSE (Server-Sent Events) transport for HTTP-based MCP communication
 pub mod sse_server;
 
 /// Standard I/O transport for subprocess-based MCP communication
 pub mod stdio;
+
+/// WebSocket transport for persistent bidirectional MCP communication
+pub mod websocket;
@@ -0,0 +1,146 @@
+use crate::{tools::GitInsightTools, types::ProfileName};
+use anyhow::Result;
+use futures::{SinkExt, StreamExt, future};
+use rmcp::ServiceExt;
+use rmcp::model::{ClientJsonRpcMessage, ServerJsonRpcMessage};
+use std::net::SocketAddr;
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::tungstenite::{self, Message};
+
+pub struct WebSocketServerApp {
+    bind_addr: SocketAddr,
+    github_token: Option<String>,
+    timezone: Option<String>,
+    profile_name: Option<ProfileName>,
+}
+
+impl WebSocketServerApp {
+    /// Creates a new WebSocket server application instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `bind_addr` - The socket address to bind the server to
+    /// * `github_token` - Optional GitHub personal access token for API authentication
+    ///
+    /// # Returns
+    ///
+    /// Returns a new WebSocketServerApp instance.
+    pub fn new(
+        bind_addr: SocketAddr,
+        github_token: Option<String>,
+        timezone: Option<String>,
+        profile_name: Option<ProfileName>,
+    ) -> Self {
+        Self {
+            bind_addr,
+            github_token,
+            timezone,
+            profile_name,
+        }
+    }
+
+    /// Starts the WebSocket server and serves GitInsightTools over WebSocket connections.
+    ///
+    /// This method binds to the configured address and waits for a Ctrl+C signal to
+    /// shutdown gracefully.
+    ///
+    /// # Returns
+    ///
+    /// Returns Ok(()) when the server shuts down gracefully.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The server fails to bind to the specified address
+    /// - Accepting TCP connections fails
+    pub async fn serve(self) -> Result<()> {
+        let listener = TcpListener::bind(self.bind_addr).await?;
+
+        tokio::select! {
+            result = self.serve_listener(listener) => result,
+            result = tokio::signal::ctrl_c() => {
+                tracing::info!("Shutting down WebSocket server");
+                result.map_err(Into::into)
+            }
+        }
+    }
+
+    /// Serves GitInsightTools on connections accepted from an already bound listener.
+    ///
+    /// Each connection gets its own service instance, which performs the MCP handshake
+    /// over the socket and stops when the client disconnects. Runs until accepting a
+    /// connection fails.
+    pub async fn serve_listener(self, listener: TcpListener) -> Result<()> {
+        // Initialize the service before accepting connections
+        tracing::info!("Initializing GitInsight service before starting WebSocket server...");
+        let init_service = GitInsightTools::new(
+            self.github_token.clone(),
+            self.timezone.clone(),
+            self.profile_name.clone(),
+        );
+        init_service.initialize().await?;
+        tracing::info!("GitInsight service initialization complete");
+
+        loop {
+            let (stream, peer_addr) = listener.accept().await?;
+            let service = GitInsightTools::new(
+                self.github_token.clone(),
+                self.timezone.clone(),
+                self.profile_name.clone(),
+            );
+
+            tokio::spawn(async move {
+                match serve_connection(stream, service).await {
+                    Ok(()) => tracing::info!("WebSocket client {} disconnected", peer_addr),
+                    Err(e) => tracing::warn!("WebSocket connection {} failed: {}", peer_addr, e),
+                }
+            });
+        }
+    }
+}
+
+/// Runs the MCP protocol over a single WebSocket connection until the client disconnects
+async fn serve_connection(stream: TcpStream, service: GitInsightTools) -> Result<()> {
+    let websocket = tokio_tungstenite::accept_async(stream).await?;
+    let (sink, stream) = websocket.split();
+
+    // Each JSON-RPC message travels as one text frame
+    let sink = sink.with(|message: ServerJsonRpcMessage| future::ready(encode_message(&message)));
+    let stream = stream.filter_map(|frame| future::ready(decode_frame(frame)));
+
+    let server = service.serve((sink, stream)).await?;
+    server.waiting().await?;
+    Ok(())
+}
+
+fn encode_message(message: &ServerJsonRpcMessage) -> Result<Message, tungstenite::Error> {
+    serde_json::to_string(message)
+        .map(Message::text)
+        .map_err(|e| {
+            tungstenite::Error::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+        })
+}
+
+/// Decode a text or binary frame into a client message, skipping control and invalid frames
+fn decode_frame(frame: Result<Message, tungstenite::Error>) -> Option<ClientJsonRpcMessage> {
+    let frame = match frame {
+        Ok(frame) => frame,
+        Err(e) => {
+            tracing::debug!("WebSocket read error: {}", e);
+            return None;
+        }
+    };
+
+    if !(frame.is_text() || frame.is_binary()) {
+        return None;
+    }
+
+    let text = frame.to_text().ok()?;
+    match serde_json::from_str(text) {
+        Ok(message) => Some(message),
+        Err(e) => {
+            tracing::warn!("Ignoring invalid JSON-RPC frame: {}", e);
+            None
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! Authentication status types
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Result of validating the configured GitHub token
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum AuthStatus {
+    /// No token is configured, so no request was made
+    NoToken,
+    /// The token is valid and belongs to `login`
+    Authenticated {
+        /// Login of the authenticated user
+        login: String,
+        /// OAuth scopes granted to the token; `None` for tokens that do not report
+        /// scopes, such as fine-grained personal access tokens
+        scopes: Option<Vec<String>>,
+    },
+    /// GitHub rejected the token
+    InvalidToken {
+        /// Error message returned by GitHub
+        message: String,
+    },
+}
+
+impl AuthStatus {
+    /// Returns true when the token was accepted by GitHub
+    pub fn is_authenticated(&self) -> bool {
+        matches!(self, AuthStatus::Authenticated { .. })
+    }
+}
@@ -0,0 +1,14 @@
+//! Repository contributor types
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contributor of a repository as reported by the REST contributors endpoint
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Contributor {
+    pub login: String,
+    /// Number of commits to the default branch
+    pub contributions: u32,
+    #[serde(default)]
+    pub avatar_url: String,
+}
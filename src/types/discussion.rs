@@ -0,0 +1,37 @@
+//! Discussion types for GitHub Discussions search results
+
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+use super::RepositoryId;
+
+/// Whether a discussion has an accepted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, Display)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscussionAnswerStatus {
+    #[strum(serialize = "answered")]
+    Answered,
+    #[strum(serialize = "unanswered")]
+    Unanswered,
+    /// The discussion's category does not accept answers (e.g. announcements)
+    #[strum(serialize = "not answerable")]
+    NotAnswerable,
+}
+
+/// GitHub discussion returned by a discussion search
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Discussion {
+    pub repository_id: RepositoryId,
+    pub number: u32,
+    pub title: String,
+    pub url: String,
+    /// Category name such as "Q&A" or "Ideas"
+    pub category: Option<String>,
+    pub answer_status: DiscussionAnswerStatus,
+    pub author: Option<String>,
+    pub comments_count: u32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
@@ -8,14 +8,15 @@
 use chrono::{DateTime, Utc};
 use once_cell::sync::Lazy;
 use regex::Regex;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
 
-use crate::types::{User, repository::RepositoryId};
+use crate::types::{ReactionSummary, User, repository::RepositoryId};
 
 use super::IssueOrPullrequestId;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct IssueUrl(pub String);
 
 impl std::fmt::Display for IssueUrl {
@@ -30,7 +31,7 @@ static ISSUE_URL_REGEX: Lazy<Regex> = Lazy::new(|| {
 });
 
 /// Wrapper type for issue numbers providing type safety
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct IssueNumber(pub u32);
 
 impl IssueNumber {
@@ -52,7 +53,7 @@ impl std::fmt::Display for IssueNumber {
 }
 
 /// Wrapper type for comment numbers providing type safety
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct CommentNumber(pub u32);
 
 impl CommentNumber {
@@ -74,7 +75,9 @@ impl std::fmt::Display for CommentNumber {
 }
 
 /// Represents the state of a GitHub issue.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumString, Display)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, EnumString, Display,
+)]
 #[strum(serialize_all = "UPPERCASE")] // For GraphQL API compatibility
 pub enum IssueState {
     /// Issue is open and active
@@ -90,7 +93,7 @@ pub enum IssueState {
 /// This struct encapsulates all issue identification logic and URL parsing
 /// specific to issues. Following domain-driven design, all issue URL
 /// parsing and reference extraction logic is self-contained within this domain.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct IssueId {
     pub git_repository: RepositoryId,
     pub number: u32,
@@ -145,7 +148,7 @@ impl std::fmt::Display for IssueId {
 ///
 /// Contains comprehensive issue information including comments, labels,
 /// assignees, and cross-references to other resources.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Issue {
     pub issue_id: IssueId,
     pub title: String,
@@ -162,6 +165,12 @@ pub struct Issue {
     pub milestone_id: Option<u64>,
     pub locked: bool,
     pub linked_resources: Vec<IssueOrPullrequestId>,
+    /// Reaction counts, present when fetched separately with `fetch_issue_reactions`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<ReactionSummary>,
+    /// Whether the issue's repository is archived (read-only)
+    #[serde(default)]
+    pub repository_archived: bool,
 }
 
 impl Issue {
@@ -200,12 +209,50 @@ impl Issue {
             milestone_id,
             locked,
             linked_resources,
+            reactions: None,
+            repository_archived: false,
         }
     }
+
+    /// Attach reaction counts fetched for this issue
+    pub fn with_reactions(mut self, reactions: ReactionSummary) -> Self {
+        self.reactions = Some(reactions);
+        self
+    }
+}
+
+impl Issue {
+    /// Open issue in `owner/<repository_name>` created and updated at
+    /// 2025-01-02 03:04:05 UTC, for tests to adjust field by field
+    ///
+    /// Public only so the CLI tests can build issues too.
+    #[doc(hidden)]
+    pub fn fixture(repository_name: &str, number: u32, title: &str) -> Self {
+        use chrono::TimeZone;
+
+        let created_at = Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap();
+        Self::new_with_all_fields(
+            IssueId::new(RepositoryId::new("owner", repository_name), number),
+            title.to_string(),
+            None,
+            IssueState::Open,
+            "alice".to_string(),
+            Vec::new(),
+            Vec::new(),
+            created_at,
+            created_at,
+            None,
+            0,
+            Vec::new(),
+            None,
+            false,
+            Vec::new(),
+        )
+    }
 }
 
 /// A comment ID specific to issue comments
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct GitIssueCommentId {
     pub git_issue_id: IssueId,
     pub comment_id: u64,
@@ -237,13 +284,16 @@ impl std::fmt::Display for GitIssueCommentId {
 }
 
 /// Represents a comment on a GitHub issue
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct IssueComment {
     pub comment_number: IssueCommentNumber,
     pub body: String,
     pub author: Option<User>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Whether the comment was written by a bot account such as a CI app
+    #[serde(default)]
+    pub author_is_bot: bool,
 }
 
 impl IssueComment {
@@ -255,18 +305,22 @@ impl IssueComment {
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
     ) -> Self {
+        let author_is_bot = author
+            .as_ref()
+            .is_some_and(|author| User::is_bot_login(author.as_str()));
         Self {
             comment_number,
             body,
             author,
             created_at,
             updated_at,
+            author_is_bot,
         }
     }
 }
 
 /// Wrapper type for comment numbers providing type safety
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct IssueCommentNumber(pub u64);
 
 impl IssueCommentNumber {
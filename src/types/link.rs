@@ -0,0 +1,357 @@
+//! Link extraction and classification
+//!
+//! This module extracts URLs from free text such as issue bodies and comments and
+//! classifies them into GitHub references (issues, pull requests, commits, and file
+//! permalinks), other GitHub pages, and external links.
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{IssueId, PullRequestId, repository::RepositoryId};
+
+/// Matches a URL in the `url` group, only where it starts a word so that hosts such as
+/// "xgithub.com" or "docs.github.com" are not read as a scheme-less "github.com" link
+static LINK_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"(?:^|[^\w.\-/@])(?P<url>(?:https?://)?(?:www\.)?github\.com/[^\s<>()\[\]{}"'`]+|https?://[^\s<>()\[\]{}"'`]+)"#,
+    )
+    .expect("Failed to compile link regex")
+});
+
+static COMMIT_SHA_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[0-9a-fA-F]{7,40}$").expect("Failed to compile commit SHA regex"));
+
+static LINE_RANGE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^L(\d+)(?:C\d+)?(?:-L(\d+)(?:C\d+)?)?$").expect("Failed to compile line regex")
+});
+
+/// Characters trimmed from the end of a URL found in prose (e.g. "see https://x.com/a.")
+const TRAILING_PUNCTUATION: &[char] = &['.', ',', ';', ':', '!', '?', '*', '_', '~'];
+
+/// Identifier of a commit in a repository
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CommitId {
+    pub git_repository: RepositoryId,
+    pub sha: String,
+}
+
+impl CommitId {
+    pub fn new(git_repository: RepositoryId, sha: impl Into<String>) -> Self {
+        Self {
+            git_repository,
+            sha: sha.into(),
+        }
+    }
+
+    /// Returns the commit URL
+    pub fn url(&self) -> String {
+        format!("{}/commit/{}", self.git_repository.url(), self.sha)
+    }
+
+    /// Returns the abbreviated (7 character) SHA
+    pub fn short_sha(&self) -> &str {
+        &self.sha[..self.sha.len().min(7)]
+    }
+}
+
+/// Commit with its lightweight metadata
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Commit {
+    pub commit_id: CommitId,
+    pub message_headline: String,
+    pub author: Option<String>,
+    pub committed_at: Option<DateTime<Utc>>,
+}
+
+/// Link to a file (optionally a line range) at a specific ref
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FilePermalink {
+    pub git_repository: RepositoryId,
+    pub git_ref: String,
+    pub path: String,
+    pub start_line: Option<u32>,
+    pub end_line: Option<u32>,
+}
+
+impl FilePermalink {
+    /// Returns the permalink URL
+    pub fn url(&self) -> String {
+        format!(
+            "{}/blob/{}/{}{}",
+            self.git_repository.url(),
+            self.git_ref,
+            self.path,
+            self.line_fragment()
+        )
+    }
+
+    /// Returns the line range fragment such as `#L10-L20`, or an empty string
+    fn line_fragment(&self) -> String {
+        match (self.start_line, self.end_line) {
+            (Some(start), Some(end)) => format!("#L{}-L{}", start, end),
+            (Some(start), None) => format!("#L{}", start),
+            _ => String::new(),
+        }
+    }
+}
+
+/// GitHub resource referenced by a link
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GitHubReference {
+    Issue(IssueId),
+    PullRequest(PullRequestId),
+    Commit(CommitId),
+    FilePermalink(FilePermalink),
+}
+
+impl GitHubReference {
+    /// Classify a GitHub URL, returning `None` for pages that are not a supported resource
+    pub fn parse_url(url: &str) -> Option<Self> {
+        let path = url
+            .trim_start_matches("https://")
+            .trim_start_matches("http://")
+            .trim_start_matches("www.")
+            .strip_prefix("github.com/")?;
+        let (path, fragment) = match path.split_once('#') {
+            Some((path, fragment)) => (path, Some(fragment)),
+            None => (path, None),
+        };
+        let path = path.split('?').next().unwrap_or(path);
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+        if segments.len() < 4 {
+            return None;
+        }
+        let repository_id = RepositoryId::new(segments[0], segments[1]);
+
+        match (segments[2], &segments[3..]) {
+            ("issues", [number, ..]) => number
+                .parse::<u32>()
+                .ok()
+                .map(|number| Self::Issue(IssueId::new(repository_id, number))),
+            ("pull", [_, "commits", sha, ..]) if COMMIT_SHA_REGEX.is_match(sha) => {
+                Some(Self::Commit(CommitId::new(repository_id, *sha)))
+            }
+            ("pull", [number, ..]) => number
+                .parse::<u32>()
+                .ok()
+                .map(|number| Self::PullRequest(PullRequestId::new(repository_id, number))),
+            ("commit", [sha, ..]) if COMMIT_SHA_REGEX.is_match(sha) => {
+                Some(Self::Commit(CommitId::new(repository_id, *sha)))
+            }
+            ("blob", [git_ref, path @ ..]) if !path.is_empty() => {
+                let (start_line, end_line) = fragment
+                    .and_then(|fragment| LINE_RANGE_REGEX.captures(fragment))
+                    .map(|captures| {
+                        (
+                            captures.get(1).and_then(|m| m.as_str().parse().ok()),
+                            captures.get(2).and_then(|m| m.as_str().parse().ok()),
+                        )
+                    })
+                    .unwrap_or((None, None));
+                Some(Self::FilePermalink(FilePermalink {
+                    git_repository: repository_id,
+                    git_ref: git_ref.to_string(),
+                    path: path.join("/"),
+                    start_line,
+                    end_line,
+                }))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the canonical URL of the referenced resource
+    pub fn url(&self) -> String {
+        match self {
+            Self::Issue(issue_id) => issue_id.url(),
+            Self::PullRequest(pr_id) => pr_id.url(),
+            Self::Commit(commit_id) => commit_id.url(),
+            Self::FilePermalink(permalink) => permalink.url(),
+        }
+    }
+}
+
+/// Links found in text, classified and deduplicated in order of first appearance
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExtractedLinks {
+    pub references: Vec<GitHubReference>,
+    /// GitHub pages that are not issues, pull requests, commits, or file permalinks
+    pub other_github_urls: Vec<String>,
+    /// Non-GitHub URLs
+    pub external_urls: Vec<String>,
+}
+
+impl ExtractedLinks {
+    /// Extract and classify all links in a text
+    pub fn extract_from_text(text: &str) -> Self {
+        let mut links = Self::default();
+        links.extend_from_text(text);
+        links
+    }
+
+    /// Extract links from another text, skipping links already collected
+    pub fn extend_from_text(&mut self, text: &str) {
+        for captures in LINK_REGEX.captures_iter(text) {
+            let url = trim_url(&captures["url"]);
+            let is_github = url
+                .trim_start_matches("https://")
+                .trim_start_matches("http://")
+                .trim_start_matches("www.")
+                .starts_with("github.com/");
+
+            if !is_github {
+                push_unique(&mut self.external_urls, url.to_string());
+            } else if let Some(reference) = GitHubReference::parse_url(url) {
+                if !self.references.contains(&reference) {
+                    self.references.push(reference);
+                }
+            } else {
+                let url = if url.starts_with("http") {
+                    url.to_string()
+                } else {
+                    format!("https://{}", url)
+                };
+                push_unique(&mut self.other_github_urls, url);
+            }
+        }
+    }
+}
+
+/// Trim trailing sentence punctuation and markdown emphasis from a URL found in prose
+fn trim_url(url: &str) -> &str {
+    url.trim_end_matches(TRAILING_PUNCTUATION)
+}
+
+fn push_unique(urls: &mut Vec<String>, url: String) {
+    if !urls.contains(&url) {
+        urls.push(url);
+    }
+}
+
+/// Resolved link with lightweight metadata for display
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkSummaryEntry {
+    pub url: String,
+    /// Title of the linked resource; `None` when it could not be resolved
+    pub title: Option<String>,
+    /// Short extra information such as state or author
+    pub detail: Option<String>,
+}
+
+/// Categorized summary of all links in an issue thread
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueLinkSummary {
+    pub issue_id: IssueId,
+    pub issue_title: String,
+    pub issues: Vec<LinkSummaryEntry>,
+    pub pull_requests: Vec<LinkSummaryEntry>,
+    pub commits: Vec<LinkSummaryEntry>,
+    pub file_permalinks: Vec<LinkSummaryEntry>,
+    pub other_github_links: Vec<String>,
+    pub external_links: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_url_issue_and_pull_request() {
+        assert_eq!(
+            GitHubReference::parse_url("https://github.com/owner/repo/issues/12"),
+            Some(GitHubReference::Issue(IssueId::new(
+                RepositoryId::new("owner", "repo"),
+                12
+            )))
+        );
+        assert_eq!(
+            GitHubReference::parse_url("github.com/owner/repo/pull/34/files"),
+            Some(GitHubReference::PullRequest(PullRequestId::new(
+                RepositoryId::new("owner", "repo"),
+                34
+            )))
+        );
+    }
+
+    #[test]
+    fn test_parse_url_commits() {
+        let expected = Some(GitHubReference::Commit(CommitId::new(
+            RepositoryId::new("owner", "repo"),
+            "a1b2c3d4e5",
+        )));
+        assert_eq!(
+            GitHubReference::parse_url("https://github.com/owner/repo/commit/a1b2c3d4e5"),
+            expected
+        );
+        assert_eq!(
+            GitHubReference::parse_url("https://github.com/owner/repo/pull/34/commits/a1b2c3d4e5"),
+            expected
+        );
+        assert_eq!(
+            GitHubReference::parse_url("https://github.com/owner/repo/commit/not-a-sha"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_url_file_permalink() {
+        let reference = GitHubReference::parse_url(
+            "https://github.com/owner/repo/blob/0123abcd/src/lib.rs#L10-L20",
+        );
+        let Some(GitHubReference::FilePermalink(permalink)) = reference else {
+            panic!("expected file permalink, got {:?}", reference);
+        };
+        assert_eq!(permalink.git_ref, "0123abcd");
+        assert_eq!(permalink.path, "src/lib.rs");
+        assert_eq!(permalink.start_line, Some(10));
+        assert_eq!(permalink.end_line, Some(20));
+        assert_eq!(
+            permalink.url(),
+            "https://github.com/owner/repo/blob/0123abcd/src/lib.rs#L10-L20"
+        );
+    }
+
+    #[test]
+    fn test_extract_from_text_categorizes_and_deduplicates() {
+        let text = "Fixed by https://github.com/owner/repo/pull/5. See \
+                    https://github.com/owner/repo/issues/3, (https://github.com/owner/repo/issues/3) \
+                    and https://github.com/owner/repo/commit/abcdef1234 plus \
+                    https://github.com/owner/repo/blob/main/README.md#L1. \
+                    Docs: https://docs.rs/regex/latest/regex/ and https://github.com/owner/repo/releases";
+
+        let links = ExtractedLinks::extract_from_text(text);
+        let urls: Vec<String> = links.references.iter().map(|r| r.url()).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://github.com/owner/repo/pull/5",
+                "https://github.com/owner/repo/issues/3",
+                "https://github.com/owner/repo/commit/abcdef1234",
+                "https://github.com/owner/repo/blob/main/README.md#L1",
+            ]
+        );
+        assert_eq!(
+            links.other_github_urls,
+            vec!["https://github.com/owner/repo/releases"]
+        );
+        assert_eq!(
+            links.external_urls,
+            vec!["https://docs.rs/regex/latest/regex/"]
+        );
+    }
+
+    #[test]
+    fn test_extract_from_text_ignores_github_inside_other_hosts() {
+        let text = "Mirror at xgithub.com/owner/repo/issues/1 and my-github.com/owner/repo, \
+                    docs at https://docs.github.com/en/rest. Tracked in github.com/owner/repo/issues/2";
+
+        let links = ExtractedLinks::extract_from_text(text);
+        let urls: Vec<String> = links.references.iter().map(|r| r.url()).collect();
+        assert_eq!(urls, vec!["https://github.com/owner/repo/issues/2"]);
+        assert!(links.other_github_urls.is_empty());
+        assert_eq!(links.external_urls, vec!["https://docs.github.com/en/rest"]);
+    }
+}
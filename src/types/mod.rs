@@ -10,64 +10,191 @@ use strum::EnumString;
 
 pub use crate::github::graphql::graphql_types::repository::MilestoneNumber;
 
+pub mod auth;
+pub mod contributor;
+pub mod discussion;
 pub mod issue;
 pub mod label;
+pub mod link;
 pub mod profile;
 pub mod project;
 pub mod pull_request;
+pub mod rate_limit;
+pub mod reaction;
 pub mod repository;
 pub mod search;
+pub mod timeline;
 pub mod user;
 
+pub use auth::*;
+pub use contributor::*;
+pub use discussion::*;
 pub use issue::*;
+pub use link::*;
 pub use profile::*;
 pub use project::*;
 pub use pull_request::*;
+pub use rate_limit::*;
+pub use reaction::*;
 pub use repository::*;
 pub use search::*;
+pub use timeline::*;
 pub use user::*;
 
 use once_cell::sync::Lazy;
 use regex::Regex;
 
+/// A user-supplied GitHub URL that could not be parsed
+///
+/// Wraps the message of the `parse_url` functions so callers can recognize URL
+/// errors by type after adding context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidUrlError(pub String);
+
+impl std::fmt::Display for InvalidUrlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidUrlError {}
+
+/// Matches issue and pull request URLs whose number ends at a word boundary
+///
+/// Trailing path segments (`/files`), query strings and fragments such as
+/// `#issuecomment-456` are left out of the match, while `/issues/123abc` is rejected.
 static ISSUE_PR_URL_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"(?:https?://)?github\.com/([^/\s]+)/([^/\s]+)/(?:pull|issues)/(\d+)")
+    Regex::new(r"(?:https?://)?github\.com/([^/\s]+)/([^/\s]+)/(?:pull|issues)/(\d+)\b")
         .expect("Failed to compile GitHub URL regex")
 });
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+/// Matches a whole issue or pull request URL ending in an `#issuecomment-<id>` anchor
+static COMMENT_ANCHOR_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^(?:https?://)?github\.com/[^/\s]+/[^/\s]+/(?:pull|issues)/\d+/?(?:\?[^\s#]*)?#issuecomment-(\d+)$",
+    )
+    .expect("Failed to compile comment anchor regex")
+});
+
+/// Matches `owner/repo#123` references that start a word
+static SHORTHAND_REFERENCE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:^|[\s(\[,;:])([A-Za-z0-9][A-Za-z0-9-]*)/([A-Za-z0-9._-]+)#(\d+)\b")
+        .expect("Failed to compile shorthand reference regex")
+});
+
+/// Matches bare `#123` references that start a word
+static BARE_REFERENCE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:^|[\s(\[,;:])#(\d+)\b").expect("Failed to compile bare reference regex")
+});
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash)]
 pub enum IssueOrPullrequestId {
     IssueId(IssueId),
     PullrequestId(PullRequestId),
 }
 
 impl IssueOrPullrequestId {
+    /// Extracts issue and pull request references from text, in order of appearance
+    ///
+    /// Recognizes full GitHub URLs and `owner/repo#123` shorthand. The shorthand does not
+    /// say whether the number is an issue or a pull request, so it is returned as an
+    /// `IssueId`; GitHub redirects issue URLs of pull requests to the pull request.
+    /// Bare `#123` references are skipped since their repository is unknown.
     pub fn extract_resource_url_from_text(text: &str) -> Vec<IssueOrPullrequestId> {
-        let mut results = Vec::new();
+        Self::extract_references(text, None)
+    }
+
+    /// Same as `extract_resource_url_from_text`, but also resolves bare `#123`
+    /// references against the given default repository
+    pub fn extract_resource_url_from_text_with_context(
+        text: &str,
+        default_repository: &RepositoryId,
+    ) -> Vec<IssueOrPullrequestId> {
+        Self::extract_references(text, Some(default_repository))
+    }
+
+    fn extract_references(
+        text: &str,
+        default_repository: Option<&RepositoryId>,
+    ) -> Vec<IssueOrPullrequestId> {
+        let mut results: Vec<(usize, IssueOrPullrequestId)> = Vec::new();
 
         for captures in ISSUE_PR_URL_REGEX.captures_iter(text) {
             let number = captures.get(3).unwrap().as_str();
 
             if number.parse::<u32>().is_ok() {
-                let full_match = captures.get(0).unwrap().as_str();
+                let full_match = captures.get(0).unwrap();
+                let position = full_match.start();
+                let full_match = full_match.as_str();
                 if full_match.contains("/pull/") {
                     // Parse as pull request
                     let pr_url = PullRequestUrl(full_match.to_string());
                     if let Ok(pr_id) = PullRequestId::parse_url(&pr_url) {
-                        results.push(IssueOrPullrequestId::PullrequestId(pr_id));
+                        results.push((position, IssueOrPullrequestId::PullrequestId(pr_id)));
                     }
                 } else if full_match.contains("/issues/") {
                     // Parse as issue
                     let issue_url = IssueUrl(full_match.to_string());
                     if let Ok(issue_id) = IssueId::parse_url(&issue_url) {
-                        results.push(IssueOrPullrequestId::IssueId(issue_id));
+                        results.push((position, IssueOrPullrequestId::IssueId(issue_id)));
                     }
                 }
             }
         }
 
-        results
+        for captures in SHORTHAND_REFERENCE_REGEX.captures_iter(text) {
+            let owner = captures.get(1).unwrap();
+            let repository_name = captures.get(2).unwrap().as_str();
+            if let Ok(number) = captures.get(3).unwrap().as_str().parse::<u32>() {
+                let repository_id = RepositoryId::new(owner.as_str(), repository_name);
+                results.push((
+                    owner.start(),
+                    IssueOrPullrequestId::IssueId(IssueId::new(repository_id, number)),
+                ));
+            }
+        }
+
+        if let Some(default_repository) = default_repository {
+            for captures in BARE_REFERENCE_REGEX.captures_iter(text) {
+                let number = captures.get(1).unwrap();
+                if let Ok(parsed) = number.as_str().parse::<u32>() {
+                    results.push((
+                        number.start(),
+                        IssueOrPullrequestId::IssueId(IssueId::new(
+                            default_repository.clone(),
+                            parsed,
+                        )),
+                    ));
+                }
+            }
+        }
+
+        results.sort_by_key(|(position, _)| *position);
+        results.into_iter().map(|(_, id)| id).collect()
     }
+
+    /// Parses an issue or pull request URL, telling the two apart by their path
+    pub fn parse_url(url: &str) -> Result<Self, String> {
+        if url.contains("/pull/") {
+            PullRequestId::parse_url(&PullRequestUrl(url.to_string()))
+                .map(IssueOrPullrequestId::PullrequestId)
+        } else if url.contains("/issues/") {
+            IssueId::parse_url(&IssueUrl(url.to_string())).map(IssueOrPullrequestId::IssueId)
+        } else {
+            Err(format!("Not an issue or pull request URL: {}", url))
+        }
+    }
+
+    /// Returns the comment id of an `#issuecomment-<id>` anchor on an issue or pull request URL
+    ///
+    /// Returns `None` for URLs without such an anchor, including anchors on other pages
+    /// such as `/files`.
+    pub fn parse_comment_anchor(url: &str) -> Option<u64> {
+        COMMENT_ANCHOR_REGEX
+            .captures(url.trim())
+            .and_then(|captures| captures.get(1)?.as_str().parse().ok())
+    }
+
     pub fn url(&self) -> String {
         match self {
             IssueOrPullrequestId::IssueId(issue_id) => issue_id.url(),
@@ -76,12 +203,59 @@ impl IssueOrPullrequestId {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum IssueOrPullrequest {
     Issue(Issue),
     PullRequest(PullRequest),
 }
 
+impl IssueOrPullrequest {
+    /// When the issue or pull request was last updated
+    pub fn updated_at(&self) -> chrono::DateTime<chrono::Utc> {
+        match self {
+            IssueOrPullrequest::Issue(issue) => issue.updated_at,
+            IssueOrPullrequest::PullRequest(pull_request) => pull_request.updated_at,
+        }
+    }
+
+    /// When the issue or pull request was created
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        match self {
+            IssueOrPullrequest::Issue(issue) => issue.created_at,
+            IssueOrPullrequest::PullRequest(pull_request) => pull_request.created_at,
+        }
+    }
+
+    /// Number of comments on the issue or pull request
+    pub fn comments_count(&self) -> usize {
+        match self {
+            IssueOrPullrequest::Issue(issue) => issue.comments_count as usize,
+            IssueOrPullrequest::PullRequest(pull_request) => pull_request.comments_count as usize,
+        }
+    }
+
+    /// Names of the labels on the issue or pull request
+    pub fn label_names(&self) -> Vec<&str> {
+        match self {
+            IssueOrPullrequest::Issue(issue) => issue.labels.iter().map(String::as_str).collect(),
+            IssueOrPullrequest::PullRequest(pull_request) => pull_request
+                .labels
+                .iter()
+                .map(|label| label.name())
+                .collect(),
+        }
+    }
+}
+
+/// Issues and pull requests fetched from a mixed list of URLs
+#[derive(Debug, Clone)]
+pub struct ResourcesDetails {
+    /// Fetched resources in the order of the input URLs
+    pub resources: Vec<IssueOrPullrequest>,
+    /// Input URLs that could not be parsed or fetched, with the reason
+    pub errors: Vec<(String, String)>,
+}
+
 pub struct SearchResult {
     pub repository_id: RepositoryId,
     pub issue_or_pull_requests: Vec<crate::types::IssueOrPullrequest>,
@@ -98,6 +272,27 @@ pub enum OutputOption {
     Light,
     /// Rich format with comprehensive details
     Rich,
+    /// Single markdown table with one row per search result
+    ///
+    /// Only search results are rendered as a table; other resources use the light format.
+    Table,
+}
+
+/// Response format of MCP tools
+///
+/// Markdown is meant for reading; JSON serializes the fetched domain types as they are,
+/// for integrations that would otherwise have to parse the markdown.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema, Default, EnumString,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum ResponseFormat {
+    /// Human-readable markdown
+    #[default]
+    Markdown,
+    /// Pretty-printed JSON of the fetched resources
+    Json,
 }
 
 #[cfg(test)]
@@ -250,4 +445,182 @@ mod tests {
         assert_eq!(issue_count, 2);
         assert_eq!(pr_count, 1);
     }
+
+    #[test]
+    fn test_extract_resource_url_from_text_shorthand() {
+        let text = "Fixed by rust-lang/rust#12345 (see also tokio-rs/tokio.rs#42).";
+        let results = IssueOrPullrequestId::extract_resource_url_from_text(text);
+
+        assert_eq!(results.len(), 2);
+
+        match &results[0] {
+            IssueOrPullrequestId::IssueId(issue_id) => {
+                assert_eq!(issue_id.git_repository.owner, "rust-lang".into());
+                assert_eq!(issue_id.git_repository.repository_name, "rust".into());
+                assert_eq!(issue_id.number, 12345);
+            }
+            _ => panic!("Expected IssueId"),
+        }
+
+        match &results[1] {
+            IssueOrPullrequestId::IssueId(issue_id) => {
+                assert_eq!(issue_id.git_repository.owner, "tokio-rs".into());
+                assert_eq!(issue_id.git_repository.repository_name, "tokio.rs".into());
+                assert_eq!(issue_id.number, 42);
+            }
+            _ => panic!("Expected IssueId"),
+        }
+    }
+
+    #[test]
+    fn test_extract_resource_url_from_text_shorthand_not_inside_urls() {
+        let text = "See https://example.com/owner/repo#12 and https://github.com/owner/repo/pull/1#issuecomment-5";
+        let results = IssueOrPullrequestId::extract_resource_url_from_text(text);
+
+        assert_eq!(results.len(), 1);
+        assert!(matches!(
+            &results[0],
+            IssueOrPullrequestId::PullrequestId(pr_id) if pr_id.number == 1
+        ));
+    }
+
+    #[test]
+    fn test_extract_resource_url_from_text_skips_bare_numbers() {
+        let text = "Duplicate of #123, related to #456";
+        let results = IssueOrPullrequestId::extract_resource_url_from_text(text);
+        assert_eq!(results.len(), 0);
+    }
+
+    #[test]
+    fn test_extract_resource_url_from_text_with_context() {
+        let text = "Duplicate of #123, related to other/project#7 and issue#8";
+        let default_repository = RepositoryId::new("owner", "repo");
+        let results = IssueOrPullrequestId::extract_resource_url_from_text_with_context(
+            text,
+            &default_repository,
+        );
+
+        assert_eq!(results.len(), 2);
+
+        match &results[0] {
+            IssueOrPullrequestId::IssueId(issue_id) => {
+                assert_eq!(issue_id.git_repository, default_repository);
+                assert_eq!(issue_id.number, 123);
+            }
+            _ => panic!("Expected IssueId"),
+        }
+
+        match &results[1] {
+            IssueOrPullrequestId::IssueId(issue_id) => {
+                assert_eq!(
+                    issue_id.git_repository,
+                    RepositoryId::new("other", "project")
+                );
+                assert_eq!(issue_id.number, 7);
+            }
+            _ => panic!("Expected IssueId"),
+        }
+    }
+
+    #[test]
+    fn test_issue_or_pullrequest_id_parse_url() {
+        let issue = IssueOrPullrequestId::parse_url("https://github.com/owner/repo/issues/12");
+        assert!(matches!(issue, Ok(IssueOrPullrequestId::IssueId(id)) if id.number == 12));
+
+        let pull_request = IssueOrPullrequestId::parse_url("https://github.com/owner/repo/pull/7/");
+        assert!(matches!(
+            pull_request,
+            Ok(IssueOrPullrequestId::PullrequestId(id)) if id.number == 7
+        ));
+
+        assert!(IssueOrPullrequestId::parse_url("https://github.com/owner/repo").is_err());
+        assert!(IssueOrPullrequestId::parse_url("https://github.com/owner/repo/issues/x").is_err());
+    }
+
+    #[test]
+    fn test_extract_resource_url_from_text_mixed_urls_and_shorthand() {
+        let text = r#"
+        Full URL: https://github.com/microsoft/vscode/pull/3604
+        Shorthand: facebook/react#9876
+        Another URL: github.com/rust-lang/rust/issues/12345
+        Bare: #55
+        "#;
+        let results = IssueOrPullrequestId::extract_resource_url_from_text_with_context(
+            text,
+            &RepositoryId::new("owner", "repo"),
+        );
+
+        let urls: Vec<String> = results.iter().map(|result| result.url()).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://github.com/microsoft/vscode/pull/3604",
+                "https://github.com/facebook/react/issues/9876",
+                "https://github.com/rust-lang/rust/issues/12345",
+                "https://github.com/owner/repo/issues/55",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_resource_url_from_text_trailing_segments() {
+        let text = "Anchored https://github.com/owner/repo/issues/12#issuecomment-456, \
+            files https://github.com/owner/repo/pull/34/files, \
+            query https://github.com/owner/repo/issues/56?q=label, \
+            suffixed https://github.com/owner/repo/issues/78abc";
+        let results = IssueOrPullrequestId::extract_resource_url_from_text(text);
+
+        let urls: Vec<String> = results.iter().map(|result| result.url()).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://github.com/owner/repo/issues/12",
+                "https://github.com/owner/repo/pull/34",
+                "https://github.com/owner/repo/issues/56",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_comment_anchor() {
+        assert_eq!(
+            IssueOrPullrequestId::parse_comment_anchor(
+                "https://github.com/owner/repo/issues/12#issuecomment-456"
+            ),
+            Some(456)
+        );
+        assert_eq!(
+            IssueOrPullrequestId::parse_comment_anchor(
+                "https://github.com/owner/repo/pull/34?notification_referrer_id=1#issuecomment-789"
+            ),
+            Some(789)
+        );
+        assert_eq!(
+            IssueOrPullrequestId::parse_comment_anchor("https://github.com/owner/repo/issues/12"),
+            None
+        );
+        assert_eq!(
+            IssueOrPullrequestId::parse_comment_anchor(
+                "https://github.com/owner/repo/pull/34/files#issuecomment-789"
+            ),
+            None
+        );
+        assert_eq!(
+            IssueOrPullrequestId::parse_comment_anchor(
+                "https://github.com/owner/repo/issues/12?q=is%3Aopen"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_response_format_parse() {
+        assert_eq!(
+            "markdown".parse::<ResponseFormat>(),
+            Ok(ResponseFormat::Markdown)
+        );
+        assert_eq!("json".parse::<ResponseFormat>(), Ok(ResponseFormat::Json));
+        assert!("yaml".parse::<ResponseFormat>().is_err());
+        assert_eq!(ResponseFormat::default(), ResponseFormat::Markdown);
+    }
 }
@@ -1,11 +1,11 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
 
-use crate::types::repository::{Owner, RepositoryName};
-use crate::types::{Branch, ProjectId, RepositoryId};
+use crate::types::repository::RepositoryUrl;
+use crate::types::{Branch, BranchStatus, ProjectId, PullRequest, RepositoryId};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct ProfileName(pub String);
@@ -51,18 +51,34 @@ impl RepositoryBranchPair {
         }
     }
 
-    /// Parse a single repository branch specifier from string in format "repo_url@branch"
-    pub fn try_from_str(specifier: &str) -> anyhow::Result<Self> {
-        let parts: Vec<&str> = specifier.split('@').collect();
-        if parts.len() != 2 {
-            return Err(anyhow::anyhow!(
-                "Invalid repository branch specifier format '{}'. Expected format: 'repo_url@branch'",
-                specifier
-            ));
-        }
-
-        let repo_url = parts[0].trim();
-        let branch_name = parts[1].trim();
+    /// Parse a repository branch specifier in format "repo_url@branch"
+    ///
+    /// The specifier is split on its last `@`, so SSH URLs such as
+    /// `git@github.com:owner/repo@main` are accepted, and branch names may contain `/`
+    /// (e.g. `feature/x`). The URL must be accepted by `RepositoryId::parse_url` and the
+    /// branch name must not contain whitespace.
+    ///
+    /// `repo_url@` and a bare `repo_url` select the repository's default branch: the
+    /// pair gets `Branch::default_marker()`, which is resolved when the branch is
+    /// fetched (see `GitHubClient::fetch_default_branch`).
+    pub fn parse_specifier(specifier: &str) -> anyhow::Result<Self> {
+        let specifier = specifier.trim();
+        let parse_repository_url =
+            |url: &str| RepositoryId::parse_url(&RepositoryUrl(url.to_string()));
+
+        let Some((repo_url, branch_name)) = specifier.rsplit_once('@') else {
+            return parse_repository_url(specifier)
+                .map(|repository_id| Self::new(repository_id, Branch::default_marker()))
+                .map_err(|_| {
+                    anyhow::anyhow!(
+                        "Invalid repository branch specifier format '{}'. Expected format: 'repo_url@branch', or 'repo_url' for the default branch",
+                        specifier
+                    )
+                });
+        };
+
+        let repo_url = repo_url.trim();
+        let branch_name = branch_name.trim();
 
         if repo_url.is_empty() {
             return Err(anyhow::anyhow!(
@@ -71,17 +87,41 @@ impl RepositoryBranchPair {
             ));
         }
 
+        let repository_id = match parse_repository_url(repo_url) {
+            Ok(repository_id) => repository_id,
+            // The `@` may belong to an SSH URL given without a branch
+            Err(e) => {
+                return parse_repository_url(specifier)
+                    .map(|repository_id| Self::new(repository_id, Branch::default_marker()))
+                    .map_err(|_| {
+                        anyhow::anyhow!(
+                            "Invalid repository URL '{}' in specifier '{}': {}",
+                            repo_url,
+                            specifier,
+                            e
+                        )
+                    });
+            }
+        };
+
         if branch_name.is_empty() {
+            return Ok(Self::new(repository_id, Branch::default_marker()));
+        }
+
+        if branch_name.contains(char::is_whitespace) {
             return Err(anyhow::anyhow!(
-                "Branch name cannot be empty in specifier '{}'",
+                "Branch name '{}' cannot contain whitespace in specifier '{}'",
+                branch_name,
                 specifier
             ));
         }
 
-        let repository_id = Self::parse_repository_url(repo_url)?;
-        let branch = Branch::new(branch_name);
+        Ok(Self::new(repository_id, Branch::new(branch_name)))
+    }
 
-        Ok(Self::new(repository_id, branch))
+    /// Parse a single repository branch specifier, see `parse_specifier`
+    pub fn try_from_str(specifier: &str) -> anyhow::Result<Self> {
+        Self::parse_specifier(specifier)
     }
 
     /// Parse multiple repository branch specifiers from strings in format "repo_url@branch"
@@ -89,38 +129,83 @@ impl RepositoryBranchPair {
         let mut parsed_specifiers = Vec::new();
 
         for specifier in specifiers {
-            let pair = Self::try_from_str(specifier.trim())?;
+            let pair = Self::parse_specifier(specifier)?;
             parsed_specifiers.push(pair);
         }
 
         Ok(parsed_specifiers)
     }
+}
+
+impl fmt::Display for RepositoryBranchPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}@{}", self.repository_id, self.branch.as_str())
+    }
+}
+
+/// Branch name pattern used to select branches across repositories
+///
+/// A pattern wrapped in slashes (e.g. `/^release-\d+$/`) is a regular expression matched
+/// anywhere in the branch name. Any other pattern is a glob matched against the whole
+/// branch name, where `*` matches any run of characters and `?` a single character.
+#[derive(Debug, Clone)]
+pub struct BranchPattern {
+    pattern: String,
+    regex: regex::Regex,
+}
+
+impl BranchPattern {
+    /// Parse a glob or slash-delimited regular expression
+    pub fn parse(pattern: &str) -> anyhow::Result<Self> {
+        let pattern = pattern.trim();
+        if pattern.is_empty() {
+            return Err(anyhow::anyhow!("Branch pattern cannot be empty"));
+        }
 
-    /// Parse repository URL into RepositoryId
-    /// This is a helper function for URL parsing
-    fn parse_repository_url(url: &str) -> anyhow::Result<RepositoryId> {
-        // Simple URL parsing for GitHub URLs
-        if let Some(captures) =
-            regex::Regex::new(r"https://github\.com/([^/]+)/([^/]+?)(?:\.git)?/?$")
-                .unwrap()
-                .captures(url)
+        let regex_source = match pattern
+            .strip_prefix('/')
+            .and_then(|rest| rest.strip_suffix('/'))
+            .filter(|source| !source.is_empty())
         {
-            let owner = captures.get(1).unwrap().as_str();
-            let repo_name = captures.get(2).unwrap().as_str();
+            Some(source) => source.to_string(),
+            None => Self::glob_to_regex(pattern),
+        };
 
-            Ok(RepositoryId {
-                owner: Owner::from(owner),
-                repository_name: RepositoryName::from(repo_name),
-            })
-        } else {
-            Err(anyhow::anyhow!("Invalid repository URL format: {}", url))
+        let regex = regex::Regex::new(&regex_source)
+            .map_err(|e| anyhow::anyhow!("Invalid branch pattern '{}': {}", pattern, e))?;
+
+        Ok(Self {
+            pattern: pattern.to_string(),
+            regex,
+        })
+    }
+
+    /// Whether the branch name matches this pattern
+    pub fn matches(&self, branch: &str) -> bool {
+        self.regex.is_match(branch)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    fn glob_to_regex(glob: &str) -> String {
+        let mut source = String::from("^");
+        for c in glob.chars() {
+            match c {
+                '*' => source.push_str(".*"),
+                '?' => source.push('.'),
+                c => source.push_str(&regex::escape(&c.to_string())),
+            }
         }
+        source.push('$');
+        source
     }
 }
 
-impl fmt::Display for RepositoryBranchPair {
+impl fmt::Display for BranchPattern {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}@{}", self.repository_id, self.branch.as_str())
+        write!(f, "{}", self.pattern)
     }
 }
 
@@ -236,6 +321,189 @@ impl RepositoryBranchGroup {
     pub fn description(&self) -> Option<&str> {
         self.description.as_deref()
     }
+
+    /// Compare the pairs of this group with those of `other`
+    ///
+    /// Pairs keep the order in which they appear in their group.
+    pub fn diff(&self, other: &RepositoryBranchGroup) -> BranchGroupDiff {
+        let only_in_a = self
+            .pairs
+            .iter()
+            .filter(|pair| !other.pairs.contains(pair))
+            .cloned()
+            .collect();
+        let only_in_b = other
+            .pairs
+            .iter()
+            .filter(|pair| !self.pairs.contains(pair))
+            .cloned()
+            .collect();
+        let common = self
+            .pairs
+            .iter()
+            .filter(|pair| other.pairs.contains(pair))
+            .cloned()
+            .collect();
+
+        BranchGroupDiff {
+            group_a: self.name.clone(),
+            group_b: other.name.clone(),
+            only_in_a,
+            only_in_b,
+            common,
+        }
+    }
+}
+
+/// Set difference between the pairs of two repository branch groups
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct BranchGroupDiff {
+    pub group_a: GroupName,
+    pub group_b: GroupName,
+    /// Pairs only in the first group
+    pub only_in_a: Vec<RepositoryBranchPair>,
+    /// Pairs only in the second group
+    pub only_in_b: Vec<RepositoryBranchPair>,
+    /// Pairs in both groups
+    pub common: Vec<RepositoryBranchPair>,
+}
+
+impl BranchGroupDiff {
+    /// Whether both groups contain exactly the same pairs
+    pub fn is_identical(&self) -> bool {
+        self.only_in_a.is_empty() && self.only_in_b.is_empty()
+    }
+}
+
+/// Live state of every branch of a repository branch group
+///
+/// Branches whose status could not be fetched carry the error message instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchGroupStatus {
+    pub group_name: GroupName,
+    pub branches: Vec<(RepositoryBranchPair, Result<BranchStatus, String>)>,
+}
+
+/// Pull requests merged into each branch of a repository branch group
+///
+/// Branches whose pull requests could not be searched carry the error message instead.
+#[derive(Debug, Clone)]
+pub struct GroupReleaseNotes {
+    pub group_name: GroupName,
+    /// Earliest merge date included
+    pub since: NaiveDate,
+    pub branches: Vec<(RepositoryBranchPair, Result<Vec<PullRequest>, String>)>,
+}
+
+/// Repository branch groups of a single profile, used for cross-profile overviews
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProfileBranchGroups {
+    pub profile_name: ProfileName,
+    /// Groups sorted by name
+    pub groups: Vec<RepositoryBranchGroup>,
+}
+
+/// Outcome of merging one profile into another
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProfileMergeSummary {
+    /// Repositories added to the target profile
+    pub repositories_merged: usize,
+    /// Repositories skipped because the target already had them
+    pub repositories_skipped: usize,
+    /// Projects added to the target profile
+    pub projects_merged: usize,
+    /// Projects skipped because the target already had them
+    pub projects_skipped: usize,
+    /// Repository branch groups added to the target profile, including renamed ones
+    pub groups_merged: usize,
+    /// Groups renamed on name collision, as (original name, new name)
+    pub renamed_groups: Vec<(GroupName, GroupName)>,
+    /// Whether the source profile was deleted after merging
+    pub source_deleted: bool,
+}
+
+/// Which registrations `ProfileService::clear_profile` removes from a profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClearScope {
+    Repositories,
+    Projects,
+    Groups,
+    All,
+}
+
+impl ClearScope {
+    pub fn includes_repositories(self) -> bool {
+        matches!(self, Self::Repositories | Self::All)
+    }
+
+    pub fn includes_projects(self) -> bool {
+        matches!(self, Self::Projects | Self::All)
+    }
+
+    pub fn includes_groups(self) -> bool {
+        matches!(self, Self::Groups | Self::All)
+    }
+}
+
+/// Number of registrations removed by clearing a profile
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProfileClearSummary {
+    pub repositories_removed: usize,
+    pub projects_removed: usize,
+    pub groups_removed: usize,
+}
+
+/// Reachability of a repository or project registered in a profile
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RegistrationStatus {
+    /// The resource could be fetched
+    Valid,
+    /// The resource does not exist (deleted, renamed, or hidden from the token)
+    NotFound,
+    /// The token is not allowed to access the resource
+    AccessDenied,
+    /// Fetching failed for another reason, e.g. a network error
+    Failed(String),
+}
+
+/// Reachability of every repository and project registered in a profile
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProfileValidationReport {
+    pub profile_name: ProfileName,
+    pub repositories: Vec<(RepositoryId, RegistrationStatus)>,
+    pub projects: Vec<(ProjectId, RegistrationStatus)>,
+}
+
+impl ProfileValidationReport {
+    /// URLs of all checked resources with their status, repositories first
+    pub fn entries(&self) -> impl Iterator<Item = (String, &RegistrationStatus)> {
+        self.repositories
+            .iter()
+            .map(|(repository_id, status)| (repository_id.url(), status))
+            .chain(
+                self.projects
+                    .iter()
+                    .map(|(project_id, status)| (project_id.url(), status)),
+            )
+    }
+
+    /// Repositories that no longer exist
+    pub fn not_found_repositories(&self) -> Vec<&RepositoryId> {
+        self.repositories
+            .iter()
+            .filter(|(_, status)| *status == RegistrationStatus::NotFound)
+            .map(|(repository_id, _)| repository_id)
+            .collect()
+    }
+
+    /// Projects that no longer exist
+    pub fn not_found_projects(&self) -> Vec<&ProjectId> {
+        self.projects
+            .iter()
+            .filter(|(_, status)| *status == RegistrationStatus::NotFound)
+            .map(|(project_id, _)| project_id)
+            .collect()
+    }
 }
 
 /// Profile name wrapper type for database isolation
@@ -245,6 +513,12 @@ pub struct ProfileInfo {
     pub name: ProfileName,
     /// Profile description
     pub description: Option<String>,
+    /// Search query used when a search omits its query
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_search_query: Option<String>,
+    /// Result limit used when a search omits its limit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_search_limit: Option<usize>,
     pub repositories: Vec<RepositoryId>,
     pub projects: Vec<ProjectId>,
     /// Repository branch groups organized by group name
@@ -262,6 +536,8 @@ impl ProfileInfo {
         Self {
             name,
             description,
+            default_search_query: None,
+            default_search_limit: None,
             repositories: Vec::new(),
             projects: Vec::new(),
             repository_branch_groups: HashMap::new(),
@@ -369,19 +645,26 @@ impl ProfileInfo {
         self.repository_branch_groups.keys().collect()
     }
 
-    /// Remove repository branch groups older than N days
-    pub fn remove_groups_older_than(&mut self, days: i64) -> Vec<GroupName> {
+    /// Names of repository branch groups created more than N days ago, sorted by name
+    pub fn groups_older_than(&self, days: i64) -> Vec<GroupName> {
         let cutoff_time = chrono::Utc::now() - chrono::Duration::days(days);
-        let mut removed_groups = Vec::new();
-
-        self.repository_branch_groups.retain(|name, group| {
-            if group.created_at < cutoff_time {
-                removed_groups.push(name.clone());
-                false
-            } else {
-                true
-            }
-        });
+        let mut groups: Vec<GroupName> = self
+            .repository_branch_groups
+            .iter()
+            .filter(|(_, group)| group.created_at < cutoff_time)
+            .map(|(name, _)| name.clone())
+            .collect();
+        groups.sort_by(|a, b| a.0.cmp(&b.0));
+        groups
+    }
+
+    /// Remove repository branch groups older than N days, returning their names sorted by name
+    pub fn remove_groups_older_than(&mut self, days: i64) -> Vec<GroupName> {
+        let removed_groups = self.groups_older_than(days);
+
+        for name in &removed_groups {
+            self.repository_branch_groups.remove(name);
+        }
 
         if !removed_groups.is_empty() {
             self.touch();
@@ -428,3 +711,73 @@ impl Default for ProfileInfo {
         Self::new(ProfileName("default".to_string()), None)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_branch_pattern_glob() {
+        let pattern = BranchPattern::parse("release-*").unwrap();
+        assert!(pattern.matches("release-1.0"));
+        assert!(pattern.matches("release-"));
+        assert!(!pattern.matches("pre-release-1.0"));
+        assert!(!pattern.matches("main"));
+
+        let exact = BranchPattern::parse("main").unwrap();
+        assert!(exact.matches("main"));
+        assert!(!exact.matches("main-backup"));
+
+        let single = BranchPattern::parse("v?.x").unwrap();
+        assert!(single.matches("v1.x"));
+        assert!(!single.matches("v10.x"));
+    }
+
+    #[test]
+    fn test_branch_pattern_regex() {
+        let pattern = BranchPattern::parse(r"/^release-\d+$/").unwrap();
+        assert!(pattern.matches("release-42"));
+        assert!(!pattern.matches("release-x"));
+
+        let unanchored = BranchPattern::parse("/feature/").unwrap();
+        assert!(unanchored.matches("user/feature-x"));
+    }
+
+    #[test]
+    fn test_branch_group_diff() {
+        let pair = |repo: &str, branch: &str| {
+            RepositoryBranchPair::new(RepositoryId::new("owner", repo), Branch::new(branch))
+        };
+        let release_candidate = RepositoryBranchGroup::new(
+            Some(GroupName::from("release-candidate")),
+            vec![
+                pair("api", "release-2"),
+                pair("web", "main"),
+                pair("cli", "main"),
+            ],
+        );
+        let production = RepositoryBranchGroup::new(
+            Some(GroupName::from("production")),
+            vec![
+                pair("web", "main"),
+                pair("api", "release-1"),
+                pair("cli", "main"),
+            ],
+        );
+
+        let diff = release_candidate.diff(&production);
+        assert_eq!(diff.group_a, GroupName::from("release-candidate"));
+        assert_eq!(diff.group_b, GroupName::from("production"));
+        assert_eq!(diff.only_in_a, vec![pair("api", "release-2")]);
+        assert_eq!(diff.only_in_b, vec![pair("api", "release-1")]);
+        assert_eq!(diff.common, vec![pair("web", "main"), pair("cli", "main")]);
+        assert!(!diff.is_identical());
+        assert!(release_candidate.diff(&release_candidate).is_identical());
+    }
+
+    #[test]
+    fn test_branch_pattern_invalid() {
+        assert!(BranchPattern::parse("").is_err());
+        assert!(BranchPattern::parse("/release-(/").is_err());
+    }
+}
@@ -4,7 +4,7 @@
 //! capabilities. Following domain-driven design principles, all project-specific
 //! URL parsing logic is contained within this module.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use schemars::JsonSchema;
@@ -12,11 +12,18 @@ use schemars::JsonSchema;
 use crate::types::label::Label;
 use crate::types::user::User;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
 use strum::{Display, EnumString};
 
-use crate::types::{issue::IssueId, pull_request::PullRequestId, repository::Owner};
+use crate::types::{
+    issue::IssueId,
+    pull_request::PullRequestId,
+    repository::{Owner, RepositoryId},
+    search::SearchCursor,
+};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct ProjectUrl(pub String);
 
 impl std::fmt::Display for ProjectUrl {
@@ -25,9 +32,12 @@ impl std::fmt::Display for ProjectUrl {
     }
 }
 
+/// Project URL, optionally scoped to a view as copied from the browser (`.../views/3`)
 static PROJECT_URL_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"(?:https?://)?github\.com/(orgs|users)/([^/]+)/projects/(\d+)")
-        .expect("Failed to compile project URL regex")
+    Regex::new(
+        r"^(?:https?://)?(?:www\.)?github\.com/(orgs|users)/([^/]+)/projects/(\d+)(?:/views/(\d+))?(?:[/?#].*)?$",
+    )
+    .expect("Failed to compile project URL regex")
 });
 
 /// Project type to distinguish between user and organization projects
@@ -102,17 +112,37 @@ impl ProjectId {
         )
     }
 
+    /// Returns the canonical project URL
+    ///
+    /// User projects live under `https://github.com/users/owner/projects/N` and
+    /// organization projects under `https://github.com/orgs/owner/projects/N`.
+    pub fn to_url(&self) -> ProjectUrl {
+        ProjectUrl(self.url())
+    }
+
     /// Parse GitHub project URL to extract owner, project number, and project type
     ///
     /// Domain-specific URL parsing moved from utils to maintain domain boundaries.
-    /// Supports both user and organization project URLs.
+    /// Supports both user and organization project URLs, including view-scoped URLs
+    /// such as `https://github.com/orgs/owner/projects/1/views/3`.
     pub fn parse_url(url: &ProjectUrl) -> Result<(String, u64, ProjectType), String> {
-        let url = url.0.to_string();
+        let (owner, number, project_type, _view) = Self::parse_url_with_view(url)?;
+        Ok((owner, number, project_type))
+    }
+
+    /// Parse a GitHub project URL like `parse_url`, also returning the view number
+    ///
+    /// The view number is `None` for URLs of the project itself.
+    pub fn parse_url_with_view(
+        url: &ProjectUrl,
+    ) -> Result<(String, u64, ProjectType, Option<u64>), String> {
+        let url = url.0.trim();
         let url = url.trim_end_matches('/');
 
         // Parse GitHub project URL patterns:
         // https://github.com/orgs/owner/projects/123
         // https://github.com/users/owner/projects/123
+        // https://github.com/orgs/owner/projects/123/views/4
         if let Some(captures) = PROJECT_URL_REGEX.captures(url) {
             let project_type = captures
                 .get(1)
@@ -127,8 +157,13 @@ impl ProjectId {
                 .as_str()
                 .parse::<u64>()
                 .map_err(|_| "Invalid project number")?;
+            let view = captures
+                .get(4)
+                .map(|view| view.as_str().parse::<u64>())
+                .transpose()
+                .map_err(|_| "Invalid project view number")?;
 
-            return Ok((owner, number, project_type));
+            return Ok((owner, number, project_type, view));
         }
 
         Err(format!("Invalid GitHub project URL format: {}", url))
@@ -160,7 +195,7 @@ impl std::fmt::Display for ProjectId {
 ///
 /// Contains comprehensive project information including custom fields,
 /// project items, and resource management capabilities.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Project {
     pub project_id: ProjectId,
     pub project_node_id: ProjectNodeId,
@@ -171,7 +206,7 @@ pub struct Project {
 }
 
 /// Represents the state of a GitHub project
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum ProjectState {
     /// Project is open and active
     Open,
@@ -180,7 +215,7 @@ pub enum ProjectState {
 }
 
 /// Represents the visibility of a GitHub project
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum ProjectVisibility {
     /// Project is public
     Public,
@@ -211,7 +246,7 @@ impl Project {
 }
 
 /// Individual project item/resource within a GitHub project
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProjectResource {
     pub project_item_id: ProjectItemId,
     pub title: Option<String>,
@@ -231,7 +266,7 @@ pub struct ProjectResource {
 
 /// Type of resource in a project
 /// Reference to the original resource (issue or PR)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum ProjectOriginalResource {
     /// Reference to an issue
     Issue(IssueId),
@@ -242,7 +277,7 @@ pub enum ProjectOriginalResource {
 }
 
 /// Custom field definition for a project
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProjectCustomField {
     pub field_id: String,
     pub field_name: String,
@@ -250,8 +285,27 @@ pub struct ProjectCustomField {
     pub options: Vec<String>,
 }
 
+/// Field definition of a project as returned by the GitHub API
+///
+/// `data_type` is GitHub's `ProjectV2FieldType` (e.g. `TEXT`, `SINGLE_SELECT`,
+/// `ITERATION`). Only single select fields have options.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProjectField {
+    pub field_id: ProjectFieldId,
+    pub field_name: ProjectFieldName,
+    pub data_type: String,
+    pub options: Vec<ProjectFieldOption>,
+}
+
+/// Selectable option of a single select project field
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProjectFieldOption {
+    pub option_id: String,
+    pub name: String,
+}
+
 /// Type of custom field in a project
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum ProjectCustomFieldType {
     /// Text field
     Text,
@@ -266,7 +320,7 @@ pub enum ProjectCustomFieldType {
 }
 
 /// Value of a custom field for a specific resource
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProjectCustomFieldValue {
     pub field_id: ProjectFieldId,
     pub field_name: ProjectFieldName,
@@ -274,7 +328,7 @@ pub struct ProjectCustomFieldValue {
 }
 
 /// Actual value of a custom field
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum ProjectFieldType {
     /// Text value
     Text,
@@ -289,7 +343,7 @@ pub enum ProjectFieldType {
 }
 
 /// Actual value of a custom field
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum ProjectFieldValue {
     /// Text value
     Text(String),
@@ -301,6 +355,103 @@ pub enum ProjectFieldValue {
     SingleSelect(String),
     /// Multi select values
     MultiSelect(Vec<String>),
+    /// Iteration value
+    Iteration(ProjectIteration),
+}
+
+/// Iteration of an iteration field that an item is assigned to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ProjectIteration {
+    pub title: String,
+    pub start_date: NaiveDate,
+    pub duration_days: u32,
+}
+
+impl ProjectFieldValue {
+    /// Check whether the value matches a filter value, ignoring ASCII case
+    ///
+    /// Multi select values match when any selected option matches, dates are
+    /// compared in `YYYY-MM-DD` form, and iterations by title.
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Self::Text(value) | Self::SingleSelect(value) => value.eq_ignore_ascii_case(expected),
+            Self::Number(value) => expected
+                .parse::<f64>()
+                .map(|expected| expected == *value)
+                .unwrap_or(false),
+            Self::Date(value) => value.format("%Y-%m-%d").to_string() == expected,
+            Self::MultiSelect(values) => values
+                .iter()
+                .any(|value| value.eq_ignore_ascii_case(expected)),
+            Self::Iteration(iteration) => iteration.title.eq_ignore_ascii_case(expected),
+        }
+    }
+}
+
+/// New value of a project item field
+///
+/// Text, number, date, and single select fields can be set; single select values are
+/// given as option ids, as listed by the field definitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum ProjectFieldUpdateValue {
+    /// Text value
+    Text(String),
+    /// Number value
+    Number(f64),
+    /// Date value
+    Date(NaiveDate),
+    /// Option id of a single select field
+    SingleSelectOptionId(String),
+}
+
+impl ProjectFieldUpdateValue {
+    /// Parse a value of the given type: `text`, `number`, `date` (`YYYY-MM-DD`), or
+    /// `single_select` (option id)
+    pub fn parse(value_type: &str, value: &str) -> Result<Self, String> {
+        match value_type.trim().to_ascii_lowercase().as_str() {
+            "text" => Ok(Self::Text(value.to_string())),
+            "number" => value
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|number| number.is_finite())
+                .map(Self::Number)
+                .ok_or_else(|| format!("Invalid number value '{}'", value)),
+            "date" => NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+                .map(Self::Date)
+                .map_err(|_| {
+                    format!(
+                        "Invalid date value '{}'. Expected format: YYYY-MM-DD",
+                        value
+                    )
+                }),
+            "single_select" => {
+                let option_id = value.trim();
+                if option_id.is_empty() {
+                    Err("Single select value must be an option id".to_string())
+                } else {
+                    Ok(Self::SingleSelectOptionId(option_id.to_string()))
+                }
+            }
+            other => Err(format!(
+                "Unsupported value type '{}'. Supported types: text, number, date, single_select",
+                other
+            )),
+        }
+    }
+}
+
+/// Parse a project field filter in `Field=Value` form
+pub fn parse_project_field_filter(filter: &str) -> Result<(String, String), String> {
+    match filter.split_once('=') {
+        Some((field_name, value)) if !field_name.trim().is_empty() => {
+            Ok((field_name.trim().to_string(), value.trim().to_string()))
+        }
+        _ => Err(format!(
+            "Invalid field filter '{}'. Expected format: 'Field=Value' (e.g. 'Status=In Progress')",
+            filter
+        )),
+    }
 }
 
 impl ProjectResource {
@@ -350,9 +501,303 @@ impl ProjectResource {
             _ => None,
         }
     }
+
+    /// Get the repository of the issue or pull request this item wraps
+    ///
+    /// Returns `None` for draft issues, which do not belong to a repository.
+    pub fn repository_id(&self) -> Option<&RepositoryId> {
+        match &self.original_resource {
+            ProjectOriginalResource::Issue(issue_id) => Some(&issue_id.git_repository),
+            ProjectOriginalResource::PullRequest(pr_id) => Some(&pr_id.git_repository),
+            ProjectOriginalResource::DraftIssue => None,
+        }
+    }
+
+    /// Get the URL of the issue or pull request this item wraps
+    ///
+    /// Returns `None` for draft issues, which exist only in the project.
+    pub fn content_url(&self) -> Option<String> {
+        match &self.original_resource {
+            ProjectOriginalResource::Issue(issue_id) => Some(issue_id.url()),
+            ProjectOriginalResource::PullRequest(pr_id) => Some(pr_id.url()),
+            ProjectOriginalResource::DraftIssue => None,
+        }
+    }
+
+    /// Check whether the item has a matching value for every `(field name, value)` filter
+    ///
+    /// Field names are compared case-insensitively. Items lacking a filtered field are
+    /// excluded.
+    pub fn matches_field_filters(&self, field_filters: &[(String, String)]) -> bool {
+        field_filters.iter().all(|(field_name, expected)| {
+            self.custom_field_values.iter().any(|field_value| {
+                field_value.field_name.eq_ignore_ascii_case(field_name)
+                    && field_value.value.matches(expected)
+            })
+        })
+    }
+
+    /// Merge project-specific values of another item wrapping the same content
+    ///
+    /// Custom field values are appended unless a field with the same name is already
+    /// present; values of this item take precedence.
+    fn merge_project_fields_from(&mut self, other: ProjectResource) {
+        for field_value in other.custom_field_values {
+            let exists = self.custom_field_values.iter().any(|existing| {
+                existing
+                    .field_name
+                    .eq_ignore_ascii_case(&field_value.field_name.0)
+            });
+            if !exists {
+                self.custom_field_values.push(field_value);
+            }
+        }
+
+        if self.column_name.is_none() {
+            self.column_name = other.column_name;
+        }
+        if self.start_date.is_none() {
+            self.start_date = other.start_date;
+        }
+        if self.end_date.is_none() {
+            self.end_date = other.end_date;
+        }
+    }
+}
+
+/// How to handle the same issue or pull request appearing on multiple project boards
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    EnumString,
+    Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ProjectResourceDeduplication {
+    /// Return every project item as fetched
+    #[default]
+    Raw,
+    /// Keep only the first item for each underlying issue or pull request
+    KeepFirst,
+    /// Keep the first item and merge custom field values of later duplicates into it
+    Merge,
+}
+
+/// Caps on fetching the items of a project page by page
+///
+/// Every cap is optional and the default fetches all pages. `max_items` counts items
+/// before field filters are applied, and `deadline` is the time budget of the whole
+/// fetch, checked before each further page.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProjectFetchLimits {
+    pub max_pages: Option<usize>,
+    pub max_items: Option<usize>,
+    pub deadline: Option<Duration>,
+}
+
+impl ProjectFetchLimits {
+    /// Limits capping only the number of items
+    pub fn max_items(max_items: usize) -> Self {
+        Self {
+            max_items: Some(max_items),
+            ..Self::default()
+        }
+    }
+
+    /// Whether any cap is reached after fetching `pages` pages with `items` items in `elapsed`
+    pub fn is_reached(&self, pages: usize, items: usize, elapsed: Duration) -> bool {
+        self.max_pages.is_some_and(|max_pages| pages >= max_pages)
+            || self.max_items.is_some_and(|max_items| items >= max_items)
+            || self.deadline.is_some_and(|deadline| elapsed >= deadline)
+    }
+}
+
+/// Items of a project fetched under `ProjectFetchLimits`
+#[derive(Debug, Clone)]
+pub struct ProjectResourcesFetch {
+    pub resources: Vec<ProjectResource>,
+    /// Cursor to resume from when a cap or the deadline stopped the fetch early
+    pub next_cursor: Option<SearchCursor>,
+}
+
+/// Project resources after cross-project deduplication
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DeduplicatedProjectResources {
+    pub resources: Vec<ProjectResource>,
+    /// Number of duplicate items collapsed into earlier ones
+    pub duplicates_collapsed: usize,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl ProjectResourceDeduplication {
+    /// Deduplicate project resources by the URL of the content they wrap
+    ///
+    /// Draft issues have no content URL and are always kept.
+    pub fn apply(&self, resources: Vec<ProjectResource>) -> DeduplicatedProjectResources {
+        if *self == Self::Raw {
+            return DeduplicatedProjectResources {
+                resources,
+                duplicates_collapsed: 0,
+            };
+        }
+
+        let mut deduplicated: Vec<ProjectResource> = Vec::with_capacity(resources.len());
+        let mut index_by_url: HashMap<String, usize> = HashMap::new();
+        let mut duplicates_collapsed = 0;
+
+        for resource in resources {
+            let Some(url) = resource.content_url() else {
+                deduplicated.push(resource);
+                continue;
+            };
+
+            match index_by_url.get(&url) {
+                Some(&index) => {
+                    duplicates_collapsed += 1;
+                    if *self == Self::Merge {
+                        deduplicated[index].merge_project_fields_from(resource);
+                    }
+                }
+                None => {
+                    index_by_url.insert(url, deduplicated.len());
+                    deduplicated.push(resource);
+                }
+            }
+        }
+
+        DeduplicatedProjectResources {
+            resources: deduplicated,
+            duplicates_collapsed,
+        }
+    }
+}
+
+/// Repository feeding a project with the number of items it contributes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ProjectRepositoryItemCount {
+    pub repository_id: RepositoryId,
+    pub item_count: usize,
+}
+
+/// Source repositories of a project's items
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProjectRepositories {
+    /// Distinct repositories sorted by item count descending
+    pub repositories: Vec<ProjectRepositoryItemCount>,
+    /// Number of draft issues, which do not belong to any repository
+    pub draft_issue_count: usize,
+}
+
+impl ProjectRepositories {
+    /// Aggregate project items by the repository of the issue or pull request they wrap
+    ///
+    /// Repositories with the same item count are ordered by owner and name.
+    pub fn from_resources(resources: &[ProjectResource]) -> Self {
+        let mut counts: HashMap<&RepositoryId, usize> = HashMap::new();
+        let mut draft_issue_count = 0;
+
+        for resource in resources {
+            match resource.repository_id() {
+                Some(repository_id) => *counts.entry(repository_id).or_insert(0) += 1,
+                None => draft_issue_count += 1,
+            }
+        }
+
+        let mut repositories: Vec<ProjectRepositoryItemCount> = counts
+            .into_iter()
+            .map(|(repository_id, item_count)| ProjectRepositoryItemCount {
+                repository_id: repository_id.clone(),
+                item_count,
+            })
+            .collect();
+        repositories.sort_by(|a, b| {
+            b.item_count
+                .cmp(&a.item_count)
+                .then_with(|| a.repository_id.cmp(&b.repository_id))
+        });
+
+        Self {
+            repositories,
+            draft_issue_count,
+        }
+    }
+}
+
+/// Project items assigned to one iteration or month of a roadmap
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProjectRoadmapBucket {
+    /// Iteration title, or `YYYY-MM` when grouping by a date field
+    pub title: String,
+    /// First day of the iteration or month
+    pub start_date: NaiveDate,
+    pub items: Vec<ProjectResource>,
+}
+
+/// Project items grouped into time buckets by an iteration or date field
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProjectRoadmap {
+    pub field_name: String,
+    /// Buckets ordered by start date
+    pub buckets: Vec<ProjectRoadmapBucket>,
+    /// Items without a value for the field
+    pub unscheduled: Vec<ProjectResource>,
+}
+
+impl ProjectRoadmap {
+    /// Group project items by their value of the given iteration or date field
+    ///
+    /// Iteration values are bucketed by iteration and date values by month. Items keep
+    /// their project order within a bucket; items without an iteration or date value
+    /// for the field are unscheduled.
+    pub fn from_resources(field_name: &str, resources: Vec<ProjectResource>) -> Self {
+        let mut buckets: BTreeMap<(NaiveDate, String), Vec<ProjectResource>> = BTreeMap::new();
+        let mut unscheduled = Vec::new();
+
+        for resource in resources {
+            let bucket = resource
+                .custom_field_values
+                .iter()
+                .find(|field_value| field_value.field_name.eq_ignore_ascii_case(field_name))
+                .and_then(|field_value| match &field_value.value {
+                    ProjectFieldValue::Iteration(iteration) => {
+                        Some((iteration.start_date, iteration.title.clone()))
+                    }
+                    ProjectFieldValue::Date(date) => {
+                        let month_start = date.date_naive().with_day(1)?;
+                        Some((month_start, month_start.format("%Y-%m").to_string()))
+                    }
+                    _ => None,
+                });
+
+            match bucket {
+                Some(key) => buckets.entry(key).or_default().push(resource),
+                None => unscheduled.push(resource),
+            }
+        }
+
+        Self {
+            field_name: field_name.to_string(),
+            buckets: buckets
+                .into_iter()
+                .map(|((start_date, title), items)| ProjectRoadmapBucket {
+                    title,
+                    start_date,
+                    items,
+                })
+                .collect(),
+            unscheduled,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProjectItemId(pub String);
 
 impl std::fmt::Display for ProjectItemId {
@@ -361,7 +806,7 @@ impl std::fmt::Display for ProjectItemId {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProjectFieldId(pub String);
 
 impl std::fmt::Display for ProjectFieldId {
@@ -370,7 +815,7 @@ impl std::fmt::Display for ProjectFieldId {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProjectFieldName(pub String);
 
 impl std::fmt::Display for ProjectFieldName {
@@ -384,3 +829,440 @@ impl ProjectFieldName {
         self.0.eq_ignore_ascii_case(other)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project_item(
+        item_id: &str,
+        original_resource: ProjectOriginalResource,
+        fields: &[(&str, &str)],
+    ) -> ProjectResource {
+        ProjectResource {
+            project_item_id: ProjectItemId(item_id.to_string()),
+            title: Some("Shared issue".to_string()),
+            author: User::new("octocat".to_string()),
+            assignees: vec![],
+            labels: vec![],
+            state: "OPEN".to_string(),
+            created_at: None,
+            updated_at: None,
+            column_name: None,
+            custom_field_values: fields
+                .iter()
+                .map(|(name, value)| ProjectCustomFieldValue {
+                    field_id: ProjectFieldId(format!("field-{}", name)),
+                    field_name: ProjectFieldName(name.to_string()),
+                    value: ProjectFieldValue::SingleSelect(value.to_string()),
+                })
+                .collect(),
+            original_resource,
+            start_date: None,
+            end_date: None,
+        }
+    }
+
+    fn issue(number: u32) -> ProjectOriginalResource {
+        ProjectOriginalResource::Issue(IssueId::new(
+            RepositoryId::new("tacogips", "gitcodes-mcp-test-1"),
+            number,
+        ))
+    }
+
+    fn sample_resources() -> Vec<ProjectResource> {
+        vec![
+            project_item("item-1", issue(1), &[("Status", "Todo")]),
+            project_item("item-2", issue(2), &[]),
+            project_item(
+                "item-3",
+                issue(1),
+                &[("Status", "Done"), ("Priority", "High")],
+            ),
+            project_item("item-4", ProjectOriginalResource::DraftIssue, &[]),
+            project_item("item-5", ProjectOriginalResource::DraftIssue, &[]),
+        ]
+    }
+
+    #[test]
+    fn test_deduplication_raw_keeps_all_items() {
+        let result = ProjectResourceDeduplication::Raw.apply(sample_resources());
+        assert_eq!(result.resources.len(), 5);
+        assert_eq!(result.duplicates_collapsed, 0);
+    }
+
+    #[test]
+    fn test_deduplication_keep_first() {
+        let result = ProjectResourceDeduplication::KeepFirst.apply(sample_resources());
+        let item_ids: Vec<&str> = result
+            .resources
+            .iter()
+            .map(|r| r.project_item_id.0.as_str())
+            .collect();
+        assert_eq!(item_ids, vec!["item-1", "item-2", "item-4", "item-5"]);
+        assert_eq!(result.duplicates_collapsed, 1);
+        assert_eq!(result.resources[0].custom_field_values.len(), 1);
+    }
+
+    #[test]
+    fn test_deduplication_merge_field_values() {
+        let result = ProjectResourceDeduplication::Merge.apply(sample_resources());
+        assert_eq!(result.resources.len(), 4);
+        assert_eq!(result.duplicates_collapsed, 1);
+
+        let merged = &result.resources[0];
+        assert_eq!(merged.project_item_id.0, "item-1");
+        let fields: Vec<(String, String)> = merged
+            .custom_field_values
+            .iter()
+            .map(|f| match &f.value {
+                ProjectFieldValue::SingleSelect(v) => (f.field_name.0.clone(), v.clone()),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("Status".to_string(), "Todo".to_string()),
+                ("Priority".to_string(), "High".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_matches_field_filters() {
+        let resources = sample_resources();
+        let status_todo = vec![("status".to_string(), "todo".to_string())];
+        let matched: Vec<&str> = resources
+            .iter()
+            .filter(|r| r.matches_field_filters(&status_todo))
+            .map(|r| r.project_item_id.0.as_str())
+            .collect();
+        assert_eq!(matched, vec!["item-1"]);
+
+        let done_and_high = vec![
+            ("Status".to_string(), "Done".to_string()),
+            ("Priority".to_string(), "High".to_string()),
+        ];
+        assert!(resources[2].matches_field_filters(&done_and_high));
+        assert!(!resources[0].matches_field_filters(&done_and_high));
+
+        // Items without the filtered field are excluded; no filters match everything
+        assert!(!resources[1].matches_field_filters(&status_todo));
+        assert!(resources[1].matches_field_filters(&[]));
+    }
+
+    #[test]
+    fn test_project_field_value_matches() {
+        assert!(ProjectFieldValue::Number(3.0).matches("3"));
+        assert!(!ProjectFieldValue::Number(3.0).matches("three"));
+        assert!(
+            ProjectFieldValue::MultiSelect(vec!["Backend".to_string(), "API".to_string()])
+                .matches("api")
+        );
+        let date = DateTime::parse_from_rfc3339("2025-03-04T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert!(ProjectFieldValue::Date(date).matches("2025-03-04"));
+    }
+
+    #[test]
+    fn test_parse_project_field_filter() {
+        assert_eq!(
+            parse_project_field_filter("Status=In Progress").unwrap(),
+            ("Status".to_string(), "In Progress".to_string())
+        );
+        assert_eq!(
+            parse_project_field_filter(" Estimate = 3 ").unwrap(),
+            ("Estimate".to_string(), "3".to_string())
+        );
+        assert!(parse_project_field_filter("Status").is_err());
+        assert!(parse_project_field_filter("=Done").is_err());
+    }
+
+    #[test]
+    fn test_project_repositories_from_resources() {
+        let other_repo_pr = ProjectOriginalResource::PullRequest(PullRequestId::new(
+            RepositoryId::new("tacogips", "github-insight"),
+            7,
+        ));
+        let mut resources = sample_resources();
+        resources.push(project_item("item-6", other_repo_pr, &[]));
+
+        let summary = ProjectRepositories::from_resources(&resources);
+        assert_eq!(
+            summary.repositories,
+            vec![
+                ProjectRepositoryItemCount {
+                    repository_id: RepositoryId::new("tacogips", "gitcodes-mcp-test-1"),
+                    item_count: 3,
+                },
+                ProjectRepositoryItemCount {
+                    repository_id: RepositoryId::new("tacogips", "github-insight"),
+                    item_count: 1,
+                },
+            ]
+        );
+        assert_eq!(summary.draft_issue_count, 2);
+    }
+
+    #[test]
+    fn test_project_roadmap_from_resources() {
+        fn with_value(mut resource: ProjectResource, value: ProjectFieldValue) -> ProjectResource {
+            resource.custom_field_values.push(ProjectCustomFieldValue {
+                field_id: ProjectFieldId("field-sprint".to_string()),
+                field_name: ProjectFieldName("Sprint".to_string()),
+                value,
+            });
+            resource
+        }
+        let iteration = |title: &str, month: u32| {
+            ProjectFieldValue::Iteration(ProjectIteration {
+                title: title.to_string(),
+                start_date: NaiveDate::from_ymd_opt(2025, month, 1).unwrap(),
+                duration_days: 14,
+            })
+        };
+
+        let resources = vec![
+            with_value(
+                project_item("item-1", issue(1), &[]),
+                iteration("Sprint 2", 4),
+            ),
+            project_item("item-2", issue(2), &[("Sprint", "Not an iteration")]),
+            with_value(
+                project_item("item-3", issue(3), &[]),
+                iteration("Sprint 1", 3),
+            ),
+            with_value(
+                project_item("item-4", issue(4), &[]),
+                iteration("Sprint 2", 4),
+            ),
+            project_item("item-5", issue(5), &[]),
+        ];
+
+        let roadmap = ProjectRoadmap::from_resources("sprint", resources);
+        let buckets: Vec<(&str, Vec<&str>)> = roadmap
+            .buckets
+            .iter()
+            .map(|bucket| {
+                (
+                    bucket.title.as_str(),
+                    bucket
+                        .items
+                        .iter()
+                        .map(|r| r.project_item_id.0.as_str())
+                        .collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            buckets,
+            vec![
+                ("Sprint 1", vec!["item-3"]),
+                ("Sprint 2", vec!["item-1", "item-4"]),
+            ]
+        );
+        let unscheduled: Vec<&str> = roadmap
+            .unscheduled
+            .iter()
+            .map(|r| r.project_item_id.0.as_str())
+            .collect();
+        assert_eq!(unscheduled, vec!["item-2", "item-5"]);
+    }
+
+    #[test]
+    fn test_project_roadmap_groups_dates_by_month() {
+        let date = |value: &str| {
+            ProjectFieldValue::Date(
+                DateTime::parse_from_rfc3339(value)
+                    .unwrap()
+                    .with_timezone(&Utc),
+            )
+        };
+        let mut resources = Vec::new();
+        for (item_id, value) in [
+            ("item-1", "2025-05-20T00:00:00Z"),
+            ("item-2", "2025-04-02T00:00:00Z"),
+            ("item-3", "2025-05-01T00:00:00Z"),
+        ] {
+            let mut resource = project_item(item_id, issue(1), &[]);
+            resource.custom_field_values.push(ProjectCustomFieldValue {
+                field_id: ProjectFieldId("field-target".to_string()),
+                field_name: ProjectFieldName("Target Date".to_string()),
+                value: date(value),
+            });
+            resources.push(resource);
+        }
+
+        let roadmap = ProjectRoadmap::from_resources("Target Date", resources);
+        let titles: Vec<(&str, usize)> = roadmap
+            .buckets
+            .iter()
+            .map(|bucket| (bucket.title.as_str(), bucket.items.len()))
+            .collect();
+        assert_eq!(titles, vec![("2025-04", 1), ("2025-05", 2)]);
+        assert_eq!(
+            roadmap.buckets[1].start_date,
+            NaiveDate::from_ymd_opt(2025, 5, 1).unwrap()
+        );
+        assert!(roadmap.unscheduled.is_empty());
+    }
+
+    #[test]
+    fn test_deduplication_from_str() {
+        assert_eq!(
+            "raw".parse::<ProjectResourceDeduplication>().unwrap(),
+            ProjectResourceDeduplication::Raw
+        );
+        assert_eq!(
+            "keep_first"
+                .parse::<ProjectResourceDeduplication>()
+                .unwrap(),
+            ProjectResourceDeduplication::KeepFirst
+        );
+        assert_eq!(
+            "merge".parse::<ProjectResourceDeduplication>().unwrap(),
+            ProjectResourceDeduplication::Merge
+        );
+        assert!("latest".parse::<ProjectResourceDeduplication>().is_err());
+    }
+
+    #[test]
+    fn test_project_field_update_value_parse() {
+        assert_eq!(
+            ProjectFieldUpdateValue::parse("text", "Needs review"),
+            Ok(ProjectFieldUpdateValue::Text("Needs review".to_string()))
+        );
+        assert_eq!(
+            ProjectFieldUpdateValue::parse("NUMBER", " 3.5 "),
+            Ok(ProjectFieldUpdateValue::Number(3.5))
+        );
+        assert_eq!(
+            ProjectFieldUpdateValue::parse("date", "2025-03-31"),
+            Ok(ProjectFieldUpdateValue::Date(
+                NaiveDate::from_ymd_opt(2025, 3, 31).unwrap()
+            ))
+        );
+        assert_eq!(
+            ProjectFieldUpdateValue::parse("single_select", "opt_done"),
+            Ok(ProjectFieldUpdateValue::SingleSelectOptionId(
+                "opt_done".to_string()
+            ))
+        );
+        assert!(ProjectFieldUpdateValue::parse("number", "NaN").is_err());
+        assert!(ProjectFieldUpdateValue::parse("date", "31/03/2025").is_err());
+        assert!(ProjectFieldUpdateValue::parse("single_select", " ").is_err());
+        assert!(ProjectFieldUpdateValue::parse("iteration", "1").is_err());
+    }
+
+    fn parse(url: &str) -> Result<(String, u64, ProjectType, Option<u64>), String> {
+        ProjectId::parse_url_with_view(&ProjectUrl(url.to_string()))
+    }
+
+    #[test]
+    fn test_parse_url_with_and_without_views() {
+        for (url, project_type, view) in [
+            (
+                "https://github.com/orgs/acme/projects/1",
+                ProjectType::Organization,
+                None,
+            ),
+            (
+                "https://github.com/orgs/acme/projects/1/",
+                ProjectType::Organization,
+                None,
+            ),
+            (
+                "https://github.com/orgs/acme/projects/1/views/3",
+                ProjectType::Organization,
+                Some(3),
+            ),
+            (
+                "https://github.com/orgs/acme/projects/1/views/3/",
+                ProjectType::Organization,
+                Some(3),
+            ),
+            (
+                "https://github.com/users/acme/projects/1",
+                ProjectType::User,
+                None,
+            ),
+            (
+                "https://github.com/users/acme/projects/1/",
+                ProjectType::User,
+                None,
+            ),
+            (
+                "https://github.com/users/acme/projects/1/views/12",
+                ProjectType::User,
+                Some(12),
+            ),
+            (
+                "github.com/users/acme/projects/1/views/12/",
+                ProjectType::User,
+                Some(12),
+            ),
+            (
+                "https://github.com/orgs/acme/projects/1/views/3?layout=board",
+                ProjectType::Organization,
+                Some(3),
+            ),
+        ] {
+            assert_eq!(
+                parse(url),
+                Ok(("acme".to_string(), 1, project_type, view)),
+                "{}",
+                url
+            );
+        }
+
+        assert_eq!(
+            ProjectId::parse_url(&ProjectUrl(
+                "https://github.com/orgs/acme/projects/1/views/3".to_string()
+            )),
+            Ok(("acme".to_string(), 1, ProjectType::Organization))
+        );
+    }
+
+    #[test]
+    fn test_to_url_for_user_and_organization_projects() {
+        let user_project = ProjectId::new(
+            Owner::from("octocat"),
+            ProjectNumber::new(1),
+            ProjectType::User,
+        );
+        assert_eq!(
+            user_project.to_url(),
+            ProjectUrl("https://github.com/users/octocat/projects/1".to_string())
+        );
+
+        let org_project = ProjectId::new(
+            Owner::from("rust-lang"),
+            ProjectNumber::new(42),
+            ProjectType::Organization,
+        );
+        assert_eq!(
+            org_project.to_url(),
+            ProjectUrl("https://github.com/orgs/rust-lang/projects/42".to_string())
+        );
+        assert_eq!(
+            ProjectId::parse_url(&org_project.to_url()),
+            Ok(("rust-lang".to_string(), 42, ProjectType::Organization))
+        );
+    }
+
+    #[test]
+    fn test_parse_url_invalid_inputs() {
+        for url in [
+            "",
+            "https://github.com/acme/projects/1",
+            "https://github.com/orgs/acme/projects/",
+            "https://github.com/orgs/acme/projects/abc",
+            "https://github.com/orgs/acme/projects/1abc",
+            "https://gitlab.com/orgs/acme/projects/1",
+        ] {
+            assert!(parse(url).is_err(), "{} should not parse", url);
+        }
+    }
+}
@@ -1,14 +1,17 @@
 use chrono::{DateTime, Utc};
 use once_cell::sync::Lazy;
 use regex::Regex;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
 
-use crate::types::{IssueOrPullrequestId, User, repository::RepositoryId};
+use crate::types::{
+    IssueOrPullrequestId, ReactionSummary, SearchQuery, User, repository::RepositoryId,
+};
 
 use super::label::Label;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct PullRequestUrl(pub String);
 
 impl std::fmt::Display for PullRequestUrl {
@@ -23,7 +26,7 @@ static PR_URL_REGEX: Lazy<Regex> = Lazy::new(|| {
 });
 
 /// Wrapper type for pull request numbers providing type safety
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct PullRequestNumber(pub u32);
 
 impl PullRequestNumber {
@@ -45,7 +48,7 @@ impl std::fmt::Display for PullRequestNumber {
 }
 
 /// Wrapper type for comment numbers providing type safety
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct PullRequestCommentNumber(pub u64);
 
 impl PullRequestCommentNumber {
@@ -67,7 +70,9 @@ impl std::fmt::Display for PullRequestCommentNumber {
 }
 
 /// Represents the state of a GitHub pull request.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumString, Display)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, EnumString, Display,
+)]
 #[strum(serialize_all = "UPPERCASE")] // For GraphQL API compatibility
 pub enum PullRequestState {
     /// Pull request is open
@@ -81,12 +86,146 @@ pub enum PullRequestState {
     Merged,
 }
 
+/// Overall review decision of a pull request as reported by GitHub.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, EnumString, Display,
+)]
+pub enum PullRequestReviewDecision {
+    /// The pull request has an approving review
+    #[strum(serialize = "APPROVED")]
+    Approved,
+    /// Changes have been requested on the pull request
+    #[strum(serialize = "CHANGES_REQUESTED")]
+    ChangesRequested,
+    /// A review is required before the pull request can be merged
+    #[strum(serialize = "REVIEW_REQUIRED")]
+    ReviewRequired,
+}
+
+/// State of an individual pull request review.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, EnumString, Display,
+)]
+pub enum PullRequestReviewState {
+    /// The reviewer approved the changes
+    #[strum(serialize = "APPROVED")]
+    Approved,
+    /// The reviewer requested changes
+    #[strum(serialize = "CHANGES_REQUESTED")]
+    ChangesRequested,
+    /// The reviewer left comments without an explicit verdict
+    #[strum(serialize = "COMMENTED")]
+    Commented,
+    /// The review was dismissed
+    #[strum(serialize = "DISMISSED")]
+    Dismissed,
+    /// The review has not been submitted yet
+    #[strum(serialize = "PENDING")]
+    Pending,
+}
+
+/// Review state used to filter pull request listings.
+///
+/// Each variant maps to a GitHub `review:` search qualifier.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, EnumString, Display,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum ReviewStateFilter {
+    /// Approved and ready to merge
+    Approved,
+    /// Changes have been requested
+    ChangesRequested,
+    /// A review is required before merging
+    Required,
+    /// No reviews yet (awaiting first review)
+    #[strum(serialize = "none")]
+    NoReviews,
+}
+
+impl ReviewStateFilter {
+    /// Returns the GitHub search qualifier for this review state
+    pub fn search_qualifier(&self) -> &'static str {
+        match self {
+            Self::Approved => "review:approved",
+            Self::ChangesRequested => "review:changes_requested",
+            Self::Required => "review:required",
+            Self::NoReviews => "review:none",
+        }
+    }
+
+    /// Check whether a fetched pull request is in this review state
+    pub fn matches(&self, pr: &PullRequest) -> bool {
+        match self {
+            Self::Approved => pr.review_decision == Some(PullRequestReviewDecision::Approved),
+            Self::ChangesRequested => {
+                pr.review_decision == Some(PullRequestReviewDecision::ChangesRequested)
+            }
+            Self::Required => pr.review_decision == Some(PullRequestReviewDecision::ReviewRequired),
+            Self::NoReviews => pr.reviewers.is_empty(),
+        }
+    }
+}
+
+/// Filter options for listing the open pull requests of a repository.
+#[derive(Debug, Clone, Default)]
+pub struct PullRequestListFilter {
+    /// Review state of the pull request
+    pub review_state: Option<ReviewStateFilter>,
+    /// Whether to list only draft (`true`) or only ready (`false`) pull requests
+    pub draft: Option<bool>,
+    /// Login of the pull request author
+    pub author: Option<String>,
+    /// Login of a user (or team member) whose review is requested
+    pub reviewer: Option<String>,
+}
+
+impl PullRequestListFilter {
+    /// Build the GitHub search query for open pull requests matching this filter
+    pub fn to_search_query(&self) -> SearchQuery {
+        let mut qualifiers = vec!["is:pr".to_string(), "is:open".to_string()];
+
+        if let Some(review_state) = &self.review_state {
+            qualifiers.push(review_state.search_qualifier().to_string());
+        }
+        if let Some(draft) = self.draft {
+            qualifiers.push(format!("draft:{}", draft));
+        }
+        if let Some(author) = &self.author {
+            qualifiers.push(format!("author:{}", author));
+        }
+        if let Some(reviewer) = &self.reviewer {
+            qualifiers.push(format!("review-requested:{}", reviewer));
+        }
+
+        SearchQuery::new(qualifiers.join(" "))
+    }
+
+    /// Check the review fields of a fetched pull request against this filter
+    ///
+    /// Author and reviewer are only applied through the search query, since
+    /// team review requests cannot be resolved from the pull request itself.
+    pub fn matches(&self, pr: &PullRequest) -> bool {
+        if let Some(review_state) = &self.review_state {
+            if !review_state.matches(pr) {
+                return false;
+            }
+        }
+        if let Some(draft) = self.draft {
+            if pr.draft != draft {
+                return false;
+            }
+        }
+        true
+    }
+}
+
 /// Strong-typed pull request identifier with URL parsing capabilities.
 ///
 /// This struct encapsulates all pull request identification logic and URL parsing
 /// specific to pull requests. Following domain-driven design, all PR URL
 /// parsing and reference extraction logic is self-contained within this domain.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct PullRequestId {
     pub git_repository: RepositoryId,
     pub number: u32,
@@ -145,7 +284,7 @@ impl std::fmt::Display for PullRequestId {
 ///
 /// Contains comprehensive pull request information including reviews, comments,
 /// branch information, and cross-references to other resources.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PullRequest {
     pub pull_request_id: PullRequestId,
     pub title: String,
@@ -167,15 +306,141 @@ pub struct PullRequest {
     pub deletions: u32,
     pub changed_files: u32,
     pub comments: Vec<PullRequestComment>,
+    /// Total number of comments, including those beyond the fetched `comments`
+    #[serde(default)]
+    pub comments_count: u32,
     pub review_thread_comments: Vec<ReviewThreadComment>,
     pub milestone_id: Option<u64>,
     pub draft: bool,
     pub mergeable: Option<bool>,
+    #[serde(default)]
+    pub review_decision: Option<PullRequestReviewDecision>,
     pub linked_resources: Vec<IssueOrPullrequestId>,
+    /// Reaction counts, present when fetched separately with `fetch_issue_reactions`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<ReactionSummary>,
+    /// Whether the pull request's repository is archived (read-only)
+    #[serde(default)]
+    pub repository_archived: bool,
+}
+
+impl PullRequest {
+    /// Attach reaction counts fetched for this pull request
+    pub fn with_reactions(mut self, reactions: ReactionSummary) -> Self {
+        self.reactions = Some(reactions);
+        self
+    }
+}
+
+/// A review submitted on a pull request
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PullRequestReview {
+    pub reviewer: Option<User>,
+    pub state: PullRequestReviewState,
+    /// `None` for pending reviews
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub body: String,
+}
+
+/// A commit of a pull request, without its diff
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct CommitSummary {
+    pub sha: String,
+    /// Full commit message
+    pub message: String,
+    /// Login of the author's GitHub account, or the git author name when unlinked
+    pub author: Option<String>,
+    pub authored_at: Option<DateTime<Utc>>,
+}
+
+impl CommitSummary {
+    /// First 7 characters of the SHA
+    pub fn short_sha(&self) -> &str {
+        self.sha.get(..7).unwrap_or(&self.sha)
+    }
+
+    /// First line of the commit message
+    pub fn message_headline(&self) -> &str {
+        self.message.lines().next().unwrap_or_default()
+    }
+}
+
+/// Commits of a single pull request, oldest first
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PullRequestCommits {
+    pub pull_request_id: PullRequestId,
+    pub commits: Vec<CommitSummary>,
+}
+
+/// Outcome of a CI check, normalized across check runs and commit statuses
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, EnumString, Display,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum CheckConclusion {
+    /// The check passed
+    Success,
+    /// The check failed, errored, timed out, was cancelled, or requires action
+    Failure,
+    /// The check is queued or still running
+    Pending,
+    /// The check completed without a pass or fail verdict
+    Neutral,
+    /// The check was skipped or is stale
+    Skipped,
+}
+
+/// A single check run or commit status of a pull request's head commit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct PullRequestCheck {
+    /// Check run name, or the context of a commit status
+    pub name: String,
+    pub conclusion: CheckConclusion,
+    /// Link to the CI run, when the check provides one
+    pub details_url: Option<String>,
+}
+
+/// CI checks of the head commit of a single pull request
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PullRequestChecks {
+    pub pull_request_id: PullRequestId,
+    /// SHA of the head commit, `None` when the pull request has no commits
+    pub head_sha: Option<String>,
+    /// Combined state of all checks, `None` when no CI is configured
+    pub state: Option<CheckConclusion>,
+    pub checks: Vec<PullRequestCheck>,
+}
+
+impl PullRequestChecks {
+    /// Number of checks with the given conclusion
+    pub fn count(&self, conclusion: CheckConclusion) -> usize {
+        self.checks
+            .iter()
+            .filter(|check| check.conclusion == conclusion)
+            .count()
+    }
+}
+
+/// Reviews of a single pull request, oldest first
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PullRequestReviews {
+    pub pull_request_id: PullRequestId,
+    pub reviews: Vec<PullRequestReview>,
+}
+
+impl PullRequestReviews {
+    /// Number of approving reviews
+    pub fn approval_count(&self) -> usize {
+        self.reviews
+            .iter()
+            .filter(|review| review.state == PullRequestReviewState::Approved)
+            .count()
+    }
 }
 
 /// A comment ID specific to pull request comments
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct GitPullRequestCommentId {
     pub pull_request_id: PullRequestId,
     pub comment_number: PullRequestCommentNumber,
@@ -202,13 +467,16 @@ impl GitPullRequestCommentId {
 }
 
 /// Represents a comment on a GitHub pull request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PullRequestComment {
     pub comment_number: u64,
     pub body: String,
     pub author: Option<User>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Whether the comment was written by a bot account such as a CI app
+    #[serde(default)]
+    pub author_is_bot: bool,
 }
 
 impl PullRequestComment {
@@ -220,18 +488,22 @@ impl PullRequestComment {
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
     ) -> Self {
+        let author_is_bot = author
+            .as_ref()
+            .is_some_and(|author| User::is_bot_login(author.as_str()));
         Self {
             comment_number,
             body,
             author,
             created_at,
             updated_at,
+            author_is_bot,
         }
     }
 }
 
 /// Represents an inline code review comment on a GitHub pull request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ReviewThreadComment {
     pub id: String,
     pub body: String,
@@ -253,7 +525,7 @@ pub struct ReviewThreadComment {
 ///
 /// This structure contains metadata about a file changed in a PR, including
 /// the file path, status (added/modified/removed), and optionally the patch/diff content.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PullRequestFile {
     /// The SHA hash identifying this file version
     pub sha: String,
@@ -280,3 +552,192 @@ pub struct PullRequestFile {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub previous_filename: Option<String>,
 }
+
+/// Default safety cap on the number of lines returned for a single file diff
+pub const DEFAULT_DIFF_MAX_LINES: u32 = 1000;
+
+/// File names that are usually generated by tools rather than written by hand
+const GENERATED_FILE_NAMES: &[&str] = &[
+    "Cargo.lock",
+    "package-lock.json",
+    "npm-shrinkwrap.json",
+    "yarn.lock",
+    "pnpm-lock.yaml",
+    "bun.lockb",
+    "composer.lock",
+    "Gemfile.lock",
+    "Pipfile.lock",
+    "poetry.lock",
+    "uv.lock",
+    "go.sum",
+    "flake.lock",
+    "mix.lock",
+    "pubspec.lock",
+    "Podfile.lock",
+];
+
+/// File name suffixes of minified or compiled artifacts
+const GENERATED_FILE_SUFFIXES: &[&str] = &[
+    ".min.js", ".min.css", ".min.mjs", ".js.map", ".css.map", ".pb.go", "_pb2.py", ".g.dart",
+];
+
+/// Check whether a file path looks like a generated artifact such as a lockfile or minified asset
+pub fn is_generated_file_path(path: &str) -> bool {
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    GENERATED_FILE_NAMES.contains(&file_name)
+        || GENERATED_FILE_SUFFIXES
+            .iter()
+            .any(|suffix| file_name.ends_with(suffix))
+}
+
+/// Count added and deleted lines of a unified diff patch
+pub fn count_patch_changes(patch: &str) -> (usize, usize) {
+    patch.lines().fold((0, 0), |(additions, deletions), line| {
+        if line.starts_with('+') && !line.starts_with("+++") {
+            (additions + 1, deletions)
+        } else if line.starts_with('-') && !line.starts_with("---") {
+            (additions, deletions + 1)
+        } else {
+            (additions, deletions)
+        }
+    })
+}
+
+/// Truncate diff content to `max_lines` lines, appending a notice when lines were cut
+pub fn truncate_diff_lines(diff: &str, max_lines: usize) -> String {
+    let total_lines = diff.lines().count();
+    if total_lines <= max_lines {
+        return diff.to_string();
+    }
+
+    let mut truncated = diff.lines().take(max_lines).collect::<Vec<_>>().join("\n");
+    truncated.push_str(&format!(
+        "\n(file diff truncated: {} of {} lines)",
+        max_lines, total_lines
+    ));
+    truncated
+}
+
+/// Split a unified diff patch into hunks, each starting with its `@@ ... @@` header
+///
+/// Lines before the first hunk header, such as `---`/`+++` file headers, belong to no hunk.
+pub fn split_diff_hunks(patch: &str) -> Vec<String> {
+    let mut hunks: Vec<Vec<&str>> = Vec::new();
+    for line in patch.lines() {
+        if line.starts_with("@@") {
+            hunks.push(vec![line]);
+        } else if let Some(hunk) = hunks.last_mut() {
+            hunk.push(line);
+        }
+    }
+    hunks.into_iter().map(|hunk| hunk.join("\n")).collect()
+}
+
+/// Get the complete hunk at `hunk_index` (0-based) of a unified diff patch
+pub fn diff_hunk_at(patch: &str, hunk_index: usize) -> Result<String, String> {
+    let mut hunks = split_diff_hunks(patch);
+    if hunk_index >= hunks.len() {
+        return Err(format!(
+            "hunk_index {} is out of range: the diff has {} hunk{}",
+            hunk_index,
+            hunks.len(),
+            if hunks.len() == 1 { "" } else { "s" }
+        ));
+    }
+    Ok(hunks.swap_remove(hunk_index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_review_state_filter_from_str() {
+        assert_eq!(
+            "approved".parse::<ReviewStateFilter>().unwrap(),
+            ReviewStateFilter::Approved
+        );
+        assert_eq!(
+            "changes_requested".parse::<ReviewStateFilter>().unwrap(),
+            ReviewStateFilter::ChangesRequested
+        );
+        assert_eq!(
+            "required".parse::<ReviewStateFilter>().unwrap(),
+            ReviewStateFilter::Required
+        );
+        assert_eq!(
+            "none".parse::<ReviewStateFilter>().unwrap(),
+            ReviewStateFilter::NoReviews
+        );
+        assert!("merged".parse::<ReviewStateFilter>().is_err());
+    }
+
+    #[test]
+    fn test_pull_request_list_filter_to_search_query() {
+        let filter = PullRequestListFilter::default();
+        assert_eq!(filter.to_search_query().as_str(), "is:pr is:open");
+
+        let filter = PullRequestListFilter {
+            review_state: Some(ReviewStateFilter::Approved),
+            draft: Some(false),
+            author: Some("alice".to_string()),
+            reviewer: Some("bob".to_string()),
+        };
+        assert_eq!(
+            filter.to_search_query().as_str(),
+            "is:pr is:open review:approved draft:false author:alice review-requested:bob"
+        );
+    }
+
+    #[test]
+    fn test_is_generated_file_path() {
+        assert!(is_generated_file_path("Cargo.lock"));
+        assert!(is_generated_file_path("frontend/package-lock.json"));
+        assert!(is_generated_file_path("static/js/app.min.js"));
+        assert!(is_generated_file_path("api/v1/service.pb.go"));
+        assert!(!is_generated_file_path("src/main.rs"));
+        assert!(!is_generated_file_path("docs/lockfile.md"));
+        assert!(!is_generated_file_path("src/minify.js"));
+    }
+
+    #[test]
+    fn test_count_patch_changes() {
+        let patch = "@@ -1,3 +1,4 @@\n fn main() {\n-    old();\n+    new();\n+    more();\n }";
+        assert_eq!(count_patch_changes(patch), (2, 1));
+        assert_eq!(count_patch_changes(""), (0, 0));
+    }
+
+    #[test]
+    fn test_truncate_diff_lines() {
+        let diff = "line1\nline2\nline3\nline4\nline5";
+        assert_eq!(truncate_diff_lines(diff, 5), diff);
+        assert_eq!(truncate_diff_lines(diff, 10), diff);
+        assert_eq!(
+            truncate_diff_lines(diff, 2),
+            "line1\nline2\n(file diff truncated: 2 of 5 lines)"
+        );
+    }
+
+    #[test]
+    fn test_diff_hunk_at() {
+        let patch = "--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1,2 +1,2 @@ fn first() {\n-    old();\n+    new();\n@@ -10,3 +10,4 @@ fn second() {\n     keep();\n+    added();\n }";
+
+        assert_eq!(split_diff_hunks(patch).len(), 2);
+        assert_eq!(
+            diff_hunk_at(patch, 0).unwrap(),
+            "@@ -1,2 +1,2 @@ fn first() {\n-    old();\n+    new();"
+        );
+        assert_eq!(
+            diff_hunk_at(patch, 1).unwrap(),
+            "@@ -10,3 +10,4 @@ fn second() {\n     keep();\n+    added();\n }"
+        );
+        assert_eq!(
+            diff_hunk_at(patch, 2).unwrap_err(),
+            "hunk_index 2 is out of range: the diff has 2 hunks"
+        );
+        assert_eq!(
+            diff_hunk_at("", 0).unwrap_err(),
+            "hunk_index 0 is out of range: the diff has 0 hunks"
+        );
+    }
+}
@@ -0,0 +1,27 @@
+//! GraphQL API rate limit types
+
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Current GraphQL API rate limit budget of the authenticated client
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct RateLimitStatus {
+    /// Maximum number of points per hour
+    pub limit: u32,
+    /// Points left in the current window
+    pub remaining: u32,
+    /// Points used in the current window
+    pub used: u32,
+    /// Points the rate limit query itself cost
+    pub cost: u32,
+    /// When the current window resets
+    pub reset_at: DateTime<Utc>,
+}
+
+impl RateLimitStatus {
+    /// Returns true when no points are left until the window resets
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == 0
+    }
+}
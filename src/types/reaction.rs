@@ -0,0 +1,38 @@
+//! Reaction types for issues and pull requests
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Reaction counts of an issue or pull request, per reaction type
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ReactionSummary {
+    pub thumbs_up: u32,
+    pub thumbs_down: u32,
+    pub laugh: u32,
+    pub hooray: u32,
+    pub confused: u32,
+    pub heart: u32,
+    pub rocket: u32,
+    pub eyes: u32,
+}
+
+impl ReactionSummary {
+    /// Total number of reactions of all types
+    pub fn total(&self) -> u32 {
+        self.counts().iter().map(|(_, count)| count).sum()
+    }
+
+    /// Counts paired with the emoji of each reaction type, in GitHub's display order
+    pub fn counts(&self) -> [(&'static str, u32); 8] {
+        [
+            ("👍", self.thumbs_up),
+            ("👎", self.thumbs_down),
+            ("😄", self.laugh),
+            ("🎉", self.hooray),
+            ("😕", self.confused),
+            ("❤️", self.heart),
+            ("🚀", self.rocket),
+            ("👀", self.eyes),
+        ]
+    }
+}
@@ -10,9 +10,10 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
-use super::{User, label::Label};
-use crate::github::graphql::graphql_types::repository::RepositoryNode;
+use super::{IssueId, User, label::Label};
+use crate::github::graphql::graphql_types::repository::{ReleaseNode, RepositoryNode};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct Branch(pub String);
@@ -25,24 +26,50 @@ impl Branch {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Marker for "the repository's default branch", resolved when the branch is fetched
+    ///
+    /// Git branch names cannot be empty, so the marker is the empty name.
+    pub fn default_marker() -> Self {
+        Self(String::new())
+    }
+
+    /// Whether this is the `default_marker` rather than a named branch
+    pub fn is_default_marker(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Branch name for display, "(default branch)" for the `default_marker`
+    pub fn display_name(&self) -> &str {
+        if self.is_default_marker() {
+            "(default branch)"
+        } else {
+            &self.0
+        }
+    }
 }
 
 /// Repository URL wrapper for type safety
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct RepositoryUrl(pub String);
 
+// Owners are alphanumerics and hyphens; repository names may also contain `.` and `_`
 static HTTPS_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"(?:https?://)?github\.com/([^/]+)/([^/]+?)(?:\.git)?(?:/.*)?/?$")
-        .expect("Failed to compile HTTPS regex")
+    Regex::new(
+        r"^(?:https?://)?(?:www\.)?github\.com/([A-Za-z0-9-]+)/([A-Za-z0-9._-]+?)(?:\.git)?(?:[/?#].*)?$",
+    )
+    .expect("Failed to compile HTTPS regex")
 });
 
 static SSH_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"git@github\.com:([^/]+)/([^/]+?)(?:\.git)?/?$")
+    Regex::new(r"^(?:ssh://)?git@github\.com[:/]([A-Za-z0-9-]+)/([A-Za-z0-9._-]+?)(?:\.git)?$")
         .expect("Failed to compile SSH regex")
 });
 
-static SIMPLE_REGEX: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"^([^/]+)/([^/]+)$").expect("Failed to compile simple regex"));
+static SIMPLE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^([A-Za-z0-9-]+)/([A-Za-z0-9._-]+?)(?:\.git)?$")
+        .expect("Failed to compile simple regex")
+});
 
 /// Owner name wrapper for type safety
 #[derive(
@@ -161,7 +188,7 @@ impl From<&str> for RepositoryUrl {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 pub struct MilestoneNumber(pub u64);
 
 impl std::fmt::Display for MilestoneNumber {
@@ -170,7 +197,7 @@ impl std::fmt::Display for MilestoneNumber {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, PartialOrd, Ord)]
 pub struct MilestoneName(pub String);
 
 impl std::fmt::Display for MilestoneName {
@@ -183,7 +210,7 @@ impl std::fmt::Display for MilestoneName {
 ///
 /// This struct represents the relationship between a repository and its milestones,
 /// storing both the numeric milestone ID and the human-readable milestone name.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 pub struct RepositoryMilestone {
     pub milestone_number: MilestoneNumber,
     /// The human-readable milestone name as displayed in GitHub
@@ -192,7 +219,7 @@ pub struct RepositoryMilestone {
     pub due_date: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 pub struct ReleaseId(pub String);
 
 impl std::fmt::Display for ReleaseId {
@@ -201,7 +228,7 @@ impl std::fmt::Display for ReleaseId {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 pub struct ReleaseName(pub String);
 
 impl std::fmt::Display for ReleaseName {
@@ -210,7 +237,7 @@ impl std::fmt::Display for ReleaseName {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 pub struct TagName(pub String);
 
 impl std::fmt::Display for TagName {
@@ -223,7 +250,7 @@ impl std::fmt::Display for TagName {
 ///
 /// This struct represents a GitHub release with all its metadata,
 /// including version information, timestamps, and author details.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 pub struct RepositoryRelease {
     /// The release ID (derived from tag name if name is not available)
     pub release_id: ReleaseId,
@@ -247,6 +274,58 @@ pub struct RepositoryRelease {
     pub url: String,
 }
 
+/// Downloadable asset attached to a release
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ReleaseAsset {
+    pub name: String,
+    /// Size in bytes
+    pub size: u64,
+    pub download_count: u64,
+    pub download_url: String,
+}
+
+/// Release together with its downloadable assets
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ReleaseWithAssets {
+    pub release: RepositoryRelease,
+    pub assets: Vec<ReleaseAsset>,
+}
+
+/// Live state of a branch compared with the default branch of its repository
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct BranchStatus {
+    pub repository_id: RepositoryId,
+    pub branch: Branch,
+    pub default_branch: Branch,
+    /// Latest commit and divergence, `None` when the branch no longer exists
+    pub head: Option<BranchHead>,
+}
+
+impl BranchStatus {
+    /// Whether the branch was deleted from the repository
+    pub fn is_missing(&self) -> bool {
+        self.head.is_none()
+    }
+}
+
+/// Latest commit of an existing branch and its divergence from the default branch
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct BranchHead {
+    pub sha: String,
+    pub committed_at: Option<DateTime<Utc>>,
+    /// Commits on the branch that are not on the default branch
+    pub ahead_by: u32,
+    /// Commits on the default branch that are not on the branch
+    pub behind_by: u32,
+}
+
+impl BranchHead {
+    /// First 7 characters of the SHA
+    pub fn short_sha(&self) -> &str {
+        self.sha.get(..7).unwrap_or(&self.sha)
+    }
+}
+
 /// A strongly-typed repository identifier for GitHub repositories
 ///
 /// This struct encapsulates all repository identification logic and URL parsing
@@ -262,10 +341,12 @@ pub struct RepositoryId {
 
 impl RepositoryId {
     /// Parse repository identifier from various input formats
-    /// - "https://github.com/owner/repo" - GitHub URL
-    /// - "git@github.com:owner/repo.git" - SSH format
+    /// - "https://github.com/owner/repo" - GitHub URL, optionally with `http://`, `www.`,
+    ///   a `.git` suffix, a trailing slash, or a trailing path such as `/issues/1`
+    /// - "git@github.com:owner/repo.git" or "ssh://git@github.com/owner/repo.git" - SSH format
+    /// - "owner/repo" - simple format
     pub fn parse_url(input: &RepositoryUrl) -> Result<Self, String> {
-        let input_str = input.as_str().trim_end_matches('/');
+        let input_str = input.as_str().trim().trim_end_matches('/');
 
         // Handle GitHub HTTPS URLs
         if let Some(captures) = HTTPS_REGEX.captures(input_str) {
@@ -288,7 +369,7 @@ impl RepositoryId {
             return Ok(Self::new(owner, repo));
         }
 
-        Err(format!("Invalid repository format: {}", input_str))
+        Err(format!("Invalid repository URL format: {}", input_str))
     }
 
     /// Creates a new repository identifier with validation
@@ -315,6 +396,13 @@ impl RepositoryId {
         format!("https://github.com/{}/{}", self.owner, self.repository_name)
     }
 
+    /// Returns the canonical repository URL, `https://github.com/owner/repo`
+    ///
+    /// The result parses back to the same identifier with `parse_url`.
+    pub fn to_url(&self) -> RepositoryUrl {
+        RepositoryUrl(self.url())
+    }
+
     /// Returns the short name (repository name only)
     ///TODO delete
     pub fn short_name(&self) -> &str {
@@ -334,11 +422,27 @@ impl std::fmt::Display for RepositoryId {
     }
 }
 
+/// Issue and pull request counts by state, with stargazer and fork counts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct RepositoryStats {
+    pub repository_id: RepositoryId,
+    pub open_issues: u64,
+    pub closed_issues: u64,
+    pub open_pull_requests: u64,
+    /// Pull requests closed without being merged
+    pub closed_pull_requests: u64,
+    pub merged_pull_requests: u64,
+    pub stargazers: u64,
+    pub forks: u64,
+    /// When the repository was last pushed to
+    pub pushed_at: Option<DateTime<Utc>>,
+}
+
 /// Git repository metadata with comprehensive information
 ///
 /// Contains repository metadata and relationships, including milestones
 /// for search filtering support.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GithubRepository {
     pub git_repository_id: RepositoryId,
     pub description: Option<String>,
@@ -348,8 +452,20 @@ pub struct GithubRepository {
     pub milestones: Vec<RepositoryMilestone>,
     pub default_branch: Option<Branch>,
     pub labels: Vec<Label>,
+    /// Hex colors of the labels without the leading `#` (e.g. "d73a4a"), keyed by label name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub label_colors: BTreeMap<String, String>,
     pub users: Vec<User>,
     pub releases: Vec<RepositoryRelease>,
+    /// Issues pinned by maintainers (up to 3), empty when none are pinned
+    #[serde(default)]
+    pub pinned_issues: Vec<IssueId>,
+    /// Repository topics (tags), empty when none are set
+    #[serde(default)]
+    pub topics: Vec<String>,
+    /// Whether the repository is archived (read-only)
+    #[serde(default)]
+    pub is_archived: bool,
 }
 
 impl GithubRepository {
@@ -366,6 +482,9 @@ impl GithubRepository {
         labels: Vec<Label>,
         users: Vec<User>,
         releases: Vec<RepositoryRelease>,
+        pinned_issues: Vec<IssueId>,
+        topics: Vec<String>,
+        is_archived: bool,
     ) -> Self {
         Self {
             git_repository_id,
@@ -376,11 +495,26 @@ impl GithubRepository {
             milestones,
             default_branch,
             labels,
+            label_colors: BTreeMap::new(),
             users,
             releases,
+            pinned_issues,
+            topics,
+            is_archived,
         }
     }
 
+    /// Attach the colors of the repository's labels, keyed by label name
+    pub fn with_label_colors(mut self, label_colors: BTreeMap<String, String>) -> Self {
+        self.label_colors = label_colors;
+        self
+    }
+
+    /// Hex color of a label without the leading `#`, when known
+    pub fn label_color(&self, label: &Label) -> Option<&str> {
+        self.label_colors.get(label.name()).map(String::as_str)
+    }
+
     /// Get repository identifier
     pub fn repository_id(&self) -> RepositoryId {
         self.git_repository_id.clone()
@@ -425,14 +559,25 @@ impl TryFrom<RepositoryNode> for GithubRepository {
                     .map(|date| date.with_timezone(&Utc));
 
                 RepositoryMilestone {
-                    milestone_number: MilestoneNumber(milestone.number as u64),
+                    milestone_number: MilestoneNumber(milestone.number),
                     milestone_name: MilestoneName(milestone.title),
                     due_date,
                 }
             })
             .collect();
 
-        // Convert labels
+        // Convert labels, keeping their colors apart so labels serialize as plain names
+        let label_colors: BTreeMap<String, String> = node
+            .labels
+            .nodes
+            .iter()
+            .filter_map(|label_node| {
+                label_node
+                    .color
+                    .clone()
+                    .map(|color| (label_node.name.clone(), color))
+            })
+            .collect();
         let labels = node
             .labels
             .nodes
@@ -448,45 +593,36 @@ impl TryFrom<RepositoryNode> for GithubRepository {
             .map(|user_node| User::new(user_node.login))
             .collect();
 
+        // Convert pinned issues
+        let pinned_issues = node
+            .pinned_issues
+            .map(|connection| {
+                connection
+                    .nodes
+                    .into_iter()
+                    .map(|pinned| IssueId::new(repository_id.clone(), pinned.issue.number))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // Convert topics
+        let topics = node
+            .repository_topics
+            .map(|connection| {
+                connection
+                    .nodes
+                    .into_iter()
+                    .map(|topic_node| topic_node.topic.name)
+                    .collect()
+            })
+            .unwrap_or_default();
+
         // Convert releases
         let releases = node
             .releases
             .nodes
             .into_iter()
-            .map(|release_node| {
-                let created_at = chrono::DateTime::parse_from_rfc3339(&release_node.created_at)
-                    .context("Failed to parse release created_at timestamp")
-                    .unwrap_or_else(|_| Utc::now().into())
-                    .with_timezone(&Utc);
-
-                let published_at = release_node
-                    .published_at
-                    .and_then(|date_str| chrono::DateTime::parse_from_rfc3339(&date_str).ok())
-                    .map(|date| date.with_timezone(&Utc));
-
-                let release_name = release_node
-                    .name
-                    .unwrap_or_else(|| release_node.tag_name.clone());
-
-                let release_id = ReleaseId(release_name.clone());
-
-                let author = release_node
-                    .author
-                    .map(|author_node| User::new(author_node.login));
-
-                RepositoryRelease {
-                    release_id,
-                    name: ReleaseName(release_name),
-                    tag_name: TagName(release_node.tag_name),
-                    description: release_node.description,
-                    created_at,
-                    published_at,
-                    is_prerelease: release_node.is_prerelease,
-                    is_draft: release_node.is_draft,
-                    author,
-                    url: release_node.url,
-                }
-            })
+            .map(RepositoryRelease::from)
             .collect();
 
         Ok(GithubRepository::new(
@@ -500,6 +636,217 @@ impl TryFrom<RepositoryNode> for GithubRepository {
             labels,
             users,
             releases,
+            pinned_issues,
+            topics,
+            node.is_archived.unwrap_or(false),
+        )
+        .with_label_colors(label_colors))
+    }
+}
+
+impl From<ReleaseNode> for RepositoryRelease {
+    fn from(release_node: ReleaseNode) -> Self {
+        use anyhow::Context;
+
+        let created_at = chrono::DateTime::parse_from_rfc3339(&release_node.created_at)
+            .context("Failed to parse release created_at timestamp")
+            .unwrap_or_else(|_| Utc::now().into())
+            .with_timezone(&Utc);
+
+        let published_at = release_node
+            .published_at
+            .and_then(|date_str| chrono::DateTime::parse_from_rfc3339(&date_str).ok())
+            .map(|date| date.with_timezone(&Utc));
+
+        let release_name = release_node
+            .name
+            .unwrap_or_else(|| release_node.tag_name.clone());
+
+        let release_id = ReleaseId(release_name.clone());
+
+        let author = release_node
+            .author
+            .map(|author_node| User::new(author_node.login));
+
+        RepositoryRelease {
+            release_id,
+            name: ReleaseName(release_name),
+            tag_name: TagName(release_node.tag_name),
+            description: release_node.description,
+            created_at,
+            published_at,
+            is_prerelease: release_node.is_prerelease,
+            is_draft: release_node.is_draft,
+            author,
+            url: release_node.url,
+        }
+    }
+}
+
+/// A fetched repository together with the identifier it was requested by
+///
+/// GitHub resolves the old name of a renamed or transferred repository to the new one,
+/// so the fetched repository may live under a different owner or name than requested.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RepositoryFetchResult {
+    pub repository: GithubRepository,
+    /// Identifier the repository was requested by
+    pub requested_id: RepositoryId,
+    /// Current identifier of the repository as reported by GitHub
+    pub canonical_id: RepositoryId,
+    /// Whether the requested identifier is an old name of the repository
+    pub was_redirected: bool,
+}
+
+impl RepositoryFetchResult {
+    /// Compare the requested identifier with the fetched repository
+    ///
+    /// GitHub names are case-insensitive, so a difference in case alone is not a redirect.
+    pub fn new(requested_id: RepositoryId, repository: GithubRepository) -> Self {
+        let canonical_id = repository.git_repository_id.clone();
+        let was_redirected = !requested_id
+            .full_name()
+            .eq_ignore_ascii_case(&canonical_id.full_name());
+        Self {
+            repository,
+            requested_id,
+            canonical_id,
+            was_redirected,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(url: &str) -> Result<RepositoryId, String> {
+        RepositoryId::parse_url(&RepositoryUrl(url.to_string()))
+    }
+
+    #[test]
+    fn test_parse_url_shapes() {
+        let expected = RepositoryId::new("owner", "repo");
+        for url in [
+            "https://github.com/owner/repo",
+            "https://github.com/owner/repo/",
+            "https://github.com/owner/repo.git",
+            "https://github.com/owner/repo.git/",
+            "http://github.com/owner/repo",
+            "https://www.github.com/owner/repo",
+            "www.github.com/owner/repo",
+            "github.com/owner/repo",
+            "https://github.com/owner/repo/issues/42",
+            "https://github.com/owner/repo?tab=readme",
+            "git@github.com:owner/repo.git",
+            "git@github.com:owner/repo",
+            "ssh://git@github.com/owner/repo.git",
+            "owner/repo",
+            " owner/repo.git ",
+        ] {
+            assert_eq!(parse(url), Ok(expected.clone()), "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_to_url_is_canonical() {
+        let repository_id = RepositoryId::new("rust-lang", "rust");
+        let url = repository_id.to_url();
+        assert_eq!(
+            url,
+            RepositoryUrl("https://github.com/rust-lang/rust".to_string())
+        );
+        assert_eq!(RepositoryId::parse_url(&url), Ok(repository_id));
+    }
+
+    #[test]
+    fn test_parse_url_keeps_dots_in_repository_names() {
+        assert_eq!(
+            parse("https://github.com/tacogips/github-insight.rs.git"),
+            Ok(RepositoryId::new("tacogips", "github-insight.rs"))
+        );
+        assert_eq!(
+            parse("octo-org/my_repo.v2"),
+            Ok(RepositoryId::new("octo-org", "my_repo.v2"))
+        );
+    }
+
+    #[test]
+    fn test_parse_url_invalid_inputs() {
+        for url in [
+            "",
+            "invalid-url",
+            "https://github.com/owner",
+            "https://github.com/",
+            "https://gitlab.com/owner/repo",
+            "https://example.com/not-a-repo",
+            "see https://github.com/owner/repo",
+            "git@gitlab.com:owner/repo.git",
+            "owner/repo/extra",
+            "github.com/owner",
+            "owner name/repo",
+        ] {
+            assert!(parse(url).is_err(), "{} should not parse", url);
+        }
+    }
+
+    fn repository_node(extra_json: &str) -> RepositoryNode {
+        serde_json::from_str(&format!(
+            r#"{{
+                "name": "repo",
+                "description": null,
+                "primaryLanguage": {{ "name": "Rust" }},
+                "createdAt": "2024-01-01T00:00:00Z",
+                "updatedAt": "2025-01-01T00:00:00Z",
+                "defaultBranchRef": {{ "name": "main" }},
+                "milestones": {{ "nodes": [] }},
+                "labels": {{ "nodes": [] }},
+                "owner": {{ "login": "owner" }},
+                {}
+                "mentionableUsers": {{ "nodes": [] }},
+                "releases": {{ "nodes": [] }}
+            }}"#,
+            extra_json
         ))
+        .expect("valid repository node JSON")
+    }
+
+    #[test]
+    fn test_try_from_repository_node_with_topics() {
+        let node = repository_node(
+            r#""repositoryTopics": { "nodes": [
+                { "topic": { "name": "rust" } },
+                { "topic": { "name": "github" } },
+                { "topic": { "name": "mcp" } }
+            ] },"#,
+        );
+
+        let repository = GithubRepository::try_from(node).unwrap();
+        assert_eq!(repository.topics, vec!["rust", "github", "mcp"]);
+    }
+
+    #[test]
+    fn test_try_from_repository_node_without_topics() {
+        let repository = GithubRepository::try_from(repository_node("")).unwrap();
+        assert!(repository.topics.is_empty());
+
+        let node = repository_node(r#""repositoryTopics": { "nodes": [] },"#);
+        assert!(GithubRepository::try_from(node).unwrap().topics.is_empty());
+    }
+
+    #[test]
+    fn test_try_from_repository_node_is_archived() {
+        let node = repository_node(r#""isArchived": true,"#);
+        assert!(GithubRepository::try_from(node).unwrap().is_archived);
+
+        let node = repository_node(r#""isArchived": false,"#);
+        assert!(!GithubRepository::try_from(node).unwrap().is_archived);
+
+        // A response without the field is treated as not archived
+        assert!(
+            !GithubRepository::try_from(repository_node(""))
+                .unwrap()
+                .is_archived
+        );
     }
 }
@@ -3,10 +3,20 @@
 //! This module provides types for search operations, results,
 //! and profile management in the GitHub Insight system.
 
+use chrono::{Duration, NaiveDate};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use strum::{Display, EnumString};
 
-use super::{ProjectId, RepositoryId};
+use super::{Branch, IssueOrPullrequest, ProjectId, RepositoryId, RepositoryUrl};
+
+/// Search query used when neither the caller nor the profile sets one
+pub const DEFAULT_SEARCH_QUERY: &str = "state:open";
+/// Search result limit used when neither the caller nor the profile sets one
+pub const DEFAULT_SEARCH_LIMIT: usize = 30;
+/// GitHub's maximum page size for search results
+pub const MAX_SEARCH_LIMIT: usize = 100;
 
 /// Represents a search text string.
 ///
@@ -23,12 +33,238 @@ impl SearchQuery {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Returns true when the query contains a `name:` qualifier, negated or not
+    pub fn has_qualifier(&self, name: &str) -> bool {
+        let prefix = format!("{}:", name.to_lowercase());
+        self.0.split_whitespace().any(|token| {
+            token
+                .trim_start_matches('-')
+                .to_lowercase()
+                .starts_with(&prefix)
+        })
+    }
+
+    /// Builds a query for pull requests merged into `base` on or after `since`
+    ///
+    /// When `labels` is not empty, only pull requests carrying at least one of them
+    /// match. Labels containing whitespace are quoted.
+    pub fn merged_pull_requests(base: &Branch, since: NaiveDate, labels: &[String]) -> Self {
+        let mut query = format!(
+            "is:pr is:merged base:{} merged:>={}",
+            base.as_str(),
+            since.format("%Y-%m-%d")
+        );
+        if !labels.is_empty() {
+            let labels: Vec<String> = labels
+                .iter()
+                .map(|label| {
+                    if label.contains(char::is_whitespace) {
+                        format!("\"{}\"", label)
+                    } else {
+                        label.clone()
+                    }
+                })
+                .collect();
+            query.push_str(&format!(" label:{}", labels.join(",")));
+        }
+        Self(query)
+    }
+
+    /// Returns true when the query asks for discussions with an `is:discussion` qualifier
+    pub fn targets_discussions(&self) -> bool {
+        self.0
+            .split_whitespace()
+            .any(|token| token.eq_ignore_ascii_case("is:discussion"))
+    }
+
+    /// Appends the qualifier selecting `state`, unless the query already contains it
+    ///
+    /// Qualifiers selecting another state, such as a `state:open` default when asking
+    /// for `Closed`, are replaced. `All` leaves the query unchanged. Qualifiers are
+    /// compared case-insensitively and `is:open`/`is:closed` count as
+    /// `state:open`/`state:closed`.
+    pub fn with_state(self, state: ResourceState) -> Self {
+        let Some(qualifier) = state.qualifier() else {
+            return self;
+        };
+        let is_equivalent = |token: &str| {
+            state
+                .equivalent_qualifiers()
+                .iter()
+                .any(|equivalent| token.eq_ignore_ascii_case(equivalent))
+        };
+        let conflicts = |token: &str| {
+            STATE_QUALIFIERS
+                .iter()
+                .any(|state_qualifier| token.eq_ignore_ascii_case(state_qualifier))
+                && !is_equivalent(token)
+        };
+
+        let mut query = if self.0.split_whitespace().any(conflicts) {
+            self.0
+                .split_whitespace()
+                .filter(|token| !conflicts(token))
+                .collect::<Vec<_>>()
+                .join(" ")
+        } else {
+            self.0
+        };
+        if query.split_whitespace().any(is_equivalent) {
+            return Self(query);
+        }
+
+        if !query.trim().is_empty() {
+            query.push(' ');
+        }
+        query.push_str(qualifier);
+        Self(query)
+    }
+
+    /// Appends `created:>=since` and `created:<=until` qualifiers for the given bounds
+    pub fn with_created_range(self, since: Option<NaiveDate>, until: Option<NaiveDate>) -> Self {
+        let mut query = self.0;
+        for qualifier in [
+            since.map(|date| format!("created:>={}", date.format("%Y-%m-%d"))),
+            until.map(|date| format!("created:<={}", date.format("%Y-%m-%d"))),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if !query.trim().is_empty() {
+                query.push(' ');
+            }
+            query.push_str(&qualifier);
+        }
+        Self(query)
+    }
+}
+
+/// State of issues and pull requests to search for
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, EnumString, Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ResourceState {
+    /// Open issues and pull requests
+    Open,
+    /// Closed issues and pull requests, including merged pull requests
+    Closed,
+    /// Merged pull requests
+    Merged,
+    /// Any state
+    All,
+}
+
+/// Search qualifiers selecting one of the states of `ResourceState`
+const STATE_QUALIFIERS: &[&str] = &[
+    "state:open",
+    "is:open",
+    "state:closed",
+    "is:closed",
+    "is:merged",
+];
+
+impl ResourceState {
+    /// Returns the GitHub search qualifier for this state, or `None` for `All`
+    pub fn qualifier(&self) -> Option<&'static str> {
+        match self {
+            Self::Open => Some("state:open"),
+            Self::Closed => Some("state:closed"),
+            Self::Merged => Some("is:merged"),
+            Self::All => None,
+        }
+    }
+
+    /// Qualifiers selecting the same state as `qualifier`
+    fn equivalent_qualifiers(&self) -> &'static [&'static str] {
+        match self {
+            Self::Open => &["state:open", "is:open"],
+            Self::Closed => &["state:closed", "is:closed"],
+            Self::Merged => &["is:merged"],
+            Self::All => &[],
+        }
+    }
+}
+
+/// Parse a search date bound given as `YYYY-MM-DD` or as days ago relative to `today` (e.g. `7d`)
+pub fn parse_search_date(input: &str, today: NaiveDate) -> Result<NaiveDate, String> {
+    let input = input.trim();
+    if let Some(days) = input.strip_suffix('d') {
+        // Unsigned so that negative forms such as "-7d" are rejected
+        let days: u32 = days
+            .parse()
+            .map_err(|_| format!("Invalid relative date '{}'. Expected e.g. '7d'", input))?;
+        return today
+            .checked_sub_signed(Duration::days(days.into()))
+            .ok_or_else(|| format!("Relative date '{}' is out of range", input));
+    }
+
+    NaiveDate::parse_from_str(input, "%Y-%m-%d").map_err(|_| {
+        format!(
+            "Invalid date '{}'. Expected YYYY-MM-DD or a relative form like '7d'",
+            input
+        )
+    })
+}
+
+/// Sort order for issue and pull request search results
+///
+/// When no sort is given, GitHub orders results by best match.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, EnumString, Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SearchSort {
+    /// Oldest first
+    CreatedAsc,
+    /// Newest first
+    CreatedDesc,
+    /// Least recently updated first
+    UpdatedAsc,
+    /// Most recently updated first
+    UpdatedDesc,
+    /// Fewest comments first
+    CommentsAsc,
+    /// Most comments first
+    CommentsDesc,
+}
+
+impl SearchSort {
+    /// Returns the GitHub search qualifier for this sort order (e.g. `sort:created-desc`)
+    pub fn qualifier(&self) -> &'static str {
+        match self {
+            Self::CreatedAsc => "sort:created-asc",
+            Self::CreatedDesc => "sort:created-desc",
+            Self::UpdatedAsc => "sort:updated-asc",
+            Self::UpdatedDesc => "sort:updated-desc",
+            Self::CommentsAsc => "sort:comments-asc",
+            Self::CommentsDesc => "sort:comments-desc",
+        }
+    }
+
+    /// Compare two search results in this sort order
+    ///
+    /// Used to order results merged from several repositories the way GitHub orders
+    /// them within one repository.
+    pub fn compare(&self, a: &IssueOrPullrequest, b: &IssueOrPullrequest) -> Ordering {
+        match self {
+            Self::CreatedAsc => a.created_at().cmp(&b.created_at()),
+            Self::CreatedDesc => b.created_at().cmp(&a.created_at()),
+            Self::UpdatedAsc => a.updated_at().cmp(&b.updated_at()),
+            Self::UpdatedDesc => b.updated_at().cmp(&a.updated_at()),
+            Self::CommentsAsc => a.comments_count().cmp(&b.comments_count()),
+            Self::CommentsDesc => b.comments_count().cmp(&a.comments_count()),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::github::graphql::search::normalize_repo_search_query;
+    use crate::github::graphql::search::{apply_search_sort, normalize_repo_search_query};
     use crate::types::RepositoryId;
 
     #[test]
@@ -61,6 +297,151 @@ mod tests {
         let result = normalize_repo_search_query(query, &repo_id);
         assert_eq!(result.as_str(), "repo:test/test is:issue is:pr");
     }
+
+    #[test]
+    fn test_apply_search_sort() {
+        // No sort leaves the query untouched, including any user-provided sort qualifier
+        let query = SearchQuery::new("repo:owner/repo is:issue sort:updated-asc");
+        let result = apply_search_sort(query, None);
+        assert_eq!(result.as_str(), "repo:owner/repo is:issue sort:updated-asc");
+
+        let query = SearchQuery::new("repo:owner/repo is:issue");
+        let result = apply_search_sort(query, Some(SearchSort::CreatedDesc));
+        assert_eq!(
+            result.as_str(),
+            "repo:owner/repo is:issue sort:created-desc"
+        );
+
+        // An explicit sort replaces sort qualifiers already in the query
+        let query = SearchQuery::new("repo:owner/repo sort:updated-asc bug");
+        let result = apply_search_sort(query, Some(SearchSort::CommentsDesc));
+        assert_eq!(result.as_str(), "repo:owner/repo bug sort:comments-desc");
+    }
+
+    #[test]
+    fn test_search_query_has_qualifier() {
+        let query = SearchQuery::new("is:issue Created:>2024-01-01 bug");
+        assert!(query.has_qualifier("created"));
+        assert!(SearchQuery::new("-created:2024-01-01").has_qualifier("created"));
+        assert!(!query.has_qualifier("updated"));
+        assert!(!SearchQuery::new("recreated: things").has_qualifier("created"));
+    }
+
+    #[test]
+    fn test_search_query_with_created_range() {
+        let since = NaiveDate::from_ymd_opt(2024, 1, 1);
+        let until = NaiveDate::from_ymd_opt(2024, 2, 29);
+
+        let query = SearchQuery::new("is:issue").with_created_range(since, until);
+        assert_eq!(
+            query.as_str(),
+            "is:issue created:>=2024-01-01 created:<=2024-02-29"
+        );
+
+        let query = SearchQuery::new("").with_created_range(None, until);
+        assert_eq!(query.as_str(), "created:<=2024-02-29");
+
+        let query = SearchQuery::new("bug").with_created_range(None, None);
+        assert_eq!(query.as_str(), "bug");
+    }
+
+    #[test]
+    fn test_search_query_with_state() {
+        let query = SearchQuery::new("is:pr bug").with_state(ResourceState::Open);
+        assert_eq!(query.as_str(), "is:pr bug state:open");
+
+        let query = SearchQuery::new("").with_state(ResourceState::Merged);
+        assert_eq!(query.as_str(), "is:merged");
+
+        let query = SearchQuery::new("bug").with_state(ResourceState::All);
+        assert_eq!(query.as_str(), "bug");
+    }
+
+    #[test]
+    fn test_search_query_with_state_is_idempotent() {
+        let query = SearchQuery::new("is:issue state:open crash")
+            .with_state(ResourceState::Open)
+            .with_state(ResourceState::Open);
+        assert_eq!(query.as_str(), "is:issue state:open crash");
+
+        let query = SearchQuery::new("STATE:OPEN").with_state(ResourceState::Open);
+        assert_eq!(query.as_str(), "STATE:OPEN");
+
+        let query = SearchQuery::new("is:closed").with_state(ResourceState::Closed);
+        assert_eq!(query.as_str(), "is:closed");
+    }
+
+    #[test]
+    fn test_search_query_with_state_replaces_conflicting_state() {
+        let query = SearchQuery::new("state:open").with_state(ResourceState::Closed);
+        assert_eq!(query.as_str(), "state:closed");
+
+        let query = SearchQuery::new("is:pr IS:OPEN bug").with_state(ResourceState::Merged);
+        assert_eq!(query.as_str(), "is:pr bug is:merged");
+
+        let query = SearchQuery::new("state:open").with_state(ResourceState::All);
+        assert_eq!(query.as_str(), "state:open");
+    }
+
+    #[test]
+    fn test_merged_pull_requests_query() {
+        let since = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+
+        let query = SearchQuery::merged_pull_requests(&Branch::new("main"), since, &[]);
+        assert_eq!(
+            query.as_str(),
+            "is:pr is:merged base:main merged:>=2024-03-01"
+        );
+
+        let query = SearchQuery::merged_pull_requests(
+            &Branch::new("release/1.2"),
+            since,
+            &["feature".to_string(), "bug fix".to_string()],
+        );
+        assert_eq!(
+            query.as_str(),
+            "is:pr is:merged base:release/1.2 merged:>=2024-03-01 label:feature,\"bug fix\""
+        );
+    }
+
+    #[test]
+    fn test_parse_search_date() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+
+        assert_eq!(
+            parse_search_date("2024-01-15", today),
+            Ok(NaiveDate::from_ymd_opt(2024, 1, 15).unwrap())
+        );
+        assert_eq!(
+            parse_search_date("7d", today),
+            Ok(NaiveDate::from_ymd_opt(2024, 3, 3).unwrap())
+        );
+        assert_eq!(parse_search_date("0d", today), Ok(today));
+        assert!(parse_search_date("yesterday", today).is_err());
+        assert!(parse_search_date("xd", today).is_err());
+        assert!(parse_search_date("-7d", today).is_err());
+        assert!(parse_search_date("2024/01/15", today).is_err());
+    }
+
+    #[test]
+    fn test_search_cursor_by_repository_new() {
+        let cursor =
+            SearchCursorByRepository::new("https://github.com/owner/repo", "Y3Vyc29yOjE=").unwrap();
+        assert_eq!(cursor.repository_id, RepositoryId::new("owner", "repo"));
+        assert_eq!(cursor.cursor.0, "Y3Vyc29yOjE=");
+
+        assert!(SearchCursorByRepository::new("not a url", "Y3Vyc29yOjE=").is_err());
+        assert!(SearchCursorByRepository::new("owner/repo", " ").is_err());
+    }
+
+    #[test]
+    fn test_search_sort_from_str() {
+        assert_eq!(
+            "updated_desc".parse::<SearchSort>().unwrap(),
+            SearchSort::UpdatedDesc
+        );
+        assert!("relevance".parse::<SearchSort>().is_err());
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -72,10 +453,36 @@ pub struct SearchCursorByRepository {
     pub repository_id: RepositoryId,
 }
 
+impl SearchCursorByRepository {
+    /// Creates a cursor for the repository at `repository_url`
+    ///
+    /// Accepts the same URL formats as `RepositoryId::parse_url`, and fails when the
+    /// URL cannot be parsed or the cursor is empty.
+    pub fn new(repository_url: &str, cursor: &str) -> Result<Self, String> {
+        let repository_id = RepositoryId::parse_url(&RepositoryUrl(repository_url.to_string()))?;
+        if cursor.trim().is_empty() {
+            return Err(format!(
+                "Search cursor for {} must not be empty",
+                repository_id
+            ));
+        }
+
+        Ok(Self {
+            cursor: SearchCursor(cursor.to_string()),
+            repository_id,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SearchResultPager {
     pub next_page_cursor: Option<SearchCursor>,
     pub has_next_page: bool,
+    /// Cursor to pass as `before` to fetch the preceding page
+    #[serde(default)]
+    pub previous_page_cursor: Option<SearchCursor>,
+    #[serde(default)]
+    pub has_previous_page: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
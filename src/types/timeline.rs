@@ -0,0 +1,45 @@
+//! Issue timeline types
+//!
+//! This module provides the cross-reference events of an issue timeline: references
+//! from other issues and pull requests, and manual links to pull requests.
+
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+use crate::types::{IssueId, IssueOrPullrequestId};
+
+/// Kind of an issue timeline event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum TimelineEventKind {
+    /// Referenced from another issue or pull request
+    CrossReferenced,
+    /// Manually linked to a pull request
+    Connected,
+    /// Manual link to a pull request removed
+    Disconnected,
+}
+
+/// Timeline event linking an issue to another issue or pull request
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TimelineEvent {
+    pub kind: TimelineEventKind,
+    pub created_at: DateTime<Utc>,
+    /// The issue or pull request on the other side of the event
+    pub resource: IssueOrPullrequestId,
+    pub title: String,
+    pub state: String,
+    /// Whether merging the referencing pull request will close the issue
+    pub will_close_target: bool,
+}
+
+/// Cross-reference timeline events of an issue, oldest first
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IssueTimeline {
+    pub issue_id: IssueId,
+    pub issue_title: String,
+    pub events: Vec<TimelineEvent>,
+}
@@ -3,13 +3,16 @@
 //! This module provides types for user identification and participation
 //! in Git resources like issues and pull requests.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::types::RepositoryId;
+
 /// User identifier wrapper type for GitHub usernames
 ///
 /// This type provides type-safe user identification for GitHub users,
 /// storing the username for complete identification.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash)]
 pub struct User(String);
 
 impl User {
@@ -22,6 +25,11 @@ impl User {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Returns true when `login` follows GitHub's `name[bot]` naming of app accounts
+    pub fn is_bot_login(login: &str) -> bool {
+        login.ends_with("[bot]")
+    }
 }
 
 impl std::fmt::Display for User {
@@ -53,3 +61,28 @@ impl PartialEq<&str> for User {
         self.0 == *other
     }
 }
+
+/// An issue or pull request listed in a user activity summary
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct UserActivityItem {
+    pub title: String,
+    pub url: String,
+}
+
+/// Issues and pull requests a user authored or is assigned to across a set of repositories
+///
+/// Items are ordered by most recent update. When a repository had more matches than
+/// `per_repository_limit`, it is listed in `truncated_repositories` and the counts
+/// are lower bounds.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UserActivity {
+    pub username: String,
+    pub repository_count: usize,
+    pub per_repository_limit: u32,
+    pub authored_open_issues: Vec<UserActivityItem>,
+    pub authored_closed_issues: Vec<UserActivityItem>,
+    pub authored_open_pull_requests: Vec<UserActivityItem>,
+    pub authored_merged_pull_requests: Vec<UserActivityItem>,
+    pub assigned_open_items: Vec<UserActivityItem>,
+    pub truncated_repositories: Vec<RepositoryId>,
+}
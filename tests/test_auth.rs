@@ -0,0 +1,62 @@
+//! Tests for validating the configured token
+
+mod test_util;
+use github_insight::tools::functions::auth::check_auth;
+use github_insight::types::AuthStatus;
+use test_util::create_mock_github_client_with_token;
+
+#[tokio::test]
+async fn test_check_auth_reports_login_and_scopes() {
+    let mut server = mockito::Server::new_async().await;
+    let viewer = server
+        .mock("POST", "/graphql")
+        .match_body(mockito::Matcher::Regex("viewer".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"viewer": {"login": "octocat"}}}"#)
+        .create_async()
+        .await;
+    let user = server
+        .mock("GET", "/user")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-oauth-scopes", "repo, read:org")
+        .with_body(r#"{"login": "octocat"}"#)
+        .create_async()
+        .await;
+
+    let status = check_auth(&create_mock_github_client_with_token(
+        &server,
+        Some("test-token"),
+    ))
+    .await
+    .expect("Failed to check auth");
+
+    assert_eq!(
+        status,
+        AuthStatus::Authenticated {
+            login: "octocat".to_string(),
+            scopes: Some(vec!["repo".to_string(), "read:org".to_string()]),
+        }
+    );
+    viewer.assert_async().await;
+    user.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_check_auth_without_token_makes_no_request() {
+    let mut server = mockito::Server::new_async().await;
+    let viewer = server
+        .mock("POST", "/graphql")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let status = check_auth(&create_mock_github_client_with_token(&server, None))
+        .await
+        .expect("Failed to check auth");
+
+    assert_eq!(status, AuthStatus::NoToken);
+    assert!(!status.is_authenticated());
+    viewer.assert_async().await;
+}
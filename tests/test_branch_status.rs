@@ -0,0 +1,81 @@
+//! Tests for fetching the live status of a branch via the REST API
+
+mod test_util;
+use github_insight::types::{Branch, RepositoryId};
+use test_util::create_mock_github_client;
+
+async fn mock_repository(server: &mut mockito::ServerGuard) -> mockito::Mock {
+    server
+        .mock("GET", "/repos/owner/repo")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"name": "repo", "default_branch": "main"}"#)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_fetch_branch_status_ahead_and_behind() {
+    let mut server = mockito::Server::new_async().await;
+    let repository = mock_repository(&mut server).await;
+    let branch = server
+        .mock("GET", "/repos/owner/repo/branches/feature-x")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"name": "feature-x", "commit": {"sha": "0123456789abcdef", "commit": {"committer": {"date": "2024-05-01T12:00:00Z"}}}}"#,
+        )
+        .create_async()
+        .await;
+    let compare = server
+        .mock("GET", "/repos/owner/repo/compare/main...feature-x")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"status": "diverged", "ahead_by": 3, "behind_by": 5}"#)
+        .create_async()
+        .await;
+
+    let status = create_mock_github_client(&server)
+        .fetch_branch_status(
+            &RepositoryId::new("owner", "repo"),
+            &Branch::new("feature-x"),
+        )
+        .await
+        .expect("Failed to fetch branch status");
+
+    assert_eq!(status.default_branch, Branch::new("main"));
+    let head = status.head.expect("branch should exist");
+    assert_eq!(head.short_sha(), "0123456");
+    assert_eq!(
+        head.committed_at.map(|date| date.to_rfc3339()),
+        Some("2024-05-01T12:00:00+00:00".to_string())
+    );
+    assert_eq!((head.ahead_by, head.behind_by), (3, 5));
+
+    repository.assert_async().await;
+    branch.assert_async().await;
+    compare.assert_async().await;
+}
+
+/// A deleted branch is reported as missing without comparing it
+#[tokio::test]
+async fn test_fetch_branch_status_missing_branch() {
+    let mut server = mockito::Server::new_async().await;
+    let repository = mock_repository(&mut server).await;
+    let branch = server
+        .mock("GET", "/repos/owner/repo/branches/gone")
+        .with_status(404)
+        .with_body(r#"{"message": "Branch not found"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let status = create_mock_github_client(&server)
+        .fetch_branch_status(&RepositoryId::new("owner", "repo"), &Branch::new("gone"))
+        .await
+        .expect("Failed to fetch branch status");
+
+    assert!(status.is_missing());
+    repository.assert_async().await;
+    branch.assert_async().await;
+}
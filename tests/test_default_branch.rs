@@ -0,0 +1,81 @@
+//! Tests for resolving the default branch of repository branch pairs
+
+mod test_util;
+use github_insight::types::{Branch, RepositoryBranchPair, RepositoryId};
+use test_util::create_mock_github_client;
+
+/// Repository response whose default branch is `default_branch`
+fn repository_response(default_branch: &str) -> String {
+    format!(
+        r#"{{
+    "data": {{
+        "repository": {{
+            "name": "repo",
+            "description": null,
+            "primaryLanguage": null,
+            "createdAt": "2024-01-01T00:00:00Z",
+            "updatedAt": "2024-06-01T00:00:00Z",
+            "defaultBranchRef": {{ "name": "{}" }},
+            "milestones": {{ "nodes": [] }},
+            "labels": {{ "nodes": [] }},
+            "owner": {{ "login": "owner" }},
+            "mentionableUsers": {{ "nodes": [] }},
+            "releases": {{ "nodes": [] }}
+        }}
+    }}
+}}"#,
+        default_branch
+    )
+}
+
+#[tokio::test]
+async fn test_fetch_default_branch() {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("POST", "/graphql")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(repository_response("main"))
+        .create_async()
+        .await;
+    let client = create_mock_github_client(&server);
+
+    let branch = client
+        .fetch_default_branch(&RepositoryId::new("owner", "repo"))
+        .await
+        .expect("Failed to fetch default branch");
+    assert_eq!(branch, Branch::new("main"));
+}
+
+/// A pair parsed without a branch resolves to the default branch; named branches are kept
+#[tokio::test]
+async fn test_resolve_default_branch_of_pair() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/graphql")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(repository_response("main"))
+        .expect(1)
+        .create_async()
+        .await;
+    let client = create_mock_github_client(&server);
+
+    let pair = RepositoryBranchPair::parse_specifier("https://github.com/owner/repo@").unwrap();
+    let resolved = client
+        .resolve_default_branch(pair)
+        .await
+        .expect("Failed to resolve default branch");
+    assert_eq!(resolved.repository_id, RepositoryId::new("owner", "repo"));
+    assert_eq!(resolved.branch, Branch::new("main"));
+
+    // A named branch needs no request
+    let pair = RepositoryBranchPair::parse_specifier("https://github.com/owner/repo@dev").unwrap();
+    let resolved = client
+        .resolve_default_branch(pair.clone())
+        .await
+        .expect("Failed to resolve named branch");
+    assert_eq!(resolved, pair);
+
+    mock.assert_async().await;
+}
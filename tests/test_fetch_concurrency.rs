@@ -0,0 +1,141 @@
+//! Tests for the concurrency limit of MultiResourceFetcher against a server counting requests in flight
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use github_insight::github::client::GitHubClient;
+use github_insight::services::MultiResourceFetcher;
+use github_insight::types::{PullRequestNumber, RepositoryId};
+
+const EMPTY_PULL_REQUESTS_RESPONSE: &str = r#"{"data":{"repository":{}}}"#;
+
+/// How long the server holds each request, so overlapping requests are observable
+const RESPONSE_DELAY: Duration = Duration::from_millis(150);
+
+/// Counters shared with the instrumented server
+#[derive(Default)]
+struct RequestCounters {
+    in_flight: AtomicUsize,
+    max_in_flight: AtomicUsize,
+    total: AtomicUsize,
+}
+
+/// Start a server answering every request with an empty pull request response
+fn spawn_instrumented_server(counters: Arc<RequestCounters>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind test server");
+    let address = listener.local_addr().unwrap();
+
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let counters = counters.clone();
+            thread::spawn(move || handle_connection(stream, &counters));
+        }
+    });
+
+    format!("http://{}/graphql", address)
+}
+
+fn handle_connection(stream: TcpStream, counters: &RequestCounters) {
+    let mut reader = BufReader::new(stream.try_clone().expect("Failed to clone stream"));
+    let mut stream = stream;
+
+    // Serve requests until the client closes the kept-alive connection
+    loop {
+        let mut content_length = 0;
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                return;
+            }
+            let header = line.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            if let Some((_, value)) = header
+                .split_once(':')
+                .filter(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+            {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+        let mut body = vec![0; content_length];
+        if reader.read_exact(&mut body).is_err() {
+            return;
+        }
+
+        let in_flight = counters.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        counters
+            .max_in_flight
+            .fetch_max(in_flight, Ordering::SeqCst);
+        counters.total.fetch_add(1, Ordering::SeqCst);
+        thread::sleep(RESPONSE_DELAY);
+        counters.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+        let response = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+            EMPTY_PULL_REQUESTS_RESPONSE.len(),
+            EMPTY_PULL_REQUESTS_RESPONSE
+        );
+        if stream.write_all(response.as_bytes()).is_err() {
+            return;
+        }
+    }
+}
+
+fn pull_request_batches(repository_count: usize) -> Vec<(RepositoryId, Vec<PullRequestNumber>)> {
+    (0..repository_count)
+        .map(|index| {
+            (
+                RepositoryId::new("owner", format!("repo-{}", index)),
+                vec![PullRequestNumber::new(1)],
+            )
+        })
+        .collect()
+}
+
+/// With a limit of 2, no more than 2 repositories are fetched at the same time
+#[tokio::test]
+async fn test_fetch_pull_requests_respects_max_concurrency() {
+    let counters = Arc::new(RequestCounters::default());
+    let endpoint = spawn_instrumented_server(counters.clone());
+
+    let client = GitHubClient::new(None, None)
+        .expect("Failed to create GitHub client")
+        .with_graphql_endpoint(endpoint);
+    let fetcher = MultiResourceFetcher::new(client).with_max_concurrency(2);
+    assert_eq!(fetcher.max_concurrency(), 2);
+
+    let results = fetcher
+        .fetch_pull_requests(pull_request_batches(6))
+        .await
+        .expect("Failed to fetch pull requests");
+
+    assert_eq!(results.len(), 6);
+    assert_eq!(counters.total.load(Ordering::SeqCst), 6);
+    assert!(counters.max_in_flight.load(Ordering::SeqCst) <= 2);
+}
+
+/// The default limit still fetches repositories in parallel
+#[tokio::test]
+async fn test_fetch_pull_requests_default_concurrency_runs_in_parallel() {
+    let counters = Arc::new(RequestCounters::default());
+    let endpoint = spawn_instrumented_server(counters.clone());
+
+    let client = GitHubClient::new(None, None)
+        .expect("Failed to create GitHub client")
+        .with_graphql_endpoint(endpoint);
+    let fetcher = MultiResourceFetcher::new(client);
+
+    fetcher
+        .fetch_pull_requests(pull_request_batches(4))
+        .await
+        .expect("Failed to fetch pull requests");
+
+    assert_eq!(counters.total.load(Ordering::SeqCst), 4);
+    assert!(counters.max_in_flight.load(Ordering::SeqCst) > 2);
+}
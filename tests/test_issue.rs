@@ -7,6 +7,8 @@ use serial_test::serial;
 
 mod test_util;
 use github_insight::services::MultiResourceFetcher;
+#[cfg(feature = "integration-tests")]
+use github_insight::types::IssueId;
 use github_insight::types::{IssueNumber, RepositoryId};
 use test_util::create_test_github_client;
 
@@ -34,7 +36,7 @@ async fn test_fetch_multiple_issues_by_numbers() {
 
     // Fetch the issues
     let result = client
-        .fetch_multiple_issues_by_numbers(repository_id.clone(), &issue_numbers)
+        .fetch_multiple_issues_by_numbers(repository_id.clone(), &issue_numbers, None)
         .await;
 
     // Verify the request succeeded
@@ -82,7 +84,7 @@ async fn test_fetch_issues_empty_input() {
 
     // Fetch the issues
     let result = client
-        .fetch_multiple_issues_by_numbers(repository_id, &issue_numbers)
+        .fetch_multiple_issues_by_numbers(repository_id, &issue_numbers, None)
         .await;
 
     // Should return empty result successfully
@@ -119,7 +121,7 @@ async fn test_fetch_non_existent_issue() {
 
     // Fetch the issue
     let result = client
-        .fetch_multiple_issues_by_numbers(repository_id, &issue_numbers)
+        .fetch_multiple_issues_by_numbers(repository_id, &issue_numbers, None)
         .await;
 
     // The client should return an error for non-existent issues
@@ -218,3 +220,98 @@ async fn test_multi_resource_fetcher_issues() {
         issues_by_repo.len()
     );
 }
+
+/// Test walking an issue's comments page by page
+///
+/// Fetches the comments of issue #1 in tacogips/gitcodes-mcp-test-1 one per page and
+/// verifies the collected comments match the comments returned by the full issue fetch.
+///
+/// Note: This test requires GitHub authentication as it uses GraphQL API.
+/// Run with: cargo test --features integration-tests
+#[tokio::test]
+#[serial]
+#[cfg(feature = "integration-tests")]
+async fn test_fetch_issue_comments_paginated() {
+    let client = create_test_github_client();
+    let repository_id =
+        RepositoryId::new("tacogips".to_string(), "gitcodes-mcp-test-1".to_string());
+    let issue_number = IssueNumber::new(1);
+
+    let issues = client
+        .fetch_multiple_issues_by_numbers(repository_id.clone(), &[issue_number], None)
+        .await
+        .expect("Failed to fetch issue");
+    let Some(issue) = issues.first() else {
+        println!("Issue #1 not found - skipping pagination check");
+        return;
+    };
+
+    let mut paginated_comments = Vec::new();
+    let mut cursor = None;
+    loop {
+        let (comments, pager) = client
+            .fetch_issue_comments_paginated(repository_id.clone(), issue_number, cursor, 1)
+            .await
+            .expect("Failed to fetch issue comments page");
+        assert!(comments.len() <= 1, "Page size should be respected");
+        paginated_comments.extend(comments);
+
+        match pager.and_then(|pager| pager.next_page_cursor) {
+            Some(next_cursor) => cursor = Some(next_cursor),
+            None => break,
+        }
+    }
+
+    let expected: Vec<_> = issue.comments.iter().map(|c| c.comment_number).collect();
+    let actual: Vec<_> = paginated_comments
+        .iter()
+        .map(|c| c.comment_number)
+        .collect();
+    assert_eq!(actual, expected);
+}
+
+/// Test fetching the cross-reference timeline of an issue
+///
+/// Verifies that the timeline of issue #1 in tacogips/gitcodes-mcp-test-1 can be fetched
+/// and that every event links to an issue or pull request with a title.
+///
+/// Note: This test requires GitHub authentication as it uses GraphQL API.
+/// Run with: cargo test --features integration-tests
+#[tokio::test]
+#[serial]
+#[cfg(feature = "integration-tests")]
+async fn test_fetch_issue_timeline() {
+    let client = create_test_github_client();
+    let issue_id = IssueId::new(
+        RepositoryId::new("tacogips".to_string(), "gitcodes-mcp-test-1".to_string()),
+        1,
+    );
+
+    let timeline = client
+        .fetch_issue_timeline(issue_id.clone())
+        .await
+        .expect("Failed to fetch issue timeline");
+
+    assert_eq!(timeline.issue_id, issue_id);
+    assert!(!timeline.issue_title.is_empty());
+    for event in &timeline.events {
+        println!(
+            "{} {} {} {}",
+            event.created_at,
+            event.kind,
+            event.title,
+            event.resource.url()
+        );
+        assert!(
+            !event.title.is_empty(),
+            "Linked resource should have a title"
+        );
+    }
+    assert!(
+        timeline
+            .events
+            .windows(2)
+            .all(|pair| pair[0].created_at <= pair[1].created_at),
+        "Events should be in chronological order"
+    );
+}
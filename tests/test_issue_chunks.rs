@@ -0,0 +1,54 @@
+//! Tests for fetching many issues by number in chunked GraphQL queries
+
+mod test_util;
+use github_insight::github::client::ISSUE_CHUNK_SIZE;
+use github_insight::types::{IssueNumber, RepositoryId};
+use test_util::create_mock_github_client;
+
+/// Response in which every aliased issue of a chunk is missing
+fn not_found_response(chunk_len: usize) -> String {
+    let issues: Vec<String> = (0..chunk_len)
+        .map(|index| format!(r#""issue{}": null"#, index))
+        .collect();
+    format!(r#"{{"data": {{"repository": {{{}}}}}}}"#, issues.join(","))
+}
+
+/// 60 issue numbers are split into two queries of `ISSUE_CHUNK_SIZE` issues
+#[tokio::test]
+async fn test_fetch_multiple_issues_by_numbers_in_chunks() {
+    assert_eq!(ISSUE_CHUNK_SIZE, 30);
+
+    let mut server = mockito::Server::new_async().await;
+    let first_chunk = server
+        .mock("POST", "/graphql")
+        .match_body(mockito::Matcher::Regex(
+            r"issue29: issue\(number: 30\)".to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(not_found_response(ISSUE_CHUNK_SIZE))
+        .expect(1)
+        .create_async()
+        .await;
+    let second_chunk = server
+        .mock("POST", "/graphql")
+        .match_body(mockito::Matcher::Regex(
+            r"issue0: issue\(number: 31\)".to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(not_found_response(ISSUE_CHUNK_SIZE))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let issue_numbers: Vec<IssueNumber> = (1..=60).map(IssueNumber::new).collect();
+    let issues = create_mock_github_client(&server)
+        .fetch_multiple_issues_by_numbers(RepositoryId::new("owner", "repo"), &issue_numbers, None)
+        .await
+        .expect("Missing issues should be skipped, not fail the fetch");
+
+    assert!(issues.is_empty());
+    first_chunk.assert_async().await;
+    second_chunk.assert_async().await;
+}
@@ -0,0 +1,52 @@
+//! Tests for fetching issue reactions via the REST API
+
+mod test_util;
+use github_insight::types::{IssueNumber, ReactionSummary, RepositoryId};
+use test_util::create_mock_github_client;
+
+#[tokio::test]
+async fn test_fetch_issue_reactions() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/repos/owner/repo/issues/42")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{
+                "number": 42,
+                "title": "Crash on startup",
+                "reactions": {
+                    "url": "https://api.github.com/repos/owner/repo/issues/42/reactions",
+                    "total_count": 9,
+                    "+1": 5,
+                    "-1": 1,
+                    "laugh": 0,
+                    "hooray": 2,
+                    "confused": 0,
+                    "heart": 0,
+                    "rocket": 0,
+                    "eyes": 1
+                }
+            }"#,
+        )
+        .create_async()
+        .await;
+
+    let reactions = create_mock_github_client(&server)
+        .fetch_issue_reactions(&RepositoryId::new("owner", "repo"), IssueNumber::new(42))
+        .await
+        .expect("Failed to fetch reactions");
+
+    assert_eq!(
+        reactions,
+        ReactionSummary {
+            thumbs_up: 5,
+            thumbs_down: 1,
+            hooray: 2,
+            eyes: 1,
+            ..Default::default()
+        }
+    );
+    assert_eq!(reactions.total(), 9);
+    mock.assert_async().await;
+}
@@ -0,0 +1,120 @@
+//! Tests for fetching the issues of a milestone
+
+mod test_util;
+use github_insight::types::{IssueState, MilestoneNumber, RepositoryId};
+use test_util::create_mock_github_client;
+
+fn issue_node(number: u32) -> String {
+    format!(
+        r#"{{
+            "number": {number},
+            "title": "Issue {number}",
+            "body": "Tracked for the release",
+            "state": "OPEN",
+            "createdAt": "2025-01-01T00:00:00Z",
+            "updatedAt": "2025-01-02T00:00:00Z",
+            "closedAt": null,
+            "url": "https://github.com/owner/repo/issues/{number}",
+            "comments": {{ "nodes": [], "totalCount": 0 }},
+            "labels": null,
+            "assignees": null,
+            "author": {{ "login": "alice" }},
+            "milestone": {{ "number": 3 }},
+            "locked": false,
+            "timelineItems": null,
+            "repository": {{ "owner": {{ "login": "owner" }}, "name": "repo" }}
+        }}"#
+    )
+}
+
+fn milestone_page(nodes: &[String], end_cursor: Option<&str>) -> String {
+    format!(
+        r#"{{
+            "data": {{
+                "repository": {{
+                    "milestone": {{
+                        "issues": {{
+                            "nodes": [{}],
+                            "pageInfo": {{ "hasNextPage": {}, "endCursor": {} }}
+                        }}
+                    }}
+                }}
+            }}
+        }}"#,
+        nodes.join(","),
+        end_cursor.is_some(),
+        end_cursor
+            .map(|cursor| format!("\"{}\"", cursor))
+            .unwrap_or_else(|| "null".to_string())
+    )
+}
+
+/// All pages of the milestone are fetched with the requested state
+#[tokio::test]
+async fn test_fetch_issues_by_milestone_paginates() {
+    let mut server = mockito::Server::new_async().await;
+    let first_page = server
+        .mock("POST", "/graphql")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"variables": {"milestone_number": 3, "states": ["OPEN"], "cursor": null}}"#
+                .to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(milestone_page(
+            &[issue_node(1), issue_node(2)],
+            Some("page-2"),
+        ))
+        .create_async()
+        .await;
+    let second_page = server
+        .mock("POST", "/graphql")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"variables": {"cursor": "page-2"}}"#.to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(milestone_page(&[issue_node(3)], None))
+        .create_async()
+        .await;
+
+    let issues = create_mock_github_client(&server)
+        .fetch_issues_by_milestone(
+            RepositoryId::new("owner", "repo"),
+            MilestoneNumber::new(3),
+            IssueState::Open,
+        )
+        .await
+        .expect("Failed to fetch milestone issues");
+
+    let numbers: Vec<u32> = issues.iter().map(|issue| issue.issue_id.number).collect();
+    assert_eq!(numbers, vec![1, 2, 3]);
+    assert_eq!(issues[0].milestone_id, Some(3));
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+}
+
+/// A milestone number that does not exist yields no issues instead of an error
+#[tokio::test]
+async fn test_fetch_issues_by_missing_milestone_returns_empty() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/graphql")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"repository": {"milestone": null}}}"#)
+        .create_async()
+        .await;
+
+    let issues = create_mock_github_client(&server)
+        .fetch_issues_by_milestone(
+            RepositoryId::new("owner", "repo"),
+            MilestoneNumber::new(999),
+            IssueState::Closed,
+        )
+        .await
+        .expect("Missing milestone should not be an error");
+
+    assert!(issues.is_empty());
+    mock.assert_async().await;
+}
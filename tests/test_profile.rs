@@ -10,7 +10,7 @@ use uuid::Uuid;
 use github_insight::services::{ProfileService, ProfileServiceError};
 use github_insight::types::{
     Branch,
-    profile::{GroupName, ProfileName, RepositoryBranchPair},
+    profile::{ClearScope, GroupName, ProfileName, RepositoryBranchPair},
     project::{ProjectId, ProjectNumber, ProjectType},
     repository::{Owner, RepositoryId, RepositoryName},
 };
@@ -466,6 +466,66 @@ fn test_list_repositories_multiple() {
     assert!(repositories.contains(&repo2));
 }
 
+#[test]
+fn test_list_repositories_sorted() {
+    let temp_dir = create_test_temp_dir();
+    let mut service = ProfileService::new(temp_dir.path().to_path_buf()).unwrap();
+    let profile = ProfileName::from("test-dummy-profile");
+
+    let zeta = create_test_repository("zeta", "app");
+    let alpha_web = create_test_repository("alpha", "web");
+    let alpha_api = create_test_repository("alpha", "api");
+    for repository in [&zeta, &alpha_web, &alpha_api] {
+        service
+            .register_repository(&profile, repository.clone())
+            .unwrap();
+    }
+
+    let repositories = service.list_repositories(&profile).unwrap();
+    assert_eq!(repositories, vec![alpha_api, alpha_web, zeta]);
+}
+
+#[test]
+fn test_list_repositories_across_profiles() {
+    let temp_dir = create_test_temp_dir();
+    let mut service = ProfileService::new(temp_dir.path().to_path_buf()).unwrap();
+
+    let shared = create_test_repository("owner1", "shared");
+    let only_work = create_test_repository("owner2", "work");
+    let only_personal = create_test_repository("alice", "dotfiles");
+
+    let work = ProfileName::from("work");
+    let personal = ProfileName::from("personal");
+    service
+        .register_repository(&work, only_work.clone())
+        .unwrap();
+    service.register_repository(&work, shared.clone()).unwrap();
+    service
+        .register_repository(&personal, shared.clone())
+        .unwrap();
+    service
+        .register_repository(&personal, only_personal.clone())
+        .unwrap();
+
+    // All profiles, de-duplicated and sorted
+    let all = service.list_repositories_across_profiles(&[]).unwrap();
+    assert_eq!(
+        all,
+        vec![only_personal.clone(), shared.clone(), only_work.clone()]
+    );
+
+    let selected = service
+        .list_repositories_across_profiles(&[work.clone()])
+        .unwrap();
+    assert_eq!(selected, vec![shared, only_work]);
+
+    let missing = service.list_repositories_across_profiles(&[ProfileName::from("missing")]);
+    assert!(matches!(
+        missing.unwrap_err(),
+        ProfileServiceError::ProfileNotFound(_)
+    ));
+}
+
 #[test]
 fn test_list_projects_empty() {
     let temp_dir = create_test_temp_dir();
@@ -505,6 +565,23 @@ fn test_list_projects_multiple() {
     assert!(projects.contains(&project2));
 }
 
+#[test]
+fn test_list_projects_sorted() {
+    let temp_dir = create_test_temp_dir();
+    let mut service = ProfileService::new(temp_dir.path().to_path_buf()).unwrap();
+    let profile = ProfileName::from("test-dummy-profile");
+
+    let zeta = create_test_project("zeta", 1);
+    let alpha_10 = create_test_project("alpha", 10);
+    let alpha_2 = create_test_project("alpha", 2);
+    for project in [&zeta, &alpha_10, &alpha_2] {
+        service.register_project(&profile, project.clone()).unwrap();
+    }
+
+    let projects = service.list_projects(&profile).unwrap();
+    assert_eq!(projects, vec![alpha_2, alpha_10, zeta]);
+}
+
 #[test]
 fn test_get_profile_info_success() {
     let temp_dir = create_test_temp_dir();
@@ -887,6 +964,45 @@ fn test_add_pair_to_group_already_exists() {
     ));
 }
 
+#[test]
+fn test_add_pairs_to_group_skips_existing() {
+    let temp_dir = create_test_temp_dir();
+    let mut service = ProfileService::new(temp_dir.path().to_path_buf()).unwrap();
+    let profile_name = ProfileName::from("test-dummy-profile");
+
+    let existing = create_test_repository_branch_unit("rust-lang", "rust", "main");
+    let new_unit = create_test_repository_branch_unit("tokio-rs", "tokio", "main");
+
+    let group_name = service
+        .register_repository_branch_group(
+            &profile_name,
+            Some(GroupName::from("pattern-group")),
+            vec![existing.clone()],
+        )
+        .unwrap();
+
+    let added = service
+        .add_pairs_to_group(
+            &profile_name,
+            &group_name,
+            vec![existing.clone(), new_unit.clone(), new_unit.clone()],
+        )
+        .unwrap();
+
+    assert_eq!(added, vec![new_unit.clone()]);
+
+    let group = service
+        .get_repository_branch_group(&profile_name, &group_name)
+        .unwrap();
+    assert_eq!(group.pairs, vec![existing.clone(), new_unit.clone()]);
+
+    // Adding only known pairs is not an error and adds nothing
+    let added_again = service
+        .add_pairs_to_group(&profile_name, &group_name, vec![existing, new_unit])
+        .unwrap();
+    assert!(added_again.is_empty());
+}
+
 #[test]
 fn test_remove_pair_from_group() {
     let temp_dir = create_test_temp_dir();
@@ -1064,13 +1180,13 @@ fn test_remove_groups_older_than() {
 
     // Cleanup groups older than 1 day (should not remove the newly created group)
     let removed_groups = service
-        .remove_groups_older_than(&ProfileName::from("test-dummy-profile"), 1)
+        .remove_groups_older_than(&ProfileName::from("test-dummy-profile"), 1, false)
         .unwrap();
     assert_eq!(removed_groups.len(), 0);
 
     // Cleanup groups older than 0 days (should remove all groups)
     let removed_groups = service
-        .remove_groups_older_than(&ProfileName::from("test-dummy-profile"), 0)
+        .remove_groups_older_than(&ProfileName::from("test-dummy-profile"), 0, false)
         .unwrap();
     assert_eq!(removed_groups.len(), 1);
     assert_eq!(removed_groups[0], group_name);
@@ -1085,6 +1201,61 @@ fn test_remove_groups_older_than() {
     ));
 }
 
+#[test]
+fn test_remove_groups_older_than_dry_run() {
+    let temp_dir = create_test_temp_dir();
+    let mut service = ProfileService::new(temp_dir.path().to_path_buf()).unwrap();
+    let profile = ProfileName::from("test-dummy-profile");
+
+    for (name, repo) in [("group-b", "rust"), ("group-a", "cargo")] {
+        service
+            .register_repository_branch_group(
+                &profile,
+                Some(GroupName::from(name)),
+                vec![create_test_repository_branch_unit(
+                    "rust-lang",
+                    repo,
+                    "main",
+                )],
+            )
+            .unwrap();
+    }
+
+    let would_remove = service.remove_groups_older_than(&profile, 0, true).unwrap();
+    assert_eq!(
+        would_remove,
+        vec![GroupName::from("group-a"), GroupName::from("group-b")]
+    );
+
+    // Nothing was removed, in memory or on disk
+    assert_eq!(
+        service
+            .list_repository_branch_groups(&profile)
+            .unwrap()
+            .len(),
+        2
+    );
+    let reloaded = ProfileService::new(temp_dir.path().to_path_buf()).unwrap();
+    assert_eq!(
+        reloaded
+            .list_repository_branch_groups(&profile)
+            .unwrap()
+            .len(),
+        2
+    );
+
+    let removed = service
+        .remove_groups_older_than(&profile, 0, false)
+        .unwrap();
+    assert_eq!(removed, would_remove);
+    assert!(
+        service
+            .list_repository_branch_groups(&profile)
+            .unwrap()
+            .is_empty()
+    );
+}
+
 #[test]
 fn test_repository_branch_group_profile_isolation() {
     let temp_dir = create_test_temp_dir();
@@ -1173,3 +1344,306 @@ fn test_repository_branch_group_persistence_across_instances() {
         assert!(group.pairs.contains(&unit2));
     }
 }
+
+#[test]
+fn test_merge_profiles_renames_colliding_groups() {
+    let temp_dir = create_test_temp_dir();
+    let mut service = ProfileService::new(temp_dir.path().to_path_buf()).unwrap();
+    let source = ProfileName::from("source-profile");
+    let target = ProfileName::from("target-profile");
+
+    let shared_repo = create_test_repository("rust-lang", "rust");
+    let source_repo = create_test_repository("tokio-rs", "tokio");
+    service
+        .register_repository(&source, shared_repo.clone())
+        .unwrap();
+    service
+        .register_repository(&source, source_repo.clone())
+        .unwrap();
+    service
+        .register_repository(&target, shared_repo.clone())
+        .unwrap();
+    service
+        .register_project(&source, create_test_project("rust-lang", 1))
+        .unwrap();
+
+    let source_unit = create_test_repository_branch_unit("tokio-rs", "tokio", "feature");
+    let target_unit = create_test_repository_branch_unit("rust-lang", "rust", "main");
+    for (profile, unit) in [(&source, &source_unit), (&target, &target_unit)] {
+        service
+            .register_repository_branch_group(
+                profile,
+                Some(GroupName::from("release")),
+                vec![unit.clone()],
+            )
+            .unwrap();
+    }
+    // Occupy the first suffixed name so the counter kicks in
+    service
+        .register_repository_branch_group(
+            &target,
+            Some(GroupName::from("release-merged")),
+            vec![target_unit.clone()],
+        )
+        .unwrap();
+
+    let summary = service.merge_profiles(&source, &target, false).unwrap();
+
+    assert_eq!(summary.repositories_merged, 1);
+    assert_eq!(summary.repositories_skipped, 1);
+    assert_eq!(summary.projects_merged, 1);
+    assert_eq!(summary.projects_skipped, 0);
+    assert_eq!(summary.groups_merged, 1);
+    assert_eq!(
+        summary.renamed_groups,
+        vec![(
+            GroupName::from("release"),
+            GroupName::from("release-merged-2")
+        )]
+    );
+    assert!(!summary.source_deleted);
+
+    let repositories = service.list_repositories(&target).unwrap();
+    assert_eq!(repositories.len(), 2);
+    assert!(repositories.contains(&source_repo));
+
+    let original = service
+        .get_repository_branch_group(&target, &GroupName::from("release"))
+        .unwrap();
+    assert_eq!(original.pairs, vec![target_unit]);
+    let merged = service
+        .get_repository_branch_group(&target, &GroupName::from("release-merged-2"))
+        .unwrap();
+    assert_eq!(merged.pairs, vec![source_unit]);
+
+    // The source profile is left untouched
+    assert_eq!(service.list_repositories(&source).unwrap().len(), 2);
+}
+
+#[test]
+fn test_merge_profiles_persistence_across_instances() {
+    let temp_dir = create_test_temp_dir();
+    let data_dir = temp_dir.path().to_path_buf();
+    let source = ProfileName::from("source-profile");
+    let target = ProfileName::from("target-profile");
+    let repository = create_test_repository("rust-lang", "rust");
+    let project = create_test_project("rust-lang", 1);
+
+    {
+        let mut service = ProfileService::new(data_dir.clone()).unwrap();
+        service
+            .register_repository(&source, repository.clone())
+            .unwrap();
+        service.register_project(&source, project.clone()).unwrap();
+        service
+            .register_repository_branch_group(
+                &source,
+                Some(GroupName::from("review")),
+                vec![create_test_repository_branch_unit(
+                    "rust-lang",
+                    "rust",
+                    "main",
+                )],
+            )
+            .unwrap();
+        service.create_profile(&target, None).unwrap();
+
+        let summary = service.merge_profiles(&source, &target, true).unwrap();
+        assert!(summary.source_deleted);
+        assert!(summary.renamed_groups.is_empty());
+    }
+
+    {
+        let service = ProfileService::new(data_dir).unwrap();
+        assert!(!service.list_profiles().contains(&source));
+        assert_eq!(
+            service.list_repositories(&target).unwrap(),
+            vec![repository]
+        );
+        assert_eq!(service.list_projects(&target).unwrap(), vec![project]);
+        assert_eq!(
+            service.list_repository_branch_groups(&target).unwrap(),
+            vec![GroupName::from("review")]
+        );
+    }
+}
+
+#[test]
+fn test_merge_profiles_invalid_arguments() {
+    let temp_dir = create_test_temp_dir();
+    let mut service = ProfileService::new(temp_dir.path().to_path_buf()).unwrap();
+    let profile = ProfileName::from("some-profile");
+    service.create_profile(&profile, None).unwrap();
+
+    assert!(matches!(
+        service.merge_profiles(&profile, &profile, false),
+        Err(ProfileServiceError::InvalidProfileName(_))
+    ));
+    assert!(matches!(
+        service.merge_profiles(&ProfileName::from("missing"), &profile, false),
+        Err(ProfileServiceError::ProfileNotFound(_))
+    ));
+    assert!(matches!(
+        service.merge_profiles(&ProfileName::default(), &profile, true),
+        Err(ProfileServiceError::InvalidProfileName(_))
+    ));
+}
+
+#[test]
+fn test_clear_profile_repositories_keeps_projects() {
+    let temp_dir = create_test_temp_dir();
+    let data_dir = temp_dir.path().to_path_buf();
+    let profile = ProfileName::from("work");
+    let project = create_test_project("rust-lang", 1);
+
+    {
+        let mut service = ProfileService::new(data_dir.clone()).unwrap();
+        service
+            .register_repository(&profile, create_test_repository("rust-lang", "rust"))
+            .unwrap();
+        service
+            .register_repository(&profile, create_test_repository("tokio-rs", "tokio"))
+            .unwrap();
+        service.register_project(&profile, project.clone()).unwrap();
+        service
+            .register_repository_branch_group(
+                &profile,
+                Some(GroupName::from("review")),
+                vec![create_test_repository_branch_unit(
+                    "rust-lang",
+                    "rust",
+                    "main",
+                )],
+            )
+            .unwrap();
+
+        let summary = service
+            .clear_profile(&profile, ClearScope::Repositories)
+            .unwrap();
+        assert_eq!(summary.repositories_removed, 2);
+        assert_eq!(summary.projects_removed, 0);
+        assert_eq!(summary.groups_removed, 0);
+    }
+
+    // A fresh instance sees the cleared repositories and the retained entries
+    let mut service = ProfileService::new(data_dir.clone()).unwrap();
+    assert!(service.list_repositories(&profile).unwrap().is_empty());
+    assert_eq!(service.list_projects(&profile).unwrap(), vec![project]);
+    assert_eq!(
+        service.list_repository_branch_groups(&profile).unwrap(),
+        vec![GroupName::from("review")]
+    );
+
+    let summary = service.clear_profile(&profile, ClearScope::All).unwrap();
+    assert_eq!(summary.projects_removed, 1);
+    assert_eq!(summary.groups_removed, 1);
+
+    let service = ProfileService::new(data_dir).unwrap();
+    assert!(service.list_profiles().contains(&profile));
+    assert!(service.list_projects(&profile).unwrap().is_empty());
+    assert!(
+        service
+            .list_repository_branch_groups(&profile)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_clear_nonexistent_profile() {
+    let temp_dir = create_test_temp_dir();
+    let mut service = ProfileService::new(temp_dir.path().to_path_buf()).unwrap();
+
+    assert!(matches!(
+        service.clear_profile(&ProfileName::from("missing"), ClearScope::All),
+        Err(ProfileServiceError::ProfileNotFound(_))
+    ));
+}
+
+#[test]
+fn test_export_import_profile_round_trip() {
+    let export_dir = create_test_temp_dir();
+    let mut service = ProfileService::new(export_dir.path().to_path_buf()).unwrap();
+    let profile_name = ProfileName::from("shared-profile");
+
+    service
+        .create_profile(&profile_name, Some("Team profile".to_string()))
+        .unwrap();
+    service
+        .register_repository(&profile_name, create_test_repository("rust-lang", "rust"))
+        .unwrap();
+    service
+        .register_repository(&profile_name, create_test_repository("tokio-rs", "tokio"))
+        .unwrap();
+    service
+        .register_project(&profile_name, create_test_project("rust-lang", 1))
+        .unwrap();
+    let group_name = service
+        .register_repository_branch_group(
+            &profile_name,
+            Some(GroupName::from("release")),
+            vec![
+                create_test_repository_branch_unit("rust-lang", "rust", "main"),
+                create_test_repository_branch_unit("tokio-rs", "tokio", "master"),
+            ],
+        )
+        .unwrap();
+
+    let json = service.export_profile(&profile_name).unwrap();
+
+    // Import into a fresh data directory under a new name
+    let import_dir = create_test_temp_dir();
+    let imported_name = ProfileName::from("imported-profile");
+    {
+        let mut service = ProfileService::new(import_dir.path().to_path_buf()).unwrap();
+        let name = service
+            .import_profile(&json, Some(imported_name.clone()))
+            .unwrap();
+        assert_eq!(name, imported_name);
+    }
+
+    let imported_service = ProfileService::new(import_dir.path().to_path_buf()).unwrap();
+    let original = service.get_profile_info(&profile_name).unwrap();
+    let imported = imported_service.get_profile_info(&imported_name).unwrap();
+
+    assert_eq!(imported.name, imported_name);
+    assert_eq!(imported.description.as_deref(), Some("Team profile"));
+    assert_eq!(imported.repositories, original.repositories);
+    assert_eq!(imported.projects, original.projects);
+    assert_eq!(
+        imported.repository_branch_groups,
+        original.repository_branch_groups
+    );
+    assert_eq!(
+        imported_service
+            .get_repository_branch_group(&imported_name, &group_name)
+            .unwrap()
+            .pairs
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn test_import_profile_already_exists() {
+    let temp_dir = create_test_temp_dir();
+    let mut service = ProfileService::new(temp_dir.path().to_path_buf()).unwrap();
+    let profile_name = ProfileName::from("existing-profile");
+    service.create_profile(&profile_name, None).unwrap();
+
+    let json = service.export_profile(&profile_name).unwrap();
+    assert_eq!(
+        service.import_profile(&json, None),
+        Err(ProfileServiceError::ProfileAlreadyExists(
+            "existing-profile".to_string()
+        ))
+    );
+    assert!(matches!(
+        service.import_profile("not json", None),
+        Err(ProfileServiceError::SerializationError(_))
+    ));
+    assert!(matches!(
+        service.export_profile(&ProfileName::from("missing")),
+        Err(ProfileServiceError::ProfileNotFound(_))
+    ));
+}
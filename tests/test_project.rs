@@ -5,6 +5,7 @@
 //!
 //! Note: All tests in this file require GitHub authentication as they use GraphQL API.
 //! Run with: cargo test --features integration-tests
+#![cfg(feature = "integration-tests")]
 
 use serial_test::serial;
 
@@ -33,7 +34,9 @@ async fn test_fetch_project_resources() {
     );
 
     // Fetch the project resources
-    let result = client.fetch_all_project_resources(project_id.clone()).await;
+    let result = client
+        .fetch_all_project_resources(project_id.clone(), &[])
+        .await;
 
     // Verify the request succeeded
     assert!(
@@ -128,7 +131,7 @@ async fn test_fetch_non_existent_project() {
     );
 
     // Fetch the project resources
-    let result = client.fetch_all_project_resources(project_id).await;
+    let result = client.fetch_all_project_resources(project_id, &[]).await;
 
     // The client should return an error for non-existent projects
     assert!(
@@ -167,7 +170,7 @@ async fn test_fetch_project_resources_mixed_types() {
     );
 
     // Fetch the project resources
-    let result = client.fetch_all_project_resources(project_id).await;
+    let result = client.fetch_all_project_resources(project_id, &[]).await;
 
     // Verify the request succeeded
     assert!(
@@ -273,7 +276,9 @@ async fn test_multi_resource_fetcher_fetch_project_resources() {
     );
 
     // Fetch the project resources using MultiResourceFetcher
-    let result = fetcher.fetch_project_resources(project_id.clone()).await;
+    let result = fetcher
+        .fetch_project_resources(project_id.clone(), &[])
+        .await;
 
     // Verify the request succeeded
     assert!(
@@ -365,7 +370,7 @@ async fn test_multi_resource_fetcher_non_existent_project() {
     );
 
     // Fetch the project resources using MultiResourceFetcher
-    let result = fetcher.fetch_project_resources(project_id).await;
+    let result = fetcher.fetch_project_resources(project_id, &[]).await;
 
     // The fetcher should return an error for non-existent projects
     assert!(
@@ -405,7 +410,7 @@ async fn test_multi_resource_fetcher_empty_project() {
     );
 
     // Fetch the project resources using MultiResourceFetcher
-    let result = fetcher.fetch_project_resources(project_id).await;
+    let result = fetcher.fetch_project_resources(project_id, &[]).await;
 
     // Verify the request succeeded
     assert!(
@@ -555,3 +560,60 @@ async fn test_fetch_non_existent_project_single() {
         error_msg
     );
 }
+
+/// Test setting a text field of a project item
+///
+/// This test writes a timestamp into the first custom text field of the first item of
+/// the tacogips/projects/1 test project, so the token must have project write access.
+#[tokio::test]
+#[serial]
+#[cfg(feature = "integration-tests")]
+async fn test_update_project_item_field() {
+    use github_insight::types::ProjectFieldUpdateValue;
+
+    let client = create_test_github_client();
+
+    // https://github.com/users/tacogips/projects/1
+    let project_id = ProjectId::new(
+        Owner::new("tacogips".to_string()),
+        ProjectNumber::new(1),
+        ProjectType::User,
+    );
+
+    let project = client
+        .fetch_project(project_id.clone())
+        .await
+        .expect("Failed to fetch project");
+    let fields = client
+        .fetch_project_fields(project_id.clone())
+        .await
+        .expect("Failed to fetch project fields");
+    let resources = client
+        .fetch_all_project_resources(project_id, &[])
+        .await
+        .expect("Failed to fetch project resources");
+
+    let Some(text_field) = fields.iter().find(|field| field.data_type == "TEXT") else {
+        println!("Test project has no custom text field; skipping update");
+        return;
+    };
+    let item = resources
+        .first()
+        .expect("Test project should have at least one item");
+
+    let value = ProjectFieldUpdateValue::Text(format!(
+        "updated by integration test at {}",
+        chrono::Utc::now().to_rfc3339()
+    ));
+    let updated_item_id = client
+        .update_project_item_field(
+            &project.project_node_id,
+            &item.project_item_id,
+            &text_field.field_id,
+            &value,
+        )
+        .await
+        .expect("Failed to update project item field");
+
+    assert_eq!(updated_item_id.0, item.project_item_id.0);
+}
@@ -0,0 +1,104 @@
+//! Tests for fetching the field definitions of a project
+
+mod test_util;
+use github_insight::types::{
+    Owner, ProjectFieldId, ProjectFieldUpdateValue, ProjectId, ProjectItemId, ProjectNodeId,
+    ProjectNumber, ProjectType,
+};
+use test_util::create_mock_github_client;
+
+const FIELDS: &str = r#"{
+    "fields": {
+        "nodes": [
+            { "__typename": "ProjectV2Field", "id": "F_title", "name": "Title", "dataType": "TITLE" },
+            {
+                "__typename": "ProjectV2SingleSelectField",
+                "id": "F_status",
+                "name": "Status",
+                "dataType": "SINGLE_SELECT",
+                "options": [
+                    { "id": "opt_todo", "name": "Todo" },
+                    { "id": "opt_done", "name": "Done" }
+                ]
+            },
+            { "__typename": "ProjectV2IterationField", "id": "F_sprint", "name": "Sprint", "dataType": "ITERATION" }
+        ]
+    }
+}"#;
+
+/// Organization projects fall back to the user query when the organization has no such project
+#[tokio::test]
+async fn test_fetch_project_fields_falls_back_to_user_project() {
+    let mut server = mockito::Server::new_async().await;
+    let organization_query = server
+        .mock("POST", "/graphql")
+        .match_body(mockito::Matcher::Regex(r"organization\(login".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{ "data": { "organization": { "projectV2": null } } }"#)
+        .create_async()
+        .await;
+    let user_query = server
+        .mock("POST", "/graphql")
+        .match_body(mockito::Matcher::Regex(r"user\(login".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!(
+            r#"{{ "data": {{ "user": {{ "projectV2": {} }} }} }}"#,
+            FIELDS
+        ))
+        .create_async()
+        .await;
+
+    let project_id = ProjectId::new(
+        Owner::new("octocat".to_string()),
+        ProjectNumber::new(1),
+        ProjectType::Organization,
+    );
+    let fields = create_mock_github_client(&server)
+        .fetch_project_fields(project_id)
+        .await
+        .expect("Failed to fetch project fields");
+
+    assert_eq!(fields.len(), 3);
+    let status = &fields[1];
+    assert_eq!(status.field_id.0, "F_status");
+    assert_eq!(status.data_type, "SINGLE_SELECT");
+    let options: Vec<(&str, &str)> = status
+        .options
+        .iter()
+        .map(|option| (option.option_id.as_str(), option.name.as_str()))
+        .collect();
+    assert_eq!(options, vec![("opt_todo", "Todo"), ("opt_done", "Done")]);
+    assert!(fields[2].options.is_empty());
+    organization_query.assert_async().await;
+    user_query.assert_async().await;
+}
+
+/// Updating a field value is rejected before any request when the client has no token
+#[tokio::test]
+async fn test_update_project_item_field_requires_token() {
+    let mut server = mockito::Server::new_async().await;
+    let mutation = server
+        .mock("POST", "/graphql")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let result = create_mock_github_client(&server)
+        .update_project_item_field(
+            &ProjectNodeId("PVT_project".to_string()),
+            &ProjectItemId("PVTI_item".to_string()),
+            &ProjectFieldId("PVTF_field".to_string()),
+            &ProjectFieldUpdateValue::Text("value".to_string()),
+        )
+        .await;
+
+    let error = result.expect_err("Unauthenticated update should fail");
+    assert!(
+        error.to_string().contains("authenticated GitHub token"),
+        "Unexpected error: {}",
+        error
+    );
+    mutation.assert_async().await;
+}
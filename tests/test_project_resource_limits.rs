@@ -0,0 +1,301 @@
+//! Tests for capping the pages and items fetched from a project
+
+use std::time::Duration;
+
+mod test_util;
+use github_insight::tools::functions;
+use github_insight::types::{
+    Owner, ProjectFetchLimits, ProjectId, ProjectNumber, ProjectResourceDeduplication, ProjectType,
+    SearchCursor, SearchCursorByProject,
+};
+use test_util::create_mock_github_client;
+
+fn user_project_id() -> ProjectId {
+    ProjectId::new(
+        Owner::new("octocat".to_string()),
+        ProjectNumber::new(1),
+        ProjectType::User,
+    )
+}
+
+fn draft_item(index: usize) -> String {
+    format!(
+        r#"{{
+            "id": "PVTI_{index}",
+            "content": {{
+                "__typename": "DraftIssue",
+                "id": "DI_{index}",
+                "title": "Draft {index}",
+                "createdAt": "2025-01-02T03:04:05Z",
+                "updatedAt": "2025-01-02T03:04:05Z"
+            }},
+            "fieldValues": {{ "nodes": [] }}
+        }}"#
+    )
+}
+
+fn items_page(indices: std::ops::Range<usize>, end_cursor: Option<&str>) -> String {
+    items_page_with_cost(indices, end_cursor, 1)
+}
+
+/// A page of items whose query reports `cost` GraphQL points
+fn items_page_with_cost(
+    indices: std::ops::Range<usize>,
+    end_cursor: Option<&str>,
+    cost: u64,
+) -> String {
+    let items: Vec<String> = indices.map(draft_item).collect();
+    format!(
+        r#"{{
+            "data": {{
+                "rateLimit": {{ "cost": {}, "remaining": 4000 }},
+                "user": {{
+                    "projectV2": {{
+                        "id": "PVT_1",
+                        "title": "Board",
+                        "items": {{
+                            "nodes": [{}],
+                            "pageInfo": {{ "hasNextPage": {}, "endCursor": {} }}
+                        }}
+                    }}
+                }}
+            }}
+        }}"#,
+        cost,
+        items.join(","),
+        end_cursor.is_some(),
+        end_cursor
+            .map(|cursor| format!("\"{}\"", cursor))
+            .unwrap_or_else(|| "null".to_string())
+    )
+}
+
+async fn mock_page(
+    server: &mut mockito::ServerGuard,
+    body_regex: &str,
+    body: String,
+    hits: usize,
+) -> mockito::Mock {
+    server
+        .mock("POST", "/graphql")
+        .match_body(mockito::Matcher::Regex(body_regex.to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .expect(hits)
+        .create_async()
+        .await
+}
+
+/// Paging stops at `max_pages` and the cursor of the next page is returned
+#[tokio::test]
+async fn test_fetch_project_resources_stops_at_page_cap() {
+    let mut server = mockito::Server::new_async().await;
+    let first_page = mock_page(
+        &mut server,
+        r"items\(first: 100\) \{",
+        items_page(0..2, Some("page-2")),
+        1,
+    )
+    .await;
+    let second_page = mock_page(
+        &mut server,
+        r#"after: \\"page-2\\""#,
+        items_page(2..4, Some("page-3")),
+        1,
+    )
+    .await;
+    let third_page = mock_page(
+        &mut server,
+        r#"after: \\"page-3\\""#,
+        items_page(4..6, None),
+        0,
+    )
+    .await;
+
+    let limits = ProjectFetchLimits {
+        max_pages: Some(2),
+        ..ProjectFetchLimits::default()
+    };
+    let fetch = create_mock_github_client(&server)
+        .fetch_project_resources_with_limits(user_project_id(), &[], limits, None)
+        .await
+        .expect("Failed to fetch project resources");
+
+    assert_eq!(fetch.resources.len(), 4);
+    assert_eq!(
+        fetch.next_cursor.map(|cursor| cursor.0).as_deref(),
+        Some("page-3")
+    );
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+    third_page.assert_async().await;
+}
+
+/// Pages ask only for the remaining items, so `max_items` is met exactly
+#[tokio::test]
+async fn test_fetch_project_resources_stops_at_item_cap() {
+    let mut server = mockito::Server::new_async().await;
+    let first_page = mock_page(
+        &mut server,
+        r"items\(first: 3\) \{",
+        items_page(0..2, Some("page-2")),
+        1,
+    )
+    .await;
+    let second_page = mock_page(
+        &mut server,
+        r#"items\(first: 1, after: \\"page-2\\"\)"#,
+        items_page(2..3, Some("page-3")),
+        1,
+    )
+    .await;
+
+    let fetch = create_mock_github_client(&server)
+        .fetch_project_resources_with_limits(
+            user_project_id(),
+            &[],
+            ProjectFetchLimits::max_items(3),
+            None,
+        )
+        .await
+        .expect("Failed to fetch project resources");
+
+    let item_ids: Vec<&str> = fetch
+        .resources
+        .iter()
+        .map(|resource| resource.project_item_id.0.as_str())
+        .collect();
+    assert_eq!(item_ids, vec!["PVTI_0", "PVTI_1", "PVTI_2"]);
+    assert_eq!(
+        fetch.next_cursor.map(|cursor| cursor.0).as_deref(),
+        Some("page-3")
+    );
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+}
+
+/// Without caps every page is fetched and no cursor is returned
+#[tokio::test]
+async fn test_fetch_project_resources_without_limits_reads_all_pages() {
+    let mut server = mockito::Server::new_async().await;
+    let first_page = mock_page(
+        &mut server,
+        r"items\(first: 100\) \{",
+        items_page(0..1, Some("page-2")),
+        1,
+    )
+    .await;
+    let second_page = mock_page(
+        &mut server,
+        r#"after: \\"page-2\\""#,
+        items_page(1..2, None),
+        1,
+    )
+    .await;
+
+    let limits = ProjectFetchLimits {
+        deadline: Some(Duration::from_secs(60)),
+        ..ProjectFetchLimits::default()
+    };
+    let fetch = create_mock_github_client(&server)
+        .fetch_project_resources_with_limits(user_project_id(), &[], limits, None)
+        .await
+        .expect("Failed to fetch project resources");
+
+    assert_eq!(fetch.resources.len(), 2);
+    assert!(fetch.next_cursor.is_none());
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+}
+
+/// Paging stops once the accumulated GraphQL cost exceeds the client's budget
+#[tokio::test]
+async fn test_fetch_project_resources_stops_when_cost_budget_is_exceeded() {
+    let mut server = mockito::Server::new_async().await;
+    let first_page = mock_page(
+        &mut server,
+        r"items\(first: 100\) \{",
+        items_page_with_cost(0..2, Some("page-2"), 6),
+        1,
+    )
+    .await;
+    let second_page = mock_page(
+        &mut server,
+        r#"after: \\"page-2\\""#,
+        items_page_with_cost(2..4, Some("page-3"), 6),
+        1,
+    )
+    .await;
+    let third_page = mock_page(
+        &mut server,
+        r#"after: \\"page-3\\""#,
+        items_page_with_cost(4..6, None, 6),
+        0,
+    )
+    .await;
+
+    let client = create_mock_github_client(&server).with_max_total_cost(10);
+    let fetch = client
+        .fetch_project_resources_with_limits(
+            user_project_id(),
+            &[],
+            ProjectFetchLimits::default(),
+            None,
+        )
+        .await
+        .expect("Failed to fetch project resources");
+
+    assert_eq!(fetch.resources.len(), 4);
+    assert_eq!(
+        fetch.next_cursor.map(|cursor| cursor.0).as_deref(),
+        Some("page-3")
+    );
+    assert_eq!(client.cost_budget().spent(), 12);
+    assert!(client.cost_budget().is_exceeded());
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+    third_page.assert_async().await;
+}
+
+/// Projects resume from their cursor and report the cursor of the next page again
+#[tokio::test]
+async fn test_get_multiple_project_resources_resumes_from_cursor() {
+    let mut server = mockito::Server::new_async().await;
+    let first_page = mock_page(
+        &mut server,
+        r"items\(first: 2\) \{",
+        items_page(0..2, Some("page-2")),
+        0,
+    )
+    .await;
+    let second_page = mock_page(
+        &mut server,
+        r#"after: \\"page-2\\""#,
+        items_page(2..4, Some("page-3")),
+        1,
+    )
+    .await;
+
+    let start_cursors = [SearchCursorByProject {
+        cursor: SearchCursor("page-2".to_string()),
+        project_id: user_project_id(),
+    }];
+    let (resources, cursors) = functions::project::get_multiple_project_resources(
+        &create_mock_github_client(&server),
+        vec![user_project_id()],
+        ProjectResourceDeduplication::Raw,
+        &[],
+        ProjectFetchLimits::max_items(2),
+        &start_cursors,
+    )
+    .await
+    .expect("Failed to fetch project resources");
+
+    assert_eq!(resources.resources.len(), 2);
+    assert_eq!(cursors.len(), 1);
+    assert_eq!(cursors[0].project_id, user_project_id());
+    assert_eq!(cursors[0].cursor.0, "page-3");
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+}
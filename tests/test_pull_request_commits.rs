@@ -0,0 +1,119 @@
+//! Tests for fetching the commits of a pull request
+
+mod test_util;
+use github_insight::types::{PullRequestNumber, RepositoryId};
+use test_util::create_mock_github_client;
+
+fn commit_node(oid: &str, message: &str, login: Option<&str>) -> String {
+    let user = login
+        .map(|login| format!(r#"{{ "login": "{}" }}"#, login))
+        .unwrap_or_else(|| "null".to_string());
+    format!(
+        r#"{{
+            "commit": {{
+                "oid": "{oid}",
+                "message": "{message}",
+                "authoredDate": "2025-01-02T03:04:05Z",
+                "author": {{ "name": "Git Author", "user": {user} }}
+            }}
+        }}"#
+    )
+}
+
+fn commits_page(nodes: &[String], end_cursor: Option<&str>) -> String {
+    format!(
+        r#"{{
+            "data": {{
+                "repository": {{
+                    "pullRequest": {{
+                        "commits": {{
+                            "nodes": [{}],
+                            "pageInfo": {{ "hasNextPage": {}, "endCursor": {} }}
+                        }}
+                    }}
+                }}
+            }}
+        }}"#,
+        nodes.join(","),
+        end_cursor.is_some(),
+        end_cursor
+            .map(|cursor| format!("\"{}\"", cursor))
+            .unwrap_or_else(|| "null".to_string())
+    )
+}
+
+/// All pages of commits are fetched, oldest first
+#[tokio::test]
+async fn test_fetch_pull_request_commits_paginates() {
+    let mut server = mockito::Server::new_async().await;
+    let first_page = server
+        .mock("POST", "/graphql")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"variables": {"pull_request_number": 7, "cursor": null}}"#.to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(commits_page(
+            &[commit_node(
+                "aaaaaaa1111",
+                "Add parser\\n\\nDetails",
+                Some("alice"),
+            )],
+            Some("page-2"),
+        ))
+        .create_async()
+        .await;
+    let second_page = server
+        .mock("POST", "/graphql")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"variables": {"cursor": "page-2"}}"#.to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(commits_page(
+            &[commit_node("bbbbbbb2222", "Fix tests", None)],
+            None,
+        ))
+        .create_async()
+        .await;
+
+    let commits = create_mock_github_client(&server)
+        .fetch_pull_request_commits(
+            RepositoryId::new("owner", "repo"),
+            PullRequestNumber::new(7),
+        )
+        .await
+        .expect("Failed to fetch commits");
+
+    assert_eq!(commits.len(), 2);
+    assert_eq!(commits[0].short_sha(), "aaaaaaa");
+    assert_eq!(commits[0].message_headline(), "Add parser");
+    assert_eq!(commits[0].author.as_deref(), Some("alice"));
+    assert_eq!(commits[1].author.as_deref(), Some("Git Author"));
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+}
+
+/// A pull request without commits yields an empty list
+#[tokio::test]
+async fn test_fetch_pull_request_commits_empty() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/graphql")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(commits_page(&[], None))
+        .create_async()
+        .await;
+
+    let commits = create_mock_github_client(&server)
+        .fetch_pull_request_commits(
+            RepositoryId::new("owner", "repo"),
+            PullRequestNumber::new(7),
+        )
+        .await
+        .expect("Failed to fetch commits");
+
+    assert!(commits.is_empty());
+    mock.assert_async().await;
+}
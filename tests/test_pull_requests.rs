@@ -5,6 +5,7 @@
 //!
 //! Note: All tests in this file require GitHub authentication as they use GraphQL API.
 //! Run with: cargo test --features integration-tests
+#![cfg(feature = "integration-tests")]
 
 use serial_test::serial;
 
@@ -12,7 +13,8 @@ mod test_util;
 use github_insight::services::MultiResourceFetcher;
 use github_insight::tools::functions;
 use github_insight::types::{
-    IssueOrPullrequest, PullRequestNumber, PullRequestUrl, RepositoryId, SearchQuery,
+    IssueOrPullrequest, PullRequestNumber, PullRequestReviewState, PullRequestUrl, RepositoryId,
+    SearchQuery,
 };
 use test_util::create_test_github_client;
 
@@ -263,7 +265,7 @@ async fn test_complex_search_queries_rust_repository() {
         "is:pr is:closed label:A-performance label:T-compiler created:>2024-01-01",
     );
     let result = client
-        .search_resources(rust_repo.clone(), query, Some(5), None)
+        .search_resources(rust_repo.clone(), query, Some(5), None, None)
         .await;
 
     match result {
@@ -284,7 +286,7 @@ async fn test_complex_search_queries_rust_repository() {
     // Test 2: Search for open PRs by specific author with exclusion
     let query = SearchQuery::new("is:pr is:open author:bors -label:rollup");
     let result = client
-        .search_resources(rust_repo.clone(), query, Some(3), None)
+        .search_resources(rust_repo.clone(), query, Some(3), None, None)
         .await;
 
     match result {
@@ -305,7 +307,7 @@ async fn test_complex_search_queries_rust_repository() {
     // Test 3: Search for PRs with date range and multiple labels
     let query = SearchQuery::new("is:pr updated:2024-01-01..2024-12-31 label:C-bug label:I-crash");
     let result = client
-        .search_resources(rust_repo, query, Some(3), None)
+        .search_resources(rust_repo, query, Some(3), None, None)
         .await;
 
     match result {
@@ -338,7 +340,7 @@ async fn test_complex_search_queries_react_repository() {
     // Test 1: Search for TypeScript-related PRs with specific file changes
     let query = SearchQuery::new("is:pr typescript OR \"type definitions\" OR \".d.ts\"");
     let result = client
-        .search_resources(react_repo.clone(), query, Some(5), None)
+        .search_resources(react_repo.clone(), query, Some(5), None, None)
         .await;
 
     match result {
@@ -361,7 +363,7 @@ async fn test_complex_search_queries_react_repository() {
         "is:pr \"performance\" AND (\"benchmark\" OR \"optimization\" OR \"faster\") is:merged",
     );
     let result = client
-        .search_resources(react_repo.clone(), query, Some(5), None)
+        .search_resources(react_repo.clone(), query, Some(5), None, None)
         .await;
 
     match result {
@@ -382,7 +384,7 @@ async fn test_complex_search_queries_react_repository() {
     // Test 3: Search for documentation updates with specific patterns
     let query = SearchQuery::new("is:pr (\"docs\" OR \"documentation\" OR \"README\") NOT \"api\"");
     let result = client
-        .search_resources(react_repo, query, Some(5), None)
+        .search_resources(react_repo, query, Some(5), None, None)
         .await;
 
     match result {
@@ -417,7 +419,7 @@ async fn test_advanced_search_operators_vscode() {
         "is:pr (\"extension\" OR \"plugin\") AND (\"marketplace\" OR \"gallery\") is:closed",
     );
     let result = client
-        .search_resources(vscode_repo.clone(), query, Some(3), None)
+        .search_resources(vscode_repo.clone(), query, Some(3), None, None)
         .await;
 
     match result {
@@ -439,7 +441,7 @@ async fn test_advanced_search_operators_vscode() {
     let query =
         SearchQuery::new("is:pr \"debug\" NOT \"console\" NOT \"log\" NOT \"output\" is:open");
     let result = client
-        .search_resources(vscode_repo.clone(), query, Some(3), None)
+        .search_resources(vscode_repo.clone(), query, Some(3), None, None)
         .await;
 
     match result {
@@ -460,7 +462,7 @@ async fn test_advanced_search_operators_vscode() {
     // Test 3: Complex date ranges with multiple conditions
     let query = SearchQuery::new("is:pr created:>2024-06-01 updated:<2024-12-01 comments:>5");
     let result = client
-        .search_resources(vscode_repo, query, Some(3), None)
+        .search_resources(vscode_repo, query, Some(3), None, None)
         .await;
 
     match result {
@@ -493,7 +495,7 @@ async fn test_user_filter_search_kubernetes() {
     // Test 1: Search by review requests and assignees
     let query = SearchQuery::new("is:pr review-requested:@me OR assignee:@me is:open");
     let result = client
-        .search_resources(k8s_repo.clone(), query, Some(3), None)
+        .search_resources(k8s_repo.clone(), query, Some(3), None, None)
         .await;
 
     match result {
@@ -516,7 +518,7 @@ async fn test_user_filter_search_kubernetes() {
         "is:pr \"@kubernetes/sig-\" AND (label:area/kubelet OR label:area/apiserver)",
     );
     let result = client
-        .search_resources(k8s_repo.clone(), query, Some(3), None)
+        .search_resources(k8s_repo.clone(), query, Some(3), None, None)
         .await;
 
     match result {
@@ -539,7 +541,7 @@ async fn test_user_filter_search_kubernetes() {
         "is:pr \"LGTM\" OR \"approved\" OR \"/approve\" is:closed merged:>2024-01-01",
     );
     let result = client
-        .search_resources(k8s_repo, query, Some(3), None)
+        .search_resources(k8s_repo, query, Some(3), None, None)
         .await;
 
     match result {
@@ -574,7 +576,7 @@ async fn test_label_milestone_search_nodejs() {
         "is:pr (label:\"confirmed-bug\" OR label:\"needs-ci\") AND label:\"fast-track\"",
     );
     let result = client
-        .search_resources(nodejs_repo.clone(), query, Some(3), None)
+        .search_resources(nodejs_repo.clone(), query, Some(3), None, None)
         .await;
 
     match result {
@@ -597,7 +599,7 @@ async fn test_label_milestone_search_nodejs() {
         "is:pr (\"fs:\" OR \"filesystem\" OR \"file system\") NOT \"test\" NOT \"doc\"",
     );
     let result = client
-        .search_resources(nodejs_repo.clone(), query, Some(3), None)
+        .search_resources(nodejs_repo.clone(), query, Some(3), None, None)
         .await;
 
     match result {
@@ -620,7 +622,7 @@ async fn test_label_milestone_search_nodejs() {
         "is:pr (\"security\" OR \"vulnerability\" OR \"CVE\") AND (\"performance\" OR \"benchmark\")",
     );
     let result = client
-        .search_resources(nodejs_repo, query, Some(3), None)
+        .search_resources(nodejs_repo, query, Some(3), None, None)
         .await;
 
     match result {
@@ -658,7 +660,7 @@ async fn test_search_edge_cases_and_error_handling() {
         created:>2020-01-01 updated:>2023-01-01",
     );
     let result = client
-        .search_resources(test_repo.clone(), complex_query, Some(2), None)
+        .search_resources(test_repo.clone(), complex_query, Some(2), None, None)
         .await;
 
     match result {
@@ -674,7 +676,7 @@ async fn test_search_edge_cases_and_error_handling() {
     // Test 2: Query with special characters and escaping
     let special_chars_query = SearchQuery::new("is:pr \"[FEATURE]\" OR \"[BUG]\" OR \"[DOCS]\"");
     let result = client
-        .search_resources(test_repo.clone(), special_chars_query, Some(2), None)
+        .search_resources(test_repo.clone(), special_chars_query, Some(2), None, None)
         .await;
 
     match result {
@@ -690,7 +692,7 @@ async fn test_search_edge_cases_and_error_handling() {
     // Test 3: Empty and minimal queries
     let minimal_query = SearchQuery::new("is:pr");
     let result = client
-        .search_resources(test_repo.clone(), minimal_query, Some(1), None)
+        .search_resources(test_repo.clone(), minimal_query, Some(1), None, None)
         .await;
 
     match result {
@@ -716,7 +718,7 @@ async fn test_search_edge_cases_and_error_handling() {
     // Test 4: Query with conflicting type filters (should return no results)
     let impossible_query = SearchQuery::new("is:pr AND is:issue"); // Explicitly impossible with AND
     let result = client
-        .search_resources(test_repo, impossible_query, Some(5), None)
+        .search_resources(test_repo, impossible_query, Some(5), None, None)
         .await;
 
     match result {
@@ -732,6 +734,62 @@ async fn test_search_edge_cases_and_error_handling() {
     }
 }
 
+/// Test fetching the reviews of a merged pull request
+///
+/// This test verifies that submitted reviews carry a reviewer and a submission timestamp,
+/// and that the approval count of the function layer matches the approved reviews.
+#[tokio::test]
+#[serial]
+#[cfg(feature = "integration-tests")]
+async fn test_fetch_pull_request_reviews() {
+    let client = create_test_github_client();
+
+    let repository_id = RepositoryId::new("tokio-rs".to_string(), "tokio".to_string());
+    let pr_number = PullRequestNumber::new(6000);
+
+    let result = client
+        .fetch_pull_request_reviews(repository_id.clone(), pr_number)
+        .await;
+    assert!(
+        result.is_ok(),
+        "Failed to fetch pull request reviews: {:?}",
+        result
+    );
+
+    let reviews = result.unwrap();
+    for review in &reviews {
+        if review.state != PullRequestReviewState::Pending {
+            assert!(
+                review.submitted_at.is_some(),
+                "Submitted reviews should have a submission timestamp"
+            );
+        }
+        println!(
+            "Review by {:?}: {} at {:?}",
+            review.reviewer, review.state, review.submitted_at
+        );
+    }
+
+    let pr_reviews = functions::pull_request::get_pull_request_reviews(
+        &client,
+        vec![PullRequestUrl(
+            "https://github.com/tokio-rs/tokio/pull/6000".to_string(),
+        )],
+    )
+    .await
+    .expect("Failed to get pull request reviews");
+    assert_eq!(pr_reviews.len(), 1);
+    assert_eq!(pr_reviews[0].pull_request_id.number, 6000);
+    assert_eq!(pr_reviews[0].reviews.len(), reviews.len());
+    assert_eq!(
+        pr_reviews[0].approval_count(),
+        reviews
+            .iter()
+            .filter(|review| review.state == PullRequestReviewState::Approved)
+            .count()
+    );
+}
+
 /// Test fetching pull request diff using REST API
 ///
 /// This test verifies that the client can successfully fetch the complete unified diff
@@ -1115,6 +1173,8 @@ async fn test_get_pull_request_diff_contents_full() {
         test_file_path.clone(),
         None, // no skip
         None, // no limit
+        None, // default max_lines
+        None, // no hunk selection
     )
     .await;
 
@@ -1182,6 +1242,8 @@ async fn test_get_pull_request_diff_contents_with_skip() {
         test_file_path.clone(),
         None,
         None,
+        None,
+        None,
     )
     .await
     .expect("Failed to fetch full diff");
@@ -1200,6 +1262,8 @@ async fn test_get_pull_request_diff_contents_with_skip() {
             test_file_path.clone(),
             Some(skip_count as u32),
             None,
+            None,
+            None,
         )
         .await;
 
@@ -1276,6 +1340,8 @@ async fn test_get_pull_request_diff_contents_with_limit() {
         test_file_path.clone(),
         None,
         None,
+        None,
+        None,
     )
     .await
     .expect("Failed to fetch full diff");
@@ -1294,6 +1360,8 @@ async fn test_get_pull_request_diff_contents_with_limit() {
             test_file_path.clone(),
             None,
             Some(limit_count),
+            None,
+            None,
         )
         .await;
 
@@ -1367,6 +1435,8 @@ async fn test_get_pull_request_diff_contents_with_skip_and_limit() {
         test_file_path.clone(),
         None,
         None,
+        None,
+        None,
     )
     .await
     .expect("Failed to fetch full diff");
@@ -1387,6 +1457,8 @@ async fn test_get_pull_request_diff_contents_with_skip_and_limit() {
             test_file_path.clone(),
             Some(skip_count as u32),
             Some(limit_count),
+            None,
+            None,
         )
         .await;
 
@@ -1480,6 +1552,8 @@ async fn test_get_pull_request_diff_contents_skip_exceeds_total() {
         test_file_path.clone(),
         None,
         None,
+        None,
+        None,
     )
     .await
     .expect("Failed to fetch full diff");
@@ -1496,6 +1570,8 @@ async fn test_get_pull_request_diff_contents_skip_exceeds_total() {
         test_file_path,
         Some(excessive_skip),
         None,
+        None,
+        None,
     )
     .await;
 
@@ -1552,6 +1628,8 @@ async fn test_get_pull_request_diff_contents_skip_zero() {
         test_file_path.clone(),
         None,
         None,
+        None,
+        None,
     )
     .await
     .expect("Failed to fetch diff without skip");
@@ -1563,6 +1641,8 @@ async fn test_get_pull_request_diff_contents_skip_zero() {
         test_file_path,
         Some(0),
         None,
+        None,
+        None,
     )
     .await
     .expect("Failed to fetch diff with skip=0");
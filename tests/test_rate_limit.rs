@@ -0,0 +1,41 @@
+//! Tests for GraphQL rate limit introspection of GitHubClient
+
+mod test_util;
+use test_util::create_mock_github_client;
+
+#[tokio::test]
+async fn test_fetch_rate_limit() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/graphql")
+        .match_body(mockito::Matcher::Regex("rateLimit".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{
+                "data": {
+                    "rateLimit": {
+                        "limit": 5000,
+                        "cost": 1,
+                        "remaining": 0,
+                        "used": 5000,
+                        "resetAt": "2025-01-02T03:04:05Z"
+                    }
+                }
+            }"#,
+        )
+        .create_async()
+        .await;
+
+    let status = create_mock_github_client(&server)
+        .fetch_rate_limit()
+        .await
+        .expect("Failed to fetch rate limit");
+
+    assert_eq!(status.limit, 5000);
+    assert_eq!(status.remaining, 0);
+    assert_eq!(status.used, 5000);
+    assert_eq!(status.reset_at.to_rfc3339(), "2025-01-02T03:04:05+00:00");
+    assert!(status.is_exhausted());
+    mock.assert_async().await;
+}
@@ -6,7 +6,9 @@
 use serial_test::serial;
 
 mod test_util;
-use github_insight::tools::functions::repository::get_multiple_repository_details;
+use github_insight::tools::functions::repository::{
+    get_multiple_repository_details, get_pinned_issues,
+};
 use github_insight::types::RepositoryUrl;
 use test_util::create_test_github_client;
 
@@ -246,3 +248,42 @@ async fn test_get_multiple_repository_details_concurrent() {
         );
     }
 }
+
+/// Test fetching pinned issues of a repository
+///
+/// This test verifies that pinned issues belong to the requested repository and that
+/// repositories without pinned issues yield an empty result rather than an error.
+#[tokio::test]
+#[serial]
+async fn test_get_pinned_issues() {
+    // Initialize GitHub client with token (if available) and reasonable timeout
+    let client = create_test_github_client();
+
+    let repository_urls = vec![RepositoryUrl::new(
+        "https://github.com/tacogips/gitcodes-mcp-test-1".to_string(),
+    )];
+
+    let result = get_pinned_issues(&client, repository_urls).await;
+
+    assert!(
+        result.is_ok(),
+        "Failed to fetch pinned issues: {:?}",
+        result
+    );
+
+    let issues_by_repo = result.unwrap();
+
+    for (repo_id, issues) in &issues_by_repo {
+        assert!(
+            issues.len() <= 3,
+            "GitHub allows at most 3 pinned issues per repository"
+        );
+        for issue in issues {
+            assert_eq!(
+                &issue.issue_id.git_repository, repo_id,
+                "Pinned issue should belong to the requested repository"
+            );
+        }
+        println!("Found {} pinned issues in {}", issues.len(), repo_id);
+    }
+}
@@ -1,7 +1,8 @@
 use github_insight::formatter::repository_branch_group::*;
 use github_insight::types::repository::{Owner, RepositoryName};
 use github_insight::types::{
-    Branch, GroupName, RepositoryBranchGroup, RepositoryBranchPair, RepositoryId,
+    Branch, GroupName, ProfileBranchGroups, ProfileName, RepositoryBranchGroup,
+    RepositoryBranchPair, RepositoryId,
 };
 
 fn create_test_pair() -> RepositoryBranchPair {
@@ -271,3 +272,44 @@ fn test_repository_branch_group_list_with_descriptions_markdown_no_descriptions(
     // Should not contain description text when no descriptions are present
     assert!(!result.0.contains("Test group description"));
 }
+
+#[test]
+fn test_repository_branch_groups_across_profiles_markdown() {
+    let profile_groups = vec![
+        ProfileBranchGroups {
+            profile_name: ProfileName::from("archive"),
+            groups: vec![],
+        },
+        ProfileBranchGroups {
+            profile_name: ProfileName::from("work"),
+            groups: vec![create_test_group()],
+        },
+    ];
+
+    let result = repository_branch_groups_across_profiles_markdown(&profile_groups, None, false);
+    assert!(
+        result
+            .0
+            .contains("| Profile | Group | Branches | Created |")
+    );
+    assert!(result.0.contains("| archive | - | 0 | - |"));
+    assert!(result.0.contains("| work | test-group | 2 |"));
+
+    let result = repository_branch_groups_across_profiles_markdown(&profile_groups, None, true);
+    assert!(!result.0.contains("archive"));
+    assert!(result.0.contains("| work | test-group | 2 |"));
+}
+
+#[test]
+fn test_repository_branch_groups_across_profiles_markdown_empty() {
+    let profile_groups = vec![ProfileBranchGroups {
+        profile_name: ProfileName::from("default"),
+        groups: vec![],
+    }];
+
+    let result = repository_branch_groups_across_profiles_markdown(&profile_groups, None, true);
+    assert_eq!(
+        result.0,
+        "No repository branch groups found in any profile."
+    );
+}
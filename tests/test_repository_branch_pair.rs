@@ -54,24 +54,25 @@ fn test_repository_branch_pair_try_from_str_with_feature_branch() {
 }
 
 #[test]
-fn test_repository_branch_pair_try_from_str_invalid_format_no_at() {
+fn test_repository_branch_pair_try_from_str_no_at_uses_default_branch() {
     let specifier = "https://github.com/rust-lang/rust";
-    let result = RepositoryBranchPair::try_from_str(specifier);
+    let pair = RepositoryBranchPair::try_from_str(specifier).unwrap();
 
-    assert!(result.is_err());
-    let error_msg = result.unwrap_err().to_string();
-    assert!(error_msg.contains("Invalid repository branch specifier format"));
-    assert!(error_msg.contains("Expected format: 'repo_url@branch'"));
+    assert_eq!(pair.repository_id.owner.as_str(), "rust-lang");
+    assert_eq!(pair.repository_id.repository_name.as_str(), "rust");
+    assert!(pair.branch.is_default_marker());
+    assert_eq!(pair.branch, Branch::default_marker());
 }
 
 #[test]
 fn test_repository_branch_pair_try_from_str_invalid_format_multiple_at() {
+    // Split on the last '@', leaving an invalid repository URL
     let specifier = "https://github.com/rust-lang/rust@main@dev";
     let result = RepositoryBranchPair::try_from_str(specifier);
 
     assert!(result.is_err());
     let error_msg = result.unwrap_err().to_string();
-    assert!(error_msg.contains("Invalid repository branch specifier format"));
+    assert!(error_msg.contains("Invalid repository URL 'https://github.com/rust-lang/rust@main'"));
 }
 
 #[test]
@@ -85,13 +86,31 @@ fn test_repository_branch_pair_try_from_str_empty_repo_url() {
 }
 
 #[test]
-fn test_repository_branch_pair_try_from_str_empty_branch() {
+fn test_repository_branch_pair_try_from_str_empty_branch_uses_default_branch() {
     let specifier = "https://github.com/rust-lang/rust@";
-    let result = RepositoryBranchPair::try_from_str(specifier);
+    let pair = RepositoryBranchPair::try_from_str(specifier).unwrap();
 
-    assert!(result.is_err());
-    let error_msg = result.unwrap_err().to_string();
-    assert!(error_msg.contains("Branch name cannot be empty"));
+    assert_eq!(pair.repository_id.repository_name.as_str(), "rust");
+    assert!(pair.branch.is_default_marker());
+    assert_eq!(pair.branch.display_name(), "(default branch)");
+
+    // The marker round-trips through the specifier syntax
+    assert_eq!(pair.to_string(), specifier);
+    assert_eq!(
+        RepositoryBranchPair::try_from_str(&pair.to_string()).unwrap(),
+        pair
+    );
+}
+
+#[test]
+fn test_repository_branch_pair_parse_specifier_ssh_url_without_branch() {
+    let pair = RepositoryBranchPair::parse_specifier("git@github.com:rust-lang/rust.git").unwrap();
+    assert_eq!(pair.repository_id.owner.as_str(), "rust-lang");
+    assert_eq!(pair.repository_id.repository_name.as_str(), "rust");
+    assert!(pair.branch.is_default_marker());
+
+    let pair = RepositoryBranchPair::parse_specifier("rust-lang/rust@ ").unwrap();
+    assert!(pair.branch.is_default_marker());
 }
 
 #[test]
@@ -114,6 +133,50 @@ fn test_repository_branch_pair_try_from_str_whitespace_around_at() {
     assert_eq!(pair.branch.as_str(), "main");
 }
 
+#[test]
+fn test_repository_branch_pair_parse_specifier_branch_with_slashes() {
+    let pair = RepositoryBranchPair::parse_specifier("https://github.com/rust-lang/rust@feature/x")
+        .unwrap();
+    assert_eq!(pair.repository_id.repository_name.as_str(), "rust");
+    assert_eq!(pair.branch.as_str(), "feature/x");
+
+    let pair = RepositoryBranchPair::parse_specifier(
+        "https://github.com/rust-lang/rust@release/1.80/hotfix",
+    )
+    .unwrap();
+    assert_eq!(pair.branch.as_str(), "release/1.80/hotfix");
+}
+
+#[test]
+fn test_repository_branch_pair_parse_specifier_ssh_url() {
+    let pair = RepositoryBranchPair::parse_specifier("git@github.com:rust-lang/rust.git@feature/x")
+        .unwrap();
+    assert_eq!(pair.repository_id.owner.as_str(), "rust-lang");
+    assert_eq!(pair.repository_id.repository_name.as_str(), "rust");
+    assert_eq!(pair.branch.as_str(), "feature/x");
+}
+
+#[test]
+fn test_repository_branch_pair_parse_specifier_malformed_inputs() {
+    let error = |specifier: &str| {
+        RepositoryBranchPair::parse_specifier(specifier)
+            .unwrap_err()
+            .to_string()
+    };
+
+    assert!(error("").contains("Invalid repository branch specifier format"));
+    assert!(error("rust-lang").contains("Expected format: 'repo_url@branch'"));
+    assert!(error("@feature/x").contains("Repository URL cannot be empty"));
+    assert!(
+        error("https://github.com/rust-lang/rust@feature x")
+            .contains("Branch name 'feature x' cannot contain whitespace")
+    );
+    assert!(
+        error("https://gitlab.com/rust-lang/rust@main")
+            .contains("Invalid repository URL 'https://gitlab.com/rust-lang/rust'")
+    );
+}
+
 #[test]
 fn test_repository_branch_pair_try_from_specifiers_single() {
     let specifiers = vec!["https://github.com/rust-lang/rust@main".to_string()];
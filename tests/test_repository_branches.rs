@@ -0,0 +1,85 @@
+//! Tests for listing repository branches via the REST API
+
+mod test_util;
+use github_insight::types::{BranchPattern, RepositoryId};
+use test_util::create_mock_github_client;
+
+const BRANCHES_PATH: &str = "/repos/owner/repo/branches";
+
+fn branches_body(names: &[String]) -> String {
+    let entries: Vec<String> = names
+        .iter()
+        .map(|name| format!(r#"{{"name": "{}", "protected": false}}"#, name))
+        .collect();
+    format!("[{}]", entries.join(","))
+}
+
+/// A full first page makes the client request the next one
+#[tokio::test]
+async fn test_fetch_repository_branch_names_paginates() {
+    let mut server = mockito::Server::new_async().await;
+    let first_page_names: Vec<String> = (0..100).map(|i| format!("feature-{}", i)).collect();
+    let first_page = server
+        .mock("GET", BRANCHES_PATH)
+        .match_query(mockito::Matcher::UrlEncoded(
+            "page".to_string(),
+            "1".to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(branches_body(&first_page_names))
+        .create_async()
+        .await;
+    let second_page = server
+        .mock("GET", BRANCHES_PATH)
+        .match_query(mockito::Matcher::UrlEncoded(
+            "page".to_string(),
+            "2".to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(branches_body(&[
+            "main".to_string(),
+            "release-1".to_string(),
+        ]))
+        .create_async()
+        .await;
+
+    let branch_names = create_mock_github_client(&server)
+        .fetch_repository_branch_names(&RepositoryId::new("owner", "repo"))
+        .await
+        .expect("Failed to fetch branches");
+
+    assert_eq!(branch_names.len(), 102);
+    assert_eq!(branch_names[100], "main");
+
+    let pattern = BranchPattern::parse("release-*").unwrap();
+    let matched: Vec<&String> = branch_names
+        .iter()
+        .filter(|name| pattern.matches(name))
+        .collect();
+    assert_eq!(matched, vec!["release-1"]);
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_fetch_repository_branch_names_not_found() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", BRANCHES_PATH)
+        .match_query(mockito::Matcher::Any)
+        .with_status(404)
+        .with_body(r#"{"message": "Not Found"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let result = create_mock_github_client(&server)
+        .fetch_repository_branch_names(&RepositoryId::new("owner", "repo"))
+        .await;
+
+    assert!(result.is_err());
+    mock.assert_async().await;
+}
@@ -0,0 +1,99 @@
+//! Tests for the opt-in repository metadata cache of GitHubClient
+
+use tokio::time::Duration;
+
+mod test_util;
+use github_insight::types::RepositoryId;
+use test_util::create_mock_github_client;
+
+const REPOSITORY_RESPONSE: &str = r#"{
+    "data": {
+        "repository": {
+            "name": "repo",
+            "description": "Cached repository",
+            "primaryLanguage": { "name": "Rust" },
+            "createdAt": "2024-01-01T00:00:00Z",
+            "updatedAt": "2024-06-01T00:00:00Z",
+            "defaultBranchRef": { "name": "main" },
+            "milestones": { "nodes": [] },
+            "labels": { "nodes": [] },
+            "owner": { "login": "owner" },
+            "pinnedIssues": { "nodes": [] },
+            "mentionableUsers": { "nodes": [] },
+            "releases": { "nodes": [] }
+        }
+    }
+}"#;
+
+async fn mock_repository_endpoint(server: &mut mockito::ServerGuard, hits: usize) -> mockito::Mock {
+    server
+        .mock("POST", "/graphql")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(REPOSITORY_RESPONSE)
+        .expect(hits)
+        .create_async()
+        .await
+}
+
+/// A second fetch within the TTL is served from the cache, including from a clone
+#[tokio::test]
+async fn test_fetch_repository_within_ttl_uses_cache() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = mock_repository_endpoint(&mut server, 1).await;
+
+    let client = create_mock_github_client(&server).with_repository_cache(Duration::from_secs(60));
+    let repository_id = RepositoryId::new("owner", "repo");
+
+    let first = client
+        .fetch_repository(repository_id.clone())
+        .await
+        .expect("Failed to fetch repository");
+    let second = client
+        .clone()
+        .fetch_repository(repository_id.clone())
+        .await
+        .expect("Failed to fetch cached repository");
+
+    assert_eq!(first.git_repository_id, repository_id);
+    assert_eq!(second.description.as_deref(), Some("Cached repository"));
+    mock.assert_async().await;
+}
+
+/// Without the cache every fetch issues a GraphQL request
+#[tokio::test]
+async fn test_fetch_repository_without_cache_requests_each_time() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = mock_repository_endpoint(&mut server, 2).await;
+
+    let client = create_mock_github_client(&server);
+    let repository_id = RepositoryId::new("owner", "repo");
+
+    for _ in 0..2 {
+        client
+            .fetch_repository(repository_id.clone())
+            .await
+            .expect("Failed to fetch repository");
+    }
+
+    mock.assert_async().await;
+}
+
+/// Entries older than the TTL are fetched again
+#[tokio::test]
+async fn test_fetch_repository_after_ttl_requests_again() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = mock_repository_endpoint(&mut server, 2).await;
+
+    let client = create_mock_github_client(&server).with_repository_cache(Duration::ZERO);
+    let repository_id = RepositoryId::new("owner", "repo");
+
+    for _ in 0..2 {
+        client
+            .fetch_repository(repository_id.clone())
+            .await
+            .expect("Failed to fetch repository");
+    }
+
+    mock.assert_async().await;
+}
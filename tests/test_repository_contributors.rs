@@ -0,0 +1,95 @@
+//! Tests for fetching repository contributors via the REST API
+
+mod test_util;
+use github_insight::types::RepositoryId;
+use test_util::create_mock_github_client;
+
+const CONTRIBUTORS_PATH: &str = "/repos/owner/repo/contributors";
+
+const CONTRIBUTORS_RESPONSE: &str = r#"[
+    {"login": "bob", "contributions": 7, "avatar_url": "https://avatars.githubusercontent.com/u/2"},
+    {"login": "alice", "contributions": 42, "avatar_url": "https://avatars.githubusercontent.com/u/1"},
+    {"login": "carol", "contributions": 3, "avatar_url": "https://avatars.githubusercontent.com/u/3"}
+]"#;
+
+#[tokio::test]
+async fn test_fetch_repository_contributors_sorted_and_limited() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", CONTRIBUTORS_PATH)
+        .match_query(mockito::Matcher::UrlEncoded(
+            "per_page".to_string(),
+            "2".to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(CONTRIBUTORS_RESPONSE)
+        .create_async()
+        .await;
+
+    let contributors = create_mock_github_client(&server)
+        .fetch_repository_contributors(RepositoryId::new("owner", "repo"), 2)
+        .await
+        .expect("Failed to fetch contributors");
+
+    let logins: Vec<&str> = contributors.iter().map(|c| c.login.as_str()).collect();
+    assert_eq!(logins, vec!["alice", "bob"]);
+    assert_eq!(contributors[0].contributions, 42);
+    assert_eq!(
+        contributors[0].avatar_url,
+        "https://avatars.githubusercontent.com/u/1"
+    );
+    mock.assert_async().await;
+}
+
+/// A 202 response while GitHub computes statistics is retried until the list is ready
+#[tokio::test]
+async fn test_fetch_repository_contributors_retries_while_computing() {
+    let mut server = mockito::Server::new_async().await;
+    let computing = server
+        .mock("GET", CONTRIBUTORS_PATH)
+        .match_query(mockito::Matcher::Any)
+        .with_status(202)
+        .with_body("{}")
+        .expect(1)
+        .create_async()
+        .await;
+    let ready = server
+        .mock("GET", CONTRIBUTORS_PATH)
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(CONTRIBUTORS_RESPONSE)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let contributors = create_mock_github_client(&server)
+        .fetch_repository_contributors(RepositoryId::new("owner", "repo"), 10)
+        .await
+        .expect("Failed to fetch contributors");
+
+    assert_eq!(contributors.len(), 3);
+    computing.assert_async().await;
+    ready.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_fetch_repository_contributors_not_found() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", CONTRIBUTORS_PATH)
+        .match_query(mockito::Matcher::Any)
+        .with_status(404)
+        .with_body(r#"{"message": "Not Found"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let result = create_mock_github_client(&server)
+        .fetch_repository_contributors(RepositoryId::new("owner", "repo"), 10)
+        .await;
+
+    assert!(result.is_err());
+    mock.assert_async().await;
+}
@@ -0,0 +1,62 @@
+//! Tests for fetching the language breakdown of a repository
+
+mod test_util;
+use github_insight::types::RepositoryId;
+use mockito::Matcher;
+use test_util::create_mock_github_client;
+
+#[tokio::test]
+async fn test_fetch_repository_languages_sorted_by_size() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/graphql")
+        .match_body(Matcher::Regex("languages".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{
+                "data": {
+                    "repository": {
+                        "languages": {
+                            "edges": [
+                                { "size": 300, "node": { "name": "Shell" } },
+                                { "size": 9000, "node": { "name": "Rust" } }
+                            ]
+                        }
+                    }
+                }
+            }"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let languages = create_mock_github_client(&server)
+        .fetch_repository_languages(RepositoryId::new("owner", "repo"))
+        .await
+        .expect("Languages should be fetched");
+
+    assert_eq!(
+        languages,
+        vec![("Rust".to_string(), 9000), ("Shell".to_string(), 300)]
+    );
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_fetch_repository_languages_missing_repository() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("POST", "/graphql")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{ "data": { "repository": null } }"#)
+        .create_async()
+        .await;
+
+    let result = create_mock_github_client(&server)
+        .fetch_repository_languages(RepositoryId::new("owner", "missing"))
+        .await;
+
+    assert!(result.is_err());
+}
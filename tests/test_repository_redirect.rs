@@ -0,0 +1,85 @@
+//! Tests for detecting renamed or transferred repositories
+
+mod test_util;
+use github_insight::github::client::GitHubClient;
+use github_insight::types::RepositoryId;
+use test_util::create_mock_github_client;
+
+/// Repository response as GitHub returns it for the repository `owner/name`
+fn repository_response(owner: &str, name: &str) -> String {
+    format!(
+        r#"{{
+    "data": {{
+        "repository": {{
+            "name": "{}",
+            "description": "Moved repository",
+            "primaryLanguage": {{ "name": "Rust" }},
+            "createdAt": "2024-01-01T00:00:00Z",
+            "updatedAt": "2024-06-01T00:00:00Z",
+            "defaultBranchRef": {{ "name": "main" }},
+            "milestones": {{ "nodes": [] }},
+            "labels": {{ "nodes": [] }},
+            "owner": {{ "login": "{}" }},
+            "pinnedIssues": {{ "nodes": [] }},
+            "mentionableUsers": {{ "nodes": [] }},
+            "releases": {{ "nodes": [] }}
+        }}
+    }}
+}}"#,
+        name, owner
+    )
+}
+
+/// Mocks the repository endpoint to answer for `owner/name` and returns a client for it
+async fn redirected_client(
+    server: &mut mockito::ServerGuard,
+    owner: &str,
+    name: &str,
+) -> GitHubClient {
+    server
+        .mock("POST", "/graphql")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(repository_response(owner, name))
+        .create_async()
+        .await;
+    create_mock_github_client(server)
+}
+
+/// A response for another owner and name is reported as a redirect to that repository
+#[tokio::test]
+async fn test_fetch_repository_detects_rename() {
+    let mut server = mockito::Server::new_async().await;
+    let client = redirected_client(&mut server, "new-owner", "new-repo").await;
+
+    let result = client
+        .fetch_repository_following_redirects(RepositoryId::new("old-owner", "old-repo"))
+        .await
+        .expect("Failed to fetch repository");
+
+    assert!(result.was_redirected);
+    assert_eq!(
+        result.requested_id,
+        RepositoryId::new("old-owner", "old-repo")
+    );
+    assert_eq!(
+        result.canonical_id,
+        RepositoryId::new("new-owner", "new-repo")
+    );
+    assert_eq!(result.repository.git_repository_id, result.canonical_id);
+}
+
+/// Names that differ only in case are the same repository
+#[tokio::test]
+async fn test_fetch_repository_ignores_case_differences() {
+    let mut server = mockito::Server::new_async().await;
+    let client = redirected_client(&mut server, "Owner", "Repo").await;
+
+    let result = client
+        .fetch_repository_following_redirects(RepositoryId::new("owner", "repo"))
+        .await
+        .expect("Failed to fetch repository");
+
+    assert!(!result.was_redirected);
+    assert_eq!(result.canonical_id, RepositoryId::new("Owner", "Repo"));
+}
@@ -0,0 +1,129 @@
+//! Tests for fetching the releases of a repository with their assets
+
+mod test_util;
+use github_insight::types::RepositoryId;
+use test_util::create_mock_github_client;
+
+fn release_node(tag: &str, assets: &[(&str, u64, u64)]) -> String {
+    let assets: Vec<String> = assets
+        .iter()
+        .map(|(name, size, downloads)| {
+            format!(
+                r#"{{
+                    "name": "{name}",
+                    "size": {size},
+                    "downloadCount": {downloads},
+                    "downloadUrl": "https://github.com/owner/repo/releases/download/{tag}/{name}"
+                }}"#
+            )
+        })
+        .collect();
+    format!(
+        r#"{{
+            "name": "Release {tag}",
+            "tagName": "{tag}",
+            "description": null,
+            "createdAt": "2025-01-02T03:04:05Z",
+            "publishedAt": "2025-01-02T03:04:05Z",
+            "isPrerelease": false,
+            "isDraft": false,
+            "author": {{ "login": "alice", "name": null }},
+            "url": "https://github.com/owner/repo/releases/tag/{tag}",
+            "releaseAssets": {{ "nodes": [{}] }}
+        }}"#,
+        assets.join(",")
+    )
+}
+
+fn releases_page(nodes: &[String], end_cursor: Option<&str>) -> String {
+    format!(
+        r#"{{
+            "data": {{
+                "repository": {{
+                    "releases": {{
+                        "nodes": [{}],
+                        "pageInfo": {{ "hasNextPage": {}, "endCursor": {} }}
+                    }}
+                }}
+            }}
+        }}"#,
+        nodes.join(","),
+        end_cursor.is_some(),
+        end_cursor
+            .map(|cursor| format!("\"{}\"", cursor))
+            .unwrap_or_else(|| "null".to_string())
+    )
+}
+
+/// Pages are fetched until the limit is reached, asking only for the remaining count
+#[tokio::test]
+async fn test_fetch_repository_releases_paginates_up_to_limit() {
+    let mut server = mockito::Server::new_async().await;
+    let first_page = server
+        .mock("POST", "/graphql")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"variables": {"first": 3, "cursor": null}}"#.to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(releases_page(
+            &[
+                release_node("v2.0.0", &[("app-linux.tar.gz", 2048, 12)]),
+                release_node("v1.1.0", &[]),
+            ],
+            Some("page-2"),
+        ))
+        .create_async()
+        .await;
+    let second_page = server
+        .mock("POST", "/graphql")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"variables": {"first": 1, "cursor": "page-2"}}"#.to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(releases_page(
+            &[release_node("v1.0.0", &[("app.zip", 100, 3)])],
+            Some("page-3"),
+        ))
+        .create_async()
+        .await;
+
+    let releases = create_mock_github_client(&server)
+        .fetch_repository_releases(RepositoryId::new("owner", "repo"), 3)
+        .await
+        .expect("Failed to fetch releases");
+
+    let tags: Vec<&str> = releases
+        .iter()
+        .map(|release| release.release.tag_name.0.as_str())
+        .collect();
+    assert_eq!(tags, vec!["v2.0.0", "v1.1.0", "v1.0.0"]);
+    assert_eq!(releases[0].assets.len(), 1);
+    assert_eq!(releases[0].assets[0].name, "app-linux.tar.gz");
+    assert_eq!(releases[0].assets[0].size, 2048);
+    assert_eq!(releases[0].assets[0].download_count, 12);
+    assert!(releases[1].assets.is_empty());
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+}
+
+/// An unknown repository is reported as an error
+#[tokio::test]
+async fn test_fetch_repository_releases_repository_not_found() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/graphql")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"repository": null}}"#)
+        .create_async()
+        .await;
+
+    let result = create_mock_github_client(&server)
+        .fetch_repository_releases(RepositoryId::new("owner", "missing"), 10)
+        .await;
+
+    assert!(result.is_err());
+    mock.assert_async().await;
+}
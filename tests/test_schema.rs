@@ -0,0 +1,45 @@
+//! Tests for the JSON Schemas of the CLI's JSON output types
+
+use schemars::schema::RootSchema;
+use schemars::schema_for;
+
+use github_insight::types::{GithubRepository, IssueOrPullrequest, Project};
+
+fn property_names(schema: &RootSchema, definition: &str) -> Vec<String> {
+    let definition = schema
+        .definitions
+        .get(definition)
+        .unwrap_or_else(|| panic!("Missing definition '{}'", definition))
+        .clone()
+        .into_object();
+    definition
+        .object
+        .expect("Definition should describe an object")
+        .properties
+        .keys()
+        .cloned()
+        .collect()
+}
+
+#[test]
+fn test_search_result_schema_describes_issues_and_pull_requests() {
+    let schema = schema_for!(Vec<IssueOrPullrequest>);
+
+    assert!(schema.definitions.contains_key("IssueOrPullrequest"));
+    let issue_properties = property_names(&schema, "Issue");
+    assert!(issue_properties.contains(&"title".to_string()));
+    assert!(issue_properties.contains(&"comments".to_string()));
+    let pull_request_properties = property_names(&schema, "PullRequest");
+    assert!(pull_request_properties.contains(&"head_branch".to_string()));
+}
+
+#[test]
+fn test_repository_and_project_schemas() {
+    let repository_schema = schema_for!(Vec<GithubRepository>);
+    let repository_properties = property_names(&repository_schema, "GithubRepository");
+    assert!(repository_properties.contains(&"git_repository_id".to_string()));
+    assert!(repository_properties.contains(&"milestones".to_string()));
+
+    let project_schema = schema_for!(Vec<Project>);
+    assert!(project_schema.definitions.contains_key("Project"));
+}
@@ -5,11 +5,14 @@
 //!
 //! Note: All tests in this file require GitHub authentication as they use GraphQL API.
 //! Run with: cargo test --features integration-tests
+#![cfg(feature = "integration-tests")]
 
 use serial_test::serial;
 
 mod test_util;
-use github_insight::types::{IssueOrPullrequest, RepositoryId, SearchCursor, SearchQuery};
+use github_insight::types::{
+    IssueOrPullrequest, RepositoryId, SearchCursor, SearchQuery, SearchSort,
+};
 use test_util::create_test_github_client;
 
 /// Test basic search functionality with general query
@@ -37,6 +40,7 @@ async fn test_search_resources_basic() {
             query,
             Some(10), // Limit to 10 results
             None,     // No cursor
+            None,     // Default ordering
         )
         .await;
 
@@ -114,6 +118,7 @@ async fn test_search_resources_with_specific_query() {
             query,
             Some(5), // Limit to 5 results
             None,    // No cursor
+            None,    // Default ordering
         )
         .await;
 
@@ -164,6 +169,7 @@ async fn test_search_resources_empty_query() {
             query,
             Some(3), // Limit to 3 results
             None,    // No cursor
+            None,    // Default ordering
         )
         .await;
 
@@ -241,6 +247,7 @@ async fn test_search_resources_with_pagination() {
             query.clone(),
             Some(1), // Very small page size
             None,    // No cursor for first page
+            None,    // Default ordering
         )
         .await;
 
@@ -289,6 +296,7 @@ async fn test_search_resources_with_pagination() {
             query,
             Some(1),
             Some(dummy_cursor), // Dummy cursor
+            None,               // Default ordering
         )
         .await;
 
@@ -324,7 +332,7 @@ async fn test_search_resources_nonexistent_repo() {
 
     // Fetch the search results
     let result = client
-        .search_resources(repository_id.clone(), query, Some(10), None)
+        .search_resources(repository_id.clone(), query, Some(10), None, None)
         .await;
 
     // The search should either succeed with empty results or fail gracefully
@@ -371,6 +379,7 @@ async fn test_search_resources_pull_requests_only() {
             query,
             Some(10), // Limit to 10 results
             None,     // No cursor
+            None,     // Default ordering
         )
         .await;
 
@@ -438,6 +447,7 @@ async fn test_search_resources_issues_only() {
             query,
             Some(10), // Limit to 10 results
             None,     // No cursor
+            None,     // Default ordering
         )
         .await;
 
@@ -502,6 +512,7 @@ async fn test_search_resources_both_types() {
             query,
             Some(20), // Larger limit to get mix of both types
             None,     // No cursor
+            None,     // Default ordering
         )
         .await;
 
@@ -581,6 +592,7 @@ async fn test_search_resources_next_pager_some() {
             query,
             Some(1), // Very small limit to ensure pagination
             None,    // No cursor
+            None,    // Default ordering
         )
         .await;
 
@@ -650,6 +662,7 @@ async fn test_search_resources_pagination_next_page() {
             query.clone(),
             Some(1), // Very small limit to ensure pagination
             None,    // No cursor for first page
+            None,    // Default ordering
         )
         .await;
 
@@ -700,6 +713,7 @@ async fn test_search_resources_pagination_next_page() {
             query.clone(),
             Some(1),           // Same small limit
             Some(next_cursor), // Use cursor from first page
+            None,              // Default ordering
         )
         .await;
 
@@ -827,6 +841,7 @@ async fn test_search_resources_repo_override() {
             query,
             Some(5), // Limit to 5 results
             None,    // No cursor
+            None,    // Default ordering
         )
         .await;
 
@@ -897,8 +912,56 @@ async fn test_search_resources_repo_override() {
     }
 
     println!(
-        "Successfully verified repo override: found {} results from {} instead of repo specified in query",
+        "Successfully verified repo override: found {} results from {}/{} instead of repo specified in query",
         search_results.len(),
-        format!("{}/{}", repository_id.owner, repository_id.repository_name)
+        repository_id.owner,
+        repository_id.repository_name
+    );
+}
+
+/// Test search results ordered by creation date
+///
+/// This test verifies that passing a sort order to search_resources returns
+/// issues ordered by creation date, oldest first.
+#[tokio::test]
+#[serial]
+#[cfg(feature = "integration-tests")]
+async fn test_search_resources_sorted_by_created_asc() {
+    let client = create_test_github_client();
+
+    let repository_id =
+        RepositoryId::new("tacogips".to_string(), "gitcodes-mcp-test-1".to_string());
+    let query = SearchQuery::new("is:issue".to_string());
+
+    let result = client
+        .search_resources(
+            repository_id,
+            query,
+            Some(10),
+            None,
+            Some(SearchSort::CreatedAsc),
+        )
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "Sorted search should be successful: {:?}",
+        result.err()
+    );
+
+    let created_dates: Vec<_> = result
+        .unwrap()
+        .issue_or_pull_requests
+        .iter()
+        .filter_map(|result| match result {
+            IssueOrPullrequest::Issue(issue) => Some(issue.created_at),
+            IssueOrPullrequest::PullRequest(_) => None,
+        })
+        .collect();
+
+    assert!(
+        created_dates.windows(2).all(|pair| pair[0] <= pair[1]),
+        "Issues should be ordered by creation date ascending: {:?}",
+        created_dates
     );
 }
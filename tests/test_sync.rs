@@ -0,0 +1,123 @@
+//! Tests for incremental repository sync with `updated_at` watermarks
+
+use chrono::{DateTime, Utc};
+
+mod test_util;
+use github_insight::services::SyncService;
+use github_insight::types::RepositoryId;
+use test_util::create_mock_github_client;
+
+fn issue_node(number: u32, updated_at: &str) -> String {
+    format!(
+        r#"{{
+            "__typename": "Issue",
+            "number": {number},
+            "title": "Issue {number}",
+            "body": "",
+            "state": "OPEN",
+            "createdAt": "2025-01-01T00:00:00Z",
+            "updatedAt": "{updated_at}",
+            "closedAt": null,
+            "url": "https://github.com/owner/repo/issues/{number}",
+            "comments": {{ "nodes": [], "totalCount": 0 }},
+            "labels": null,
+            "assignees": null,
+            "author": {{ "login": "alice" }},
+            "milestone": null,
+            "locked": false,
+            "timelineItems": null,
+            "repository": {{ "owner": {{ "login": "owner" }}, "name": "repo" }}
+        }}"#
+    )
+}
+
+fn search_page(nodes: &[String], end_cursor: Option<&str>) -> String {
+    format!(
+        r#"{{
+            "data": {{
+                "search": {{
+                    "nodes": [{}],
+                    "pageInfo": {{ "hasNextPage": {}, "endCursor": {} }}
+                }}
+            }}
+        }}"#,
+        nodes.join(","),
+        end_cursor.is_some(),
+        end_cursor
+            .map(|cursor| format!("\"{}\"", cursor))
+            .unwrap_or_else(|| "null".to_string())
+    )
+}
+
+fn timestamp(value: &str) -> DateTime<Utc> {
+    value.parse().expect("Invalid timestamp")
+}
+
+/// All pages are fetched and the watermark is the latest `updated_at` seen
+#[tokio::test]
+async fn test_sync_repository_incremental_returns_latest_watermark() {
+    let mut server = mockito::Server::new_async().await;
+    let first_page = server
+        .mock("POST", "/graphql")
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::Regex(r"updated:>=2025-02-01T00:00:00Z".to_string()),
+            mockito::Matcher::PartialJsonString(r#"{"variables": {"cursor": null}}"#.to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(search_page(
+            &[
+                issue_node(1, "2025-02-03T08:00:00Z"),
+                issue_node(2, "2025-02-10T12:30:00Z"),
+            ],
+            Some("page-2"),
+        ))
+        .create_async()
+        .await;
+    let second_page = server
+        .mock("POST", "/graphql")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"variables": {"cursor": "page-2"}}"#.to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(search_page(&[issue_node(3, "2025-02-05T00:00:00Z")], None))
+        .create_async()
+        .await;
+
+    let (resources, watermark) = SyncService::new(create_mock_github_client(&server))
+        .sync_repository_incremental(
+            RepositoryId::new("owner", "repo"),
+            timestamp("2025-02-01T00:00:00Z"),
+        )
+        .await
+        .expect("Failed to sync repository");
+
+    assert_eq!(resources.len(), 3);
+    assert_eq!(watermark, timestamp("2025-02-10T12:30:00Z"));
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+}
+
+/// Without changes the previous watermark is kept
+#[tokio::test]
+async fn test_sync_repository_incremental_without_changes_keeps_watermark() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/graphql")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(search_page(&[], None))
+        .create_async()
+        .await;
+
+    let since = timestamp("2025-02-01T00:00:00Z");
+    let (resources, watermark) = SyncService::new(create_mock_github_client(&server))
+        .sync_repository_incremental(RepositoryId::new("owner", "repo"), since)
+        .await
+        .expect("Failed to sync repository");
+
+    assert!(resources.is_empty());
+    assert_eq!(watermark, since);
+    mock.assert_async().await;
+}
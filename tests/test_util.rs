@@ -1,7 +1,7 @@
-//! Integration tests for GitHub client pull request functionality
+//! Shared helpers for creating GitHub clients in integration tests
 //!
-//! These tests verify the ability to fetch pull requests by number from real GitHub repositories.
-//! Tests use the GITHUB_INSIGHT_GITHUB_TOKEN environment variable for authentication.
+//! Each test crate pulls this in with `mod test_util;` and uses only some of the helpers.
+#![allow(dead_code)]
 
 use std::env;
 use tokio::time::Duration;
@@ -19,3 +19,21 @@ pub fn create_test_github_client() -> GitHubClient {
     GitHubClient::new(token, Some(Duration::from_secs(15)))
         .expect("Failed to create GitHub client for testing. Note: GraphQL API requires authentication even for public repositories.")
 }
+
+/// Creates a GitHub client that sends GraphQL and REST requests to a mock server
+///
+/// The client has no token, so no network access or GitHub credentials are needed.
+pub fn create_mock_github_client(server: &mockito::ServerGuard) -> GitHubClient {
+    create_mock_github_client_with_token(server, None)
+}
+
+/// Creates a GitHub client that sends requests to a mock server with the given token
+pub fn create_mock_github_client_with_token(
+    server: &mockito::ServerGuard,
+    token: Option<&str>,
+) -> GitHubClient {
+    GitHubClient::new(token.map(str::to_string), None)
+        .expect("Failed to create GitHub client")
+        .with_graphql_endpoint(format!("{}/graphql", server.url()))
+        .with_rest_base_url(server.url())
+}
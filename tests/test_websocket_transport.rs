@@ -0,0 +1,145 @@
+//! Tests for the WebSocket MCP transport
+//!
+//! These tests start the server on an ephemeral local port and talk to it with raw
+//! JSON-RPC text frames, so they need neither network access nor a GitHub token.
+
+use std::ffi::OsString;
+use std::path::Path;
+
+use futures::{SinkExt, StreamExt};
+use serde_json::{Value, json};
+use serial_test::serial;
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::Message;
+
+use github_insight::transport::websocket::WebSocketServerApp;
+
+type ClientSocket =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn send_frame(socket: &mut ClientSocket, message: Value) {
+    socket
+        .send(Message::text(message.to_string()))
+        .await
+        .expect("Failed to send frame");
+}
+
+/// Points `HOME` at another directory until dropped, then restores the previous value
+struct HomeGuard {
+    previous: Option<OsString>,
+}
+
+impl HomeGuard {
+    fn set(home: &Path) -> Self {
+        let previous = std::env::var_os("HOME");
+        unsafe {
+            std::env::set_var("HOME", home);
+        }
+        Self { previous }
+    }
+}
+
+impl Drop for HomeGuard {
+    fn drop(&mut self) {
+        unsafe {
+            match &self.previous {
+                Some(previous) => std::env::set_var("HOME", previous),
+                None => std::env::remove_var("HOME"),
+            }
+        }
+    }
+}
+
+/// Read frames until the JSON-RPC response with the given id arrives
+async fn receive_response(socket: &mut ClientSocket, id: u64) -> Value {
+    loop {
+        let frame = socket
+            .next()
+            .await
+            .expect("Connection closed before response")
+            .expect("Failed to read frame");
+        if !frame.is_text() {
+            continue;
+        }
+        let message: Value =
+            serde_json::from_str(frame.to_text().unwrap()).expect("Response is not JSON");
+        if message["id"] == json!(id) {
+            return message;
+        }
+    }
+}
+
+/// Connect, perform the MCP handshake, and call a profile tool over one socket
+#[tokio::test]
+#[serial]
+async fn test_websocket_handshake_and_tool_call() {
+    // Keep the profile store of this test out of the real home directory
+    let home = tempfile::tempdir().expect("Failed to create temporary home");
+    let _home_guard = HomeGuard::set(home.path());
+
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind listener");
+    let addr = listener.local_addr().unwrap();
+    let app = WebSocketServerApp::new(addr, None, None, None);
+    let server = tokio::spawn(app.serve_listener(listener));
+
+    let (mut socket, _) = tokio_tungstenite::connect_async(format!("ws://{}", addr))
+        .await
+        .expect("Failed to connect");
+
+    send_frame(
+        &mut socket,
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2024-11-05",
+                "capabilities": {},
+                "clientInfo": { "name": "websocket-test", "version": "0.0.1" }
+            }
+        }),
+    )
+    .await;
+    let initialize = receive_response(&mut socket, 1).await;
+    assert!(
+        initialize["result"]["capabilities"]["tools"].is_object(),
+        "Unexpected initialize response: {}",
+        initialize
+    );
+
+    send_frame(
+        &mut socket,
+        json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+    )
+    .await;
+    send_frame(
+        &mut socket,
+        json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "tools/call",
+            "params": { "name": "list_repository_urls_in_current_profile", "arguments": {} }
+        }),
+    )
+    .await;
+    let call = receive_response(&mut socket, 2).await;
+    assert_eq!(call["jsonrpc"], "2.0");
+    assert_eq!(call["result"]["isError"], json!(false), "{}", call);
+    assert_eq!(call["result"]["content"][0]["type"], "text");
+    let urls: Vec<String> =
+        serde_json::from_str(call["result"]["content"][0]["text"].as_str().unwrap())
+            .expect("Tool output is not a JSON array");
+    assert!(urls.is_empty());
+
+    socket.close(None).await.expect("Failed to close socket");
+
+    // Disconnecting a client must not stop the server from accepting new ones
+    let (mut second, _) = tokio_tungstenite::connect_async(format!("ws://{}", addr))
+        .await
+        .expect("Failed to reconnect after disconnect");
+    second.close(None).await.ok();
+
+    server.abort();
+}